@@ -122,6 +122,7 @@ fn configure_validation(builder: Builder) -> Builder {
             ("CreateCollection.optimizers_config", ""),
             ("CreateCollection.vectors_config", ""),
             ("CreateCollection.quantization_config", ""),
+            ("CreateCollection.strict_mode_config", ""),
             ("UpdateCollection.collection_name", "length(min = 1, max = 255)"),
             ("UpdateCollection.optimizers_config", ""),
             ("UpdateCollection.params", ""),
@@ -129,17 +130,21 @@ fn configure_validation(builder: Builder) -> Builder {
             ("UpdateCollection.hnsw_config", ""),
             ("UpdateCollection.vectors_config", ""),
             ("UpdateCollection.quantization_config", ""),
+            ("UpdateCollection.strict_mode_config", ""),
             ("DeleteCollection.collection_name", "length(min = 1, max = 255)"),
             ("DeleteCollection.timeout", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
             ("CollectionConfig.params", ""),
             ("CollectionConfig.hnsw_config", ""),
             ("CollectionConfig.optimizers_config", ""),
             ("CollectionConfig.quantization_config", ""),
+            ("CollectionConfig.strict_mode_config", ""),
             ("CollectionParams.vectors_config", ""),
             ("ChangeAliases.timeout", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
             ("ListCollectionAliasesRequest.collection_name", "length(min = 1, max = 255)"),
             ("HnswConfigDiff.ef_construct", "custom = \"crate::grpc::validate::validate_u64_range_min_4\""),
             ("WalConfigDiff.wal_capacity_mb", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
+            ("StrictModeConfig.max_query_limit", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
+            ("StrictModeConfig.max_timeout", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
             ("OptimizersConfigDiff.deleted_threshold", "custom = \"crate::grpc::validate::validate_f64_range_1\""),
             ("OptimizersConfigDiff.vacuum_min_vector_number", "custom = \"crate::grpc::validate::validate_u64_range_min_100\""),
             ("VectorsConfig.config", ""),
@@ -147,6 +152,7 @@ fn configure_validation(builder: Builder) -> Builder {
             ("VectorParams.size", "range(min = 1, max = 65536)"),
             ("VectorParams.hnsw_config", ""),
             ("VectorParams.quantization_config", ""),
+            ("VectorParams.additional_quantization_configs", ""),
             ("VectorParamsMap.map", ""),
             ("VectorParamsDiff.hnsw_config", ""),
             ("VectorParamsDiff.quantization_config", ""),
@@ -182,17 +188,26 @@ fn configure_validation(builder: Builder) -> Builder {
         .validates(&[
             ("UpsertPoints.collection_name", "length(min = 1, max = 255)"),
             ("UpsertPoints.points", ""),
+            ("UpsertPoints.idempotency_key", "length(min = 1, max = 255)"),
             ("DeletePoints.collection_name", "length(min = 1, max = 255)"),
+            ("DeletePoints.max_affected_points", "range(min = 1)"),
+            ("RestorePoints.collection_name", "length(min = 1, max = 255)"),
             ("UpdatePointVectors.collection_name", "length(min = 1, max = 255)"),
             ("UpdatePointVectors.vectors", "custom(function = \"crate::grpc::validate::validate_named_vectors_not_empty\", message = \"must specify vectors to update\")"),
             ("DeletePointVectors.collection_name", "length(min = 1, max = 255)"),
             ("DeletePointVectors.vector_names", "length(min = 1, message = \"must specify vector names to delete\")"),
             ("GetPoints.collection_name", "length(min = 1, max = 255)"),
             ("SetPayloadPoints.collection_name", "length(min = 1, max = 255)"),
+            ("SetPayloadPoints.max_affected_points", "range(min = 1)"),
+            ("MutatePayloadPoints.collection_name", "length(min = 1, max = 255)"),
+            ("MutatePayloadPoints.mutations", "length(min = 1, message = \"must specify mutations to apply\")"),
             ("DeletePayloadPoints.collection_name", "length(min = 1, max = 255)"),
+            ("DeletePayloadPoints.max_affected_points", "range(min = 1)"),
             ("ClearPayloadPoints.collection_name", "length(min = 1, max = 255)"),
+            ("ClearPayloadPoints.max_affected_points", "range(min = 1)"),
             ("UpdateBatchPoints.collection_name", "length(min = 1, max = 255)"),
             ("UpdateBatchPoints.operations", "length(min = 1)"),
+            ("UpdateBatchPoints.idempotency_key", "length(min = 1, max = 255)"),
             ("CreateFieldIndexCollection.collection_name", "length(min = 1, max = 255)"),
             ("CreateFieldIndexCollection.field_name", "length(min = 1)"),
             ("DeleteFieldIndexCollection.collection_name", "length(min = 1, max = 255)"),
@@ -245,6 +260,7 @@ fn configure_validation(builder: Builder) -> Builder {
             ("DiscoverBatchPoints.timeout", "custom = \"crate::grpc::validate::validate_u64_range_min_1\""),
             ("CountPoints.collection_name", "length(min = 1, max = 255)"),
             ("CountPoints.filter", ""),
+            ("SubscribeUpdatesRequest.collection_name", "length(min = 1, max = 255)"),
             ("GeoPolygon.exterior", "custom = \"crate::grpc::validate::validate_geo_polygon_exterior\""),
             ("GeoPolygon.interiors", "custom = \"crate::grpc::validate::validate_geo_polygon_interiors\""),
             ("Filter.should", ""),
@@ -273,9 +289,12 @@ fn configure_validation(builder: Builder) -> Builder {
         .validates(&[
             ("UpsertPointsInternal.upsert_points", ""),
             ("DeletePointsInternal.delete_points", ""),
+            ("RestorePointsInternal.restore_points", ""),
             ("UpdateVectorsInternal.update_vectors", ""),
             ("DeleteVectorsInternal.delete_vectors", ""),
             ("SetPayloadPointsInternal.set_payload_points", ""),
+            ("MutatePayloadPointsInternal.mutate_payload_points", ""),
+            ("UpdateBatchInternal.update_batch_points", ""),
             ("DeletePayloadPointsInternal.delete_payload_points", ""),
             ("ClearPayloadPointsInternal.clear_payload_points", ""),
             ("CreateFieldIndexCollectionInternal.create_field_index_collection", ""),
@@ -5,6 +5,8 @@ use std::time::Instant;
 use chrono::{NaiveDateTime, Timelike};
 use itertools::Itertools;
 use segment::data_types::integer_index::IntegerIndexType;
+use segment::data_types::relative_datetime;
+use segment::data_types::search_explain as segment_explain;
 use segment::data_types::text_index::TextIndexType;
 use segment::data_types::vectors as segment_vectors;
 use segment::json_path::JsonPath;
@@ -16,10 +18,13 @@ use uuid::Uuid;
 
 use super::qdrant::raw_query::RawContextPair;
 use super::qdrant::{
-    raw_query, start_from, BinaryQuantization, CompressionRatio, DatetimeRange, Direction,
-    GeoLineString, GroupId, LookupLocation, MultiVectorComparator, MultiVectorConfig, OrderBy,
-    OrderValue, Range, RawVector, RecommendStrategy, SearchPointGroups, SearchPoints,
-    ShardKeySelector, SparseIndices, StartFrom, WithLookup,
+    raw_query, start_from, BinaryQuantization, BinaryQuantizationEncoding,
+    BinaryQuantizationQueryEncoding, CardinalityExplanation, CompressionRatio, DatetimeRange,
+    Direction, GeoLineString, GroupId, LookupLocation, MultiVectorComparator, MultiVectorConfig,
+    MultiVectorIndexMode, OrderBy, OrderByKey, OrderValue, Range, RawVector, RecommendStrategy,
+    RelativeDatetimeRange, SearchExplanation, SearchPointGroups, SearchPoints, SearchStrategy,
+    SearchTimings, SegmentSearchExplanation, ShardKeySelector, ShardSearchExplanation,
+    SparseIndices, StartFrom, WithLookup,
 };
 use crate::grpc::models::{CollectionsResponse, VersionInfo};
 use crate::grpc::qdrant::condition::ConditionOneOf;
@@ -37,10 +42,10 @@ use crate::grpc::qdrant::{
     MultiDenseVector, NamedVectors, NestedCondition, PayloadExcludeSelector,
     PayloadIncludeSelector, PayloadIndexParams, PayloadSchemaInfo, PayloadSchemaType, PointId,
     PointsOperationResponse, PointsOperationResponseInternal, ProductQuantization,
-    QuantizationConfig, QuantizationSearchParams, QuantizationType, RepeatedIntegers,
-    RepeatedStrings, ScalarQuantization, ScoredPoint, SearchParams, ShardKey, SparseVector, Struct,
-    TextIndexParams, TokenizerType, UpdateResult, UpdateResultInternal, Value, ValuesCount, Vector,
-    Vectors, VectorsSelector, WithPayloadSelector, WithVectorsSelector,
+    QuantizationConfig, QuantizationKind, QuantizationSearchParams, QuantizationType,
+    RepeatedIntegers, RepeatedStrings, ScalarQuantization, ScoredPoint, SearchParams, ShardKey,
+    SparseVector, Struct, TextIndexParams, TokenizerType, UpdateResult, UpdateResultInternal,
+    Value, ValuesCount, Vector, Vectors, VectorsSelector, WithPayloadSelector, WithVectorsSelector,
 };
 use crate::rest::schema as rest;
 
@@ -51,7 +56,7 @@ pub fn payload_to_proto(payload: segment::types::Payload) -> HashMap<String, Val
         .collect()
 }
 
-fn json_to_proto(json_value: serde_json::Value) -> Value {
+pub fn json_to_proto(json_value: serde_json::Value) -> Value {
     match json_value {
         serde_json::Value::Null => Value {
             kind: Some(Kind::NullValue(0)),
@@ -96,7 +101,7 @@ pub fn proto_to_payloads(proto: HashMap<String, Value>) -> Result<segment::types
     Ok(map.into())
 }
 
-fn proto_to_json(proto: Value) -> Result<serde_json::Value, Status> {
+pub fn proto_to_json(proto: Value) -> Result<serde_json::Value, Status> {
     match proto.kind {
         None => Ok(serde_json::Value::default()),
         Some(kind) => match kind {
@@ -450,6 +455,10 @@ impl From<QuantizationSearchParams> for segment::types::QuantizationSearchParams
             ignore: params.ignore.unwrap_or(default_quantization_ignore_value()),
             rescore: params.rescore,
             oversampling: params.oversampling,
+            quantization: params
+                .quantization
+                .and_then(QuantizationKind::from_i32)
+                .map(segment::types::QuantizationKind::from),
         }
     }
 }
@@ -460,6 +469,29 @@ impl From<segment::types::QuantizationSearchParams> for QuantizationSearchParams
             ignore: Some(params.ignore),
             rescore: params.rescore,
             oversampling: params.oversampling,
+            quantization: params
+                .quantization
+                .map(|kind| QuantizationKind::from(kind) as i32),
+        }
+    }
+}
+
+impl From<QuantizationKind> for segment::types::QuantizationKind {
+    fn from(value: QuantizationKind) -> Self {
+        match value {
+            QuantizationKind::Scalar => Self::Scalar,
+            QuantizationKind::Product => Self::Product,
+            QuantizationKind::Binary => Self::Binary,
+        }
+    }
+}
+
+impl From<segment::types::QuantizationKind> for QuantizationKind {
+    fn from(value: segment::types::QuantizationKind) -> Self {
+        match value {
+            segment::types::QuantizationKind::Scalar => Self::Scalar,
+            segment::types::QuantizationKind::Product => Self::Product,
+            segment::types::QuantizationKind::Binary => Self::Binary,
         }
     }
 }
@@ -644,6 +676,11 @@ impl From<segment::types::ScoredPoint> for ScoredPoint {
             vectors: point.vector.map(|v| v.into()),
             shard_key: point.shard_key.map(convert_shard_key_to_grpc),
             order_value: point.order_value.map(From::from),
+            then_order_values: point
+                .then_order_values
+                .into_iter()
+                .map(From::from)
+                .collect(),
         }
     }
 }
@@ -664,6 +701,182 @@ impl From<segment::data_types::groups::GroupId> for GroupId {
     }
 }
 
+impl From<segment_explain::SearchStrategy> for SearchStrategy {
+    fn from(value: segment_explain::SearchStrategy) -> Self {
+        match value {
+            segment_explain::SearchStrategy::FullScan => SearchStrategy::FullScan,
+            segment_explain::SearchStrategy::PayloadIndex => SearchStrategy::PayloadIndex,
+            segment_explain::SearchStrategy::Hnsw => SearchStrategy::Hnsw,
+            segment_explain::SearchStrategy::HnswFiltered => SearchStrategy::HnswFiltered,
+            segment_explain::SearchStrategy::Sparse => SearchStrategy::Sparse,
+            segment_explain::SearchStrategy::SkippedIndexedOnly => {
+                SearchStrategy::SkippedIndexedOnly
+            }
+        }
+    }
+}
+
+impl From<SearchStrategy> for segment_explain::SearchStrategy {
+    fn from(value: SearchStrategy) -> Self {
+        match value {
+            SearchStrategy::FullScan => segment_explain::SearchStrategy::FullScan,
+            SearchStrategy::PayloadIndex => segment_explain::SearchStrategy::PayloadIndex,
+            SearchStrategy::Hnsw => segment_explain::SearchStrategy::Hnsw,
+            SearchStrategy::HnswFiltered => segment_explain::SearchStrategy::HnswFiltered,
+            SearchStrategy::Sparse => segment_explain::SearchStrategy::Sparse,
+            SearchStrategy::SkippedIndexedOnly => {
+                segment_explain::SearchStrategy::SkippedIndexedOnly
+            }
+        }
+    }
+}
+
+impl From<segment_explain::CardinalityExplanation> for CardinalityExplanation {
+    fn from(value: segment_explain::CardinalityExplanation) -> Self {
+        let segment_explain::CardinalityExplanation {
+            primary_clauses,
+            min,
+            exp,
+            max,
+        } = value;
+        Self {
+            primary_clauses,
+            min: min as u64,
+            exp: exp as u64,
+            max: max as u64,
+        }
+    }
+}
+
+impl From<CardinalityExplanation> for segment_explain::CardinalityExplanation {
+    fn from(value: CardinalityExplanation) -> Self {
+        let CardinalityExplanation {
+            primary_clauses,
+            min,
+            exp,
+            max,
+        } = value;
+        Self {
+            primary_clauses,
+            min: min as usize,
+            exp: exp as usize,
+            max: max as usize,
+        }
+    }
+}
+
+impl From<segment_explain::SearchTimings> for SearchTimings {
+    fn from(value: segment_explain::SearchTimings) -> Self {
+        let segment_explain::SearchTimings {
+            planning,
+            prefiltering,
+            search,
+            rescoring,
+        } = value;
+        Self {
+            planning,
+            prefiltering,
+            search,
+            rescoring,
+        }
+    }
+}
+
+impl From<SearchTimings> for segment_explain::SearchTimings {
+    fn from(value: SearchTimings) -> Self {
+        let SearchTimings {
+            planning,
+            prefiltering,
+            search,
+            rescoring,
+        } = value;
+        Self {
+            planning,
+            prefiltering,
+            search,
+            rescoring,
+        }
+    }
+}
+
+impl From<segment_explain::SegmentSearchExplanation> for SegmentSearchExplanation {
+    fn from(value: segment_explain::SegmentSearchExplanation) -> Self {
+        let segment_explain::SegmentSearchExplanation {
+            segment,
+            vector_name,
+            batch_size,
+            search,
+        } = value;
+        Self {
+            segment,
+            vector_name,
+            batch_size: batch_size as u64,
+            strategy: SearchStrategy::from(search.strategy) as i32,
+            cardinality: search.cardinality.map(From::from),
+            quantization: search.quantization,
+            rescoring: search.rescoring,
+            timings: Some(search.timings.into()),
+        }
+    }
+}
+
+impl TryFrom<SegmentSearchExplanation> for segment_explain::SegmentSearchExplanation {
+    type Error = Status;
+
+    fn try_from(value: SegmentSearchExplanation) -> Result<Self, Self::Error> {
+        let SegmentSearchExplanation {
+            segment,
+            vector_name,
+            batch_size,
+            strategy,
+            cardinality,
+            quantization,
+            rescoring,
+            timings,
+        } = value;
+
+        let strategy = SearchStrategy::from_i32(strategy).ok_or_else(|| {
+            Status::invalid_argument(format!("Unknown search strategy: {strategy}"))
+        })?;
+
+        Ok(Self {
+            segment,
+            vector_name,
+            batch_size: batch_size as usize,
+            search: segment_explain::VectorSearchExplanation {
+                strategy: strategy.into(),
+                cardinality: cardinality.map(From::from),
+                quantization,
+                rescoring,
+                timings: timings.map(From::from).unwrap_or_default(),
+            },
+        })
+    }
+}
+
+impl From<segment_explain::ShardSearchExplanation> for ShardSearchExplanation {
+    fn from(value: segment_explain::ShardSearchExplanation) -> Self {
+        let segment_explain::ShardSearchExplanation {
+            shard_id,
+            segments,
+            timings,
+        } = value;
+        Self {
+            shard_id,
+            segments: segments.into_iter().map(From::from).collect(),
+            timings: Some(timings.into()),
+        }
+    }
+}
+
+impl From<segment_explain::SearchExplanation> for SearchExplanation {
+    fn from(value: segment_explain::SearchExplanation) -> Self {
+        Self {
+            shards: value.shards.into_iter().map(From::from).collect(),
+        }
+    }
+}
+
 impl TryFrom<NamedVectors> for HashMap<String, segment_vectors::Vector> {
     type Error = Status;
 
@@ -777,6 +990,9 @@ impl From<segment::types::ScalarQuantization> for ScalarQuantization {
                 segment::types::ScalarType::Int8 => {
                     crate::grpc::qdrant::QuantizationType::Int8 as i32
                 }
+                segment::types::ScalarType::Int4 => {
+                    crate::grpc::qdrant::QuantizationType::Int4 as i32
+                }
             },
             quantile: config.quantile,
             always_ram: config.always_ram,
@@ -792,6 +1008,7 @@ impl TryFrom<ScalarQuantization> for segment::types::ScalarQuantization {
             scalar: segment::types::ScalarQuantizationConfig {
                 r#type: match QuantizationType::from_i32(value.r#type) {
                     Some(QuantizationType::Int8) => segment::types::ScalarType::Int8,
+                    Some(QuantizationType::Int4) => segment::types::ScalarType::Int4,
                     Some(QuantizationType::UnknownQuantization) | None => {
                         return Err(Status::invalid_argument("Unknown quantization type"));
                     }
@@ -848,6 +1065,62 @@ impl From<segment::types::BinaryQuantization> for BinaryQuantization {
         let config = value.binary;
         BinaryQuantization {
             always_ram: config.always_ram,
+            encoding: config
+                .encoding
+                .map(|encoding| BinaryQuantizationEncoding::from(encoding) as i32),
+            query_encoding: config
+                .query_encoding
+                .map(|encoding| BinaryQuantizationQueryEncoding::from(encoding) as i32),
+        }
+    }
+}
+
+impl From<segment::types::BinaryQuantizationEncoding> for BinaryQuantizationEncoding {
+    fn from(value: segment::types::BinaryQuantizationEncoding) -> Self {
+        match value {
+            segment::types::BinaryQuantizationEncoding::OneBit => Self::OneBit,
+            segment::types::BinaryQuantizationEncoding::TwoBits => Self::TwoBits,
+            segment::types::BinaryQuantizationEncoding::OneAndHalfBits => Self::OneAndHalfBits,
+        }
+    }
+}
+
+impl TryFrom<i32> for segment::types::BinaryQuantizationEncoding {
+    type Error = Status;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match BinaryQuantizationEncoding::from_i32(value) {
+            None => Err(Status::invalid_argument(format!(
+                "Unknown binary quantization encoding: {value}"
+            ))),
+            Some(BinaryQuantizationEncoding::OneBit) => Ok(Self::OneBit),
+            Some(BinaryQuantizationEncoding::TwoBits) => Ok(Self::TwoBits),
+            Some(BinaryQuantizationEncoding::OneAndHalfBits) => Ok(Self::OneAndHalfBits),
+        }
+    }
+}
+
+impl From<segment::types::BinaryQuantizationQueryEncoding> for BinaryQuantizationQueryEncoding {
+    fn from(value: segment::types::BinaryQuantizationQueryEncoding) -> Self {
+        match value {
+            segment::types::BinaryQuantizationQueryEncoding::Binary => Self::Binary,
+            segment::types::BinaryQuantizationQueryEncoding::Scalar4Bits => Self::Scalar4Bits,
+            segment::types::BinaryQuantizationQueryEncoding::Scalar8Bits => Self::Scalar8Bits,
+        }
+    }
+}
+
+impl TryFrom<i32> for segment::types::BinaryQuantizationQueryEncoding {
+    type Error = Status;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match BinaryQuantizationQueryEncoding::from_i32(value) {
+            None => Err(Status::invalid_argument(format!(
+                "Unknown binary quantization query encoding: {value}"
+            ))),
+            Some(BinaryQuantizationQueryEncoding::Binary) => Ok(Self::Binary),
+            Some(BinaryQuantizationQueryEncoding::Scalar4Bits) => Ok(Self::Scalar4Bits),
+            Some(BinaryQuantizationQueryEncoding::Scalar8Bits) => Ok(Self::Scalar8Bits),
         }
     }
 }
@@ -859,6 +1132,8 @@ impl TryFrom<BinaryQuantization> for segment::types::BinaryQuantization {
         Ok(segment::types::BinaryQuantization {
             binary: segment::types::BinaryQuantizationConfig {
                 always_ram: value.always_ram,
+                encoding: value.encoding.map(TryInto::try_into).transpose()?,
+                query_encoding: value.query_encoding.map(TryInto::try_into).transpose()?,
             },
         })
     }
@@ -911,6 +1186,7 @@ impl From<segment::types::MultiVectorConfig> for MultiVectorConfig {
     fn from(value: segment::types::MultiVectorConfig) -> Self {
         Self {
             comparator: MultiVectorComparator::from(value.comparator) as i32,
+            index_mode: Some(MultiVectorIndexMode::from(value.index_mode) as i32),
         }
     }
 }
@@ -929,8 +1205,18 @@ impl TryFrom<MultiVectorConfig> for segment::types::MultiVectorConfig {
     fn try_from(value: MultiVectorConfig) -> Result<Self, Self::Error> {
         let comparator = MultiVectorComparator::from_i32(value.comparator)
             .ok_or_else(|| Status::invalid_argument("Unknown multi vector comparator"))?;
+        let index_mode = value
+            .index_mode
+            .map(|index_mode| {
+                MultiVectorIndexMode::from_i32(index_mode)
+                    .ok_or_else(|| Status::invalid_argument("Unknown multi vector index mode"))
+            })
+            .transpose()?
+            .map(segment::types::MultiVectorIndexMode::from)
+            .unwrap_or_default();
         Ok(segment::types::MultiVectorConfig {
             comparator: segment::types::MultiVectorComparator::from(comparator),
+            index_mode,
         })
     }
 }
@@ -943,6 +1229,24 @@ impl From<MultiVectorComparator> for segment::types::MultiVectorComparator {
     }
 }
 
+impl From<segment::types::MultiVectorIndexMode> for MultiVectorIndexMode {
+    fn from(value: segment::types::MultiVectorIndexMode) -> Self {
+        match value {
+            segment::types::MultiVectorIndexMode::Point => MultiVectorIndexMode::Point,
+            segment::types::MultiVectorIndexMode::Token => MultiVectorIndexMode::Token,
+        }
+    }
+}
+
+impl From<MultiVectorIndexMode> for segment::types::MultiVectorIndexMode {
+    fn from(value: MultiVectorIndexMode) -> Self {
+        match value {
+            MultiVectorIndexMode::Point => segment::types::MultiVectorIndexMode::Point,
+            MultiVectorIndexMode::Token => segment::types::MultiVectorIndexMode::Token,
+        }
+    }
+}
+
 fn conditions_helper_from_grpc(
     conditions: Vec<Condition>,
 ) -> Result<Option<Vec<segment::types::Condition>>, tonic::Status> {
@@ -1176,6 +1480,7 @@ impl TryFrom<FieldCondition> for segment::types::FieldCondition {
             values_count,
             geo_polygon,
             datetime_range,
+            relative_datetime_range,
         } = value;
 
         let geo_bounding_box =
@@ -1187,11 +1492,14 @@ impl TryFrom<FieldCondition> for segment::types::FieldCondition {
         let datetime_range = datetime_range
             .map(segment::types::RangeInterface::try_from)
             .transpose()?;
+        let relative_datetime_range = relative_datetime_range
+            .map(segment::types::RangeInterface::try_from)
+            .transpose()?;
 
         Ok(Self {
             key: json_path_from_proto(&key)?,
             r#match: r#match.map_or_else(|| Ok(None), |m| m.try_into().map(Some))?,
-            range: range.or(datetime_range),
+            range: range.or(datetime_range).or(relative_datetime_range),
             geo_bounding_box,
             geo_radius,
             geo_polygon,
@@ -1212,10 +1520,15 @@ impl From<segment::types::FieldCondition> for FieldCondition {
             values_count,
         } = value;
 
-        let (range, datetime_range) = match range {
-            Some(segment::types::RangeInterface::Float(range)) => (Some(range.into()), None),
-            Some(segment::types::RangeInterface::DateTime(range)) => (None, Some(range.into())),
-            None => (None, None),
+        let (range, datetime_range, relative_datetime_range) = match range {
+            Some(segment::types::RangeInterface::Float(range)) => (Some(range.into()), None, None),
+            Some(segment::types::RangeInterface::DateTime(range)) => {
+                (None, Some(range.into()), None)
+            }
+            Some(segment::types::RangeInterface::RelativeDateTime(range)) => {
+                (None, None, Some(range.into()))
+            }
+            None => (None, None, None),
         };
 
         Self {
@@ -1227,6 +1540,7 @@ impl From<segment::types::FieldCondition> for FieldCondition {
             geo_polygon: geo_polygon.map(Into::into),
             values_count: values_count.map(Into::into),
             datetime_range,
+            relative_datetime_range,
         }
     }
 }
@@ -1391,6 +1705,62 @@ impl From<segment::types::Range<DateTimePayloadType>> for DatetimeRange {
     }
 }
 
+impl TryFrom<RelativeDatetimeRange> for segment::types::RangeInterface {
+    type Error = Status;
+
+    fn try_from(value: RelativeDatetimeRange) -> Result<Self, Self::Error> {
+        let RelativeDatetimeRange {
+            lt,
+            gt,
+            gte,
+            lte,
+            timezone,
+        } = value;
+
+        let parse = |bound: Option<String>| {
+            bound
+                .map(|bound| bound.parse())
+                .transpose()
+                .map_err(|err: String| {
+                    Status::invalid_argument(format!("Unable to parse datetime: {err}"))
+                })
+        };
+
+        Ok(Self::RelativeDateTime(
+            relative_datetime::RelativeDatetimeRange {
+                lt: parse(lt)?,
+                gt: parse(gt)?,
+                gte: parse(gte)?,
+                lte: parse(lte)?,
+                timezone: timezone
+                    .map(|timezone| timezone.parse())
+                    .transpose()
+                    .map_err(Status::invalid_argument)?,
+            },
+        ))
+    }
+}
+
+impl From<relative_datetime::RelativeDatetimeRange> for RelativeDatetimeRange {
+    fn from(value: relative_datetime::RelativeDatetimeRange) -> Self {
+        let relative_datetime::RelativeDatetimeRange {
+            lt,
+            gt,
+            gte,
+            lte,
+            timezone,
+        } = value;
+
+        Self {
+            lt: lt.map(|bound| bound.to_string()),
+            gt: gt.map(|bound| bound.to_string()),
+            gte: gte.map(|bound| bound.to_string()),
+            lte: lte.map(|bound| bound.to_string()),
+            timezone: timezone.map(|timezone| timezone.0.name().to_string()),
+        }
+    }
+}
+
 impl From<ValuesCount> for segment::types::ValuesCount {
     fn from(value: ValuesCount) -> Self {
         Self {
@@ -1524,10 +1894,18 @@ impl TryFrom<OrderBy> for segment::data_types::order_by::OrderBy {
             })
             .transpose()?;
 
+        let then_by = value
+            .then_by
+            .into_iter()
+            .map(segment::data_types::order_by::OrderByKey::try_from)
+            .collect::<Result<_, _>>()?;
+
         Ok(Self {
             key: json_path_from_proto(&value.key)?,
             direction,
             start_from,
+            geo_distance_from: value.geo_distance_from.map(Into::into),
+            then_by,
         })
     }
 }
@@ -1538,6 +1916,33 @@ impl From<segment::data_types::order_by::OrderBy> for OrderBy {
             key: value.key.to_string(),
             direction: value.direction.map(|d| Direction::from(d) as i32),
             start_from: value.start_from.map(|start_from| start_from.into()),
+            geo_distance_from: value.geo_distance_from.map(Into::into),
+            then_by: value.then_by.into_iter().map(From::from).collect(),
+        }
+    }
+}
+
+impl TryFrom<OrderByKey> for segment::data_types::order_by::OrderByKey {
+    type Error = Status;
+
+    fn try_from(value: OrderByKey) -> Result<Self, Self::Error> {
+        Ok(Self {
+            key: json_path_from_proto(&value.key)?,
+            direction: value
+                .direction
+                .and_then(Direction::from_i32)
+                .map(segment::data_types::order_by::Direction::from),
+            geo_distance_from: value.geo_distance_from.map(Into::into),
+        })
+    }
+}
+
+impl From<segment::data_types::order_by::OrderByKey> for OrderByKey {
+    fn from(value: segment::data_types::order_by::OrderByKey) -> Self {
+        Self {
+            key: value.key.to_string(),
+            direction: value.direction.map(|d| Direction::from(d) as i32),
+            geo_distance_from: value.geo_distance_from.map(Into::into),
         }
     }
 }
@@ -1606,6 +2011,8 @@ impl TryFrom<Distance> for segment::types::Distance {
             Distance::Euclid => segment::types::Distance::Euclid,
             Distance::Dot => segment::types::Distance::Dot,
             Distance::Manhattan => segment::types::Distance::Manhattan,
+            Distance::Hamming => segment::types::Distance::Hamming,
+            Distance::Jaccard => segment::types::Distance::Jaccard,
         })
     }
 }
@@ -1671,6 +2078,8 @@ impl From<UpdateResultInternal> for UpdateResult {
         Self {
             operation_id: res.operation_id,
             status: res.status,
+            skipped: res.skipped,
+            dry_run: res.dry_run,
         }
     }
 }
@@ -1682,6 +2091,8 @@ impl From<UpdateResult> for UpdateResultInternal {
             operation_id: res.operation_id,
             status: res.status,
             clock_tag: None,
+            skipped: res.skipped,
+            dry_run: res.dry_run,
         }
     }
 }
@@ -1966,6 +2377,7 @@ impl TryFrom<SearchPointGroups> for rest::SearchGroupsRequestInternal {
             timeout: None,
             shard_key_selector: None,
             sparse_indices: value.sparse_indices,
+            explain: None,
         };
 
         if let Some(sparse_indices) = &search_points.sparse_indices {
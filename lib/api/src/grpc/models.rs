@@ -1,6 +1,7 @@
 use std::fmt::Debug;
 
 use schemars::JsonSchema;
+use segment::data_types::search_explain::SearchExplanation;
 use serde;
 use serde::Serialize;
 
@@ -43,6 +44,9 @@ pub struct ApiResponse<D> {
     pub result: Option<D>,
     pub status: ApiStatus,
     pub time: f64,
+    /// How the search was executed, if requested with `explain`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<SearchExplanation>,
 }
 
 #[derive(Debug, Serialize, JsonSchema)]
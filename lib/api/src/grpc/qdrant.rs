@@ -27,6 +27,13 @@ pub struct VectorParams {
     /// Configuration for multi-vector search
     #[prost(message, optional, tag = "7")]
     pub multivector_config: ::core::option::Option<MultiVectorConfig>,
+    /// Quantization configs built in addition to the primary one, of different kinds, can be selected by kind at search time
+    #[prost(message, repeated, tag = "8")]
+    #[validate]
+    pub additional_quantization_configs: ::prost::alloc::vec::Vec<QuantizationConfig>,
+    /// Name of the dense vector this vector is a prefix view of. The view indexes the first `size` dimensions of the source vector
+    #[prost(string, optional, tag = "9")]
+    pub prefix_of: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -136,6 +143,9 @@ pub struct MultiVectorConfig {
     /// Comparator for multi-vector search
     #[prost(enumeration = "MultiVectorComparator", tag = "1")]
     pub comparator: i32,
+    /// Which vectors are used as nodes of the HNSW graph. Default: Point
+    #[prost(enumeration = "MultiVectorIndexMode", optional, tag = "2")]
+    pub index_mode: ::core::option::Option<i32>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -389,6 +399,12 @@ pub struct BinaryQuantization {
     /// If true - quantized vectors always will be stored in RAM, ignoring the config of main storage
     #[prost(bool, optional, tag = "1")]
     pub always_ram: ::core::option::Option<bool>,
+    /// Number of bits used to encode each dimension. Default is OneBit
+    #[prost(enumeration = "BinaryQuantizationEncoding", optional, tag = "2")]
+    pub encoding: ::core::option::Option<i32>,
+    /// Encoding of the query vector. Default is Binary
+    #[prost(enumeration = "BinaryQuantizationQueryEncoding", optional, tag = "3")]
+    pub query_encoding: ::core::option::Option<i32>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -422,6 +438,80 @@ pub struct Disabled {}
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StrictModeConfig {
+    /// Whether strict mode is enabled for the collection. Default: false
+    #[prost(bool, optional, tag = "1")]
+    pub enabled: ::core::option::Option<bool>,
+    /// Max allowed `limit` of search, query, scroll and other requests returning points
+    #[prost(uint64, optional, tag = "2")]
+    #[validate(custom = "crate::grpc::validate::validate_u64_range_min_1")]
+    pub max_query_limit: ::core::option::Option<u64>,
+    /// Max allowed `offset` of search, query and scroll requests
+    #[prost(uint64, optional, tag = "3")]
+    pub max_query_offset: ::core::option::Option<u64>,
+    /// Max allowed `timeout` of a request, in seconds. Also applies to requests without a timeout
+    #[prost(uint64, optional, tag = "4")]
+    #[validate(custom = "crate::grpc::validate::validate_u64_range_min_1")]
+    pub max_timeout: ::core::option::Option<u64>,
+    /// Allow filtering on unindexed fields in search, query, scroll and count requests. Default: true
+    #[prost(bool, optional, tag = "5")]
+    pub unindexed_filtering_retrieve: ::core::option::Option<bool>,
+    /// Allow filtering on unindexed fields in updates, like delete by filter. Default: true
+    #[prost(bool, optional, tag = "6")]
+    pub unindexed_filtering_update: ::core::option::Option<bool>,
+    /// Max allowed `hnsw_ef` in search params
+    #[prost(uint64, optional, tag = "7")]
+    pub search_max_hnsw_ef: ::core::option::Option<u64>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadFieldRange {
+    /// point.key < range.lt
+    #[prost(double, optional, tag = "1")]
+    pub lt: ::core::option::Option<f64>,
+    /// point.key > range.gt
+    #[prost(double, optional, tag = "2")]
+    pub gt: ::core::option::Option<f64>,
+    /// point.key >= range.gte
+    #[prost(double, optional, tag = "3")]
+    pub gte: ::core::option::Option<f64>,
+    /// point.key <= range.lte
+    #[prost(double, optional, tag = "4")]
+    pub lte: ::core::option::Option<f64>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadFieldRule {
+    /// Reject points without a value in this field. Default: false
+    #[prost(bool, optional, tag = "1")]
+    pub required: ::core::option::Option<bool>,
+    /// Type of the field values. Values of an array are checked one by one
+    #[prost(enumeration = "PayloadSchemaType", optional, tag = "2")]
+    pub data_type: ::core::option::Option<i32>,
+    /// Values allowed in the field. If empty - any value is allowed
+    #[prost(message, repeated, tag = "3")]
+    pub allowed_values: ::prost::alloc::vec::Vec<Value>,
+    /// Range of allowed numeric values of the field
+    #[prost(message, optional, tag = "4")]
+    pub range: ::core::option::Option<PayloadFieldRange>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadValidationConfig {
+    /// Rules for payload fields, by path of the field. Fields without a rule accept any value
+    #[prost(map = "string, message", tag = "1")]
+    pub fields: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        PayloadFieldRule,
+    >,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QuantizationConfigDiff {
     #[prost(oneof = "quantization_config_diff::Quantization", tags = "1, 2, 3, 4")]
     #[validate]
@@ -499,6 +589,19 @@ pub struct CreateCollection {
     /// Configuration for sparse vectors
     #[prost(message, optional, tag = "16")]
     pub sparse_vectors_config: ::core::option::Option<SparseVectorConfig>,
+    /// Strict mode guardrails against expensive requests
+    #[prost(message, optional, tag = "17")]
+    #[validate]
+    pub strict_mode_config: ::core::option::Option<StrictModeConfig>,
+    /// Payload field with the expiry time of each point, as RFC 3339 datetime. Expired points are hidden from reads and removed in background
+    #[prost(string, optional, tag = "18")]
+    pub expiry_field: ::core::option::Option<::prost::alloc::string::String>,
+    /// Rules for payload values of upserted points
+    #[prost(message, optional, tag = "19")]
+    pub payload_validation_config: ::core::option::Option<PayloadValidationConfig>,
+    /// If set, deleted points are kept in trash for this many seconds, hidden from reads but restorable
+    #[prost(uint64, optional, tag = "20")]
+    pub trash_retention_sec: ::core::option::Option<u64>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -536,6 +639,13 @@ pub struct UpdateCollection {
     /// New sparse vector parameters
     #[prost(message, optional, tag = "8")]
     pub sparse_vectors_config: ::core::option::Option<SparseVectorConfig>,
+    /// New strict mode parameters
+    #[prost(message, optional, tag = "9")]
+    #[validate]
+    pub strict_mode_config: ::core::option::Option<StrictModeConfig>,
+    /// New payload validation rules, replacing the existing ones
+    #[prost(message, optional, tag = "10")]
+    pub payload_validation_config: ::core::option::Option<PayloadValidationConfig>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -592,6 +702,12 @@ pub struct CollectionParams {
     /// Configuration for sparse vectors
     #[prost(message, optional, tag = "10")]
     pub sparse_vectors_config: ::core::option::Option<SparseVectorConfig>,
+    /// Payload field with the expiry time of each point, as RFC 3339 datetime. Expired points are hidden from reads and removed in background
+    #[prost(string, optional, tag = "11")]
+    pub expiry_field: ::core::option::Option<::prost::alloc::string::String>,
+    /// If set, deleted points are kept in trash for this many seconds, hidden from reads but restorable
+    #[prost(uint64, optional, tag = "12")]
+    pub trash_retention_sec: ::core::option::Option<u64>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -610,6 +726,9 @@ pub struct CollectionParamsDiff {
     /// Fan-out every read request to these many additional remote nodes (and return first available response)
     #[prost(uint32, optional, tag = "4")]
     pub read_fan_out_factor: ::core::option::Option<u32>,
+    /// Payload field with the expiry time of each point, as RFC 3339 datetime. Expired points are hidden from reads and removed in background
+    #[prost(string, optional, tag = "5")]
+    pub expiry_field: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -634,6 +753,13 @@ pub struct CollectionConfig {
     #[prost(message, optional, tag = "5")]
     #[validate]
     pub quantization_config: ::core::option::Option<QuantizationConfig>,
+    /// Strict mode guardrails against expensive requests
+    #[prost(message, optional, tag = "6")]
+    #[validate]
+    pub strict_mode_config: ::core::option::Option<StrictModeConfig>,
+    /// Rules for payload values of upserted points
+    #[prost(message, optional, tag = "7")]
+    pub payload_validation_config: ::core::option::Option<PayloadValidationConfig>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1128,6 +1254,10 @@ pub enum Datatype {
     Float32 = 1,
     Uint8 = 2,
     Float16 = 3,
+    /// Packed bits, 8 dimensions per byte
+    Bit = 4,
+    BFloat16 = 5,
+    Int8 = 6,
 }
 impl Datatype {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1140,6 +1270,9 @@ impl Datatype {
             Datatype::Float32 => "Float32",
             Datatype::Uint8 => "Uint8",
             Datatype::Float16 => "Float16",
+            Datatype::Bit => "Bit",
+            Datatype::BFloat16 => "BFloat16",
+            Datatype::Int8 => "Int8",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1149,6 +1282,9 @@ impl Datatype {
             "Float32" => Some(Self::Float32),
             "Uint8" => Some(Self::Uint8),
             "Float16" => Some(Self::Float16),
+            "Bit" => Some(Self::Bit),
+            "BFloat16" => Some(Self::BFloat16),
+            "Int8" => Some(Self::Int8),
             _ => None,
         }
     }
@@ -1208,12 +1344,45 @@ impl MultiVectorComparator {
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum MultiVectorIndexMode {
+    /// Each point is a single node of the HNSW graph
+    Point = 0,
+    /// Each sub-vector is a separate node of the HNSW graph, hits are rescored with the comparator
+    Token = 1,
+}
+impl MultiVectorIndexMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            MultiVectorIndexMode::Point => "Point",
+            MultiVectorIndexMode::Token => "Token",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Point" => Some(Self::Point),
+            "Token" => Some(Self::Token),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum Distance {
     UnknownDistance = 0,
     Cosine = 1,
     Euclid = 2,
     Dot = 3,
     Manhattan = 4,
+    /// Only for `Bit` datatype
+    Hamming = 5,
+    /// Only for `Bit` datatype
+    Jaccard = 6,
 }
 impl Distance {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1227,6 +1396,8 @@ impl Distance {
             Distance::Euclid => "Euclid",
             Distance::Dot => "Dot",
             Distance::Manhattan => "Manhattan",
+            Distance::Hamming => "Hamming",
+            Distance::Jaccard => "Jaccard",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1237,6 +1408,8 @@ impl Distance {
             "Euclid" => Some(Self::Euclid),
             "Dot" => Some(Self::Dot),
             "Manhattan" => Some(Self::Manhattan),
+            "Hamming" => Some(Self::Hamming),
+            "Jaccard" => Some(Self::Jaccard),
             _ => None,
         }
     }
@@ -1332,6 +1505,7 @@ impl PayloadSchemaType {
 pub enum QuantizationType {
     UnknownQuantization = 0,
     Int8 = 1,
+    Int4 = 2,
 }
 impl QuantizationType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1342,6 +1516,7 @@ impl QuantizationType {
         match self {
             QuantizationType::UnknownQuantization => "UnknownQuantization",
             QuantizationType::Int8 => "Int8",
+            QuantizationType::Int4 => "Int4",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1349,6 +1524,7 @@ impl QuantizationType {
         match value {
             "UnknownQuantization" => Some(Self::UnknownQuantization),
             "Int8" => Some(Self::Int8),
+            "Int4" => Some(Self::Int4),
             _ => None,
         }
     }
@@ -1392,6 +1568,105 @@ impl CompressionRatio {
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum BinaryQuantizationEncoding {
+    /// One bit per dimension
+    OneBit = 0,
+    /// Two bits per dimension
+    TwoBits = 1,
+    /// Three bits per pair of dimensions
+    OneAndHalfBits = 2,
+}
+impl BinaryQuantizationEncoding {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            BinaryQuantizationEncoding::OneBit => "OneBit",
+            BinaryQuantizationEncoding::TwoBits => "TwoBits",
+            BinaryQuantizationEncoding::OneAndHalfBits => "OneAndHalfBits",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "OneBit" => Some(Self::OneBit),
+            "TwoBits" => Some(Self::TwoBits),
+            "OneAndHalfBits" => Some(Self::OneAndHalfBits),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum BinaryQuantizationQueryEncoding {
+    /// Query is encoded the same way as the stored vectors
+    Binary = 0,
+    /// Query is quantized to 4 bits per value and scored asymmetrically
+    Scalar4Bits = 1,
+    /// Query is quantized to 8 bits per value and scored asymmetrically
+    Scalar8Bits = 2,
+}
+impl BinaryQuantizationQueryEncoding {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            BinaryQuantizationQueryEncoding::Binary => "Binary",
+            BinaryQuantizationQueryEncoding::Scalar4Bits => "Scalar4Bits",
+            BinaryQuantizationQueryEncoding::Scalar8Bits => "Scalar8Bits",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Binary" => Some(Self::Binary),
+            "Scalar4Bits" => Some(Self::Scalar4Bits),
+            "Scalar8Bits" => Some(Self::Scalar8Bits),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum QuantizationKind {
+    /// Scalar quantization
+    Scalar = 0,
+    /// Product quantization
+    Product = 1,
+    /// Binary quantization
+    Binary = 2,
+}
+impl QuantizationKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            QuantizationKind::Scalar => "Scalar",
+            QuantizationKind::Product => "Product",
+            QuantizationKind::Binary => "Binary",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Scalar" => Some(Self::Scalar),
+            "Product" => Some(Self::Product),
+            "Binary" => Some(Self::Binary),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum ShardingMethod {
     /// Auto-sharding based on record ids
     Auto = 0,
@@ -3834,6 +4109,16 @@ pub struct UpsertPoints {
     /// Option for custom sharding to specify used shard keys
     #[prost(message, optional, tag = "5")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
+    /// If specified, only points that match this filter will be updated, others will be inserted
+    #[prost(message, optional, tag = "6")]
+    pub update_filter: ::core::option::Option<Filter>,
+    /// How to handle points, which already exist or don't exist yet
+    #[prost(enumeration = "UpdateMode", optional, tag = "7")]
+    pub update_mode: ::core::option::Option<i32>,
+    /// Unique key of the request, repeated requests with the same key are applied only once
+    #[prost(string, optional, tag = "8")]
+    #[validate(length(min = 1, max = 255))]
+    pub idempotency_key: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -3856,6 +4141,35 @@ pub struct DeletePoints {
     /// Option for custom sharding to specify used shard keys
     #[prost(message, optional, tag = "5")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
+    /// If true, only report the points, which the operation would affect, without applying it
+    #[prost(bool, optional, tag = "6")]
+    pub dry_run: ::core::option::Option<bool>,
+    /// Reject the operation, if it would affect more points than this
+    #[prost(uint64, optional, tag = "7")]
+    #[validate(range(min = 1))]
+    pub max_affected_points: ::core::option::Option<u64>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RestorePoints {
+    /// name of the collection
+    #[prost(string, tag = "1")]
+    #[validate(length(min = 1, max = 255))]
+    pub collection_name: ::prost::alloc::string::String,
+    /// Wait until the changes have been applied?
+    #[prost(bool, optional, tag = "2")]
+    pub wait: ::core::option::Option<bool>,
+    /// Points to move out of trash
+    #[prost(message, optional, tag = "3")]
+    pub points: ::core::option::Option<PointsSelector>,
+    /// Write ordering guarantees
+    #[prost(message, optional, tag = "4")]
+    pub ordering: ::core::option::Option<WriteOrdering>,
+    /// Option for custom sharding to specify used shard keys
+    #[prost(message, optional, tag = "5")]
+    pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -3903,6 +4217,9 @@ pub struct UpdatePointVectors {
     /// Option for custom sharding to specify used shard keys
     #[prost(message, optional, tag = "5")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
+    /// If specified, only points that match this filter will be updated
+    #[prost(message, optional, tag = "6")]
+    pub update_filter: ::core::option::Option<Filter>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -3967,6 +4284,113 @@ pub struct SetPayloadPoints {
     /// Option for indicate property of payload
     #[prost(string, optional, tag = "8")]
     pub key: ::core::option::Option<::prost::alloc::string::String>,
+    /// If true, only report the points, which the operation would affect, without applying it
+    #[prost(bool, optional, tag = "9")]
+    pub dry_run: ::core::option::Option<bool>,
+    /// Reject the operation, if it would affect more points than this
+    #[prost(uint64, optional, tag = "10")]
+    #[validate(range(min = 1))]
+    pub max_affected_points: ::core::option::Option<u64>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadIncrement {
+    /// Path to the numeric value
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    /// Number to add, use negative number to decrement
+    #[prost(message, optional, tag = "2")]
+    pub by: ::core::option::Option<Value>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadAppend {
+    /// Path to the array
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    /// Values to append
+    #[prost(message, repeated, tag = "2")]
+    pub values: ::prost::alloc::vec::Vec<Value>,
+    /// If true, values already present in the array are not appended again
+    #[prost(bool, optional, tag = "3")]
+    pub unique: ::core::option::Option<bool>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadRemove {
+    /// Path to the array
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    /// Values to remove
+    #[prost(message, repeated, tag = "2")]
+    pub values: ::prost::alloc::vec::Vec<Value>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadMergePatch {
+    /// Path to the value to patch, if not specified - the whole payload is patched
+    #[prost(string, optional, tag = "1")]
+    pub key: ::core::option::Option<::prost::alloc::string::String>,
+    /// JSON merge patch, as defined in RFC 7396
+    #[prost(message, optional, tag = "2")]
+    pub patch: ::core::option::Option<Value>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadMutation {
+    #[prost(oneof = "payload_mutation::Mutation", tags = "1, 2, 3, 4")]
+    pub mutation: ::core::option::Option<payload_mutation::Mutation>,
+}
+/// Nested message and enum types in `PayloadMutation`.
+pub mod payload_mutation {
+    #[derive(serde::Serialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Mutation {
+        /// Add a number to the numeric value
+        #[prost(message, tag = "1")]
+        Increment(super::PayloadIncrement),
+        /// Append values to the array
+        #[prost(message, tag = "2")]
+        Append(super::PayloadAppend),
+        /// Remove values from the array
+        #[prost(message, tag = "3")]
+        Remove(super::PayloadRemove),
+        /// Apply JSON merge patch to the value
+        #[prost(message, tag = "4")]
+        MergePatch(super::PayloadMergePatch),
+    }
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MutatePayloadPoints {
+    /// name of the collection
+    #[prost(string, tag = "1")]
+    #[validate(length(min = 1, max = 255))]
+    pub collection_name: ::prost::alloc::string::String,
+    /// Wait until the changes have been applied?
+    #[prost(bool, optional, tag = "2")]
+    pub wait: ::core::option::Option<bool>,
+    /// Mutations, applied in order to the payload of each point
+    #[prost(message, repeated, tag = "3")]
+    #[validate(length(min = 1, message = "must specify mutations to apply"))]
+    pub mutations: ::prost::alloc::vec::Vec<PayloadMutation>,
+    /// Affected points
+    #[prost(message, optional, tag = "4")]
+    pub points_selector: ::core::option::Option<PointsSelector>,
+    /// Write ordering guarantees
+    #[prost(message, optional, tag = "5")]
+    pub ordering: ::core::option::Option<WriteOrdering>,
+    /// Option for custom sharding to specify used shard keys
+    #[prost(message, optional, tag = "6")]
+    pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -3992,6 +4416,13 @@ pub struct DeletePayloadPoints {
     /// Option for custom sharding to specify used shard keys
     #[prost(message, optional, tag = "7")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
+    /// If true, only report the points, which the operation would affect, without applying it
+    #[prost(bool, optional, tag = "8")]
+    pub dry_run: ::core::option::Option<bool>,
+    /// Reject the operation, if it would affect more points than this
+    #[prost(uint64, optional, tag = "9")]
+    #[validate(range(min = 1))]
+    pub max_affected_points: ::core::option::Option<u64>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -4014,6 +4445,13 @@ pub struct ClearPayloadPoints {
     /// Option for custom sharding to specify used shard keys
     #[prost(message, optional, tag = "5")]
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
+    /// If true, only report the points, which the operation would affect, without applying it
+    #[prost(bool, optional, tag = "6")]
+    pub dry_run: ::core::option::Option<bool>,
+    /// Reject the operation, if it would affect more points than this
+    #[prost(uint64, optional, tag = "7")]
+    #[validate(range(min = 1))]
+    pub max_affected_points: ::core::option::Option<u64>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -4182,6 +4620,11 @@ pub struct QuantizationSearchParams {
     #[prost(double, optional, tag = "3")]
     #[validate(custom = "crate::grpc::validate::validate_f64_range_min_1")]
     pub oversampling: ::core::option::Option<f64>,
+    ///
+    /// Kind of quantization to use for the search, if multiple quantizations are configured for the vector.
+    /// If not set, the primary quantization config is used. Search fails if the vector has no quantization of this kind.
+    #[prost(enumeration = "QuantizationKind", optional, tag = "4")]
+    pub quantization: ::core::option::Option<i32>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -4260,6 +4703,9 @@ pub struct SearchPoints {
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
     #[prost(message, optional, tag = "15")]
     pub sparse_indices: ::core::option::Option<SparseIndices>,
+    /// If true, explain how each segment executed the search. Ignored in batch requests
+    #[prost(bool, optional, tag = "16")]
+    pub explain: ::core::option::Option<bool>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -4391,6 +4837,26 @@ pub struct OrderBy {
     /// Start from this value
     #[prost(message, optional, tag = "3")]
     pub start_from: ::core::option::Option<StartFrom>,
+    /// Order by the distance from this point to the geo value of `key`
+    #[prost(message, optional, tag = "4")]
+    pub geo_distance_from: ::core::option::Option<GeoPoint>,
+    /// Additional keys to order by, used when the values of the previous keys are equal
+    #[prost(message, repeated, tag = "5")]
+    pub then_by: ::prost::alloc::vec::Vec<OrderByKey>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderByKey {
+    /// Payload key to order by
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    /// Ascending or descending order
+    #[prost(enumeration = "Direction", optional, tag = "2")]
+    pub direction: ::core::option::Option<i32>,
+    /// Order by the distance from this point to the geo value of `key`
+    #[prost(message, optional, tag = "3")]
+    pub geo_distance_from: ::core::option::Option<GeoPoint>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -4903,6 +5369,9 @@ pub struct QueryPoints {
     #[prost(uint64, optional, tag = "15")]
     #[validate(custom = "crate::grpc::validate::validate_u64_range_min_1")]
     pub timeout: ::core::option::Option<u64>,
+    /// If true, explain how each segment executed the searches of the query. Ignored in batch requests
+    #[prost(bool, optional, tag = "16")]
+    pub explain: ::core::option::Option<bool>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -4944,6 +5413,12 @@ pub mod points_update_operation {
         /// Option for custom sharding to specify used shard keys
         #[prost(message, optional, tag = "2")]
         pub shard_key_selector: ::core::option::Option<super::ShardKeySelector>,
+        /// If specified, only points that match this filter will be updated, others will be inserted
+        #[prost(message, optional, tag = "3")]
+        pub update_filter: ::core::option::Option<super::Filter>,
+        /// How to handle points, which already exist or don't exist yet
+        #[prost(enumeration = "super::UpdateMode", optional, tag = "4")]
+        pub update_mode: ::core::option::Option<i32>,
     }
     #[derive(serde::Serialize)]
     #[allow(clippy::derive_partial_eq_without_eq)]
@@ -5006,6 +5481,9 @@ pub mod points_update_operation {
         /// Option for custom sharding to specify used shard keys
         #[prost(message, optional, tag = "2")]
         pub shard_key_selector: ::core::option::Option<super::ShardKeySelector>,
+        /// If specified, only points that match this filter will be updated
+        #[prost(message, optional, tag = "3")]
+        pub update_filter: ::core::option::Option<super::Filter>,
     }
     #[derive(serde::Serialize)]
     #[allow(clippy::derive_partial_eq_without_eq)]
@@ -5088,6 +5566,13 @@ pub struct UpdateBatchPoints {
     /// Write ordering guarantees
     #[prost(message, optional, tag = "4")]
     pub ordering: ::core::option::Option<WriteOrdering>,
+    /// If true, operations of each shard are applied as a single unit and rolled back on failure
+    #[prost(bool, optional, tag = "5")]
+    pub atomic: ::core::option::Option<bool>,
+    /// Unique key of the request, repeated requests with the same key are applied only once. Only supported for atomic batches
+    #[prost(string, optional, tag = "6")]
+    #[validate(length(min = 1, max = 255))]
+    pub idempotency_key: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -5109,6 +5594,23 @@ pub struct UpdateResult {
     /// Operation status
     #[prost(enumeration = "UpdateStatus", tag = "2")]
     pub status: i32,
+    /// Number of points skipped due to the update mode or update filter
+    #[prost(uint64, optional, tag = "3")]
+    pub skipped: ::core::option::Option<u64>,
+    /// Points, which the operation would affect, only reported for dry-run requests
+    #[prost(message, optional, tag = "4")]
+    pub dry_run: ::core::option::Option<DryRunResult>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DryRunResult {
+    /// Exact number of points, which the operation would affect
+    #[prost(uint64, tag = "1")]
+    pub affected_points: u64,
+    /// IDs of some of the affected points
+    #[prost(message, repeated, tag = "2")]
+    pub sample: ::prost::alloc::vec::Vec<PointId>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -5154,6 +5656,9 @@ pub struct ScoredPoint {
     /// Order by value
     #[prost(message, optional, tag = "8")]
     pub order_value: ::core::option::Option<OrderValue>,
+    /// Values of the secondary order-by keys
+    #[prost(message, repeated, tag = "9")]
+    pub then_order_values: ::prost::alloc::vec::Vec<OrderValue>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -5204,12 +5709,99 @@ pub struct GroupsResult {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SearchResponse {
-    #[prost(message, repeated, tag = "1")]
-    pub result: ::prost::alloc::vec::Vec<ScoredPoint>,
-    /// Time spent to process
-    #[prost(double, tag = "2")]
+pub struct CardinalityExplanation {
+    /// Conditions which can select the matching points through payload indexes
+    #[prost(string, repeated, tag = "1")]
+    pub primary_clauses: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Minimal possible number of matching points
+    #[prost(uint64, tag = "2")]
+    pub min: u64,
+    /// Expected number of matching points
+    #[prost(uint64, tag = "3")]
+    pub exp: u64,
+    /// Largest possible number of matching points
+    #[prost(uint64, tag = "4")]
+    pub max: u64,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchTimings {
+    /// Seconds spent estimating the cardinality of the filter and choosing the strategy
+    #[prost(double, tag = "1")]
+    pub planning: f64,
+    /// Seconds spent selecting the points matching the filter with the payload index
+    #[prost(double, tag = "2")]
+    pub prefiltering: f64,
+    /// Seconds spent scoring vectors
+    #[prost(double, tag = "3")]
+    pub search: f64,
+    /// Seconds spent scoring the best quantized results again with the original vectors
+    #[prost(double, tag = "4")]
+    pub rescoring: f64,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SegmentSearchExplanation {
+    /// Id of the segment
+    #[prost(string, tag = "1")]
+    pub segment: ::prost::alloc::string::String,
+    /// Name of the searched vector
+    #[prost(string, tag = "2")]
+    pub vector_name: ::prost::alloc::string::String,
+    /// Number of search requests in the batch
+    #[prost(uint64, tag = "3")]
+    pub batch_size: u64,
+    /// Chosen search strategy
+    #[prost(enumeration = "SearchStrategy", tag = "4")]
+    pub strategy: i32,
+    /// Estimated number of points matching the filter, if the strategy was chosen based on it
+    #[prost(message, optional, tag = "5")]
+    pub cardinality: ::core::option::Option<CardinalityExplanation>,
+    /// Whether quantized vectors were used to score points
+    #[prost(bool, tag = "6")]
+    pub quantization: bool,
+    /// Whether quantized results were re-scored with the original vectors
+    #[prost(bool, tag = "7")]
+    pub rescoring: bool,
+    /// Time spent in each stage of the search
+    #[prost(message, optional, tag = "8")]
+    pub timings: ::core::option::Option<SearchTimings>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShardSearchExplanation {
+    /// Id of the shard
+    #[prost(uint32, tag = "1")]
+    pub shard_id: u32,
+    /// Explanations of all searched segments of the shard
+    #[prost(message, repeated, tag = "2")]
+    pub segments: ::prost::alloc::vec::Vec<SegmentSearchExplanation>,
+    /// Total time spent in each stage of the search, over all segments
+    #[prost(message, optional, tag = "3")]
+    pub timings: ::core::option::Option<SearchTimings>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchExplanation {
+    #[prost(message, repeated, tag = "1")]
+    pub shards: ::prost::alloc::vec::Vec<ShardSearchExplanation>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub result: ::prost::alloc::vec::Vec<ScoredPoint>,
+    /// Time spent to process
+    #[prost(double, tag = "2")]
     pub time: f64,
+    /// How the search was executed, if requested
+    #[prost(message, optional, tag = "3")]
+    pub explanation: ::core::option::Option<SearchExplanation>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -5220,6 +5812,9 @@ pub struct QueryResponse {
     /// Time spent to process
     #[prost(double, tag = "2")]
     pub time: f64,
+    /// How the searches of the query were executed, if requested
+    #[prost(message, optional, tag = "3")]
+    pub explanation: ::core::option::Option<SearchExplanation>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -5379,6 +5974,86 @@ pub struct UpdateBatchResponse {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeUpdatesRequest {
+    /// Name of the collection
+    #[prost(string, tag = "1")]
+    #[validate(length(min = 1, max = 255))]
+    pub collection_name: ::prost::alloc::string::String,
+    /// Operation number in the WAL of each shard to start streaming from, inclusive. Shards, which are not listed, are streamed from the oldest operation in their WAL
+    #[prost(map = "uint32, uint64", tag = "2")]
+    pub start_from: ::std::collections::HashMap<u32, u64>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateClock {
+    /// Id of the peer, which assigned the clock
+    #[prost(uint64, tag = "1")]
+    pub peer_id: u64,
+    /// Id of the clock on the peer
+    #[prost(uint32, tag = "2")]
+    pub clock_id: u32,
+    /// Tick of the clock
+    #[prost(uint64, tag = "3")]
+    pub clock_tick: u64,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeUpdatesResponse {
+    /// Id of the shard, the operation belongs to
+    #[prost(uint32, tag = "1")]
+    pub shard_id: u32,
+    /// Sequential number of the operation in the shard WAL, to resume streaming of the shard after it
+    #[prost(uint64, tag = "2")]
+    pub op_num: u64,
+    /// Clock of the operation, if it was replicated through the cluster
+    #[prost(message, optional, tag = "3")]
+    pub clock: ::core::option::Option<UpdateClock>,
+    #[prost(
+        oneof = "subscribe_updates_response::Update",
+        tags = "4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16"
+    )]
+    pub update: ::core::option::Option<subscribe_updates_response::Update>,
+}
+/// Nested message and enum types in `SubscribeUpdatesResponse`.
+pub mod subscribe_updates_response {
+    #[derive(serde::Serialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Update {
+        #[prost(message, tag = "4")]
+        UpsertPoints(super::UpsertPoints),
+        #[prost(message, tag = "5")]
+        DeletePoints(super::DeletePoints),
+        #[prost(message, tag = "6")]
+        UpdateVectors(super::UpdatePointVectors),
+        #[prost(message, tag = "7")]
+        DeleteVectors(super::DeletePointVectors),
+        #[prost(message, tag = "8")]
+        SetPayload(super::SetPayloadPoints),
+        #[prost(message, tag = "9")]
+        OverwritePayload(super::SetPayloadPoints),
+        #[prost(message, tag = "10")]
+        MutatePayload(super::MutatePayloadPoints),
+        #[prost(message, tag = "11")]
+        DeletePayload(super::DeletePayloadPoints),
+        #[prost(message, tag = "12")]
+        ClearPayload(super::ClearPayloadPoints),
+        #[prost(message, tag = "13")]
+        CreateFieldIndex(super::CreateFieldIndexCollection),
+        #[prost(message, tag = "14")]
+        DeleteFieldIndex(super::DeleteFieldIndexCollection),
+        #[prost(message, tag = "15")]
+        UpdateBatch(super::UpdateBatchPoints),
+        #[prost(message, tag = "16")]
+        RestorePoints(super::RestorePoints),
+    }
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Filter {
     /// At least one of those conditions should match
     #[prost(message, repeated, tag = "1")]
@@ -5495,6 +6170,9 @@ pub struct FieldCondition {
     /// Check if datetime is within a given range
     #[prost(message, optional, tag = "8")]
     pub datetime_range: ::core::option::Option<DatetimeRange>,
+    /// Check if datetime is within a range relative to the time of the request
+    #[prost(message, optional, tag = "9")]
+    pub relative_datetime_range: ::core::option::Option<RelativeDatetimeRange>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -5583,6 +6261,26 @@ pub struct DatetimeRange {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RelativeDatetimeRange {
+    /// Datetime in RFC 3339 format, or relative to the time of the request, e.g. `now-7d/d`
+    #[prost(string, optional, tag = "1")]
+    pub lt: ::core::option::Option<::prost::alloc::string::String>,
+    /// Datetime in RFC 3339 format, or relative to the time of the request, e.g. `now-7d/d`
+    #[prost(string, optional, tag = "2")]
+    pub gt: ::core::option::Option<::prost::alloc::string::String>,
+    /// Datetime in RFC 3339 format, or relative to the time of the request, e.g. `now-7d/d`
+    #[prost(string, optional, tag = "3")]
+    pub gte: ::core::option::Option<::prost::alloc::string::String>,
+    /// Datetime in RFC 3339 format, or relative to the time of the request, e.g. `now-7d/d`
+    #[prost(string, optional, tag = "4")]
+    pub lte: ::core::option::Option<::prost::alloc::string::String>,
+    /// IANA timezone for calendar units and rounding, e.g. `Europe/Berlin`. Default: UTC
+    #[prost(string, optional, tag = "5")]
+    pub timezone: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GeoBoundingBox {
     /// north-west corner
     #[prost(message, optional, tag = "1")]
@@ -5725,6 +6423,39 @@ impl WriteOrderingType {
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum UpdateMode {
+    /// Insert new points and overwrite existing ones, default
+    Upsert = 0,
+    /// Only insert new points, existing points are left intact
+    InsertOnly = 1,
+    /// Only overwrite existing points, new points are not inserted
+    UpdateOnly = 2,
+}
+impl UpdateMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            UpdateMode::Upsert => "Upsert",
+            UpdateMode::InsertOnly => "InsertOnly",
+            UpdateMode::UpdateOnly => "UpdateOnly",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Upsert" => Some(Self::Upsert),
+            "InsertOnly" => Some(Self::InsertOnly),
+            "UpdateOnly" => Some(Self::UpdateOnly),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum ReadConsistencyType {
     /// Send request to all nodes and return points which are present on all of them
     All = 0,
@@ -5885,6 +6616,51 @@ impl Fusion {
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum SearchStrategy {
+    /// All vectors of the segment are scored
+    FullScan = 0,
+    /// Points matching the filter are selected with the payload index, then scored one by one
+    PayloadIndex = 1,
+    /// HNSW graph search without filter
+    Hnsw = 2,
+    /// HNSW graph search, checking the filter while traversing the graph
+    HnswFiltered = 3,
+    /// Inverted index search of sparse vectors
+    Sparse = 4,
+    /// Segment is not searched, as it is too large to be searched without an index and `indexed_only` is requested
+    SkippedIndexedOnly = 5,
+}
+impl SearchStrategy {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SearchStrategy::FullScan => "FullScan",
+            SearchStrategy::PayloadIndex => "PayloadIndex",
+            SearchStrategy::Hnsw => "Hnsw",
+            SearchStrategy::HnswFiltered => "HnswFiltered",
+            SearchStrategy::Sparse => "Sparse",
+            SearchStrategy::SkippedIndexedOnly => "SkippedIndexedOnly",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "FullScan" => Some(Self::FullScan),
+            "PayloadIndex" => Some(Self::PayloadIndex),
+            "Hnsw" => Some(Self::Hnsw),
+            "HnswFiltered" => Some(Self::HnswFiltered),
+            "Sparse" => Some(Self::Sparse),
+            "SkippedIndexedOnly" => Some(Self::SkippedIndexedOnly),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum UpdateStatus {
     UnknownUpdateStatus = 0,
     /// Update is received, but not processed yet
@@ -5893,6 +6669,8 @@ pub enum UpdateStatus {
     Completed = 2,
     /// Internal: update is rejected due to an outdated clock
     ClockRejected = 3,
+    /// Update is not applied, only the points it would affect are reported
+    DryRun = 4,
 }
 impl UpdateStatus {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -5905,6 +6683,7 @@ impl UpdateStatus {
             UpdateStatus::Acknowledged => "Acknowledged",
             UpdateStatus::Completed => "Completed",
             UpdateStatus::ClockRejected => "ClockRejected",
+            UpdateStatus::DryRun => "DryRun",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -5914,6 +6693,7 @@ impl UpdateStatus {
             "Acknowledged" => Some(Self::Acknowledged),
             "Completed" => Some(Self::Completed),
             "ClockRejected" => Some(Self::ClockRejected),
+            "DryRun" => Some(Self::DryRun),
             _ => None,
         }
     }
@@ -6052,6 +6832,30 @@ pub mod points_client {
             self.inner.unary(req, path, codec).await
         }
         ///
+        /// Restore deleted points from trash
+        pub async fn restore(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RestorePoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::PointsOperationResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/qdrant.Points/Restore");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("qdrant.Points", "Restore"));
+            self.inner.unary(req, path, codec).await
+        }
+        ///
         /// Retrieve points
         pub async fn get(
             &mut self,
@@ -6178,6 +6982,33 @@ pub mod points_client {
             self.inner.unary(req, path, codec).await
         }
         ///
+        /// Atomically modify payload values of points, based on their stored values
+        pub async fn mutate_payload(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MutatePayloadPoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::PointsOperationResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.Points/MutatePayload",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "MutatePayload"));
+            self.inner.unary(req, path, codec).await
+        }
+        ///
         /// Delete specified key payload for points
         pub async fn delete_payload(
             &mut self,
@@ -6615,6 +7446,33 @@ pub mod points_client {
             req.extensions_mut().insert(GrpcMethod::new("qdrant.Points", "QueryBatch"));
             self.inner.unary(req, path, codec).await
         }
+        ///
+        /// Stream all update operations of a collection in order of each shard, starting from the given positions of the shard WALs. All shards of the collection must be local to the node. The stream keeps waiting for new updates, and fails if a requested position is already truncated from the WAL.
+        pub async fn subscribe_updates(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SubscribeUpdatesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::SubscribeUpdatesResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.Points/SubscribeUpdates",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "SubscribeUpdates"));
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -6643,6 +7501,15 @@ pub mod points_server {
             tonic::Status,
         >;
         ///
+        /// Restore deleted points from trash
+        async fn restore(
+            &self,
+            request: tonic::Request<super::RestorePoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::PointsOperationResponse>,
+            tonic::Status,
+        >;
+        ///
         /// Retrieve points
         async fn get(
             &self,
@@ -6685,6 +7552,15 @@ pub mod points_server {
             tonic::Status,
         >;
         ///
+        /// Atomically modify payload values of points, based on their stored values
+        async fn mutate_payload(
+            &self,
+            request: tonic::Request<super::MutatePayloadPoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::PointsOperationResponse>,
+            tonic::Status,
+        >;
+        ///
         /// Delete specified key payload for points
         async fn delete_payload(
             &self,
@@ -6839,6 +7715,24 @@ pub mod points_server {
             tonic::Response<super::QueryBatchResponse>,
             tonic::Status,
         >;
+        /// Server streaming response type for the SubscribeUpdates method.
+        type SubscribeUpdatesStream: futures_core::Stream<
+                Item = std::result::Result<
+                    super::SubscribeUpdatesResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        ///
+        /// Stream all update operations of a collection in order of each shard, starting from the given positions of the shard WALs. All shards of the collection must be local to the node. The stream keeps waiting for new updates, and fails if a requested position is already truncated from the WAL.
+        async fn subscribe_updates(
+            &self,
+            request: tonic::Request<super::SubscribeUpdatesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::SubscribeUpdatesStream>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct PointsServer<T: Points> {
@@ -7007,6 +7901,50 @@ pub mod points_server {
                     };
                     Box::pin(fut)
                 }
+                "/qdrant.Points/Restore" => {
+                    #[allow(non_camel_case_types)]
+                    struct RestoreSvc<T: Points>(pub Arc<T>);
+                    impl<T: Points> tonic::server::UnaryService<super::RestorePoints>
+                    for RestoreSvc<T> {
+                        type Response = super::PointsOperationResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RestorePoints>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Points>::restore(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RestoreSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/qdrant.Points/Get" => {
                     #[allow(non_camel_case_types)]
                     struct GetSvc<T: Points>(pub Arc<T>);
@@ -7231,13 +8169,11 @@ pub mod points_server {
                     };
                     Box::pin(fut)
                 }
-                "/qdrant.Points/DeletePayload" => {
+                "/qdrant.Points/MutatePayload" => {
                     #[allow(non_camel_case_types)]
-                    struct DeletePayloadSvc<T: Points>(pub Arc<T>);
-                    impl<
-                        T: Points,
-                    > tonic::server::UnaryService<super::DeletePayloadPoints>
-                    for DeletePayloadSvc<T> {
+                    struct MutatePayloadSvc<T: Points>(pub Arc<T>);
+                    impl<T: Points> tonic::server::UnaryService<super::MutatePayloadPoints>
+                    for MutatePayloadSvc<T> {
                         type Response = super::PointsOperationResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
@@ -7245,11 +8181,57 @@ pub mod points_server {
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DeletePayloadPoints>,
+                            request: tonic::Request<super::MutatePayloadPoints>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Points>::delete_payload(&inner, request).await
+                                <T as Points>::mutate_payload(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = MutatePayloadSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.Points/DeletePayload" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeletePayloadSvc<T: Points>(pub Arc<T>);
+                    impl<
+                        T: Points,
+                    > tonic::server::UnaryService<super::DeletePayloadPoints>
+                    for DeletePayloadSvc<T> {
+                        type Response = super::PointsOperationResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeletePayloadPoints>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Points>::delete_payload(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -7993,6 +8975,54 @@ pub mod points_server {
                     };
                     Box::pin(fut)
                 }
+                "/qdrant.Points/SubscribeUpdates" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubscribeUpdatesSvc<T: Points>(pub Arc<T>);
+                    impl<
+                        T: Points,
+                    > tonic::server::ServerStreamingService<
+                        super::SubscribeUpdatesRequest,
+                    > for SubscribeUpdatesSvc<T> {
+                        type Response = super::SubscribeUpdatesResponse;
+                        type ResponseStream = T::SubscribeUpdatesStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SubscribeUpdatesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Points>::subscribe_updates(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SubscribeUpdatesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -8095,6 +9125,25 @@ pub struct DeletePointsInternal {
     pub shard_id: ::core::option::Option<u32>,
     #[prost(message, optional, tag = "3")]
     pub clock_tag: ::core::option::Option<ClockTag>,
+    /// If set, points are moved to trash at this time, instead of being deleted
+    #[prost(message, optional, tag = "4")]
+    pub trashed_at: ::core::option::Option<::prost_wkt_types::Timestamp>,
+}
+#[derive(serde::Serialize)]
+#[derive(validator::Validate)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RestorePointsInternal {
+    #[prost(message, optional, tag = "1")]
+    #[validate]
+    pub restore_points: ::core::option::Option<RestorePoints>,
+    #[prost(uint32, optional, tag = "2")]
+    pub shard_id: ::core::option::Option<u32>,
+    #[prost(message, optional, tag = "3")]
+    pub clock_tag: ::core::option::Option<ClockTag>,
+    /// Only points moved to trash after this time are restored
+    #[prost(message, optional, tag = "4")]
+    pub trashed_after: ::core::option::Option<::prost_wkt_types::Timestamp>,
 }
 #[derive(serde::Serialize)]
 #[derive(validator::Validate)]
@@ -8139,6 +9188,19 @@ pub struct SetPayloadPointsInternal {
 #[derive(validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MutatePayloadPointsInternal {
+    #[prost(message, optional, tag = "1")]
+    #[validate]
+    pub mutate_payload_points: ::core::option::Option<MutatePayloadPoints>,
+    #[prost(uint32, optional, tag = "2")]
+    pub shard_id: ::core::option::Option<u32>,
+    #[prost(message, optional, tag = "3")]
+    pub clock_tag: ::core::option::Option<ClockTag>,
+}
+#[derive(serde::Serialize)]
+#[derive(validator::Validate)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeletePayloadPointsInternal {
     #[prost(message, optional, tag = "1")]
     #[validate]
@@ -8165,6 +9227,22 @@ pub struct ClearPayloadPointsInternal {
 #[derive(validator::Validate)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateBatchInternal {
+    #[prost(message, optional, tag = "1")]
+    #[validate]
+    pub update_batch_points: ::core::option::Option<UpdateBatchPoints>,
+    #[prost(uint32, optional, tag = "2")]
+    pub shard_id: ::core::option::Option<u32>,
+    #[prost(message, optional, tag = "3")]
+    pub clock_tag: ::core::option::Option<ClockTag>,
+    /// If set, deleted points of the batch are moved to trash at this time
+    #[prost(message, optional, tag = "4")]
+    pub trashed_at: ::core::option::Option<::prost_wkt_types::Timestamp>,
+}
+#[derive(serde::Serialize)]
+#[derive(validator::Validate)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateFieldIndexCollectionInternal {
     #[prost(message, optional, tag = "1")]
     #[validate]
@@ -8215,6 +9293,12 @@ pub struct UpdateResultInternal {
     pub status: i32,
     #[prost(message, optional, tag = "3")]
     pub clock_tag: ::core::option::Option<ClockTag>,
+    /// Number of points skipped due to the update mode or update filter
+    #[prost(uint64, optional, tag = "4")]
+    pub skipped: ::core::option::Option<u64>,
+    /// Points, which the operation would affect, only reported for dry-run requests
+    #[prost(message, optional, tag = "5")]
+    pub dry_run: ::core::option::Option<DryRunResult>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -8593,6 +9677,9 @@ pub struct QueryBatchPointsInternal {
     #[prost(uint64, optional, tag = "4")]
     #[validate(custom = "crate::grpc::validate::validate_u64_range_min_1")]
     pub timeout: ::core::option::Option<u64>,
+    /// If true, explain how each segment executed the searches
+    #[prost(bool, optional, tag = "5")]
+    pub explain: ::core::option::Option<bool>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -8617,6 +9704,9 @@ pub struct QueryBatchResponseInternal {
     /// Time spent to process
     #[prost(double, tag = "2")]
     pub time: f64,
+    /// How each segment executed the searches, if requested
+    #[prost(message, repeated, tag = "3")]
+    pub explanations: ::prost::alloc::vec::Vec<SegmentSearchExplanation>,
 }
 /// Generated client implementations.
 pub mod points_internal_client {
@@ -8778,6 +9868,31 @@ pub mod points_internal_client {
                 .insert(GrpcMethod::new("qdrant.PointsInternal", "Delete"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn restore(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RestorePointsInternal>,
+        ) -> std::result::Result<
+            tonic::Response<super::PointsOperationResponseInternal>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.PointsInternal/Restore",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.PointsInternal", "Restore"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn update_vectors(
             &mut self,
             request: impl tonic::IntoRequest<super::UpdateVectorsInternal>,
@@ -8878,6 +9993,31 @@ pub mod points_internal_client {
                 .insert(GrpcMethod::new("qdrant.PointsInternal", "OverwritePayload"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn mutate_payload(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MutatePayloadPointsInternal>,
+        ) -> std::result::Result<
+            tonic::Response<super::PointsOperationResponseInternal>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.PointsInternal/MutatePayload",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.PointsInternal", "MutatePayload"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn delete_payload(
             &mut self,
             request: impl tonic::IntoRequest<super::DeletePayloadPointsInternal>,
@@ -8978,6 +10118,31 @@ pub mod points_internal_client {
                 .insert(GrpcMethod::new("qdrant.PointsInternal", "DeleteFieldIndex"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn update_batch(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateBatchInternal>,
+        ) -> std::result::Result<
+            tonic::Response<super::PointsOperationResponseInternal>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.PointsInternal/UpdateBatch",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.PointsInternal", "UpdateBatch"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn core_search_batch(
             &mut self,
             request: impl tonic::IntoRequest<super::CoreSearchBatchPointsInternal>,
@@ -9148,6 +10313,13 @@ pub mod points_internal_server {
             tonic::Response<super::PointsOperationResponseInternal>,
             tonic::Status,
         >;
+        async fn restore(
+            &self,
+            request: tonic::Request<super::RestorePointsInternal>,
+        ) -> std::result::Result<
+            tonic::Response<super::PointsOperationResponseInternal>,
+            tonic::Status,
+        >;
         async fn update_vectors(
             &self,
             request: tonic::Request<super::UpdateVectorsInternal>,
@@ -9176,6 +10348,13 @@ pub mod points_internal_server {
             tonic::Response<super::PointsOperationResponseInternal>,
             tonic::Status,
         >;
+        async fn mutate_payload(
+            &self,
+            request: tonic::Request<super::MutatePayloadPointsInternal>,
+        ) -> std::result::Result<
+            tonic::Response<super::PointsOperationResponseInternal>,
+            tonic::Status,
+        >;
         async fn delete_payload(
             &self,
             request: tonic::Request<super::DeletePayloadPointsInternal>,
@@ -9204,6 +10383,13 @@ pub mod points_internal_server {
             tonic::Response<super::PointsOperationResponseInternal>,
             tonic::Status,
         >;
+        async fn update_batch(
+            &self,
+            request: tonic::Request<super::UpdateBatchInternal>,
+        ) -> std::result::Result<
+            tonic::Response<super::PointsOperationResponseInternal>,
+            tonic::Status,
+        >;
         async fn core_search_batch(
             &self,
             request: tonic::Request<super::CoreSearchBatchPointsInternal>,
@@ -9455,6 +10641,52 @@ pub mod points_internal_server {
                     };
                     Box::pin(fut)
                 }
+                "/qdrant.PointsInternal/Restore" => {
+                    #[allow(non_camel_case_types)]
+                    struct RestoreSvc<T: PointsInternal>(pub Arc<T>);
+                    impl<
+                        T: PointsInternal,
+                    > tonic::server::UnaryService<super::RestorePointsInternal>
+                    for RestoreSvc<T> {
+                        type Response = super::PointsOperationResponseInternal;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RestorePointsInternal>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PointsInternal>::restore(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RestoreSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/qdrant.PointsInternal/UpdateVectors" => {
                     #[allow(non_camel_case_types)]
                     struct UpdateVectorsSvc<T: PointsInternal>(pub Arc<T>);
@@ -9640,6 +10872,53 @@ pub mod points_internal_server {
                     };
                     Box::pin(fut)
                 }
+                "/qdrant.PointsInternal/MutatePayload" => {
+                    #[allow(non_camel_case_types)]
+                    struct MutatePayloadSvc<T: PointsInternal>(pub Arc<T>);
+                    impl<
+                        T: PointsInternal,
+                    > tonic::server::UnaryService<super::MutatePayloadPointsInternal>
+                    for MutatePayloadSvc<T> {
+                        type Response = super::PointsOperationResponseInternal;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MutatePayloadPointsInternal>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PointsInternal>::mutate_payload(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = MutatePayloadSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/qdrant.PointsInternal/DeletePayload" => {
                     #[allow(non_camel_case_types)]
                     struct DeletePayloadSvc<T: PointsInternal>(pub Arc<T>);
@@ -9832,6 +11111,56 @@ pub mod points_internal_server {
                     };
                     Box::pin(fut)
                 }
+                "/qdrant.PointsInternal/UpdateBatch" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateBatchSvc<T: PointsInternal>(pub Arc<T>);
+                    impl<
+                        T: PointsInternal,
+                    > tonic::server::UnaryService<
+                        super::UpdateBatchInternal,
+                    > for UpdateBatchSvc<T> {
+                        type Response = super::PointsOperationResponseInternal;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::UpdateBatchInternal,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PointsInternal>::update_batch(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UpdateBatchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/qdrant.PointsInternal/CoreSearchBatch" => {
                     #[allow(non_camel_case_types)]
                     struct CoreSearchBatchSvc<T: PointsInternal>(pub Arc<T>);
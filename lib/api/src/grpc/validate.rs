@@ -199,6 +199,7 @@ impl Validate for grpc::FieldCondition {
             r#match,
             range,
             datetime_range,
+            relative_datetime_range,
             geo_bounding_box,
             geo_radius,
             geo_polygon,
@@ -208,6 +209,7 @@ impl Validate for grpc::FieldCondition {
         let all_fields_none = r#match.is_none()
             && range.is_none()
             && datetime_range.is_none()
+            && relative_datetime_range.is_none()
             && geo_bounding_box.is_none()
             && geo_radius.is_none()
             && geo_polygon.is_none()
@@ -178,6 +178,7 @@ impl From<ScoredPoint> for segment::types::ScoredPoint {
             vector: value.vector.map(From::from),
             shard_key: value.shard_key,
             order_value: value.order_value.map(From::from),
+            then_order_values: Vec::new(),
         }
     }
 }
@@ -237,6 +238,8 @@ impl From<OrderByInterface> for OrderBy {
                 key,
                 direction: None,
                 start_from: None,
+                geo_distance_from: None,
+                then_by: Vec::new(),
             },
             OrderByInterface::Struct(order_by) => order_by,
         }
@@ -203,6 +203,9 @@ pub struct QueryRequest {
     #[serde(flatten)]
     pub internal: QueryRequestInternal,
     pub shard_key: Option<ShardKeySelector>,
+    /// If true, the response explains how each shard and segment executed the searches of the query.
+    /// Ignored in batch requests.
+    pub explain: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
@@ -7,6 +7,7 @@ use segment::types::QuantizationConfig;
 use semver::Version;
 
 use super::Collection;
+use crate::config::{PayloadValidationConfig, StrictModeConfig};
 use crate::operations::config_diff::*;
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
 use crate::operations::types::*;
@@ -23,7 +24,7 @@ lazy_static! {
 
 impl Collection {
     /// Updates collection params:
-    /// Saves new params on disk
+    /// Saves new params on disk and creates payload indexes, required by the new params
     ///
     /// After this, `recreate_optimizers_blocking` must be called to create new optimizers using
     /// the updated configuration.
@@ -36,6 +37,7 @@ impl Collection {
             config.params = params_diff.update(&config.params)?;
         }
         self.collection_config.read().await.save(&self.path)?;
+        self.create_required_payload_indexes().await?;
         Ok(())
     }
 
@@ -162,6 +164,42 @@ impl Collection {
         Ok(())
     }
 
+    /// Updates strict mode config:
+    /// Saves new params on disk
+    ///
+    /// Takes effect immediately for all subsequent requests, no optimizers need to be recreated.
+    pub async fn update_strict_mode_config(
+        &self,
+        strict_mode_diff: StrictModeConfig,
+    ) -> CollectionResult<()> {
+        {
+            let mut config = self.collection_config.write().await;
+            let strict_mode_config = match &config.strict_mode_config {
+                Some(existing) => strict_mode_diff.update(existing)?,
+                None => strict_mode_diff,
+            };
+            config.strict_mode_config = Some(strict_mode_config);
+        }
+        self.collection_config.read().await.save(&self.path)?;
+        Ok(())
+    }
+
+    /// Replaces payload validation rules:
+    /// Saves new params on disk
+    ///
+    /// Applies to subsequent updates only, stored payloads are not re-validated.
+    pub async fn update_payload_validation_config(
+        &self,
+        payload_validation_config: PayloadValidationConfig,
+    ) -> CollectionResult<()> {
+        {
+            let mut config = self.collection_config.write().await;
+            config.payload_validation_config = Some(payload_validation_config);
+        }
+        self.collection_config.read().await.save(&self.path)?;
+        Ok(())
+    }
+
     /// Handle replica changes
     ///
     /// add and remove replicas from replica set
@@ -0,0 +1,78 @@
+use chrono::Utc;
+
+use super::Collection;
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::types::{
+    CollectionError, CollectionResult, CountRequestInternal, DryRunResult, ScrollRequestInternal,
+};
+use crate::operations::CollectionUpdateOperations;
+
+/// Number of affected point IDs reported by a dry run
+const DRY_RUN_SAMPLE_SIZE: usize = 10;
+
+impl Collection {
+    /// Count points, which the update operation would affect, and sample some of their IDs.
+    ///
+    /// Points are counted with the same filter, which the operation applies in the shards,
+    /// so expired points, which are not removed yet, are counted as well.
+    /// The count is exact across all selected shards at the time of the request,
+    /// but concurrent updates may change the points, which the operation affects, before it is applied.
+    /// The sample only contains visible points.
+    pub async fn affected_points(
+        &self,
+        mut operation: CollectionUpdateOperations,
+        shard_selection: &ShardSelectorInternal,
+    ) -> CollectionResult<DryRunResult> {
+        let now = Utc::now();
+        for filter in operation.filters_mut() {
+            filter.resolve_relative_datetimes(now);
+        }
+
+        let mut operation = self.apply_trash(operation, now).await?;
+
+        // Points in trash are not visible to count and scroll
+        if operation.is_restore_operation() {
+            return Err(CollectionError::bad_input(
+                "Restore operations can't be checked for affected points",
+            ));
+        }
+
+        let Some(filter) = operation.filter_mut().cloned() else {
+            return Err(CollectionError::bad_input(
+                "Only operations selecting points by filter can be checked for affected points",
+            ));
+        };
+
+        let count = self
+            .count_impl(
+                CountRequestInternal {
+                    filter: Some(filter.clone()),
+                    exact: true,
+                },
+                None,
+                shard_selection,
+                false,
+            )
+            .await?;
+
+        let sample = self
+            .scroll_by(
+                ScrollRequestInternal {
+                    offset: None,
+                    limit: Some(DRY_RUN_SAMPLE_SIZE),
+                    filter: Some(filter),
+                    with_payload: Some(false.into()),
+                    with_vector: false.into(),
+                    order_by: None,
+                },
+                None,
+                shard_selection,
+            )
+            .await?;
+
+        Ok(DryRunResult {
+            affected_points: count.count,
+            sample: sample.points.into_iter().map(|point| point.id).collect(),
+        })
+    }
+}
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use cancel::{CancellationToken, DropGuard};
+use chrono::Utc;
+use segment::types::Filter;
+use tokio::runtime::Handle;
+use tokio::sync::RwLock;
+
+use super::Collection;
+use crate::config::CollectionConfig;
+use crate::operations::point_ops::{PointOperations, WriteOrdering};
+use crate::operations::types::{CollectionResult, CountRequestInternal};
+use crate::operations::CollectionUpdateOperations;
+use crate::shards::shard_holder::LockedShardHolder;
+
+/// Interval at which expired points are removed from the collection
+///
+/// Expired points are hidden from reads right away, this only affects how soon space is reclaimed.
+const EXPIRY_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Removal is submitted by the leader of this ordering, which is the same replica on all peers
+const EXPIRY_WRITE_ORDERING: WriteOrdering = WriteOrdering::Medium;
+
+/// Removes points, which are expired according to the collection config.
+///
+/// Removal is a regular delete operation, submitted by the update leader of each shard.
+/// So it goes through the WAL, gets a clock tag and is replicated like any other update,
+/// instead of being repeated on each replica independently.
+#[derive(Clone)]
+pub(super) struct ExpiryRemover {
+    shards_holder: Arc<LockedShardHolder>,
+    collection_config: Arc<RwLock<CollectionConfig>>,
+    updates_lock: Arc<RwLock<()>>,
+}
+
+impl ExpiryRemover {
+    pub(super) fn new(
+        shards_holder: Arc<LockedShardHolder>,
+        collection_config: Arc<RwLock<CollectionConfig>>,
+        updates_lock: Arc<RwLock<()>>,
+    ) -> Self {
+        Self {
+            shards_holder,
+            collection_config,
+            updates_lock,
+        }
+    }
+
+    /// Periodically remove expired points in background, until the returned guard is dropped
+    pub(super) fn spawn(self, runtime: &Handle) -> DropGuard {
+        let cancel = CancellationToken::new();
+        let cancelled = cancel.clone();
+
+        runtime.spawn(async move {
+            loop {
+                // Only wait is cancellable, removal is not cancel safe
+                tokio::select! {
+                    _ = cancelled.cancelled() => return,
+                    _ = tokio::time::sleep(EXPIRY_CHECK_INTERVAL) => {}
+                }
+
+                if let Err(err) = self.remove_expired_points().await {
+                    log::error!("Failed to remove expired points: {err}");
+                }
+            }
+        });
+
+        cancel.drop_guard()
+    }
+
+    async fn removed_points_filter(&self) -> Option<Filter> {
+        self.collection_config
+            .read()
+            .await
+            .params
+            .expired_condition(Utc::now())
+            .map(Filter::new_must)
+    }
+
+    async fn remove_expired_points(&self) -> CollectionResult<()> {
+        let Some(filter) = self.removed_points_filter().await else {
+            return Ok(());
+        };
+
+        let _update_lock = self.updates_lock.read().await;
+        let shards_holder = self.shards_holder.read().await;
+
+        for replica_set in shards_holder.all_shards() {
+            // Other replicas receive the removal from the leader
+            if !replica_set.is_leader_for_update(EXPIRY_WRITE_ORDERING) {
+                continue;
+            }
+
+            // Don't write no-op deletes into the WAL on every check
+            let count_request = Arc::new(CountRequestInternal {
+                filter: Some(filter.clone()),
+                exact: true,
+            });
+            let count = replica_set.count_local(count_request).await?;
+            if count.map_or(true, |count| count.count == 0) {
+                continue;
+            }
+
+            let operation = CollectionUpdateOperations::PointOperation(
+                PointOperations::DeletePointsByFilter(filter.clone()),
+            );
+            replica_set
+                .update_with_consistency(operation, true, EXPIRY_WRITE_ORDERING)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Collection {
+    /// Remove expired points right away, instead of waiting for the background task
+    pub async fn remove_expired_points(&self) -> CollectionResult<()> {
+        ExpiryRemover::new(
+            self.shards_holder.clone(),
+            self.collection_config.clone(),
+            self.updates_lock.clone(),
+        )
+        .remove_expired_points()
+        .await
+    }
+}
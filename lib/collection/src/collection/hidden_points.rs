@@ -0,0 +1,32 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use segment::types::{Condition, Filter, PointIdType};
+
+use super::Collection;
+
+impl Collection {
+    /// Filter, which hides points expired at the given time.
+    ///
+    /// Points in trash don't need a filter, segments don't return them at all.
+    /// Returns `None` if no expiry field is configured for the collection.
+    pub(super) async fn hidden_points_filter(&self, now: DateTime<Utc>) -> Option<Filter> {
+        self.collection_config
+            .read()
+            .await
+            .params
+            .expired_condition(now)
+            .map(Filter::new_must_not)
+    }
+
+    /// Filter, which selects the given points, unless they are expired.
+    ///
+    /// Points are retrieved with this filter, so that hidden points are skipped inside the shards.
+    /// Returns `None` if no expiry field is configured for the collection.
+    pub(super) async fn visible_points_filter(&self, ids: &[PointIdType]) -> Option<Filter> {
+        let hidden_points_filter = self.hidden_points_filter(Utc::now()).await?;
+
+        let has_id = ids.iter().copied().collect::<HashSet<_>>();
+        Some(Filter::new_must(Condition::HasId(has_id.into())).merge_owned(hidden_points_filter))
+    }
+}
@@ -1,4 +1,7 @@
 mod collection_ops;
+mod dry_run;
+mod expiry;
+mod hidden_points;
 pub mod payload_index_schema;
 mod point_ops;
 pub mod query;
@@ -8,6 +11,7 @@ mod shard_transfer;
 mod sharding_keys;
 mod snapshots;
 mod state_management;
+mod strict_mode;
 
 use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
@@ -15,6 +19,7 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 
+use cancel::DropGuard;
 use common::cpu::CpuBudget;
 use common::types::TelemetryDetail;
 use io::storage_version::StorageVersion;
@@ -23,6 +28,7 @@ use semver::Version;
 use tokio::runtime::Handle;
 use tokio::sync::{Mutex, RwLock, RwLockWriteGuard};
 
+use crate::collection::expiry::ExpiryRemover;
 use crate::collection::payload_index_schema::PayloadIndexSchema;
 use crate::collection_state::{ShardInfo, State};
 use crate::common::is_ready::IsReady;
@@ -46,6 +52,7 @@ use crate::shards::transfer::transfer_tasks_pool::{TaskResult, TransferTasksPool
 use crate::shards::transfer::{ShardTransfer, ShardTransferMethod};
 use crate::shards::{replica_set, CollectionId};
 use crate::telemetry::CollectionTelemetry;
+use crate::wal_delta::WalUpdatesReader;
 
 /// Collection's data is split into several shards.
 #[allow(dead_code)]
@@ -78,6 +85,8 @@ pub struct Collection {
     // Search runtime handle.
     search_runtime: Handle,
     optimizer_cpu_budget: CpuBudget,
+    // Stops background removal of expired points, once the collection is dropped.
+    _expiry_remover: DropGuard,
 }
 
 pub type RequestShardTransfer = Arc<dyn Fn(ShardTransfer) + Send + Sync>;
@@ -145,12 +154,21 @@ impl Collection {
         }
 
         let locked_shard_holder = Arc::new(LockedShardHolder::new(shard_holder));
+        let updates_lock = Arc::new(RwLock::new(()));
+        let update_runtime = update_runtime.unwrap_or_else(Handle::current);
+
+        let expiry_remover = ExpiryRemover::new(
+            locked_shard_holder.clone(),
+            shared_collection_config.clone(),
+            updates_lock.clone(),
+        )
+        .spawn(&update_runtime);
 
         // Once the config is persisted - the collection is considered to be successfully created.
         CollectionVersion::save(path)?;
         collection_config.save(path)?;
 
-        Ok(Self {
+        let collection = Self {
             id: name.clone(),
             shards_holder: locked_shard_holder,
             collection_config: shared_collection_config,
@@ -168,11 +186,16 @@ impl Collection {
             abort_shard_transfer_cb: abort_shard_transfer,
             init_time: start_time.elapsed(),
             is_initialized: Default::default(),
-            updates_lock: Default::default(),
-            update_runtime: update_runtime.unwrap_or_else(Handle::current),
+            updates_lock,
+            update_runtime,
             search_runtime: search_runtime.unwrap_or_else(Handle::current),
             optimizer_cpu_budget,
-        })
+            _expiry_remover: expiry_remover,
+        };
+
+        collection.create_required_payload_indexes().await?;
+
+        Ok(collection)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -258,6 +281,15 @@ impl Collection {
             .await;
 
         let locked_shard_holder = Arc::new(LockedShardHolder::new(shard_holder));
+        let updates_lock = Arc::new(RwLock::new(()));
+        let update_runtime = update_runtime.unwrap_or_else(Handle::current);
+
+        let expiry_remover = ExpiryRemover::new(
+            locked_shard_holder.clone(),
+            shared_collection_config.clone(),
+            updates_lock.clone(),
+        )
+        .spawn(&update_runtime);
 
         Self {
             id: collection_id.clone(),
@@ -277,10 +309,11 @@ impl Collection {
             abort_shard_transfer_cb: abort_shard_transfer,
             init_time: start_time.elapsed(),
             is_initialized: Default::default(),
-            updates_lock: Default::default(),
-            update_runtime: update_runtime.unwrap_or_else(Handle::current),
+            updates_lock,
+            update_runtime,
             search_runtime: search_runtime.unwrap_or_else(Handle::current),
             optimizer_cpu_budget,
+            _expiry_remover: expiry_remover,
         }
     }
 
@@ -486,6 +519,42 @@ impl Collection {
         replica_set.update_shard_cutoff_point(cutoff).await
     }
 
+    /// Readers of update operations in the WAL of every shard of the collection.
+    ///
+    /// Used to stream changes of the collection to subscribers,
+    /// so all shards of the collection must be local to this peer.
+    /// Fails if `requested_shards` contains a shard, which the collection doesn't have.
+    pub async fn wal_updates(
+        &self,
+        requested_shards: impl IntoIterator<Item = ShardId>,
+    ) -> CollectionResult<Vec<(ShardId, WalUpdatesReader)>> {
+        let shard_holder_read = self.shards_holder.read().await;
+
+        for shard_id in requested_shards {
+            if shard_holder_read.get_shard(&shard_id).is_none() {
+                return Err(CollectionError::not_found(format!(
+                    "Shard {shard_id} of collection {}",
+                    self.id,
+                )));
+            }
+        }
+
+        let mut readers = Vec::new();
+        for (&shard_id, replica_set) in shard_holder_read.get_shards() {
+            if !replica_set.has_local_shard().await {
+                return Err(CollectionError::pre_condition_failed(format!(
+                    "Shard {shard_id} of collection {} is not local to this peer, \
+                     updates can only be streamed from a peer with all shards",
+                    self.id,
+                )));
+            }
+
+            readers.push((shard_id, replica_set.wal_updates().await?));
+        }
+
+        Ok(readers)
+    }
+
     pub async fn state(&self) -> State {
         let shards_holder = self.shards_holder.read().await;
         let transfers = shards_holder.shard_transfers.read().clone();
@@ -68,6 +68,32 @@ impl Collection {
         self.update_all_local(create_index_operation, wait).await
     }
 
+    /// Create payload indexes, required by the collection params, unless they already exist.
+    ///
+    /// Called on every peer when the collection is created or its params are updated.
+    pub(crate) async fn create_required_payload_indexes(&self) -> CollectionResult<()> {
+        let required = self
+            .collection_config
+            .read()
+            .await
+            .params
+            .required_payload_indexes();
+
+        for (field_name, field_schema) in required {
+            let exists = self
+                .payload_index_schema
+                .read()
+                .schema
+                .get(&field_name)
+                .is_some_and(|schema| schema.name() == field_schema.name());
+            if !exists {
+                self.create_payload_index(field_name, field_schema).await?;
+            }
+        }
+
+        Ok(())
+    }
+
     pub async fn drop_payload_index(
         &self,
         field_name: JsonPath,
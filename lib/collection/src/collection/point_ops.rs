@@ -1,6 +1,10 @@
 use std::collections::HashSet;
+use std::future::Future;
+use std::slice;
 use std::sync::Arc;
+use std::time::Duration;
 
+use chrono::{DateTime, Utc};
 use futures::stream::FuturesUnordered;
 use futures::{future, StreamExt as _, TryFutureExt, TryStreamExt as _};
 use itertools::Itertools;
@@ -9,13 +13,19 @@ use segment::types::{Filter, ShardKey, WithPayload, WithPayloadInterface};
 use validator::Validate as _;
 
 use super::Collection;
+use crate::common::slow_request_guard::SlowRequestGuard;
+use crate::events::{SlowRequestDetails, SlowRequestType};
 use crate::operations::consistency_params::ReadConsistency;
+use crate::operations::payload_validation::payload_mutations;
 use crate::operations::point_ops::WriteOrdering;
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
 use crate::operations::types::*;
 use crate::operations::{CollectionUpdateOperations, OperationWithClockTag};
 use crate::shards::shard::ShardId;
 
+/// Number of points read at once, to check payload mutations against their stored payload
+const MUTATION_CHECK_PAGE_SIZE: usize = 100;
+
 impl Collection {
     /// Apply collection update operation to all local shards.
     /// Return None if there are no local shards
@@ -131,13 +141,39 @@ impl Collection {
     /// This method is cancel safe.
     pub async fn update_from_client(
         &self,
-        operation: CollectionUpdateOperations,
+        mut operation: CollectionUpdateOperations,
         wait: bool,
         ordering: WriteOrdering,
         shard_keys_selection: Option<ShardKey>,
     ) -> CollectionResult<UpdateResult> {
         operation.validate()?;
 
+        {
+            let config = self.collection_config.read().await;
+            if let Some(payload_validation) = &config.payload_validation_config {
+                payload_validation.check_operation(&operation)?;
+            }
+        }
+
+        // Resolve once, so that all shards and their WALs see the same filter
+        let now = Utc::now();
+        for filter in operation.filters_mut() {
+            filter.resolve_relative_datetimes(now);
+        }
+
+        self.check_payload_mutations(&operation, &shard_keys_selection.clone().into())
+            .await?;
+
+        let filters = operation.filters_mut();
+        if filters.is_empty() {
+            self.check_strict_mode_update(None).await?;
+        }
+        for filter in filters {
+            self.check_strict_mode_update(Some(&*filter)).await?;
+        }
+
+        let operation = self.apply_trash(operation, now).await?;
+
         let update_lock = self.updates_lock.clone().read_owned().await;
         let shard_holder = self.shards_holder.clone().read_owned().await;
 
@@ -145,7 +181,7 @@ impl Collection {
             let _update_lock = update_lock;
 
             let updates: FuturesUnordered<_> = shard_holder
-                .split_by_shard(operation, &shard_keys_selection)?
+                .split_update_by_shard(operation, &shard_keys_selection)?
                 .into_iter()
                 .map(move |(shard, operation)| {
                     shard.update_with_consistency(operation, wait, ordering)
@@ -187,8 +223,17 @@ impl Collection {
                 first_err
             }
         } else {
+            // Points are skipped independently in each shard
+            let skipped = results
+                .iter()
+                .filter_map(|result| result.as_ref().ok()?.skipped)
+                .reduce(|total, skipped| total + skipped);
+
             // At least one result is always present.
-            results.pop().unwrap()
+            results.pop().unwrap().map(|mut result| {
+                result.skipped = skipped;
+                result
+            })
         }
     }
 
@@ -205,16 +250,161 @@ impl Collection {
             .await
     }
 
+    /// Deleted points are moved to trash instead, if it is enabled for the collection
+    ///
+    /// Restore operations are limited to points, which are still within the retention period.
+    /// They are rejected, if trash is not enabled.
+    pub(super) async fn apply_trash(
+        &self,
+        mut operation: CollectionUpdateOperations,
+        now: DateTime<Utc>,
+    ) -> CollectionResult<CollectionUpdateOperations> {
+        let trash_retained_after = self
+            .collection_config
+            .read()
+            .await
+            .params
+            .trash_retained_after(now);
+
+        let Some(trash_retained_after) = trash_retained_after else {
+            if operation.is_restore_operation() {
+                return Err(CollectionError::bad_request(
+                    "Trash is not enabled for the collection",
+                ));
+            }
+            return Ok(operation);
+        };
+
+        operation.restore_trashed_after(trash_retained_after);
+        Ok(operation.into_trash(now))
+    }
+
+    /// Reject payload mutations, which would make the stored payload violate the validation rules.
+    ///
+    /// The result of a mutation depends on the stored payload, so it is read before the update.
+    /// Updates of the same points, applied concurrently with this check, are not taken into account.
+    async fn check_payload_mutations(
+        &self,
+        operation: &CollectionUpdateOperations,
+        shard_selection: &ShardSelectorInternal,
+    ) -> CollectionResult<()> {
+        let Some(payload_validation) = self
+            .collection_config
+            .read()
+            .await
+            .payload_validation_config
+            .clone()
+        else {
+            return Ok(());
+        };
+
+        let mut violations = Vec::new();
+
+        for mutation in payload_mutations(operation) {
+            let mut records = Vec::new();
+
+            if let Some(ids) = &mutation.points {
+                let request = PointRequestInternal {
+                    ids: ids.clone(),
+                    with_payload: Some(true.into()),
+                    with_vector: false.into(),
+                };
+                records.extend(
+                    self.retrieve(request, None, shard_selection)
+                        .await?
+                        .into_iter()
+                        .map(|record| (record.id, record.payload)),
+                );
+            } else if let Some(filter) = &mutation.filter {
+                let mut offset = None;
+                loop {
+                    let request = ScrollRequestInternal {
+                        offset,
+                        limit: Some(MUTATION_CHECK_PAGE_SIZE),
+                        filter: Some(filter.clone()),
+                        with_payload: Some(true.into()),
+                        with_vector: false.into(),
+                        order_by: None,
+                    };
+                    let page = self.scroll_by(request, None, shard_selection).await?;
+                    records.extend(
+                        page.points
+                            .into_iter()
+                            .map(|record| (record.id, record.payload)),
+                    );
+
+                    offset = page.next_page_offset;
+                    if offset.is_none() {
+                        break;
+                    }
+                }
+            }
+
+            for (id, payload) in records {
+                let payload = payload.unwrap_or_default();
+                for violation in
+                    payload_validation.mutation_violations(&mutation.mutations, &payload)
+                {
+                    violations.push(format!("point {id}: {violation}"));
+                }
+            }
+        }
+
+        if violations.is_empty() {
+            return Ok(());
+        }
+
+        Err(CollectionError::bad_input(format!(
+            "Payload validation failed: {}",
+            violations.join("; "),
+        )))
+    }
+
     pub async fn scroll_by(
+        &self,
+        request: ScrollRequestInternal,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: &ShardSelectorInternal,
+    ) -> CollectionResult<ScrollResult> {
+        let slow_request_guard = SlowRequestGuard::new(&self.id, SlowRequestType::Scroll, || {
+            vec![SlowRequestDetails {
+                filters: request.filter.iter().cloned().collect(),
+                limit: request
+                    .limit
+                    .unwrap_or_else(|| ScrollRequestInternal::default().limit.unwrap()),
+                hnsw_ef: None,
+            }]
+        });
+
+        SlowRequestGuard::track(
+            slow_request_guard,
+            self.do_scroll_by(request, read_consistency, shard_selection),
+        )
+        .await
+    }
+
+    async fn do_scroll_by(
         &self,
         mut request: ScrollRequestInternal,
         read_consistency: Option<ReadConsistency>,
         shard_selection: &ShardSelectorInternal,
     ) -> CollectionResult<ScrollResult> {
+        if let Some(filter) = &mut request.filter {
+            filter.resolve_relative_datetimes(Utc::now());
+        }
+
+        let timeout = self
+            .check_strict_mode(slice::from_ref(&request), None)
+            .await?;
+
         merge_filters(
             &mut request.filter,
             self.shards_holder.read().await.resharding_filter(),
         );
+        merge_filters(
+            &mut request.filter,
+            self.hidden_points_filter(Utc::now()).await,
+        );
 
         let default_request = ScrollRequestInternal::default();
 
@@ -235,6 +425,13 @@ impl Collection {
             return Err(CollectionError::bad_input("Cannot use an `offset` when using `order_by`. The alternative for paging is to use `order_by.start_from` and a filter to exclude the IDs that you've already seen for the `order_by.start_from` value".to_string()));
         };
 
+        if order_by
+            .as_ref()
+            .is_some_and(|order_by| !order_by.is_single_numeric_key())
+        {
+            return Err(CollectionError::bad_input("Ordering by `geo_distance_from` or by several keys with `then_by` is only supported in the query API".to_string()));
+        }
+
         if limit == 0 {
             return Err(CollectionError::BadRequest {
                 description: "Limit cannot be 0".to_string(),
@@ -276,7 +473,7 @@ impl Collection {
                     })
             });
 
-            future::try_join_all(scroll_futures).await?
+            with_timeout(timeout, "scroll", future::try_join_all(scroll_futures)).await?
         };
 
         let retrieved_iter = retrieved_points.into_iter();
@@ -345,15 +542,43 @@ impl Collection {
     }
 
     pub async fn count(
+        &self,
+        request: CountRequestInternal,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: &ShardSelectorInternal,
+    ) -> CollectionResult<CountResult> {
+        self.count_impl(request, read_consistency, shard_selection, true)
+            .await
+    }
+
+    /// Count points, optionally including points hidden by expiry.
+    ///
+    /// Hidden points are still selected by update operations, so they are counted for dry runs.
+    pub(super) async fn count_impl(
         &self,
         mut request: CountRequestInternal,
         read_consistency: Option<ReadConsistency>,
         shard_selection: &ShardSelectorInternal,
+        exclude_hidden: bool,
     ) -> CollectionResult<CountResult> {
+        if let Some(filter) = &mut request.filter {
+            filter.resolve_relative_datetimes(Utc::now());
+        }
+
+        let timeout = self
+            .check_strict_mode(slice::from_ref(&request), None)
+            .await?;
+
         merge_filters(
             &mut request.filter,
             self.shards_holder.read().await.resharding_filter(),
         );
+        if exclude_hidden {
+            merge_filters(
+                &mut request.filter,
+                self.hidden_points_filter(Utc::now()).await,
+            );
+        }
 
         let shards_holder = self.shards_holder.read().await;
         let shards = shards_holder.select_shards(shard_selection)?;
@@ -371,11 +596,14 @@ impl Collection {
             })
             .collect();
 
-        let mut count = 0;
-
-        while let Some(response) = requests.try_next().await? {
-            count += response.count;
-        }
+        let count = with_timeout(timeout, "count", async {
+            let mut count = 0;
+            while let Some(response) = requests.try_next().await? {
+                count += response.count;
+            }
+            Ok(count)
+        })
+        .await?;
 
         Ok(CountResult { count })
     }
@@ -393,6 +621,9 @@ impl Collection {
         let with_payload = WithPayload::from(with_payload_interface);
         let request = Arc::new(request);
 
+        // Expired points are hidden, if configured for the collection
+        let visible_points_filter = self.visible_points_filter(&request.ids).await;
+
         #[allow(unused_assignments)]
         let mut resharding_filter = None;
 
@@ -402,33 +633,58 @@ impl Collection {
             // Get resharding filter, while we hold the lock to shard holder
             resharding_filter = shard_holder.resharding_filter_impl();
 
+            let local_only = shard_selection.is_shard_id();
+
             let target_shards = shard_holder.select_shards(shard_selection)?;
             let retrieve_futures = target_shards.into_iter().map(|(shard, shard_key)| {
                 let shard_key = shard_key.cloned();
-                shard
-                    .retrieve(
-                        request.clone(),
-                        &with_payload,
-                        &request.with_vector,
-                        read_consistency,
-                        shard_selection.is_shard_id(),
-                    )
-                    .and_then(move |mut records| async move {
-                        if shard_key.is_none() {
-                            return Ok(records);
+                let request = request.clone();
+                let with_payload = &with_payload;
+                let visible_points_filter = visible_points_filter.as_ref();
+                async move {
+                    let mut records = match visible_points_filter {
+                        // Select points with the filter, to skip hidden points in the same request
+                        Some(filter) if !request.ids.is_empty() => {
+                            shard
+                                .scroll_by(
+                                    None,
+                                    request.ids.len(),
+                                    with_payload_interface,
+                                    &request.with_vector,
+                                    Some(filter),
+                                    read_consistency,
+                                    local_only,
+                                    None,
+                                )
+                                .await?
                         }
+                        _ => {
+                            shard
+                                .retrieve(
+                                    request.clone(),
+                                    with_payload,
+                                    &request.with_vector,
+                                    read_consistency,
+                                    local_only,
+                                )
+                                .await?
+                        }
+                    };
+
+                    if shard_key.is_some() {
                         for point in &mut records {
                             point.shard_key.clone_from(&shard_key);
                         }
-                        Ok(records)
-                    })
+                    }
+                    CollectionResult::Ok(records)
+                }
             });
 
             future::try_join_all(retrieve_futures).await?
         };
 
         let mut covered_point_ids = HashSet::new();
-        let points = all_shard_collection_results
+        let points: Vec<_> = all_shard_collection_results
             .into_iter()
             .flatten()
             // If resharding is in progress, and *read* hash-ring is committed, filter-out "resharded" points
@@ -452,3 +708,18 @@ fn merge_filters(filter: &mut Option<Filter>, resharding_filter: Option<Filter>)
         });
     }
 }
+
+/// Run the future under the given timeout, if any
+async fn with_timeout<T>(
+    timeout: Option<Duration>,
+    operation: &str,
+    future: impl Future<Output = CollectionResult<T>>,
+) -> CollectionResult<T> {
+    let Some(timeout) = timeout else {
+        return future.await;
+    };
+
+    tokio::time::timeout(timeout, future)
+        .await
+        .map_err(|_elapsed| CollectionError::timeout(timeout.as_secs() as usize, operation))?
+}
@@ -1,9 +1,13 @@
 use std::sync::Arc;
 use std::time::Duration;
 
-use futures::{future, TryFutureExt};
+use chrono::Utc;
+use futures::future;
 use itertools::{Either, Itertools};
 use segment::common::reciprocal_rank_fusion::rrf_scoring;
+use segment::data_types::search_explain::{
+    SearchExplanation, SegmentSearchExplanation, ShardSearchExplanation,
+};
 use segment::types::{Order, ScoredPoint};
 use segment::utils::scored_point_ties::ScoredPointTies;
 use tokio::time::Instant;
@@ -11,7 +15,9 @@ use tokio::time::Instant;
 use super::Collection;
 use crate::common::batching::batch_requests;
 use crate::common::fetch_vectors::resolve_referenced_vectors_batch;
+use crate::common::slow_request_guard::SlowRequestGuard;
 use crate::common::transpose_iterator::transposed_iter;
+use crate::events::{SlowRequestDetails, SlowRequestType};
 use crate::operations::consistency_params::ReadConsistency;
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
 use crate::operations::types::{CollectionError, CollectionResult};
@@ -28,59 +34,136 @@ struct IntermediateQueryInfo<'a> {
 
 impl Collection {
     /// Returns a shape of [shard_id, batch_id, intermediate_response, points]
+    ///
+    /// If `explain` is set, also returns how each shard executed the searches.
     async fn batch_query_shards_concurrently(
         &self,
         batch_request: Arc<Vec<ShardQueryRequest>>,
         read_consistency: Option<ReadConsistency>,
         shard_selection: &ShardSelectorInternal,
         timeout: Option<Duration>,
-    ) -> CollectionResult<Vec<Vec<ShardQueryResponse>>> {
+        explain: bool,
+    ) -> CollectionResult<(Vec<Vec<ShardQueryResponse>>, Vec<ShardSearchExplanation>)> {
         // query all shards concurrently
         let shard_holder = self.shards_holder.read().await;
         let target_shards = shard_holder.select_shards(shard_selection)?;
         let all_searches = target_shards.iter().map(|(shard, shard_key)| {
             let shard_key = shard_key.cloned();
-            shard
-                .query_batch(
-                    Arc::clone(&batch_request),
-                    read_consistency,
-                    shard_selection.is_shard_id(),
-                    timeout,
-                )
-                .and_then(move |mut shard_responses| async move {
-                    if shard_key.is_none() {
-                        return Ok(shard_responses);
-                    }
+            let batch_request = Arc::clone(&batch_request);
+            async move {
+                let (mut shard_responses, explanation) = if explain {
+                    let (shard_responses, segments) = shard
+                        .explain_query_batch(batch_request, shard_selection.is_shard_id(), timeout)
+                        .await?;
+                    let explanation = ShardSearchExplanation::new(shard.shard_id, segments);
+                    (shard_responses, Some(explanation))
+                } else {
+                    let shard_responses = shard
+                        .query_batch(
+                            batch_request,
+                            read_consistency,
+                            shard_selection.is_shard_id(),
+                            timeout,
+                        )
+                        .await?;
+                    (shard_responses, None)
+                };
+
+                if shard_key.is_some() {
                     shard_responses
                         .iter_mut()
                         .flatten()
                         .flatten()
                         .for_each(|point| point.shard_key.clone_from(&shard_key));
+                }
 
-                    Ok(shard_responses)
-                })
+                Ok::<_, CollectionError>((shard_responses, explanation))
+            }
         });
-        future::try_join_all(all_searches).await
+
+        let (all_responses, explanations): (Vec<_>, Vec<_>) = future::try_join_all(all_searches)
+            .await?
+            .into_iter()
+            .unzip();
+
+        Ok((all_responses, explanations.into_iter().flatten().collect()))
     }
 
     /// This function is used to query the collection. It will return a list of scored points.
+    ///
+    /// If `explain` is set, also returns how each shard executed the searches.
     async fn do_query_batch(
         &self,
         requests_batch: Vec<ShardQueryRequest>,
         read_consistency: Option<ReadConsistency>,
         shard_selection: ShardSelectorInternal,
         timeout: Option<Duration>,
-    ) -> CollectionResult<Vec<Vec<ScoredPoint>>> {
+        explain: bool,
+    ) -> CollectionResult<(Vec<Vec<ScoredPoint>>, Vec<ShardSearchExplanation>)> {
+        let slow_request_guard = SlowRequestGuard::new(&self.id, SlowRequestType::Query, || {
+            requests_batch
+                .iter()
+                .map(|request| SlowRequestDetails {
+                    filters: request
+                        .filter_refs()
+                        .into_iter()
+                        .flatten()
+                        .cloned()
+                        .collect(),
+                    limit: request.limit,
+                    hnsw_ef: request.params.and_then(|params| params.hnsw_ef),
+                })
+                .collect()
+        });
+
+        SlowRequestGuard::track(
+            slow_request_guard,
+            self.query_shards_batch(
+                requests_batch,
+                read_consistency,
+                shard_selection,
+                timeout,
+                explain,
+            ),
+        )
+        .await
+    }
+
+    async fn query_shards_batch(
+        &self,
+        mut requests_batch: Vec<ShardQueryRequest>,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: ShardSelectorInternal,
+        timeout: Option<Duration>,
+        explain: bool,
+    ) -> CollectionResult<(Vec<Vec<ScoredPoint>>, Vec<ShardSearchExplanation>)> {
         let instant = Instant::now();
 
+        let now = Utc::now();
+        for request in &mut requests_batch {
+            request.resolve_relative_datetimes(now);
+        }
+
+        let timeout = self.check_strict_mode(&requests_batch, timeout).await?;
+
+        if let Some(hidden_points_filter) = self.hidden_points_filter(now).await {
+            for request in &mut requests_batch {
+                request.filter = Some(match request.filter.take() {
+                    Some(filter) => filter.merge_owned(hidden_points_filter.clone()),
+                    None => hidden_points_filter.clone(),
+                });
+            }
+        }
+
         let requests_batch = Arc::new(requests_batch);
 
-        let all_shards_results = self
+        let (all_shards_results, explanations) = self
             .batch_query_shards_concurrently(
                 requests_batch.clone(),
                 read_consistency,
                 &shard_selection,
                 timeout,
+                explain,
             )
             .await?;
 
@@ -120,7 +203,7 @@ impl Collection {
             });
         let results = future::try_join_all(results_f).await?;
 
-        Ok(results)
+        Ok((results, explanations))
     }
 
     /// To be called on the user-responding instance. Resolves ids into vectors, and merges the results from local and remote shards.
@@ -165,6 +248,7 @@ impl Collection {
                     read_consistency,
                     shard_selection,
                     timeout,
+                    false,
                 ));
 
                 Ok(())
@@ -174,32 +258,93 @@ impl Collection {
         let results = future::try_join_all(futures)
             .await?
             .into_iter()
-            .flatten()
+            .flat_map(|(results, _)| results)
             .collect();
 
         Ok(results)
     }
 
+    /// Same as `query_batch` for a single request, but also explains how each shard and
+    /// segment executed the searches of the query.
+    ///
+    /// Each shard is queried on a single replica, as explanations of replicas can't be merged.
+    pub async fn explain_query(
+        &self,
+        request: CollectionQueryRequest,
+        shard_selection: ShardSelectorInternal,
+        read_consistency: Option<ReadConsistency>,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<(Vec<ScoredPoint>, SearchExplanation)> {
+        let requests_batch = vec![(request, shard_selection)];
+
+        // Turn ids into vectors, if necessary.
+        // Query requests only reference points of this collection, see `RetrieveRequest::get_lookup_collection`,
+        // so no other collection is ever requested.
+        let ids_to_vectors = resolve_referenced_vectors_batch(
+            &requests_batch,
+            self,
+            |_| async { None },
+            read_consistency,
+        )
+        .await?;
+
+        let Some((request, shard_selection)) = requests_batch.into_iter().next() else {
+            unreachable!("batch contains one request");
+        };
+        let shard_request = request.try_into_shard_request(&ids_to_vectors)?;
+
+        self.explain_shard_query(shard_request, read_consistency, shard_selection, timeout)
+            .await
+    }
+
+    pub(super) async fn explain_shard_query(
+        &self,
+        request: ShardQueryRequest,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: ShardSelectorInternal,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<(Vec<ScoredPoint>, SearchExplanation)> {
+        let (mut results, shards) = self
+            .do_query_batch(
+                vec![request],
+                read_consistency,
+                shard_selection,
+                timeout,
+                true,
+            )
+            .await?;
+
+        let result = results
+            .pop()
+            .ok_or_else(|| CollectionError::service_error("Expected one response for one query"))?;
+
+        Ok((result, SearchExplanation { shards }))
+    }
+
     /// To be called on the remote instance. Only used for the internal service.
     ///
     /// If the root query is a Fusion, the returned results correspond to each the prefetches.
     /// Otherwise, it will be a list with a single list of scored points.
+    ///
+    /// If `explain` is set, also returns how each segment executed the searches.
     pub async fn query_batch_internal(
         &self,
         requests: Vec<ShardQueryRequest>,
         shard_selection: &ShardSelectorInternal,
         timeout: Option<Duration>,
-    ) -> CollectionResult<Vec<ShardQueryResponse>> {
+        explain: bool,
+    ) -> CollectionResult<(Vec<ShardQueryResponse>, Vec<SegmentSearchExplanation>)> {
         let requests_arc = Arc::new(requests);
 
         // Results from all shards
         // Shape: [num_shards, batch_size, num_intermediate_results, num_points]
-        let all_shards_results = self
+        let (all_shards_results, explanations) = self
             .batch_query_shards_concurrently(
                 Arc::clone(&requests_arc),
                 None,
                 shard_selection,
                 timeout,
+                explain,
             )
             .await?;
 
@@ -211,7 +356,13 @@ impl Collection {
                     .await
             });
         let merged = futures::future::try_join_all(merged_f).await?;
-        Ok(merged)
+
+        let segments = explanations
+            .into_iter()
+            .flat_map(|explanation| explanation.segments)
+            .collect();
+
+        Ok((merged, segments))
     }
 
     /// Merges the results in each shard for each intermediate query.
@@ -243,6 +394,20 @@ impl Collection {
             query_infos.into_iter().zip(all_shards_result_by_transposed)
         {
             // `shards_results` shape: [num_shards, num_scored_points]
+            if let Some(ScoringQuery::OrderBy(order_by)) = query_info.scoring_query {
+                // Order-by values of several keys may have different directions,
+                // so they are compared key by key instead of through the score order
+                let intermediate_result = shards_results
+                    .into_iter()
+                    .kmerge_by(|a, b| order_by.cmp_scored_points(a, b).is_lt())
+                    .dedup()
+                    .take(query_info.take)
+                    .collect();
+
+                results.push(intermediate_result);
+                continue;
+            }
+
             let order = ScoringQuery::order(query_info.scoring_query, &collection_params)?;
 
             // Equivalent to:
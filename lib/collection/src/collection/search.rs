@@ -3,8 +3,10 @@ use std::mem;
 use std::sync::Arc;
 use std::time::Duration;
 
+use chrono::Utc;
 use futures::{future, TryFutureExt};
 use itertools::{Either, Itertools};
+use segment::data_types::search_explain::SearchExplanation;
 use segment::data_types::vectors::VectorStructInternal;
 use segment::types::{
     ExtendedPointId, Filter, Order, ScoredPoint, WithPayloadInterface, WithVector,
@@ -12,7 +14,8 @@ use segment::types::{
 use tokio::time::Instant;
 
 use super::Collection;
-use crate::events::SlowQueryEvent;
+use crate::common::slow_request_guard::SlowRequestGuard;
+use crate::events::{SlowQueryEvent, SlowRequestDetails, SlowRequestType};
 use crate::operations::consistency_params::ReadConsistency;
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
 use crate::operations::types::*;
@@ -38,6 +41,24 @@ impl Collection {
         Ok(results.into_iter().next().unwrap())
     }
 
+    /// Same as `search`, but also explains how each shard and segment executed the search
+    ///
+    /// Each shard is searched on a single replica, as explanations of replicas can't be merged.
+    pub async fn explain_search(
+        &self,
+        request: CoreSearchRequest,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: ShardSelectorInternal,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<(Vec<ScoredPoint>, SearchExplanation)> {
+        if request.limit == 0 {
+            return Ok((vec![], SearchExplanation::default()));
+        }
+
+        self.explain_shard_query(request.into(), read_consistency, shard_selection, timeout)
+            .await
+    }
+
     pub async fn core_search_batch(
         &self,
         request: CoreSearchRequestBatch,
@@ -124,12 +145,49 @@ impl Collection {
     }
 
     async fn do_core_search_batch(
+        &self,
+        request: CoreSearchRequestBatch,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: &ShardSelectorInternal,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<Vec<Vec<ScoredPoint>>> {
+        let slow_request_guard = SlowRequestGuard::new(&self.id, SlowRequestType::Search, || {
+            request
+                .searches
+                .iter()
+                .map(|req| SlowRequestDetails {
+                    filters: req.filter.iter().cloned().collect(),
+                    limit: req.limit,
+                    hnsw_ef: req.params.and_then(|params| params.hnsw_ef),
+                })
+                .collect()
+        });
+
+        SlowRequestGuard::track(
+            slow_request_guard,
+            self.search_shards_batch(request, read_consistency, shard_selection, timeout),
+        )
+        .await
+    }
+
+    async fn search_shards_batch(
         &self,
         mut request: CoreSearchRequestBatch,
         read_consistency: Option<ReadConsistency>,
         shard_selection: &ShardSelectorInternal,
         timeout: Option<Duration>,
     ) -> CollectionResult<Vec<Vec<ScoredPoint>>> {
+        let now = Utc::now();
+        for filter in request
+            .searches
+            .iter_mut()
+            .filter_map(|search| search.filter.as_mut())
+        {
+            filter.resolve_relative_datetimes(now);
+        }
+
+        let timeout = self.check_strict_mode(&request.searches, timeout).await?;
+
         if let Some(resharding_filter) = self.shards_holder.read().await.resharding_filter() {
             for search in &mut request.searches {
                 match &mut search.filter {
@@ -144,6 +202,15 @@ impl Collection {
             }
         }
 
+        if let Some(hidden_points_filter) = self.hidden_points_filter(now).await {
+            for search in &mut request.searches {
+                search.filter = Some(match search.filter.take() {
+                    Some(filter) => filter.merge_owned(hidden_points_filter.clone()),
+                    None => hidden_points_filter.clone(),
+                });
+            }
+        }
+
         let request = Arc::new(request);
 
         let instant = Instant::now();
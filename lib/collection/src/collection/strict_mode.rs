@@ -0,0 +1,60 @@
+use std::time::Duration;
+
+use segment::types::Filter;
+
+use super::Collection;
+use crate::config::StrictModeConfig;
+use crate::operations::types::CollectionResult;
+use crate::operations::verification::{self, StrictModeVerification};
+
+impl Collection {
+    /// Strict mode config of the collection, if strict mode is enabled
+    async fn enabled_strict_mode_config(&self) -> Option<StrictModeConfig> {
+        self.collection_config
+            .read()
+            .await
+            .strict_mode_config
+            .as_ref()
+            .filter(|config| config.is_enabled())
+            .cloned()
+    }
+
+    /// Reject read requests which are not allowed by the strict mode of the collection.
+    ///
+    /// Returns the timeout the requests should run with.
+    pub(super) async fn check_strict_mode(
+        &self,
+        requests: &[impl StrictModeVerification],
+        timeout: Option<Duration>,
+    ) -> CollectionResult<Option<Duration>> {
+        let Some(config) = self.enabled_strict_mode_config().await else {
+            return Ok(timeout);
+        };
+
+        let payload_index_schema = self.payload_index_schema.read();
+        for request in requests {
+            request.check_strict_mode(&config, &payload_index_schema.schema)?;
+        }
+
+        verification::check_timeout(timeout, &config)
+    }
+
+    /// Reject filtered updates which are not allowed by the strict mode of the collection
+    pub(super) async fn check_strict_mode_update(
+        &self,
+        filter: Option<&Filter>,
+    ) -> CollectionResult<()> {
+        let Some(config) = self.enabled_strict_mode_config().await else {
+            return Ok(());
+        };
+
+        if let Some(filter) = filter {
+            if !config.unindexed_filtering_update.unwrap_or(true) {
+                let payload_index_schema = self.payload_index_schema.read();
+                verification::check_filter_indexed(filter, &payload_index_schema.schema)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -3,8 +3,12 @@ use segment::types::SeqNumberType;
 
 use crate::collection_manager::holders::segment_holder::SegmentHolder;
 use crate::collection_manager::segments_updater::*;
-use crate::operations::types::CollectionResult;
-use crate::operations::CollectionUpdateOperations;
+use crate::operations::operation_effect::{EstimateOperationEffectArea, OperationEffectArea};
+use crate::operations::types::{CollectionError, CollectionResult};
+use crate::operations::{AtomicBatchOp, CollectionUpdateOperations};
+
+/// Max number of points, whose state is saved to roll back an atomic batch
+const MAX_ATOMIC_BATCH_BACKUP_POINTS: usize = 10_000;
 
 /// Implementation of the update operation
 #[derive(Default)]
@@ -50,6 +54,25 @@ impl CollectionUpdater {
         // Allow only one update at a time, ensure no data races between segments.
         // let _lock = self.update_lock.lock().unwrap();
         let operation_result = match operation {
+            // Hold the write lock for the whole batch, so that reads never observe partially
+            // applied or rolled back batch
+            CollectionUpdateOperations::AtomicBatch(batch) => {
+                Self::process_atomic_batch(&mut segments.write(), op_num, batch)
+            }
+            operation => Self::process_operation(&segments.read(), op_num, operation),
+        };
+
+        CollectionUpdater::handle_update_result(segments, op_num, &operation_result);
+
+        operation_result
+    }
+
+    fn process_operation(
+        segments: &SegmentHolder,
+        op_num: SeqNumberType,
+        operation: CollectionUpdateOperations,
+    ) -> CollectionResult<usize> {
+        match operation {
             CollectionUpdateOperations::PointOperation(point_operation) => {
                 process_point_operation(segments, op_num, point_operation)
             }
@@ -62,11 +85,89 @@ impl CollectionUpdater {
             CollectionUpdateOperations::FieldIndexOperation(index_operation) => {
                 process_field_index_operation(segments, op_num, &index_operation)
             }
-        };
+            CollectionUpdateOperations::AtomicBatch(batch) => {
+                Self::apply_atomic_batch(segments, op_num, batch)
+            }
+        }
+    }
 
-        CollectionUpdater::handle_update_result(segments, op_num, &operation_result);
+    /// Apply all operations of the batch under the same operation number.
+    ///
+    /// All operations of the batch share its operation number, so points written by one
+    /// operation of the batch must still be writable by the following ones, and by the rollback.
+    /// This is only allowed if the batch is applied for the first time. On WAL replay of an
+    /// already applied batch, points with its operation number are skipped as usual.
+    fn process_atomic_batch(
+        segments: &mut SegmentHolder,
+        op_num: SeqNumberType,
+        batch: AtomicBatchOp,
+    ) -> CollectionResult<usize> {
+        let is_first_apply = segments
+            .iter()
+            .all(|(_, segment)| segment.get().read().version() < op_num);
+        if is_first_apply {
+            segments.atomic_batch_op_num = Some(op_num);
+        }
 
-        operation_result
+        let result = Self::apply_atomic_batch(segments, op_num, batch);
+
+        segments.atomic_batch_op_num = None;
+        result
+    }
+
+    /// Before each operation of the batch, the state of the points it affects is saved.
+    /// If an operation fails, all saved points are restored and the error is returned.
+    ///
+    /// If the rollback fails as well, both errors are returned as a transient error,
+    /// so that the operation is marked as failed and the shard reports it.
+    fn apply_atomic_batch(
+        segments: &SegmentHolder,
+        op_num: SeqNumberType,
+        batch: AtomicBatchOp,
+    ) -> CollectionResult<usize> {
+        let mut backup = PointsBackup::new(MAX_ATOMIC_BATCH_BACKUP_POINTS);
+        let mut result = 0;
+
+        for operation in batch.operations {
+            let operation_result = Self::backup_affected_points(segments, &mut backup, &operation)
+                .and_then(|()| Self::process_operation(segments, op_num, operation));
+
+            match operation_result {
+                Ok(res) => result += res,
+                Err(err) => {
+                    log::debug!("Rolling back atomic batch {op_num}: {err}");
+
+                    if let Err(restore_err) = backup.restore(segments, op_num) {
+                        log::error!(
+                            "Failed to roll back atomic batch {op_num}, shard is left in an inconsistent state: {restore_err}",
+                        );
+
+                        return Err(CollectionError::service_error(format!(
+                            "Atomic batch failed: {err}, and its rollback failed: {restore_err}",
+                        )));
+                    }
+
+                    return Err(err);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn backup_affected_points(
+        segments: &SegmentHolder,
+        backup: &mut PointsBackup,
+        operation: &CollectionUpdateOperations,
+    ) -> CollectionResult<()> {
+        match operation.estimate_effect_area() {
+            OperationEffectArea::Empty => Ok(()),
+            OperationEffectArea::Points(points) => backup.save(segments, points),
+            OperationEffectArea::Filter(filter) => {
+                let points = points_by_filter(segments, &filter)?;
+                backup.save(segments, points)
+            }
+        }
     }
 }
 
@@ -83,8 +184,13 @@ mod tests {
     use crate::collection_manager::fixtures::build_test_holder;
     use crate::collection_manager::segments_searcher::SegmentsSearcher;
     use crate::collection_manager::segments_updater::upsert_points;
-    use crate::operations::payload_ops::{DeletePayloadOp, PayloadOps, SetPayloadOp};
-    use crate::operations::point_ops::{PointOperations, PointStruct};
+    use crate::operations::payload_ops::{
+        DeletePayloadOp, MutatePayloadOp, PayloadMutation, PayloadOps, SetPayloadOp,
+    };
+    use crate::operations::point_ops::{
+        PointInsertOperationsInternal, PointOperations, PointStruct,
+    };
+    use crate::operations::vector_ops::{PointVectors, UpdateVectorsOp, VectorOperations};
 
     #[test]
     fn test_sync_ops() {
@@ -178,7 +284,7 @@ mod tests {
         }
 
         process_point_operation(
-            &segments,
+            &segments.read(),
             101,
             PointOperations::DeletePoints {
                 ids: vec![500.into()],
@@ -210,7 +316,7 @@ mod tests {
         let points = vec![1.into(), 2.into(), 3.into()];
 
         process_payload_operation(
-            &segments,
+            &segments.read(),
             100,
             PayloadOps::SetPayload(SetPayloadOp {
                 payload,
@@ -239,7 +345,7 @@ mod tests {
 
         // Test payload delete
         process_payload_operation(
-            &segments,
+            &segments.read(),
             101,
             PayloadOps::DeletePayload(DeletePayloadOp {
                 points: Some(vec![3.into()]),
@@ -272,7 +378,7 @@ mod tests {
         assert!(res[0].payload.as_ref().unwrap().contains_key("color"));
 
         process_payload_operation(
-            &segments,
+            &segments.read(),
             102,
             PayloadOps::ClearPayload {
                 points: vec![2.into()],
@@ -289,4 +395,152 @@ mod tests {
         assert_eq!(res.len(), 1);
         assert!(!res[0].payload.as_ref().unwrap().contains_key("color"));
     }
+
+    #[test]
+    fn test_mutate_payload_ops() {
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let segments = build_test_holder(dir.path());
+
+        let payload: Payload = serde_json::from_str(r#"{"count":1,"tags":["a"]}"#).unwrap();
+        let points = vec![1.into(), 2.into()];
+
+        process_payload_operation(
+            &segments.read(),
+            100,
+            PayloadOps::SetPayload(SetPayloadOp {
+                payload,
+                points: Some(points.clone()),
+                filter: None,
+                key: None,
+            }),
+        )
+        .unwrap();
+
+        let mutations = vec![
+            PayloadMutation::Increment {
+                key: "count".parse().unwrap(),
+                by: 2.into(),
+            },
+            PayloadMutation::Append {
+                key: "tags".parse().unwrap(),
+                values: vec![json!("a"), json!("b")],
+                unique: true,
+            },
+        ];
+
+        // Mutations are applied twice, each time against the stored payload
+        for op_num in [101, 102] {
+            process_payload_operation(
+                &segments.read(),
+                op_num,
+                PayloadOps::MutatePayload(MutatePayloadOp {
+                    mutations: mutations.clone(),
+                    points: Some(vec![1.into()]),
+                    filter: None,
+                }),
+            )
+            .unwrap();
+        }
+
+        let res =
+            SegmentsSearcher::retrieve(&segments, &points, &WithPayload::from(true), &false.into())
+                .unwrap();
+        assert_eq!(res.len(), 2);
+
+        for record in res {
+            let payload = record.payload.unwrap();
+            if record.id == 1.into() {
+                assert_eq!(payload.0["count"], json!(5));
+                assert_eq!(payload.0["tags"], json!(["a", "b"]));
+            } else {
+                assert_eq!(payload.0["count"], json!(1));
+                assert_eq!(payload.0["tags"], json!(["a"]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_atomic_batch() {
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let segments = build_test_holder(dir.path());
+
+        let ids = [1.into(), 2.into(), 500.into()];
+        let retrieve = || {
+            let mut records =
+                SegmentsSearcher::retrieve(&segments, &ids, &WithPayload::from(true), &true.into())
+                    .unwrap();
+            records.sort_by_key(|record| record.id);
+            records
+        };
+
+        let batch = |update_vector_id: u64| {
+            CollectionUpdateOperations::AtomicBatch(AtomicBatchOp {
+                operations: vec![
+                    CollectionUpdateOperations::PayloadOperation(PayloadOps::SetPayload(
+                        SetPayloadOp {
+                            payload: json!({ "color": "blue" }).into(),
+                            points: Some(vec![1.into()]),
+                            filter: None,
+                            key: None,
+                        },
+                    )),
+                    CollectionUpdateOperations::PointOperation(PointOperations::DeletePoints {
+                        ids: vec![2.into()],
+                    }),
+                    CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+                        PointInsertOperationsInternal::PointsList(vec![PointStruct {
+                            id: 500.into(),
+                            vector: VectorStructInternal::from(vec![2., 0., 2., 0.]).into(),
+                            payload: None,
+                        }]),
+                    )),
+                    CollectionUpdateOperations::VectorOperation(VectorOperations::UpdateVectors(
+                        UpdateVectorsOp {
+                            points: vec![PointVectors {
+                                id: update_vector_id.into(),
+                                vector: VectorStructInternal::from(vec![1., 1., 1., 1.]).into(),
+                            }],
+                            update_filter: None,
+                        },
+                    )),
+                ],
+                idempotency_key: None,
+            })
+        };
+
+        let before = retrieve();
+        assert_eq!(before.len(), 2);
+
+        // Last operation refers to a missing point, so the whole batch is rolled back
+        let res = CollectionUpdater::update(&segments, 100, batch(1000));
+        assert!(res.is_err());
+        assert_eq!(retrieve(), before);
+
+        CollectionUpdater::update(&segments, 101, batch(1)).unwrap();
+
+        let after = retrieve();
+        assert_eq!(after.len(), 2);
+        assert_eq!(after[0].id, 1.into());
+        assert_eq!(after[0].payload.as_ref().unwrap().0["color"], json!("blue"));
+        assert_eq!(
+            after[0].vector.as_ref().unwrap().get(DEFAULT_VECTOR_NAME),
+            Some((&vec![1., 1., 1., 1.]).into())
+        );
+        assert_eq!(after[1].id, 500.into());
+    }
+
+    #[test]
+    fn test_atomic_batch_backup_limit() {
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let segments = build_test_holder(dir.path());
+
+        let mut backup = PointsBackup::new(2);
+        backup.save(&segments.read(), [1.into(), 2.into()]).unwrap();
+
+        // Already saved points are not counted twice
+        backup.save(&segments.read(), [2.into()]).unwrap();
+
+        let res = backup.save(&segments.read(), [3.into()]);
+        assert!(matches!(res, Err(CollectionError::BadInput { .. })));
+    }
 }
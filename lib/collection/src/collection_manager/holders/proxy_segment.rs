@@ -4,14 +4,16 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use bitvec::prelude::BitVec;
+use chrono::{DateTime, Utc};
 use common::types::{PointOffsetType, TelemetryDetail};
 use parking_lot::{RwLock, RwLockUpgradableReadGuard};
 use segment::common::operation_error::{OperationResult, SegmentFailedState};
 use segment::data_types::named_vectors::NamedVectors;
-use segment::data_types::order_by::OrderValue;
+use segment::data_types::order_by::OrderValues;
 use segment::data_types::query_context::{QueryContext, SegmentQueryContext};
 use segment::data_types::vectors::{QueryVector, Vector};
 use segment::entry::entry_point::SegmentEntry;
+use segment::id_tracker::TrashedPoint;
 use segment::index::field_index::CardinalityEstimation;
 use segment::json_path::JsonPath;
 use segment::telemetry::SegmentTelemetry;
@@ -183,6 +185,14 @@ impl ProxySegment {
         Ok(true)
     }
 
+    /// Point in trash of the wrapped segment, which is not yet removed from it
+    fn wrapped_trashed_point(&self, point_id: PointIdType) -> Option<TrashedPoint> {
+        if self.deleted_points.read().contains(&point_id) {
+            return None;
+        }
+        self.wrapped_segment.get().read().trashed_point(point_id)
+    }
+
     fn add_deleted_points_condition_to_filter(
         &self,
         filter: Option<&Filter>,
@@ -424,16 +434,20 @@ impl SegmentEntry for ProxySegment {
     ) -> OperationResult<bool> {
         let mut was_deleted = false;
 
+        // Points in trash of the wrapped segment are also marked, so they are purged from it
         let point_offset = match &self.wrapped_segment {
             LockedSegment::Original(raw_segment) => {
-                let point_offset = raw_segment.read().get_internal_id(point_id);
-                if point_offset.is_some() {
+                let raw_segment_guard = raw_segment.read();
+                let point_offset = raw_segment_guard.get_internal_id(point_id);
+                if point_offset.is_some() || raw_segment_guard.trashed_point(point_id).is_some() {
                     was_deleted = self.deleted_points.write().insert(point_id);
                 }
                 point_offset
             }
             LockedSegment::Proxy(proxy) => {
-                if proxy.read().has_point(point_id) {
+                let proxy_guard = proxy.read();
+                if proxy_guard.has_point(point_id) || proxy_guard.trashed_point(point_id).is_some()
+                {
                     was_deleted = self.deleted_points.write().insert(point_id);
                 }
                 None
@@ -529,6 +543,126 @@ impl SegmentEntry for ProxySegment {
             .clear_payload(op_num, point_id)
     }
 
+    fn trash_point(
+        &mut self,
+        op_num: SeqNumberType,
+        point_id: PointIdType,
+        trashed_at: DateTime<Utc>,
+    ) -> OperationResult<bool> {
+        self.move_if_exists(op_num, point_id)?;
+        self.write_segment
+            .get()
+            .write()
+            .trash_point(op_num, point_id, trashed_at)
+    }
+
+    fn restore_point(
+        &mut self,
+        op_num: SeqNumberType,
+        point_id: PointIdType,
+    ) -> OperationResult<bool> {
+        let write_trashed_point = self.write_segment.get().read().trashed_point(point_id);
+        let wrapped_trashed_point = self.wrapped_trashed_point(point_id);
+
+        let wrapped_trashed_point = match (write_trashed_point, wrapped_trashed_point) {
+            (_, None) => None,
+            (Some(write_point), Some(wrapped_point))
+                if write_point.version >= wrapped_point.version =>
+            {
+                None
+            }
+            (_, Some(wrapped_point)) => Some(wrapped_point),
+        };
+
+        let Some(wrapped_trashed_point) = wrapped_trashed_point else {
+            return self
+                .write_segment
+                .get()
+                .write()
+                .restore_point(op_num, point_id);
+        };
+
+        if wrapped_trashed_point.version > op_num {
+            // Point was moved to trash by a later operation
+            return Ok(false);
+        }
+
+        // Wrapped segment can't be changed, copy the point out of its trash instead
+        let deleted_points_guard = self.deleted_points.upgradable_read();
+        let (all_vectors, payload) = {
+            let wrapped_segment = self.wrapped_segment.get();
+            let wrapped_segment_guard = wrapped_segment.read();
+            (
+                wrapped_segment_guard.trashed_vectors(point_id)?,
+                wrapped_segment_guard.trashed_payload(point_id)?,
+            )
+        };
+
+        {
+            let segment_arc = self.write_segment.get();
+            let mut write_segment = segment_arc.write();
+
+            write_segment.upsert_point(op_num, point_id, all_vectors)?;
+            if !payload.is_empty() {
+                write_segment.set_full_payload(op_num, point_id, &payload)?;
+            }
+        }
+
+        RwLockUpgradableReadGuard::upgrade(deleted_points_guard).insert(point_id);
+
+        Ok(true)
+    }
+
+    fn trashed_point(&self, point_id: PointIdType) -> Option<TrashedPoint> {
+        let write_trashed_point = self.write_segment.get().read().trashed_point(point_id);
+        let wrapped_trashed_point = self.wrapped_trashed_point(point_id);
+        match (write_trashed_point, wrapped_trashed_point) {
+            (Some(write_point), Some(wrapped_point)) => {
+                if write_point.version >= wrapped_point.version {
+                    Some(write_point)
+                } else {
+                    Some(wrapped_point)
+                }
+            }
+            (write_point, wrapped_point) => write_point.or(wrapped_point),
+        }
+    }
+
+    fn trashed_points(&self, filter: Option<&Filter>) -> Vec<PointIdType> {
+        let deleted_points = self.deleted_points.read();
+        let mut trashed_points: Vec<_> = self
+            .wrapped_segment
+            .get()
+            .read()
+            .trashed_points(filter)
+            .into_iter()
+            .filter(|point_id| !deleted_points.contains(point_id))
+            .collect();
+        let mut write_segment_points = self.write_segment.get().read().trashed_points(filter);
+        trashed_points.append(&mut write_segment_points);
+        trashed_points.sort_unstable();
+        trashed_points.dedup();
+        trashed_points
+    }
+
+    fn trashed_vectors(&self, point_id: PointIdType) -> OperationResult<NamedVectors> {
+        let write_segment = self.write_segment.get();
+        let segment_guard = write_segment.read();
+        if segment_guard.trashed_point(point_id).is_some() {
+            return segment_guard.trashed_vectors(point_id);
+        }
+        self.wrapped_segment.get().read().trashed_vectors(point_id)
+    }
+
+    fn trashed_payload(&self, point_id: PointIdType) -> OperationResult<Payload> {
+        let write_segment = self.write_segment.get();
+        let segment_guard = write_segment.read();
+        if segment_guard.trashed_point(point_id).is_some() {
+            return segment_guard.trashed_payload(point_id);
+        }
+        self.wrapped_segment.get().read().trashed_payload(point_id)
+    }
+
     fn vector(&self, vector_name: &str, point_id: PointIdType) -> OperationResult<Option<Vector>> {
         return if self.deleted_points.read().contains(&point_id) {
             self.write_segment
@@ -636,7 +770,7 @@ impl SegmentEntry for ProxySegment {
         limit: Option<usize>,
         filter: Option<&'a Filter>,
         order_by: &'a segment::data_types::order_by::OrderBy,
-    ) -> OperationResult<Vec<(OrderValue, PointIdType)>> {
+    ) -> OperationResult<Vec<(OrderValues, PointIdType)>> {
         let deleted_points = self.deleted_points.read();
         let mut read_points = if deleted_points.is_empty() {
             self.wrapped_segment
@@ -658,7 +792,9 @@ impl SegmentEntry for ProxySegment {
             .read()
             .read_ordered_filtered(limit, filter, order_by)?;
         read_points.append(&mut write_segment_points);
-        read_points.sort_unstable();
+        read_points.sort_unstable_by(|(a_values, a_id), (b_values, b_id)| {
+            order_by.cmp_points((a_values, a_id), (b_values, b_id))
+        });
         Ok(read_points)
     }
 
@@ -1534,8 +1670,10 @@ mod tests {
                         storage_type: VectorStorageType::Memory,
                         index: Indexes::Plain {},
                         quantization_config: None,
+                        additional_quantization_configs: vec![],
                         multivector_config: None,
                         datatype: None,
+                        prefix_of: None,
                     },
                 ),
                 (
@@ -1546,8 +1684,10 @@ mod tests {
                         storage_type: VectorStorageType::Memory,
                         index: Indexes::Plain {},
                         quantization_config: None,
+                        additional_quantization_configs: vec![],
                         multivector_config: None,
                         datatype: None,
+                        prefix_of: None,
                     },
                 ),
             ]),
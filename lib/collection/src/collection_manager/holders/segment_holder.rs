@@ -12,6 +12,7 @@ use parking_lot::{RwLock, RwLockReadGuard, RwLockUpgradableReadGuard, RwLockWrit
 use rand::seq::SliceRandom;
 use segment::common::operation_error::{OperationError, OperationResult};
 use segment::entry::entry_point::SegmentEntry;
+use segment::id_tracker::TrashedPoint;
 use segment::segment::{Segment, SegmentVersion};
 use segment::segment_constructor::build_segment;
 use segment::types::{PointIdType, SegmentConfig, SeqNumberType};
@@ -152,6 +153,10 @@ pub struct SegmentHolder {
 
     /// Holds the first uncorrected error happened with optimizer
     pub optimizer_errors: Option<CollectionError>,
+
+    /// Operation number of the atomic batch, which is being applied for the first time.
+    /// Points with this version are written by the batch itself, so they are not skipped.
+    pub(crate) atomic_batch_op_num: Option<SeqNumberType>,
 }
 
 pub type LockedSegmentHolder = Arc<RwLock<SegmentHolder>>;
@@ -394,6 +399,36 @@ impl<'s> SegmentHolder {
             .and_then(|idx| self.appendable_segments.get(idx).cloned())
     }
 
+    /// Find the segment with the latest copy of the point in trash
+    ///
+    /// Returns `None` if the point is not in trash, or if it exists outside of trash in any segment.
+    pub fn latest_trashed_point(
+        &self,
+        point_id: PointIdType,
+    ) -> Option<(Arc<RwLock<dyn SegmentEntry>>, TrashedPoint)> {
+        let mut latest_trashed: Option<(Arc<RwLock<dyn SegmentEntry>>, TrashedPoint)> = None;
+        for (_idx, segment) in self.iter() {
+            let segment_arc = segment.get();
+            let trashed_point = {
+                let segment_guard = segment_arc.read();
+                if segment_guard.has_point(point_id) {
+                    return None;
+                }
+                segment_guard.trashed_point(point_id)
+            };
+            let Some(trashed_point) = trashed_point else {
+                continue;
+            };
+            let is_latest = latest_trashed
+                .as_ref()
+                .map_or(true, |(_, latest)| trashed_point.version > latest.version);
+            if is_latest {
+                latest_trashed = Some((segment_arc, trashed_point));
+            }
+        }
+        latest_trashed
+    }
+
     /// Selects point ids, which is stored in this segment
     fn segment_points(&self, ids: &[PointIdType], segment: &dyn SegmentEntry) -> Vec<PointIdType> {
         ids.iter()
@@ -565,7 +600,9 @@ impl<'s> SegmentHolder {
             update_nonappendable,
             |point_id, _idx, write_segment, &update_nonappendable| {
                 if let Some(point_version) = write_segment.point_version(point_id) {
-                    if point_version >= op_num {
+                    let is_written_by_batch =
+                        point_version == op_num && self.atomic_batch_op_num == Some(op_num);
+                    if point_version >= op_num && !is_written_by_batch {
                         applied_points.insert(point_id);
                         return Ok(false);
                     }
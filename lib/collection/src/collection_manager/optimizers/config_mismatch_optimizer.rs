@@ -176,8 +176,30 @@ impl ConfigMismatchOptimizer {
                                         && (vector_data.quantization_config.is_some()
                                             != target_quantization.is_some())
                                 });
+                            if quantization_mismatch {
+                                return true;
+                            }
 
-                            quantization_mismatch
+                            // Check additional quantizations mismatch
+                            let target_additional_quantization = target_quantization
+                                .and_then(|_| {
+                                    self.collection_params.vectors.get_params(vector_name)
+                                })
+                                .map(|vector_params| {
+                                    vector_params.additional_quantization_configs.as_slice()
+                                })
+                                .unwrap_or_default();
+                            let current_additional_quantization =
+                                &vector_data.additional_quantization_configs;
+                            vector_data.index.is_indexed()
+                                && (current_additional_quantization.len()
+                                    != target_additional_quantization.len()
+                                    || current_additional_quantization
+                                        .iter()
+                                        .zip(target_additional_quantization)
+                                        .any(|(current, target)| {
+                                            current.mismatch_requires_rebuild(target)
+                                        }))
                         });
 
                 // Determine whether dense data in segment has mismatch
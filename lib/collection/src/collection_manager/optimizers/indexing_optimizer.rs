@@ -280,7 +280,6 @@ impl SegmentOptimizer for IndexingOptimizer {
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeMap;
-    use std::ops::Deref;
     use std::sync::atomic::AtomicBool;
     use std::sync::Arc;
 
@@ -507,7 +506,7 @@ mod tests {
 
         // ----- CREATE AN INDEXED FIELD ------
         process_field_index_operation(
-            locked_holder.deref(),
+            &locked_holder.read(),
             opnum.next().unwrap(),
             &FieldIndexOperations::CreateIndex(CreateIndex {
                 field_name: payload_field.clone(),
@@ -631,7 +630,7 @@ mod tests {
             .num_vectors;
 
         process_point_operation(
-            locked_holder.deref(),
+            &locked_holder.read(),
             opnum.next().unwrap(),
             insert_point_ops,
         )
@@ -700,7 +699,7 @@ mod tests {
         .into();
 
         process_point_operation(
-            locked_holder.deref(),
+            &locked_holder.read(),
             opnum.next().unwrap(),
             insert_point_ops,
         )
@@ -3,6 +3,7 @@ use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
+use chrono::Utc;
 use common::cpu::CpuPermit;
 use io::storage_version::StorageVersion;
 use itertools::Itertools;
@@ -178,6 +179,15 @@ pub trait SegmentOptimizer {
                 let vector_quantization = param_quantization
                     .or(collection_quantization.as_ref())
                     .cloned();
+
+                // Additional quantizations are only built next to a primary one
+                if vector_quantization.is_some() {
+                    config.additional_quantization_configs = collection_params
+                        .vectors
+                        .get_params(vector_name)
+                        .map(|params| params.additional_quantization_configs.clone())
+                        .unwrap_or_default();
+                }
                 config.quantization_config = vector_quantization;
             });
         }
@@ -242,11 +252,15 @@ pub trait SegmentOptimizer {
             },
         };
 
-        Ok(SegmentBuilder::new(
-            self.segments_path(),
-            self.temp_path(),
-            &optimized_config,
-        )?)
+        let mut segment_builder =
+            SegmentBuilder::new(self.segments_path(), self.temp_path(), &optimized_config)?;
+
+        // Points in trash are purged, once their retention period is over
+        if let Some(trashed_after) = collection_params.trash_retained_after(Utc::now()) {
+            segment_builder.retain_trash(trashed_after);
+        }
+
+        Ok(segment_builder)
     }
 
     /// Restores original segments from proxies
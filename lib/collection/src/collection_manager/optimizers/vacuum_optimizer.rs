@@ -2,6 +2,7 @@ use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use chrono::Utc;
 use ordered_float::OrderedFloat;
 use parking_lot::Mutex;
 use segment::common::operation_time_statistics::OperationDurationsAggregator;
@@ -94,8 +95,21 @@ impl VacuumOptimizer {
         };
         let read_segment = segment_entry.read();
 
-        let littered_ratio =
-            read_segment.deleted_point_count() as f64 / read_segment.total_point_count() as f64;
+        // Points in trash are deleted, but vacuum would keep them until the retention period is over
+        let retained_trash_count = match self.collection_params.trash_retained_after(Utc::now()) {
+            Some(trashed_after) => read_segment
+                .id_tracker
+                .borrow()
+                .iter_trashed()
+                .filter(|(_, trashed_point)| trashed_point.trashed_at > trashed_after)
+                .count(),
+            None => 0,
+        };
+        let deleted_point_count = read_segment
+            .deleted_point_count()
+            .saturating_sub(retained_trash_count);
+
+        let littered_ratio = deleted_point_count as f64 / read_segment.total_point_count() as f64;
         let is_big = read_segment.total_point_count() >= self.min_vectors_number;
         let is_littered = littered_ratio > self.deleted_threshold;
 
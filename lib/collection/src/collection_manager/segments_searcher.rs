@@ -5,10 +5,11 @@ use common::types::ScoreType;
 use futures::future::try_join_all;
 use itertools::Itertools;
 use ordered_float::Float;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use segment::common::operation_error::OperationError;
 use segment::data_types::named_vectors::NamedVectors;
 use segment::data_types::query_context::QueryContext;
+use segment::data_types::search_explain::{SegmentSearchExplanation, VectorSearchExplanation};
 use segment::data_types::vectors::{QueryVector, VectorStructInternal};
 use segment::types::{
     Filter, Indexes, PointIdType, ScoredPoint, SearchParams, SegmentConfig, SeqNumberType,
@@ -22,6 +23,7 @@ use super::holders::segment_holder::LockedSegmentHolder;
 use crate::collection_manager::holders::segment_holder::{LockedSegment, SegmentHolder};
 use crate::collection_manager::probabilistic_segment_search_sampling::find_search_sampling_over_point_distribution;
 use crate::collection_manager::search_result_aggregator::BatchResultAggregator;
+use crate::common::slow_request_guard::add_segments_touched;
 use crate::common::stopping_guard::StoppingGuard;
 use crate::config::CollectionConfig;
 use crate::operations::query_enum::QueryEnum;
@@ -267,6 +269,8 @@ impl SegmentsSearcher {
                 .unzip()
         };
 
+        add_segments_touched(locked_segments.len());
+
         // perform search on all segments concurrently
         // the resulting Vec is in the same order as the segment searches were provided.
         let (all_search_results_per_segment, further_results) =
@@ -585,7 +589,13 @@ fn execute_batch_search(
     };
 
     let vectors_batch = &vectors_batch.iter().collect_vec();
-    let segment_query_context = query_context.get_segment_query_context();
+    let explanation = query_context
+        .explain_collector()
+        .map(|_| Mutex::new(VectorSearchExplanation::default()));
+    let mut segment_query_context = query_context.get_segment_query_context();
+    if let Some(explanation) = &explanation {
+        segment_query_context = segment_query_context.with_explanation(explanation);
+    }
     let res = read_segment.search_batch(
         search_params.vector_name,
         vectors_batch,
@@ -597,6 +607,19 @@ fn execute_batch_search(
         segment_query_context,
     )?;
 
+    if let (Some(collector), Some(explanation)) = (query_context.explain_collector(), explanation) {
+        collector.add(SegmentSearchExplanation {
+            segment: read_segment
+                .data_path()
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            vector_name: search_params.vector_name.to_string(),
+            batch_size: vectors_batch.len(),
+            search: explanation.into_inner(),
+        });
+    }
+
     let further_results = res
         .iter()
         .map(|batch_result| batch_result.len() == top)
@@ -2,21 +2,25 @@
 
 use std::collections::{HashMap, HashSet};
 
+use chrono::{DateTime, Utc};
 use itertools::iproduct;
-use parking_lot::{RwLock, RwLockWriteGuard};
+use parking_lot::RwLockWriteGuard;
 use segment::common::operation_error::{OperationError, OperationResult};
 use segment::data_types::named_vectors::NamedVectors;
 use segment::data_types::vectors::{BatchVectorStructInternal, VectorStructInternal};
 use segment::entry::entry_point::SegmentEntry;
 use segment::json_path::JsonPath;
 use segment::types::{
-    Filter, Payload, PayloadFieldSchema, PayloadKeyType, PayloadKeyTypeRef, PointIdType,
-    SeqNumberType,
+    Condition, Filter, HasIdCondition, Payload, PayloadFieldSchema, PayloadKeyType,
+    PayloadKeyTypeRef, PointIdType, SeqNumberType,
 };
 
 use crate::collection_manager::holders::segment_holder::SegmentHolder;
-use crate::operations::payload_ops::PayloadOps;
-use crate::operations::point_ops::{PointInsertOperationsInternal, PointOperations, PointStruct};
+use crate::operations::payload_ops::{PayloadMutation, PayloadOps};
+use crate::operations::point_ops::{
+    ConditionalInsertOperationInternal, PointInsertOperationsInternal, PointOperations,
+    PointStruct, UpdateMode,
+};
 use crate::operations::types::{CollectionError, CollectionResult};
 use crate::operations::vector_ops::{PointVectors, VectorOperations};
 use crate::operations::FieldIndexOperations;
@@ -48,11 +52,141 @@ pub(crate) fn delete_points(
         .map_err(Into::into)
 }
 
+/// Moves points to trash in all segments, returns number of actually moved points
+pub(crate) fn trash_points(
+    segments: &SegmentHolder,
+    op_num: SeqNumberType,
+    ids: &[PointIdType],
+    trashed_at: DateTime<Utc>,
+) -> CollectionResult<usize> {
+    segments
+        .apply_points(
+            ids,
+            |_| (),
+            |id, _idx, write_segment, ()| write_segment.trash_point(op_num, id, trashed_at),
+        )
+        .map_err(Into::into)
+}
+
+/// Moves points matching the given filter to trash
+pub(crate) fn trash_points_by_filter(
+    segments: &SegmentHolder,
+    op_num: SeqNumberType,
+    filter: &Filter,
+    trashed_at: DateTime<Utc>,
+) -> CollectionResult<usize> {
+    let affected_points = points_by_filter(segments, filter)?;
+    trash_points(segments, op_num, &affected_points, trashed_at)
+}
+
+/// Moves points out of trash, returns number of actually restored points
+///
+/// Only the latest copy of a point in trash is restored, and only if it was moved to trash after
+/// `trashed_after`. Points, which exist outside of trash, are not affected.
+/// Points in trash of non-appendable segments are moved into an appendable segment.
+pub(crate) fn restore_points(
+    segments: &SegmentHolder,
+    op_num: SeqNumberType,
+    ids: &[PointIdType],
+    trashed_after: Option<DateTime<Utc>>,
+) -> CollectionResult<usize> {
+    let _update_guard = segments.update_tracker().update();
+
+    let appendable_segments = segments.appendable_segments_ids();
+
+    let mut restored = 0;
+    for &point_id in ids {
+        let Some((segment_arc, trashed_point)) = segments.latest_trashed_point(point_id) else {
+            continue;
+        };
+        if trashed_after.is_some_and(|trashed_after| trashed_point.trashed_at <= trashed_after) {
+            continue;
+        }
+
+        let mut write_segment = segment_arc.write();
+        let is_restored = if write_segment.is_appendable() {
+            write_segment.restore_point(op_num, point_id)?
+        } else if trashed_point.version > op_num {
+            // Point was moved to trash by a later operation
+            false
+        } else {
+            segments.aloha_random_write(
+                &appendable_segments,
+                |_appendable_idx, appendable_write_segment| {
+                    let all_vectors = write_segment.trashed_vectors(point_id)?;
+                    let payload = write_segment.trashed_payload(point_id)?;
+
+                    appendable_write_segment.upsert_point(op_num, point_id, all_vectors)?;
+                    appendable_write_segment.set_full_payload(op_num, point_id, &payload)?;
+
+                    // Purge the point from trash of the non-appendable segment
+                    write_segment.delete_point(op_num, point_id)?;
+                    Ok(true)
+                },
+            )?
+        };
+        restored += is_restored as usize;
+    }
+    Ok(restored)
+}
+
+/// Moves points in trash matching the given filter out of trash
+pub(crate) fn restore_points_by_filter(
+    segments: &SegmentHolder,
+    op_num: SeqNumberType,
+    filter: &Filter,
+    trashed_after: Option<DateTime<Utc>>,
+) -> CollectionResult<usize> {
+    let mut affected_points: Vec<PointIdType> = Vec::new();
+    segments.for_each_segment(|s| {
+        affected_points.extend(s.trashed_points(Some(filter)));
+        Ok(true)
+    })?;
+    affected_points.sort_unstable();
+    affected_points.dedup();
+    restore_points(segments, op_num, &affected_points, trashed_after)
+}
+
+/// Checks if the point stored in the given segment matches the filter
+fn point_matches_filter(
+    segment: &dyn SegmentEntry,
+    point_id: PointIdType,
+    filter: &Filter,
+) -> bool {
+    let has_id = HasIdCondition::from(HashSet::from([point_id]));
+    let point_filter = Filter::new_must(Condition::HasId(has_id)).merge(filter);
+    !segment
+        .read_filtered(None, Some(1), Some(&point_filter))
+        .is_empty()
+}
+
+/// Selects the points among `ids`, which exist in the segments but don't match the filter
+///
+/// Must be evaluated before applying the update: a point stored in a non-appendable segment
+/// is moved to an appendable one by the update, even if it is left untouched afterwards.
+fn select_excluded_by_filter_ids(
+    segments: &SegmentHolder,
+    ids: &[PointIdType],
+    filter: &Filter,
+) -> CollectionResult<HashSet<PointIdType>> {
+    let mut excluded_ids = HashSet::new();
+    segments.read_points(ids, |id, segment| {
+        if !point_matches_filter(&**segment, id, filter) {
+            excluded_ids.insert(id);
+        }
+        Ok(true)
+    })?;
+    Ok(excluded_ids)
+}
+
 /// Update the specified named vectors of a point, keeping unspecified vectors intact.
+///
+/// If `update_filter` is given, points not matching it are left untouched.
 pub(crate) fn update_vectors(
     segments: &SegmentHolder,
     op_num: SeqNumberType,
     points: Vec<PointVectors>,
+    update_filter: Option<&Filter>,
 ) -> CollectionResult<usize> {
     // Build a map of vectors to update per point, merge updates on same point ID
     let mut points_map: HashMap<PointIdType, NamedVectors> = HashMap::new();
@@ -64,7 +198,12 @@ pub(crate) fn update_vectors(
         entry.merge(named_vector);
     }
 
-    let ids: Vec<PointIdType> = points_map.keys().copied().collect();
+    let mut ids: Vec<PointIdType> = points_map.keys().copied().collect();
+
+    if let Some(filter) = update_filter {
+        let excluded_ids = select_excluded_by_filter_ids(segments, &ids, filter)?;
+        ids.retain(|id| !excluded_ids.contains(id));
+    }
 
     let updated_points = segments.apply_points_with_conditional_move(
         op_num,
@@ -161,7 +300,52 @@ pub(crate) fn set_payload(
     Ok(updated_points.len())
 }
 
-fn points_by_filter(
+/// Apply payload mutations to the stored payload of each point.
+///
+/// Mutations are evaluated under the write lock of the segment, holding the point,
+/// so concurrent mutations of the same point can't get lost.
+pub(crate) fn mutate_payload(
+    segments: &SegmentHolder,
+    op_num: SeqNumberType,
+    mutations: &[PayloadMutation],
+    points: &[PointIdType],
+) -> CollectionResult<usize> {
+    let updated_points = segments.apply_points_with_conditional_move(
+        op_num,
+        points,
+        |id, write_segment| {
+            let mut payload = write_segment.payload(id)?;
+            for mutation in mutations {
+                mutation.apply(&mut payload);
+            }
+            write_segment.set_full_payload(op_num, id, &payload)
+        },
+        |segment| {
+            iproduct!(segment.get_indexed_fields().keys(), mutations).all(
+                |(indexed_path, mutation)| {
+                    mutation.key().is_some_and(|mutated_path| {
+                        !indexed_path.is_affected_by_value_remove(mutated_path)
+                    })
+                },
+            )
+        },
+    )?;
+
+    check_unprocessed_points(points, &updated_points)?;
+    Ok(updated_points.len())
+}
+
+pub(crate) fn mutate_payload_by_filter(
+    segments: &SegmentHolder,
+    op_num: SeqNumberType,
+    mutations: &[PayloadMutation],
+    filter: &Filter,
+) -> CollectionResult<usize> {
+    let affected_points = points_by_filter(segments, filter)?;
+    mutate_payload(segments, op_num, mutations, &affected_points)
+}
+
+pub(crate) fn points_by_filter(
     segments: &SegmentHolder,
     filter: &Filter,
 ) -> CollectionResult<Vec<PointIdType>> {
@@ -377,6 +561,85 @@ pub(crate) fn sync_points(
     Ok((deleted, num_new, num_updated))
 }
 
+/// Stored state of points, which allows to restore them after a failed atomic batch
+pub(crate) struct PointsBackup {
+    /// Vectors and payload of each saved point, `None` if the point didn't exist
+    points: HashMap<PointIdType, Option<PointStruct>>,
+    /// Max number of points to save, to bound memory used by the backup
+    max_points: usize,
+}
+
+impl PointsBackup {
+    pub fn new(max_points: usize) -> Self {
+        Self {
+            points: HashMap::new(),
+            max_points,
+        }
+    }
+
+    /// Save the current state of the given points, unless it was saved already
+    pub fn save(
+        &mut self,
+        segments: &SegmentHolder,
+        ids: impl IntoIterator<Item = PointIdType>,
+    ) -> CollectionResult<()> {
+        let ids: Vec<_> = ids
+            .into_iter()
+            .filter(|id| !self.points.contains_key(id))
+            .collect();
+
+        if self.points.len() + ids.len() > self.max_points {
+            return Err(CollectionError::bad_input(format!(
+                "Atomic batch affects too many points, at most {} points are allowed",
+                self.max_points,
+            )));
+        }
+
+        // Points, which are not found in any segment, have to be removed on restore
+        for &id in &ids {
+            self.points.insert(id, None);
+        }
+
+        // Unlike `sync_points`, inconsistent storage is not tolerated here:
+        // restoring a point with missing vectors would overwrite them with empty ones
+        segments.read_points(&ids, |id, segment| {
+            let all_vectors = segment.all_vectors(id)?;
+            let payload = segment.payload(id)?;
+            self.points.insert(
+                id,
+                Some(PointStruct {
+                    id,
+                    vector: VectorStructInternal::from(all_vectors).into(),
+                    payload: Some(payload),
+                }),
+            );
+            Ok(true)
+        })?;
+
+        Ok(())
+    }
+
+    /// Restore the saved state of the points
+    ///
+    /// Restored points get the version of the current operation, so that the restored state
+    /// is not overwritten by older operations on WAL replay.
+    /// Points already written by the batch are overwritten, see [`SegmentHolder::atomic_batch_op_num`].
+    pub fn restore(self, segments: &SegmentHolder, op_num: SeqNumberType) -> CollectionResult<()> {
+        let mut created_points = Vec::new();
+        let mut stored_points = Vec::new();
+        for (id, point) in self.points {
+            match point {
+                Some(point) => stored_points.push(point),
+                None => created_points.push(id),
+            }
+        }
+
+        delete_points(segments, op_num, &created_points)?;
+        upsert_points(segments, op_num, &stored_points)?;
+        Ok(())
+    }
+}
+
 /// Checks point id in each segment, update point if found.
 /// All not found points are inserted into random segment.
 /// Returns: number of updated points.
@@ -385,12 +648,74 @@ pub(crate) fn upsert_points<'a, T>(
     op_num: SeqNumberType,
     points: T,
 ) -> CollectionResult<usize>
+where
+    T: IntoIterator<Item = &'a PointStruct>,
+{
+    let (res, _skipped) =
+        upsert_points_with_mode(segments, op_num, points, None, UpdateMode::Upsert)?;
+    Ok(res)
+}
+
+/// Same as [`upsert_points`], but restricted by the `condition` and the `update_mode`.
+///
+/// Existing points are only overwritten if they match `condition` and `update_mode` allows updates.
+/// The condition is checked under the write lock of the segment, holding the point.
+/// Points, that don't exist yet, are only inserted if `update_mode` allows inserts.
+///
+/// Returns: number of points, which were inserted or overwritten.
+pub(crate) fn conditional_upsert_points<'a, T>(
+    segments: &SegmentHolder,
+    op_num: SeqNumberType,
+    points: T,
+    condition: Option<&Filter>,
+    update_mode: UpdateMode,
+) -> CollectionResult<usize>
+where
+    T: IntoIterator<Item = &'a PointStruct>,
+{
+    let points_map: HashMap<PointIdType, &PointStruct> =
+        points.into_iter().map(|p| (p.id, p)).collect();
+    let points_count = points_map.len();
+
+    let (_res, skipped) = upsert_points_with_mode(
+        segments,
+        op_num,
+        points_map.into_values(),
+        condition,
+        update_mode,
+    )?;
+
+    Ok(points_count.saturating_sub(skipped))
+}
+
+/// Returns: (number of updated points, number of skipped points)
+fn upsert_points_with_mode<'a, T>(
+    segments: &SegmentHolder,
+    op_num: SeqNumberType,
+    points: T,
+    condition: Option<&Filter>,
+    update_mode: UpdateMode,
+) -> CollectionResult<(usize, usize)>
 where
     T: IntoIterator<Item = &'a PointStruct>,
 {
     let points_map: HashMap<PointIdType, &PointStruct> =
         points.into_iter().map(|p| (p.id, p)).collect();
-    let ids: Vec<PointIdType> = points_map.keys().copied().collect();
+    let mut ids: Vec<PointIdType> = points_map.keys().copied().collect();
+
+    // Existing points, which must be kept as is
+    let excluded_ids = if !update_mode.allows_update() {
+        let mut existing_ids = HashSet::new();
+        segments.read_points(&ids, |id, _segment| Ok(existing_ids.insert(id)))?;
+        existing_ids
+    } else if let Some(condition) = condition {
+        select_excluded_by_filter_ids(segments, &ids, condition)?
+    } else {
+        HashSet::new()
+    };
+    ids.retain(|id| !excluded_ids.contains(id));
+
+    let mut skipped = excluded_ids.len();
 
     // Update points in writable segments
     let updated_points = segments.apply_points_with_conditional_move(
@@ -416,6 +741,11 @@ where
         .cloned()
         .filter(|x| !(updated_points.contains(x)));
 
+    if !update_mode.allows_insert() {
+        skipped += new_point_ids.count();
+        return Ok((res, skipped));
+    }
+
     {
         let default_write_segment =
             segments
@@ -439,51 +769,43 @@ where
         RwLockWriteGuard::unlock_fair(write_segment);
     };
 
-    Ok(res)
+    Ok((res, skipped))
 }
 
 pub(crate) fn process_point_operation(
-    segments: &RwLock<SegmentHolder>,
+    segments: &SegmentHolder,
     op_num: SeqNumberType,
     point_operation: PointOperations,
 ) -> CollectionResult<usize> {
     match point_operation {
-        PointOperations::DeletePoints { ids, .. } => delete_points(&segments.read(), op_num, &ids),
+        PointOperations::DeletePoints { ids, .. } => delete_points(segments, op_num, &ids),
         PointOperations::UpsertPoints(operation) => {
-            let points: Vec<_> = match operation {
-                PointInsertOperationsInternal::PointsBatch(batch) => {
-                    let batch_vectors: BatchVectorStructInternal = batch.vectors.into();
-                    let all_vectors = batch_vectors.into_all_vectors(batch.ids.len());
-                    let vectors_iter = batch.ids.into_iter().zip(all_vectors);
-                    match batch.payloads {
-                        None => vectors_iter
-                            .map(|(id, vectors)| PointStruct {
-                                id,
-                                vector: VectorStructInternal::from(vectors).into(),
-                                payload: None,
-                            })
-                            .collect(),
-                        Some(payloads) => vectors_iter
-                            .zip(payloads)
-                            .map(|((id, vectors), payload)| PointStruct {
-                                id,
-                                vector: VectorStructInternal::from(vectors).into(),
-                                payload,
-                            })
-                            .collect(),
-                    }
-                }
-                PointInsertOperationsInternal::PointsList(points) => points,
-            };
-            let res = upsert_points(&segments.read(), op_num, points.iter())?;
+            let points = insert_operation_into_points(operation);
+            let res = upsert_points(segments, op_num, points.iter())?;
+            Ok(res)
+        }
+        PointOperations::UpsertPointsConditional(ConditionalInsertOperationInternal {
+            points_op,
+            condition,
+            update_mode,
+            idempotency_key: _,
+        }) => {
+            let points = insert_operation_into_points(points_op);
+            let res = conditional_upsert_points(
+                segments,
+                op_num,
+                points.iter(),
+                condition.as_ref(),
+                update_mode,
+            )?;
             Ok(res)
         }
         PointOperations::DeletePointsByFilter(filter) => {
-            delete_points_by_filter(&segments.read(), op_num, &filter)
+            delete_points_by_filter(segments, op_num, &filter)
         }
         PointOperations::SyncPoints(operation) => {
             let (deleted, new, updated) = sync_points(
-                &segments.read(),
+                segments,
                 op_num,
                 operation.from_id,
                 operation.to_id,
@@ -491,29 +813,81 @@ pub(crate) fn process_point_operation(
             )?;
             Ok(deleted + new + updated)
         }
+        PointOperations::TrashPoints(operation) => {
+            let mut trashed = 0;
+            if let Some(points) = operation.points {
+                trashed += trash_points(segments, op_num, &points, operation.trashed_at)?;
+            }
+            if let Some(filter) = operation.filter {
+                trashed += trash_points_by_filter(segments, op_num, &filter, operation.trashed_at)?;
+            }
+            Ok(trashed)
+        }
+        PointOperations::RestorePoints(operation) => {
+            let mut restored = 0;
+            if let Some(points) = operation.points {
+                restored += restore_points(segments, op_num, &points, operation.trashed_after)?;
+            }
+            if let Some(filter) = operation.filter {
+                restored +=
+                    restore_points_by_filter(segments, op_num, &filter, operation.trashed_after)?;
+            }
+            Ok(restored)
+        }
+    }
+}
+
+fn insert_operation_into_points(operation: PointInsertOperationsInternal) -> Vec<PointStruct> {
+    match operation {
+        PointInsertOperationsInternal::PointsBatch(batch) => {
+            let batch_vectors: BatchVectorStructInternal = batch.vectors.into();
+            let all_vectors = batch_vectors.into_all_vectors(batch.ids.len());
+            let vectors_iter = batch.ids.into_iter().zip(all_vectors);
+            match batch.payloads {
+                None => vectors_iter
+                    .map(|(id, vectors)| PointStruct {
+                        id,
+                        vector: VectorStructInternal::from(vectors).into(),
+                        payload: None,
+                    })
+                    .collect(),
+                Some(payloads) => vectors_iter
+                    .zip(payloads)
+                    .map(|((id, vectors), payload)| PointStruct {
+                        id,
+                        vector: VectorStructInternal::from(vectors).into(),
+                        payload,
+                    })
+                    .collect(),
+            }
+        }
+        PointInsertOperationsInternal::PointsList(points) => points,
     }
 }
 
 pub(crate) fn process_vector_operation(
-    segments: &RwLock<SegmentHolder>,
+    segments: &SegmentHolder,
     op_num: SeqNumberType,
     vector_operation: VectorOperations,
 ) -> CollectionResult<usize> {
     match vector_operation {
-        VectorOperations::UpdateVectors(operation) => {
-            update_vectors(&segments.read(), op_num, operation.points)
-        }
+        VectorOperations::UpdateVectors(operation) => update_vectors(
+            segments,
+            op_num,
+            operation.points,
+            operation.update_filter.as_ref(),
+        ),
         VectorOperations::DeleteVectors(ids, vector_names) => {
-            delete_vectors(&segments.read(), op_num, &ids.points, &vector_names)
+            delete_vectors(segments, op_num, &ids.points, &vector_names)
         }
         VectorOperations::DeleteVectorsByFilter(filter, vector_names) => {
-            delete_vectors_by_filter(&segments.read(), op_num, &filter, &vector_names)
+            delete_vectors_by_filter(segments, op_num, &filter, &vector_names)
         }
     }
 }
 
 pub(crate) fn process_payload_operation(
-    segments: &RwLock<SegmentHolder>,
+    segments: &SegmentHolder,
     op_num: SeqNumberType,
     payload_operation: PayloadOps,
 ) -> CollectionResult<usize> {
@@ -521,9 +895,9 @@ pub(crate) fn process_payload_operation(
         PayloadOps::SetPayload(sp) => {
             let payload: Payload = sp.payload;
             if let Some(points) = sp.points {
-                set_payload(&segments.read(), op_num, &payload, &points, &sp.key)
+                set_payload(segments, op_num, &payload, &points, &sp.key)
             } else if let Some(filter) = sp.filter {
-                set_payload_by_filter(&segments.read(), op_num, &payload, &filter, &sp.key)
+                set_payload_by_filter(segments, op_num, &payload, &filter, &sp.key)
             } else {
                 Err(CollectionError::BadRequest {
                     description: "No points or filter specified".to_string(),
@@ -532,27 +906,36 @@ pub(crate) fn process_payload_operation(
         }
         PayloadOps::DeletePayload(dp) => {
             if let Some(points) = dp.points {
-                delete_payload(&segments.read(), op_num, &points, &dp.keys)
+                delete_payload(segments, op_num, &points, &dp.keys)
             } else if let Some(filter) = dp.filter {
-                delete_payload_by_filter(&segments.read(), op_num, &filter, &dp.keys)
+                delete_payload_by_filter(segments, op_num, &filter, &dp.keys)
             } else {
                 Err(CollectionError::BadRequest {
                     description: "No points or filter specified".to_string(),
                 })
             }
         }
-        PayloadOps::ClearPayload { ref points, .. } => {
-            clear_payload(&segments.read(), op_num, points)
-        }
+        PayloadOps::ClearPayload { ref points, .. } => clear_payload(segments, op_num, points),
         PayloadOps::ClearPayloadByFilter(ref filter) => {
-            clear_payload_by_filter(&segments.read(), op_num, filter)
+            clear_payload_by_filter(segments, op_num, filter)
         }
         PayloadOps::OverwritePayload(sp) => {
             let payload: Payload = sp.payload;
             if let Some(points) = sp.points {
-                overwrite_payload(&segments.read(), op_num, &payload, &points)
+                overwrite_payload(segments, op_num, &payload, &points)
             } else if let Some(filter) = sp.filter {
-                overwrite_payload_by_filter(&segments.read(), op_num, &payload, &filter)
+                overwrite_payload_by_filter(segments, op_num, &payload, &filter)
+            } else {
+                Err(CollectionError::BadRequest {
+                    description: "No points or filter specified".to_string(),
+                })
+            }
+        }
+        PayloadOps::MutatePayload(mp) => {
+            if let Some(points) = mp.points {
+                mutate_payload(segments, op_num, &mp.mutations, &points)
+            } else if let Some(filter) = mp.filter {
+                mutate_payload_by_filter(segments, op_num, &mp.mutations, &filter)
             } else {
                 Err(CollectionError::BadRequest {
                     description: "No points or filter specified".to_string(),
@@ -563,19 +946,19 @@ pub(crate) fn process_payload_operation(
 }
 
 pub(crate) fn process_field_index_operation(
-    segments: &RwLock<SegmentHolder>,
+    segments: &SegmentHolder,
     op_num: SeqNumberType,
     field_index_operation: &FieldIndexOperations,
 ) -> CollectionResult<usize> {
     match field_index_operation {
         FieldIndexOperations::CreateIndex(index_data) => create_field_index(
-            &segments.read(),
+            segments,
             op_num,
             &index_data.field_name,
             index_data.field_schema.as_ref(),
         ),
         FieldIndexOperations::DeleteIndex(field_name) => {
-            delete_field_index(&segments.read(), op_num, field_name)
+            delete_field_index(segments, op_num, field_name)
         }
     }
 }
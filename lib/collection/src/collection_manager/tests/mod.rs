@@ -8,7 +8,12 @@ use itertools::Itertools;
 use parking_lot::RwLock;
 use segment::data_types::vectors::{only_default_vector, VectorStructInternal};
 use segment::entry::entry_point::SegmentEntry;
-use segment::types::{PayloadFieldSchema, PayloadKeyType, PointIdType};
+use segment::json_path::path;
+use segment::types::{
+    Condition, FieldCondition, Filter, Match, Payload, PayloadFieldSchema, PayloadKeyType,
+    PointIdType,
+};
+use serde_json::json;
 use tempfile::Builder;
 
 use crate::collection_manager::fixtures::{build_segment_1, build_segment_2, empty_segment};
@@ -16,8 +21,8 @@ use crate::collection_manager::holders::proxy_segment::ProxySegment;
 use crate::collection_manager::holders::segment_holder::{
     LockedSegment, LockedSegmentHolder, SegmentHolder, SegmentId,
 };
-use crate::collection_manager::segments_updater::upsert_points;
-use crate::operations::point_ops::PointStruct;
+use crate::collection_manager::segments_updater::{conditional_upsert_points, upsert_points};
+use crate::operations::point_ops::{PointStruct, UpdateMode};
 
 fn wrap_proxy(segments: LockedSegmentHolder, sid: SegmentId, path: &Path) -> SegmentId {
     let mut write_segments = segments.write();
@@ -233,3 +238,102 @@ fn test_upsert_points_in_smallest_segment() {
         }
     }
 }
+
+#[test]
+fn test_conditional_upsert_points() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+
+    let mut holder = SegmentHolder::default();
+    let sid1 = holder.add_new(build_segment_1(dir.path()));
+    let segments = Arc::new(RwLock::new(holder));
+
+    let green: Payload = json!({ "color": "green" }).into();
+    let points: Vec<_> = [1, 3, 100]
+        .into_iter()
+        .map(|id| PointStruct {
+            id: id.into(),
+            vector: VectorStructInternal::from(vec![0.0, 0.0, 0.0, 0.0]).into(),
+            payload: Some(green.clone()),
+        })
+        .collect();
+
+    // Only overwrite blue points
+    let condition = Filter::new_must(Condition::Field(FieldCondition::new_match(
+        path("color"),
+        Match::from("blue".to_string()),
+    )));
+
+    conditional_upsert_points(
+        &segments.read(),
+        1000,
+        &points,
+        Some(&condition),
+        UpdateMode::Upsert,
+    )
+    .unwrap();
+
+    let segment = segments.read().get(sid1).unwrap().get();
+    let segment = segment.read();
+
+    // Point 1 is red, so it is left intact
+    assert_eq!(
+        segment.payload(1.into()).unwrap(),
+        Payload::from(json!({ "color": ["red"] })),
+    );
+    // Point 3 is blue, so it is overwritten
+    assert_eq!(segment.payload(3.into()).unwrap(), green);
+    // Point 100 did not exist, so it is inserted
+    assert_eq!(segment.payload(100.into()).unwrap(), green);
+}
+
+#[test]
+fn test_upsert_points_update_mode() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+
+    let mut holder = SegmentHolder::default();
+    let sid1 = holder.add_new(build_segment_1(dir.path()));
+    let segments = Arc::new(RwLock::new(holder));
+
+    let green: Payload = json!({ "color": "green" }).into();
+    let yellow: Payload = json!({ "color": "yellow" }).into();
+    let red: Payload = json!({ "color": ["red"] }).into();
+
+    let make_points = |ids: &[u64], payload: &Payload| -> Vec<PointStruct> {
+        ids.iter()
+            .map(|&id| PointStruct {
+                id: id.into(),
+                vector: VectorStructInternal::from(vec![0.0, 0.0, 0.0, 0.0]).into(),
+                payload: Some(payload.clone()),
+            })
+            .collect()
+    };
+
+    // Point 1 exists, so only point 100 is inserted
+    let applied = conditional_upsert_points(
+        &segments.read(),
+        1000,
+        &make_points(&[1, 100], &green),
+        None,
+        UpdateMode::InsertOnly,
+    )
+    .unwrap();
+    assert_eq!(applied, 1);
+
+    // Point 101 doesn't exist, so only point 100 is updated
+    let applied = conditional_upsert_points(
+        &segments.read(),
+        1001,
+        &make_points(&[100, 101], &yellow),
+        None,
+        UpdateMode::UpdateOnly,
+    )
+    .unwrap();
+    assert_eq!(applied, 1);
+
+    let segment = segments.read().get(sid1).unwrap().get();
+    let segment = segment.read();
+
+    assert_eq!(segment.payload(1.into()).unwrap(), red);
+    assert_eq!(segment.payload(100.into()).unwrap(), yellow);
+    assert!(!segment.has_point(101.into()));
+}
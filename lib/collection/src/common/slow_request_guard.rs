@@ -0,0 +1,99 @@
+use std::future::Future;
+use std::mem;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::events::{
+    SlowRequestDetails, SlowRequestEvent, SlowRequestType, SLOW_REQUEST_LOG_THRESHOLD,
+};
+use crate::operations::types::CollectionResult;
+use crate::shards::CollectionId;
+
+tokio::task_local! {
+    /// Number of segments read by the tracked request, which is executed in the current task
+    static SEGMENTS_TOUCHED: Arc<AtomicUsize>;
+}
+
+/// Count segments, read on behalf of the current request.
+///
+/// No-op, if the request is not tracked by [`SlowRequestGuard`].
+pub fn add_segments_touched(count: usize) {
+    let _ = SEGMENTS_TOUCHED.try_with(|touched| touched.fetch_add(count, Ordering::Relaxed));
+}
+
+/// Structure that publishes [`SlowRequestEvent`] when dropped, if the request took longer than
+/// the slow request threshold.
+///
+/// Same as `ScopeDurationMeasurer` used for requests telemetry, the request is considered failed
+/// unless it has completed successfully. So requests, that returned an error, timed out or were
+/// cancelled, are logged as well.
+pub struct SlowRequestGuard {
+    collection_id: CollectionId,
+    request_type: SlowRequestType,
+    requests: Vec<SlowRequestDetails>,
+    segments_touched: Arc<AtomicUsize>,
+    threshold: Duration,
+    instant: Instant,
+    success: bool,
+}
+
+impl SlowRequestGuard {
+    /// Start measuring the request, returns `None` if slow request log is disabled.
+    ///
+    /// `requests` is only evaluated, if slow request log is enabled.
+    pub fn new(
+        collection_id: &CollectionId,
+        request_type: SlowRequestType,
+        requests: impl FnOnce() -> Vec<SlowRequestDetails>,
+    ) -> Option<Self> {
+        let threshold = *SLOW_REQUEST_LOG_THRESHOLD.get()?;
+
+        Some(Self {
+            collection_id: collection_id.clone(),
+            request_type,
+            requests: requests(),
+            segments_touched: Arc::new(AtomicUsize::new(0)),
+            threshold,
+            instant: Instant::now(),
+            success: false,
+        })
+    }
+
+    /// Execute `request`, counting segments it reads locally
+    pub async fn track<T>(
+        guard: Option<Self>,
+        request: impl Future<Output = CollectionResult<T>>,
+    ) -> CollectionResult<T> {
+        let Some(mut guard) = guard else {
+            return request.await;
+        };
+
+        let result = SEGMENTS_TOUCHED
+            .scope(guard.segments_touched.clone(), request)
+            .await;
+
+        guard.success = result.is_ok();
+
+        result
+    }
+}
+
+impl Drop for SlowRequestGuard {
+    fn drop(&mut self) {
+        let duration = self.instant.elapsed();
+
+        if duration <= self.threshold {
+            return;
+        }
+
+        issues::publish(SlowRequestEvent {
+            collection_id: mem::take(&mut self.collection_id),
+            request_type: self.request_type,
+            requests: mem::take(&mut self.requests),
+            segments_touched: self.segments_touched.load(Ordering::Relaxed),
+            duration,
+            success: self.success,
+        });
+    }
+}
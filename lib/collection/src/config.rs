@@ -6,15 +6,19 @@ use std::path::Path;
 
 use atomicwrites::AtomicFile;
 use atomicwrites::OverwriteBehavior::AllowOverwrite;
+use chrono::{DateTime, Utc};
+use merge::Merge;
 use schemars::JsonSchema;
 use segment::common::anonymize::Anonymize;
 use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
 use segment::index::sparse_index::sparse_index_config::{SparseIndexConfig, SparseIndexType};
+use segment::json_path::JsonPath;
 use segment::types::{
     default_replication_factor_const, default_shard_number_const,
-    default_write_consistency_factor_const, Distance, HnswConfig, Indexes, PayloadStorageType,
-    QuantizationConfig, SparseVectorDataConfig, VectorDataConfig, VectorStorageDatatype,
-    VectorStorageType,
+    default_write_consistency_factor_const, Condition, Distance, FieldCondition, FloatPayloadType,
+    HnswConfig, Indexes, PayloadFieldSchema, PayloadSchemaType, PayloadStorageType,
+    QuantizationConfig, Range, SparseVectorDataConfig, SparseVectorStorageType, VectorDataConfig,
+    VectorStorageDatatype, VectorStorageType,
 };
 use serde::{Deserialize, Serialize};
 use validator::Validate;
@@ -57,6 +61,77 @@ impl Default for WalConfig {
     }
 }
 
+/// Guardrails against expensive requests, checked before a request reaches the shards
+#[derive(
+    Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq, Hash, Default, Merge,
+)]
+#[serde(rename_all = "snake_case")]
+pub struct StrictModeConfig {
+    /// Whether strict mode is enabled for the collection. Default: false
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Max allowed `limit` of search, query, scroll and other requests returning points
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub max_query_limit: Option<usize>,
+    /// Max allowed `offset` of search, query and scroll requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_query_offset: Option<usize>,
+    /// Max allowed `timeout` of a request, in seconds. Also applies to requests without a timeout.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub max_timeout: Option<usize>,
+    /// Allow filtering on unindexed fields in search, query, scroll and count requests. Default: true
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unindexed_filtering_retrieve: Option<bool>,
+    /// Allow filtering on unindexed fields in updates, like delete by filter. Default: true
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unindexed_filtering_update: Option<bool>,
+    /// Max allowed `hnsw_ef` in search params
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search_max_hnsw_ef: Option<usize>,
+}
+
+impl StrictModeConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.unwrap_or(false)
+    }
+}
+
+/// Rules for payload values, checked before points are upserted or their payload is set
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct PayloadValidationConfig {
+    /// Rules for payload fields, by path of the field.
+    /// Fields without a rule accept any value.
+    #[serde(default)]
+    pub fields: HashMap<JsonPath, PayloadFieldRule>,
+}
+
+/// Rule for values of a single payload field
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct PayloadFieldRule {
+    /// Reject points without a value in this field. Default: false
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+    /// Type of the field values. Values of an array are checked one by one.
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub data_type: Option<PayloadSchemaType>,
+    /// Values allowed in the field
+    #[serde(default, rename = "enum", skip_serializing_if = "Option::is_none")]
+    pub allowed_values: Option<Vec<serde_json::Value>>,
+    /// Range of allowed numeric values of the field
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range: Option<Range<FloatPayloadType>>,
+}
+
+impl PayloadFieldRule {
+    pub fn is_required(&self) -> bool {
+        self.required.unwrap_or(false)
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq, Hash, Clone, Copy, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum ShardingMethod {
@@ -106,6 +181,16 @@ pub struct CollectionParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[validate]
     pub sparse_vectors: Option<BTreeMap<String, SparseVectorParams>>,
+    /// Payload field with the expiry time of each point, as an RFC 3339 datetime.
+    /// Expired points are hidden from all reads and removed in background.
+    /// Points without this field never expire.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiry_field: Option<JsonPath>,
+    /// If set, deleted points are moved to trash instead of being removed right away.
+    /// Trashed points are hidden from all reads and can be restored within the given number of seconds,
+    /// after that they are purged by the optimizer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trash_retention_sec: Option<u64>,
 }
 
 impl CollectionParams {
@@ -116,6 +201,41 @@ impl CollectionParams {
             PayloadStorageType::InMemory
         }
     }
+
+    /// Condition, which matches points expired at the given time.
+    /// `None` if points of the collection never expire.
+    pub fn expired_condition(&self, now: DateTime<Utc>) -> Option<Condition> {
+        let expiry_field = self.expiry_field.clone()?;
+        Some(Condition::Field(FieldCondition::new_datetime_range(
+            expiry_field,
+            Range {
+                lte: Some(now.into()),
+                ..Default::default()
+            },
+        )))
+    }
+
+    /// Payload indexes, which must exist for the configured params.
+    /// Expired points are selected by a datetime range on the expiry field, which requires an index.
+    pub fn required_payload_indexes(&self) -> Vec<(JsonPath, PayloadFieldSchema)> {
+        self.expiry_field
+            .iter()
+            .map(|field| {
+                (
+                    field.clone(),
+                    PayloadFieldSchema::FieldType(PayloadSchemaType::Datetime),
+                )
+            })
+            .collect()
+    }
+
+    /// Points moved to trash after the returned time are still retained at the given time.
+    /// Older ones can't be restored anymore and are purged by the optimizer.
+    /// `None` if trash is not enabled for the collection.
+    pub fn trash_retained_after(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let retention = chrono::Duration::seconds(self.trash_retention_sec? as i64);
+        Some(now - retention)
+    }
 }
 
 impl Anonymize for CollectionParams {
@@ -129,6 +249,8 @@ impl Anonymize for CollectionParams {
             read_fan_out_factor: self.read_fan_out_factor,
             on_disk_payload: self.on_disk_payload,
             sparse_vectors: self.sparse_vectors.anonymize(),
+            expiry_field: self.expiry_field.anonymize(),
+            trash_retention_sec: self.trash_retention_sec,
         }
     }
 }
@@ -161,6 +283,11 @@ pub struct CollectionConfig {
     pub wal_config: WalConfig,
     #[serde(default)]
     pub quantization_config: Option<QuantizationConfig>,
+    #[serde(default)]
+    #[validate]
+    pub strict_mode_config: Option<StrictModeConfig>,
+    #[serde(default)]
+    pub payload_validation_config: Option<PayloadValidationConfig>,
 }
 
 impl CollectionConfig {
@@ -206,6 +333,8 @@ impl CollectionParams {
             read_fan_out_factor: None,
             on_disk_payload: default_on_disk_payload(),
             sparse_vectors: None,
+            expiry_field: None,
+            trash_retention_sec: None,
         }
     }
 
@@ -353,6 +482,7 @@ impl CollectionParams {
                         index: Indexes::Plain {},
                         // Disabled quantization
                         quantization_config: None,
+                        additional_quantization_configs: Vec::new(),
                         // Default to in memory storage
                         storage_type: if params.on_disk.unwrap_or_default() {
                             VectorStorageType::ChunkedMmap
@@ -361,6 +491,7 @@ impl CollectionParams {
                         },
                         multivector_config: params.multivector_config,
                         datatype: params.datatype.map(VectorStorageDatatype::from),
+                        prefix_of: params.prefix_of.clone(),
                     },
                 )
             })
@@ -391,6 +522,7 @@ impl CollectionParams {
                                     .and_then(|index| index.datatype)
                                     .map(VectorStorageDatatype::from),
                             },
+                            storage_type: SparseVectorStorageType::default(),
                         },
                     ))
                 })
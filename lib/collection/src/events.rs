@@ -1,10 +1,17 @@
 use std::collections::HashMap;
+use std::sync::OnceLock;
+use std::time::Duration;
 
 use segment::json_path::JsonPathV2;
 use segment::types::{Filter, PayloadFieldSchema};
 
 use crate::shards::CollectionId;
 
+/// Requests taking longer than this are published as [`SlowRequestEvent`]s.
+///
+/// Slow request log is disabled, if not set.
+pub static SLOW_REQUEST_LOG_THRESHOLD: OnceLock<Duration> = OnceLock::new();
+
 pub struct CollectionDeletedEvent {
     pub collection_id: CollectionId,
 }
@@ -15,6 +22,33 @@ pub struct SlowQueryEvent {
     pub schema: HashMap<JsonPathV2, PayloadFieldSchema>,
 }
 
+pub struct SlowRequestEvent {
+    pub collection_id: CollectionId,
+    pub request_type: SlowRequestType,
+    /// Individual requests of the batch
+    pub requests: Vec<SlowRequestDetails>,
+    /// Number of segments read on this peer while executing the request
+    pub segments_touched: usize,
+    /// Time taken by the whole batch
+    pub duration: Duration,
+    /// Whether the request completed successfully.
+    /// Failed, timed out and cancelled requests are reported as well.
+    pub success: bool,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SlowRequestType {
+    Search,
+    Query,
+    Scroll,
+}
+
+pub struct SlowRequestDetails {
+    pub filters: Vec<Filter>,
+    pub limit: usize,
+    pub hnsw_ef: Option<usize>,
+}
+
 pub struct IndexCreatedEvent {
     pub collection_id: CollectionId,
     pub field_name: JsonPathV2,
@@ -203,6 +203,7 @@ mod unit_tests {
             vector: None,
             shard_key: None,
             order_value: None,
+            then_order_values: Vec::new(),
         }
     }
 
@@ -215,6 +216,7 @@ mod unit_tests {
             vector: None,
             shard_key: None,
             order_value: None,
+            then_order_values: Vec::new(),
         }
     }
 
@@ -2,13 +2,14 @@ use std::num::NonZeroU32;
 
 use merge::Merge;
 use schemars::JsonSchema;
+use segment::json_path::JsonPath;
 use segment::types::{BinaryQuantization, HnswConfig, ProductQuantization, ScalarQuantization};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use validator::{Validate, ValidationErrors};
 
-use crate::config::{CollectionParams, WalConfig};
+use crate::config::{CollectionParams, StrictModeConfig, WalConfig};
 use crate::operations::types::CollectionResult;
 use crate::optimizers_builder::OptimizersConfig;
 
@@ -110,6 +111,10 @@ pub struct CollectionParamsDiff {
     /// Note: those payload values that are involved in filtering and are indexed - remain in RAM.
     #[serde(default)]
     pub on_disk_payload: Option<bool>,
+    /// Payload field with the expiry time of each point, as an RFC 3339 datetime.
+    /// Expired points are hidden from all reads and removed in background.
+    #[serde(default)]
+    pub expiry_field: Option<JsonPath>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, Merge)]
@@ -203,6 +208,8 @@ impl DiffConfig<WalConfig> for WalConfigDiff {}
 
 impl DiffConfig<CollectionParams> for CollectionParamsDiff {}
 
+impl DiffConfig<StrictModeConfig> for StrictModeConfig {}
+
 impl From<HnswConfig> for HnswConfigDiff {
     fn from(config: HnswConfig) -> Self {
         HnswConfigDiff::from_full(&config).unwrap()
@@ -348,6 +355,7 @@ mod tests {
             write_consistency_factor: Some(NonZeroU32::new(2).unwrap()),
             read_fan_out_factor: None,
             on_disk_payload: None,
+            expiry_field: None,
         };
 
         let new_params = diff.update(&params).unwrap();
@@ -4,8 +4,10 @@ use std::time::Duration;
 
 use api::grpc::conversions::{
     convert_shard_key_from_grpc, convert_shard_key_from_grpc_opt, convert_shard_key_to_grpc,
-    from_grpc_dist, json_path_from_proto, payload_to_proto, proto_to_payloads,
+    from_grpc_dist, json_path_from_proto, json_to_proto, payload_to_proto, proto_to_json,
+    proto_to_payloads,
 };
+use api::grpc::qdrant::payload_mutation::Mutation;
 use api::grpc::qdrant::quantization_config_diff::Quantization;
 use api::grpc::qdrant::update_collection_cluster_setup_request::{
     Operation as ClusterOperationsPb, Operation,
@@ -19,7 +21,9 @@ use segment::data_types::vectors::{
     BatchVectorStructInternal, Named, NamedQuery, NamedVectorStruct, Vector, VectorStructInternal,
     DEFAULT_VECTOR_NAME,
 };
-use segment::types::{Distance, MultiVectorConfig, QuantizationConfig, ScoredPoint};
+use segment::types::{
+    Distance, MultiVectorConfig, PayloadSchemaType, QuantizationConfig, Range, ScoredPoint,
+};
 use segment::vector_storage::query::{ContextPair, ContextQuery, DiscoveryQuery, RecoQuery};
 use sparse::common::sparse_vector::{validate_sparse_vector_impl, SparseVector};
 use tonic::Status;
@@ -32,7 +36,8 @@ use super::types::{
 };
 use crate::config::{
     default_replication_factor, default_write_consistency_factor, CollectionConfig,
-    CollectionParams, ShardingMethod, WalConfig,
+    CollectionParams, PayloadFieldRule, PayloadValidationConfig, ShardingMethod, StrictModeConfig,
+    WalConfig,
 };
 use crate::lookup::types::WithLookupInterface;
 use crate::lookup::WithLookup;
@@ -46,16 +51,17 @@ use crate::operations::config_diff::{
     CollectionParamsDiff, HnswConfigDiff, OptimizersConfigDiff, QuantizationConfigDiff,
     WalConfigDiff,
 };
+use crate::operations::payload_ops::PayloadMutation;
 use crate::operations::point_ops::PointsSelector::PointIdsSelector;
 use crate::operations::point_ops::{
-    Batch, FilterSelector, PointIdsList, PointStruct, PointsSelector, WriteOrdering,
+    Batch, FilterSelector, PointIdsList, PointStruct, PointsSelector, UpdateMode, WriteOrdering,
 };
 use crate::operations::query_enum::QueryEnum;
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
 use crate::operations::types::{
     AliasDescription, CollectionClusterInfo, CollectionInfo, CollectionStatus, CountResult,
-    LocalShardInfo, OptimizersStatus, RecommendRequestInternal, Record, RemoteShardInfo,
-    ShardTransferInfo, UpdateResult, UpdateStatus, VectorParams, VectorsConfig,
+    DryRunResult, LocalShardInfo, OptimizersStatus, RecommendRequestInternal, Record,
+    RemoteShardInfo, ShardTransferInfo, UpdateResult, UpdateStatus, VectorParams, VectorsConfig,
 };
 use crate::optimizers_builder::OptimizersConfig;
 use crate::shards::remote_shard::{CollectionCoreSearchRequest, CollectionSearchRequest};
@@ -262,6 +268,154 @@ impl From<api::grpc::qdrant::WalConfigDiff> for WalConfigDiff {
     }
 }
 
+impl From<api::grpc::qdrant::StrictModeConfig> for StrictModeConfig {
+    fn from(value: api::grpc::qdrant::StrictModeConfig) -> Self {
+        Self {
+            enabled: value.enabled,
+            max_query_limit: value.max_query_limit.map(|v| v as usize),
+            max_query_offset: value.max_query_offset.map(|v| v as usize),
+            max_timeout: value.max_timeout.map(|v| v as usize),
+            unindexed_filtering_retrieve: value.unindexed_filtering_retrieve,
+            unindexed_filtering_update: value.unindexed_filtering_update,
+            search_max_hnsw_ef: value.search_max_hnsw_ef.map(|v| v as usize),
+        }
+    }
+}
+
+impl From<StrictModeConfig> for api::grpc::qdrant::StrictModeConfig {
+    fn from(value: StrictModeConfig) -> Self {
+        Self {
+            enabled: value.enabled,
+            max_query_limit: value.max_query_limit.map(|v| v as u64),
+            max_query_offset: value.max_query_offset.map(|v| v as u64),
+            max_timeout: value.max_timeout.map(|v| v as u64),
+            unindexed_filtering_retrieve: value.unindexed_filtering_retrieve,
+            unindexed_filtering_update: value.unindexed_filtering_update,
+            search_max_hnsw_ef: value.search_max_hnsw_ef.map(|v| v as u64),
+        }
+    }
+}
+
+impl TryFrom<api::grpc::qdrant::PayloadValidationConfig> for PayloadValidationConfig {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::PayloadValidationConfig) -> Result<Self, Self::Error> {
+        let fields = value
+            .fields
+            .into_iter()
+            .map(|(path, rule)| Ok((json_path_from_proto(&path)?, rule.try_into()?)))
+            .collect::<Result<_, Status>>()?;
+        Ok(Self { fields })
+    }
+}
+
+impl From<PayloadValidationConfig> for api::grpc::qdrant::PayloadValidationConfig {
+    fn from(value: PayloadValidationConfig) -> Self {
+        Self {
+            fields: value
+                .fields
+                .into_iter()
+                .map(|(path, rule)| (path.to_string(), rule.into()))
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<api::grpc::qdrant::PayloadFieldRule> for PayloadFieldRule {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::PayloadFieldRule) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::PayloadFieldRule {
+            required,
+            data_type,
+            allowed_values,
+            range,
+        } = value;
+
+        let data_type = data_type
+            .map(|data_type| {
+                use api::grpc::qdrant::PayloadSchemaType as GrpcPayloadSchemaType;
+                match GrpcPayloadSchemaType::from_i32(data_type) {
+                    Some(GrpcPayloadSchemaType::Keyword) => Ok(PayloadSchemaType::Keyword),
+                    Some(GrpcPayloadSchemaType::Integer) => Ok(PayloadSchemaType::Integer),
+                    Some(GrpcPayloadSchemaType::Float) => Ok(PayloadSchemaType::Float),
+                    Some(GrpcPayloadSchemaType::Geo) => Ok(PayloadSchemaType::Geo),
+                    Some(GrpcPayloadSchemaType::Text) => Ok(PayloadSchemaType::Text),
+                    Some(GrpcPayloadSchemaType::Bool) => Ok(PayloadSchemaType::Bool),
+                    Some(GrpcPayloadSchemaType::Datetime) => Ok(PayloadSchemaType::Datetime),
+                    Some(GrpcPayloadSchemaType::UnknownType) | None => Err(
+                        Status::invalid_argument(format!("Unknown payload type: {data_type}")),
+                    ),
+                }
+            })
+            .transpose()?;
+
+        let allowed_values = if allowed_values.is_empty() {
+            None
+        } else {
+            Some(
+                allowed_values
+                    .into_iter()
+                    .map(proto_to_json)
+                    .collect::<Result<_, _>>()?,
+            )
+        };
+
+        Ok(Self {
+            required,
+            data_type,
+            allowed_values,
+            range: range.map(|range| Range {
+                lt: range.lt,
+                gt: range.gt,
+                gte: range.gte,
+                lte: range.lte,
+            }),
+        })
+    }
+}
+
+impl From<PayloadFieldRule> for api::grpc::qdrant::PayloadFieldRule {
+    fn from(value: PayloadFieldRule) -> Self {
+        let PayloadFieldRule {
+            required,
+            data_type,
+            allowed_values,
+            range,
+        } = value;
+
+        let data_type = data_type.map(|data_type| {
+            use api::grpc::qdrant::PayloadSchemaType as GrpcPayloadSchemaType;
+            let data_type = match data_type {
+                PayloadSchemaType::Keyword => GrpcPayloadSchemaType::Keyword,
+                PayloadSchemaType::Integer => GrpcPayloadSchemaType::Integer,
+                PayloadSchemaType::Float => GrpcPayloadSchemaType::Float,
+                PayloadSchemaType::Geo => GrpcPayloadSchemaType::Geo,
+                PayloadSchemaType::Text => GrpcPayloadSchemaType::Text,
+                PayloadSchemaType::Bool => GrpcPayloadSchemaType::Bool,
+                PayloadSchemaType::Datetime => GrpcPayloadSchemaType::Datetime,
+            };
+            data_type as i32
+        });
+
+        Self {
+            required,
+            data_type,
+            allowed_values: allowed_values
+                .unwrap_or_default()
+                .into_iter()
+                .map(json_to_proto)
+                .collect(),
+            range: range.map(|range| api::grpc::qdrant::PayloadFieldRange {
+                lt: range.lt,
+                gt: range.gt,
+                gte: range.gte,
+                lte: range.lte,
+            }),
+        }
+    }
+}
+
 impl TryFrom<api::grpc::qdrant::CollectionParamsDiff> for CollectionParamsDiff {
     type Error = Status;
 
@@ -284,6 +438,11 @@ impl TryFrom<api::grpc::qdrant::CollectionParamsDiff> for CollectionParamsDiff {
                 .transpose()?,
             read_fan_out_factor: value.read_fan_out_factor,
             on_disk_payload: value.on_disk_payload,
+            expiry_field: value
+                .expiry_field
+                .as_deref()
+                .map(json_path_from_proto)
+                .transpose()?,
         })
     }
 }
@@ -385,6 +544,8 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
                     write_consistency_factor: Some(config.params.write_consistency_factor.get()),
                     read_fan_out_factor: config.params.read_fan_out_factor,
                     sharding_method: config.params.sharding_method.map(sharding_method_to_proto),
+                    expiry_field: config.params.expiry_field.map(|field| field.to_string()),
+                    trash_retention_sec: config.params.trash_retention_sec,
                     sparse_vectors_config: config.params.sparse_vectors.map(|sparse_vectors| {
                         api::grpc::qdrant::SparseVectorConfig {
                             map: sparse_vectors
@@ -429,6 +590,8 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
                     wal_segments_ahead: Some(config.wal_config.wal_segments_ahead as u64),
                 }),
                 quantization_config: config.quantization_config.map(|x| x.into()),
+                strict_mode_config: config.strict_mode_config.map(Into::into),
+                payload_validation_config: config.payload_validation_config.map(Into::into),
             }),
             payload_schema: payload_schema
                 .into_iter()
@@ -549,12 +712,18 @@ impl TryFrom<api::grpc::qdrant::VectorParams> for VectorParams {
                 .quantization_config
                 .map(grpc_to_segment_quantization_config)
                 .transpose()?,
+            additional_quantization_configs: vector_params
+                .additional_quantization_configs
+                .into_iter()
+                .map(grpc_to_segment_quantization_config)
+                .collect::<Result<_, _>>()?,
             on_disk: vector_params.on_disk,
             datatype: convert_datatype_from_proto(vector_params.datatype)?,
             multivector_config: vector_params
                 .multivector_config
                 .map(MultiVectorConfig::try_from)
                 .transpose()?,
+            prefix_of: vector_params.prefix_of,
         })
     }
 }
@@ -567,6 +736,9 @@ fn convert_datatype_from_proto(datatype: Option<i32>) -> Result<Option<Datatype>
                 api::grpc::qdrant::Datatype::Uint8 => Ok(Some(Datatype::Uint8)),
                 api::grpc::qdrant::Datatype::Float32 => Ok(Some(Datatype::Float32)),
                 api::grpc::qdrant::Datatype::Float16 => Ok(Some(Datatype::Float16)),
+                api::grpc::qdrant::Datatype::Bit => Ok(Some(Datatype::Bit)),
+                api::grpc::qdrant::Datatype::BFloat16 => Ok(Some(Datatype::BFloat16)),
+                api::grpc::qdrant::Datatype::Int8 => Ok(Some(Datatype::Int8)),
                 api::grpc::qdrant::Datatype::Default => Ok(None),
             }
         } else {
@@ -739,6 +911,12 @@ impl TryFrom<api::grpc::qdrant::CollectionConfig> for CollectionConfig {
                         .sharding_method
                         .map(sharding_method_from_proto)
                         .transpose()?,
+                    expiry_field: params
+                        .expiry_field
+                        .as_deref()
+                        .map(json_path_from_proto)
+                        .transpose()?,
+                    trash_retention_sec: params.trash_retention_sec,
                 },
             },
             hnsw_config: match config.hnsw_config {
@@ -760,6 +938,11 @@ impl TryFrom<api::grpc::qdrant::CollectionConfig> for CollectionConfig {
                     None
                 }
             },
+            strict_mode_config: config.strict_mode_config.map(Into::into),
+            payload_validation_config: config
+                .payload_validation_config
+                .map(TryInto::try_into)
+                .transpose()?,
         })
     }
 }
@@ -926,10 +1109,36 @@ impl From<UpdateResult> for api::grpc::qdrant::UpdateResultInternal {
             operation_id: res.operation_id,
             status: res.status.into(),
             clock_tag: res.clock_tag.map(Into::into),
+            skipped: res.skipped.map(|skipped| skipped as u64),
+            dry_run: res.dry_run.map(Into::into),
+        }
+    }
+}
+
+impl From<DryRunResult> for api::grpc::qdrant::DryRunResult {
+    fn from(res: DryRunResult) -> Self {
+        Self {
+            affected_points: res.affected_points as u64,
+            sample: res.sample.into_iter().map(Into::into).collect(),
         }
     }
 }
 
+impl TryFrom<api::grpc::qdrant::DryRunResult> for DryRunResult {
+    type Error = Status;
+
+    fn try_from(res: api::grpc::qdrant::DryRunResult) -> Result<Self, Self::Error> {
+        Ok(Self {
+            affected_points: res.affected_points as usize,
+            sample: res
+                .sample
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
 impl From<UpdateResult> for api::grpc::qdrant::UpdateResult {
     fn from(res: UpdateResult) -> Self {
         api::grpc::qdrant::UpdateResultInternal::from(res).into()
@@ -944,6 +1153,8 @@ impl TryFrom<api::grpc::qdrant::UpdateResultInternal> for UpdateResult {
             operation_id: res.operation_id,
             status: res.status.try_into()?,
             clock_tag: res.clock_tag.map(Into::into),
+            skipped: res.skipped.map(|skipped| skipped as usize),
+            dry_run: res.dry_run.map(TryInto::try_into).transpose()?,
         };
 
         Ok(res)
@@ -964,6 +1175,7 @@ impl From<UpdateStatus> for i32 {
             UpdateStatus::Acknowledged => api::grpc::qdrant::UpdateStatus::Acknowledged as i32,
             UpdateStatus::Completed => api::grpc::qdrant::UpdateStatus::Completed as i32,
             UpdateStatus::ClockRejected => api::grpc::qdrant::UpdateStatus::ClockRejected as i32,
+            UpdateStatus::DryRun => api::grpc::qdrant::UpdateStatus::DryRun as i32,
         }
     }
 }
@@ -979,6 +1191,7 @@ impl TryFrom<i32> for UpdateStatus {
             api::grpc::qdrant::UpdateStatus::Acknowledged => Self::Acknowledged,
             api::grpc::qdrant::UpdateStatus::Completed => Self::Completed,
             api::grpc::qdrant::UpdateStatus::ClockRejected => Self::ClockRejected,
+            api::grpc::qdrant::UpdateStatus::DryRun => Self::DryRun,
 
             api::grpc::qdrant::UpdateStatus::UnknownUpdateStatus => {
                 return Err(Status::invalid_argument(
@@ -991,6 +1204,127 @@ impl TryFrom<i32> for UpdateStatus {
     }
 }
 
+impl From<UpdateMode> for api::grpc::qdrant::UpdateMode {
+    fn from(mode: UpdateMode) -> Self {
+        match mode {
+            UpdateMode::Upsert => Self::Upsert,
+            UpdateMode::InsertOnly => Self::InsertOnly,
+            UpdateMode::UpdateOnly => Self::UpdateOnly,
+        }
+    }
+}
+
+impl TryFrom<i32> for UpdateMode {
+    type Error = Status;
+
+    fn try_from(mode: i32) -> Result<Self, Self::Error> {
+        let mode = api::grpc::qdrant::UpdateMode::from_i32(mode)
+            .ok_or_else(|| Status::invalid_argument("Malformed UpdateMode type"))?;
+
+        let mode = match mode {
+            api::grpc::qdrant::UpdateMode::Upsert => Self::Upsert,
+            api::grpc::qdrant::UpdateMode::InsertOnly => Self::InsertOnly,
+            api::grpc::qdrant::UpdateMode::UpdateOnly => Self::UpdateOnly,
+        };
+
+        Ok(mode)
+    }
+}
+
+impl From<PayloadMutation> for api::grpc::qdrant::PayloadMutation {
+    fn from(mutation: PayloadMutation) -> Self {
+        let mutation = match mutation {
+            PayloadMutation::Increment { key, by } => {
+                Mutation::Increment(api::grpc::qdrant::PayloadIncrement {
+                    key: key.to_string(),
+                    by: Some(json_to_proto(serde_json::Value::Number(by))),
+                })
+            }
+            PayloadMutation::Append {
+                key,
+                values,
+                unique,
+            } => Mutation::Append(api::grpc::qdrant::PayloadAppend {
+                key: key.to_string(),
+                values: values.into_iter().map(json_to_proto).collect(),
+                unique: Some(unique),
+            }),
+            PayloadMutation::Remove { key, values } => {
+                Mutation::Remove(api::grpc::qdrant::PayloadRemove {
+                    key: key.to_string(),
+                    values: values.into_iter().map(json_to_proto).collect(),
+                })
+            }
+            PayloadMutation::MergePatch { key, patch } => {
+                Mutation::MergePatch(api::grpc::qdrant::PayloadMergePatch {
+                    key: key.map(|key| key.to_string()),
+                    patch: Some(json_to_proto(patch)),
+                })
+            }
+        };
+
+        Self {
+            mutation: Some(mutation),
+        }
+    }
+}
+
+impl TryFrom<api::grpc::qdrant::PayloadMutation> for PayloadMutation {
+    type Error = Status;
+
+    fn try_from(mutation: api::grpc::qdrant::PayloadMutation) -> Result<Self, Self::Error> {
+        let mutation = match mutation.mutation {
+            Some(Mutation::Increment(api::grpc::qdrant::PayloadIncrement { key, by })) => {
+                let by = by
+                    .map(proto_to_json)
+                    .transpose()?
+                    .and_then(|by| match by {
+                        serde_json::Value::Number(by) => Some(by),
+                        _ => None,
+                    })
+                    .ok_or_else(|| Status::invalid_argument("Increment value must be a number"))?;
+                Self::Increment {
+                    key: json_path_from_proto(&key)?,
+                    by,
+                }
+            }
+            Some(Mutation::Append(api::grpc::qdrant::PayloadAppend {
+                key,
+                values,
+                unique,
+            })) => Self::Append {
+                key: json_path_from_proto(&key)?,
+                values: values
+                    .into_iter()
+                    .map(proto_to_json)
+                    .collect::<Result<_, _>>()?,
+                unique: unique.unwrap_or_default(),
+            },
+            Some(Mutation::Remove(api::grpc::qdrant::PayloadRemove { key, values })) => {
+                Self::Remove {
+                    key: json_path_from_proto(&key)?,
+                    values: values
+                        .into_iter()
+                        .map(proto_to_json)
+                        .collect::<Result<_, _>>()?,
+                }
+            }
+            Some(Mutation::MergePatch(api::grpc::qdrant::PayloadMergePatch { key, patch })) => {
+                Self::MergePatch {
+                    key: key.as_deref().map(json_path_from_proto).transpose()?,
+                    patch: patch
+                        .map(proto_to_json)
+                        .transpose()?
+                        .ok_or_else(|| Status::invalid_argument("Merge patch is missing"))?,
+                }
+            }
+            None => return Err(Status::invalid_argument("Payload mutation is missing")),
+        };
+
+        Ok(mutation)
+    }
+}
+
 impl From<api::grpc::qdrant::CountResult> for CountResult {
     fn from(value: api::grpc::qdrant::CountResult) -> Self {
         Self {
@@ -1025,6 +1359,7 @@ impl TryFrom<api::grpc::qdrant::SearchPoints> for CoreSearchRequest {
             timeout: _,
             shard_key_selector: _,
             sparse_indices,
+            explain: _,
         } = value;
 
         if let Some(sparse_indices) = &sparse_indices {
@@ -1086,6 +1421,7 @@ impl<'a> From<CollectionSearchRequest<'a>> for api::grpc::qdrant::SearchPoints {
             timeout: None,
             shard_key_selector: None,
             sparse_indices,
+            explain: None,
         }
     }
 }
@@ -1549,6 +1885,8 @@ impl From<VectorParams> for api::grpc::qdrant::VectorParams {
                 Distance::Euclid => api::grpc::qdrant::Distance::Euclid,
                 Distance::Dot => api::grpc::qdrant::Distance::Dot,
                 Distance::Manhattan => api::grpc::qdrant::Distance::Manhattan,
+                Distance::Hamming => api::grpc::qdrant::Distance::Hamming,
+                Distance::Jaccard => api::grpc::qdrant::Distance::Jaccard,
             }
             .into(),
             hnsw_config: value.hnsw_config.map(Into::into),
@@ -1557,9 +1895,15 @@ impl From<VectorParams> for api::grpc::qdrant::VectorParams {
             datatype: value
                 .datatype
                 .map(|dt| api::grpc::qdrant::Datatype::from(dt).into()),
+            additional_quantization_configs: value
+                .additional_quantization_configs
+                .into_iter()
+                .map(Into::into)
+                .collect(),
             multivector_config: value
                 .multivector_config
                 .map(api::grpc::qdrant::MultiVectorConfig::from),
+            prefix_of: value.prefix_of,
         }
     }
 }
@@ -1570,6 +1914,9 @@ impl From<Datatype> for api::grpc::qdrant::Datatype {
             Datatype::Float32 => api::grpc::qdrant::Datatype::Float32,
             Datatype::Uint8 => api::grpc::qdrant::Datatype::Uint8,
             Datatype::Float16 => api::grpc::qdrant::Datatype::Float16,
+            Datatype::Bit => api::grpc::qdrant::Datatype::Bit,
+            Datatype::BFloat16 => api::grpc::qdrant::Datatype::BFloat16,
+            Datatype::Int8 => api::grpc::qdrant::Datatype::Int8,
         }
     }
 }
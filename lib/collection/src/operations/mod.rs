@@ -5,25 +5,29 @@ pub mod conversions;
 pub mod conversions_rest;
 pub mod operation_effect;
 pub mod payload_ops;
+pub mod payload_validation;
 pub mod point_ops;
 pub mod query_enum;
 pub mod shard_selector_internal;
 pub mod shared_storage_config;
 pub mod snapshot_ops;
 pub mod snapshot_storage_ops;
+pub mod trash;
 pub mod types;
 pub mod universal_query;
 pub mod validation;
 pub mod vector_ops;
 pub mod vector_params_builder;
+pub mod verification;
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 use segment::json_path::JsonPath;
-use segment::types::{ExtendedPointId, PayloadFieldSchema};
+use segment::types::{ExtendedPointId, Filter, PayloadFieldSchema};
 use serde::{Deserialize, Serialize};
 use strum::{EnumDiscriminants, EnumIter};
-use validator::Validate;
+use validator::{Validate, ValidationError, ValidationErrors};
 
 use crate::hash_ring::{HashRing, ShardIds};
 use crate::shards::shard::{PeerId, ShardId};
@@ -143,6 +147,71 @@ pub enum CollectionUpdateOperations {
     VectorOperation(vector_ops::VectorOperations),
     PayloadOperation(payload_ops::PayloadOps),
     FieldIndexOperation(FieldIndexOperations),
+    AtomicBatch(AtomicBatchOp),
+}
+
+/// Sequence of operations, which is written into WAL as a single record
+/// and applied to the shard as a unit.
+///
+/// If any of the operations fails, points affected by the batch are restored
+/// to the state they had before the batch.
+/// Atomicity is guaranteed within each shard, but not across shards.
+/// The number of points affected by the batch in a single shard is bounded.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AtomicBatchOp {
+    pub operations: Vec<CollectionUpdateOperations>,
+    /// Each shard applies the batch only once per key, within the idempotency window.
+    /// Any point, vector or payload update can be deduplicated by sending it as a batch with a key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+}
+
+impl AtomicBatchOp {
+    /// Check if the operation can be rolled back, and therefore may be part of the batch
+    fn is_supported(operation: &CollectionUpdateOperations) -> bool {
+        match operation {
+            // Sync deletes points of the whole id range, which are not known before applying it.
+            // Restored points are not available before applying it, so they can't be backed up.
+            CollectionUpdateOperations::PointOperation(operation) => !matches!(
+                operation,
+                point_ops::PointOperations::SyncPoints(_)
+                    | point_ops::PointOperations::RestorePoints(_)
+            ),
+            CollectionUpdateOperations::VectorOperation(_)
+            | CollectionUpdateOperations::PayloadOperation(_) => true,
+            // Field indexes are not part of point state, so they can't be restored from a backup
+            CollectionUpdateOperations::FieldIndexOperation(_)
+            | CollectionUpdateOperations::AtomicBatch(_) => false,
+        }
+    }
+}
+
+impl Validate for AtomicBatchOp {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        for operation in &self.operations {
+            if !Self::is_supported(operation) {
+                let mut errors = ValidationErrors::new();
+                let mut error = ValidationError::new("atomic_batch");
+                error.message = Some(Cow::from(
+                    "only point, vector and payload updates are supported in atomic batch",
+                ));
+                errors.add("operations", error);
+                return Err(errors);
+            }
+            // Only the key of the whole batch is honoured
+            if operation.idempotency_key().is_some() {
+                let mut errors = ValidationErrors::new();
+                let mut error = ValidationError::new("atomic_batch");
+                error.message = Some(Cow::from(
+                    "idempotency key must be set on the atomic batch, not on its operations",
+                ));
+                errors.add("operations", error);
+                return Err(errors);
+            }
+            operation.validate()?;
+        }
+        Ok(())
+    }
 }
 
 /// A mapping of operation to shard.
@@ -203,6 +272,7 @@ impl Validate for CollectionUpdateOperations {
             CollectionUpdateOperations::VectorOperation(operation) => operation.validate(),
             CollectionUpdateOperations::PayloadOperation(operation) => operation.validate(),
             CollectionUpdateOperations::FieldIndexOperation(operation) => operation.validate(),
+            CollectionUpdateOperations::AtomicBatch(operation) => operation.validate(),
         }
     }
 }
@@ -268,11 +338,79 @@ impl SplitByShard for CollectionUpdateOperations {
             operation @ CollectionUpdateOperations::FieldIndexOperation(_) => {
                 OperationToShard::to_all(operation)
             }
+            // Operations of the batch may target different sets of shards,
+            // so the batch is split operation by operation in `ShardHolder::split_update_by_shard`
+            operation @ CollectionUpdateOperations::AtomicBatch(_) => {
+                debug_assert!(false, "Atomic batch must be split operation by operation");
+                OperationToShard::to_all(operation)
+            }
         }
     }
 }
 
 impl CollectionUpdateOperations {
+    /// Filter selecting the points to update, if the operation has one
+    pub fn filter_mut(&mut self) -> Option<&mut Filter> {
+        match self {
+            CollectionUpdateOperations::PointOperation(
+                point_ops::PointOperations::DeletePointsByFilter(filter),
+            ) => Some(filter),
+            CollectionUpdateOperations::PointOperation(
+                point_ops::PointOperations::UpsertPointsConditional(operation),
+            ) => operation.condition.as_mut(),
+            CollectionUpdateOperations::PointOperation(
+                point_ops::PointOperations::TrashPoints(operation),
+            ) => operation.filter.as_mut(),
+            CollectionUpdateOperations::PointOperation(
+                point_ops::PointOperations::RestorePoints(operation),
+            ) => operation.filter.as_mut(),
+            CollectionUpdateOperations::VectorOperation(
+                vector_ops::VectorOperations::DeleteVectorsByFilter(filter, _),
+            ) => Some(filter),
+            CollectionUpdateOperations::VectorOperation(
+                vector_ops::VectorOperations::UpdateVectors(operation),
+            ) => operation.update_filter.as_mut(),
+            CollectionUpdateOperations::PayloadOperation(operation) => match operation {
+                payload_ops::PayloadOps::SetPayload(operation)
+                | payload_ops::PayloadOps::OverwritePayload(operation) => operation.filter.as_mut(),
+                payload_ops::PayloadOps::MutatePayload(operation) => operation.filter.as_mut(),
+                payload_ops::PayloadOps::DeletePayload(operation) => operation.filter.as_mut(),
+                payload_ops::PayloadOps::ClearPayloadByFilter(filter) => Some(filter),
+                payload_ops::PayloadOps::ClearPayload { .. } => None,
+            },
+            CollectionUpdateOperations::PointOperation(_)
+            | CollectionUpdateOperations::VectorOperation(_)
+            | CollectionUpdateOperations::FieldIndexOperation(_)
+            | CollectionUpdateOperations::AtomicBatch(_) => None,
+        }
+    }
+
+    /// Key, which deduplicates repeated requests of the operation
+    ///
+    /// Only conditional upserts and atomic batches carry a key,
+    /// any other update is deduplicated by sending it as an atomic batch with a key.
+    pub fn idempotency_key(&self) -> Option<&str> {
+        match self {
+            CollectionUpdateOperations::PointOperation(
+                point_ops::PointOperations::UpsertPointsConditional(operation),
+            ) => operation.idempotency_key.as_deref(),
+            CollectionUpdateOperations::AtomicBatch(batch) => batch.idempotency_key.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Filters selecting the points to update, including filters of all operations in a batch
+    pub fn filters_mut(&mut self) -> Vec<&mut Filter> {
+        match self {
+            CollectionUpdateOperations::AtomicBatch(batch) => batch
+                .operations
+                .iter_mut()
+                .flat_map(|operation| operation.filters_mut())
+                .collect(),
+            operation => operation.filter_mut().into_iter().collect(),
+        }
+    }
+
     pub fn is_write_operation(&self) -> bool {
         match self {
             CollectionUpdateOperations::PointOperation(operation) => operation.is_write_operation(),
@@ -285,6 +423,10 @@ impl CollectionUpdateOperations {
             CollectionUpdateOperations::FieldIndexOperation(operation) => {
                 operation.is_write_operation()
             }
+            CollectionUpdateOperations::AtomicBatch(batch) => batch
+                .operations
+                .iter()
+                .any(|operation| operation.is_write_operation()),
         }
     }
 }
@@ -296,6 +438,7 @@ mod tests {
 
     use super::payload_ops::*;
     use super::point_ops::*;
+    use super::trash::*;
     use super::vector_ops::*;
     use super::*;
 
@@ -368,6 +511,12 @@ mod tests {
                 any::<vector_ops::VectorOperations>().prop_map(Self::VectorOperation),
                 any::<payload_ops::PayloadOps>().prop_map(Self::PayloadOperation),
                 any::<FieldIndexOperations>().prop_map(Self::FieldIndexOperation),
+                proptest::collection::vec(any::<point_ops::PointOperations>(), 0..3).prop_map(
+                    |operations| Self::AtomicBatch(AtomicBatchOp {
+                        operations: operations.into_iter().map(Self::PointOperation).collect(),
+                        idempotency_key: None,
+                    })
+                ),
             ]
             .boxed()
         }
@@ -394,11 +543,30 @@ mod tests {
                 points: Vec::new(),
             });
 
+            let trash = Self::TrashPoints(TrashPointsOp {
+                points: Some(Vec::new()),
+                filter: None,
+                trashed_at: chrono::DateTime::UNIX_EPOCH,
+            });
+
+            let restore = Self::RestorePoints(RestorePointsOp {
+                points: None,
+                filter: Some(Filter {
+                    should: None,
+                    min_should: None,
+                    must: None,
+                    must_not: None,
+                }),
+                trashed_after: Some(chrono::DateTime::UNIX_EPOCH),
+            });
+
             prop_oneof![
                 Just(upsert),
                 Just(delete),
                 Just(delete_by_filter),
                 Just(sync),
+                Just(trash),
+                Just(restore),
             ]
             .boxed()
         }
@@ -409,7 +577,10 @@ mod tests {
         type Strategy = BoxedStrategy<Self>;
 
         fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
-            let update = Self::UpdateVectors(UpdateVectorsOp { points: Vec::new() });
+            let update = Self::UpdateVectors(UpdateVectorsOp {
+                points: Vec::new(),
+                update_filter: None,
+            });
 
             let delete = Self::DeleteVectors(
                 PointIdsList {
@@ -1,4 +1,6 @@
-use segment::types::{Filter, PointIdType};
+use std::collections::HashSet;
+
+use segment::types::{Condition, Filter, HasIdCondition, PointIdType};
 
 use super::vector_ops;
 use crate::operations::payload_ops::PayloadOps;
@@ -38,6 +40,39 @@ impl EstimateOperationEffectArea for CollectionUpdateOperations {
                 payload_operation.estimate_effect_area()
             }
             CollectionUpdateOperations::FieldIndexOperation(_) => OperationEffectArea::Empty,
+            CollectionUpdateOperations::AtomicBatch(batch) => {
+                let mut points = Vec::new();
+                let mut filters = Vec::new();
+                for operation in &batch.operations {
+                    match operation.estimate_effect_area() {
+                        OperationEffectArea::Empty => {}
+                        OperationEffectArea::Points(operation_points) => {
+                            points.extend(operation_points)
+                        }
+                        OperationEffectArea::Filter(filter) => filters.push(filter),
+                    }
+                }
+
+                if filters.is_empty() {
+                    if points.is_empty() {
+                        OperationEffectArea::Empty
+                    } else {
+                        OperationEffectArea::Points(points)
+                    }
+                } else {
+                    // Any point, selected by one of the operations
+                    let mut should: Vec<_> = filters.into_iter().map(Condition::Filter).collect();
+                    if !points.is_empty() {
+                        should.push(Condition::HasId(HasIdCondition::from(
+                            points.into_iter().collect::<HashSet<_>>(),
+                        )));
+                    }
+                    OperationEffectArea::Filter(Filter {
+                        should: Some(should),
+                        ..Default::default()
+                    })
+                }
+            }
         }
     }
 }
@@ -48,6 +83,9 @@ impl EstimateOperationEffectArea for point_ops::PointOperations {
             point_ops::PointOperations::UpsertPoints(insert_operations) => {
                 insert_operations.estimate_effect_area()
             }
+            point_ops::PointOperations::UpsertPointsConditional(conditional_upsert) => {
+                conditional_upsert.points_op.estimate_effect_area()
+            }
             point_ops::PointOperations::DeletePoints { ids } => {
                 OperationEffectArea::Points(ids.clone())
             }
@@ -61,6 +99,24 @@ impl EstimateOperationEffectArea for point_ops::PointOperations {
                 );
                 OperationEffectArea::Points(sync_op.points.iter().map(|x| x.id).collect())
             }
+            point_ops::PointOperations::TrashPoints(trash_points) => {
+                if let Some(points) = &trash_points.points {
+                    OperationEffectArea::Points(points.clone())
+                } else if let Some(filter) = &trash_points.filter {
+                    OperationEffectArea::Filter(filter.clone())
+                } else {
+                    OperationEffectArea::Empty
+                }
+            }
+            point_ops::PointOperations::RestorePoints(restore_points) => {
+                if let Some(points) = &restore_points.points {
+                    OperationEffectArea::Points(points.clone())
+                } else if let Some(filter) = &restore_points.filter {
+                    OperationEffectArea::Filter(filter.clone())
+                } else {
+                    OperationEffectArea::Empty
+                }
+            }
         }
     }
 }
@@ -107,6 +163,15 @@ impl EstimateOperationEffectArea for PayloadOps {
                     OperationEffectArea::Empty
                 }
             }
+            PayloadOps::MutatePayload(mutate_payload) => {
+                if let Some(points) = &mutate_payload.points {
+                    OperationEffectArea::Points(points.clone())
+                } else if let Some(filter) = &mutate_payload.filter {
+                    OperationEffectArea::Filter(filter.clone())
+                } else {
+                    OperationEffectArea::Empty
+                }
+            }
             PayloadOps::DeletePayload(delete_payload) => {
                 if let Some(points) = &delete_payload.points {
                     OperationEffectArea::Points(points.clone())
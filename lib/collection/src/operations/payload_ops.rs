@@ -1,11 +1,14 @@
+use std::borrow::Cow;
+
 use api::rest::ShardKeySelector;
 use schemars::JsonSchema;
 use segment::json_path::JsonPath;
 use segment::types::{Filter, Payload, PayloadKeyType, PointIdType};
 use serde;
 use serde::{Deserialize, Serialize};
+use serde_json::{Number, Value};
 use strum::{EnumDiscriminants, EnumIter};
-use validator::Validate;
+use validator::{Validate, ValidationError, ValidationErrors};
 
 use super::{split_iter_by_shard, OperationToShard, SplitByShard};
 use crate::hash_ring::HashRing;
@@ -133,6 +136,207 @@ impl TryFrom<DeletePayloadShadow> for DeletePayload {
     }
 }
 
+/// Atomic modification of a payload value, evaluated against the stored payload of each point.
+///
+/// Stored values of an incompatible type are left intact.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PayloadMutation {
+    /// Add a number to the numeric value. A missing value is treated as 0.
+    /// Use a negative number to decrement.
+    Increment { key: JsonPath, by: Number },
+    /// Append values to the array. A missing value is treated as an empty array.
+    Append {
+        key: JsonPath,
+        values: Vec<Value>,
+        /// If true, values already present in the array are not appended again
+        #[serde(default)]
+        unique: bool,
+    },
+    /// Remove all occurrences of the values from the array
+    Remove { key: JsonPath, values: Vec<Value> },
+    /// Apply JSON merge patch (RFC 7396) to the value.
+    /// If no key is specified, the patch is applied to the whole payload.
+    MergePatch {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        key: Option<JsonPath>,
+        patch: Value,
+    },
+}
+
+impl PayloadMutation {
+    /// Apply the mutation to the payload of a single point
+    pub fn apply(&self, payload: &mut Payload) {
+        match self {
+            PayloadMutation::Increment { key, by } => {
+                key.value_update(&mut payload.0, &mut |value| increment_value(value, by));
+            }
+            PayloadMutation::Append {
+                key,
+                values,
+                unique,
+            } => key.value_update(&mut payload.0, &mut |value| {
+                if value.is_null() {
+                    *value = Value::Array(Vec::new());
+                }
+                let Value::Array(array) = value else {
+                    return;
+                };
+                for new_value in values {
+                    if !*unique || !array.contains(new_value) {
+                        array.push(new_value.clone());
+                    }
+                }
+            }),
+            PayloadMutation::Remove { key, values } => {
+                key.value_update(&mut payload.0, &mut |value| {
+                    if let Value::Array(array) = value {
+                        array.retain(|value| !values.contains(value));
+                    }
+                });
+            }
+            PayloadMutation::MergePatch {
+                key: Some(key),
+                patch,
+            } => key.value_update(&mut payload.0, &mut |value| merge_patch(value, patch)),
+            PayloadMutation::MergePatch { key: None, patch } => {
+                let mut value = Value::Object(std::mem::take(&mut payload.0));
+                merge_patch(&mut value, patch);
+                if let Value::Object(map) = value {
+                    payload.0 = map;
+                }
+            }
+        }
+    }
+
+    /// Keys, which values might be changed by the mutation. `None` means the whole payload.
+    pub fn key(&self) -> Option<&JsonPath> {
+        match self {
+            PayloadMutation::Increment { key, .. }
+            | PayloadMutation::Append { key, .. }
+            | PayloadMutation::Remove { key, .. } => Some(key),
+            PayloadMutation::MergePatch { key, .. } => key.as_ref(),
+        }
+    }
+}
+
+impl Validate for PayloadMutation {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        match self {
+            PayloadMutation::MergePatch { key: None, patch } if !patch.is_object() => {
+                let mut errors = ValidationErrors::new();
+                let mut error = ValidationError::new("merge_patch");
+                error.message = Some(Cow::from(
+                    "patch must be an object, if applied to the whole payload",
+                ));
+                errors.add("patch", error);
+                Err(errors)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+fn increment_value(value: &mut Value, by: &Number) {
+    let current = match value {
+        Value::Null => Number::from(0),
+        Value::Number(number) => number.clone(),
+        _ => return,
+    };
+
+    let integer_sum = match (current.as_i64(), by.as_i64()) {
+        (Some(current), Some(by)) => current.checked_add(by).map(Number::from),
+        _ => None,
+    };
+
+    let sum = integer_sum.or_else(|| Number::from_f64(current.as_f64()? + by.as_f64()?));
+
+    if let Some(sum) = sum {
+        *value = Value::Number(sum);
+    }
+}
+
+/// JSON merge patch, as defined in RFC 7396
+fn merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+
+    if !target.is_object() {
+        *target = Value::Object(serde_json::Map::new());
+    }
+
+    let Value::Object(target) = target else {
+        unreachable!();
+    };
+
+    for (key, value) in patch {
+        if value.is_null() {
+            target.remove(key);
+        } else {
+            merge_patch(target.entry(key.clone()).or_insert(Value::Null), value);
+        }
+    }
+}
+
+/// This data structure is used in API interface and applied across multiple shards
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
+#[serde(try_from = "MutatePayloadShadow")]
+pub struct MutatePayload {
+    /// Mutations, applied in order to the payload of each point
+    #[validate]
+    #[validate(length(min = 1, message = "must specify mutations to apply"))]
+    pub mutations: Vec<PayloadMutation>,
+    /// Mutates payload of each point in this list
+    pub points: Option<Vec<PointIdType>>,
+    /// Mutates payload of each point that satisfy this filter condition
+    pub filter: Option<Filter>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard_key: Option<ShardKeySelector>,
+}
+
+/// This data structure is used inside shard operations queue
+/// and supposed to be written into WAL of individual shard.
+///
+/// Unlike `MutatePayload` it does not contain `shard_key` field
+/// as individual shard does not need to know about shard key
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, Validate)]
+pub struct MutatePayloadOp {
+    /// Mutations, applied in order to the payload of each point
+    #[validate]
+    pub mutations: Vec<PayloadMutation>,
+    /// Mutates payload of each point in this list
+    pub points: Option<Vec<PointIdType>>,
+    /// Mutates payload of each point that satisfy this filter condition
+    pub filter: Option<Filter>,
+}
+
+#[derive(Deserialize)]
+struct MutatePayloadShadow {
+    pub mutations: Vec<PayloadMutation>,
+    pub points: Option<Vec<PointIdType>>,
+    pub filter: Option<Filter>,
+    pub shard_key: Option<ShardKeySelector>,
+}
+
+impl TryFrom<MutatePayloadShadow> for MutatePayload {
+    type Error = PointsSelectorValidationError;
+
+    fn try_from(value: MutatePayloadShadow) -> Result<Self, Self::Error> {
+        if value.points.is_some() || value.filter.is_some() {
+            Ok(MutatePayload {
+                mutations: value.mutations,
+                points: value.points,
+                filter: value.filter,
+                shard_key: value.shard_key,
+            })
+        } else {
+            Err(PointsSelectorValidationError)
+        }
+    }
+}
+
 /// Define operations description for point payloads manipulation
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize, EnumDiscriminants)]
 #[strum_discriminants(derive(EnumIter))]
@@ -148,6 +352,8 @@ pub enum PayloadOps {
     ClearPayloadByFilter(Filter),
     /// Overwrite full payload with given keys
     OverwritePayload(SetPayloadOp),
+    /// Atomically modify payload values, based on their stored values
+    MutatePayload(MutatePayloadOp),
 }
 
 impl PayloadOps {
@@ -158,6 +364,7 @@ impl PayloadOps {
             PayloadOps::ClearPayload { .. } => false,
             PayloadOps::ClearPayloadByFilter(_) => false,
             PayloadOps::OverwritePayload(_) => true,
+            PayloadOps::MutatePayload(_) => true,
         }
     }
 }
@@ -170,6 +377,7 @@ impl Validate for PayloadOps {
             PayloadOps::ClearPayload { .. } => Ok(()),
             PayloadOps::ClearPayloadByFilter(_) => Ok(()),
             PayloadOps::OverwritePayload(operation) => operation.validate(),
+            PayloadOps::MutatePayload(operation) => operation.validate(),
         }
     }
 }
@@ -189,6 +397,9 @@ impl SplitByShard for PayloadOps {
             PayloadOps::OverwritePayload(operation) => operation
                 .split_by_shard(ring)
                 .map(PayloadOps::OverwritePayload),
+            PayloadOps::MutatePayload(operation) => operation
+                .split_by_shard(ring)
+                .map(PayloadOps::MutatePayload),
         }
     }
 }
@@ -230,6 +441,24 @@ impl SplitByShard for SetPayloadOp {
     }
 }
 
+impl SplitByShard for MutatePayloadOp {
+    fn split_by_shard(self, ring: &HashRing) -> OperationToShard<Self> {
+        match (&self.points, &self.filter) {
+            (Some(_), _) => {
+                split_iter_by_shard(self.points.unwrap(), |id| *id, ring).map(|points| {
+                    MutatePayloadOp {
+                        points: Some(points),
+                        mutations: self.mutations.clone(),
+                        filter: self.filter.clone(),
+                    }
+                })
+            }
+            (None, Some(_)) => OperationToShard::to_all(self),
+            (None, None) => OperationToShard::to_none(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use segment::types::{Payload, PayloadContainer};
@@ -306,4 +535,43 @@ mod tests {
             _ => panic!("Wrong operation"),
         }
     }
+
+    #[test]
+    fn test_payload_mutations() {
+        let mut payload: Payload = serde_json::json!({
+            "views": 10,
+            "score": 1.5,
+            "labels": ["a", "b"],
+            "meta": {"author": "x", "draft": true},
+        })
+        .into();
+
+        let mutations: Vec<PayloadMutation> = serde_json::from_value(serde_json::json!([
+            {"increment": {"key": "views", "by": 1}},
+            {"increment": {"key": "score", "by": -0.5}},
+            {"increment": {"key": "stats.likes", "by": 3}},
+            {"increment": {"key": "labels", "by": 1}},
+            {"append": {"key": "labels", "values": ["b", "c"], "unique": true}},
+            {"append": {"key": "tags", "values": ["x"]}},
+            {"remove": {"key": "labels", "values": ["a"]}},
+            {"merge_patch": {"key": "meta", "patch": {"draft": null, "editor": "y"}}},
+        ]))
+        .unwrap();
+
+        for mutation in &mutations {
+            mutation.apply(&mut payload);
+        }
+
+        let expected: Payload = serde_json::json!({
+            "views": 11,
+            "score": 1.0,
+            "stats": {"likes": 3},
+            "labels": ["b", "c"],
+            "tags": ["x"],
+            "meta": {"author": "x", "editor": "y"},
+        })
+        .into();
+
+        assert_eq!(payload, expected);
+    }
 }
@@ -0,0 +1,437 @@
+use segment::json_path::{JsonPath, JsonPathInterface};
+use segment::types::{DateTimePayloadType, GeoPoint, Payload, PayloadSchemaType};
+use serde_json::Value;
+
+use super::payload_ops::{
+    DeletePayloadOp, MutatePayloadOp, PayloadMutation, PayloadOps, SetPayloadOp,
+};
+use super::point_ops::{PointInsertOperationsInternal, PointOperations};
+use super::types::{CollectionError, CollectionResult};
+use super::CollectionUpdateOperations;
+use crate::config::{PayloadFieldRule, PayloadValidationConfig};
+
+impl PayloadValidationConfig {
+    /// Reject operations, which would store payload values violating the configured rules.
+    ///
+    /// Upserted points are checked one by one, and all violations are reported in the error.
+    pub fn check_operation(&self, operation: &CollectionUpdateOperations) -> CollectionResult<()> {
+        let mut violations = Vec::new();
+        self.collect_violations(operation, &mut violations);
+
+        if violations.is_empty() {
+            return Ok(());
+        }
+
+        Err(CollectionError::bad_input(format!(
+            "Payload validation failed: {}",
+            violations.join("; "),
+        )))
+    }
+
+    fn collect_violations(
+        &self,
+        operation: &CollectionUpdateOperations,
+        violations: &mut Vec<String>,
+    ) {
+        match operation {
+            CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(points)) => {
+                self.collect_points_violations(points, violations);
+            }
+            CollectionUpdateOperations::PointOperation(
+                PointOperations::UpsertPointsConditional(operation),
+            ) => {
+                self.collect_points_violations(&operation.points_op, violations);
+            }
+            CollectionUpdateOperations::PayloadOperation(PayloadOps::SetPayload(operation)) => {
+                self.collect_set_payload_violations(operation, false, violations);
+            }
+            CollectionUpdateOperations::PayloadOperation(PayloadOps::OverwritePayload(
+                operation,
+            )) => {
+                self.collect_set_payload_violations(operation, true, violations);
+            }
+            CollectionUpdateOperations::PayloadOperation(PayloadOps::DeletePayload(operation)) => {
+                self.collect_delete_payload_violations(operation, violations);
+            }
+            CollectionUpdateOperations::PayloadOperation(
+                PayloadOps::ClearPayload { .. } | PayloadOps::ClearPayloadByFilter(_),
+            ) => {
+                violations.extend(
+                    self.required_fields().map(|path| {
+                        format!("field `{path}` is required, payload can't be cleared")
+                    }),
+                );
+            }
+            // Result of a mutation depends on the stored payload, see `mutation_violations`
+            CollectionUpdateOperations::PayloadOperation(PayloadOps::MutatePayload(_)) => {}
+            CollectionUpdateOperations::AtomicBatch(batch) => {
+                for operation in &batch.operations {
+                    self.collect_violations(operation, violations);
+                }
+            }
+            CollectionUpdateOperations::PointOperation(_)
+            | CollectionUpdateOperations::VectorOperation(_)
+            | CollectionUpdateOperations::FieldIndexOperation(_) => {}
+        }
+    }
+
+    /// Describe violations, which the mutations would introduce into the stored payload of a point.
+    ///
+    /// Violations already present in the stored payload are not reported,
+    /// so points stored before the rules were configured can still be mutated.
+    pub fn mutation_violations(
+        &self,
+        mutations: &[PayloadMutation],
+        payload: &Payload,
+    ) -> Vec<String> {
+        let existing = self.payload_violations(payload, true);
+
+        let mut mutated = payload.clone();
+        for mutation in mutations {
+            mutation.apply(&mut mutated);
+        }
+
+        self.payload_violations(&mutated, true)
+            .into_iter()
+            .filter(|violation| !existing.contains(violation))
+            .collect()
+    }
+
+    fn required_fields(&self) -> impl Iterator<Item = &JsonPath> {
+        self.fields
+            .iter()
+            .filter(|(_, rule)| rule.is_required())
+            .map(|(path, _)| path)
+    }
+
+    fn collect_delete_payload_violations(
+        &self,
+        operation: &DeletePayloadOp,
+        violations: &mut Vec<String>,
+    ) {
+        for path in self.required_fields() {
+            if operation
+                .keys
+                .iter()
+                .any(|key| path.is_affected_by_value_remove(key))
+            {
+                violations.push(format!("field `{path}` is required and can't be deleted"));
+            }
+        }
+    }
+
+    fn collect_points_violations(
+        &self,
+        points: &PointInsertOperationsInternal,
+        violations: &mut Vec<String>,
+    ) {
+        let empty_payload = Payload::default();
+
+        let points: Box<dyn Iterator<Item = _>> = match points {
+            PointInsertOperationsInternal::PointsBatch(batch) => {
+                Box::new(batch.ids.iter().enumerate().map(|(i, id)| {
+                    let payload = batch
+                        .payloads
+                        .as_ref()
+                        .and_then(|payloads| payloads.get(i)?.as_ref());
+                    (id, payload)
+                }))
+            }
+            PointInsertOperationsInternal::PointsList(points) => Box::new(
+                points
+                    .iter()
+                    .map(|point| (&point.id, point.payload.as_ref())),
+            ),
+        };
+
+        for (id, payload) in points {
+            let payload = payload.unwrap_or(&empty_payload);
+            for violation in self.payload_violations(payload, true) {
+                violations.push(format!("point {id}: {violation}"));
+            }
+        }
+    }
+
+    fn collect_set_payload_violations(
+        &self,
+        operation: &SetPayloadOp,
+        overwrite: bool,
+        violations: &mut Vec<String>,
+    ) {
+        match &operation.key {
+            // Only overwriting without a key replaces all fields of the points
+            None => violations.extend(self.payload_violations(&operation.payload, overwrite)),
+            Some(key) => {
+                let mut payload = serde_json::Map::new();
+                JsonPath::value_set(Some(key), &mut payload, &operation.payload.0);
+                violations.extend(self.payload_violations(&Payload(payload), false));
+            }
+        }
+    }
+
+    /// Describe all violations of the rules by the given payload.
+    ///
+    /// If `complete` is false, the payload is merged into an existing one,
+    /// so missing required fields are not reported.
+    fn payload_violations(&self, payload: &Payload, complete: bool) -> Vec<String> {
+        let mut violations = Vec::new();
+
+        for (path, rule) in &self.fields {
+            let values: Vec<_> = path
+                .value_get(&payload.0)
+                .into_iter()
+                .flat_map(|value| match value {
+                    Value::Array(array) => array.iter().collect(),
+                    value => vec![value],
+                })
+                .filter(|value| !value.is_null())
+                .collect();
+
+            if values.is_empty() {
+                if complete && rule.is_required() {
+                    violations.push(format!("missing required field `{path}`"));
+                }
+                continue;
+            }
+
+            violations.extend(
+                values
+                    .into_iter()
+                    .filter_map(|value| rule.value_violation(value))
+                    .map(|violation| format!("field `{path}` {violation}")),
+            );
+        }
+
+        violations
+    }
+}
+
+impl PayloadFieldRule {
+    /// Describe the violation of this rule by a single value, if any
+    fn value_violation(&self, value: &Value) -> Option<String> {
+        if let Some(data_type) = self.data_type {
+            if !value_has_type(value, data_type) {
+                return Some(format!("must be {}, got {value}", data_type.name()));
+            }
+        }
+
+        if let Some(allowed_values) = &self.allowed_values {
+            if !allowed_values.contains(value) {
+                return Some(format!("must be one of the allowed values, got {value}"));
+            }
+        }
+
+        if let Some(range) = &self.range {
+            match value.as_f64() {
+                Some(number) if range.check_range(number) => {}
+                Some(_) => return Some(format!("is out of the allowed range, got {value}")),
+                None => return Some(format!("must be a number, got {value}")),
+            }
+        }
+
+        None
+    }
+}
+
+/// Payload mutations of the operation, which must be checked against the stored payload
+pub fn payload_mutations(operation: &CollectionUpdateOperations) -> Vec<&MutatePayloadOp> {
+    match operation {
+        CollectionUpdateOperations::PayloadOperation(PayloadOps::MutatePayload(operation)) => {
+            vec![operation]
+        }
+        CollectionUpdateOperations::AtomicBatch(batch) => batch
+            .operations
+            .iter()
+            .flat_map(payload_mutations)
+            .collect(),
+        CollectionUpdateOperations::PointOperation(_)
+        | CollectionUpdateOperations::VectorOperation(_)
+        | CollectionUpdateOperations::PayloadOperation(_)
+        | CollectionUpdateOperations::FieldIndexOperation(_) => Vec::new(),
+    }
+}
+
+fn value_has_type(value: &Value, data_type: PayloadSchemaType) -> bool {
+    match data_type {
+        PayloadSchemaType::Keyword | PayloadSchemaType::Text => value.is_string(),
+        PayloadSchemaType::Integer => value.is_i64() || value.is_u64(),
+        PayloadSchemaType::Float => value.is_number(),
+        PayloadSchemaType::Bool => value.is_boolean(),
+        PayloadSchemaType::Geo => serde_json::from_value::<GeoPoint>(value.clone()).is_ok(),
+        PayloadSchemaType::Datetime => value
+            .as_str()
+            .is_some_and(|value| value.parse::<DateTimePayloadType>().is_ok()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use api::rest::VectorStruct;
+    use segment::types::Range;
+    use serde_json::json;
+
+    use super::*;
+    use crate::operations::point_ops::PointStruct;
+
+    fn config() -> PayloadValidationConfig {
+        PayloadValidationConfig {
+            fields: HashMap::from([
+                (
+                    "price".parse().unwrap(),
+                    PayloadFieldRule {
+                        required: Some(true),
+                        data_type: Some(PayloadSchemaType::Float),
+                        allowed_values: None,
+                        range: Some(Range {
+                            gte: Some(0.0),
+                            ..Default::default()
+                        }),
+                    },
+                ),
+                (
+                    "color".parse().unwrap(),
+                    PayloadFieldRule {
+                        required: None,
+                        data_type: Some(PayloadSchemaType::Keyword),
+                        allowed_values: Some(vec![json!("red"), json!("green")]),
+                        range: None,
+                    },
+                ),
+            ]),
+        }
+    }
+
+    fn upsert(payloads: Vec<Value>) -> CollectionUpdateOperations {
+        let points = payloads
+            .into_iter()
+            .enumerate()
+            .map(|(id, payload)| PointStruct {
+                id: (id as u64).into(),
+                vector: VectorStruct::Single(vec![1.0]),
+                payload: Some(serde_json::from_value(payload).unwrap()),
+            })
+            .collect();
+        CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+            PointInsertOperationsInternal::PointsList(points),
+        ))
+    }
+
+    fn set_payload(payload: Value, key: Option<&str>) -> CollectionUpdateOperations {
+        CollectionUpdateOperations::PayloadOperation(PayloadOps::SetPayload(SetPayloadOp {
+            payload: serde_json::from_value(payload).unwrap(),
+            points: Some(vec![0u64.into()]),
+            filter: None,
+            key: key.map(|key| key.parse().unwrap()),
+        }))
+    }
+
+    #[test]
+    fn test_upsert_validation() {
+        let config = config();
+
+        config
+            .check_operation(&upsert(vec![
+                json!({ "price": 10 }),
+                json!({ "price": [1.5, 2.5], "color": "red", "other": "value" }),
+            ]))
+            .unwrap();
+
+        let error = config
+            .check_operation(&upsert(vec![
+                json!({ "price": 10 }),
+                json!({ "price": "12" }),
+                json!({ "color": "blue", "price": -1 }),
+            ]))
+            .unwrap_err()
+            .to_string();
+        assert!(!error.contains("point 0"));
+        assert!(error.contains("point 1: field `price` must be float"));
+        assert!(error.contains("point 2: field `color` must be one of the allowed values"));
+        assert!(error.contains("point 2: field `price` is out of the allowed range"));
+
+        let error = config
+            .check_operation(&upsert(vec![json!({ "color": "red" })]))
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("point 0: missing required field `price`"));
+    }
+
+    #[test]
+    fn test_set_payload_validation() {
+        let config = config();
+
+        // Required fields may already be present in the stored payload
+        config
+            .check_operation(&set_payload(json!({ "color": "green" }), None))
+            .unwrap();
+        config
+            .check_operation(&set_payload(json!({ "price": 1.0 }), None))
+            .unwrap();
+
+        assert!(config
+            .check_operation(&set_payload(json!({ "price": "free" }), None))
+            .is_err());
+
+        // Payload assigned by key is checked at its final location
+        config
+            .check_operation(&set_payload(json!({ "price": "free" }), Some("meta")))
+            .unwrap();
+        assert!(config
+            .check_operation(&set_payload(json!({ "ignored": 1 }), Some("color")))
+            .is_err());
+    }
+
+    #[test]
+    fn test_payload_removal_validation() {
+        let config = config();
+
+        let delete_payload = |keys: &[&str]| {
+            CollectionUpdateOperations::PayloadOperation(PayloadOps::DeletePayload(
+                DeletePayloadOp {
+                    keys: keys.iter().map(|key| key.parse().unwrap()).collect(),
+                    points: Some(vec![0u64.into()]),
+                    filter: None,
+                },
+            ))
+        };
+
+        config.check_operation(&delete_payload(&["color"])).unwrap();
+        assert!(config.check_operation(&delete_payload(&["price"])).is_err());
+
+        let clear_payload =
+            CollectionUpdateOperations::PayloadOperation(PayloadOps::ClearPayload {
+                points: vec![0u64.into()],
+            });
+        let error = config
+            .check_operation(&clear_payload)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("field `price` is required"));
+    }
+
+    #[test]
+    fn test_mutation_validation() {
+        let config = config();
+        let mutations = |patch: Value| vec![PayloadMutation::MergePatch { key: None, patch }];
+
+        let payload: Payload =
+            serde_json::from_value(json!({ "price": 1, "color": "red" })).unwrap();
+        assert!(config
+            .mutation_violations(&mutations(json!({ "price": 2 })), &payload)
+            .is_empty());
+
+        let violations = config.mutation_violations(
+            &mutations(json!({ "price": null, "color": "blue" })),
+            &payload,
+        );
+        assert_eq!(violations.len(), 2);
+
+        // Existing violations don't prevent unrelated mutations
+        let payload: Payload = serde_json::from_value(json!({ "color": "blue" })).unwrap();
+        assert!(config
+            .mutation_violations(&mutations(json!({ "other": 1 })), &payload)
+            .is_empty());
+    }
+}
@@ -1,5 +1,5 @@
 use std::borrow::Cow;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use api::rest::{BatchVectorStruct, ShardKeySelector, VectorStruct};
 use itertools::izip;
@@ -12,6 +12,7 @@ use serde::{Deserialize, Serialize};
 use strum::{EnumDiscriminants, EnumIter};
 use validator::Validate;
 
+use super::trash::{RestorePointsOp, TrashPointsOp};
 use super::{point_to_shards, split_iter_by_shard, OperationToShard, SplitByShard};
 use crate::hash_ring::HashRing;
 use crate::operations::types::Record;
@@ -34,6 +35,39 @@ pub enum WriteOrdering {
     Strong,
 }
 
+/// Defines how upsert handles points, which already exist or don't exist yet
+///
+/// * `upsert` - insert new points and overwrite existing ones, default
+///
+/// * `insert_only` - only insert new points, existing points are left intact
+///
+/// * `update_only` - only overwrite existing points, new points are not inserted
+///
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateMode {
+    #[default]
+    Upsert,
+    InsertOnly,
+    UpdateOnly,
+}
+
+impl UpdateMode {
+    pub fn is_upsert(&self) -> bool {
+        matches!(self, UpdateMode::Upsert)
+    }
+
+    /// Whether points, which don't exist yet, should be inserted
+    pub fn allows_insert(&self) -> bool {
+        !matches!(self, UpdateMode::UpdateOnly)
+    }
+
+    /// Whether points, which already exist, should be overwritten
+    pub fn allows_update(&self) -> bool {
+        !matches!(self, UpdateMode::InsertOnly)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema, Validate)]
 #[serde(rename_all = "snake_case")]
 pub struct PointStruct {
@@ -156,6 +190,20 @@ pub struct PointsBatch {
     pub batch: Batch,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKeySelector>,
+    /// If specified, existing points are only overwritten if they match this filter.
+    /// Points that don't exist yet are inserted regardless.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub update_filter: Option<Filter>,
+    /// How to handle points, which already exist or don't exist yet. Default: `upsert`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_mode: Option<UpdateMode>,
+    /// Unique key of the request. Repeated requests with the same key return the result
+    /// of the first one, without applying the points again.
+    /// Not allowed in atomic batches, which only accept a key for the whole batch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1, max = 255))]
+    pub idempotency_key: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, JsonSchema, Validate)]
@@ -164,6 +212,20 @@ pub struct PointsList {
     pub points: Vec<PointStruct>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKeySelector>,
+    /// If specified, existing points are only overwritten if they match this filter.
+    /// Points that don't exist yet are inserted regardless.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub update_filter: Option<Filter>,
+    /// How to handle points, which already exist or don't exist yet. Default: `upsert`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_mode: Option<UpdateMode>,
+    /// Unique key of the request. Repeated requests with the same key return the result
+    /// of the first one, without applying the points again.
+    /// Not allowed in atomic batches, which only accept a key for the whole batch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1, max = 255))]
+    pub idempotency_key: Option<String>,
 }
 
 impl<'de> serde::Deserialize<'de> for PointInsertOperations {
@@ -214,11 +276,40 @@ impl Validate for PointInsertOperations {
 }
 
 impl PointInsertOperations {
-    pub fn decompose(self) -> (Option<ShardKeySelector>, PointInsertOperationsInternal) {
-        match self {
-            PointInsertOperations::PointsBatch(batch) => (batch.shard_key, batch.batch.into()),
-            PointInsertOperations::PointsList(list) => (list.shard_key, list.points.into()),
-        }
+    /// Split into shard key and the point operation to apply
+    pub fn decompose(self) -> (Option<ShardKeySelector>, PointOperations) {
+        let (shard_key, points_op, update_filter, update_mode, idempotency_key) = match self {
+            PointInsertOperations::PointsBatch(batch) => (
+                batch.shard_key,
+                batch.batch.into(),
+                batch.update_filter,
+                batch.update_mode,
+                batch.idempotency_key,
+            ),
+            PointInsertOperations::PointsList(list) => (
+                list.shard_key,
+                list.points.into(),
+                list.update_filter,
+                list.update_mode,
+                list.idempotency_key,
+            ),
+        };
+
+        let update_mode = update_mode.unwrap_or_default();
+
+        let operation =
+            if update_filter.is_none() && update_mode.is_upsert() && idempotency_key.is_none() {
+                PointOperations::UpsertPoints(points_op)
+            } else {
+                PointOperations::UpsertPointsConditional(ConditionalInsertOperationInternal {
+                    points_op,
+                    condition: update_filter,
+                    update_mode,
+                    idempotency_key,
+                })
+            };
+
+        (shard_key, operation)
     }
 }
 
@@ -320,6 +411,8 @@ impl From<Batch> for PointInsertOperations {
         PointInsertOperations::PointsBatch(PointsBatch {
             batch,
             shard_key: None,
+            update_filter: None,
+            update_mode: None,
         })
     }
 }
@@ -329,6 +422,8 @@ impl From<Vec<PointStruct>> for PointInsertOperations {
         PointInsertOperations::PointsList(PointsList {
             points,
             shard_key: None,
+            update_filter: None,
+            update_mode: None,
         })
     }
 }
@@ -345,27 +440,87 @@ impl From<Vec<PointStruct>> for PointInsertOperationsInternal {
     }
 }
 
+/// Upsert, restricted by the `condition` and the `update_mode`,
+/// and deduplicated by the `idempotency_key`
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ConditionalInsertOperationInternal {
+    pub points_op: PointInsertOperationsInternal,
+    /// Existing points are only overwritten if they match this filter
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub condition: Option<Filter>,
+    #[serde(default)]
+    pub update_mode: UpdateMode,
+    /// Each shard applies the operation only once per key, within the idempotency window
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+}
+
+impl ConditionalInsertOperationInternal {
+    /// Number of distinct points in the operation
+    pub fn points_count(&self) -> usize {
+        match &self.points_op {
+            PointInsertOperationsInternal::PointsBatch(batch) => {
+                batch.ids.iter().collect::<HashSet<_>>().len()
+            }
+            PointInsertOperationsInternal::PointsList(points) => points
+                .iter()
+                .map(|point| point.id)
+                .collect::<HashSet<_>>()
+                .len(),
+        }
+    }
+}
+
+impl SplitByShard for ConditionalInsertOperationInternal {
+    fn split_by_shard(self, ring: &HashRing) -> OperationToShard<Self> {
+        let ConditionalInsertOperationInternal {
+            points_op,
+            condition,
+            update_mode,
+            idempotency_key,
+        } = self;
+
+        points_op
+            .split_by_shard(ring)
+            .map(|points_op| ConditionalInsertOperationInternal {
+                points_op,
+                condition: condition.clone(),
+                update_mode,
+                idempotency_key: idempotency_key.clone(),
+            })
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize, EnumDiscriminants)]
 #[strum_discriminants(derive(EnumIter))]
 #[serde(rename_all = "snake_case")]
 pub enum PointOperations {
     /// Insert or update points
     UpsertPoints(PointInsertOperationsInternal),
+    /// Insert or update points, restricted by the condition and the update mode
+    UpsertPointsConditional(ConditionalInsertOperationInternal),
     /// Delete point if exists
     DeletePoints { ids: Vec<PointIdType> },
     /// Delete points by given filter criteria
     DeletePointsByFilter(Filter),
     /// Points Sync
     SyncPoints(PointSyncOperation),
+    /// Move points to trash, so they can be restored within the retention period
+    TrashPoints(TrashPointsOp),
+    /// Move points out of trash
+    RestorePoints(RestorePointsOp),
 }
 
 impl PointOperations {
     pub fn is_write_operation(&self) -> bool {
         match self {
             PointOperations::UpsertPoints(_) => true,
+            PointOperations::UpsertPointsConditional(_) => true,
             PointOperations::DeletePoints { .. } => false,
             PointOperations::DeletePointsByFilter(_) => false,
             PointOperations::SyncPoints(_) => true,
+            PointOperations::TrashPoints(_) => false,
+            PointOperations::RestorePoints(_) => true,
         }
     }
 }
@@ -374,9 +529,14 @@ impl Validate for PointOperations {
     fn validate(&self) -> Result<(), validator::ValidationErrors> {
         match self {
             PointOperations::UpsertPoints(upsert_points) => upsert_points.validate(),
+            PointOperations::UpsertPointsConditional(conditional_upsert) => {
+                conditional_upsert.points_op.validate()
+            }
             PointOperations::DeletePoints { ids: _ } => Ok(()),
             PointOperations::DeletePointsByFilter(_) => Ok(()),
             PointOperations::SyncPoints(_) => Ok(()),
+            PointOperations::TrashPoints(operation) => operation.validate(),
+            PointOperations::RestorePoints(operation) => operation.validate(),
         }
     }
 }
@@ -542,6 +702,9 @@ impl SplitByShard for PointOperations {
             PointOperations::UpsertPoints(upsert_points) => upsert_points
                 .split_by_shard(ring)
                 .map(PointOperations::UpsertPoints),
+            PointOperations::UpsertPointsConditional(conditional_upsert) => conditional_upsert
+                .split_by_shard(ring)
+                .map(PointOperations::UpsertPointsConditional),
             PointOperations::DeletePoints { ids } => split_iter_by_shard(ids, |id| *id, ring)
                 .map(|ids| PointOperations::DeletePoints { ids }),
             by_filter @ PointOperations::DeletePointsByFilter(_) => {
@@ -553,6 +716,12 @@ impl SplitByShard for PointOperations {
                 #[cfg(not(debug_assertions))]
                 OperationToShard::by_shard(vec![])
             }
+            PointOperations::TrashPoints(operation) => operation
+                .split_by_shard(ring)
+                .map(PointOperations::TrashPoints),
+            PointOperations::RestorePoints(operation) => operation
+                .split_by_shard(ring)
+                .map(PointOperations::RestorePoints),
         }
     }
 }
@@ -11,6 +11,8 @@ use crate::shards::transfer::ShardTransferMethod;
 const DEFAULT_SEARCH_TIMEOUT: Duration = Duration::from_secs(60);
 const DEFAULT_UPDATE_QUEUE_SIZE: usize = 100;
 const DEFAULT_UPDATE_QUEUE_SIZE_LISTENER: usize = 10_000;
+/// Default time, for which shards remember idempotency keys of applied upserts.
+const DEFAULT_IDEMPOTENCY_WINDOW: Duration = Duration::from_secs(60 * 60);
 pub const DEFAULT_IO_SHARD_TRANSFER_LIMIT: Option<usize> = Some(1);
 pub const DEFAULT_SNAPSHOTS_PATH: &str = "./snapshots";
 
@@ -31,6 +33,7 @@ pub struct SharedStorageConfig {
     pub outgoing_shard_transfers_limit: Option<usize>,
     pub snapshots_path: String,
     pub snapshots_config: SnapShotsConfig,
+    pub idempotency_window: Duration,
 }
 
 impl Default for SharedStorageConfig {
@@ -48,6 +51,7 @@ impl Default for SharedStorageConfig {
             outgoing_shard_transfers_limit: DEFAULT_IO_SHARD_TRANSFER_LIMIT,
             snapshots_path: DEFAULT_SNAPSHOTS_PATH.to_string(),
             snapshots_config: default::Default::default(),
+            idempotency_window: DEFAULT_IDEMPOTENCY_WINDOW,
         }
     }
 }
@@ -67,6 +71,7 @@ impl SharedStorageConfig {
         outgoing_shard_transfers_limit: Option<usize>,
         snapshots_path: String,
         snapshots_config: SnapShotsConfig,
+        idempotency_window: Option<Duration>,
     ) -> Self {
         let update_queue_size = update_queue_size.unwrap_or(match node_type {
             NodeType::Normal => DEFAULT_UPDATE_QUEUE_SIZE,
@@ -85,6 +90,7 @@ impl SharedStorageConfig {
             outgoing_shard_transfers_limit,
             snapshots_path,
             snapshots_config,
+            idempotency_window: idempotency_window.unwrap_or(DEFAULT_IDEMPOTENCY_WINDOW),
         }
     }
 }
@@ -0,0 +1,140 @@
+use chrono::{DateTime, Utc};
+use segment::types::{Filter, PointIdType};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use super::point_ops::{FilterSelector, PointIdsList, PointOperations, PointsSelector};
+use super::{
+    split_iter_by_shard, AtomicBatchOp, CollectionUpdateOperations, OperationToShard, SplitByShard,
+};
+use crate::hash_ring::HashRing;
+
+/// Move points to trash, instead of deleting them.
+///
+/// Points in trash are hidden like deleted ones, but keep their vectors and payload,
+/// until the optimizer purges them after the trash retention period.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, Validate)]
+pub struct TrashPointsOp {
+    /// Moves each point in this list to trash
+    pub points: Option<Vec<PointIdType>>,
+    /// Moves points that satisfy this filter condition to trash
+    pub filter: Option<Filter>,
+    /// Time the points are moved to trash, retention period starts from it
+    pub trashed_at: DateTime<Utc>,
+}
+
+/// Move points out of trash.
+///
+/// Points, which exist outside of trash, are not affected.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, Validate)]
+pub struct RestorePointsOp {
+    /// Restores each point in this list
+    pub points: Option<Vec<PointIdType>>,
+    /// Restores points in trash that satisfy this filter condition
+    pub filter: Option<Filter>,
+    /// Only points moved to trash after this time are restored, older ones are about to be purged.
+    /// Set by the collection from the trash retention period, before the operation is applied.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trashed_after: Option<DateTime<Utc>>,
+}
+
+impl From<PointsSelector> for RestorePointsOp {
+    fn from(points: PointsSelector) -> Self {
+        let (points, filter) = match points {
+            PointsSelector::PointIdsSelector(PointIdsList { points, .. }) => (Some(points), None),
+            PointsSelector::FilterSelector(FilterSelector { filter, .. }) => (None, Some(filter)),
+        };
+
+        RestorePointsOp {
+            points,
+            filter,
+            trashed_after: None,
+        }
+    }
+}
+
+impl SplitByShard for TrashPointsOp {
+    fn split_by_shard(self, ring: &HashRing) -> OperationToShard<Self> {
+        match (&self.points, &self.filter) {
+            (Some(_), _) => {
+                split_iter_by_shard(self.points.unwrap(), |id| *id, ring).map(|points| {
+                    TrashPointsOp {
+                        points: Some(points),
+                        filter: self.filter.clone(),
+                        trashed_at: self.trashed_at,
+                    }
+                })
+            }
+            (None, Some(_)) => OperationToShard::to_all(self),
+            (None, None) => OperationToShard::to_none(),
+        }
+    }
+}
+
+impl SplitByShard for RestorePointsOp {
+    fn split_by_shard(self, ring: &HashRing) -> OperationToShard<Self> {
+        match (&self.points, &self.filter) {
+            (Some(_), _) => {
+                split_iter_by_shard(self.points.unwrap(), |id| *id, ring).map(|points| {
+                    RestorePointsOp {
+                        points: Some(points),
+                        filter: self.filter.clone(),
+                        trashed_after: self.trashed_after,
+                    }
+                })
+            }
+            (None, Some(_)) => OperationToShard::to_all(self),
+            (None, None) => OperationToShard::to_none(),
+        }
+    }
+}
+
+impl CollectionUpdateOperations {
+    /// Replace point deletions with moving points to trash at the given time.
+    pub fn into_trash(self, trashed_at: DateTime<Utc>) -> Self {
+        let (points, filter) = match self {
+            CollectionUpdateOperations::PointOperation(PointOperations::DeletePoints { ids }) => {
+                (Some(ids), None)
+            }
+            CollectionUpdateOperations::PointOperation(PointOperations::DeletePointsByFilter(
+                filter,
+            )) => (None, Some(filter)),
+            CollectionUpdateOperations::AtomicBatch(batch) => {
+                let operations = batch
+                    .operations
+                    .into_iter()
+                    .map(|operation| operation.into_trash(trashed_at))
+                    .collect();
+                return CollectionUpdateOperations::AtomicBatch(AtomicBatchOp {
+                    operations,
+                    idempotency_key: batch.idempotency_key,
+                });
+            }
+            operation => return operation,
+        };
+
+        CollectionUpdateOperations::PointOperation(PointOperations::TrashPoints(TrashPointsOp {
+            points,
+            filter,
+            trashed_at,
+        }))
+    }
+
+    /// Operation, which moves points out of trash
+    pub fn is_restore_operation(&self) -> bool {
+        matches!(
+            self,
+            CollectionUpdateOperations::PointOperation(PointOperations::RestorePoints(_)),
+        )
+    }
+
+    /// Restore only points, which were moved to trash after the given time.
+    pub fn restore_trashed_after(&mut self, trashed_after: DateTime<Utc>) {
+        if let CollectionUpdateOperations::PointOperation(PointOperations::RestorePoints(
+            operation,
+        )) = self
+        {
+            operation.trashed_after = Some(trashed_after);
+        }
+    }
+}
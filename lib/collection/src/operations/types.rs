@@ -1,5 +1,5 @@
 use std::backtrace::Backtrace;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::error::Error as _;
 use std::fmt::Write as _;
 use std::iter;
@@ -261,17 +261,19 @@ pub struct RemoteShardInfo {
 
 /// `Acknowledged` - Request is saved to WAL and will be process in a queue.
 /// `Completed` - Request is completed, changes are actual.
-#[derive(Debug, Serialize, JsonSchema, PartialEq, Eq)]
+/// `DryRun` - Request is not applied, only the points it would affect are reported.
+#[derive(Debug, Serialize, JsonSchema, PartialEq, Eq, Clone, Copy)]
 #[serde(rename_all = "snake_case")]
 pub enum UpdateStatus {
     Acknowledged,
     Completed,
+    DryRun,
     /// Internal: update is rejected due to an outdated clock
     #[schemars(skip)]
     ClockRejected,
 }
 
-#[derive(Debug, Serialize, JsonSchema)]
+#[derive(Debug, Serialize, JsonSchema, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct UpdateResult {
     /// Sequential number of the operation
@@ -285,6 +287,40 @@ pub struct UpdateResult {
     /// Provided if incoming update request also specify clock tick
     #[serde(skip)]
     pub clock_tag: Option<ClockTag>,
+
+    /// Number of points, which were not written because of the `update_mode` or `update_filter`.
+    /// Only reported for restricted upserts, which waited for the result.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skipped: Option<usize>,
+
+    /// Points, which the operation would affect. Only reported for dry-run requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<DryRunResult>,
+}
+
+/// Points selected by the filter of an update operation
+#[derive(Debug, Serialize, JsonSchema, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct DryRunResult {
+    /// Exact number of points, which the operation would affect
+    pub affected_points: usize,
+    /// IDs of some of the affected points
+    pub sample: Vec<PointIdType>,
+}
+
+/// Safety checks of update operations, which select points by filter
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FilterUpdateChecks {
+    /// Only report the points, which the operation would affect, without applying it
+    pub dry_run: bool,
+    /// Reject the operation, if it would affect more points than this
+    pub max_affected_points: Option<usize>,
+}
+
+impl FilterUpdateChecks {
+    pub fn is_empty(&self) -> bool {
+        !self.dry_run && self.max_affected_points.is_none()
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
@@ -393,6 +429,10 @@ pub struct SearchRequest {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKeySelector>,
+    /// If true, the response explains how each shard and segment executed the search.
+    /// Ignored in batch requests.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explain: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
@@ -828,6 +868,8 @@ pub enum CollectionError {
     PreConditionFailed { description: String },
     #[error("Object Store error: {what}")]
     ObjectStoreError { what: String },
+    #[error("Strict mode error: {description}")]
+    StrictMode { description: String },
 }
 
 impl CollectionError {
@@ -902,6 +944,12 @@ impl CollectionError {
         }
     }
 
+    pub fn strict_mode(description: impl Into<String>) -> CollectionError {
+        CollectionError::StrictMode {
+            description: description.into(),
+        }
+    }
+
     /// Returns true if the error is transient and the operation can be retried.
     /// Returns false if the error is not transient and the operation should fail on all replicas.
     pub fn is_transient(&self) -> bool {
@@ -921,6 +969,7 @@ impl CollectionError {
             Self::InconsistentShardFailure { .. } => false,
             Self::ForwardProxyError { .. } => false,
             Self::ObjectStoreError { .. } => false,
+            Self::StrictMode { .. } => false,
         }
     }
 }
@@ -990,6 +1039,7 @@ impl From<OperationError> for CollectionError {
             },
             OperationError::WrongPayloadKey { description } => Self::BadInput { description },
             OperationError::MissingRangeIndexForOrderBy { .. } => Self::bad_input(format!("{err}")),
+            OperationError::MissingGeoIndexForOrderBy { .. } => Self::bad_input(format!("{err}")),
         }
     }
 }
@@ -1204,6 +1254,10 @@ pub enum Datatype {
     Float32,
     Uint8,
     Float16,
+    Bit,
+    #[serde(rename = "bfloat16")]
+    BFloat16,
+    Int8,
 }
 
 impl From<Datatype> for VectorStorageDatatype {
@@ -1212,12 +1266,16 @@ impl From<Datatype> for VectorStorageDatatype {
             Datatype::Float32 => VectorStorageDatatype::Float32,
             Datatype::Uint8 => VectorStorageDatatype::Uint8,
             Datatype::Float16 => VectorStorageDatatype::Float16,
+            Datatype::Bit => VectorStorageDatatype::Bit,
+            Datatype::BFloat16 => VectorStorageDatatype::BFloat16,
+            Datatype::Int8 => VectorStorageDatatype::Int8,
         }
     }
 }
 
 /// Params of single vector data storage
 #[derive(Debug, Hash, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq)]
+#[validate(schema(function = "validate_vector_params"))]
 #[serde(rename_all = "snake_case")]
 pub struct VectorParams {
     /// Size of a vectors used
@@ -1237,6 +1295,13 @@ pub struct VectorParams {
     )]
     #[validate]
     pub quantization_config: Option<QuantizationConfig>,
+    /// Custom params for quantizations, which are built in addition to the primary one.
+    /// Search can select one of them by kind with the `quantization` search param,
+    /// so all quantizations of a vector must be of different kinds.
+    /// Ignored if no primary quantization config is set.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[validate]
+    pub additional_quantization_configs: Vec<QuantizationConfig>,
     /// If true, vectors are served from disk, improving RAM usage at the cost of latency
     /// Default: false
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1252,10 +1317,67 @@ pub struct VectorParams {
     ///   2 bytes.
     /// - For `uint8` datatype - vectors are stored as unsigned 8-bit integers, 1 byte.
     ///   It expects vector elements to be in range `[0, 255]`.
+    /// - For `bit` datatype - vectors are stored as packed bits, 8 dimensions per byte.
+    ///   It expects `size` in bits and vectors of `ceil(size / 8)` bytes in range `[0, 255]`.
+    ///   Use with `Hamming` or `Jaccard` distance.
+    /// - For `bfloat16` datatype - vectors are stored as brain floating point numbers, 2 bytes.
+    ///   Keeps the range of `float32` at a lower precision than `float16`.
+    /// - For `int8` datatype - vectors are stored as signed 8-bit integers, 1 byte.
+    ///   It expects vector elements to be in range `[-128, 127]`.
     pub datatype: Option<Datatype>,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub multivector_config: Option<MultiVectorConfig>,
+
+    /// Name of another dense vector, which this vector is a prefix view of.
+    /// A prefix view indexes only the first `size` dimensions of its source vector, and does not
+    /// store vectors of its own. Vectors are written into the source vector only.
+    /// Useful for Matryoshka embeddings: search the view, then rescore with the full vector.
+    /// Prefix views are not quantized.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefix_of: Option<String>,
+}
+
+/// Validate that params of a single vector are consistent with each other
+fn validate_vector_params(params: &VectorParams) -> Result<(), ValidationError> {
+    if params.distance.is_bit() != (params.datatype == Some(Datatype::Bit)) {
+        return Err(vector_params_error(
+            "distance",
+            "`hamming` and `jaccard` distances must be used with `bit` datatype, and only with it",
+        ));
+    }
+
+    if params.distance.is_bit()
+        && (params.quantization_config.is_some()
+            || !params.additional_quantization_configs.is_empty())
+    {
+        return Err(vector_params_error(
+            "quantization",
+            "quantization is not supported for `hamming` and `jaccard` distances",
+        ));
+    }
+
+    // Search selects one of the quantizations by kind
+    let mut quantization_kinds = HashSet::new();
+    let distinct_quantization_kinds = params
+        .quantization_config
+        .iter()
+        .chain(&params.additional_quantization_configs)
+        .all(|config| quantization_kinds.insert(config.kind()));
+    if !distinct_quantization_kinds {
+        return Err(vector_params_error(
+            "quantization",
+            "all quantization configs of a vector must be of different kinds",
+        ));
+    }
+
+    Ok(())
+}
+
+fn vector_params_error(code: &'static str, message: &'static str) -> ValidationError {
+    let mut error = ValidationError::new(code);
+    error.message = Some(message.into());
+    error
 }
 
 /// Validate the value is in `[1, 65536]` or `None`.
@@ -1549,12 +1671,81 @@ impl Anonymize for VectorsConfig {
 impl Validate for VectorsConfig {
     fn validate(&self) -> Result<(), ValidationErrors> {
         match self {
-            VectorsConfig::Single(single) => single.validate(),
-            VectorsConfig::Multi(multi) => common::validation::validate_iter(multi.values()),
+            VectorsConfig::Single(single) => {
+                single.validate()?;
+                if single.prefix_of.is_some() {
+                    return Err(prefix_of_error(
+                        "a prefix view requires a source vector in the same collection",
+                    ));
+                }
+                Ok(())
+            }
+            VectorsConfig::Multi(multi) => {
+                common::validation::validate_iter(multi.values())?;
+                validate_prefix_views(multi)
+            }
         }
     }
 }
 
+fn prefix_of_error(message: impl Into<String>) -> ValidationErrors {
+    let mut errors = ValidationErrors::new();
+    errors.add("prefix_of", {
+        let mut error = ValidationError::new("prefix_of");
+        error.message = Some(message.into().into());
+        error
+    });
+    errors
+}
+
+/// Validate that each prefix view refers to a dense vector with compatible parameters
+fn validate_prefix_views(vectors: &BTreeMap<String, VectorParams>) -> Result<(), ValidationErrors> {
+    for (name, params) in vectors {
+        let Some(source_name) = &params.prefix_of else {
+            continue;
+        };
+        let Some(source) = vectors.get(source_name) else {
+            return Err(prefix_of_error(format!(
+                "source vector {source_name} of prefix view {name} does not exist"
+            )));
+        };
+        if source.prefix_of.is_some() {
+            return Err(prefix_of_error(format!(
+                "source vector {source_name} of prefix view {name} is a prefix view itself"
+            )));
+        }
+        if params.multivector_config.is_some() || source.multivector_config.is_some() {
+            return Err(prefix_of_error(format!(
+                "prefix view {name} can't be used with multivectors"
+            )));
+        }
+        if params.size > source.size {
+            return Err(prefix_of_error(format!(
+                "size of prefix view {name} exceeds size of source vector {source_name}"
+            )));
+        }
+        if params.distance != source.distance || params.datatype != source.datatype {
+            return Err(prefix_of_error(format!(
+                "prefix view {name} must have the same distance and datatype as source vector {source_name}"
+            )));
+        }
+        if source.datatype == Some(Datatype::Bit) {
+            return Err(prefix_of_error(format!(
+                "prefix view {name} can't be used with bit vectors"
+            )));
+        }
+        // Prefix views are scored on original vectors of the source, without quantization
+        if params.quantization_config.is_some()
+            || !params.additional_quantization_configs.is_empty()
+        {
+            return Err(prefix_of_error(format!(
+                "prefix view {name} can't have quantization config"
+            )));
+        }
+    }
+    Ok(())
+}
+
 impl From<VectorParams> for VectorsConfig {
     fn from(params: VectorParams) -> Self {
         VectorsConfig::Single(params)
@@ -611,6 +611,7 @@ pub mod from_grpc {
                 shard_key_selector: _,
                 lookup_from,
                 timeout: _,
+                explain: _,
             } = value;
 
             let request = CollectionQueryRequest {
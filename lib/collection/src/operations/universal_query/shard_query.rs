@@ -1,4 +1,5 @@
 use api::grpc::qdrant as grpc;
+use chrono::{DateTime, Utc};
 use common::types::ScoreType;
 use itertools::Itertools;
 use segment::data_types::order_by::OrderBy;
@@ -9,7 +10,7 @@ use tonic::Status;
 
 use crate::config::CollectionParams;
 use crate::operations::query_enum::QueryEnum;
-use crate::operations::types::CollectionResult;
+use crate::operations::types::{CollectionResult, CoreSearchRequest};
 
 /// Internal response type for a universal query request.
 ///
@@ -140,6 +141,17 @@ impl ShardQueryRequest {
 
         filters
     }
+
+    /// Resolve relative datetime ranges in all filters, including the ones of prefetches
+    pub fn resolve_relative_datetimes(&mut self, now: DateTime<Utc>) {
+        if let Some(filter) = &mut self.filter {
+            filter.resolve_relative_datetimes(now);
+        }
+
+        for prefetch in &mut self.prefetches {
+            prefetch.resolve_relative_datetimes(now);
+        }
+    }
 }
 
 impl ShardPrefetch {
@@ -154,6 +166,43 @@ impl ShardPrefetch {
 
         filters
     }
+
+    fn resolve_relative_datetimes(&mut self, now: DateTime<Utc>) {
+        if let Some(filter) = &mut self.filter {
+            filter.resolve_relative_datetimes(now);
+        }
+
+        for prefetch in &mut self.prefetches {
+            prefetch.resolve_relative_datetimes(now);
+        }
+    }
+}
+
+impl From<CoreSearchRequest> for ShardQueryRequest {
+    fn from(value: CoreSearchRequest) -> Self {
+        let CoreSearchRequest {
+            query,
+            filter,
+            params,
+            limit,
+            offset,
+            with_payload,
+            with_vector,
+            score_threshold,
+        } = value;
+
+        Self {
+            prefetches: vec![],
+            query: Some(ScoringQuery::Vector(query)),
+            filter,
+            score_threshold,
+            limit,
+            offset,
+            params,
+            with_vector: with_vector.unwrap_or_default(),
+            with_payload: with_payload.unwrap_or(WithPayloadInterface::Bool(false)),
+        }
+    }
 }
 
 impl TryFrom<grpc::QueryShardPoints> for ShardQueryRequest {
@@ -20,6 +20,10 @@ pub struct UpdateVectors {
     pub points: Vec<PointVectors>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKeySelector>,
+    /// If specified, vectors are only updated for points matching this filter
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate]
+    pub update_filter: Option<Filter>,
 }
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema)]
@@ -66,6 +70,9 @@ pub struct UpdateVectorsOp {
     #[validate]
     #[validate(length(min = 1, message = "must specify points to update"))]
     pub points: Vec<PointVectors>,
+    /// Only update points matching this filter
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_filter: Option<Filter>,
 }
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize, EnumDiscriminants)]
@@ -109,9 +116,11 @@ impl SplitByShard for Vec<PointVectors> {
 impl SplitByShard for VectorOperations {
     fn split_by_shard(self, ring: &HashRing) -> OperationToShard<Self> {
         match self {
-            VectorOperations::UpdateVectors(update_vectors) => {
-                let shard_points = update_vectors
-                    .points
+            VectorOperations::UpdateVectors(UpdateVectorsOp {
+                points,
+                update_filter,
+            }) => {
+                let shard_points = points
                     .into_iter()
                     .flat_map(|point| {
                         point_to_shards(&point.id, ring)
@@ -128,7 +137,10 @@ impl SplitByShard for VectorOperations {
                 let shard_ops = shard_points.into_iter().map(|(shard_id, points)| {
                     (
                         shard_id,
-                        VectorOperations::UpdateVectors(UpdateVectorsOp { points }),
+                        VectorOperations::UpdateVectors(UpdateVectorsOp {
+                            points,
+                            update_filter: update_filter.clone(),
+                        }),
                     )
                 });
                 OperationToShard::by_shard(shard_ops)
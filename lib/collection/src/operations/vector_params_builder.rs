@@ -17,9 +17,11 @@ impl VectorParamsBuilder {
                 distance,
                 hnsw_config: None,
                 quantization_config: None,
+                additional_quantization_configs: Vec::new(),
                 on_disk: None,
                 datatype: None,
                 multivector_config: None,
+                prefix_of: None,
             },
         }
     }
@@ -34,6 +36,16 @@ impl VectorParamsBuilder {
         self
     }
 
+    pub fn with_additional_quantization_config(
+        mut self,
+        quantization_config: QuantizationConfig,
+    ) -> Self {
+        self.vector_params
+            .additional_quantization_configs
+            .push(quantization_config);
+        self
+    }
+
     pub fn with_on_disk(mut self, on_disk: bool) -> Self {
         self.vector_params.on_disk = Some(on_disk);
         self
@@ -49,6 +61,11 @@ impl VectorParamsBuilder {
         self
     }
 
+    pub fn with_prefix_of(mut self, source_name: impl Into<String>) -> Self {
+        self.vector_params.prefix_of = Some(source_name.into());
+        self
+    }
+
     pub fn build(self) -> VectorParams {
         self.vector_params
     }
@@ -0,0 +1,296 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use itertools::Itertools;
+use segment::problems::UnindexedField;
+use segment::types::{Filter, PayloadFieldSchema, PayloadKeyType, SearchParams};
+
+use crate::config::StrictModeConfig;
+use crate::operations::types::{
+    CollectionError, CollectionResult, CoreSearchRequest, CountRequestInternal,
+    ScrollRequestInternal,
+};
+use crate::operations::universal_query::shard_query::{ShardPrefetch, ShardQueryRequest};
+
+/// Requests which can be checked against the strict mode config of a collection
+pub trait StrictModeVerification {
+    /// Number of points requested, including the ones of nested requests
+    fn query_limits(&self) -> Vec<usize>;
+
+    /// Number of points skipped
+    fn query_offset(&self) -> Option<usize>;
+
+    /// Filters which are applied to read points
+    fn retrieve_filters(&self) -> Vec<&Filter>;
+
+    /// Search params, including the ones of nested requests
+    fn search_params(&self) -> Vec<&SearchParams>;
+
+    /// Check the request against the strict mode config.
+    ///
+    /// The config is expected to be enabled, see [`StrictModeConfig::is_enabled`].
+    fn check_strict_mode(
+        &self,
+        config: &StrictModeConfig,
+        payload_schema: &HashMap<PayloadKeyType, PayloadFieldSchema>,
+    ) -> CollectionResult<()> {
+        if let Some(max_query_limit) = config.max_query_limit {
+            for limit in self.query_limits() {
+                check_limit("limit", limit, max_query_limit)?;
+            }
+        }
+
+        if let (Some(max_query_offset), Some(offset)) =
+            (config.max_query_offset, self.query_offset())
+        {
+            check_limit("offset", offset, max_query_offset)?;
+        }
+
+        if let Some(search_max_hnsw_ef) = config.search_max_hnsw_ef {
+            for hnsw_ef in self.search_params().into_iter().filter_map(|p| p.hnsw_ef) {
+                check_limit("hnsw_ef", hnsw_ef, search_max_hnsw_ef)?;
+            }
+        }
+
+        if !config.unindexed_filtering_retrieve.unwrap_or(true) {
+            for filter in self.retrieve_filters() {
+                check_filter_indexed(filter, payload_schema)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn check_limit(name: &str, value: usize, max: usize) -> CollectionResult<()> {
+    if value > max {
+        return Err(CollectionError::strict_mode(format!(
+            "{name} is {value}, but at most {max} is allowed in this collection"
+        )));
+    }
+    Ok(())
+}
+
+/// Check that every field of the filter is served by a payload index
+pub fn check_filter_indexed(
+    filter: &Filter,
+    payload_schema: &HashMap<PayloadKeyType, PayloadFieldSchema>,
+) -> CollectionResult<()> {
+    let unindexed_fields = UnindexedField::get_unindexed_fields(filter, payload_schema);
+
+    // Report the first field by name, to keep the error message stable
+    let Some((field_name, field_schemas)) = unindexed_fields
+        .into_iter()
+        .min_by_key(|(field_name, _)| field_name.to_string())
+    else {
+        return Ok(());
+    };
+
+    Err(CollectionError::strict_mode(format!(
+        "Filtering on unindexed field \"{field_name}\" is not allowed in this collection. \
+         Create a payload index of one of the types [{}] for it",
+        field_schemas.iter().map(|schema| schema.name()).join(", "),
+    )))
+}
+
+/// Resolve the timeout of a request under the strict mode config
+///
+/// Requests without a timeout get the max allowed one.
+pub fn check_timeout(
+    timeout: Option<Duration>,
+    config: &StrictModeConfig,
+) -> CollectionResult<Option<Duration>> {
+    let Some(max_timeout) = config
+        .max_timeout
+        .map(|secs| Duration::from_secs(secs as u64))
+    else {
+        return Ok(timeout);
+    };
+
+    match timeout {
+        Some(timeout) if timeout > max_timeout => Err(CollectionError::strict_mode(format!(
+            "timeout is {} seconds, but at most {} seconds are allowed in this collection",
+            timeout.as_secs_f32(),
+            max_timeout.as_secs(),
+        ))),
+        Some(timeout) => Ok(Some(timeout)),
+        None => Ok(Some(max_timeout)),
+    }
+}
+
+impl StrictModeVerification for CoreSearchRequest {
+    fn query_limits(&self) -> Vec<usize> {
+        vec![self.limit]
+    }
+
+    fn query_offset(&self) -> Option<usize> {
+        Some(self.offset)
+    }
+
+    fn retrieve_filters(&self) -> Vec<&Filter> {
+        self.filter.iter().collect()
+    }
+
+    fn search_params(&self) -> Vec<&SearchParams> {
+        self.params.iter().collect()
+    }
+}
+
+impl StrictModeVerification for ShardQueryRequest {
+    fn query_limits(&self) -> Vec<usize> {
+        let mut limits = vec![self.limit];
+        for prefetch in &self.prefetches {
+            prefetch.collect_limits(&mut limits);
+        }
+        limits
+    }
+
+    fn query_offset(&self) -> Option<usize> {
+        Some(self.offset)
+    }
+
+    fn retrieve_filters(&self) -> Vec<&Filter> {
+        let mut filters = self.filter.iter().collect();
+        for prefetch in &self.prefetches {
+            prefetch.collect_filters(&mut filters);
+        }
+        filters
+    }
+
+    fn search_params(&self) -> Vec<&SearchParams> {
+        let mut params = self.params.iter().collect();
+        for prefetch in &self.prefetches {
+            prefetch.collect_params(&mut params);
+        }
+        params
+    }
+}
+
+impl ShardPrefetch {
+    fn collect_limits(&self, limits: &mut Vec<usize>) {
+        limits.push(self.limit);
+        for prefetch in &self.prefetches {
+            prefetch.collect_limits(limits);
+        }
+    }
+
+    fn collect_filters<'a>(&'a self, filters: &mut Vec<&'a Filter>) {
+        filters.extend(&self.filter);
+        for prefetch in &self.prefetches {
+            prefetch.collect_filters(filters);
+        }
+    }
+
+    fn collect_params<'a>(&'a self, params: &mut Vec<&'a SearchParams>) {
+        params.extend(&self.params);
+        for prefetch in &self.prefetches {
+            prefetch.collect_params(params);
+        }
+    }
+}
+
+impl StrictModeVerification for ScrollRequestInternal {
+    fn query_limits(&self) -> Vec<usize> {
+        self.limit.into_iter().collect()
+    }
+
+    fn query_offset(&self) -> Option<usize> {
+        None
+    }
+
+    fn retrieve_filters(&self) -> Vec<&Filter> {
+        self.filter.iter().collect()
+    }
+
+    fn search_params(&self) -> Vec<&SearchParams> {
+        Vec::new()
+    }
+}
+
+impl StrictModeVerification for CountRequestInternal {
+    fn query_limits(&self) -> Vec<usize> {
+        Vec::new()
+    }
+
+    fn query_offset(&self) -> Option<usize> {
+        None
+    }
+
+    fn retrieve_filters(&self) -> Vec<&Filter> {
+        self.filter.iter().collect()
+    }
+
+    fn search_params(&self) -> Vec<&SearchParams> {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use segment::types::{Condition, FieldCondition, Match, PayloadSchemaType, ValueVariants};
+
+    use super::*;
+
+    fn strict_mode_config() -> StrictModeConfig {
+        StrictModeConfig {
+            enabled: Some(true),
+            max_query_limit: Some(10),
+            max_query_offset: Some(100),
+            max_timeout: Some(5),
+            unindexed_filtering_retrieve: Some(false),
+            unindexed_filtering_update: None,
+            search_max_hnsw_ef: Some(64),
+        }
+    }
+
+    fn scroll_request(limit: usize, key: &str) -> ScrollRequestInternal {
+        ScrollRequestInternal {
+            limit: Some(limit),
+            filter: Some(Filter::new_must(Condition::Field(
+                FieldCondition::new_match(
+                    key.parse().unwrap(),
+                    Match::new_value(ValueVariants::Integer(1)),
+                ),
+            ))),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_strict_mode_scroll() {
+        let config = strict_mode_config();
+        let payload_schema = HashMap::from([(
+            "indexed".parse().unwrap(),
+            PayloadFieldSchema::FieldType(PayloadSchemaType::Integer),
+        )]);
+
+        scroll_request(10, "indexed")
+            .check_strict_mode(&config, &payload_schema)
+            .unwrap();
+
+        let err = scroll_request(11, "indexed")
+            .check_strict_mode(&config, &payload_schema)
+            .unwrap_err();
+        assert!(matches!(err, CollectionError::StrictMode { .. }));
+
+        let err = scroll_request(10, "unindexed")
+            .check_strict_mode(&config, &payload_schema)
+            .unwrap_err();
+        assert!(matches!(err, CollectionError::StrictMode { .. }));
+    }
+
+    #[test]
+    fn test_strict_mode_timeout() {
+        let config = strict_mode_config();
+
+        assert_eq!(
+            check_timeout(None, &config).unwrap(),
+            Some(Duration::from_secs(5)),
+        );
+        assert_eq!(
+            check_timeout(Some(Duration::from_secs(2)), &config).unwrap(),
+            Some(Duration::from_secs(2)),
+        );
+        assert!(check_timeout(Some(Duration::from_secs(6)), &config).is_err());
+    }
+}
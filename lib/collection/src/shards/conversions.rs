@@ -1,12 +1,18 @@
-use api::grpc::conversions::{convert_shard_key_from_grpc_opt, payload_to_proto};
+use api::grpc::conversions::{
+    convert_shard_key_from_grpc_opt, date_time_to_proto, payload_to_proto,
+};
 use api::grpc::qdrant::points_selector::PointsSelectorOneOf;
+use api::grpc::qdrant::points_update_operation::{Operation, PointStructList};
+use api::grpc::qdrant::subscribe_updates_response::Update;
 use api::grpc::qdrant::{
-    ClearPayloadPoints, ClearPayloadPointsInternal, CreateFieldIndexCollection,
-    CreateFieldIndexCollectionInternal, DeleteFieldIndexCollection,
+    points_update_operation, ClearPayloadPoints, ClearPayloadPointsInternal,
+    CreateFieldIndexCollection, CreateFieldIndexCollectionInternal, DeleteFieldIndexCollection,
     DeleteFieldIndexCollectionInternal, DeletePayloadPoints, DeletePayloadPointsInternal,
-    DeletePointVectors, DeletePoints, DeletePointsInternal, DeleteVectorsInternal, PointVectors,
-    PointsIdsList, PointsSelector, SetPayloadPoints, SetPayloadPointsInternal, SyncPoints,
-    SyncPointsInternal, UpdatePointVectors, UpdateVectorsInternal, UpsertPoints,
+    DeletePointVectors, DeletePoints, DeletePointsInternal, DeleteVectorsInternal,
+    MutatePayloadPoints, MutatePayloadPointsInternal, PointVectors, PointsIdsList, PointsSelector,
+    PointsUpdateOperation, RestorePoints, RestorePointsInternal, SetPayloadPoints,
+    SetPayloadPointsInternal, SyncPoints, SyncPointsInternal, UpdateBatchInternal,
+    UpdateBatchPoints, UpdatePointVectors, UpdateVectorsInternal, UpsertPoints,
     UpsertPointsInternal, VectorsSelector,
 };
 use segment::data_types::vectors::VectorStructInternal;
@@ -15,13 +21,16 @@ use segment::types::{Filter, PayloadFieldSchema, PayloadSchemaParams, PointIdTyp
 use tonic::Status;
 
 use crate::operations::conversions::write_ordering_to_proto;
-use crate::operations::payload_ops::{DeletePayloadOp, SetPayloadOp};
+use crate::operations::payload_ops::{DeletePayloadOp, MutatePayloadOp, PayloadOps, SetPayloadOp};
 use crate::operations::point_ops::{
-    PointInsertOperationsInternal, PointSyncOperation, WriteOrdering,
+    PointInsertOperationsInternal, PointOperations, PointSyncOperation, UpdateMode, WriteOrdering,
+};
+use crate::operations::trash::{RestorePointsOp, TrashPointsOp};
+use crate::operations::types::{CollectionError, CollectionResult};
+use crate::operations::vector_ops::{UpdateVectorsOp, VectorOperations};
+use crate::operations::{
+    AtomicBatchOp, ClockTag, CollectionUpdateOperations, CreateIndex, FieldIndexOperations,
 };
-use crate::operations::types::CollectionResult;
-use crate::operations::vector_ops::UpdateVectorsOp;
-use crate::operations::{ClockTag, CreateIndex};
 use crate::shards::shard::ShardId;
 
 pub fn internal_sync_points(
@@ -50,11 +59,15 @@ pub fn internal_sync_points(
     })
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn internal_upsert_points(
     shard_id: Option<ShardId>,
     clock_tag: Option<ClockTag>,
     collection_name: String,
     point_insert_operations: PointInsertOperationsInternal,
+    update_filter: Option<Filter>,
+    update_mode: Option<UpdateMode>,
+    idempotency_key: Option<String>,
     wait: bool,
     ordering: Option<WriteOrdering>,
 ) -> CollectionResult<UpsertPointsInternal> {
@@ -64,19 +77,28 @@ pub fn internal_upsert_points(
         upsert_points: Some(UpsertPoints {
             collection_name,
             wait: Some(wait),
-            points: match point_insert_operations {
-                PointInsertOperationsInternal::PointsBatch(batch) => batch.try_into()?,
-                PointInsertOperationsInternal::PointsList(list) => list
-                    .into_iter()
-                    .map(|id| id.try_into())
-                    .collect::<Result<Vec<_>, Status>>()?,
-            },
+            points: points_to_grpc(point_insert_operations)?,
             ordering: ordering.map(write_ordering_to_proto),
             shard_key_selector: None,
+            update_filter: update_filter.map(Into::into),
+            update_mode: update_mode.map(|mode| api::grpc::qdrant::UpdateMode::from(mode) as i32),
+            idempotency_key,
         }),
     })
 }
 
+fn points_to_grpc(
+    point_insert_operations: PointInsertOperationsInternal,
+) -> Result<Vec<api::grpc::qdrant::PointStruct>, Status> {
+    match point_insert_operations {
+        PointInsertOperationsInternal::PointsBatch(batch) => batch.try_into(),
+        PointInsertOperationsInternal::PointsList(list) => list
+            .into_iter()
+            .map(|id| id.try_into())
+            .collect::<Result<Vec<_>, Status>>(),
+    }
+}
+
 pub fn internal_delete_points(
     shard_id: Option<ShardId>,
     clock_tag: Option<ClockTag>,
@@ -98,7 +120,10 @@ pub fn internal_delete_points(
             }),
             ordering: ordering.map(write_ordering_to_proto),
             shard_key_selector: None,
+            dry_run: None,
+            max_affected_points: None,
         }),
+        trashed_at: None,
     }
 }
 
@@ -121,7 +146,68 @@ pub fn internal_delete_points_by_filter(
             }),
             ordering: ordering.map(write_ordering_to_proto),
             shard_key_selector: None,
+            dry_run: None,
+            max_affected_points: None,
+        }),
+        trashed_at: None,
+    }
+}
+
+pub fn internal_trash_points(
+    shard_id: Option<ShardId>,
+    clock_tag: Option<ClockTag>,
+    collection_name: String,
+    trash_points: TrashPointsOp,
+    wait: bool,
+    ordering: Option<WriteOrdering>,
+) -> DeletePointsInternal {
+    let TrashPointsOp {
+        points,
+        filter,
+        trashed_at,
+    } = trash_points;
+
+    DeletePointsInternal {
+        shard_id,
+        clock_tag: clock_tag.map(Into::into),
+        delete_points: Some(DeletePoints {
+            collection_name,
+            wait: Some(wait),
+            points: payload_points_selector(points, filter),
+            ordering: ordering.map(write_ordering_to_proto),
+            shard_key_selector: None,
+            dry_run: None,
+            max_affected_points: None,
+        }),
+        trashed_at: Some(date_time_to_proto(trashed_at.into())),
+    }
+}
+
+pub fn internal_restore_points(
+    shard_id: Option<ShardId>,
+    clock_tag: Option<ClockTag>,
+    collection_name: String,
+    restore_points: RestorePointsOp,
+    wait: bool,
+    ordering: Option<WriteOrdering>,
+) -> RestorePointsInternal {
+    let RestorePointsOp {
+        points,
+        filter,
+        trashed_after,
+    } = restore_points;
+
+    RestorePointsInternal {
+        shard_id,
+        clock_tag: clock_tag.map(Into::into),
+        restore_points: Some(RestorePoints {
+            collection_name,
+            wait: Some(wait),
+            points: payload_points_selector(points, filter),
+            ordering: ordering.map(write_ordering_to_proto),
+            shard_key_selector: None,
         }),
+        trashed_after: trashed_after.map(|trashed_after| date_time_to_proto(trashed_after.into())),
     }
 }
 
@@ -149,6 +235,7 @@ pub fn internal_update_vectors(
                 .collect(),
             ordering: ordering.map(write_ordering_to_proto),
             shard_key_selector: None,
+            update_filter: update_vectors.update_filter.map(Into::into),
         }),
     }
 }
@@ -240,6 +327,46 @@ pub fn internal_set_payload(
             ordering: ordering.map(write_ordering_to_proto),
             shard_key_selector: None,
             key: set_payload.key.map(|key| key.to_string()),
+            dry_run: None,
+            max_affected_points: None,
+        }),
+    }
+}
+
+pub fn internal_mutate_payload(
+    shard_id: Option<ShardId>,
+    clock_tag: Option<ClockTag>,
+    collection_name: String,
+    mutate_payload: MutatePayloadOp,
+    wait: bool,
+    ordering: Option<WriteOrdering>,
+) -> MutatePayloadPointsInternal {
+    let points_selector = if let Some(points) = mutate_payload.points {
+        Some(PointsSelector {
+            points_selector_one_of: Some(PointsSelectorOneOf::Points(PointsIdsList {
+                ids: points.into_iter().map(|id| id.into()).collect(),
+            })),
+        })
+    } else {
+        mutate_payload.filter.map(|filter| PointsSelector {
+            points_selector_one_of: Some(PointsSelectorOneOf::Filter(filter.into())),
+        })
+    };
+
+    MutatePayloadPointsInternal {
+        shard_id,
+        clock_tag: clock_tag.map(Into::into),
+        mutate_payload_points: Some(MutatePayloadPoints {
+            collection_name,
+            wait: Some(wait),
+            mutations: mutate_payload
+                .mutations
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            points_selector,
+            ordering: ordering.map(write_ordering_to_proto),
+            shard_key_selector: None,
         }),
     }
 }
@@ -278,6 +405,8 @@ pub fn internal_delete_payload(
             points_selector,
             ordering: ordering.map(write_ordering_to_proto),
             shard_key_selector: None,
+            dry_run: None,
+            max_affected_points: None,
         }),
     }
 }
@@ -303,6 +432,8 @@ pub fn internal_clear_payload(
             }),
             ordering: ordering.map(write_ordering_to_proto),
             shard_key_selector: None,
+            dry_run: None,
+            max_affected_points: None,
         }),
     }
 }
@@ -326,6 +457,8 @@ pub fn internal_clear_payload_by_filter(
             }),
             ordering: ordering.map(write_ordering_to_proto),
             shard_key_selector: None,
+            dry_run: None,
+            max_affected_points: None,
         }),
     }
 }
@@ -415,6 +548,402 @@ pub fn internal_delete_index(
     }
 }
 
+fn point_ids_selector(ids: Vec<PointIdType>) -> PointsSelector {
+    PointsSelector {
+        points_selector_one_of: Some(PointsSelectorOneOf::Points(PointsIdsList {
+            ids: ids.into_iter().map(|id| id.into()).collect(),
+        })),
+    }
+}
+
+fn filter_selector(filter: Filter) -> PointsSelector {
+    PointsSelector {
+        points_selector_one_of: Some(PointsSelectorOneOf::Filter(filter.into())),
+    }
+}
+
+fn payload_points_selector(
+    points: Option<Vec<PointIdType>>,
+    filter: Option<Filter>,
+) -> Option<PointsSelector> {
+    match points {
+        Some(points) => Some(point_ids_selector(points)),
+        None => filter.map(filter_selector),
+    }
+}
+
+/// Convert a single operation of an atomic batch into its gRPC representation.
+///
+/// Shard key is never set, as the batch is always sent to a specific shard.
+fn batch_operation_to_grpc(
+    operation: CollectionUpdateOperations,
+) -> CollectionResult<PointsUpdateOperation> {
+    let operation = match operation {
+        CollectionUpdateOperations::PointOperation(point_operation) => match point_operation {
+            PointOperations::UpsertPoints(points_op) => Operation::Upsert(PointStructList {
+                points: points_to_grpc(points_op)?,
+                shard_key_selector: None,
+                update_filter: None,
+                update_mode: None,
+            }),
+            PointOperations::UpsertPointsConditional(conditional_upsert) => {
+                Operation::Upsert(PointStructList {
+                    points: points_to_grpc(conditional_upsert.points_op)?,
+                    shard_key_selector: None,
+                    update_filter: conditional_upsert.condition.map(Into::into),
+                    update_mode: Some(api::grpc::qdrant::UpdateMode::from(
+                        conditional_upsert.update_mode,
+                    ) as i32),
+                })
+            }
+            PointOperations::DeletePoints { ids } => {
+                Operation::DeletePoints(points_update_operation::DeletePoints {
+                    points: Some(point_ids_selector(ids)),
+                    shard_key_selector: None,
+                })
+            }
+            PointOperations::DeletePointsByFilter(filter) => {
+                Operation::DeletePoints(points_update_operation::DeletePoints {
+                    points: Some(filter_selector(filter)),
+                    shard_key_selector: None,
+                })
+            }
+            // Sent as a deletion, which the peer moves to trash at `trashed_at` of the batch,
+            // see `internal_update_batch`
+            PointOperations::TrashPoints(trash_points) => {
+                Operation::DeletePoints(points_update_operation::DeletePoints {
+                    points: payload_points_selector(trash_points.points, trash_points.filter),
+                    shard_key_selector: None,
+                })
+            }
+            PointOperations::SyncPoints(_) => {
+                return Err(CollectionError::bad_request(
+                    "Sync operation is not supported in atomic batch",
+                ))
+            }
+            PointOperations::RestorePoints(_) => {
+                return Err(CollectionError::bad_request(
+                    "Restore operation is not supported in atomic batch",
+                ))
+            }
+        },
+        CollectionUpdateOperations::VectorOperation(vector_operation) => match vector_operation {
+            VectorOperations::UpdateVectors(update_vectors) => {
+                Operation::UpdateVectors(points_update_operation::UpdateVectors {
+                    points: update_vectors
+                        .points
+                        .into_iter()
+                        .map(|point| PointVectors {
+                            id: Some(point.id.into()),
+                            vectors: Some(VectorStructInternal::from(point.vector).into()),
+                        })
+                        .collect(),
+                    shard_key_selector: None,
+                    update_filter: update_vectors.update_filter.map(Into::into),
+                })
+            }
+            VectorOperations::DeleteVectors(points, vector_names) => {
+                Operation::DeleteVectors(points_update_operation::DeleteVectors {
+                    points_selector: Some(point_ids_selector(points.points)),
+                    vectors: Some(VectorsSelector {
+                        names: vector_names,
+                    }),
+                    shard_key_selector: None,
+                })
+            }
+            VectorOperations::DeleteVectorsByFilter(filter, vector_names) => {
+                Operation::DeleteVectors(points_update_operation::DeleteVectors {
+                    points_selector: Some(filter_selector(filter)),
+                    vectors: Some(VectorsSelector {
+                        names: vector_names,
+                    }),
+                    shard_key_selector: None,
+                })
+            }
+        },
+        CollectionUpdateOperations::PayloadOperation(payload_operation) => {
+            match payload_operation {
+                PayloadOps::SetPayload(set_payload) => {
+                    Operation::SetPayload(points_update_operation::SetPayload {
+                        payload: payload_to_proto(set_payload.payload),
+                        points_selector: payload_points_selector(
+                            set_payload.points,
+                            set_payload.filter,
+                        ),
+                        shard_key_selector: None,
+                        key: set_payload.key.map(|key| key.to_string()),
+                    })
+                }
+                PayloadOps::OverwritePayload(set_payload) => {
+                    Operation::OverwritePayload(points_update_operation::OverwritePayload {
+                        payload: payload_to_proto(set_payload.payload),
+                        points_selector: payload_points_selector(
+                            set_payload.points,
+                            set_payload.filter,
+                        ),
+                        shard_key_selector: None,
+                        key: None,
+                    })
+                }
+                PayloadOps::DeletePayload(delete_payload) => {
+                    Operation::DeletePayload(points_update_operation::DeletePayload {
+                        keys: delete_payload
+                            .keys
+                            .into_iter()
+                            .map(|key| key.to_string())
+                            .collect(),
+                        points_selector: payload_points_selector(
+                            delete_payload.points,
+                            delete_payload.filter,
+                        ),
+                        shard_key_selector: None,
+                    })
+                }
+                PayloadOps::ClearPayload { points } => {
+                    Operation::ClearPayload(points_update_operation::ClearPayload {
+                        points: Some(point_ids_selector(points)),
+                        shard_key_selector: None,
+                    })
+                }
+                PayloadOps::ClearPayloadByFilter(filter) => {
+                    Operation::ClearPayload(points_update_operation::ClearPayload {
+                        points: Some(filter_selector(filter)),
+                        shard_key_selector: None,
+                    })
+                }
+                PayloadOps::MutatePayload(_) => {
+                    return Err(CollectionError::bad_request(
+                        "Payload mutation is not supported in atomic batch",
+                    ))
+                }
+            }
+        }
+        CollectionUpdateOperations::FieldIndexOperation(_)
+        | CollectionUpdateOperations::AtomicBatch(_) => {
+            return Err(CollectionError::bad_request(
+                "Only point, vector and payload updates are supported in atomic batch",
+            ))
+        }
+    };
+
+    Ok(PointsUpdateOperation {
+        operation: Some(operation),
+    })
+}
+
+pub fn internal_update_batch(
+    shard_id: Option<ShardId>,
+    clock_tag: Option<ClockTag>,
+    collection_name: String,
+    batch: AtomicBatchOp,
+    wait: bool,
+    ordering: Option<WriteOrdering>,
+) -> CollectionResult<UpdateBatchInternal> {
+    // All deletions of a batch are moved to trash at the same time
+    let trashed_at = batch
+        .operations
+        .iter()
+        .find_map(|operation| match operation {
+            CollectionUpdateOperations::PointOperation(PointOperations::TrashPoints(
+                trash_points,
+            )) => Some(date_time_to_proto(trash_points.trashed_at.into())),
+            _ => None,
+        });
+
+    Ok(UpdateBatchInternal {
+        shard_id,
+        clock_tag: clock_tag.map(Into::into),
+        trashed_at,
+        update_batch_points: Some(UpdateBatchPoints {
+            collection_name,
+            wait: Some(wait),
+            operations: batch
+                .operations
+                .into_iter()
+                .map(batch_operation_to_grpc)
+                .collect::<CollectionResult<_>>()?,
+            ordering: ordering.map(write_ordering_to_proto),
+            atomic: Some(true),
+            idempotency_key: batch.idempotency_key,
+        }),
+    })
+}
+
+/// Convert an update operation into the public gRPC messages, to stream collection updates
+///
+/// Points, copied by a shard transfer with sync, are reported as an upsert.
+/// Points removed by the sync are not reported, as they are removed from the source shard first.
+/// Points moved to trash are reported as deleted, and restored points as restored.
+pub fn update_operation_to_grpc(
+    collection_name: String,
+    operation: CollectionUpdateOperations,
+) -> CollectionResult<Option<Update>> {
+    let update = match operation {
+        CollectionUpdateOperations::PointOperation(point_operation) => match point_operation {
+            PointOperations::UpsertPoints(points) => internal_upsert_points(
+                None,
+                None,
+                collection_name,
+                points,
+                None,
+                None,
+                None,
+                false,
+                None,
+            )?
+            .upsert_points
+            .map(Update::UpsertPoints),
+            PointOperations::UpsertPointsConditional(conditional_upsert) => internal_upsert_points(
+                None,
+                None,
+                collection_name,
+                conditional_upsert.points_op,
+                conditional_upsert.condition,
+                Some(conditional_upsert.update_mode),
+                conditional_upsert.idempotency_key,
+                false,
+                None,
+            )?
+            .upsert_points
+            .map(Update::UpsertPoints),
+            PointOperations::DeletePoints { ids } => {
+                internal_delete_points(None, None, collection_name, ids, false, None)
+                    .delete_points
+                    .map(Update::DeletePoints)
+            }
+            PointOperations::DeletePointsByFilter(filter) => {
+                internal_delete_points_by_filter(None, None, collection_name, filter, false, None)
+                    .delete_points
+                    .map(Update::DeletePoints)
+            }
+            PointOperations::SyncPoints(sync_points) => internal_upsert_points(
+                None,
+                None,
+                collection_name,
+                PointInsertOperationsInternal::PointsList(sync_points.points),
+                None,
+                None,
+                None,
+                false,
+                None,
+            )?
+            .upsert_points
+            .map(Update::UpsertPoints),
+            PointOperations::TrashPoints(trash_points) => {
+                internal_trash_points(None, None, collection_name, trash_points, false, None)
+                    .delete_points
+                    .map(Update::DeletePoints)
+            }
+            PointOperations::RestorePoints(restore_points) => {
+                internal_restore_points(None, None, collection_name, restore_points, false, None)
+                    .restore_points
+                    .map(Update::RestorePoints)
+            }
+        },
+        CollectionUpdateOperations::VectorOperation(vector_operation) => match vector_operation {
+            VectorOperations::UpdateVectors(update_vectors) => {
+                internal_update_vectors(None, None, collection_name, update_vectors, false, None)
+                    .update_vectors
+                    .map(Update::UpdateVectors)
+            }
+            VectorOperations::DeleteVectors(points, vector_names) => internal_delete_vectors(
+                None,
+                None,
+                collection_name,
+                points.points,
+                vector_names,
+                false,
+                None,
+            )
+            .delete_vectors
+            .map(Update::DeleteVectors),
+            VectorOperations::DeleteVectorsByFilter(filter, vector_names) => {
+                internal_delete_vectors_by_filter(
+                    None,
+                    None,
+                    collection_name,
+                    filter,
+                    vector_names,
+                    false,
+                    None,
+                )
+                .delete_vectors
+                .map(Update::DeleteVectors)
+            }
+        },
+        CollectionUpdateOperations::PayloadOperation(payload_operation) => {
+            match payload_operation {
+                PayloadOps::SetPayload(set_payload) => {
+                    internal_set_payload(None, None, collection_name, set_payload, false, None)
+                        .set_payload_points
+                        .map(Update::SetPayload)
+                }
+                PayloadOps::OverwritePayload(set_payload) => {
+                    internal_set_payload(None, None, collection_name, set_payload, false, None)
+                        .set_payload_points
+                        .map(Update::OverwritePayload)
+                }
+                PayloadOps::MutatePayload(mutate_payload) => internal_mutate_payload(
+                    None,
+                    None,
+                    collection_name,
+                    mutate_payload,
+                    false,
+                    None,
+                )
+                .mutate_payload_points
+                .map(Update::MutatePayload),
+                PayloadOps::DeletePayload(delete_payload) => internal_delete_payload(
+                    None,
+                    None,
+                    collection_name,
+                    delete_payload,
+                    false,
+                    None,
+                )
+                .delete_payload_points
+                .map(Update::DeletePayload),
+                PayloadOps::ClearPayload { points } => {
+                    internal_clear_payload(None, None, collection_name, points, false, None)
+                        .clear_payload_points
+                        .map(Update::ClearPayload)
+                }
+                PayloadOps::ClearPayloadByFilter(filter) => internal_clear_payload_by_filter(
+                    None,
+                    None,
+                    collection_name,
+                    filter,
+                    false,
+                    None,
+                )
+                .clear_payload_points
+                .map(Update::ClearPayload),
+            }
+        }
+        CollectionUpdateOperations::FieldIndexOperation(field_index_operation) => {
+            match field_index_operation {
+                FieldIndexOperations::CreateIndex(create_index) => {
+                    internal_create_index(None, None, collection_name, create_index, false, None)
+                        .create_field_index_collection
+                        .map(Update::CreateFieldIndex)
+                }
+                FieldIndexOperations::DeleteIndex(field_name) => {
+                    internal_delete_index(None, None, collection_name, field_name, false, None)
+                        .delete_field_index_collection
+                        .map(Update::DeleteFieldIndex)
+                }
+            }
+        }
+        CollectionUpdateOperations::AtomicBatch(batch) => {
+            internal_update_batch(None, None, collection_name, batch, false, None)?
+                .update_batch_points
+                .map(Update::UpdateBatch)
+        }
+    };
+
+    Ok(update)
+}
+
 pub fn try_scored_point_from_grpc(
     point: api::grpc::qdrant::ScoredPoint,
     with_payload: bool,
@@ -444,5 +973,10 @@ pub fn try_scored_point_from_grpc(
         vector,
         shard_key: convert_shard_key_from_grpc_opt(point.shard_key),
         order_value: point.order_value.map(TryFrom::try_from).transpose()?,
+        then_order_values: point
+            .then_order_values
+            .into_iter()
+            .map(TryFrom::try_from)
+            .collect::<Result<_, _>>()?,
     })
 }
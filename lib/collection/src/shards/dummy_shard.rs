@@ -4,6 +4,7 @@ use std::time::Duration;
 
 use async_trait::async_trait;
 use segment::data_types::order_by::OrderBy;
+use segment::data_types::search_explain::SegmentSearchExplanation;
 use segment::types::{
     ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
 };
@@ -110,4 +111,13 @@ impl ShardOperation for DummyShard {
     ) -> CollectionResult<Vec<ShardQueryResponse>> {
         self.dummy()
     }
+
+    async fn explain_query_batch(
+        &self,
+        _requests: Arc<Vec<ShardQueryRequest>>,
+        _search_runtime_handle: &Handle,
+        _timeout: Option<Duration>,
+    ) -> CollectionResult<(Vec<ShardQueryResponse>, Vec<SegmentSearchExplanation>)> {
+        self.dummy()
+    }
 }
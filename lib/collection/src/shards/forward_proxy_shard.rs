@@ -1,10 +1,14 @@
+use std::collections::BTreeMap;
 use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use common::types::TelemetryDetail;
 use segment::data_types::order_by::OrderBy;
+use segment::data_types::search_explain::SegmentSearchExplanation;
+use segment::data_types::vectors::VectorStructInternal;
 use segment::types::{
     ExtendedPointId, Filter, PointIdType, ScoredPoint, WithPayload, WithPayloadInterface,
     WithVector,
@@ -18,6 +22,7 @@ use crate::hash_ring::HashRing;
 use crate::operations::point_ops::{
     PointInsertOperationsInternal, PointOperations, PointStruct, PointSyncOperation,
 };
+use crate::operations::trash::TrashPointsOp;
 use crate::operations::types::{
     CollectionError, CollectionInfo, CollectionResult, CoreSearchRequestBatch,
     CountRequestInternal, CountResult, PointRequestInternal, Record, UpdateResult, UpdateStatus,
@@ -156,6 +161,95 @@ impl ForwardProxyShard {
         Ok(next_page_offset)
     }
 
+    /// Move points in trash to the remote shard, keeping the time they were moved to trash at.
+    ///
+    /// Points in trash are not part of transferred batches, so they are inserted into the remote
+    /// shard and moved to trash again.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe.
+    pub async fn transfer_trash(
+        &self,
+        batch_size: usize,
+        hashring_filter: Option<&HashRing>,
+    ) -> CollectionResult<()> {
+        debug_assert!(batch_size > 0);
+        let _update_lock = self.update_lock.lock().await;
+
+        let mut point_ids = Vec::new();
+        self.wrapped_shard
+            .segments()
+            .read()
+            .for_each_segment(|segment| {
+                point_ids.extend(segment.trashed_points(None));
+                Ok(true)
+            })?;
+        point_ids.sort_unstable();
+        point_ids.dedup();
+        if let Some(hashring) = hashring_filter {
+            point_ids.retain(|point_id| hashring.is_in_shard(point_id, self.remote_shard.id));
+        }
+
+        for batch in point_ids.chunks(batch_size) {
+            let mut points = Vec::with_capacity(batch.len());
+            let mut trashed_ids: BTreeMap<DateTime<Utc>, Vec<PointIdType>> = BTreeMap::new();
+            {
+                let segments = self.wrapped_shard.segments().read();
+                for &point_id in batch {
+                    let Some((segment, trashed_point)) = segments.latest_trashed_point(point_id)
+                    else {
+                        continue;
+                    };
+                    let segment = segment.read();
+                    points.push(PointStruct {
+                        id: point_id,
+                        vector: VectorStructInternal::from(segment.trashed_vectors(point_id)?)
+                            .into(),
+                        payload: Some(segment.trashed_payload(point_id)?),
+                    });
+                    trashed_ids
+                        .entry(trashed_point.trashed_at)
+                        .or_default()
+                        .push(point_id);
+                }
+            }
+
+            if points.is_empty() {
+                continue;
+            }
+
+            // TODO: Is cancelling `RemoteShard::update` safe for *receiver*?
+            self.remote_shard
+                .update(
+                    OperationWithClockTag::from(CollectionUpdateOperations::PointOperation(
+                        PointOperations::UpsertPoints(PointInsertOperationsInternal::PointsList(
+                            points,
+                        )),
+                    )),
+                    false,
+                )
+                .await?;
+
+            for (trashed_at, ids) in trashed_ids {
+                self.remote_shard
+                    .update(
+                        OperationWithClockTag::from(CollectionUpdateOperations::PointOperation(
+                            PointOperations::TrashPoints(TrashPointsOp {
+                                points: Some(ids),
+                                filter: None,
+                                trashed_at,
+                            }),
+                        )),
+                        false,
+                    )
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn deconstruct(self) -> (LocalShard, RemoteShard) {
         (self.wrapped_shard, self.remote_shard)
     }
@@ -309,4 +403,17 @@ impl ShardOperation for ForwardProxyShard {
             .query_batch(requests, search_runtime_handle, timeout)
             .await
     }
+
+    /// Forward read-only `explain_query_batch` to `wrapped_shard`
+    async fn explain_query_batch(
+        &self,
+        requests: Arc<Vec<ShardQueryRequest>>,
+        search_runtime_handle: &Handle,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<(Vec<ShardQueryResponse>, Vec<SegmentSearchExplanation>)> {
+        let local_shard = &self.wrapped_shard;
+        local_shard
+            .explain_query_batch(requests, search_runtime_handle, timeout)
+            .await
+    }
 }
@@ -0,0 +1,315 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use io::file_operations;
+use parking_lot::Mutex;
+use segment::types::SeqNumberType;
+use serde::{Deserialize, Serialize};
+
+use crate::operations::types::{CollectionResult, UpdateResult, UpdateStatus};
+
+/// Maximum number of remembered keys per shard, the oldest keys are forgotten first
+const MAX_IDEMPOTENCY_KEYS: usize = 100_000;
+
+const IDEMPOTENCY_KEYS_FILE: &str = "idempotency_keys.json";
+
+/// Results of operations applied to the shard, by their idempotency key.
+///
+/// Keys are forgotten once they are older than the configured window,
+/// or once there are more than [`MAX_IDEMPOTENCY_KEYS`] of them.
+///
+/// Keys are persisted on flush, before the WAL is truncated,
+/// so that keys of truncated operations survive a restart.
+#[derive(Debug)]
+pub struct IdempotencyKeys {
+    window: Duration,
+    results: HashMap<String, AppliedOperation>,
+    /// Keys of operations, which are being written to the WAL
+    pending: HashSet<String>,
+    /// Keys in the order they were applied, to expire them
+    expiry_queue: VecDeque<(SystemTime, String)>,
+    /// Whether the keys have changed since the last time they were persisted
+    changed: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct AppliedOperation {
+    applied_at: SystemTime,
+    op_num: SeqNumberType,
+    /// Persisted operations were applied before the restart
+    #[serde(skip, default = "completed_status")]
+    status: UpdateStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    skipped: Option<usize>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct StoredKey {
+    key: String,
+    #[serde(flatten)]
+    applied: AppliedOperation,
+}
+
+fn completed_status() -> UpdateStatus {
+    UpdateStatus::Completed
+}
+
+impl IdempotencyKeys {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            results: HashMap::new(),
+            pending: HashSet::new(),
+            expiry_queue: VecDeque::new(),
+            changed: false,
+        }
+    }
+
+    /// Load keys persisted in the shard directory, if there are any
+    pub fn load_or_new(shard_path: &Path, window: Duration) -> CollectionResult<Self> {
+        let mut keys = Self::new(window);
+
+        let path = Self::path(shard_path);
+        if !path.exists() {
+            return Ok(keys);
+        }
+
+        let stored: Vec<StoredKey> = file_operations::read_json(&path)?;
+        for StoredKey { key, applied } in stored {
+            keys.expiry_queue
+                .push_back((applied.applied_at, key.clone()));
+            keys.results.insert(key, applied);
+        }
+        keys.expire();
+
+        Ok(keys)
+    }
+
+    /// Persist keys into the shard directory, if they have changed
+    pub fn store_if_changed(&mut self, shard_path: &Path) -> CollectionResult<()> {
+        if !self.changed {
+            return Ok(());
+        }
+
+        self.expire();
+
+        let stored: Vec<_> = self
+            .expiry_queue
+            .iter()
+            .filter_map(|(applied_at, key)| {
+                let applied = self.results.get(key)?;
+                (applied.applied_at == *applied_at).then(|| StoredKey {
+                    key: key.clone(),
+                    applied: applied.clone(),
+                })
+            })
+            .collect();
+
+        file_operations::atomic_save_json(&Self::path(shard_path), &stored)?;
+        self.changed = false;
+        Ok(())
+    }
+
+    pub fn path(shard_path: &Path) -> PathBuf {
+        shard_path.join(IDEMPOTENCY_KEYS_FILE)
+    }
+
+    /// Reserve the key for an operation, which is about to be written to the WAL.
+    ///
+    /// Returns the result of the operation, which already has the key, instead.
+    /// The lock is only held for the lookup, so the WAL is written without blocking other keys.
+    pub fn reserve(keys: &Arc<Mutex<Self>>, key: String) -> Result<ReservedKey, UpdateResult> {
+        let mut locked_keys = keys.lock();
+        if let Some(result) = locked_keys.get(&key) {
+            return Err(result);
+        }
+        locked_keys.pending.insert(key.clone());
+
+        Ok(ReservedKey {
+            keys: keys.clone(),
+            key: Some(key),
+        })
+    }
+
+    /// Result of the operation, which was applied with the given key.
+    ///
+    /// Operations, which are still being written to the WAL, are acknowledged without an ID.
+    pub fn get(&mut self, key: &str) -> Option<UpdateResult> {
+        if self.pending.contains(key) {
+            return Some(UpdateResult {
+                operation_id: None,
+                status: UpdateStatus::Acknowledged,
+                clock_tag: None,
+                skipped: None,
+                dry_run: None,
+            });
+        }
+
+        self.expire();
+        self.results.get(key).map(|applied| UpdateResult {
+            operation_id: Some(applied.op_num),
+            status: applied.status,
+            clock_tag: None,
+            skipped: applied.skipped,
+            dry_run: None,
+        })
+    }
+
+    /// Remember the result of the operation applied with the given key.
+    ///
+    /// Only updates the result, if the same operation was already remembered.
+    pub fn insert(&mut self, key: String, op_num: SeqNumberType, result: &UpdateResult) {
+        self.pending.remove(&key);
+        self.changed = true;
+
+        if let Some(applied) = self.results.get_mut(&key) {
+            if applied.op_num == op_num {
+                applied.status = result.status;
+                applied.skipped = result.skipped;
+                return;
+            }
+        }
+
+        let applied_at = SystemTime::now();
+        self.expiry_queue.push_back((applied_at, key.clone()));
+        self.results.insert(
+            key,
+            AppliedOperation {
+                applied_at,
+                op_num,
+                status: result.status,
+                skipped: result.skipped,
+            },
+        );
+
+        self.expire();
+    }
+
+    /// Forget the key of the operation, which failed to apply, so that it can be retried
+    pub fn remove(&mut self, key: &str, op_num: SeqNumberType) {
+        if self
+            .results
+            .get(key)
+            .is_some_and(|applied| applied.op_num == op_num)
+        {
+            self.results.remove(key);
+            self.changed = true;
+        }
+    }
+
+    fn expire(&mut self) {
+        while let Some((applied_at, _)) = self.expiry_queue.front() {
+            let expired = applied_at
+                .elapsed()
+                .is_ok_and(|elapsed| elapsed >= self.window);
+
+            if !expired && self.expiry_queue.len() <= MAX_IDEMPOTENCY_KEYS {
+                break;
+            }
+
+            let (applied_at, key) = self.expiry_queue.pop_front().unwrap();
+            if self
+                .results
+                .get(&key)
+                .is_some_and(|applied| applied.applied_at == applied_at)
+            {
+                self.results.remove(&key);
+                self.changed = true;
+            }
+        }
+    }
+}
+
+/// Key reserved for an operation, which is being written to the WAL.
+///
+/// The key is released on drop, unless the result of the written operation is inserted.
+/// So a failed or cancelled write doesn't block requests with the same key.
+#[derive(Debug)]
+pub struct ReservedKey {
+    keys: Arc<Mutex<IdempotencyKeys>>,
+    key: Option<String>,
+}
+
+impl ReservedKey {
+    /// Remember the result of the operation, which was written to the WAL with the reserved key
+    pub fn insert(mut self, op_num: SeqNumberType, result: &UpdateResult) {
+        if let Some(key) = self.key.take() {
+            self.keys.lock().insert(key, op_num, result);
+        }
+    }
+}
+
+impl Drop for ReservedKey {
+    fn drop(&mut self) {
+        if let Some(key) = self.key.take() {
+            self.keys.lock().pending.remove(&key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::Builder;
+
+    use super::*;
+
+    fn acknowledged(op_num: SeqNumberType) -> UpdateResult {
+        UpdateResult {
+            operation_id: Some(op_num),
+            status: UpdateStatus::Acknowledged,
+            clock_tag: None,
+            skipped: None,
+            dry_run: None,
+        }
+    }
+
+    #[test]
+    fn test_keys_persistence() {
+        let dir = Builder::new().prefix("idempotency").tempdir().unwrap();
+        let window = Duration::from_secs(60);
+
+        let mut keys = IdempotencyKeys::new(window);
+        keys.insert("first".to_string(), 1, &acknowledged(1));
+        keys.insert("second".to_string(), 2, &acknowledged(2));
+        keys.remove("second", 2);
+        keys.store_if_changed(dir.path()).unwrap();
+
+        let mut keys = IdempotencyKeys::load_or_new(dir.path(), window).unwrap();
+        let result = keys.get("first").unwrap();
+        assert_eq!(result.operation_id, Some(1));
+        assert_eq!(result.status, UpdateStatus::Completed);
+        assert!(keys.get("second").is_none());
+    }
+
+    #[test]
+    fn test_reserved_keys() {
+        let keys = Arc::new(Mutex::new(IdempotencyKeys::new(Duration::from_secs(60))));
+
+        let reserved = IdempotencyKeys::reserve(&keys, "written".to_string()).unwrap();
+        let repeated = IdempotencyKeys::reserve(&keys, "written".to_string()).unwrap_err();
+        assert_eq!(repeated.operation_id, None);
+        assert_eq!(repeated.status, UpdateStatus::Acknowledged);
+
+        reserved.insert(1, &acknowledged(1));
+        let repeated = IdempotencyKeys::reserve(&keys, "written".to_string()).unwrap_err();
+        assert_eq!(repeated.operation_id, Some(1));
+
+        // Key of an operation, which was not written, is released
+        drop(IdempotencyKeys::reserve(&keys, "failed".to_string()).unwrap());
+        assert!(keys.lock().get("failed").is_none());
+    }
+
+    #[test]
+    fn test_keys_limit() {
+        let mut keys = IdempotencyKeys::new(Duration::from_secs(60));
+        for op_num in 0..=MAX_IDEMPOTENCY_KEYS as SeqNumberType {
+            keys.insert(op_num.to_string(), op_num, &acknowledged(op_num));
+        }
+
+        assert_eq!(keys.results.len(), MAX_IDEMPOTENCY_KEYS);
+        assert!(keys.get("0").is_none());
+        assert!(keys.get("1").is_some());
+    }
+}
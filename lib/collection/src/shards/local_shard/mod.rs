@@ -1,5 +1,6 @@
 pub mod clock_map;
 pub mod disk_usage_watcher;
+pub mod idempotency;
 pub(super) mod query;
 pub(super) mod scroll;
 pub(super) mod search;
@@ -39,6 +40,7 @@ use wal::{Wal, WalOptions};
 
 use self::clock_map::{ClockMap, RecoveryPoint};
 use self::disk_usage_watcher::DiskUsageWatcher;
+use self::idempotency::IdempotencyKeys;
 use super::update_tracker::UpdateTracker;
 use crate::collection::payload_index_schema::PayloadIndexSchema;
 use crate::collection_manager::collection_updater::CollectionUpdater;
@@ -51,7 +53,7 @@ use crate::config::CollectionConfig;
 use crate::operations::shared_storage_config::SharedStorageConfig;
 use crate::operations::types::{
     check_sparse_compatible_with_segment_config, CollectionError, CollectionInfoInternal,
-    CollectionResult, CollectionStatus, OptimizersStatus,
+    CollectionResult, CollectionStatus, OptimizersStatus, UpdateResult, UpdateStatus,
 };
 use crate::operations::OperationWithClockTag;
 use crate::optimizers_builder::{build_optimizers, clear_temp_segments, OptimizersConfig};
@@ -62,7 +64,7 @@ use crate::shards::telemetry::{LocalShardTelemetry, OptimizerTelemetry};
 use crate::shards::CollectionId;
 use crate::update_handler::{Optimizer, UpdateHandler, UpdateSignal};
 use crate::wal::SerdeWal;
-use crate::wal_delta::{LockedWal, RecoverableWal};
+use crate::wal_delta::{LockedWal, RecoverableWal, WalUpdatesReader};
 
 /// If rendering WAL load progression in basic text form, report progression every 60 seconds.
 const WAL_LOAD_REPORT_EVERY: Duration = Duration::from_secs(60);
@@ -81,6 +83,7 @@ pub struct LocalShard {
     pub(super) update_handler: Arc<Mutex<UpdateHandler>>,
     pub(super) update_sender: ArcSwap<Sender<UpdateSignal>>,
     pub(super) update_tracker: UpdateTracker,
+    pub(super) idempotency_keys: Arc<ParkingMutex<IdempotencyKeys>>,
     pub(super) path: PathBuf,
     pub(super) optimizers: Arc<Vec<Arc<Optimizer>>>,
     pub(super) optimizers_log: Arc<ParkingMutex<TrackerLog>>,
@@ -101,6 +104,11 @@ impl LocalShard {
 
         LocalShardClocks::move_data(from, to).await?;
 
+        let idempotency_keys_from = IdempotencyKeys::path(from);
+        if idempotency_keys_from.exists() {
+            move_file(idempotency_keys_from, IdempotencyKeys::path(to)).await?;
+        }
+
         Ok(())
     }
 
@@ -129,6 +137,11 @@ impl LocalShard {
 
         LocalShardClocks::delete_data(shard_path).await?;
 
+        let idempotency_keys_path = IdempotencyKeys::path(shard_path);
+        if idempotency_keys_path.exists() {
+            remove_file(idempotency_keys_path).await?;
+        }
+
         Ok(())
     }
 
@@ -160,6 +173,17 @@ impl LocalShard {
         )
         .await;
 
+        let idempotency_keys =
+            IdempotencyKeys::load_or_new(shard_path, shared_storage_config.idempotency_window)
+                .unwrap_or_else(|err| {
+                    log::warn!(
+                        "Failed to load idempotency keys of shard {}: {err}",
+                        shard_path.display(),
+                    );
+                    IdempotencyKeys::new(shared_storage_config.idempotency_window)
+                });
+        let idempotency_keys = Arc::new(ParkingMutex::new(idempotency_keys));
+
         let mut update_handler = UpdateHandler::new(
             shared_storage_config.clone(),
             payload_index_schema.clone(),
@@ -172,6 +196,7 @@ impl LocalShard {
             config.optimizer_config.flush_interval_sec,
             config.optimizer_config.max_optimization_threads,
             clocks.clone(),
+            idempotency_keys.clone(),
             shard_path.into(),
         );
 
@@ -192,6 +217,7 @@ impl LocalShard {
             update_handler: Arc::new(Mutex::new(update_handler)),
             update_sender: ArcSwap::from_pointee(update_sender),
             update_tracker,
+            idempotency_keys,
             path: shard_path.to_owned(),
             update_runtime,
             optimizers,
@@ -539,6 +565,7 @@ impl LocalShard {
     /// Loads latest collection operations from WAL
     pub async fn load_from_wal(&self, collection_id: CollectionId) -> CollectionResult<()> {
         let mut newest_clocks = self.wal.newest_clocks.lock().await;
+        let mut idempotency_keys = self.idempotency_keys.lock();
         let wal = self.wal.wal.lock();
         let bar = ProgressBar::new(wal.len(false));
 
@@ -578,6 +605,21 @@ impl LocalShard {
                 newest_clocks.advance_clock(clock_tag);
             }
 
+            // Repeated keys are never written to WAL, so only remember them for future requests
+            if let Some(key) = update.operation.idempotency_key() {
+                idempotency_keys.insert(
+                    key.to_string(),
+                    op_num,
+                    &UpdateResult {
+                        operation_id: Some(op_num),
+                        status: UpdateStatus::Completed,
+                        clock_tag: update.clock_tag,
+                        skipped: None,
+                        dry_run: None,
+                    },
+                );
+            }
+
             // Propagate `CollectionError::ServiceError`, but skip other error types.
             match &CollectionUpdater::update(segments, op_num, update.operation) {
                 Err(err @ CollectionError::ServiceError { error, backtrace }) => {
@@ -799,6 +841,15 @@ impl LocalShard {
 
         LocalShardClocks::copy_data(&self.path, snapshot_shard_path).await?;
 
+        let idempotency_keys_path = IdempotencyKeys::path(&self.path);
+        if idempotency_keys_path.exists() {
+            copy(
+                idempotency_keys_path,
+                IdempotencyKeys::path(snapshot_shard_path),
+            )
+            .await?;
+        }
+
         // copy shard's config
         let shard_config_path = ShardConfig::get_config_path(&self.path);
         let target_shard_config_path = snapshot_shard_path.join(SHARD_CONFIG_FILE);
@@ -1043,6 +1094,11 @@ impl LocalShard {
     pub async fn update_cutoff(&self, cutoff: &RecoveryPoint) {
         self.wal.update_cutoff(cutoff).await
     }
+
+    /// Reader of update operations in the shard WAL, used to stream changes to subscribers
+    pub fn wal_updates(&self) -> WalUpdatesReader {
+        self.wal.updates_reader()
+    }
 }
 
 impl Drop for LocalShard {
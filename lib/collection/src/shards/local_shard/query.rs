@@ -7,6 +7,7 @@ use api::rest::OrderByInterface;
 use futures::future::BoxFuture;
 use futures::FutureExt;
 use segment::common::reciprocal_rank_fusion::rrf_scoring;
+use segment::data_types::search_explain::SearchExplainCollector;
 use segment::types::{Filter, HasIdCondition, ScoredPoint, WithPayloadInterface, WithVector};
 use tokio::runtime::Handle;
 
@@ -49,11 +50,15 @@ impl PrefetchResults {
 }
 
 impl LocalShard {
+    /// Execute the planned query on the shard
+    ///
+    /// If `explain_collector` is given, each segment explains how it executed the searches.
     pub async fn do_planned_query(
         &self,
         request: PlannedQuery,
         search_runtime_handle: &Handle,
         timeout: Option<Duration>,
+        explain_collector: Option<SearchExplainCollector>,
     ) -> CollectionResult<Vec<ShardQueryResponse>> {
         let start_time = std::time::Instant::now();
         let timeout = timeout.unwrap_or(self.shared_storage_config.search_timeout);
@@ -64,6 +69,7 @@ impl LocalShard {
             }),
             search_runtime_handle,
             Some(timeout),
+            explain_collector.clone(),
         );
 
         let scrolls_f =
@@ -82,6 +88,7 @@ impl LocalShard {
                 &prefetch_holder,
                 search_runtime_handle,
                 timeout,
+                explain_collector.clone(),
                 0,
             )
         });
@@ -141,6 +148,7 @@ impl LocalShard {
         prefetch_holder: &'query PrefetchResults,
         search_runtime_handle: &'shard Handle,
         timeout: Duration,
+        explain_collector: Option<SearchExplainCollector>,
         depth: usize,
     ) -> BoxFuture<'query, CollectionResult<Vec<Vec<ScoredPoint>>>>
     where
@@ -166,6 +174,7 @@ impl LocalShard {
                                 prefetch_holder,
                                 search_runtime_handle,
                                 timeout,
+                                explain_collector.clone(),
                                 depth + 1,
                             )
                             .await?
@@ -184,6 +193,7 @@ impl LocalShard {
                         rescore_params,
                         search_runtime_handle,
                         timeout,
+                        explain_collector,
                     )
                     .await?;
 
@@ -206,6 +216,7 @@ impl LocalShard {
         rescore_params: RescoreParams,
         search_runtime_handle: &Handle,
         timeout: Duration,
+        explain_collector: Option<SearchExplainCollector>,
     ) -> CollectionResult<Vec<ScoredPoint>> {
         let RescoreParams {
             rescore,
@@ -290,6 +301,7 @@ impl LocalShard {
                     Arc::new(rescoring_core_search_request),
                     search_runtime_handle,
                     Some(timeout),
+                    explain_collector,
                 )
                 .await?
                 // One search request is sent. We expect only one result
@@ -3,7 +3,7 @@ use std::time::Duration;
 
 use futures::future::try_join_all;
 use itertools::Itertools as _;
-use segment::data_types::order_by::{Direction, OrderBy, OrderValue};
+use segment::data_types::order_by::{OrderBy, OrderValues};
 use segment::types::{
     ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
 };
@@ -12,6 +12,7 @@ use tokio::runtime::Handle;
 use super::LocalShard;
 use crate::collection_manager::holders::segment_holder::LockedSegment;
 use crate::collection_manager::segments_searcher::SegmentsSearcher;
+use crate::common::slow_request_guard::add_segments_touched;
 use crate::operations::types::{
     CollectionError, CollectionResult, QueryScrollRequestInternal, Record,
 };
@@ -83,6 +84,7 @@ impl LocalShard {
                     vector: record.vector,
                     shard_key: record.shard_key,
                     order_value: None,
+                    then_order_values: Vec::new(),
                 })
                 .collect(),
             Some(order_by) => {
@@ -101,14 +103,18 @@ impl LocalShard {
                     .into_iter()
                     .zip(values)
                     .skip(*offset)
-                    .map(|(record, value)| ScoredPoint {
-                        id: record.id,
-                        version: 0,
-                        score: 0.0,
-                        payload: record.payload,
-                        vector: record.vector,
-                        shard_key: record.shard_key,
-                        order_value: Some(value),
+                    .map(|(record, values)| {
+                        let mut values = values.into_iter();
+                        ScoredPoint {
+                            id: record.id,
+                            version: 0,
+                            score: 0.0,
+                            payload: record.payload,
+                            vector: record.vector,
+                            shard_key: record.shard_key,
+                            order_value: values.next(),
+                            then_order_values: values.collect(),
+                        }
                     })
                     .collect()
             }
@@ -129,6 +135,7 @@ impl LocalShard {
         let segments = self.segments();
 
         let (non_appendable, appendable) = segments.read().split_segments();
+        add_segments_touched(non_appendable.len() + appendable.len());
 
         let read_filtered = |segment: LockedSegment| {
             let filter = filter.cloned();
@@ -170,10 +177,11 @@ impl LocalShard {
         filter: Option<&Filter>,
         search_runtime_handle: &Handle,
         order_by: &OrderBy,
-    ) -> CollectionResult<(Vec<Record>, Vec<OrderValue>)> {
+    ) -> CollectionResult<(Vec<Record>, Vec<OrderValues>)> {
         let segments = self.segments();
 
         let (non_appendable, appendable) = segments.read().split_segments();
+        add_segments_touched(non_appendable.len() + appendable.len());
 
         let read_ordered_filtered = |segment: LockedSegment| {
             let filter = filter.cloned();
@@ -198,9 +206,10 @@ impl LocalShard {
 
         let (values, point_ids): (Vec<_>, Vec<_>) = all_reads
             .into_iter()
-            .kmerge_by(|a, b| match order_by.direction() {
-                Direction::Asc => a <= b,
-                Direction::Desc => a >= b,
+            .kmerge_by(|(a_values, a_id), (b_values, b_id)| {
+                order_by
+                    .cmp_points((a_values, a_id), (b_values, b_id))
+                    .is_le()
             })
             .dedup()
             .take(limit)
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use segment::data_types::search_explain::SearchExplainCollector;
 use segment::types::ScoredPoint;
 use tokio::runtime::Handle;
 
@@ -11,11 +12,15 @@ use crate::operations::query_enum::QueryEnum;
 use crate::operations::types::{CollectionError, CollectionResult, CoreSearchRequestBatch};
 
 impl LocalShard {
+    /// Search the segments of the shard
+    ///
+    /// If `explain_collector` is given, each segment explains how it executed the search.
     pub async fn do_search(
         &self,
         core_request: Arc<CoreSearchRequestBatch>,
         search_runtime_handle: &Handle,
         timeout: Option<Duration>,
+        explain_collector: Option<SearchExplainCollector>,
     ) -> CollectionResult<Vec<Vec<ScoredPoint>>> {
         let is_stopped_guard = StoppingGuard::new();
 
@@ -30,11 +35,15 @@ impl LocalShard {
             )
             .await?;
 
-            let Some(query_context) = query_context_opt else {
+            let Some(mut query_context) = query_context_opt else {
                 // No segments to search
                 return Ok(vec![]);
             };
 
+            if let Some(explain_collector) = explain_collector {
+                query_context = query_context.with_explain_collector(explain_collector);
+            }
+
             (query_context, collection_config.params.clone())
         };
 
@@ -3,6 +3,7 @@ use std::time::Duration;
 
 use async_trait::async_trait;
 use segment::data_types::order_by::OrderBy;
+use segment::data_types::search_explain::{SearchExplainCollector, SegmentSearchExplanation};
 use segment::types::{
     ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
 };
@@ -10,13 +11,15 @@ use tokio::runtime::Handle;
 use tokio::sync::oneshot;
 
 use crate::collection_manager::segments_searcher::SegmentsSearcher;
+use crate::operations::point_ops::PointOperations;
 use crate::operations::types::{
     CollectionError, CollectionInfo, CollectionResult, CoreSearchRequestBatch,
     CountRequestInternal, CountResult, PointRequestInternal, Record, UpdateResult, UpdateStatus,
 };
 use crate::operations::universal_query::planned_query::PlannedQuery;
 use crate::operations::universal_query::shard_query::{ShardQueryRequest, ShardQueryResponse};
-use crate::operations::OperationWithClockTag;
+use crate::operations::{CollectionUpdateOperations, OperationWithClockTag};
+use crate::shards::local_shard::idempotency::IdempotencyKeys;
 use crate::shards::local_shard::LocalShard;
 use crate::shards::shard_trait::ShardOperation;
 use crate::update_handler::{OperationData, UpdateSignal};
@@ -56,6 +59,30 @@ impl ShardOperation for LocalShard {
             ));
         }
 
+        // Restricted upserts report, how many of the points were skipped
+        let restricted_points_count = match &operation.operation {
+            CollectionUpdateOperations::PointOperation(
+                PointOperations::UpsertPointsConditional(conditional_upsert),
+            ) if wait => Some(conditional_upsert.points_count()),
+            _ => None,
+        };
+
+        // Operations with an idempotency key are applied only once.
+        // The key is reserved before the WAL write, so concurrent repeated requests don't apply it again.
+        let idempotency_key = operation.operation.idempotency_key().map(str::to_string);
+        let reserved_key = match &idempotency_key {
+            Some(key) => match IdempotencyKeys::reserve(&self.idempotency_keys, key.clone()) {
+                Ok(reserved_key) => Some(reserved_key),
+                Err(result) => {
+                    return Ok(UpdateResult {
+                        clock_tag: operation.clock_tag,
+                        ..result
+                    });
+                }
+            },
+            None => None,
+        };
+
         let operation_id = {
             let update_sender = self.update_sender.load();
             let channel_permit = update_sender.reserve().await?;
@@ -72,6 +99,8 @@ impl ShardOperation for LocalShard {
                         operation_id: None,
                         status: UpdateStatus::ClockRejected,
                         clock_tag: operation.clock_tag,
+                        skipped: None,
+                        dry_run: None,
                     });
                 }
 
@@ -88,20 +117,52 @@ impl ShardOperation for LocalShard {
             operation_id
         };
 
-        if let Some(receiver) = callback_receiver {
-            let _res = receiver.await??;
-            Ok(UpdateResult {
-                operation_id: Some(operation_id),
-                status: UpdateStatus::Completed,
-                clock_tag: operation.clock_tag,
-            })
-        } else {
-            Ok(UpdateResult {
-                operation_id: Some(operation_id),
-                status: UpdateStatus::Acknowledged,
-                clock_tag: operation.clock_tag,
-            })
+        let acknowledged = UpdateResult {
+            operation_id: Some(operation_id),
+            status: UpdateStatus::Acknowledged,
+            clock_tag: operation.clock_tag,
+            skipped: None,
+            dry_run: None,
+        };
+
+        // Repeated requests may already see the key, while the operation is being applied
+        if let Some(reserved_key) = reserved_key {
+            reserved_key.insert(operation_id, &acknowledged);
+        }
+
+        let Some(receiver) = callback_receiver else {
+            return Ok(acknowledged);
+        };
+
+        let applied = match receiver
+            .await
+            .map_err(CollectionError::from)
+            .and_then(|res| res)
+        {
+            Ok(applied) => applied,
+            Err(err) => {
+                // Failed operation is not applied, so a retry with the same key should apply it
+                if let Some(key) = &idempotency_key {
+                    self.idempotency_keys.lock().remove(key, operation_id);
+                }
+                return Err(err);
+            }
+        };
+        let completed = UpdateResult {
+            operation_id: Some(operation_id),
+            status: UpdateStatus::Completed,
+            clock_tag: operation.clock_tag,
+            skipped: restricted_points_count.map(|count| count.saturating_sub(applied)),
+            dry_run: None,
+        };
+
+        if let Some(key) = idempotency_key {
+            self.idempotency_keys
+                .lock()
+                .insert(key, operation_id, &completed);
         }
+
+        Ok(completed)
     }
 
     async fn scroll_by(
@@ -138,7 +199,8 @@ impl ShardOperation for LocalShard {
                     )
                     .await?;
 
-                records.iter_mut().zip(values).for_each(|(record, value)| {
+                records.iter_mut().zip(values).for_each(|(record, values)| {
+                    let value = values[0];
                     // TODO(1.11): stop inserting the value in the payload, only use the order_value
                     // Add order_by value to the payload. It will be removed in the next step, after crossing the shard boundary.
                     let new_payload =
@@ -164,7 +226,7 @@ impl ShardOperation for LocalShard {
         search_runtime_handle: &Handle,
         timeout: Option<Duration>,
     ) -> CollectionResult<Vec<Vec<ScoredPoint>>> {
-        self.do_search(request, search_runtime_handle, timeout)
+        self.do_search(request, search_runtime_handle, timeout, None)
             .await
     }
 
@@ -195,7 +257,28 @@ impl ShardOperation for LocalShard {
     ) -> CollectionResult<Vec<ShardQueryResponse>> {
         let planned_query = PlannedQuery::try_from(requests.as_ref().to_owned())?;
 
-        self.do_planned_query(planned_query, search_runtime_handle, timeout)
+        self.do_planned_query(planned_query, search_runtime_handle, timeout, None)
             .await
     }
+
+    async fn explain_query_batch(
+        &self,
+        requests: Arc<Vec<ShardQueryRequest>>,
+        search_runtime_handle: &Handle,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<(Vec<ShardQueryResponse>, Vec<SegmentSearchExplanation>)> {
+        let planned_query = PlannedQuery::try_from(requests.as_ref().to_owned())?;
+
+        let explain_collector = SearchExplainCollector::default();
+        let responses = self
+            .do_planned_query(
+                planned_query,
+                search_runtime_handle,
+                timeout,
+                Some(explain_collector.clone()),
+            )
+            .await?;
+
+        Ok((responses, explain_collector.take()))
+    }
 }
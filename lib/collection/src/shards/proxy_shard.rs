@@ -7,6 +7,7 @@ use std::time::Duration;
 use async_trait::async_trait;
 use common::types::TelemetryDetail;
 use segment::data_types::order_by::OrderBy;
+use segment::data_types::search_explain::SegmentSearchExplanation;
 use segment::types::{
     ExtendedPointId, Filter, PointIdType, ScoredPoint, WithPayload, WithPayloadInterface,
     WithVector,
@@ -259,4 +260,17 @@ impl ShardOperation for ProxyShard {
             .query_batch(request, search_runtime_handle, timeout)
             .await
     }
+
+    /// Forward read-only `explain_query_batch` to `wrapped_shard`
+    async fn explain_query_batch(
+        &self,
+        request: Arc<Vec<ShardQueryRequest>>,
+        search_runtime_handle: &Handle,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<(Vec<ShardQueryResponse>, Vec<SegmentSearchExplanation>)> {
+        let local_shard = &self.wrapped_shard;
+        local_shard
+            .explain_query_batch(request, search_runtime_handle, timeout)
+            .await
+    }
 }
@@ -7,6 +7,7 @@ use async_trait::async_trait;
 use common::types::TelemetryDetail;
 use parking_lot::Mutex as ParkingMutex;
 use segment::data_types::order_by::OrderBy;
+use segment::data_types::search_explain::SegmentSearchExplanation;
 use segment::types::{
     ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
 };
@@ -306,6 +307,21 @@ impl ShardOperation for QueueProxyShard {
             .query_batch(requests, search_runtime_handle, timeout)
             .await
     }
+
+    /// Forward read-only `explain_query_batch` to `wrapped_shard`
+    async fn explain_query_batch(
+        &self,
+        requests: Arc<Vec<ShardQueryRequest>>,
+        search_runtime_handle: &Handle,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<(Vec<ShardQueryResponse>, Vec<SegmentSearchExplanation>)> {
+        self.inner
+            .as_ref()
+            .expect("Queue proxy has been finalized")
+            .wrapped_shard
+            .explain_query_batch(requests, search_runtime_handle, timeout)
+            .await
+    }
 }
 
 // Safe guard in debug mode to ensure that `finalize()` is called before dropping
@@ -591,6 +607,19 @@ impl ShardOperation for Inner {
             .query_batch(request, search_runtime_handle, timeout)
             .await
     }
+
+    /// Forward read-only `explain_query_batch` to `wrapped_shard`
+    async fn explain_query_batch(
+        &self,
+        request: Arc<Vec<ShardQueryRequest>>,
+        search_runtime_handle: &Handle,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<(Vec<ShardQueryResponse>, Vec<SegmentSearchExplanation>)> {
+        let local_shard = &self.wrapped_shard;
+        local_shard
+            .explain_query_batch(request, search_runtime_handle, timeout)
+            .await
+    }
 }
 
 /// Transfer batch of operations without retries
@@ -26,6 +26,7 @@ use segment::common::operation_time_statistics::{
     OperationDurationsAggregator, ScopeDurationMeasurer,
 };
 use segment::data_types::order_by::OrderBy;
+use segment::data_types::search_explain::SegmentSearchExplanation;
 use segment::types::{
     ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
 };
@@ -55,7 +56,8 @@ use crate::shards::channel_service::ChannelService;
 use crate::shards::conversions::{
     internal_clear_payload, internal_clear_payload_by_filter, internal_create_index,
     internal_delete_index, internal_delete_payload, internal_delete_points,
-    internal_delete_points_by_filter, internal_set_payload, internal_sync_points,
+    internal_delete_points_by_filter, internal_mutate_payload, internal_restore_points,
+    internal_set_payload, internal_sync_points, internal_trash_points, internal_update_batch,
     internal_upsert_points, try_scored_point_from_grpc,
 };
 use crate::shards::shard::{PeerId, ShardId};
@@ -259,6 +261,27 @@ impl RemoteShard {
                         operation.clock_tag,
                         collection_name,
                         point_insert_operations,
+                        None,
+                        None,
+                        None,
+                        wait,
+                        ordering,
+                    )?;
+                    self.with_points_client(|mut client| async move {
+                        client.upsert(tonic::Request::new(request.clone())).await
+                    })
+                    .await?
+                    .into_inner()
+                }
+                PointOperations::UpsertPointsConditional(conditional_upsert) => {
+                    let request = &internal_upsert_points(
+                        shard_id,
+                        operation.clock_tag,
+                        collection_name,
+                        conditional_upsert.points_op,
+                        conditional_upsert.condition,
+                        Some(conditional_upsert.update_mode),
+                        conditional_upsert.idempotency_key,
                         wait,
                         ordering,
                     )?;
@@ -298,6 +321,36 @@ impl RemoteShard {
                     .await?
                     .into_inner()
                 }
+                PointOperations::TrashPoints(trash_points) => {
+                    let request = &internal_trash_points(
+                        shard_id,
+                        operation.clock_tag,
+                        collection_name,
+                        trash_points,
+                        wait,
+                        ordering,
+                    );
+                    self.with_points_client(|mut client| async move {
+                        client.delete(tonic::Request::new(request.clone())).await
+                    })
+                    .await?
+                    .into_inner()
+                }
+                PointOperations::RestorePoints(restore_points) => {
+                    let request = &internal_restore_points(
+                        shard_id,
+                        operation.clock_tag,
+                        collection_name,
+                        restore_points,
+                        wait,
+                        ordering,
+                    );
+                    self.with_points_client(|mut client| async move {
+                        client.restore(tonic::Request::new(request.clone())).await
+                    })
+                    .await?
+                    .into_inner()
+                }
                 PointOperations::SyncPoints(operation) => {
                     let request = &internal_sync_points(
                         shard_id,
@@ -387,6 +440,23 @@ impl RemoteShard {
                     .await?
                     .into_inner()
                 }
+                PayloadOps::MutatePayload(mutate_payload) => {
+                    let request = &internal_mutate_payload(
+                        shard_id,
+                        operation.clock_tag,
+                        collection_name,
+                        mutate_payload,
+                        wait,
+                        ordering,
+                    );
+                    self.with_points_client(|mut client| async move {
+                        client
+                            .mutate_payload(tonic::Request::new(request.clone()))
+                            .await
+                    })
+                    .await?
+                    .into_inner()
+                }
                 PayloadOps::DeletePayload(delete_payload) => {
                     let request = &internal_delete_payload(
                         shard_id,
@@ -493,6 +563,23 @@ impl RemoteShard {
                     .into_inner()
                 }
             },
+            CollectionUpdateOperations::AtomicBatch(batch) => {
+                let request = &internal_update_batch(
+                    shard_id,
+                    operation.clock_tag,
+                    collection_name,
+                    batch,
+                    wait,
+                    ordering,
+                )?;
+                self.with_points_client(|mut client| async move {
+                    client
+                        .update_batch(tonic::Request::new(request.clone()))
+                        .await
+                })
+                .await?
+                .into_inner()
+            }
         };
         match point_operation_response.result {
             None => Err(CollectionError::service_error(
@@ -629,6 +716,74 @@ impl RemoteShard {
 
         Ok(())
     }
+
+    /// Query the remote shard, optionally explaining how each segment executed the searches
+    async fn do_query_batch(
+        &self,
+        requests: &[ShardQueryRequest],
+        timeout: Option<Duration>,
+        explain: bool,
+    ) -> CollectionResult<(Vec<ShardQueryResponse>, Vec<SegmentSearchExplanation>)> {
+        let mut timer = ScopeDurationMeasurer::new(&self.telemetry_search_durations);
+        timer.set_success(false);
+
+        let batch_response = self
+            .with_points_client(|mut client| async move {
+                let query_points = requests
+                    .iter()
+                    .map(|request| QueryShardPoints::from(request.clone()))
+                    .collect();
+
+                let request = &QueryBatchPointsInternal {
+                    collection_name: self.collection_id.clone(),
+                    query_points,
+                    shard_id: Some(self.id),
+                    timeout: timeout.map(|t| t.as_secs()),
+                    explain: Some(explain),
+                };
+
+                let mut request = tonic::Request::new(request.clone());
+
+                if let Some(timeout) = timeout {
+                    request.set_timeout(timeout);
+                }
+
+                client.query_batch(request).await
+            })
+            .await?
+            .into_inner();
+
+        let explanations = batch_response
+            .explanations
+            .into_iter()
+            .map(SegmentSearchExplanation::try_from)
+            .try_collect()?;
+
+        let result = batch_response
+            .results
+            .into_iter()
+            .zip(requests.iter())
+            .map(|(query_result, request)| {
+                let is_payload_required = request.with_payload.is_required();
+
+                query_result
+                    .intermediate_results
+                    .into_iter()
+                    .map(|intermediate| {
+                        intermediate
+                            .result
+                            .into_iter()
+                            .map(|point| try_scored_point_from_grpc(point, is_payload_required))
+                            .collect()
+                    })
+                    .collect()
+            })
+            .try_collect()?;
+
+        timer.set_success(true);
+
+        Ok((result, explanations))
+    }
 }
 
 // New-type to own the type in the crate for conversions via From
@@ -844,59 +999,16 @@ impl ShardOperation for RemoteShard {
         _search_runtime_handle: &Handle,
         timeout: Option<Duration>,
     ) -> CollectionResult<Vec<ShardQueryResponse>> {
-        let mut timer = ScopeDurationMeasurer::new(&self.telemetry_search_durations);
-        timer.set_success(false);
-
-        let requests = requests.as_ref();
-
-        let batch_response = self
-            .with_points_client(|mut client| async move {
-                let query_points = requests
-                    .iter()
-                    .map(|request| QueryShardPoints::from(request.clone()))
-                    .collect();
-
-                let request = &QueryBatchPointsInternal {
-                    collection_name: self.collection_id.clone(),
-                    query_points,
-                    shard_id: Some(self.id),
-                    timeout: timeout.map(|t| t.as_secs()),
-                };
-
-                let mut request = tonic::Request::new(request.clone());
-
-                if let Some(timeout) = timeout {
-                    request.set_timeout(timeout);
-                }
-
-                client.query_batch(request).await
-            })
-            .await?
-            .into_inner();
-
-        let result = batch_response
-            .results
-            .into_iter()
-            .zip(requests.iter())
-            .map(|(query_result, request)| {
-                let is_payload_required = request.with_payload.is_required();
-
-                query_result
-                    .intermediate_results
-                    .into_iter()
-                    .map(|intermediate| {
-                        intermediate
-                            .result
-                            .into_iter()
-                            .map(|point| try_scored_point_from_grpc(point, is_payload_required))
-                            .collect()
-                    })
-                    .collect()
-            })
-            .try_collect()?;
-
-        timer.set_success(true);
+        let (responses, _) = self.do_query_batch(&requests, timeout, false).await?;
+        Ok(responses)
+    }
 
-        Ok(result)
+    async fn explain_query_batch(
+        &self,
+        requests: Arc<Vec<ShardQueryRequest>>,
+        _search_runtime_handle: &Handle,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<(Vec<ShardQueryResponse>, Vec<SegmentSearchExplanation>)> {
+        self.do_query_batch(&requests, timeout, true).await
     }
 }
@@ -37,6 +37,7 @@ use crate::shards::replica_set::clock_set::ClockSet;
 use crate::shards::shard::{PeerId, Shard, ShardId};
 use crate::shards::shard_config::ShardConfig;
 use crate::shards::telemetry::ReplicaSetTelemetry;
+use crate::wal_delta::WalUpdatesReader;
 
 //    │    Collection Created
 //    │
@@ -892,6 +893,18 @@ impl ShardReplicaSet {
         local_shard.update_cutoff(cutoff).await
     }
 
+    /// Reader of update operations in the WAL of the local shard
+    pub(crate) async fn wal_updates(&self) -> CollectionResult<WalUpdatesReader> {
+        let local_shard = self.local.read().await;
+        let Some(local_shard) = local_shard.as_ref() else {
+            return Err(CollectionError::NotFound {
+                what: "Peer does not have local shard".into(),
+            });
+        };
+
+        local_shard.wal_updates()
+    }
+
     pub(crate) fn get_snapshots_storage_manager(&self) -> CollectionResult<SnapshotStorageManager> {
         SnapshotStorageManager::new(self.shared_storage_config.snapshots_config.clone())
     }
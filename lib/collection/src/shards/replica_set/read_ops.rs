@@ -3,6 +3,7 @@ use std::time::Duration;
 
 use futures::FutureExt as _;
 use segment::data_types::order_by::OrderBy;
+use segment::data_types::search_explain::SegmentSearchExplanation;
 use segment::types::*;
 
 use super::ShardReplicaSet;
@@ -157,4 +158,30 @@ impl ShardReplicaSet {
         )
         .await
     }
+
+    /// Query a single replica of the shard, explaining how each of its segments executed the searches
+    ///
+    /// Explanations of different replicas can't be merged, so read consistency is not applied.
+    pub async fn explain_query_batch(
+        &self,
+        requests: Arc<Vec<ShardQueryRequest>>,
+        local_only: bool,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<(Vec<ShardQueryResponse>, Vec<SegmentSearchExplanation>)> {
+        self.execute_read_operation(
+            |shard| {
+                let requests = Arc::clone(&requests);
+                let search_runtime = self.search_runtime.clone();
+
+                async move {
+                    shard
+                        .explain_query_batch(requests, &search_runtime, timeout)
+                        .await
+                }
+                .boxed()
+            },
+            local_only,
+        )
+        .await
+    }
 }
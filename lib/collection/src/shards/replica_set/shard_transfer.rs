@@ -355,6 +355,28 @@ impl ShardReplicaSet {
             .await
     }
 
+    /// Custom operation for transferring points in trash from one shard to another during transfer
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe.
+    pub async fn transfer_trash(
+        &self,
+        batch_size: usize,
+        hashring_filter: Option<&HashRing>,
+    ) -> CollectionResult<()> {
+        let local = self.local.read().await;
+
+        let Some(Shard::ForwardProxy(proxy)) = local.deref() else {
+            return Err(CollectionError::service_error(format!(
+                "Cannot transfer trash from shard {} because it is not proxified",
+                self.shard_id
+            )));
+        };
+
+        proxy.transfer_trash(batch_size, hashring_filter).await
+    }
+
     /// Custom operation for transferring indexes from one shard to another during transfer
     ///
     /// # Cancel safety
@@ -112,6 +112,11 @@ impl ShardReplicaSet {
         }
     }
 
+    /// Whether this peer is the designated leader for updates with the given ordering
+    pub(crate) fn is_leader_for_update(&self, ordering: WriteOrdering) -> bool {
+        self.leader_peer_for_update(ordering) == Some(self.this_peer_id())
+    }
+
     /// Designated a leader replica for the update based on the WriteOrdering
     fn leader_peer_for_update(&self, ordering: WriteOrdering) -> Option<PeerId> {
         match ordering {
@@ -579,6 +584,8 @@ mod tests {
             wal_config,
             hnsw_config: Default::default(),
             quantization_config: None,
+            strict_mode_config: None,
+            payload_validation_config: None,
         };
 
         let payload_index_schema_dir = Builder::new().prefix("qdrant-test").tempdir().unwrap();
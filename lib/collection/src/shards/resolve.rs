@@ -279,6 +279,7 @@ mod test {
             vector: None,
             shard_key: None,
             order_value: None,
+            then_order_values: Vec::new(),
         }
     }
 
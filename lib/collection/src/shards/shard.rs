@@ -14,6 +14,7 @@ use crate::shards::proxy_shard::ProxyShard;
 use crate::shards::queue_proxy_shard::QueueProxyShard;
 use crate::shards::shard_trait::ShardOperation;
 use crate::shards::telemetry::LocalShardTelemetry;
+use crate::wal_delta::WalUpdatesReader;
 
 pub type ShardId = u32;
 
@@ -209,4 +210,18 @@ impl Shard {
             ))),
         }
     }
+
+    pub fn wal_updates(&self) -> CollectionResult<WalUpdatesReader> {
+        match self {
+            Self::Local(local_shard) => Ok(local_shard.wal_updates()),
+            Self::ForwardProxy(proxy_shard) => Ok(proxy_shard.wrapped_shard.wal_updates()),
+
+            Self::Proxy(_) | Self::QueueProxy(_) | Self::Dummy(_) => {
+                Err(CollectionError::service_error(format!(
+                    "Reading WAL updates not supported on {}",
+                    self.variant_name(),
+                )))
+            }
+        }
+    }
 }
@@ -24,7 +24,9 @@ use crate::operations::shard_selector_internal::ShardSelectorInternal;
 use crate::operations::shared_storage_config::SharedStorageConfig;
 use crate::operations::snapshot_ops::SnapshotDescription;
 use crate::operations::types::{CollectionError, CollectionResult, ShardTransferInfo};
-use crate::operations::{OperationToShard, SplitByShard};
+use crate::operations::{
+    AtomicBatchOp, CollectionUpdateOperations, OperationToShard, SplitByShard,
+};
 use crate::optimizers_builder::OptimizersConfig;
 use crate::save_on_disk::SaveOnDisk;
 use crate::shards::channel_service::ChannelService;
@@ -573,6 +575,50 @@ impl ShardHolder {
         Ok(shard_ops)
     }
 
+    /// Same as [`Self::split_by_shard`], but atomic batches are split operation by operation,
+    /// so that each shard receives a single batch with only the operations it is responsible for.
+    pub fn split_update_by_shard(
+        &self,
+        operation: CollectionUpdateOperations,
+        shard_keys_selection: &Option<ShardKey>,
+    ) -> CollectionResult<Vec<(&ShardReplicaSet, CollectionUpdateOperations)>> {
+        let CollectionUpdateOperations::AtomicBatch(batch) = operation else {
+            return self.split_by_shard(operation, shard_keys_selection);
+        };
+
+        let AtomicBatchOp {
+            operations,
+            idempotency_key,
+        } = batch;
+
+        // Keep the original order of operations within each shard
+        let mut by_shard: Vec<(&ShardReplicaSet, Vec<CollectionUpdateOperations>)> = Vec::new();
+        for operation in operations {
+            for (shard, operation) in self.split_by_shard(operation, shard_keys_selection)? {
+                match by_shard
+                    .iter_mut()
+                    .find(|(existing, _)| existing.shard_id == shard.shard_id)
+                {
+                    Some((_, operations)) => operations.push(operation),
+                    None => by_shard.push((shard, vec![operation])),
+                }
+            }
+        }
+
+        Ok(by_shard
+            .into_iter()
+            .map(|(shard, operations)| {
+                (
+                    shard,
+                    CollectionUpdateOperations::AtomicBatch(AtomicBatchOp {
+                        operations,
+                        idempotency_key: idempotency_key.clone(),
+                    }),
+                )
+            })
+            .collect())
+    }
+
     pub fn register_start_shard_transfer(&self, transfer: ShardTransfer) -> CollectionResult<bool> {
         let changed = self
             .shard_transfers
@@ -3,6 +3,7 @@ use std::time::Duration;
 
 use async_trait::async_trait;
 use segment::data_types::order_by::OrderBy;
+use segment::data_types::search_explain::SegmentSearchExplanation;
 use segment::types::*;
 use tokio::runtime::Handle;
 
@@ -54,6 +55,14 @@ pub trait ShardOperation {
         search_runtime_handle: &Handle,
         timeout: Option<Duration>,
     ) -> CollectionResult<Vec<ShardQueryResponse>>;
+
+    /// Same as `query_batch`, but also explains how each segment executed the searches
+    async fn explain_query_batch(
+        &self,
+        requests: Arc<Vec<ShardQueryRequest>>,
+        search_runtime_handle: &Handle,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<(Vec<ShardQueryResponse>, Vec<SegmentSearchExplanation>)>;
 }
 
 pub type ShardOperationSS = dyn ShardOperation + Send + Sync;
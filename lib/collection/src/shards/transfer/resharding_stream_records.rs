@@ -109,6 +109,23 @@ pub(crate) async fn transfer_resharding_stream_records(
         }
     }
 
+    // Points in trash are not part of the batches, transfer them separately
+    {
+        let shard_holder = shard_holder.read().await;
+
+        let Some(replica_set) = shard_holder.get_shard(&shard_id) else {
+            // Forward proxy gone?!
+            // That would be a programming error.
+            return Err(CollectionError::service_error(format!(
+                "Shard {shard_id} is not found"
+            )));
+        };
+
+        replica_set
+            .transfer_trash(TRANSFER_BATCH_SIZE, Some(&hashring))
+            .await?;
+    }
+
     // Update cutoff point on remote shard, disallow recovery before our current last seen
     {
         let shard_holder = shard_holder.read().await;
@@ -46,6 +46,8 @@ impl Anonymize for CollectionConfig {
             optimizer_config: self.optimizer_config.clone(),
             wal_config: self.wal_config.clone(),
             quantization_config: self.quantization_config.clone(),
+            strict_mode_config: self.strict_mode_config.clone(),
+            payload_validation_config: self.payload_validation_config.clone(),
         }
     }
 }
@@ -0,0 +1,76 @@
+use std::num::NonZeroU32;
+
+use api::rest::VectorStruct;
+use segment::types::{Condition, Distance, ExtendedPointId, FieldCondition, Filter, Payload};
+use serde_json::json;
+use tempfile::Builder;
+
+use crate::config::CollectionParams;
+use crate::operations::point_ops::{
+    PointInsertOperationsInternal, PointOperations, PointStruct, WriteOrdering,
+};
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::types::VectorsConfig;
+use crate::operations::vector_params_builder::VectorParamsBuilder;
+use crate::operations::CollectionUpdateOperations;
+use crate::tests::fixtures::create_local_collection;
+
+const SHARD_COUNT: u32 = 2;
+const POINT_COUNT: u64 = 30;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_affected_points_across_shards() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+    let snapshots_dir = Builder::new().prefix("test_snapshots").tempdir().unwrap();
+
+    let collection = create_local_collection(
+        collection_dir.path(),
+        snapshots_dir.path(),
+        CollectionParams {
+            vectors: VectorsConfig::Single(VectorParamsBuilder::new(4, Distance::Dot).build()),
+            shard_number: NonZeroU32::new(SHARD_COUNT).unwrap(),
+            ..CollectionParams::empty()
+        },
+    )
+    .await;
+
+    let points = (0..POINT_COUNT)
+        .map(|id| PointStruct {
+            id: id.into(),
+            vector: VectorStruct::Single(vec![1.0, 2.0, 3.0, 4.0]),
+            payload: Some(
+                serde_json::from_value::<Payload>(json!({ "even": id % 2 == 0 })).unwrap(),
+            ),
+        })
+        .collect();
+
+    collection
+        .update_from_client_simple(
+            CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+                PointInsertOperationsInternal::PointsList(points),
+            )),
+            true,
+            WriteOrdering::default(),
+        )
+        .await
+        .expect("failed to insert points");
+
+    let filter = Filter::new_must(Condition::Field(FieldCondition::new_match(
+        "even".parse().unwrap(),
+        true.into(),
+    )));
+
+    let operation =
+        CollectionUpdateOperations::PointOperation(PointOperations::DeletePointsByFilter(filter));
+    let affected = collection
+        .affected_points(operation, &ShardSelectorInternal::All)
+        .await
+        .unwrap();
+
+    assert_eq!(affected.affected_points, (POINT_COUNT / 2) as usize);
+    assert!(!affected.sample.is_empty());
+    assert!(affected.sample.iter().all(|id| match id {
+        ExtendedPointId::NumId(id) => id % 2 == 0,
+        ExtendedPointId::Uuid(_) => false,
+    }));
+}
@@ -1,16 +1,30 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::Arc;
 
+use common::cpu::CpuBudget;
 use segment::data_types::vectors::VectorStructInternal;
 use segment::types::{
     Condition, Distance, Filter, PayloadFieldSchema, PayloadSchemaType, PointIdType,
 };
 
+use crate::collection::Collection;
 use crate::config::{CollectionConfig, CollectionParams, WalConfig};
 use crate::operations::point_ops::{PointOperations, PointStruct};
+use crate::operations::shared_storage_config::SharedStorageConfig;
 use crate::operations::types::VectorsConfig;
 use crate::operations::vector_params_builder::VectorParamsBuilder;
 use crate::operations::{CollectionUpdateOperations, CreateIndex, FieldIndexOperations};
 use crate::optimizers_builder::OptimizersConfig;
+use crate::shards::channel_service::ChannelService;
+use crate::shards::collection_shard_distribution::CollectionShardDistribution;
+use crate::shards::replica_set::ReplicaState;
+use crate::shards::shard::PeerId;
+use crate::tests::snapshot_test::{
+    dummy_abort_shard_transfer, dummy_on_replica_failure, dummy_request_shard_transfer,
+};
+
+pub const LOCAL_PEER_ID: PeerId = 1;
 
 pub const TEST_OPTIMIZERS_CONFIG: OptimizersConfig = OptimizersConfig {
     deleted_threshold: 0.9,
@@ -45,6 +59,8 @@ pub fn create_collection_config() -> CollectionConfig {
         wal_config,
         hnsw_config: Default::default(),
         quantization_config: Default::default(),
+        strict_mode_config: Default::default(),
+        payload_validation_config: Default::default(),
     }
 }
 
@@ -110,3 +126,58 @@ pub fn delete_point_operation(idx: u64) -> CollectionUpdateOperations {
 pub fn filter_single_id(id: impl Into<PointIdType>) -> Filter {
     Filter::new_must(Condition::HasId(HashSet::from([id.into()]).into()))
 }
+
+/// Create a collection with the given params, with all shards local to a single peer and active.
+pub async fn create_local_collection(
+    collection_dir: &Path,
+    snapshots_dir: &Path,
+    params: CollectionParams,
+) -> Collection {
+    let shard_count = params.shard_number.get();
+
+    let config = CollectionConfig {
+        params,
+        optimizer_config: OptimizersConfig::fixture(),
+        wal_config: WalConfig {
+            wal_capacity_mb: 1,
+            wal_segments_ahead: 0,
+        },
+        hnsw_config: Default::default(),
+        quantization_config: Default::default(),
+        strict_mode_config: Default::default(),
+        payload_validation_config: Default::default(),
+    };
+
+    let collection = Collection::new(
+        "test".to_string(),
+        LOCAL_PEER_ID,
+        collection_dir,
+        snapshots_dir,
+        &config,
+        Arc::new(SharedStorageConfig::default()),
+        CollectionShardDistribution {
+            shards: (0..shard_count)
+                .map(|shard_id| (shard_id, HashSet::from([LOCAL_PEER_ID])))
+                .collect::<HashMap<_, _>>(),
+        },
+        ChannelService::default(),
+        dummy_on_replica_failure(),
+        dummy_request_shard_transfer(),
+        dummy_abort_shard_transfer(),
+        None,
+        None,
+        CpuBudget::default(),
+        None,
+    )
+    .await
+    .unwrap();
+
+    for shard_id in 0..shard_count {
+        collection
+            .set_shard_replica_state(shard_id, LOCAL_PEER_ID, ReplicaState::Active, None)
+            .await
+            .expect("failed to activate shard");
+    }
+
+    collection
+}
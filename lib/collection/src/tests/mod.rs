@@ -1,10 +1,14 @@
+mod affected_points;
 mod fix_payload_indices;
 pub mod fixtures;
+mod point_expiry;
 mod points_dedup;
 mod sha_256_test;
 mod shard_query;
 mod snapshot_test;
 mod sparse_vectors_validation_tests;
+mod trash;
+mod vector_params_validation_tests;
 mod wal_recovery_test;
 
 use std::sync::Arc;
@@ -0,0 +1,165 @@
+use std::collections::HashSet;
+
+use api::rest::VectorStruct;
+use chrono::{Duration, Utc};
+use segment::types::{Condition, Distance, ExtendedPointId, Filter, Payload, PayloadSchemaType};
+use serde_json::json;
+use tempfile::Builder;
+
+use crate::collection::Collection;
+use crate::config::CollectionParams;
+use crate::operations::point_ops::{
+    PointInsertOperationsInternal, PointOperations, PointStruct, WriteOrdering,
+};
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::types::{
+    CountRequestInternal, PointRequestInternal, ScrollRequestInternal, VectorsConfig,
+};
+use crate::operations::vector_params_builder::VectorParamsBuilder;
+use crate::operations::CollectionUpdateOperations;
+use crate::tests::fixtures::create_local_collection;
+
+const EXPIRED_POINT_ID: ExtendedPointId = ExtendedPointId::NumId(1);
+const ALIVE_POINT_ID: ExtendedPointId = ExtendedPointId::NumId(2);
+const PERMANENT_POINT_ID: ExtendedPointId = ExtendedPointId::NumId(3);
+
+/// Create a collection with an expiry field, and one expired, one alive and one permanent point.
+async fn fixture(collection_dir: &std::path::Path, snapshots_dir: &std::path::Path) -> Collection {
+    let collection = create_local_collection(
+        collection_dir,
+        snapshots_dir,
+        CollectionParams {
+            vectors: VectorsConfig::Single(VectorParamsBuilder::new(4, Distance::Dot).build()),
+            expiry_field: Some("expires_at".parse().unwrap()),
+            ..CollectionParams::empty()
+        },
+    )
+    .await;
+
+    let now = Utc::now();
+    let point = |id, payload: serde_json::Value| PointStruct {
+        id,
+        vector: VectorStruct::Single(vec![1.0, 2.0, 3.0, 4.0]),
+        payload: Some(serde_json::from_value::<Payload>(payload).unwrap()),
+    };
+    let points = vec![
+        point(
+            EXPIRED_POINT_ID,
+            json!({ "expires_at": (now - Duration::hours(1)).to_rfc3339() }),
+        ),
+        point(
+            ALIVE_POINT_ID,
+            json!({ "expires_at": (now + Duration::hours(1)).to_rfc3339() }),
+        ),
+        point(PERMANENT_POINT_ID, json!({ "num": 1 })),
+    ];
+
+    collection
+        .update_from_client_simple(
+            CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+                PointInsertOperationsInternal::PointsList(points),
+            )),
+            true,
+            WriteOrdering::default(),
+        )
+        .await
+        .expect("failed to insert points");
+
+    collection
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_expired_points_are_hidden() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+    let snapshots_dir = Builder::new().prefix("test_snapshots").tempdir().unwrap();
+    let collection = fixture(collection_dir.path(), snapshots_dir.path()).await;
+
+    let expected = HashSet::from([ALIVE_POINT_ID, PERMANENT_POINT_ID]);
+
+    let scrolled = collection
+        .scroll_by(
+            ScrollRequestInternal {
+                offset: None,
+                limit: Some(10),
+                filter: None,
+                with_payload: Some(false.into()),
+                with_vector: false.into(),
+                order_by: None,
+            },
+            None,
+            &ShardSelectorInternal::All,
+        )
+        .await
+        .unwrap();
+    let scrolled: HashSet<_> = scrolled.points.iter().map(|point| point.id).collect();
+    assert_eq!(scrolled, expected);
+
+    let retrieved = collection
+        .retrieve(
+            PointRequestInternal {
+                ids: vec![EXPIRED_POINT_ID, ALIVE_POINT_ID, PERMANENT_POINT_ID],
+                with_payload: Some(true.into()),
+                with_vector: false.into(),
+            },
+            None,
+            &ShardSelectorInternal::All,
+        )
+        .await
+        .unwrap();
+    let retrieved: HashSet<_> = retrieved.iter().map(|record| record.id).collect();
+    assert_eq!(retrieved, expected);
+
+    let count = collection
+        .count(
+            CountRequestInternal {
+                filter: None,
+                exact: true,
+            },
+            None,
+            &ShardSelectorInternal::All,
+        )
+        .await
+        .unwrap();
+    assert_eq!(count.count, expected.len());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_expired_points_are_removed() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+    let snapshots_dir = Builder::new().prefix("test_snapshots").tempdir().unwrap();
+    let collection = fixture(collection_dir.path(), snapshots_dir.path()).await;
+
+    let info = collection.info(&ShardSelectorInternal::All).await.unwrap();
+    assert_eq!(
+        info.payload_schema[&"expires_at".parse().unwrap()].data_type,
+        PayloadSchemaType::Datetime,
+    );
+    // Hidden, but not removed yet
+    assert_eq!(info.points_count, Some(3));
+
+    collection.remove_expired_points().await.unwrap();
+
+    let info = collection.info(&ShardSelectorInternal::All).await.unwrap();
+    assert_eq!(info.points_count, Some(2));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_dry_run_counts_expired_points() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+    let snapshots_dir = Builder::new().prefix("test_snapshots").tempdir().unwrap();
+    let collection = fixture(collection_dir.path(), snapshots_dir.path()).await;
+
+    // Update by filter still selects expired points, which are not removed yet
+    let filter = Filter::new_must(Condition::HasId(
+        HashSet::from([EXPIRED_POINT_ID, ALIVE_POINT_ID, PERMANENT_POINT_ID]).into(),
+    ));
+    let operation =
+        CollectionUpdateOperations::PointOperation(PointOperations::DeletePointsByFilter(filter));
+
+    let affected = collection
+        .affected_points(operation, &ShardSelectorInternal::All)
+        .await
+        .unwrap();
+    assert_eq!(affected.affected_points, 3);
+    assert!(!affected.sample.contains(&EXPIRED_POINT_ID));
+}
@@ -66,6 +66,9 @@ fn validate_error_sparse_vector_points_batch() {
             payloads: None,
         },
         shard_key: None,
+        update_filter: None,
+        update_mode: None,
+        idempotency_key: None,
     });
 }
 
@@ -74,6 +77,9 @@ fn validate_error_sparse_vector_points_list() {
     check_validation_error(PointsList {
         points: vec![wrong_point_struct()],
         shard_key: None,
+        update_filter: None,
+        update_mode: None,
+        idempotency_key: None,
     });
 }
 
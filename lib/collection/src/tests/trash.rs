@@ -0,0 +1,372 @@
+use std::collections::HashSet;
+
+use api::grpc::qdrant::subscribe_updates_response::Update;
+use api::rest::VectorStruct;
+use chrono::Utc;
+use segment::types::{Condition, Distance, ExtendedPointId, FieldCondition, Filter, Payload};
+use serde_json::json;
+use tempfile::Builder;
+
+use crate::collection::Collection;
+use crate::config::CollectionParams;
+use crate::operations::payload_ops::PayloadOps;
+use crate::operations::point_ops::{
+    FilterSelector, PointIdsList, PointInsertOperationsInternal, PointOperations, PointStruct,
+    PointsSelector, WriteOrdering,
+};
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::trash::RestorePointsOp;
+use crate::operations::types::{PointRequestInternal, ScrollRequestInternal, VectorsConfig};
+use crate::operations::vector_params_builder::VectorParamsBuilder;
+use crate::operations::CollectionUpdateOperations;
+use crate::shards::conversions::update_operation_to_grpc;
+use crate::tests::fixtures::create_local_collection;
+
+/// Create a collection with the given trash retention, and three points.
+async fn fixture(
+    collection_dir: &std::path::Path,
+    snapshots_dir: &std::path::Path,
+    trash_retention_sec: Option<u64>,
+) -> Collection {
+    let collection = create_local_collection(
+        collection_dir,
+        snapshots_dir,
+        CollectionParams {
+            vectors: VectorsConfig::Single(VectorParamsBuilder::new(4, Distance::Dot).build()),
+            trash_retention_sec,
+            ..CollectionParams::empty()
+        },
+    )
+    .await;
+
+    let points = (1..=3)
+        .map(|id| PointStruct {
+            id: ExtendedPointId::NumId(id),
+            vector: VectorStruct::Single(vec![1.0, 2.0, 3.0, 4.0]),
+            payload: Some(serde_json::from_value::<Payload>(json!({ "num": id })).unwrap()),
+        })
+        .collect();
+
+    upsert(&collection, points).await;
+
+    collection
+}
+
+async fn upsert(collection: &Collection, points: Vec<PointStruct>) {
+    collection
+        .update_from_client_simple(
+            CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+                PointInsertOperationsInternal::PointsList(points),
+            )),
+            true,
+            WriteOrdering::default(),
+        )
+        .await
+        .expect("failed to insert points");
+}
+
+async fn delete(collection: &Collection, operation: PointOperations) {
+    collection
+        .update_from_client_simple(
+            CollectionUpdateOperations::PointOperation(operation),
+            true,
+            WriteOrdering::default(),
+        )
+        .await
+        .expect("failed to delete points");
+}
+
+fn restore_operation(selector: PointsSelector) -> CollectionUpdateOperations {
+    CollectionUpdateOperations::PointOperation(PointOperations::RestorePoints(
+        RestorePointsOp::from(selector),
+    ))
+}
+
+fn restore_ids_operation(ids: Vec<ExtendedPointId>) -> CollectionUpdateOperations {
+    restore_operation(PointsSelector::PointIdsSelector(PointIdsList {
+        points: ids,
+        shard_key: None,
+    }))
+}
+
+fn num_filter(num: i64) -> Filter {
+    Filter::new_must(Condition::Field(FieldCondition::new_match(
+        "num".parse().unwrap(),
+        num.into(),
+    )))
+}
+
+async fn visible_ids(collection: &Collection) -> HashSet<ExtendedPointId> {
+    let scrolled = collection
+        .scroll_by(
+            ScrollRequestInternal {
+                offset: None,
+                limit: Some(10),
+                filter: None,
+                with_payload: Some(false.into()),
+                with_vector: false.into(),
+                order_by: None,
+            },
+            None,
+            &ShardSelectorInternal::All,
+        )
+        .await
+        .unwrap();
+    scrolled.points.iter().map(|point| point.id).collect()
+}
+
+async fn retrieve_payload(collection: &Collection, id: ExtendedPointId) -> Option<Payload> {
+    let retrieved = collection
+        .retrieve(
+            PointRequestInternal {
+                ids: vec![id],
+                with_payload: Some(true.into()),
+                with_vector: false.into(),
+            },
+            None,
+            &ShardSelectorInternal::All,
+        )
+        .await
+        .unwrap();
+    retrieved
+        .into_iter()
+        .next()
+        .map(|record| record.payload.unwrap_or_default())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_deleted_points_are_restorable() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+    let snapshots_dir = Builder::new().prefix("test_snapshots").tempdir().unwrap();
+    let collection = fixture(collection_dir.path(), snapshots_dir.path(), Some(3600)).await;
+
+    let trashed_id = ExtendedPointId::NumId(1);
+
+    delete(
+        &collection,
+        PointOperations::DeletePoints {
+            ids: vec![trashed_id],
+        },
+    )
+    .await;
+
+    assert_eq!(
+        visible_ids(&collection).await,
+        HashSet::from([ExtendedPointId::NumId(2), ExtendedPointId::NumId(3)]),
+    );
+    assert!(retrieve_payload(&collection, trashed_id).await.is_none());
+
+    collection
+        .update_from_client_simple(
+            restore_ids_operation(vec![trashed_id]),
+            true,
+            WriteOrdering::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        visible_ids(&collection).await,
+        HashSet::from([
+            ExtendedPointId::NumId(1),
+            ExtendedPointId::NumId(2),
+            ExtendedPointId::NumId(3),
+        ]),
+    );
+
+    // Payload is restored as it was, trash doesn't add any fields to it
+    let payload = retrieve_payload(&collection, trashed_id).await.unwrap();
+    assert_eq!(
+        payload,
+        serde_json::from_value::<Payload>(json!({ "num": 1 })).unwrap(),
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_deleted_points_are_restorable_by_filter() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+    let snapshots_dir = Builder::new().prefix("test_snapshots").tempdir().unwrap();
+    let collection = fixture(collection_dir.path(), snapshots_dir.path(), Some(3600)).await;
+
+    delete(
+        &collection,
+        PointOperations::DeletePointsByFilter(Filter {
+            must_not: num_filter(1).must,
+            ..Default::default()
+        }),
+    )
+    .await;
+
+    assert_eq!(
+        visible_ids(&collection).await,
+        HashSet::from([ExtendedPointId::NumId(1)]),
+    );
+
+    // Payload of points in trash is still matched by the restore filter
+    collection
+        .update_from_client_simple(
+            restore_operation(PointsSelector::FilterSelector(FilterSelector {
+                filter: num_filter(3),
+                shard_key: None,
+            })),
+            true,
+            WriteOrdering::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        visible_ids(&collection).await,
+        HashSet::from([ExtendedPointId::NumId(1), ExtendedPointId::NumId(3)]),
+    );
+
+    // Points in trash are not matched by read filters
+    let scrolled = collection
+        .scroll_by(
+            ScrollRequestInternal {
+                offset: None,
+                limit: Some(10),
+                filter: Some(num_filter(2)),
+                with_payload: Some(false.into()),
+                with_vector: false.into(),
+                order_by: None,
+            },
+            None,
+            &ShardSelectorInternal::All,
+        )
+        .await
+        .unwrap();
+    assert!(scrolled.points.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_trashed_points_are_not_updated() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+    let snapshots_dir = Builder::new().prefix("test_snapshots").tempdir().unwrap();
+    let collection = fixture(collection_dir.path(), snapshots_dir.path(), Some(3600)).await;
+
+    let trashed_id = ExtendedPointId::NumId(1);
+    let updated_id = ExtendedPointId::NumId(2);
+
+    delete(
+        &collection,
+        PointOperations::DeletePoints {
+            ids: vec![trashed_id],
+        },
+    )
+    .await;
+
+    collection
+        .update_from_client_simple(
+            CollectionUpdateOperations::PayloadOperation(PayloadOps::ClearPayload {
+                points: vec![trashed_id, updated_id],
+            }),
+            true,
+            WriteOrdering::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        visible_ids(&collection).await,
+        HashSet::from([updated_id, ExtendedPointId::NumId(3)]),
+    );
+
+    collection
+        .update_from_client_simple(
+            restore_ids_operation(vec![trashed_id]),
+            true,
+            WriteOrdering::default(),
+        )
+        .await
+        .unwrap();
+
+    let trashed_payload = retrieve_payload(&collection, trashed_id).await.unwrap();
+    assert_eq!(trashed_payload.0.get("num"), Some(&json!(1)));
+
+    let updated_payload = retrieve_payload(&collection, updated_id).await.unwrap();
+    assert!(updated_payload.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_upsert_replaces_trashed_point() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+    let snapshots_dir = Builder::new().prefix("test_snapshots").tempdir().unwrap();
+    let collection = fixture(collection_dir.path(), snapshots_dir.path(), Some(3600)).await;
+
+    let trashed_id = ExtendedPointId::NumId(1);
+
+    delete(
+        &collection,
+        PointOperations::DeletePoints {
+            ids: vec![trashed_id],
+        },
+    )
+    .await;
+
+    upsert(
+        &collection,
+        vec![PointStruct {
+            id: trashed_id,
+            vector: VectorStruct::Single(vec![4.0, 3.0, 2.0, 1.0]),
+            payload: Some(serde_json::from_value::<Payload>(json!({ "num": 10 })).unwrap()),
+        }],
+    )
+    .await;
+
+    // Nothing is left in trash, so the new point is kept as is
+    collection
+        .update_from_client_simple(
+            restore_ids_operation(vec![trashed_id]),
+            true,
+            WriteOrdering::default(),
+        )
+        .await
+        .unwrap();
+
+    let payload = retrieve_payload(&collection, trashed_id).await.unwrap();
+    assert_eq!(payload.0.get("num"), Some(&json!(10)));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_restore_requires_trash() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+    let snapshots_dir = Builder::new().prefix("test_snapshots").tempdir().unwrap();
+    let collection = fixture(collection_dir.path(), snapshots_dir.path(), None).await;
+
+    let deleted_id = ExtendedPointId::NumId(1);
+
+    delete(
+        &collection,
+        PointOperations::DeletePoints {
+            ids: vec![deleted_id],
+        },
+    )
+    .await;
+
+    let restored = collection
+        .update_from_client_simple(
+            restore_ids_operation(vec![deleted_id]),
+            true,
+            WriteOrdering::default(),
+        )
+        .await;
+    assert!(restored.is_err());
+    assert!(retrieve_payload(&collection, deleted_id).await.is_none());
+}
+
+#[test]
+fn test_trashed_points_are_streamed_as_deleted() {
+    let operation = CollectionUpdateOperations::PointOperation(PointOperations::DeletePoints {
+        ids: vec![ExtendedPointId::NumId(1)],
+    })
+    .into_trash(Utc::now());
+
+    let update = update_operation_to_grpc("test".to_string(), operation).unwrap();
+    assert!(matches!(update, Some(Update::DeletePoints(_))));
+
+    let update =
+        update_operation_to_grpc("test".to_string(), restore_ids_operation(vec![1.into()]))
+            .unwrap();
+    assert!(matches!(update, Some(Update::RestorePoints(_))));
+}
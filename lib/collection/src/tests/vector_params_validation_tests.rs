@@ -0,0 +1,99 @@
+use segment::types::{
+    BinaryQuantization, BinaryQuantizationConfig, Distance, QuantizationConfig, ScalarQuantization,
+    ScalarQuantizationConfig, ScalarType,
+};
+use validator::Validate;
+
+use crate::operations::types::{Datatype, VectorsConfig};
+use crate::operations::vector_params_builder::VectorParamsBuilder;
+
+fn scalar_quantization() -> QuantizationConfig {
+    QuantizationConfig::Scalar(ScalarQuantization {
+        scalar: ScalarQuantizationConfig {
+            r#type: ScalarType::Int8,
+            quantile: None,
+            always_ram: None,
+        },
+    })
+}
+
+fn binary_quantization() -> QuantizationConfig {
+    QuantizationConfig::Binary(BinaryQuantization {
+        binary: BinaryQuantizationConfig {
+            always_ram: None,
+            encoding: None,
+            query_encoding: None,
+        },
+    })
+}
+
+#[test]
+fn test_bit_datatype_requires_bit_distance() {
+    for distance in [Distance::Hamming, Distance::Jaccard] {
+        let params = VectorParamsBuilder::new(64, distance)
+            .with_datatype(Datatype::Bit)
+            .build();
+        assert!(params.validate().is_ok());
+
+        let params = VectorParamsBuilder::new(64, distance).build();
+        assert!(params.validate().is_err());
+    }
+
+    let params = VectorParamsBuilder::new(64, Distance::Dot)
+        .with_datatype(Datatype::Bit)
+        .build();
+    assert!(params.validate().is_err());
+}
+
+#[test]
+fn test_bit_distance_rejects_quantization() {
+    let params = VectorParamsBuilder::new(64, Distance::Hamming)
+        .with_datatype(Datatype::Bit)
+        .with_quantization_config(binary_quantization())
+        .build();
+    assert!(params.validate().is_err());
+}
+
+#[test]
+fn test_quantization_kinds_must_differ() {
+    let params = VectorParamsBuilder::new(64, Distance::Dot)
+        .with_quantization_config(scalar_quantization())
+        .with_additional_quantization_config(binary_quantization())
+        .build();
+    assert!(params.validate().is_ok());
+
+    let params = VectorParamsBuilder::new(64, Distance::Dot)
+        .with_quantization_config(scalar_quantization())
+        .with_additional_quantization_config(scalar_quantization())
+        .build();
+    assert!(params.validate().is_err());
+}
+
+#[test]
+fn test_prefix_view_rejects_quantization() {
+    let source = VectorParamsBuilder::new(64, Distance::Cosine).build();
+    let prefix_view = || VectorParamsBuilder::new(16, Distance::Cosine).with_prefix_of("full");
+
+    let config = VectorsConfig::Multi(
+        [
+            ("full".to_string(), source.clone()),
+            ("prefix".to_string(), prefix_view().build()),
+        ]
+        .into(),
+    );
+    assert!(config.validate().is_ok());
+
+    let config = VectorsConfig::Multi(
+        [
+            ("full".to_string(), source),
+            (
+                "prefix".to_string(),
+                prefix_view()
+                    .with_quantization_config(scalar_quantization())
+                    .build(),
+            ),
+        ]
+        .into(),
+    );
+    assert!(config.validate().is_err());
+}
@@ -6,6 +6,10 @@ use tempfile::Builder;
 use tokio::runtime::Handle;
 use tokio::sync::RwLock;
 
+use crate::operations::point_ops::{
+    ConditionalInsertOperationInternal, PointOperations, UpdateMode,
+};
+use crate::operations::CollectionUpdateOperations;
 use crate::save_on_disk::SaveOnDisk;
 use crate::shards::local_shard::LocalShard;
 use crate::shards::shard_trait::ShardOperation;
@@ -117,3 +121,123 @@ async fn test_delete_from_indexed_payload() {
     assert_eq!(number_of_indexed_points, 4);
     assert_eq!(number_of_indexed_points_after_load, 3);
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_read_wal_updates() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+
+    let config = create_collection_config();
+
+    let payload_index_schema_dir = Builder::new().prefix("qdrant-test").tempdir().unwrap();
+    let payload_index_schema_file = payload_index_schema_dir.path().join("payload-schema.json");
+    let payload_index_schema =
+        Arc::new(SaveOnDisk::load_or_init_default(payload_index_schema_file).unwrap());
+
+    let shard = LocalShard::build(
+        0,
+        "test".to_string(),
+        collection_dir.path(),
+        Arc::new(RwLock::new(config.clone())),
+        Arc::new(Default::default()),
+        payload_index_schema,
+        Handle::current(),
+        CpuBudget::default(),
+        config.optimizer_config.clone(),
+    )
+    .await
+    .unwrap();
+
+    let mut updates_reader = shard.wal_updates();
+    assert!(updates_reader.read(0, 10).await.unwrap().is_empty());
+
+    shard.update(upsert_operation().into(), true).await.unwrap();
+    shard
+        .update(delete_point_operation(4).into(), true)
+        .await
+        .unwrap();
+
+    // Reader is notified about operations written since the last read
+    assert!(updates_reader.wait().await);
+
+    let updates = updates_reader.read(0, 10).await.unwrap();
+    let op_nums: Vec<_> = updates.iter().map(|(op_num, _)| *op_num).collect();
+    assert_eq!(op_nums, vec![0, 1]);
+
+    let updates = updates_reader.read(1, 10).await.unwrap();
+    assert_eq!(updates.len(), 1);
+
+    let updates = updates_reader.read(2, 10).await.unwrap();
+    assert!(updates.is_empty());
+
+    // Reader is stopped once the shard is gone
+    drop(shard);
+    assert!(!updates_reader.wait().await);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_idempotent_upsert() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+
+    let config = create_collection_config();
+
+    let payload_index_schema_dir = Builder::new().prefix("qdrant-test").tempdir().unwrap();
+    let payload_index_schema_file = payload_index_schema_dir.path().join("payload-schema.json");
+    let payload_index_schema =
+        Arc::new(SaveOnDisk::load_or_init_default(payload_index_schema_file).unwrap());
+
+    let shard = LocalShard::build(
+        0,
+        "test".to_string(),
+        collection_dir.path(),
+        Arc::new(RwLock::new(config.clone())),
+        Arc::new(Default::default()),
+        payload_index_schema.clone(),
+        Handle::current(),
+        CpuBudget::default(),
+        config.optimizer_config.clone(),
+    )
+    .await
+    .unwrap();
+
+    let keyed_upsert = || {
+        let CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(points_op)) =
+            upsert_operation()
+        else {
+            unreachable!()
+        };
+        CollectionUpdateOperations::PointOperation(PointOperations::UpsertPointsConditional(
+            ConditionalInsertOperationInternal {
+                points_op,
+                condition: None,
+                update_mode: UpdateMode::Upsert,
+                idempotency_key: Some("request-1".to_string()),
+            },
+        ))
+    };
+
+    let first = shard.update(keyed_upsert().into(), true).await.unwrap();
+    let repeated = shard.update(keyed_upsert().into(), true).await.unwrap();
+    assert_eq!(first.operation_id, repeated.operation_id);
+    assert_eq!(shard.wal_updates().read(0, 10).await.unwrap().len(), 1);
+
+    drop(shard);
+
+    // Keys are restored from the WAL
+    let shard = LocalShard::load(
+        0,
+        "test".to_string(),
+        collection_dir.path(),
+        Arc::new(RwLock::new(config.clone())),
+        config.optimizer_config.clone(),
+        Arc::new(Default::default()),
+        payload_index_schema,
+        Handle::current(),
+        CpuBudget::default(),
+    )
+    .await
+    .unwrap();
+
+    let repeated = shard.update(keyed_upsert().into(), true).await.unwrap();
+    assert_eq!(first.operation_id, repeated.operation_id);
+    assert_eq!(shard.wal_updates().read(0, 10).await.unwrap().len(), 1);
+}
@@ -32,6 +32,7 @@ use crate::operations::shared_storage_config::SharedStorageConfig;
 use crate::operations::types::{CollectionError, CollectionResult};
 use crate::operations::CollectionUpdateOperations;
 use crate::save_on_disk::SaveOnDisk;
+use crate::shards::local_shard::idempotency::IdempotencyKeys;
 use crate::shards::local_shard::LocalShardClocks;
 use crate::wal::WalError;
 use crate::wal_delta::LockedWal;
@@ -117,6 +118,8 @@ pub struct UpdateHandler {
     pub max_optimization_threads: Option<usize>,
     /// Highest and cutoff clocks for the shard WAL.
     clocks: LocalShardClocks,
+    /// Idempotency keys of applied operations, persisted before the WAL is truncated.
+    idempotency_keys: Arc<Mutex<IdempotencyKeys>>,
     shard_path: PathBuf,
     /// Whether we have ever triggered optimizers since starting.
     has_triggered_optimizers: Arc<AtomicBool>,
@@ -136,6 +139,7 @@ impl UpdateHandler {
         flush_interval_sec: u64,
         max_optimization_threads: Option<usize>,
         clocks: LocalShardClocks,
+        idempotency_keys: Arc<Mutex<IdempotencyKeys>>,
         shard_path: PathBuf,
     ) -> UpdateHandler {
         UpdateHandler {
@@ -156,6 +160,7 @@ impl UpdateHandler {
             optimization_handles: Arc::new(TokioMutex::new(vec![])),
             max_optimization_threads,
             clocks,
+            idempotency_keys,
             shard_path,
             has_triggered_optimizers: Default::default(),
         }
@@ -190,6 +195,7 @@ impl UpdateHandler {
             self.flush_interval_sec,
             flush_rx,
             self.clocks.clone(),
+            self.idempotency_keys.clone(),
             self.shard_path.clone(),
         )));
         self.flush_stop = Some(flush_tx);
@@ -699,6 +705,7 @@ impl UpdateHandler {
         flush_interval_sec: u64,
         mut stop_receiver: oneshot::Receiver<()>,
         clocks: LocalShardClocks,
+        idempotency_keys: Arc<Mutex<IdempotencyKeys>>,
         shard_path: PathBuf,
     ) {
         loop {
@@ -754,6 +761,12 @@ impl UpdateHandler {
                 segments.write().report_optimizer_error(err);
             }
 
+            // Keys of truncated operations can't be recovered from the WAL anymore
+            if let Err(err) = idempotency_keys.lock().store_if_changed(&shard_path) {
+                log::warn!("Failed to store idempotency keys to disk: {err}");
+                segments.write().report_optimizer_error(err);
+            }
+
             if let Err(err) = wal.lock().ack(ack) {
                 log::warn!("Failed to acknowledge WAL version: {err}");
                 segments.write().report_optimizer_error(err);
@@ -3,8 +3,9 @@ use std::sync::Arc;
 
 use parking_lot::{Mutex as ParkingMutex, MutexGuard as ParkingMutexGuard};
 use thiserror::Error;
-use tokio::sync::Mutex;
+use tokio::sync::{watch, Mutex};
 
+use crate::operations::types::{CollectionError, CollectionResult};
 use crate::operations::{ClockTag, OperationWithClockTag};
 use crate::shards::local_shard::clock_map::{ClockMap, RecoveryPoint};
 use crate::wal::SerdeWal;
@@ -28,6 +29,9 @@ pub struct RecoverableWal {
     ///   - (so if we advance these clocks, we have to advance `newest_clocks` as well)
     /// - this WAL cannot resolve any delta below any of these clocks
     pub(super) oldest_clocks: Arc<Mutex<ClockMap>>,
+
+    /// Number of the last operation written into the WAL, notifies [`WalUpdatesReader`]s
+    appended: watch::Sender<u64>,
 }
 
 impl RecoverableWal {
@@ -40,6 +44,7 @@ impl RecoverableWal {
             wal,
             newest_clocks: highest_clocks,
             oldest_clocks: cutoff_clocks,
+            appended: watch::Sender::new(0),
         }
     }
 
@@ -72,7 +77,17 @@ impl RecoverableWal {
 
         // Write operation to WAL
         let mut wal_lock = self.wal.lock();
-        wal_lock.write(operation).map(|op_num| (op_num, wal_lock))
+        let op_num = wal_lock.write(operation)?;
+        self.appended.send_replace(op_num);
+        Ok((op_num, wal_lock))
+    }
+
+    /// Reader of operations in this WAL, which is notified about every newly written operation
+    pub fn updates_reader(&self) -> WalUpdatesReader {
+        WalUpdatesReader {
+            wal: self.wal.clone(),
+            appended: self.appended.subscribe(),
+        }
     }
 
     /// Update the cutoff clock map based on the given recovery point
@@ -239,6 +254,58 @@ fn resolve_wal_delta(
     Err(WalDeltaError::NotFound)
 }
 
+/// Reads operations from the WAL in order, and waits for new operations to be written
+pub struct WalUpdatesReader {
+    wal: LockedWal,
+    appended: watch::Receiver<u64>,
+}
+
+impl WalUpdatesReader {
+    /// Read at most `limit` operations, starting at operation number `from`
+    ///
+    /// Operations which are acknowledged but not yet truncated from the WAL are still readable.
+    /// The WAL is locked and read on a blocking thread, as deserializing operations may take a while.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the WAL was already truncated past `from`.
+    pub async fn read(
+        &mut self,
+        from: u64,
+        limit: usize,
+    ) -> CollectionResult<Vec<(u64, OperationWithClockTag)>> {
+        // Operations written after this point wake up `wait`, even if they are read right away
+        self.appended.mark_unchanged();
+
+        let wal = self.wal.clone();
+        tokio::task::spawn_blocking(move || {
+            let wal = wal.lock();
+
+            let first_index = wal.first_closed_index();
+            if from < first_index {
+                return Err(CollectionError::pre_condition_failed(format!(
+                    "WAL position {from} is already truncated, first available position is {first_index}",
+                )));
+            }
+
+            Ok(wal.read(from).take(limit).collect())
+        })
+        .await?
+    }
+
+    /// First operation number, which is still available in the WAL
+    pub fn first_index(&self) -> u64 {
+        self.wal.lock().first_closed_index()
+    }
+
+    /// Wait until a new operation is written into the WAL, since the last read
+    ///
+    /// Returns `false` if the WAL is gone, because the shard was dropped or replaced.
+    pub async fn wait(&mut self) -> bool {
+        self.appended.changed().await.is_ok()
+    }
+}
+
 #[derive(Error, Debug, Clone, PartialEq, Eq)]
 #[error("cannot resolve WAL delta: {0}")]
 pub enum WalDeltaError {
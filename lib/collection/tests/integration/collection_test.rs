@@ -548,6 +548,8 @@ async fn test_ordered_scroll_api_with_shards(shard_number: u32) {
                         key: key.parse().unwrap(),
                         direction: Some(Direction::Asc),
                         start_from: None,
+                        geo_distance_from: None,
+                        then_by: Vec::new(),
                     })),
                 },
                 None,
@@ -578,6 +580,8 @@ async fn test_ordered_scroll_api_with_shards(shard_number: u32) {
                         key: key.parse().unwrap(),
                         direction: Some(Direction::Desc),
                         start_from: None,
+                        geo_distance_from: None,
+                        then_by: Vec::new(),
                     })),
                 },
                 None,
@@ -617,6 +621,8 @@ async fn test_ordered_scroll_api_with_shards(shard_number: u32) {
                         key: key.parse().unwrap(),
                         direction: Some(Direction::Asc),
                         start_from: None,
+                        geo_distance_from: None,
+                        then_by: Vec::new(),
                     })),
                 },
                 None,
@@ -655,6 +661,8 @@ async fn test_ordered_scroll_api_with_shards(shard_number: u32) {
                         key: key.parse().unwrap(),
                         direction: Some(Direction::Desc),
                         start_from: None,
+                        geo_distance_from: None,
+                        then_by: Vec::new(),
                     })),
                 },
                 None,
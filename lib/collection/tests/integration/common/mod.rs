@@ -51,6 +51,8 @@ pub async fn simple_collection_fixture(collection_path: &Path, shard_number: u32
         wal_config,
         hnsw_config: Default::default(),
         quantization_config: Default::default(),
+        strict_mode_config: Default::default(),
+        payload_validation_config: Default::default(),
     };
 
     let snapshot_path = collection_path.join("snapshots");
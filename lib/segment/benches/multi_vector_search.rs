@@ -46,8 +46,10 @@ fn multi_vector_search_benchmark(c: &mut Criterion) {
                 storage_type: VectorStorageType::Memory,
                 index: Indexes::Plain {},
                 quantization_config: None,
+                additional_quantization_configs: vec![],
                 multivector_config: Some(MultiVectorConfig::default()), // uses multivec config
                 datatype: None,
+                prefix_of: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -84,6 +86,7 @@ fn multi_vector_search_benchmark(c: &mut Criterion) {
         quantized_vectors: quantized_vectors.clone(),
         payload_index: segment.payload_index.clone(),
         hnsw_config,
+        prefix_dim: None,
         permit: Some(permit),
         stopped: &stopped,
     })
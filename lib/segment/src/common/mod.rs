@@ -132,6 +132,17 @@ pub fn check_named_vectors(
     segment_config: &SegmentConfig,
 ) -> OperationResult<()> {
     for (vector_name, vector_data) in vectors.iter() {
+        if let Some(source_name) = segment_config
+            .vector_data
+            .get(vector_name)
+            .and_then(|config| config.prefix_of.as_ref())
+        {
+            return Err(OperationError::ValidationError {
+                description: format!(
+                    "Vector {vector_name} is a prefix view of {source_name} and can't be written directly"
+                ),
+            });
+        }
         check_vector(vector_name, &vector_data.into(), segment_config)?;
     }
     Ok(())
@@ -177,7 +188,7 @@ fn check_vector_against_config(
     match vector {
         VectorRef::Dense(vector) => {
             // Check dimensionality
-            let dim = vector_config.size;
+            let dim = vector_config.storage_dim();
             if vector.len() != dim {
                 return Err(OperationError::WrongVectorDimension {
                     expected_dim: dim,
@@ -189,7 +200,7 @@ fn check_vector_against_config(
         VectorRef::Sparse(_) => Err(OperationError::WrongSparse),
         VectorRef::MultiDense(multi_vector) => {
             // Check dimensionality
-            let dim = vector_config.size;
+            let dim = vector_config.storage_dim();
             for vector in multi_vector.multi_vectors() {
                 if vector.len() != dim {
                     return Err(OperationError::WrongVectorDimension {
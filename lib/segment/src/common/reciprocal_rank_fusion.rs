@@ -66,6 +66,7 @@ mod tests {
             vector: None,
             shard_key: None,
             order_value: None,
+            then_order_values: Vec::new(),
         }
     }
 
@@ -52,8 +52,10 @@ impl From<SegmentConfigV5> for SegmentConfig {
                     storage_type: (old_data.on_disk == Some(true))
                         .then_some(VectorStorageType::Mmap)
                         .unwrap_or_else(|| old_segment.storage_type.into()),
+                    additional_quantization_configs: vec![],
                     multivector_config: None,
                     datatype: None,
+                    prefix_of: None,
                 };
 
                 (vector_name, new_data)
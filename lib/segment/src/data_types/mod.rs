@@ -4,6 +4,8 @@ pub mod named_vectors;
 pub mod order_by;
 pub mod primitive;
 pub mod query_context;
+pub mod relative_datetime;
+pub mod search_explain;
 pub mod text_index;
 pub mod tiny_map;
 pub mod vectors;
@@ -7,11 +7,14 @@ use super::primitive::PrimitiveVectorElement;
 use super::tiny_map;
 use super::vectors::{
     DenseVector, MultiDenseVectorInternal, TypedMultiDenseVector, TypedMultiDenseVectorRef, Vector,
-    VectorElementType, VectorElementTypeByte, VectorElementTypeHalf, VectorRef,
+    VectorElementType, VectorElementTypeBFloat16, VectorElementTypeByte, VectorElementTypeHalf,
+    VectorElementTypeInt8, VectorRef,
 };
 use crate::common::operation_error::OperationError;
 use crate::spaces::metric::Metric;
-use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+};
 use crate::types::{Distance, VectorDataConfig, VectorStorageDatatype};
 
 type CowKey<'a> = Cow<'a, str>;
@@ -330,33 +333,97 @@ impl<'a> NamedVectors<'a> {
                 Distance::Manhattan => {
                     <ManhattanMetric as Metric<VectorElementType>>::preprocess(dense_vector)
                 }
+                Distance::Hamming => {
+                    <HammingMetric as Metric<VectorElementType>>::preprocess(dense_vector)
+                }
+                Distance::Jaccard => {
+                    <JaccardMetric as Metric<VectorElementType>>::preprocess(dense_vector)
+                }
+            },
+            Some(VectorStorageDatatype::Uint8 | VectorStorageDatatype::Bit) => {
+                match config.distance {
+                    Distance::Cosine => {
+                        <CosineMetric as Metric<VectorElementTypeByte>>::preprocess(dense_vector)
+                    }
+                    Distance::Euclid => {
+                        <EuclidMetric as Metric<VectorElementTypeByte>>::preprocess(dense_vector)
+                    }
+                    Distance::Dot => {
+                        <DotProductMetric as Metric<VectorElementTypeByte>>::preprocess(
+                            dense_vector,
+                        )
+                    }
+                    Distance::Manhattan => {
+                        <ManhattanMetric as Metric<VectorElementTypeByte>>::preprocess(dense_vector)
+                    }
+                    Distance::Hamming => {
+                        <HammingMetric as Metric<VectorElementTypeByte>>::preprocess(dense_vector)
+                    }
+                    Distance::Jaccard => {
+                        <JaccardMetric as Metric<VectorElementTypeByte>>::preprocess(dense_vector)
+                    }
+                }
+            }
+            Some(VectorStorageDatatype::Float16) => match config.distance {
+                Distance::Cosine => {
+                    <CosineMetric as Metric<VectorElementTypeHalf>>::preprocess(dense_vector)
+                }
+                Distance::Euclid => {
+                    <EuclidMetric as Metric<VectorElementTypeHalf>>::preprocess(dense_vector)
+                }
+                Distance::Dot => {
+                    <DotProductMetric as Metric<VectorElementTypeHalf>>::preprocess(dense_vector)
+                }
+                Distance::Manhattan => {
+                    <ManhattanMetric as Metric<VectorElementTypeHalf>>::preprocess(dense_vector)
+                }
+                Distance::Hamming => {
+                    <HammingMetric as Metric<VectorElementTypeHalf>>::preprocess(dense_vector)
+                }
+                Distance::Jaccard => {
+                    <JaccardMetric as Metric<VectorElementTypeHalf>>::preprocess(dense_vector)
+                }
             },
-            Some(VectorStorageDatatype::Uint8) => match config.distance {
+            Some(VectorStorageDatatype::BFloat16) => match config.distance {
                 Distance::Cosine => {
-                    <CosineMetric as Metric<VectorElementTypeByte>>::preprocess(dense_vector)
+                    <CosineMetric as Metric<VectorElementTypeBFloat16>>::preprocess(dense_vector)
                 }
                 Distance::Euclid => {
-                    <EuclidMetric as Metric<VectorElementTypeByte>>::preprocess(dense_vector)
+                    <EuclidMetric as Metric<VectorElementTypeBFloat16>>::preprocess(dense_vector)
                 }
                 Distance::Dot => {
-                    <DotProductMetric as Metric<VectorElementTypeByte>>::preprocess(dense_vector)
+                    <DotProductMetric as Metric<VectorElementTypeBFloat16>>::preprocess(
+                        dense_vector,
+                    )
                 }
                 Distance::Manhattan => {
-                    <ManhattanMetric as Metric<VectorElementTypeByte>>::preprocess(dense_vector)
+                    <ManhattanMetric as Metric<VectorElementTypeBFloat16>>::preprocess(dense_vector)
+                }
+                Distance::Hamming => {
+                    <HammingMetric as Metric<VectorElementTypeBFloat16>>::preprocess(dense_vector)
+                }
+                Distance::Jaccard => {
+                    <JaccardMetric as Metric<VectorElementTypeBFloat16>>::preprocess(dense_vector)
                 }
             },
-            Some(VectorStorageDatatype::Float16) => match config.distance {
+            Some(VectorStorageDatatype::Int8) => match config.distance {
                 Distance::Cosine => {
-                    <CosineMetric as Metric<VectorElementTypeHalf>>::preprocess(dense_vector)
+                    <CosineMetric as Metric<VectorElementTypeInt8>>::preprocess(dense_vector)
                 }
                 Distance::Euclid => {
-                    <EuclidMetric as Metric<VectorElementTypeHalf>>::preprocess(dense_vector)
+                    <EuclidMetric as Metric<VectorElementTypeInt8>>::preprocess(dense_vector)
                 }
                 Distance::Dot => {
-                    <DotProductMetric as Metric<VectorElementTypeHalf>>::preprocess(dense_vector)
+                    <DotProductMetric as Metric<VectorElementTypeInt8>>::preprocess(dense_vector)
                 }
                 Distance::Manhattan => {
-                    <ManhattanMetric as Metric<VectorElementTypeHalf>>::preprocess(dense_vector)
+                    <ManhattanMetric as Metric<VectorElementTypeInt8>>::preprocess(dense_vector)
+                }
+                Distance::Hamming => {
+                    <HammingMetric as Metric<VectorElementTypeInt8>>::preprocess(dense_vector)
+                }
+                Distance::Jaccard => {
+                    <JaccardMetric as Metric<VectorElementTypeInt8>>::preprocess(dense_vector)
                 }
             },
         }
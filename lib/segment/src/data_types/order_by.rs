@@ -1,12 +1,19 @@
+use std::cmp::Ordering;
+use std::iter;
+
+use geo::prelude::HaversineDistance;
+use geo::Point;
 use num_cmp::NumCmp;
 use ordered_float::OrderedFloat;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
 use validator::Validate;
 
 use crate::json_path::JsonPath;
 use crate::types::{
-    DateTimePayloadType, FloatPayloadType, IntPayloadType, Order, Payload, Range, RangeInterface,
+    DateTimePayloadType, FloatPayloadType, GeoPoint, IntPayloadType, Order, Payload, PointIdType,
+    Range, RangeInterface, ScoredPoint,
 };
 
 const INTERNAL_KEY_OF_ORDER_BY_VALUE: &str = "____ordered_with____";
@@ -38,6 +45,16 @@ impl Direction {
     }
 }
 
+impl Direction {
+    /// Compares two values so that [`Ordering::Less`] means that `a` goes first in this direction.
+    pub fn directed_cmp<T: Ord>(&self, a: &T, b: &T) -> Ordering {
+        match self {
+            Direction::Asc => a.cmp(b),
+            Direction::Desc => b.cmp(a),
+        }
+    }
+}
+
 impl From<Direction> for Order {
     fn from(direction: Direction) -> Self {
         match direction {
@@ -57,6 +74,9 @@ pub enum StartFrom {
     Datetime(DateTimePayloadType),
 }
 
+/// Values of a point for every key of an [`OrderBy`], starting with the primary key.
+pub type OrderValues = SmallVec<[OrderValue; 1]>;
+
 #[derive(Deserialize, Serialize, JsonSchema, Validate, Clone, Debug, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub struct OrderBy {
@@ -68,9 +88,106 @@ pub struct OrderBy {
 
     /// Which payload value to start scrolling from. Default is the lowest value for `asc` and the highest for `desc`
     pub start_from: Option<StartFrom>,
+
+    /// Order by the distance in meters from this point to the geo value of `key`, instead of by a numeric value.
+    /// Requires a geo index on `key`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub geo_distance_from: Option<GeoPoint>,
+
+    /// Additional keys to order by, used in sequence when the values of all previous keys are equal
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub then_by: Vec<OrderByKey>,
+}
+
+/// Secondary key of an `order_by`, used to break ties of the previous keys
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct OrderByKey {
+    /// Payload key to order by
+    pub key: JsonPath,
+
+    /// Direction of ordering: `asc` or `desc`. Default is ascending.
+    pub direction: Option<Direction>,
+
+    /// Order by the distance in meters from this point to the geo value of `key`, instead of by a numeric value.
+    /// Requires a geo index on `key`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub geo_distance_from: Option<GeoPoint>,
+}
+
+impl OrderByKey {
+    pub fn direction(&self) -> Direction {
+        self.direction.unwrap_or_default()
+    }
 }
 
 impl OrderBy {
+    /// Whether this ordering uses only a single numeric key, so it can be streamed directly from
+    /// a range index.
+    pub fn is_single_numeric_key(&self) -> bool {
+        self.geo_distance_from.is_none() && self.then_by.is_empty()
+    }
+
+    /// All keys of this ordering, starting with the primary one.
+    ///
+    /// Yields `(key, direction, geo_distance_from)` for each of them.
+    pub fn keys(&self) -> impl Iterator<Item = (&JsonPath, Direction, Option<&GeoPoint>)> {
+        iter::once((&self.key, self.direction(), self.geo_distance_from.as_ref())).chain(
+            self.then_by
+                .iter()
+                .map(|key| (&key.key, key.direction(), key.geo_distance_from.as_ref())),
+        )
+    }
+
+    /// Compares the values of two points key by key, each in its own direction.
+    ///
+    /// [`Ordering::Less`] means that `a` goes first.
+    pub fn cmp_values<'a>(
+        &self,
+        a: impl IntoIterator<Item = &'a OrderValue>,
+        b: impl IntoIterator<Item = &'a OrderValue>,
+    ) -> Ordering {
+        self.keys()
+            .zip(a.into_iter().zip(b))
+            .map(|((_, direction, _), (a, b))| direction.directed_cmp(a, b))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+
+    /// Compares two points by their values, breaking ties by point ID in the primary direction.
+    ///
+    /// [`Ordering::Less`] means that `a` goes first.
+    pub fn cmp_points<'a>(
+        &self,
+        (a_values, a_id): (impl IntoIterator<Item = &'a OrderValue>, &PointIdType),
+        (b_values, b_id): (impl IntoIterator<Item = &'a OrderValue>, &PointIdType),
+    ) -> Ordering {
+        self.cmp_values(a_values, b_values)
+            .then_with(|| self.direction().directed_cmp(a_id, b_id))
+    }
+
+    /// Same as [`OrderBy::cmp_points`], reading values from `order_value` and `then_order_values`.
+    pub fn cmp_scored_points(&self, a: &ScoredPoint, b: &ScoredPoint) -> Ordering {
+        self.cmp_points((a.order_values(), &a.id), (b.order_values(), &b.id))
+    }
+
+    /// Value used for points which don't have a value for a secondary key, so that they go last.
+    pub fn missing_value(direction: Direction) -> OrderValue {
+        match direction {
+            Direction::Asc => OrderValue::MAX,
+            Direction::Desc => OrderValue::MIN,
+        }
+    }
+
+    /// Whether `value` of the primary key is within the range defined by `start_from`.
+    pub fn is_from_start(&self, value: &OrderValue) -> bool {
+        let start_from = self.start_from();
+        match self.direction() {
+            Direction::Asc => value >= &start_from,
+            Direction::Desc => value <= &start_from,
+        }
+    }
+
     /// Returns a range representation of OrderBy.
     pub fn as_range(&self) -> RangeInterface {
         self.start_from
@@ -121,10 +238,7 @@ impl OrderBy {
     fn json_value_to_ordering_value(&self, value: Option<serde_json::Value>) -> OrderValue {
         value
             .and_then(|v| OrderValue::try_from(v).ok())
-            .unwrap_or_else(|| match self.direction() {
-                Direction::Asc => OrderValue::MAX,
-                Direction::Desc => OrderValue::MIN,
-            })
+            .unwrap_or_else(|| Self::missing_value(self.direction()))
     }
 
     pub fn get_order_value_from_payload(&self, payload: Option<&Payload>) -> OrderValue {
@@ -150,6 +264,19 @@ pub enum OrderValue {
 impl OrderValue {
     const MAX: Self = Self::Float(f64::NAN);
     const MIN: Self = Self::Float(f64::MIN);
+
+    /// Distance in meters between `origin` and the closest of `points`, if any.
+    pub fn closest_geo_distance<'a>(
+        origin: &GeoPoint,
+        points: impl IntoIterator<Item = &'a GeoPoint>,
+    ) -> Option<Self> {
+        let origin = Point::new(origin.lon, origin.lat);
+        points
+            .into_iter()
+            .map(|point| OrderedFloat(origin.haversine_distance(&Point::new(point.lon, point.lat))))
+            .min()
+            .map(|distance| OrderValue::Float(distance.0))
+    }
 }
 
 impl From<OrderValue> for serde_json::Value {
@@ -226,9 +353,63 @@ impl Ord for OrderValue {
 
 #[cfg(test)]
 mod tests {
+    use std::cmp::Ordering;
+
     use proptest::proptest;
 
-    use crate::data_types::order_by::OrderValue;
+    use crate::data_types::order_by::{Direction, OrderBy, OrderByKey, OrderValue};
+    use crate::json_path::path;
+    use crate::types::GeoPoint;
+
+    #[test]
+    fn test_cmp_values_with_mixed_directions() {
+        let order_by = OrderBy {
+            key: path("distance"),
+            direction: Some(Direction::Asc),
+            start_from: None,
+            geo_distance_from: None,
+            then_by: vec![OrderByKey {
+                key: path("rating"),
+                direction: Some(Direction::Desc),
+                geo_distance_from: None,
+            }],
+        };
+
+        let close_good = [OrderValue::Float(10.0), OrderValue::Int(5)];
+        let close_bad = [OrderValue::Float(10.0), OrderValue::Int(1)];
+        let far_good = [OrderValue::Float(20.0), OrderValue::Int(5)];
+
+        assert_eq!(order_by.cmp_values(&close_good, &close_bad), Ordering::Less);
+        assert_eq!(order_by.cmp_values(&close_bad, &far_good), Ordering::Less);
+        assert_eq!(
+            order_by.cmp_values(&close_good, &close_good),
+            Ordering::Equal
+        );
+
+        // Ties are broken by id, in the direction of the primary key
+        assert_eq!(
+            order_by.cmp_points((&close_good, &1.into()), (&close_good, &2.into())),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn test_closest_geo_distance() {
+        let origin = GeoPoint { lon: 0.0, lat: 0.0 };
+        let points = [
+            GeoPoint { lon: 1.0, lat: 1.0 },
+            GeoPoint { lon: 0.5, lat: 0.5 },
+        ];
+
+        // haversine distance between (0, 0) and (0.5, 0.5) is 78626.29627999048
+        let Some(OrderValue::Float(distance)) = OrderValue::closest_geo_distance(&origin, &points)
+        else {
+            panic!("expected a float distance");
+        };
+        assert!((distance - 78626.29627999048).abs() < 1.0);
+
+        assert!(OrderValue::closest_geo_distance(&origin, &[]).is_none());
+    }
 
     proptest! {
 
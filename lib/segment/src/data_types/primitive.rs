@@ -1,12 +1,15 @@
 use std::borrow::Cow;
 
-use half::f16;
+use half::{bf16, f16};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
 use super::named_vectors::CowMultiVector;
 use super::vectors::TypedMultiDenseVector;
-use crate::data_types::vectors::{VectorElementType, VectorElementTypeByte, VectorElementTypeHalf};
+use crate::data_types::vectors::{
+    VectorElementType, VectorElementTypeBFloat16, VectorElementTypeByte, VectorElementTypeHalf,
+    VectorElementTypeInt8,
+};
 use crate::spaces::metric::Metric;
 use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
 use crate::types::{Distance, QuantizationConfig, VectorStorageDatatype};
@@ -151,6 +154,8 @@ impl PrimitiveVectorElement for VectorElementTypeByte {
                 Distance::Manhattan => {
                     <ManhattanMetric as Metric<VectorElementType>>::preprocess(vector)
                 }
+                // Bit metrics are not quantized, keep the vector as is
+                Distance::Hamming | Distance::Jaccard => vector,
             };
             Cow::from(preprocessed_vector)
         }
@@ -188,3 +193,115 @@ impl PrimitiveVectorElement for VectorElementTypeByte {
         ))
     }
 }
+
+impl PrimitiveVectorElement for VectorElementTypeBFloat16 {
+    fn slice_from_float_cow(vector: Cow<[VectorElementType]>) -> Cow<[Self]> {
+        Cow::Owned(vector.iter().map(|&x| bf16::from_f32(x)).collect())
+    }
+
+    fn slice_to_float_cow(vector: Cow<[Self]>) -> Cow<[VectorElementType]> {
+        Cow::Owned(vector.iter().map(|&x| bf16::to_f32(x)).collect_vec())
+    }
+
+    fn quantization_preprocess<'a>(
+        _quantization_config: &QuantizationConfig,
+        _distance: Distance,
+        vector: &'a [Self],
+    ) -> Cow<'a, [f32]> {
+        Cow::Owned(vector.iter().map(|&x| bf16::to_f32(x)).collect_vec())
+    }
+
+    fn from_float_multivector(
+        multivector: CowMultiVector<VectorElementType>,
+    ) -> CowMultiVector<Self> {
+        CowMultiVector::Owned(TypedMultiDenseVector::new(
+            multivector
+                .as_vec_ref()
+                .flattened_vectors
+                .iter()
+                .map(|&x| bf16::from_f32(x))
+                .collect_vec(),
+            multivector.as_vec_ref().dim,
+        ))
+    }
+
+    fn into_float_multivector(
+        multivector: CowMultiVector<Self>,
+    ) -> CowMultiVector<VectorElementType> {
+        CowMultiVector::Owned(TypedMultiDenseVector::new(
+            multivector
+                .as_vec_ref()
+                .flattened_vectors
+                .iter()
+                .map(|&x| bf16::to_f32(x))
+                .collect_vec(),
+            multivector.as_vec_ref().dim,
+        ))
+    }
+
+    fn datatype() -> VectorStorageDatatype {
+        VectorStorageDatatype::BFloat16
+    }
+}
+
+impl PrimitiveVectorElement for VectorElementTypeInt8 {
+    fn slice_from_float_cow(vector: Cow<[VectorElementType]>) -> Cow<[Self]> {
+        Cow::Owned(vector.iter().map(|&x| x as i8).collect())
+    }
+
+    fn slice_to_float_cow(vector: Cow<[Self]>) -> Cow<[VectorElementType]> {
+        Cow::Owned(vector.iter().map(|&x| x as VectorElementType).collect_vec())
+    }
+
+    fn quantization_preprocess<'a>(
+        _quantization_config: &QuantizationConfig,
+        distance: Distance,
+        vector: &'a [Self],
+    ) -> Cow<'a, [f32]> {
+        // Signed values are already centered around zero, no shift is needed for binary quantization
+        let vector = vector.iter().map(|&x| x as VectorElementType).collect_vec();
+        let preprocessed_vector = match distance {
+            Distance::Cosine => <CosineMetric as Metric<VectorElementType>>::preprocess(vector),
+            Distance::Euclid => <EuclidMetric as Metric<VectorElementType>>::preprocess(vector),
+            Distance::Dot => <DotProductMetric as Metric<VectorElementType>>::preprocess(vector),
+            Distance::Manhattan => {
+                <ManhattanMetric as Metric<VectorElementType>>::preprocess(vector)
+            }
+            // Bit metrics are not quantized, keep the vector as is
+            Distance::Hamming | Distance::Jaccard => vector,
+        };
+        Cow::from(preprocessed_vector)
+    }
+
+    fn datatype() -> VectorStorageDatatype {
+        VectorStorageDatatype::Int8
+    }
+
+    fn from_float_multivector(
+        multivector: CowMultiVector<VectorElementType>,
+    ) -> CowMultiVector<Self> {
+        CowMultiVector::Owned(TypedMultiDenseVector::new(
+            multivector
+                .as_vec_ref()
+                .flattened_vectors
+                .iter()
+                .map(|&x| x as Self)
+                .collect_vec(),
+            multivector.as_vec_ref().dim,
+        ))
+    }
+
+    fn into_float_multivector(
+        multivector: CowMultiVector<Self>,
+    ) -> CowMultiVector<VectorElementType> {
+        CowMultiVector::Owned(TypedMultiDenseVector::new(
+            multivector
+                .as_vec_ref()
+                .flattened_vectors
+                .iter()
+                .map(|&x| x as VectorElementType)
+                .collect_vec(),
+            multivector.as_vec_ref().dim,
+        ))
+    }
+}
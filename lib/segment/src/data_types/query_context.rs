@@ -4,8 +4,10 @@ use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 
 use bitvec::prelude::BitSlice;
+use parking_lot::Mutex;
 use sparse::common::types::{DimId, DimWeight};
 
+use crate::data_types::search_explain::{SearchExplainCollector, VectorSearchExplanation};
 use crate::data_types::tiny_map;
 
 #[derive(Debug)]
@@ -26,6 +28,10 @@ pub struct QueryContext {
     /// Required for processing sparse vector search with `idf-dot` similarity.
     #[allow(dead_code)]
     idf: tiny_map::TinyMap<String, HashMap<DimId, usize>>,
+
+    /// Collects explanations of how each segment executed the search.
+    /// Only present if the search is explained.
+    explain_collector: Option<SearchExplainCollector>,
 }
 
 impl QueryContext {
@@ -35,6 +41,7 @@ impl QueryContext {
             search_optimized_threshold_kb,
             is_stopped: Arc::new(AtomicBool::new(false)),
             idf: tiny_map::TinyMap::new(),
+            explain_collector: None,
         }
     }
 
@@ -47,6 +54,16 @@ impl QueryContext {
         self
     }
 
+    /// Collect explanations of how each segment executes the search
+    pub fn with_explain_collector(mut self, collector: SearchExplainCollector) -> Self {
+        self.explain_collector = Some(collector);
+        self
+    }
+
+    pub fn explain_collector(&self) -> Option<&SearchExplainCollector> {
+        self.explain_collector.as_ref()
+    }
+
     pub fn available_point_count(&self) -> usize {
         self.available_point_count
     }
@@ -83,6 +100,7 @@ impl QueryContext {
         SegmentQueryContext {
             query_context: Some(self),
             deleted_points: None,
+            explanation: None,
         }
     }
 }
@@ -98,6 +116,7 @@ impl Default for QueryContext {
 pub struct SegmentQueryContext<'a> {
     query_context: Option<&'a QueryContext>,
     deleted_points: Option<&'a BitSlice>,
+    explanation: Option<&'a Mutex<VectorSearchExplanation>>,
 }
 
 impl<'a> SegmentQueryContext<'a> {
@@ -109,10 +128,12 @@ impl<'a> SegmentQueryContext<'a> {
                 is_stopped: Some(&query_context.is_stopped),
                 idf: query_context.idf.get(vector_name),
                 deleted_points: self.deleted_points,
+                explanation: self.explanation,
             }
         } else {
             VectorQueryContext {
                 deleted_points: self.deleted_points,
+                explanation: self.explanation,
                 ..Default::default()
             }
         }
@@ -122,6 +143,12 @@ impl<'a> SegmentQueryContext<'a> {
         self.deleted_points = Some(deleted_points);
         self
     }
+
+    /// Let the vector index explain how it executes the search
+    pub fn with_explanation(mut self, explanation: &'a Mutex<VectorSearchExplanation>) -> Self {
+        self.explanation = Some(explanation);
+        self
+    }
 }
 
 /// Query context related to a specific vector
@@ -139,6 +166,8 @@ pub struct VectorQueryContext<'a> {
     idf: Option<&'a HashMap<DimId, usize>>,
 
     deleted_points: Option<&'a BitSlice>,
+
+    explanation: Option<&'a Mutex<VectorSearchExplanation>>,
 }
 
 pub enum SimpleCow<'a, T> {
@@ -170,6 +199,13 @@ impl VectorQueryContext<'_> {
         self.deleted_points
     }
 
+    /// Record how the search is executed, if it is explained
+    pub fn explain(&self, f: impl FnOnce(&mut VectorSearchExplanation)) {
+        if let Some(explanation) = self.explanation {
+            f(&mut explanation.lock());
+        }
+    }
+
     pub fn is_stopped(&self) -> SimpleCow<'_, AtomicBool> {
         self.is_stopped
             .map(SimpleCow::Borrowed)
@@ -211,6 +247,7 @@ impl Default for VectorQueryContext<'_> {
             is_stopped: None,
             idf: None,
             deleted_points: None,
+            explanation: None,
         }
     }
 }
@@ -0,0 +1,479 @@
+//! Datetime range bounds relative to the time of the request, like `now-7d` or `now/d`.
+//!
+//! The syntax follows the date math of Elasticsearch: an expression starts with `now`, followed
+//! by any number of shifts (`+1h`, `-7d`) and roundings (`/d`, `/M`), applied from left to right.
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{
+    DateTime, Datelike, Duration, LocalResult, Months, NaiveDateTime, SecondsFormat, TimeZone as _,
+    Timelike, Utc,
+};
+use chrono_tz::Tz;
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
+use schemars::JsonSchema;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::types::{DateTimePayloadType, DateTimeWrapper, Range};
+
+const NOW: &str = "now";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateMathUnit {
+    Year,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+impl DateMathUnit {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'y' => Some(Self::Year),
+            'M' => Some(Self::Month),
+            'w' => Some(Self::Week),
+            'd' => Some(Self::Day),
+            'h' | 'H' => Some(Self::Hour),
+            'm' => Some(Self::Minute),
+            's' => Some(Self::Second),
+            _ => None,
+        }
+    }
+
+    fn as_char(self) -> char {
+        match self {
+            Self::Year => 'y',
+            Self::Month => 'M',
+            Self::Week => 'w',
+            Self::Day => 'd',
+            Self::Hour => 'h',
+            Self::Minute => 'm',
+            Self::Second => 's',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateMathOperation {
+    /// Add the given amount of units, subtract if negative
+    Shift(i64, DateMathUnit),
+    /// Round down to the start of the unit, or up to its last microsecond for `gt` and `lte` bounds
+    Round(DateMathUnit),
+}
+
+/// Bound of a datetime range: either a fixed datetime, or relative to the time of the request
+#[derive(Debug, Clone, PartialEq)]
+pub enum DatetimeExpression {
+    Absolute(DateTimePayloadType),
+    /// `now`, followed by date math operations
+    Now(Vec<DateMathOperation>),
+}
+
+impl DatetimeExpression {
+    /// Evaluate the expression at `now`, applying calendar units in the given timezone.
+    ///
+    /// Results which don't fit into the supported datetime range are saturated.
+    pub fn resolve(&self, now: DateTime<Utc>, timezone: Tz, round_up: bool) -> DateTimePayloadType {
+        let operations = match self {
+            Self::Absolute(datetime) => return *datetime,
+            Self::Now(operations) => operations,
+        };
+
+        let mut datetime = now.with_timezone(&timezone);
+        for operation in operations {
+            datetime = match *operation {
+                DateMathOperation::Shift(amount, unit) => shift(datetime, amount, unit)
+                    .unwrap_or_else(|| {
+                        let saturated = if amount < 0 {
+                            DateTime::<Utc>::MIN_UTC
+                        } else {
+                            DateTime::<Utc>::MAX_UTC
+                        };
+                        saturated.with_timezone(&timezone)
+                    }),
+                DateMathOperation::Round(unit) => {
+                    round(datetime, unit, round_up).unwrap_or(datetime)
+                }
+            };
+        }
+
+        DateTimeWrapper(datetime.with_timezone(&Utc))
+    }
+}
+
+/// Calendar units keep the local time of day, even across daylight saving time changes.
+/// Time units are exact durations.
+fn shift(datetime: DateTime<Tz>, amount: i64, unit: DateMathUnit) -> Option<DateTime<Tz>> {
+    let local = datetime.naive_local();
+    let shifted_local = match unit {
+        DateMathUnit::Hour => return datetime.checked_add_signed(Duration::try_hours(amount)?),
+        DateMathUnit::Minute => return datetime.checked_add_signed(Duration::try_minutes(amount)?),
+        DateMathUnit::Second => return datetime.checked_add_signed(Duration::try_seconds(amount)?),
+        DateMathUnit::Day => local.checked_add_signed(Duration::try_days(amount)?)?,
+        DateMathUnit::Week => local.checked_add_signed(Duration::try_weeks(amount)?)?,
+        DateMathUnit::Month | DateMathUnit::Year => {
+            let months = if unit == DateMathUnit::Year {
+                amount.checked_mul(12)?
+            } else {
+                amount
+            };
+            let months = Months::new(u32::try_from(months.unsigned_abs()).ok()?);
+            if amount < 0 {
+                local.checked_sub_months(months)?
+            } else {
+                local.checked_add_months(months)?
+            }
+        }
+    };
+    from_local(datetime.timezone(), shifted_local)
+}
+
+fn round(datetime: DateTime<Tz>, unit: DateMathUnit, round_up: bool) -> Option<DateTime<Tz>> {
+    let local = datetime.naive_local();
+    let date = local.date();
+    let start = match unit {
+        DateMathUnit::Year => date.with_ordinal(1)?.and_hms_opt(0, 0, 0)?,
+        DateMathUnit::Month => date.with_day(1)?.and_hms_opt(0, 0, 0)?,
+        DateMathUnit::Week => {
+            let days_since_monday = i64::from(date.weekday().num_days_from_monday());
+            date.checked_sub_signed(Duration::try_days(days_since_monday)?)?
+                .and_hms_opt(0, 0, 0)?
+        }
+        DateMathUnit::Day => date.and_hms_opt(0, 0, 0)?,
+        DateMathUnit::Hour => date.and_hms_opt(local.hour(), 0, 0)?,
+        DateMathUnit::Minute => date.and_hms_opt(local.hour(), local.minute(), 0)?,
+        DateMathUnit::Second => date.and_hms_opt(local.hour(), local.minute(), local.second())?,
+    };
+    let start = from_local(datetime.timezone(), start)?;
+    if !round_up {
+        return Some(start);
+    }
+
+    // Datetimes are stored with microsecond precision
+    shift(start, 1, unit)?.checked_sub_signed(Duration::microseconds(1))
+}
+
+/// Local times skipped by a daylight saving time change are moved forward by an hour,
+/// ambiguous ones resolve to the earliest instant.
+fn from_local(timezone: Tz, local: NaiveDateTime) -> Option<DateTime<Tz>> {
+    match timezone.from_local_datetime(&local) {
+        LocalResult::Single(datetime) => Some(datetime),
+        LocalResult::Ambiguous(earliest, _) => Some(earliest),
+        LocalResult::None => timezone
+            .from_local_datetime(&local.checked_add_signed(Duration::try_hours(1)?)?)
+            .earliest(),
+    }
+}
+
+fn parse_unit(rest: &mut &str, expression: &str) -> Result<DateMathUnit, String> {
+    let unit = rest
+        .chars()
+        .next()
+        .and_then(DateMathUnit::from_char)
+        .ok_or_else(|| {
+            format!("expected one of the units y, M, w, d, h, m or s in '{expression}'")
+        })?;
+    // All units are single ASCII characters
+    *rest = &rest[1..];
+    Ok(unit)
+}
+
+impl FromStr for DatetimeExpression {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some(mut rest) = s.strip_prefix(NOW) else {
+            return DateTimePayloadType::from_str(s)
+                .map(Self::Absolute)
+                .map_err(|_| format!("'{s}' is neither a datetime nor a `now` expression"));
+        };
+
+        let mut operations = Vec::new();
+        while let Some(op) = rest.chars().next() {
+            rest = &rest[op.len_utf8()..];
+            let operation = match op {
+                '+' | '-' => {
+                    let digits = rest
+                        .find(|c: char| !c.is_ascii_digit())
+                        .unwrap_or(rest.len());
+                    let amount: i64 = rest[..digits]
+                        .parse()
+                        .map_err(|_| format!("expected an amount after '{op}' in '{s}'"))?;
+                    rest = &rest[digits..];
+                    let unit = parse_unit(&mut rest, s)?;
+                    let amount = if op == '-' { -amount } else { amount };
+                    DateMathOperation::Shift(amount, unit)
+                }
+                '/' => DateMathOperation::Round(parse_unit(&mut rest, s)?),
+                _ => return Err(format!("unexpected '{op}' in '{s}', expected +, - or /")),
+            };
+            operations.push(operation);
+        }
+
+        Ok(Self::Now(operations))
+    }
+}
+
+impl fmt::Display for DatetimeExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let operations = match self {
+            Self::Absolute(datetime) => {
+                return f.write_str(&datetime.0.to_rfc3339_opts(SecondsFormat::AutoSi, true));
+            }
+            Self::Now(operations) => operations,
+        };
+
+        f.write_str(NOW)?;
+        for operation in operations {
+            match *operation {
+                DateMathOperation::Shift(amount, unit) => {
+                    let sign = if amount < 0 { '-' } else { '+' };
+                    write!(f, "{sign}{}{}", amount.unsigned_abs(), unit.as_char())?;
+                }
+                DateMathOperation::Round(unit) => write!(f, "/{}", unit.as_char())?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for DatetimeExpression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DatetimeExpression {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        string.parse().map_err(de::Error::custom)
+    }
+}
+
+impl JsonSchema for DatetimeExpression {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        "DatetimeExpression".to_string()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        String::json_schema(gen)
+    }
+}
+
+/// Timezone from the IANA database, e.g. `Europe/Berlin`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NamedTimezone(pub Tz);
+
+impl Serialize for NamedTimezone {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for NamedTimezone {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        string.parse().map_err(de::Error::custom)
+    }
+}
+
+impl FromStr for NamedTimezone {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Tz::from_str(s)
+            .map(Self)
+            .map_err(|_| format!("unknown timezone '{s}', expected a name like 'Europe/Berlin'"))
+    }
+}
+
+impl JsonSchema for NamedTimezone {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        "NamedTimezone".to_string()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        String::json_schema(gen)
+    }
+}
+
+/// Datetime range filter request, with bounds relative to the time of the request
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct RelativeDatetimeRange {
+    /// point.key < range.lt
+    pub lt: Option<DatetimeExpression>,
+    /// point.key > range.gt
+    pub gt: Option<DatetimeExpression>,
+    /// point.key >= range.gte
+    pub gte: Option<DatetimeExpression>,
+    /// point.key <= range.lte
+    pub lte: Option<DatetimeExpression>,
+    /// Timezone to round and shift by days, weeks, months and years in. Default: UTC
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<NamedTimezone>,
+}
+
+impl RelativeDatetimeRange {
+    /// Whether any bound depends on the time of the request
+    pub fn is_relative(&self) -> bool {
+        [&self.lt, &self.gt, &self.gte, &self.lte]
+            .into_iter()
+            .flatten()
+            .any(|bound| matches!(bound, DatetimeExpression::Now(_)))
+    }
+
+    /// Evaluate the bounds at `now`.
+    ///
+    /// Upper inclusive and lower exclusive bounds are rounded up, so `lte: now/d` includes the
+    /// whole current day and `gt: now/d` excludes it.
+    pub fn resolve(&self, now: DateTime<Utc>) -> Range<DateTimePayloadType> {
+        let timezone = self.timezone.map_or(Tz::UTC, |tz| tz.0);
+        let resolve = |bound: &Option<DatetimeExpression>, round_up: bool| {
+            bound
+                .as_ref()
+                .map(|expression| expression.resolve(now, timezone, round_up))
+        };
+        Range {
+            lt: resolve(&self.lt, false),
+            gt: resolve(&self.gt, true),
+            gte: resolve(&self.gte, false),
+            lte: resolve(&self.lte, true),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn resolve(expression: &str, now: &str, timezone: Tz, round_up: bool) -> DateTime<Utc> {
+        let expression: DatetimeExpression = expression.parse().unwrap();
+        expression.resolve(utc(now), timezone, round_up).0
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        for expression in ["now", "now-7d", "now+1h/h", "now-1M/M+2w", "now/y"] {
+            let parsed: DatetimeExpression = expression.parse().unwrap();
+            assert_eq!(parsed.to_string(), expression);
+        }
+
+        let parsed: DatetimeExpression = "now-10H".parse().unwrap();
+        assert_eq!(
+            parsed,
+            DatetimeExpression::Now(vec![DateMathOperation::Shift(-10, DateMathUnit::Hour)]),
+        );
+
+        let absolute: DatetimeExpression = "2024-03-01T10:00:00Z".parse().unwrap();
+        assert!(matches!(absolute, DatetimeExpression::Absolute(_)));
+
+        for invalid in ["now-d", "now-1", "now/x", "now*2d", "yesterday"] {
+            assert!(invalid.parse::<DatetimeExpression>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_resolve_utc() {
+        let now = "2024-03-13T15:42:10.5Z";
+        let tz = Tz::UTC;
+
+        assert_eq!(resolve("now", now, tz, false), utc(now));
+        assert_eq!(
+            resolve("now-7d", now, tz, false),
+            utc("2024-03-06T15:42:10.5Z")
+        );
+        assert_eq!(
+            resolve("now/d", now, tz, false),
+            utc("2024-03-13T00:00:00Z")
+        );
+        assert_eq!(
+            resolve("now/d", now, tz, true),
+            utc("2024-03-13T23:59:59.999999Z")
+        );
+        assert_eq!(
+            resolve("now/w", now, tz, false),
+            utc("2024-03-11T00:00:00Z")
+        );
+        assert_eq!(
+            resolve("now-1M/M", now, tz, false),
+            utc("2024-02-01T00:00:00Z")
+        );
+        assert_eq!(
+            resolve("now-1M/M", now, tz, true),
+            utc("2024-02-29T23:59:59.999999Z")
+        );
+        assert_eq!(
+            resolve("now+1y/y", now, tz, false),
+            utc("2025-01-01T00:00:00Z")
+        );
+    }
+
+    #[test]
+    fn test_resolve_in_timezone() {
+        let berlin = Tz::Europe__Berlin;
+
+        // Local midnight in Berlin, in winter time
+        assert_eq!(
+            resolve("now/d", "2024-03-13T15:00:00Z", berlin, false),
+            utc("2024-03-12T23:00:00Z")
+        );
+
+        // Daylight saving time starts on 2024-03-31, a day keeps the local time...
+        assert_eq!(
+            resolve("now+1d", "2024-03-30T12:00:00Z", berlin, false),
+            utc("2024-03-31T11:00:00Z")
+        );
+        // ...while 24 hours don't
+        assert_eq!(
+            resolve("now+24h", "2024-03-30T12:00:00Z", berlin, false),
+            utc("2024-03-31T12:00:00Z")
+        );
+        // The day of the change is only 23 hours long
+        assert_eq!(
+            resolve("now/d", "2024-03-31T12:00:00Z", berlin, true),
+            utc("2024-03-31T21:59:59.999999Z")
+        );
+    }
+
+    #[test]
+    fn test_resolve_range() {
+        let range: RelativeDatetimeRange = serde_json::from_value(serde_json::json!({
+            "gte": "now-1d/d",
+            "lt": "now/d",
+            "lte": "2024-01-01T00:00:00Z",
+            "timezone": "America/New_York",
+        }))
+        .unwrap();
+        assert!(range.is_relative());
+
+        let resolved = range.resolve(utc("2024-03-13T03:00:00Z"));
+        assert_eq!(resolved.gte.unwrap().0, utc("2024-03-11T04:00:00Z"));
+        assert_eq!(resolved.lt.unwrap().0, utc("2024-03-12T04:00:00Z"));
+        assert_eq!(resolved.lte.unwrap().0, utc("2024-01-01T00:00:00Z"));
+        assert_eq!(resolved.gt, None);
+
+        let invalid = serde_json::from_value::<RelativeDatetimeRange>(serde_json::json!({
+            "gte": "now-1d",
+            "timezone": "Mars/Olympus_Mons",
+        }));
+        assert!(invalid.is_err());
+    }
+}
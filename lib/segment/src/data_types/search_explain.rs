@@ -0,0 +1,235 @@
+use std::ops::AddAssign;
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::index::field_index::{CardinalityEstimation, PrimaryCondition};
+
+/// Way a segment served a vector search
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Deserialize,
+    Serialize,
+    JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchStrategy {
+    /// All vectors of the segment are scored
+    #[default]
+    FullScan,
+    /// Points matching the filter are selected with the payload index, then scored one by one
+    PayloadIndex,
+    /// HNSW graph search without filter
+    Hnsw,
+    /// HNSW graph search, checking the filter while traversing the graph
+    HnswFiltered,
+    /// Inverted index search of sparse vectors
+    Sparse,
+    /// Segment is not searched, as it is too large to be searched without an index and
+    /// `indexed_only` is requested
+    SkippedIndexedOnly,
+}
+
+/// Query planner estimation of the number of points matching a filter
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub struct CardinalityExplanation {
+    /// Conditions which can select the matching points through payload indexes
+    pub primary_clauses: Vec<String>,
+    /// Minimal possible number of matching points
+    pub min: usize,
+    /// Expected number of matching points
+    pub exp: usize,
+    /// Largest possible number of matching points
+    pub max: usize,
+}
+
+impl From<&CardinalityEstimation> for CardinalityExplanation {
+    fn from(estimation: &CardinalityEstimation) -> Self {
+        let primary_clauses = estimation
+            .primary_clauses
+            .iter()
+            .map(|clause| match clause {
+                PrimaryCondition::Condition(condition) => serde_json::to_string(condition),
+                PrimaryCondition::IsEmpty(condition) => serde_json::to_string(condition),
+                PrimaryCondition::IsNull(condition) => serde_json::to_string(condition),
+                PrimaryCondition::Ids(ids) => Ok(format!("{{\"has_id\": {} points}}", ids.len())),
+            })
+            .filter_map(Result::ok)
+            .collect();
+
+        Self {
+            primary_clauses,
+            min: estimation.min,
+            exp: estimation.exp,
+            max: estimation.max,
+        }
+    }
+}
+
+/// Time spent in each stage of a search, in seconds
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize, Serialize, JsonSchema)]
+pub struct SearchTimings {
+    /// Estimating the cardinality of the filter and choosing the strategy
+    pub planning: f64,
+    /// Selecting the points matching the filter with the payload index
+    pub prefiltering: f64,
+    /// Scoring vectors, through the index or one by one
+    pub search: f64,
+    /// Scoring the best quantized results again with the original vectors
+    pub rescoring: f64,
+}
+
+impl SearchTimings {
+    pub fn add_planning(&mut self, duration: Duration) {
+        self.planning += duration.as_secs_f64();
+    }
+
+    pub fn add_prefiltering(&mut self, duration: Duration) {
+        self.prefiltering += duration.as_secs_f64();
+    }
+
+    pub fn add_search(&mut self, duration: Duration) {
+        self.search += duration.as_secs_f64();
+    }
+
+    pub fn add_rescoring(&mut self, duration: Duration) {
+        self.rescoring += duration.as_secs_f64();
+    }
+}
+
+impl AddAssign<&SearchTimings> for SearchTimings {
+    fn add_assign(&mut self, other: &SearchTimings) {
+        self.planning += other.planning;
+        self.prefiltering += other.prefiltering;
+        self.search += other.search;
+        self.rescoring += other.rescoring;
+    }
+}
+
+/// How a vector index executed a search
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
+pub struct VectorSearchExplanation {
+    /// Chosen search strategy
+    pub strategy: SearchStrategy,
+    /// Estimated number of points matching the filter, if the strategy was chosen based on it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cardinality: Option<CardinalityExplanation>,
+    /// Whether quantized vectors were used to score points
+    pub quantization: bool,
+    /// Whether quantized results were re-scored with the original vectors
+    pub rescoring: bool,
+    /// Time spent in each stage of the search
+    pub timings: SearchTimings,
+}
+
+/// How a segment executed a batch of vector searches with the same parameters
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
+pub struct SegmentSearchExplanation {
+    /// Id of the segment
+    pub segment: String,
+    /// Name of the searched vector
+    pub vector_name: String,
+    /// Number of search requests in the batch
+    pub batch_size: usize,
+    #[serde(flatten)]
+    pub search: VectorSearchExplanation,
+}
+
+/// How a shard executed a search, segment by segment
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
+pub struct ShardSearchExplanation {
+    /// Id of the shard
+    pub shard_id: u32,
+    /// Explanations of all searched segments of the shard
+    pub segments: Vec<SegmentSearchExplanation>,
+    /// Total time spent in each stage of the search, over all segments
+    pub timings: SearchTimings,
+}
+
+impl ShardSearchExplanation {
+    pub fn new(shard_id: u32, segments: Vec<SegmentSearchExplanation>) -> Self {
+        let mut timings = SearchTimings::default();
+        for segment in &segments {
+            timings += &segment.search.timings;
+        }
+
+        Self {
+            shard_id,
+            segments,
+            timings,
+        }
+    }
+}
+
+/// How a search request was executed by each shard of the collection
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize, JsonSchema)]
+pub struct SearchExplanation {
+    pub shards: Vec<ShardSearchExplanation>,
+}
+
+/// Shared collector of the explanations of all segments taking part in a search
+#[derive(Debug, Clone, Default)]
+pub struct SearchExplainCollector {
+    explanations: Arc<Mutex<Vec<SegmentSearchExplanation>>>,
+}
+
+impl SearchExplainCollector {
+    pub fn add(&self, explanation: SegmentSearchExplanation) {
+        self.explanations.lock().push(explanation);
+    }
+
+    /// Take all explanations collected so far
+    pub fn take(&self) -> Vec<SegmentSearchExplanation> {
+        std::mem::take(&mut *self.explanations.lock())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment_explanation(strategy: SearchStrategy, search: f64) -> SegmentSearchExplanation {
+        SegmentSearchExplanation {
+            segment: "segment".to_string(),
+            vector_name: String::new(),
+            batch_size: 1,
+            search: VectorSearchExplanation {
+                strategy,
+                timings: SearchTimings {
+                    planning: 0.5,
+                    search,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_shard_explanation_sums_timings() {
+        let collector = SearchExplainCollector::default();
+        collector.add(segment_explanation(SearchStrategy::Hnsw, 1.0));
+        collector
+            .clone()
+            .add(segment_explanation(SearchStrategy::FullScan, 2.0));
+
+        let shard = ShardSearchExplanation::new(1, collector.take());
+
+        assert_eq!(shard.segments.len(), 2);
+        assert_eq!(shard.timings.planning, 1.0);
+        assert_eq!(shard.timings.search, 3.0);
+        assert_eq!(shard.timings.rescoring, 0.0);
+        assert!(collector.take().is_empty());
+    }
+}
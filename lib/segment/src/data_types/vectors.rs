@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 use std::slice::ChunksExactMut;
 
-use half::f16;
+use half::{bf16, f16};
 use itertools::Itertools;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -192,6 +192,10 @@ pub type VectorElementTypeHalf = f16;
 
 pub type VectorElementTypeByte = u8;
 
+pub type VectorElementTypeBFloat16 = bf16;
+
+pub type VectorElementTypeInt8 = i8;
+
 pub const DEFAULT_VECTOR_NAME: &str = "";
 
 pub type TypedDenseVector<T> = Vec<T>;
@@ -1,13 +1,15 @@
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
+use chrono::{DateTime, Utc};
 use common::types::TelemetryDetail;
 
 use crate::common::operation_error::{OperationResult, SegmentFailedState};
 use crate::data_types::named_vectors::NamedVectors;
-use crate::data_types::order_by::{OrderBy, OrderValue};
+use crate::data_types::order_by::{OrderBy, OrderValues};
 use crate::data_types::query_context::{QueryContext, SegmentQueryContext};
 use crate::data_types::vectors::{QueryVector, Vector};
+use crate::id_tracker::TrashedPoint;
 use crate::index::field_index::CardinalityEstimation;
 use crate::json_path::JsonPath;
 use crate::telemetry::SegmentTelemetry;
@@ -96,6 +98,38 @@ pub trait SegmentEntry {
         point_id: PointIdType,
     ) -> OperationResult<bool>;
 
+    /// Move point to trash
+    ///
+    /// Point is hidden as if it was deleted, but its vectors and payload are kept,
+    /// until it is restored or purged by deleting it.
+    fn trash_point(
+        &mut self,
+        op_num: SeqNumberType,
+        point_id: PointIdType,
+        trashed_at: DateTime<Utc>,
+    ) -> OperationResult<bool>;
+
+    /// Move point out of trash in place
+    ///
+    /// Available for appendable segments only.
+    fn restore_point(
+        &mut self,
+        op_num: SeqNumberType,
+        point_id: PointIdType,
+    ) -> OperationResult<bool>;
+
+    /// Get point in trash of this segment
+    fn trashed_point(&self, point_id: PointIdType) -> Option<TrashedPoint>;
+
+    /// Points in trash of this segment, which satisfy filtering condition
+    fn trashed_points(&self, filter: Option<&Filter>) -> Vec<PointIdType>;
+
+    /// Retrieve all vectors of the point in trash
+    fn trashed_vectors(&self, point_id: PointIdType) -> OperationResult<NamedVectors>;
+
+    /// Retrieve payload of the point in trash
+    fn trashed_payload(&self, point_id: PointIdType) -> OperationResult<Payload>;
+
     fn vector(&self, vector_name: &str, point_id: PointIdType) -> OperationResult<Option<Vector>>;
 
     fn all_vectors(&self, point_id: PointIdType) -> OperationResult<NamedVectors>;
@@ -118,13 +152,15 @@ pub trait SegmentEntry {
     /// Return points which satisfies filtering condition ordered by the `order_by.key` field,
     /// starting with `order_by.start_from` value including.
     ///
-    /// Will fail if there is no index for the order_by key.
+    /// Each point comes with its values for all keys of `order_by`, starting with the primary one.
+    ///
+    /// Will fail if there is no index for any of the order_by keys.
     fn read_ordered_filtered<'a>(
         &'a self,
         limit: Option<usize>,
         filter: Option<&'a Filter>,
         order_by: &'a OrderBy,
-    ) -> OperationResult<Vec<(OrderValue, PointIdType)>>;
+    ) -> OperationResult<Vec<(OrderValues, PointIdType)>>;
 
     /// Read points in [from; to) range
     fn read_range(&self, from: Option<PointIdType>, to: Option<PointIdType>) -> Vec<PointIdType>;
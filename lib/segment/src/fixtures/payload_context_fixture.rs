@@ -3,6 +3,7 @@ use std::sync::Arc;
 
 use atomic_refcell::AtomicRefCell;
 use bitvec::prelude::{BitSlice, BitVec};
+use chrono::{DateTime, Utc};
 use common::types::PointOffsetType;
 use rand::prelude::StdRng;
 use rand::SeedableRng;
@@ -13,7 +14,7 @@ use crate::common::Flusher;
 use crate::fixtures::payload_fixtures::{
     generate_diverse_payload, FLT_KEY, GEO_KEY, INT_KEY, STR_KEY, TEXT_KEY,
 };
-use crate::id_tracker::IdTracker;
+use crate::id_tracker::{IdTracker, TrashedPoint};
 use crate::index::plain_payload_index::PlainPayloadIndex;
 use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::index::PayloadIndex;
@@ -85,6 +86,34 @@ impl IdTracker for FixtureIdTracker {
         Ok(())
     }
 
+    fn trash(
+        &mut self,
+        external_id: PointIdType,
+        _version: SeqNumberType,
+        _trashed_at: DateTime<Utc>,
+    ) -> OperationResult<()> {
+        self.drop(external_id)
+    }
+
+    fn restore(&mut self, _external_id: PointIdType) -> OperationResult<Option<PointOffsetType>> {
+        Ok(None)
+    }
+
+    fn purge_trashed(
+        &mut self,
+        _external_id: PointIdType,
+    ) -> OperationResult<Option<PointOffsetType>> {
+        Ok(None)
+    }
+
+    fn trashed_point(&self, _external_id: PointIdType) -> Option<TrashedPoint> {
+        None
+    }
+
+    fn iter_trashed(&self) -> Box<dyn Iterator<Item = (PointIdType, TrashedPoint)> + '_> {
+        Box::new(std::iter::empty())
+    }
+
     fn iter_external(&self) -> Box<dyn Iterator<Item = PointIdType> + '_> {
         Box::new(
             self.ids
@@ -144,6 +173,10 @@ impl IdTracker for FixtureIdTracker {
         Box::new(|| Ok(()))
     }
 
+    fn trash_flusher(&self) -> Flusher {
+        Box::new(|| Ok(()))
+    }
+
     fn is_deleted_point(&self, key: PointOffsetType) -> bool {
         let key = key as usize;
         if key >= self.deleted.len() {
@@ -1,7 +1,9 @@
 use bitvec::prelude::BitSlice;
+use chrono::{DateTime, Utc};
 use common::types::PointOffsetType;
 use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
 
 use crate::common::operation_error::OperationResult;
 use crate::common::Flusher;
@@ -14,6 +16,17 @@ use crate::types::{PointIdType, SeqNumberType};
 /// would otherwise be introduced by HNSW/ID tracker point sampling.
 const SEED: u64 = 0b1011000011011110001110010101001010001011001101001010010001111010;
 
+/// Point, which was moved to trash.
+///
+/// Its internal ID is marked as deleted, but vectors and payload are kept, so it can be restored.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub struct TrashedPoint {
+    pub internal_id: PointOffsetType,
+    /// Version of the operation, which moved the point to trash
+    pub version: SeqNumberType,
+    pub trashed_at: DateTime<Utc>,
+}
+
 /// Trait for point ids tracker.
 ///
 /// This tracker is used to convert external (i.e. user-facing) point id into internal point id
@@ -44,6 +57,35 @@ pub trait IdTracker {
     /// Drop mapping
     fn drop(&mut self, external_id: PointIdType) -> OperationResult<()>;
 
+    /// Drop mapping, but remember the internal ID of the point, so it can be restored
+    ///
+    /// Does nothing if the point does not exist.
+    fn trash(
+        &mut self,
+        external_id: PointIdType,
+        version: SeqNumberType,
+        trashed_at: DateTime<Utc>,
+    ) -> OperationResult<()>;
+
+    /// Set mapping back to the internal ID of the trashed point
+    ///
+    /// Returns internal ID of the restored point, if it was in trash.
+    fn restore(&mut self, external_id: PointIdType) -> OperationResult<Option<PointOffsetType>>;
+
+    /// Forget the trashed point, so it can't be restored anymore
+    ///
+    /// Returns internal ID of the purged point, if it was in trash.
+    fn purge_trashed(
+        &mut self,
+        external_id: PointIdType,
+    ) -> OperationResult<Option<PointOffsetType>>;
+
+    /// Returns the trashed point with the given external ID
+    fn trashed_point(&self, external_id: PointIdType) -> Option<TrashedPoint>;
+
+    /// Iterate over all trashed points
+    fn iter_trashed(&self) -> Box<dyn Iterator<Item = (PointIdType, TrashedPoint)> + '_>;
+
     /// Iterate over all external IDs
     ///
     /// Count should match `available_point_count`.
@@ -88,6 +130,9 @@ pub trait IdTracker {
     /// Flush points versions to disk
     fn versions_flusher(&self) -> Flusher;
 
+    /// Flush trashed points to disk
+    fn trash_flusher(&self) -> Flusher;
+
     /// Number of total points
     ///
     /// - includes soft deleted points
@@ -212,6 +257,53 @@ impl IdTracker for IdTrackerEnum {
         }
     }
 
+    fn trash(
+        &mut self,
+        external_id: PointIdType,
+        version: SeqNumberType,
+        trashed_at: DateTime<Utc>,
+    ) -> OperationResult<()> {
+        match self {
+            IdTrackerEnum::MutableIdTracker(id_tracker) => {
+                id_tracker.trash(external_id, version, trashed_at)
+            }
+            IdTrackerEnum::ImmutableIdTracker(id_tracker) => {
+                id_tracker.trash(external_id, version, trashed_at)
+            }
+        }
+    }
+
+    fn restore(&mut self, external_id: PointIdType) -> OperationResult<Option<PointOffsetType>> {
+        match self {
+            IdTrackerEnum::MutableIdTracker(id_tracker) => id_tracker.restore(external_id),
+            IdTrackerEnum::ImmutableIdTracker(id_tracker) => id_tracker.restore(external_id),
+        }
+    }
+
+    fn purge_trashed(
+        &mut self,
+        external_id: PointIdType,
+    ) -> OperationResult<Option<PointOffsetType>> {
+        match self {
+            IdTrackerEnum::MutableIdTracker(id_tracker) => id_tracker.purge_trashed(external_id),
+            IdTrackerEnum::ImmutableIdTracker(id_tracker) => id_tracker.purge_trashed(external_id),
+        }
+    }
+
+    fn trashed_point(&self, external_id: PointIdType) -> Option<TrashedPoint> {
+        match self {
+            IdTrackerEnum::MutableIdTracker(id_tracker) => id_tracker.trashed_point(external_id),
+            IdTrackerEnum::ImmutableIdTracker(id_tracker) => id_tracker.trashed_point(external_id),
+        }
+    }
+
+    fn iter_trashed(&self) -> Box<dyn Iterator<Item = (PointIdType, TrashedPoint)> + '_> {
+        match self {
+            IdTrackerEnum::MutableIdTracker(id_tracker) => id_tracker.iter_trashed(),
+            IdTrackerEnum::ImmutableIdTracker(id_tracker) => id_tracker.iter_trashed(),
+        }
+    }
+
     fn iter_external(&self) -> Box<dyn Iterator<Item = PointIdType> + '_> {
         match self {
             IdTrackerEnum::MutableIdTracker(id_tracker) => id_tracker.iter_external(),
@@ -257,6 +349,13 @@ impl IdTracker for IdTrackerEnum {
         }
     }
 
+    fn trash_flusher(&self) -> Flusher {
+        match self {
+            IdTrackerEnum::MutableIdTracker(id_tracker) => id_tracker.trash_flusher(),
+            IdTrackerEnum::ImmutableIdTracker(id_tracker) => id_tracker.trash_flusher(),
+        }
+    }
+
     fn total_point_count(&self) -> usize {
         match self {
             IdTrackerEnum::MutableIdTracker(id_tracker) => id_tracker.total_point_count(),
@@ -3,6 +3,7 @@ use std::sync::Arc;
 
 use bincode;
 use bitvec::prelude::{BitSlice, BitVec};
+use chrono::{DateTime, Utc};
 use common::types::PointOffsetType;
 use parking_lot::RwLock;
 use rocksdb::DB;
@@ -12,9 +13,11 @@ use uuid::Uuid;
 use crate::common::operation_error::OperationResult;
 use crate::common::rocksdb_buffered_delete_wrapper::DatabaseColumnScheduledDeleteWrapper;
 use crate::common::rocksdb_buffered_update_wrapper::DatabaseColumnScheduledUpdateWrapper;
-use crate::common::rocksdb_wrapper::{DatabaseColumnWrapper, DB_MAPPING_CF, DB_VERSIONS_CF};
+use crate::common::rocksdb_wrapper::{
+    DatabaseColumnWrapper, DB_MAPPING_CF, DB_TRASH_CF, DB_VERSIONS_CF,
+};
 use crate::common::Flusher;
-use crate::id_tracker::IdTracker;
+use crate::id_tracker::{IdTracker, TrashedPoint};
 use crate::types::{ExtendedPointId, PointIdType, SeqNumberType};
 
 /// Point Id type used for storing ids internally
@@ -63,8 +66,10 @@ pub struct SimpleIdTracker {
     internal_to_version: Vec<SeqNumberType>,
     external_to_internal_num: BTreeMap<u64, PointOffsetType>,
     external_to_internal_uuid: BTreeMap<Uuid, PointOffsetType>,
+    trashed: BTreeMap<PointIdType, TrashedPoint>,
     mapping_db_wrapper: DatabaseColumnScheduledDeleteWrapper,
     versions_db_wrapper: DatabaseColumnScheduledUpdateWrapper,
+    trash_db_wrapper: DatabaseColumnScheduledDeleteWrapper,
 }
 
 impl SimpleIdTracker {
@@ -122,7 +127,7 @@ impl SimpleIdTracker {
 
         let mut internal_to_version: Vec<SeqNumberType> = Default::default();
         let versions_db_wrapper = DatabaseColumnScheduledUpdateWrapper::new(
-            DatabaseColumnWrapper::new(store, DB_VERSIONS_CF),
+            DatabaseColumnWrapper::new(store.clone(), DB_VERSIONS_CF),
         );
         for (key, val) in versions_db_wrapper.lock_db().iter()? {
             let external_id = Self::restore_key(&key);
@@ -144,6 +149,40 @@ impl SimpleIdTracker {
             }
         }
 
+        let mut trashed: BTreeMap<PointIdType, TrashedPoint> = Default::default();
+        let mut outdated_trash_keys = Vec::new();
+        let trash_db_wrapper = DatabaseColumnScheduledDeleteWrapper::new(
+            DatabaseColumnWrapper::new(store, DB_TRASH_CF),
+        );
+        for (key, val) in trash_db_wrapper.lock_db().iter()? {
+            let external_id = Self::restore_key(&key);
+            let trashed_point: TrashedPoint = bincode::deserialize(&val).unwrap();
+            let internal_id = trashed_point.internal_id as usize;
+
+            let is_live = match external_id {
+                PointIdType::NumId(idx) => external_to_internal_num.contains_key(&idx),
+                PointIdType::Uuid(uuid) => external_to_internal_uuid.contains_key(&uuid),
+            };
+            let is_reused = deleted.get(internal_id).map_or(false, |deleted| !deleted);
+            if is_live || is_reused {
+                // Point was restored or inserted again, trash entry is outdated
+                outdated_trash_keys.push(key);
+                continue;
+            }
+
+            // Keep internal ids of trashed points reserved, so they are not reused for new points
+            if internal_id >= internal_to_external.len() {
+                internal_to_external.resize(internal_id + 1, PointIdType::NumId(u64::MAX));
+            }
+            if internal_id >= deleted.len() {
+                deleted.resize(internal_id + 1, true);
+            }
+            trashed.insert(external_id, trashed_point);
+        }
+        for key in outdated_trash_keys {
+            trash_db_wrapper.remove(key)?;
+        }
+
         #[cfg(debug_assertions)]
         {
             for (idx, id) in external_to_internal_num.iter() {
@@ -162,8 +201,10 @@ impl SimpleIdTracker {
             internal_to_version,
             external_to_internal_num,
             external_to_internal_uuid,
+            trashed,
             mapping_db_wrapper,
             versions_db_wrapper,
+            trash_db_wrapper,
         })
     }
 
@@ -181,6 +222,7 @@ impl SimpleIdTracker {
             .remove(Self::store_key(external_id))?;
         self.versions_db_wrapper
             .remove(Self::store_key(external_id))?;
+        self.trash_db_wrapper.remove(Self::store_key(external_id))?;
         Ok(())
     }
 
@@ -236,6 +278,9 @@ impl IdTracker for SimpleIdTracker {
         external_id: PointIdType,
         internal_id: PointOffsetType,
     ) -> OperationResult<()> {
+        // Trash entry is removed from disk on load, once the new mapping is persisted
+        self.trashed.remove(&external_id);
+
         match external_id {
             PointIdType::NumId(idx) => {
                 self.external_to_internal_num.insert(idx, internal_id);
@@ -273,6 +318,62 @@ impl IdTracker for SimpleIdTracker {
         Ok(())
     }
 
+    fn trash(
+        &mut self,
+        external_id: PointIdType,
+        version: SeqNumberType,
+        trashed_at: DateTime<Utc>,
+    ) -> OperationResult<()> {
+        let Some(internal_id) = self.internal_id(external_id) else {
+            return Ok(());
+        };
+        self.drop(external_id)?;
+
+        let trashed_point = TrashedPoint {
+            internal_id,
+            version,
+            trashed_at,
+        };
+        self.trash_db_wrapper.put(
+            Self::store_key(&external_id),
+            bincode::serialize(&trashed_point).unwrap(),
+        )?;
+        self.trashed.insert(external_id, trashed_point);
+        Ok(())
+    }
+
+    fn restore(&mut self, external_id: PointIdType) -> OperationResult<Option<PointOffsetType>> {
+        let Some(trashed_point) = self.trashed.get(&external_id).copied() else {
+            return Ok(None);
+        };
+        self.set_link(external_id, trashed_point.internal_id)?;
+        Ok(Some(trashed_point.internal_id))
+    }
+
+    fn purge_trashed(
+        &mut self,
+        external_id: PointIdType,
+    ) -> OperationResult<Option<PointOffsetType>> {
+        let Some(trashed_point) = self.trashed.remove(&external_id) else {
+            return Ok(None);
+        };
+        self.trash_db_wrapper
+            .remove(Self::store_key(&external_id))?;
+        Ok(Some(trashed_point.internal_id))
+    }
+
+    fn trashed_point(&self, external_id: PointIdType) -> Option<TrashedPoint> {
+        self.trashed.get(&external_id).copied()
+    }
+
+    fn iter_trashed(&self) -> Box<dyn Iterator<Item = (PointIdType, TrashedPoint)> + '_> {
+        Box::new(
+            self.trashed
+                .iter()
+                .map(|(external_id, trashed_point)| (*external_id, *trashed_point)),
+        )
+    }
+
     fn iter_external(&self) -> Box<dyn Iterator<Item = PointIdType> + '_> {
         let iter_num = self
             .external_to_internal_num
@@ -373,6 +474,13 @@ impl IdTracker for SimpleIdTracker {
         self.versions_db_wrapper.flusher()
     }
 
+    /// Creates a flusher function, that persists the trashed points.
+    /// This function should be called _before_ flushing the mapping database, so a trashed point
+    /// is never lost: a trash entry is discarded on load if its mapping is still persisted.
+    fn trash_flusher(&self) -> Flusher {
+        self.trash_db_wrapper.flusher()
+    }
+
     fn is_deleted_point(&self, key: PointOffsetType) -> bool {
         let key = key as usize;
         if key >= self.deleted.len() {
@@ -493,4 +601,68 @@ mod tests {
 
         assert_eq!(sorted_from_tracker, values);
     }
+
+    #[test]
+    fn test_trash_and_restore() {
+        let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+        let db = open_db(dir.path(), &[DB_VECTOR_CF]).unwrap();
+
+        let mut id_tracker = SimpleIdTracker::open(db.clone()).unwrap();
+
+        for id in 0..4 {
+            id_tracker
+                .set_link(id.into(), id as PointOffsetType)
+                .unwrap();
+            id_tracker
+                .set_internal_version(id as PointOffsetType, 1)
+                .unwrap();
+        }
+
+        let trashed_at = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        id_tracker.trash(2.into(), 2, trashed_at).unwrap();
+        id_tracker.trash(3.into(), 3, trashed_at).unwrap();
+
+        assert_eq!(id_tracker.internal_id(3.into()), None);
+        assert!(id_tracker.is_deleted_point(3));
+        assert_eq!(id_tracker.available_point_count(), 2);
+        assert_eq!(
+            id_tracker.trashed_point(3.into()),
+            Some(TrashedPoint {
+                internal_id: 3,
+                version: 3,
+                trashed_at,
+            }),
+        );
+
+        assert_eq!(id_tracker.restore(2.into()).unwrap(), Some(2));
+        assert_eq!(id_tracker.restore(2.into()).unwrap(), None);
+        assert_eq!(id_tracker.internal_id(2.into()), Some(2));
+        assert_eq!(id_tracker.trashed_point(2.into()), None);
+
+        id_tracker.trash_flusher()().unwrap();
+        id_tracker.mapping_flusher()().unwrap();
+        id_tracker.versions_flusher()().unwrap();
+        drop(id_tracker);
+
+        let mut id_tracker = SimpleIdTracker::open(db).unwrap();
+
+        assert_eq!(id_tracker.internal_id(2.into()), Some(2));
+        assert_eq!(
+            id_tracker.iter_trashed().collect_vec(),
+            vec![(
+                3.into(),
+                TrashedPoint {
+                    internal_id: 3,
+                    version: 3,
+                    trashed_at,
+                },
+            )],
+        );
+        // Internal id of the trashed point is not reused
+        assert!(id_tracker.is_deleted_point(3));
+        assert_eq!(id_tracker.total_point_count(), 4);
+
+        assert_eq!(id_tracker.purge_trashed(3.into()).unwrap(), Some(3));
+        assert_eq!(id_tracker.iter_trashed().count(), 0);
+    }
 }
@@ -350,6 +350,19 @@ impl FieldIndex {
             | FieldIndex::FullTextIndex(_) => None,
         }
     }
+
+    pub fn as_geo(&self) -> Option<&GeoMapIndex> {
+        match self {
+            FieldIndex::GeoIndex(index) => Some(index),
+            FieldIndex::IntIndex(_)
+            | FieldIndex::DatetimeIndex(_)
+            | FieldIndex::IntMapIndex(_)
+            | FieldIndex::KeywordIndex(_)
+            | FieldIndex::FloatIndex(_)
+            | FieldIndex::BinaryIndex(_)
+            | FieldIndex::FullTextIndex(_) => None,
+        }
+    }
 }
 
 pub enum NumericFieldIndex<'a> {
@@ -237,6 +237,13 @@ impl<T: Encodable + Numericable + Default> NumericIndex<T> {
             RangeInterface::DateTime(datetime_range) => {
                 datetime_range.map(|dt| T::from_i64(dt.timestamp()))
             }
+            RangeInterface::RelativeDateTime(_) => {
+                debug_assert!(
+                    false,
+                    "relative datetime range must be resolved before search"
+                );
+                return CardinalityEstimation::exact(0);
+            }
         };
 
         let lbound = if let Some(lte) = range.lte {
@@ -345,6 +352,15 @@ impl<T: Encodable + Numericable + Default> PayloadFieldIndex for NumericIndex<T>
             RangeInterface::DateTime(datetime_range) => {
                 datetime_range.map(|dt| T::from_i64(dt.timestamp()))
             }
+            RangeInterface::RelativeDateTime(_) => {
+                debug_assert!(
+                    false,
+                    "relative datetime range must be resolved before search"
+                );
+                return Err(OperationError::service_error(
+                    "relative datetime range is not resolved",
+                ));
+            }
         }
         .as_index_key_bounds();
 
@@ -536,6 +552,13 @@ where
             RangeInterface::DateTime(datetime_range) => {
                 datetime_range.map(|dt| T::from_i64(dt.timestamp()))
             }
+            RangeInterface::RelativeDateTime(_) => {
+                debug_assert!(
+                    false,
+                    "relative datetime range must be resolved before search"
+                );
+                return Box::new(vec![].into_iter());
+            }
         };
         let (start_bound, end_bound) = range.as_index_key_bounds();
 
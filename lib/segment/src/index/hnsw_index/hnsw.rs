@@ -4,6 +4,7 @@ use std::path::{Path, PathBuf};
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 use std::thread;
+use std::time::Instant;
 
 use atomic_refcell::AtomicRefCell;
 use bitvec::prelude::BitSlice;
@@ -25,6 +26,7 @@ use crate::common::operation_time_statistics::{
 };
 use crate::common::BYTES_IN_KB;
 use crate::data_types::query_context::VectorQueryContext;
+use crate::data_types::search_explain::{CardinalityExplanation, SearchStrategy};
 use crate::data_types::vectors::{QueryVector, Vector, VectorRef};
 use crate::id_tracker::IdTrackerSS;
 use crate::index::hnsw_index::build_condition_checker::BuildConditionChecker;
@@ -32,21 +34,27 @@ use crate::index::hnsw_index::config::HnswGraphConfig;
 use crate::index::hnsw_index::graph_layers::GraphLayers;
 use crate::index::hnsw_index::graph_layers_builder::GraphLayersBuilder;
 use crate::index::hnsw_index::point_scorer::FilteredScorer;
+use crate::index::hnsw_index::token_filter_context::TokenFilterContext;
 use crate::index::query_estimator::adjust_to_available_vectors;
 use crate::index::sample_estimation::sample_check_cardinality;
 use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::index::visited_pool::{VisitedListHandle, VisitedPool};
 use crate::index::{PayloadIndex, VectorIndex};
+use crate::payload_storage::FilterContext;
 use crate::telemetry::VectorIndexSearchesTelemetry;
 use crate::types::Condition::Field;
 use crate::types::{
     default_quantization_ignore_value, default_quantization_oversampling_value, FieldCondition,
-    Filter, HnswConfig, QuantizationSearchParams, SearchParams,
+    Filter, HnswConfig, MultiVectorIndexMode, QuantizationKind, QuantizationSearchParams,
+    SearchParams,
 };
+use crate::vector_storage::multi_dense::token_mapping::TokenMapping;
 use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
 use crate::vector_storage::query::DiscoveryQuery;
+use crate::vector_storage::token_raw_scorer::new_token_raw_scorer;
 use crate::vector_storage::{
-    new_raw_scorer, new_stoppable_raw_scorer, RawScorer, VectorStorage, VectorStorageEnum,
+    new_raw_scorer_with_prefix, new_stoppable_raw_scorer, new_stoppable_raw_scorer_with_prefix,
+    RawScorer, VectorStorage, VectorStorageEnum,
 };
 
 const HNSW_USE_HEURISTIC: bool = true;
@@ -66,6 +74,10 @@ pub struct HNSWIndex<TGraphLinks: GraphLinks> {
     config: HnswGraphConfig,
     path: PathBuf,
     graph: GraphLayers<TGraphLinks>,
+    /// Number of leading dimensions to index, if this index is built over a prefix view
+    prefix_dim: Option<usize>,
+    /// Mapping of graph nodes to sub-vectors, if this index is built over multivector tokens
+    token_mapping: Option<TokenMapping>,
     searches_telemetry: HNSWSearchesTelemetry,
 }
 
@@ -85,6 +97,7 @@ pub struct HnswIndexOpenArgs<'a> {
     pub quantized_vectors: Arc<AtomicRefCell<Option<QuantizedVectors>>>,
     pub payload_index: Arc<AtomicRefCell<StructPayloadIndex>>,
     pub hnsw_config: HnswConfig,
+    pub prefix_dim: Option<usize>,
     pub permit: Option<Arc<CpuPermit>>,
     pub stopped: &'a AtomicBool,
 }
@@ -98,6 +111,7 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
             quantized_vectors,
             payload_index,
             hnsw_config,
+            prefix_dim,
             permit,
             stopped,
         } = args;
@@ -107,22 +121,15 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
         let config_path = HnswGraphConfig::get_config_path(path);
         let graph_path = GraphLayers::<TGraphLinks>::get_path(path);
         let graph_links_path = GraphLayers::<TGraphLinks>::get_links_path(path);
-        let (config, graph) = if graph_path.exists() {
+        let token_mapping_path = TokenMapping::get_path(path);
+        let (config, graph, token_mapping) = if graph_path.exists() {
             let config = if config_path.exists() {
                 HnswGraphConfig::load(&config_path)?
             } else {
                 let vector_storage = vector_storage.borrow();
                 let available_vectors = vector_storage.available_vector_count();
-                let full_scan_threshold = vector_storage
-                    .available_size_in_bytes()
-                    .checked_div(available_vectors)
-                    .and_then(|avg_vector_size| {
-                        hnsw_config
-                            .full_scan_threshold
-                            .saturating_mul(BYTES_IN_KB)
-                            .checked_div(avg_vector_size)
-                    })
-                    .unwrap_or(1);
+                let full_scan_threshold =
+                    Self::full_scan_threshold(&vector_storage, &hnsw_config, available_vectors);
 
                 HnswGraphConfig::new(
                     hnsw_config.m,
@@ -134,7 +141,17 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
                 )
             };
 
-            (config, GraphLayers::load(&graph_path, &graph_links_path)?)
+            let token_mapping = if token_mapping_path.exists() {
+                Some(TokenMapping::load(&token_mapping_path)?)
+            } else {
+                None
+            };
+
+            (
+                config,
+                GraphLayers::load(&graph_path, &graph_links_path)?,
+                token_mapping,
+            )
         } else {
             let num_cpus = match permit {
                 Some(p) => p.num_cpus as usize,
@@ -149,21 +166,46 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
                     get_num_cpus()
                 }
             };
-            let (config, graph) = Self::build_index(
-                path,
-                id_tracker.as_ref().borrow().deref(),
-                &vector_storage.borrow(),
-                &quantized_vectors.borrow(),
-                &payload_index.borrow(),
-                hnsw_config,
-                num_cpus,
-                stopped,
-            )?;
+            let is_token_index = vector_storage
+                .borrow()
+                .try_multi_vector_config()
+                .is_some_and(|config| config.index_mode == MultiVectorIndexMode::Token);
+
+            if is_token_index {
+                let (config, graph, token_mapping) = Self::build_token_index(
+                    path,
+                    id_tracker.as_ref().borrow().deref(),
+                    &vector_storage.borrow(),
+                    &payload_index.borrow(),
+                    hnsw_config,
+                    num_cpus,
+                    stopped,
+                )?;
+
+                // Graph must be saved last, its presence marks the index as complete
+                token_mapping.save(&token_mapping_path)?;
+                config.save(&config_path)?;
+                graph.save(&graph_path)?;
+
+                (config, graph, Some(token_mapping))
+            } else {
+                let (config, graph) = Self::build_index(
+                    path,
+                    id_tracker.as_ref().borrow().deref(),
+                    &vector_storage.borrow(),
+                    &quantized_vectors.borrow(),
+                    &payload_index.borrow(),
+                    hnsw_config,
+                    prefix_dim,
+                    num_cpus,
+                    stopped,
+                )?;
 
-            config.save(&config_path)?;
-            graph.save(&graph_path)?;
+                config.save(&config_path)?;
+                graph.save(&graph_path)?;
 
-            (config, graph)
+                (config, graph, None)
+            }
         };
 
         Ok(HNSWIndex {
@@ -174,6 +216,8 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
             config,
             path: path.to_owned(),
             graph,
+            prefix_dim,
+            token_mapping,
             searches_telemetry: HNSWSearchesTelemetry {
                 unfiltered_hnsw: OperationDurationsAggregator::new(),
                 unfiltered_plain: OperationDurationsAggregator::new(),
@@ -194,6 +238,53 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
         self.quantized_vectors.clone()
     }
 
+    /// Number of vectors, below which a full scan is preferred over the graph search
+    fn full_scan_threshold(
+        vector_storage: &VectorStorageEnum,
+        hnsw_config: &HnswConfig,
+        vector_count: usize,
+    ) -> usize {
+        vector_storage
+            .available_size_in_bytes()
+            .checked_div(vector_count)
+            .and_then(|avg_vector_size| {
+                hnsw_config
+                    .full_scan_threshold
+                    .saturating_mul(BYTES_IN_KB)
+                    .checked_div(avg_vector_size)
+            })
+            .unwrap_or(1)
+    }
+
+    fn build_thread_pool(num_cpus: usize) -> OperationResult<ThreadPool> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .thread_name(|idx| format!("hnsw-build-{idx}"))
+            .num_threads(num_cpus)
+            .spawn_handler(|thread| {
+                let mut b = thread::Builder::new();
+                if let Some(name) = thread.name() {
+                    b = b.name(name.to_owned());
+                }
+                if let Some(stack_size) = thread.stack_size() {
+                    b = b.stack_size(stack_size);
+                }
+                b.spawn(|| {
+                    // On Linux, use lower thread priority so we interfere less with serving traffic
+                    #[cfg(target_os = "linux")]
+                    if let Err(err) = linux_low_thread_priority() {
+                        log::debug!(
+                            "Failed to set low thread priority for HNSW building, ignoring: {err}"
+                        );
+                    }
+
+                    thread.run()
+                })?;
+                Ok(())
+            })
+            .build()?;
+        Ok(pool)
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn build_index(
         path: &Path,
@@ -202,21 +293,14 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
         quantized_vectors: &Option<QuantizedVectors>,
         payload_index: &StructPayloadIndex,
         hnsw_config: HnswConfig,
+        prefix_dim: Option<usize>,
         num_cpus: usize,
         stopped: &AtomicBool,
     ) -> OperationResult<(HnswGraphConfig, GraphLayers<TGraphLinks>)> {
         let total_vector_count = vector_storage.total_vector_count();
 
-        let full_scan_threshold = vector_storage
-            .available_size_in_bytes()
-            .checked_div(total_vector_count)
-            .and_then(|avg_vector_size| {
-                hnsw_config
-                    .full_scan_threshold
-                    .saturating_mul(BYTES_IN_KB)
-                    .checked_div(avg_vector_size)
-            })
-            .unwrap_or(1);
+        let full_scan_threshold =
+            Self::full_scan_threshold(vector_storage, &hnsw_config, total_vector_count);
 
         let mut config = HnswGraphConfig::new(
             hnsw_config.m,
@@ -248,31 +332,7 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
             HNSW_USE_HEURISTIC,
         );
 
-        let pool = rayon::ThreadPoolBuilder::new()
-            .thread_name(|idx| format!("hnsw-build-{idx}"))
-            .num_threads(num_cpus)
-            .spawn_handler(|thread| {
-                let mut b = thread::Builder::new();
-                if let Some(name) = thread.name() {
-                    b = b.name(name.to_owned());
-                }
-                if let Some(stack_size) = thread.stack_size() {
-                    b = b.stack_size(stack_size);
-                }
-                b.spawn(|| {
-                    // On Linux, use lower thread priority so we interfere less with serving traffic
-                    #[cfg(target_os = "linux")]
-                    if let Err(err) = linux_low_thread_priority() {
-                        log::debug!(
-                            "Failed to set low thread priority for HNSW building, ignoring: {err}"
-                        );
-                    }
-
-                    thread.run()
-                })?;
-                Ok(())
-            })
-            .build()?;
+        let pool = Self::build_thread_pool(num_cpus)?;
 
         for vector_id in id_tracker.iter_ids_excluding(deleted_bitslice) {
             check_process_stopped(stopped)?;
@@ -305,7 +365,12 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
                         stopped,
                     )
                 } else {
-                    new_raw_scorer(vector, vector_storage, id_tracker.deleted_point_bitslice())
+                    new_raw_scorer_with_prefix(
+                        vector,
+                        vector_storage,
+                        prefix_dim,
+                        id_tracker.deleted_point_bitslice(),
+                    )
                 }?;
                 let points_scorer = FilteredScorer::new(raw_scorer.as_ref(), None);
 
@@ -373,6 +438,7 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
                         vector_storage,
                         quantized_vectors,
                         payload_index,
+                        prefix_dim,
                         &pool,
                         stopped,
                         &mut additional_graph,
@@ -411,12 +477,255 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
         Ok((config, graph))
     }
 
+    /// Build a graph over the individual sub-vectors (tokens) of a multivector storage
+    ///
+    /// Additional payload links connect tokens of the points in the same payload block.
+    /// Quantized vectors are not used for the token graph,
+    /// points of the found tokens are rescored with the original vectors on search.
+    #[allow(clippy::too_many_arguments)]
+    fn build_token_index(
+        path: &Path,
+        id_tracker: &IdTrackerSS,
+        vector_storage: &VectorStorageEnum,
+        payload_index: &StructPayloadIndex,
+        hnsw_config: HnswConfig,
+        num_cpus: usize,
+        stopped: &AtomicBool,
+    ) -> OperationResult<(HnswGraphConfig, GraphLayers<TGraphLinks>, TokenMapping)> {
+        let total_vector_count = vector_storage.total_vector_count();
+
+        let full_scan_threshold =
+            Self::full_scan_threshold(vector_storage, &hnsw_config, total_vector_count);
+
+        let mut config = HnswGraphConfig::new(
+            hnsw_config.m,
+            hnsw_config.ef_construct,
+            full_scan_threshold,
+            hnsw_config.max_indexing_threads,
+            hnsw_config.payload_m,
+            total_vector_count,
+        );
+
+        let deleted_bitslice = vector_storage.deleted_vector_bitslice();
+        let token_mapping = TokenMapping::build(
+            id_tracker.iter_ids_excluding(deleted_bitslice),
+            vector_storage,
+            stopped,
+        )?;
+        let total_token_count = token_mapping.len();
+
+        debug!(
+            "building token HNSW for {total_token_count} tokens of {total_vector_count} vectors with {num_cpus} CPUs"
+        );
+
+        let mut rng = thread_rng();
+        let mut graph_layers_builder = GraphLayersBuilder::new(
+            total_token_count,
+            config.m,
+            config.m0,
+            config.ef_construct,
+            std::cmp::max(
+                1,
+                total_token_count
+                    .checked_div(full_scan_threshold)
+                    .unwrap_or(0)
+                    * 10,
+            ),
+            HNSW_USE_HEURISTIC,
+        );
+
+        for token_id in 0..total_token_count as PointOffsetType {
+            check_process_stopped(stopped)?;
+            let level = graph_layers_builder.get_random_layer(&mut rng);
+            graph_layers_builder.set_levels(token_id, level);
+        }
+
+        let mut indexed_vectors = 0;
+
+        let pool = Self::build_thread_pool(num_cpus)?;
+
+        if config.m > 0 {
+            let insert_token = |token_id| {
+                check_process_stopped(stopped)?;
+                let vector = token_mapping.token_vector(token_id, vector_storage)?;
+                let raw_scorer = new_token_raw_scorer(
+                    vector,
+                    vector_storage,
+                    token_mapping.tokens(),
+                    id_tracker.deleted_point_bitslice(),
+                    stopped,
+                )?;
+                let points_scorer = FilteredScorer::new(raw_scorer.as_ref(), None);
+
+                graph_layers_builder.link_new_point(token_id, points_scorer);
+                Ok::<_, OperationError>(())
+            };
+
+            let first_tokens =
+                total_token_count.min(SINGLE_THREADED_HNSW_BUILD_THRESHOLD) as PointOffsetType;
+
+            for token_id in 0..first_tokens {
+                insert_token(token_id)?;
+            }
+
+            pool.install(|| {
+                (first_tokens..total_token_count as PointOffsetType)
+                    .into_par_iter()
+                    .try_for_each(insert_token)
+            })?;
+
+            indexed_vectors = token_mapping.point_count();
+
+            debug!("finish token graph");
+        } else {
+            debug!("skip building token HNSW graph");
+        }
+
+        let visited_pool = VisitedPool::new();
+        let mut block_filter_list = visited_pool.get(total_token_count);
+        let mut block_points_list = visited_pool.get(total_vector_count);
+        let visits_iteration = block_points_list.get_current_iteration_id();
+
+        let payload_m = config.payload_m.unwrap_or(config.m);
+
+        if payload_m > 0 {
+            let average_links_per_0_level =
+                graph_layers_builder.get_average_connectivity_on_level(0);
+            let average_links_per_0_level_int = (average_links_per_0_level as usize).max(1);
+
+            for (field, _) in payload_index.indexed_fields() {
+                debug!("building additional token index for field {}", &field);
+
+                let percolation_multiplier = 4;
+                let max_block_size = if config.m > 0 {
+                    total_vector_count / average_links_per_0_level_int * percolation_multiplier
+                } else {
+                    usize::MAX
+                };
+
+                for payload_block in payload_index.payload_blocks(&field, full_scan_threshold) {
+                    check_process_stopped(stopped)?;
+                    if payload_block.cardinality > max_block_size {
+                        continue;
+                    }
+                    let mut additional_graph = GraphLayersBuilder::new_with_params(
+                        total_token_count,
+                        payload_m,
+                        config.payload_m0.unwrap_or(config.m0),
+                        config.ef_construct,
+                        1,
+                        HNSW_USE_HEURISTIC,
+                        false,
+                    );
+                    Self::build_token_filtered_graph(
+                        id_tracker,
+                        vector_storage,
+                        payload_index,
+                        &token_mapping,
+                        &pool,
+                        stopped,
+                        &mut additional_graph,
+                        payload_block.condition,
+                        &mut block_filter_list,
+                        &mut block_points_list,
+                    )?;
+                    graph_layers_builder.merge_from_other(additional_graph);
+                }
+            }
+
+            let indexed_payload_vectors = block_points_list.count_visits_since(visits_iteration);
+            indexed_vectors = indexed_vectors.max(indexed_payload_vectors);
+        } else {
+            debug!("skip building additional token HNSW links");
+        }
+
+        config.indexed_vector_count.replace(indexed_vectors);
+
+        let graph_links_path = GraphLayers::<TGraphLinks>::get_links_path(path);
+        let graph: GraphLayers<TGraphLinks> =
+            graph_layers_builder.into_graph_layers(Some(&graph_links_path))?;
+
+        Ok((config, graph, token_mapping))
+    }
+
+    /// Link tokens of the points matching the payload block condition with each other
+    #[allow(clippy::too_many_arguments)]
+    fn build_token_filtered_graph(
+        id_tracker: &IdTrackerSS,
+        vector_storage: &VectorStorageEnum,
+        payload_index: &StructPayloadIndex,
+        token_mapping: &TokenMapping,
+        pool: &ThreadPool,
+        stopped: &AtomicBool,
+        graph_layers_builder: &mut GraphLayersBuilder,
+        condition: FieldCondition,
+        block_filter_list: &mut VisitedListHandle,
+        block_points_list: &mut VisitedListHandle,
+    ) -> OperationResult<()> {
+        block_filter_list.next_iteration();
+        block_points_list.next_iteration();
+
+        let filter = Filter::new_must(Field(condition));
+        for block_point_id in payload_index.query_points(&filter) {
+            block_points_list.check_and_update_visited(block_point_id);
+        }
+
+        // Deleted points have no tokens in the mapping
+        let tokens_to_index: Vec<_> = (0..token_mapping.len() as PointOffsetType)
+            .filter(|&token_id| block_points_list.check(token_mapping.point_id(token_id)))
+            .collect();
+
+        for block_token_id in tokens_to_index.iter().copied() {
+            block_filter_list.check_and_update_visited(block_token_id);
+        }
+
+        let insert_token = |block_token_id| {
+            check_process_stopped(stopped)?;
+
+            let vector = token_mapping.token_vector(block_token_id, vector_storage)?;
+            let raw_scorer = new_token_raw_scorer(
+                vector,
+                vector_storage,
+                token_mapping.tokens(),
+                id_tracker.deleted_point_bitslice(),
+                stopped,
+            )?;
+            let block_condition_checker = BuildConditionChecker {
+                filter_list: block_filter_list,
+                current_point: block_token_id,
+            };
+            let points_scorer =
+                FilteredScorer::new(raw_scorer.as_ref(), Some(&block_condition_checker));
+
+            graph_layers_builder.link_new_point(block_token_id, points_scorer);
+            Ok::<_, OperationError>(())
+        };
+
+        let first_tokens = tokens_to_index
+            .len()
+            .min(SINGLE_THREADED_HNSW_BUILD_THRESHOLD);
+
+        for token_id in tokens_to_index[..first_tokens].iter().copied() {
+            insert_token(token_id)?;
+        }
+        if tokens_to_index.len() > first_tokens {
+            pool.install(|| {
+                tokens_to_index
+                    .into_par_iter()
+                    .skip(first_tokens)
+                    .try_for_each(insert_token)
+            })?;
+        }
+        Ok(())
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn build_filtered_graph(
         id_tracker: &IdTrackerSS,
         vector_storage: &VectorStorageEnum,
         quantized_vectors: &Option<QuantizedVectors>,
         payload_index: &StructPayloadIndex,
+        prefix_dim: Option<usize>,
         pool: &ThreadPool,
         stopped: &AtomicBool,
         graph_layers_builder: &mut GraphLayersBuilder,
@@ -456,7 +765,12 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
                     deleted_bitslice,
                     stopped,
                 ),
-                None => new_raw_scorer(vector, vector_storage, id_tracker.deleted_point_bitslice()),
+                None => new_raw_scorer_with_prefix(
+                    vector,
+                    vector_storage,
+                    prefix_dim,
+                    id_tracker.deleted_point_bitslice(),
+                ),
             }?;
             let block_condition_checker = BuildConditionChecker {
                 filter_list: block_filter_list,
@@ -519,6 +833,7 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
         let raw_scorer = Self::construct_search_scorer(
             vector,
             &vector_storage,
+            self.prefix_dim,
             quantized_vectors.as_ref(),
             deleted_points,
             params,
@@ -529,10 +844,24 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
         let filter_context = filter.map(|f| payload_index.filter_context(f));
         let points_scorer = FilteredScorer::new(raw_scorer.as_ref(), filter_context.as_deref());
 
+        let search_timer = Instant::now();
         let search_result =
             self.graph
                 .search(oversampled_top, ef, points_scorer, custom_entry_points);
-        self.postprocess_search_result(search_result, vector, params, top, &is_stopped)
+        vector_query_context.explain(|explanation| {
+            explanation.quantization =
+                Self::is_quantized_search(quantized_vectors.as_ref(), params);
+            explanation.timings.add_search(search_timer.elapsed());
+        });
+
+        self.postprocess_search_result(
+            search_result,
+            vector,
+            params,
+            top,
+            &is_stopped,
+            vector_query_context,
+        )
     }
 
     fn search_vectors_with_graph(
@@ -543,6 +872,22 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
         params: Option<&SearchParams>,
         vector_query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        if let Some(token_mapping) = &self.token_mapping {
+            return vectors
+                .iter()
+                .map(|&vector| {
+                    self.search_with_token_graph(
+                        vector,
+                        token_mapping,
+                        filter,
+                        top,
+                        params,
+                        vector_query_context,
+                    )
+                })
+                .collect();
+        }
+
         vectors
             .iter()
             .map(|&vector| match vector {
@@ -560,6 +905,127 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
             .collect()
     }
 
+    /// Search the graph built over multivector tokens
+    ///
+    /// Each query sub-vector is searched in the token graph separately. Points of all found tokens
+    /// are then rescored with the multivector comparator.
+    ///
+    /// Recommend, discovery and context queries combine several multivectors into a single score,
+    /// which does not decompose into scores of separate tokens. Such queries are answered with
+    /// an exact search over all (filtered) points instead.
+    fn search_with_token_graph(
+        &self,
+        vector: &QueryVector,
+        token_mapping: &TokenMapping,
+        filter: Option<&Filter>,
+        top: usize,
+        params: Option<&SearchParams>,
+        vector_query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<ScoredPointOffset>> {
+        let QueryVector::Nearest(Vector::MultiDense(query)) = vector else {
+            // Token graph can't be navigated with combined queries, score all points instead
+            debug!(
+                "token graph can't be used for combined multivector queries, using exact search"
+            );
+            vector_query_context.explain(|explanation| {
+                explanation.strategy = match filter {
+                    Some(_) => SearchStrategy::PayloadIndex,
+                    None => SearchStrategy::FullScan,
+                };
+            });
+            let filtered_points: Vec<_> = match filter {
+                Some(filter) => self.payload_index.borrow().query_points(filter),
+                None => self.id_tracker.borrow().iter_ids().collect(),
+            };
+            return self.search_plain(vector, &filtered_points, top, params, vector_query_context);
+        };
+
+        let ef = params
+            .and_then(|params| params.hnsw_ef)
+            .unwrap_or(self.config.ef)
+            .max(top);
+        let max_ef = token_mapping.len().max(ef);
+
+        let is_stopped = vector_query_context.is_stopped();
+
+        let id_tracker = self.id_tracker.borrow();
+        let payload_index = self.payload_index.borrow();
+        let vector_storage = self.vector_storage.borrow();
+
+        let deleted_points = vector_query_context
+            .deleted_points()
+            .unwrap_or(id_tracker.deleted_point_bitslice());
+
+        let filter_context = filter.map(|f| payload_index.filter_context(f));
+        let token_filter_context =
+            filter_context
+                .as_deref()
+                .map(|filter_context| TokenFilterContext {
+                    filter_context,
+                    token_mapping,
+                });
+
+        let search_timer = Instant::now();
+        let mut candidates = Vec::new();
+        for query_token in query.multi_vectors() {
+            check_process_stopped(&is_stopped)?;
+            let raw_scorer = new_token_raw_scorer(
+                query_token.to_vec(),
+                &vector_storage,
+                token_mapping.tokens(),
+                deleted_points,
+                &is_stopped,
+            )?;
+
+            // Several tokens of the same point may be found, search wider
+            // until tokens of at least `top` distinct points are found
+            let mut token_ef = ef;
+            let found_points = loop {
+                let points_scorer = FilteredScorer::new(
+                    raw_scorer.as_ref(),
+                    token_filter_context
+                        .as_ref()
+                        .map(|context| context as &dyn FilterContext),
+                );
+                let found_tokens = self.graph.search(token_ef, token_ef, points_scorer, None);
+                let mut found_points: Vec<_> = found_tokens
+                    .iter()
+                    .map(|scored_token| token_mapping.point_id(scored_token.idx))
+                    .collect();
+                found_points.sort_unstable();
+                found_points.dedup();
+
+                let is_exhausted = found_tokens.len() < token_ef || token_ef >= max_ef;
+                if found_points.len() >= top || is_exhausted {
+                    break found_points;
+                }
+                check_process_stopped(&is_stopped)?;
+                token_ef = token_ef.saturating_mul(2).min(max_ef);
+            };
+            candidates.extend(found_points);
+        }
+        candidates.sort_unstable();
+        candidates.dedup();
+        vector_query_context.explain(|explanation| {
+            explanation.timings.add_search(search_timer.elapsed());
+        });
+
+        let rescoring_timer = Instant::now();
+        let raw_scorer = new_stoppable_raw_scorer(
+            vector.to_owned(),
+            &vector_storage,
+            deleted_points,
+            &is_stopped,
+        )?;
+        let search_result = raw_scorer.peek_top_iter(&mut candidates.into_iter(), top);
+        vector_query_context.explain(|explanation| {
+            explanation.rescoring = true;
+            explanation.timings.add_rescoring(rescoring_timer.elapsed());
+        });
+
+        Ok(search_result)
+    }
+
     fn search_plain(
         &self,
         vector: &QueryVector,
@@ -581,6 +1047,7 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
         let raw_scorer = Self::construct_search_scorer(
             vector,
             &vector_storage,
+            self.prefix_dim,
             quantized_vectors.as_ref(),
             deleted_points,
             params,
@@ -588,10 +1055,23 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
         )?;
         let oversampled_top = Self::get_oversampled_top(quantized_vectors.as_ref(), params, top);
 
+        let search_timer = Instant::now();
         let search_result =
             raw_scorer.peek_top_iter(&mut filtered_points.iter().copied(), oversampled_top);
-
-        self.postprocess_search_result(search_result, vector, params, top, &is_stopped)
+        vector_query_context.explain(|explanation| {
+            explanation.quantization =
+                Self::is_quantized_search(quantized_vectors.as_ref(), params);
+            explanation.timings.add_search(search_timer.elapsed());
+        });
+
+        self.postprocess_search_result(
+            search_result,
+            vector,
+            params,
+            top,
+            &is_stopped,
+            vector_query_context,
+        )
     }
 
     fn search_vectors_plain(
@@ -604,7 +1084,14 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
     ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
         let payload_index = self.payload_index.borrow();
         // share filtered points for all query vectors
+        let prefiltering_timer = Instant::now();
         let filtered_points = payload_index.query_points(filter);
+        vector_query_context.explain(|explanation| {
+            explanation.strategy = SearchStrategy::PayloadIndex;
+            explanation
+                .timings
+                .add_prefiltering(prefiltering_timer.elapsed());
+        });
         vectors
             .iter()
             .map(|vector| {
@@ -661,9 +1148,16 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
         quantized_storage.is_some() && !ignore_quantization
     }
 
+    fn quantization_kind(params: Option<&SearchParams>) -> Option<QuantizationKind> {
+        params
+            .and_then(|p| p.quantization)
+            .and_then(|q| q.quantization)
+    }
+
     fn construct_search_scorer<'a>(
         vector: &QueryVector,
         vector_storage: &'a VectorStorageEnum,
+        prefix_dim: Option<usize>,
         quantized_storage: Option<&'a QuantizedVectors>,
         deleted_points: &'a BitSlice,
         params: Option<&SearchParams>,
@@ -671,15 +1165,18 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
     ) -> OperationResult<Box<dyn RawScorer + 'a>> {
         let quantization_enabled = Self::is_quantized_search(quantized_storage, params);
         match quantized_storage {
-            Some(quantized_storage) if quantization_enabled => quantized_storage.raw_scorer(
-                vector.to_owned(),
-                deleted_points,
-                vector_storage.deleted_vector_bitslice(),
-                is_stopped,
-            ),
-            _ => new_stoppable_raw_scorer(
+            Some(quantized_storage) if quantization_enabled => quantized_storage
+                .select(Self::quantization_kind(params))?
+                .raw_scorer(
+                    vector.to_owned(),
+                    deleted_points,
+                    vector_storage.deleted_vector_bitslice(),
+                    is_stopped,
+                ),
+            _ => new_stoppable_raw_scorer_with_prefix(
                 vector.to_owned(),
                 vector_storage,
+                prefix_dim,
                 deleted_points,
                 is_stopped,
             ),
@@ -713,6 +1210,7 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
         params: Option<&SearchParams>,
         top: usize,
         is_stopped: &AtomicBool,
+        vector_query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<ScoredPointOffset>> {
         let id_tracker = self.id_tracker.borrow();
         let vector_storage = self.vector_storage.borrow();
@@ -720,10 +1218,12 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
 
         let quantization_enabled = Self::is_quantized_search(quantized_vectors.as_ref(), params);
 
-        let default_rescoring = quantized_vectors
-            .as_ref()
-            .map(|q| q.default_rescoring())
-            .unwrap_or(false);
+        let default_rescoring = match quantized_vectors.as_ref() {
+            Some(q) if quantization_enabled => q
+                .select(Self::quantization_kind(params))?
+                .default_rescoring(),
+            _ => false,
+        };
         let rescore = quantization_enabled
             && params
                 .and_then(|p| p.quantization)
@@ -731,9 +1231,11 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
                 .unwrap_or(default_rescoring);
 
         let mut postprocess_result = if rescore {
-            let raw_scorer = new_stoppable_raw_scorer(
+            let rescoring_timer = Instant::now();
+            let raw_scorer = new_stoppable_raw_scorer_with_prefix(
                 vector.to_owned(),
                 &vector_storage,
+                self.prefix_dim,
                 id_tracker.deleted_point_bitslice(),
                 is_stopped,
             )?;
@@ -743,6 +1245,11 @@ impl<TGraphLinks: GraphLinks> HNSWIndex<TGraphLinks> {
 
             re_scored.sort_unstable();
             re_scored.reverse();
+
+            vector_query_context.explain(|explanation| {
+                explanation.rescoring = true;
+                explanation.timings.add_rescoring(rescoring_timer.elapsed());
+            });
             re_scored
         } else {
             search_result
@@ -782,6 +1289,10 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
 
                 // Do plain or graph search
                 if plain_search {
+                    query_context.explain(|explanation| {
+                        explanation.strategy = SearchStrategy::FullScan;
+                    });
+                    let search_timer = Instant::now();
                     let _timer = ScopeDurationMeasurer::new(if exact {
                         &self.searches_telemetry.exact_unfiltered
                     } else {
@@ -793,19 +1304,27 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
 
                     let is_stopped = query_context.is_stopped();
 
-                    vectors
+                    let search_result = vectors
                         .iter()
                         .map(|&vector| {
-                            new_stoppable_raw_scorer(
+                            new_stoppable_raw_scorer_with_prefix(
                                 vector.to_owned(),
                                 &vector_storage,
+                                self.prefix_dim,
                                 deleted_points,
                                 &is_stopped,
                             )
                             .map(|scorer| scorer.peek_top_all(top))
                         })
-                        .collect()
+                        .collect();
+                    query_context.explain(|explanation| {
+                        explanation.timings.add_search(search_timer.elapsed());
+                    });
+                    search_result
                 } else {
+                    query_context.explain(|explanation| {
+                        explanation.strategy = SearchStrategy::Hnsw;
+                    });
                     let _timer =
                         ScopeDurationMeasurer::new(&self.searches_telemetry.unfiltered_hnsw);
                     self.search_vectors_with_graph(vectors, None, top, params, query_context)
@@ -824,6 +1343,7 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
                             ignore: true,
                             rescore: Some(false),
                             oversampling: None,
+                            quantization: None,
                         }); // disable quantization for exact search
                         params
                     });
@@ -838,6 +1358,7 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
                     );
                 }
 
+                let planning_timer = Instant::now();
                 let payload_index = self.payload_index.borrow();
                 let vector_storage = self.vector_storage.borrow();
                 let id_tracker = self.id_tracker.borrow();
@@ -849,8 +1370,16 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
                     id_tracker.available_point_count(),
                 );
 
+                query_context.explain(|explanation| {
+                    explanation.cardinality =
+                        Some(CardinalityExplanation::from(&query_cardinality));
+                });
+
                 if query_cardinality.max < self.config.full_scan_threshold {
                     // if cardinality is small - use plain index
+                    query_context.explain(|explanation| {
+                        explanation.timings.add_planning(planning_timer.elapsed());
+                    });
                     let _timer =
                         ScopeDurationMeasurer::new(&self.searches_telemetry.small_cardinality);
                     return self.search_vectors_plain(
@@ -864,6 +1393,10 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
 
                 if query_cardinality.min > self.config.full_scan_threshold {
                     // if cardinality is high enough - use HNSW index
+                    query_context.explain(|explanation| {
+                        explanation.strategy = SearchStrategy::HnswFiltered;
+                        explanation.timings.add_planning(planning_timer.elapsed());
+                    });
                     let _timer =
                         ScopeDurationMeasurer::new(&self.searches_telemetry.large_cardinality);
                     return self.search_vectors_with_graph(
@@ -879,13 +1412,21 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
 
                 // Fast cardinality estimation is not enough, do sample estimation of cardinality
                 let id_tracker = self.id_tracker.borrow();
-                if sample_check_cardinality(
+                let is_large_cardinality = sample_check_cardinality(
                     id_tracker.sample_ids(Some(vector_storage.deleted_vector_bitslice())),
                     |idx| filter_context.check(idx),
                     self.config.full_scan_threshold,
                     available_vector_count, // Check cardinality among available vectors
-                ) {
+                );
+                query_context.explain(|explanation| {
+                    explanation.timings.add_planning(planning_timer.elapsed());
+                });
+
+                if is_large_cardinality {
                     // if cardinality is high enough - use HNSW index
+                    query_context.explain(|explanation| {
+                        explanation.strategy = SearchStrategy::HnswFiltered;
+                    });
                     let _timer =
                         ScopeDurationMeasurer::new(&self.searches_telemetry.large_cardinality);
                     self.search_vectors_with_graph(vectors, filter, top, params, query_context)
@@ -920,6 +1461,7 @@ impl<TGraphLinks: GraphLinks> VectorIndex for HNSWIndex<TGraphLinks> {
             GraphLayers::<TGraphLinks>::get_path(&self.path),
             GraphLayers::<TGraphLinks>::get_links_path(&self.path),
             HnswGraphConfig::get_config_path(&self.path),
+            TokenMapping::get_path(&self.path),
         ]
         .into_iter()
         .filter(|p| p.exists())
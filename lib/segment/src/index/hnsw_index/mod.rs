@@ -10,6 +10,7 @@ pub mod graph_links;
 pub mod hnsw;
 pub mod point_scorer;
 mod search_context;
+pub mod token_filter_context;
 
 #[cfg(test)]
 mod tests;
@@ -44,8 +44,10 @@ fn test_graph_connectivity() {
                 storage_type: VectorStorageType::Memory,
                 index: Indexes::Plain {},
                 quantization_config: None,
+                additional_quantization_configs: vec![],
                 multivector_config: None,
                 datatype: None,
+                prefix_of: None,
             },
         )]),
         payload_storage_type: Default::default(),
@@ -85,6 +87,7 @@ fn test_graph_connectivity() {
         quantized_vectors: Default::default(),
         payload_index: payload_index_ptr.clone(),
         hnsw_config,
+        prefix_dim: None,
         permit: Some(permit),
         stopped: &stopped,
     })
@@ -0,0 +1,17 @@
+use common::types::PointOffsetType;
+
+use crate::payload_storage::FilterContext;
+use crate::vector_storage::multi_dense::token_mapping::TokenMapping;
+
+/// Checks filter conditions of tokens against the points they belong to
+pub struct TokenFilterContext<'a> {
+    pub filter_context: &'a dyn FilterContext,
+    pub token_mapping: &'a TokenMapping,
+}
+
+impl<'a> FilterContext for TokenFilterContext<'a> {
+    fn check(&self, token_id: PointOffsetType) -> bool {
+        self.filter_context
+            .check(self.token_mapping.point_id(token_id))
+    }
+}
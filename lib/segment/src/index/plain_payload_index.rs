@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::fs::create_dir_all;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Instant;
 
 use atomic_refcell::AtomicRefCell;
 use common::types::{PointOffsetType, ScoredPointOffset, TelemetryDetail};
@@ -14,6 +15,7 @@ use crate::common::operation_time_statistics::{
 };
 use crate::common::{Flusher, BYTES_IN_KB};
 use crate::data_types::query_context::VectorQueryContext;
+use crate::data_types::search_explain::SearchStrategy;
 use crate::data_types::vectors::{QueryVector, VectorRef};
 use crate::id_tracker::IdTrackerSS;
 use crate::index::field_index::{CardinalityEstimation, PayloadBlockCondition};
@@ -27,7 +29,9 @@ use crate::types::{
     Filter, Payload, PayloadFieldSchema, PayloadKeyType, PayloadKeyTypeRef, PayloadSchemaType,
     SearchParams,
 };
-use crate::vector_storage::{new_stoppable_raw_scorer, VectorStorage, VectorStorageEnum};
+use crate::vector_storage::{
+    new_stoppable_raw_scorer_with_prefix, VectorStorage, VectorStorageEnum,
+};
 
 /// Implementation of `PayloadIndex` which does not really indexes anything.
 ///
@@ -205,6 +209,8 @@ pub struct PlainIndex {
     id_tracker: Arc<AtomicRefCell<IdTrackerSS>>,
     vector_storage: Arc<AtomicRefCell<VectorStorageEnum>>,
     payload_index: Arc<AtomicRefCell<StructPayloadIndex>>,
+    /// Number of leading dimensions to score, if this index is over a prefix view
+    prefix_dim: Option<usize>,
     filtered_searches_telemetry: Arc<Mutex<OperationDurationsAggregator>>,
     unfiltered_searches_telemetry: Arc<Mutex<OperationDurationsAggregator>>,
 }
@@ -214,11 +220,13 @@ impl PlainIndex {
         id_tracker: Arc<AtomicRefCell<IdTrackerSS>>,
         vector_storage: Arc<AtomicRefCell<VectorStorageEnum>>,
         payload_index: Arc<AtomicRefCell<StructPayloadIndex>>,
+        prefix_dim: Option<usize>,
     ) -> PlainIndex {
         PlainIndex {
             id_tracker,
             vector_storage,
             payload_index,
+            prefix_dim,
             filtered_searches_telemetry: OperationDurationsAggregator::new(),
             unfiltered_searches_telemetry: OperationDurationsAggregator::new(),
         }
@@ -267,6 +275,9 @@ impl VectorIndex for PlainIndex {
                 filter,
             )
         {
+            query_context.explain(|explanation| {
+                explanation.strategy = SearchStrategy::SkippedIndexedOnly;
+            });
             return Ok(vec![vec![]; vectors.len()]);
         }
 
@@ -278,16 +289,25 @@ impl VectorIndex for PlainIndex {
                 let id_tracker = self.id_tracker.borrow();
                 let payload_index = self.payload_index.borrow();
                 let vector_storage = self.vector_storage.borrow();
+                let prefiltering_timer = Instant::now();
                 let filtered_ids_vec = payload_index.query_points(filter);
+                query_context.explain(|explanation| {
+                    explanation.strategy = SearchStrategy::PayloadIndex;
+                    explanation
+                        .timings
+                        .add_prefiltering(prefiltering_timer.elapsed());
+                });
                 let deleted_points = query_context
                     .deleted_points()
                     .unwrap_or(id_tracker.deleted_point_bitslice());
-                vectors
+                let search_timer = Instant::now();
+                let search_result = vectors
                     .iter()
                     .map(|&vector| {
-                        new_stoppable_raw_scorer(
+                        new_stoppable_raw_scorer_with_prefix(
                             vector.to_owned(),
                             &vector_storage,
+                            self.prefix_dim,
                             deleted_points,
                             &is_stopped,
                         )
@@ -295,7 +315,11 @@ impl VectorIndex for PlainIndex {
                             scorer.peek_top_iter(&mut filtered_ids_vec.iter().copied(), top)
                         })
                     })
-                    .collect()
+                    .collect();
+                query_context.explain(|explanation| {
+                    explanation.timings.add_search(search_timer.elapsed());
+                });
+                search_result
             }
             None => {
                 let _timer = ScopeDurationMeasurer::new(&self.unfiltered_searches_telemetry);
@@ -304,18 +328,27 @@ impl VectorIndex for PlainIndex {
                 let deleted_points = query_context
                     .deleted_points()
                     .unwrap_or(id_tracker.deleted_point_bitslice());
-                vectors
+                query_context.explain(|explanation| {
+                    explanation.strategy = SearchStrategy::FullScan;
+                });
+                let search_timer = Instant::now();
+                let search_result = vectors
                     .iter()
                     .map(|&vector| {
-                        new_stoppable_raw_scorer(
+                        new_stoppable_raw_scorer_with_prefix(
                             vector.to_owned(),
                             &vector_storage,
+                            self.prefix_dim,
                             deleted_points,
                             &is_stopped,
                         )
                         .map(|scorer| scorer.peek_top_all(top))
                     })
-                    .collect()
+                    .collect();
+                query_context.explain(|explanation| {
+                    explanation.timings.add_search(search_timer.elapsed());
+                });
+                search_result
             }
         }
     }
@@ -354,6 +387,12 @@ impl VectorIndex for PlainIndex {
         id: PointOffsetType,
         vector: Option<VectorRef>,
     ) -> OperationResult<()> {
+        if self.prefix_dim.is_some() {
+            // Prefix views share the storage of their source vector, which is updated by the
+            // source index
+            return Ok(());
+        }
+
         let mut vector_storage = self.vector_storage.borrow_mut();
 
         if let Some(vector) = vector {
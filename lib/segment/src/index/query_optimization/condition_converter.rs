@@ -229,6 +229,13 @@ pub fn get_range_checkers(index: &FieldIndex, range: RangeInterface) -> Option<C
     match range {
         RangeInterface::Float(range) => get_float_range_checkers(index, range),
         RangeInterface::DateTime(range) => get_datetime_range_checkers(index, range),
+        RangeInterface::RelativeDateTime(_) => {
+            debug_assert!(
+                false,
+                "relative datetime range must be resolved before search"
+            );
+            None
+        }
     }
 }
 
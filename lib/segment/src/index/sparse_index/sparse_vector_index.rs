@@ -4,6 +4,7 @@ use std::fs::{create_dir_all, remove_dir_all, rename};
 use std::path::{Path, PathBuf};
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
+use std::time::Instant;
 
 use atomic_refcell::AtomicRefCell;
 use common::types::{PointOffsetType, ScoredPointOffset, TelemetryDetail};
@@ -23,6 +24,7 @@ use crate::common::operation_error::{check_process_stopped, OperationError, Oper
 use crate::common::operation_time_statistics::ScopeDurationMeasurer;
 use crate::data_types::named_vectors::CowVector;
 use crate::data_types::query_context::VectorQueryContext;
+use crate::data_types::search_explain::{CardinalityExplanation, SearchStrategy};
 use crate::data_types::vectors::{QueryVector, Vector, VectorRef};
 use crate::id_tracker::IdTrackerSS;
 use crate::index::field_index::CardinalityEstimation;
@@ -421,12 +423,24 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
         match filter {
             Some(filter) => {
                 // if cardinality is small - use plain search
+                let planning_timer = Instant::now();
                 let query_cardinality = self.get_query_cardinality(filter);
                 let threshold = self
                     .config
                     .full_scan_threshold
                     .unwrap_or(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD);
-                if query_cardinality.max < threshold {
+                let is_small_cardinality = query_cardinality.max < threshold;
+                vector_query_context.explain(|explanation| {
+                    explanation.strategy = if is_small_cardinality {
+                        SearchStrategy::PayloadIndex
+                    } else {
+                        SearchStrategy::Sparse
+                    };
+                    explanation.cardinality =
+                        Some(CardinalityExplanation::from(&query_cardinality));
+                    explanation.timings.add_planning(planning_timer.elapsed());
+                });
+                if is_small_cardinality {
                     let _timer =
                         ScopeDurationMeasurer::new(&self.searches_telemetry.small_cardinality);
                     self.search_plain(
@@ -443,6 +457,9 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
                 }
             }
             None => {
+                vector_query_context.explain(|explanation| {
+                    explanation.strategy = SearchStrategy::Sparse;
+                });
                 let _timer = ScopeDurationMeasurer::new(&self.searches_telemetry.unfiltered_sparse);
                 self.search_sparse(&vector, filter, top, vector_query_context)
             }
@@ -470,6 +487,13 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
                 vector_query_context,
             ),
             QueryVector::Recommend(_) | QueryVector::Discovery(_) | QueryVector::Context(_) => {
+                vector_query_context.explain(|explanation| {
+                    explanation.strategy = if filter.is_some() {
+                        SearchStrategy::PayloadIndex
+                    } else {
+                        SearchStrategy::FullScan
+                    };
+                });
                 let _timer = if filter.is_some() {
                     ScopeDurationMeasurer::new(&self.searches_telemetry.filtered_plain)
                 } else {
@@ -509,6 +533,7 @@ impl<TInvertedIndex: InvertedIndex> VectorIndex for SparseVectorIndex<TInvertedI
         _params: Option<&SearchParams>,
         query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        let search_timer = Instant::now();
         let mut results = Vec::with_capacity(vectors.len());
         let mut prefiltered_points = None;
         for vector in vectors {
@@ -535,6 +560,11 @@ impl<TInvertedIndex: InvertedIndex> VectorIndex for SparseVectorIndex<TInvertedI
 
             results.push(search_results);
         }
+        query_context.explain(|explanation| {
+            // Planning is measured separately, don't count it twice
+            let search = search_timer.elapsed().as_secs_f64() - explanation.timings.planning;
+            explanation.timings.search = search.max(0.0);
+        });
         Ok(results)
     }
 
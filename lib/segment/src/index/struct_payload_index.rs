@@ -28,12 +28,13 @@ use crate::index::visited_pool::VisitedPool;
 use crate::index::PayloadIndex;
 use crate::json_path::{JsonPath, JsonPathInterface as _};
 use crate::payload_storage::payload_storage_enum::PayloadStorageEnum;
+use crate::payload_storage::query_checker::{check_filter, check_payload};
 use crate::payload_storage::{FilterContext, PayloadStorage};
 use crate::telemetry::PayloadIndexTelemetry;
 use crate::types::{
     infer_collection_value_type, infer_value_type, Condition, FieldCondition, Filter,
-    IsEmptyCondition, IsNullCondition, Payload, PayloadContainer, PayloadField, PayloadFieldSchema,
-    PayloadKeyType, PayloadKeyTypeRef, PayloadSchemaType,
+    IsEmptyCondition, IsNullCondition, OwnedPayloadRef, Payload, PayloadContainer, PayloadField,
+    PayloadFieldSchema, PayloadKeyType, PayloadKeyTypeRef, PayloadSchemaType, PointIdType,
 };
 
 pub const PAYLOAD_FIELD_INDEX_PATH: &str = "fields";
@@ -181,7 +182,12 @@ impl StructPayloadIndex {
             index.recreate()?;
         }
 
+        let id_tracker = self.id_tracker.borrow();
         payload_storage.iter(|point_id, point_payload| {
+            // Payload of trashed points is kept in storage, but they must not be indexed
+            if id_tracker.is_deleted_point(point_id) {
+                return Ok(true);
+            }
             let field_value = &point_payload.get_value(field);
             for field_index in field_indexes.iter_mut() {
                 field_index.add_point(point_id, field_value)?;
@@ -201,6 +207,56 @@ impl StructPayloadIndex {
         Ok(())
     }
 
+    /// Remove point from all field indexes, but keep its payload in storage
+    pub fn remove_from_field_indexes(&mut self, point_id: PointOffsetType) -> OperationResult<()> {
+        for field_indexes in self.field_indexes.values_mut() {
+            for index in field_indexes {
+                index.remove_point(point_id)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Add point to all field indexes, based on its payload in storage
+    pub fn add_to_field_indexes(&mut self, point_id: PointOffsetType) -> OperationResult<()> {
+        let payload = self.payload(point_id)?;
+        for (field, field_indexes) in &mut self.field_indexes {
+            let field_value = payload.get_value(field);
+            if field_value.is_empty() {
+                continue;
+            }
+            for index in field_indexes {
+                index.add_point(point_id, &field_value)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Check if a trashed point matches the filter
+    ///
+    /// Trashed points are not in field indexes and have no ID mapping, so conditions are checked
+    /// against the stored payload and the given external ID.
+    pub fn check_trashed_point(
+        &self,
+        filter: &Filter,
+        point_id: PointIdType,
+        internal_id: PointOffsetType,
+    ) -> OperationResult<bool> {
+        let payload = self.payload(internal_id)?;
+        let checker = |condition: &Condition| match condition {
+            Condition::HasId(has_id) => has_id.has_id.contains(&point_id),
+            Condition::Resharding(resharding) => resharding.check(point_id),
+            condition => check_payload(
+                Box::new(|| OwnedPayloadRef::from(&payload)),
+                None,
+                &Filter::new_must(condition.clone()),
+                internal_id,
+                &self.field_indexes,
+            ),
+        };
+        Ok(check_filter(&checker, filter))
+    }
+
     /// Number of available points
     ///
     /// - excludes soft deleted points
@@ -525,11 +581,7 @@ impl PayloadIndex for StructPayloadIndex {
     }
 
     fn drop(&mut self, point_id: PointOffsetType) -> OperationResult<Option<Payload>> {
-        for (_, field_indexes) in self.field_indexes.iter_mut() {
-            for index in field_indexes {
-                index.remove_point(point_id)?;
-            }
-        }
+        self.remove_from_field_indexes(point_id)?;
         self.payload.borrow_mut().drop(point_id)
     }
 
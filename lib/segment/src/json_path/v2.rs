@@ -164,6 +164,19 @@ impl JsonPathInterface for JsonPathV2 {
 }
 
 impl JsonPathV2 {
+    /// Apply `f` to each value at this path.
+    ///
+    /// `f` receives `null` for a missing value. Missing object keys along the path are only
+    /// created, if `f` sets a non-null value.
+    /// Array indices out of bounds are not created, and values of incompatible types are left intact.
+    pub fn value_update(
+        &self,
+        json_map: &mut serde_json::Map<String, Value>,
+        f: &mut impl FnMut(&mut Value),
+    ) {
+        value_update_map(&self.first_key, &self.rest, json_map, f);
+    }
+
     /// Check if a path is a compatible prefix of another path or vice versa.
     pub fn compatible(&self, other: &Self) -> bool {
         if self.first_key != other.first_key {
@@ -332,6 +345,52 @@ fn value_set_map(
     }
 }
 
+fn value_update(path: &[JsonPathItem], value: &mut Value, f: &mut impl FnMut(&mut Value)) {
+    let Some((head, rest)) = path.split_first() else {
+        f(value);
+        return;
+    };
+
+    match (head, value) {
+        (JsonPathItem::Key(key), value @ Value::Null) => {
+            let mut map = serde_json::Map::new();
+            value_update_map(key, rest, &mut map, f);
+            if !map.is_empty() {
+                *value = Value::Object(map);
+            }
+        }
+        (JsonPathItem::Key(key), Value::Object(map)) => value_update_map(key, rest, map, f),
+        (JsonPathItem::Index(index), Value::Array(array)) => {
+            if let Some(value) = array.get_mut(*index) {
+                value_update(rest, value, f);
+            }
+        }
+        (JsonPathItem::WildcardIndex, Value::Array(array)) => {
+            for value in array {
+                value_update(rest, value, f);
+            }
+        }
+        _ => (),
+    }
+}
+
+fn value_update_map(
+    key: &str,
+    path: &[JsonPathItem],
+    map: &mut serde_json::Map<String, Value>,
+    f: &mut impl FnMut(&mut Value),
+) {
+    if let Some(value) = map.get_mut(key) {
+        value_update(path, value, f);
+    } else {
+        let mut value = Value::Null;
+        value_update(path, &mut value, f);
+        if !value.is_null() {
+            map.insert(key.to_string(), value);
+        }
+    }
+}
+
 fn value_remove(
     head: &JsonPathItem,
     rest: &[JsonPathItem],
@@ -498,6 +557,27 @@ mod tests {
             .is_affected_by_value_set(&serde_json::from_str(r#"{"b": {"x": 1}}"#).unwrap(), None));
     }
 
+    #[test]
+    fn test_value_update() {
+        let mut payload: serde_json::Map<String, Value> =
+            serde_json::from_str(r#"{"a": [{"b": 1}, {"b": 2}], "c": "x"}"#).unwrap();
+
+        let mut increment = |value: &mut Value| {
+            *value = Value::from(value.as_i64().unwrap_or(0) + 10);
+        };
+
+        path("a[].b").value_update(&mut payload, &mut increment);
+        path("d.e").value_update(&mut payload, &mut increment);
+        path("c.e").value_update(&mut payload, &mut increment);
+        path("a[5].b").value_update(&mut payload, &mut increment);
+        path("f[0]").value_update(&mut payload, &mut increment);
+
+        assert_eq!(
+            Value::Object(payload),
+            serde_json::json!({"a": [{"b": 11}, {"b": 12}], "c": "x", "d": {"e": 10}}),
+        );
+    }
+
     #[test]
     fn test_is_affected_by_value_remove() {
         assert!(path("a").is_affected_by_value_remove(&path("a")));
@@ -52,6 +52,13 @@ impl ValueChecker for FieldCondition {
                 .is_some_and(|range_interface| match range_interface {
                     RangeInterface::Float(condition) => condition.check_match(payload),
                     RangeInterface::DateTime(condition) => condition.check_match(payload),
+                    RangeInterface::RelativeDateTime(_) => {
+                        debug_assert!(
+                            false,
+                            "relative datetime range must be resolved before search"
+                        );
+                        false
+                    }
                 })
             || geo_radius
                 .as_ref()
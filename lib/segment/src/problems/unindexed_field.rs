@@ -90,8 +90,7 @@ impl UnindexedField {
         payload_schema: &HashMap<PayloadKeyType, PayloadFieldSchema>,
         collection_name: String,
     ) {
-        let unindexed_issues =
-            Extractor::new(filter, payload_schema, collection_name).into_issues();
+        let unindexed_issues = Extractor::new(filter, payload_schema).into_issues(collection_name);
 
         log::trace!("Found unindexed issues: {unindexed_issues:#?}");
 
@@ -99,6 +98,18 @@ impl UnindexedField {
             issue.submit();
         }
     }
+
+    /// Fields used by the filter without a fitting payload index, along with the index types
+    /// which would serve their conditions
+    pub fn get_unindexed_fields(
+        filter: &Filter,
+        payload_schema: &HashMap<PayloadKeyType, PayloadFieldSchema>,
+    ) -> HashMap<PayloadKeyType, Vec<PayloadFieldSchema>> {
+        let mut unindexed_schema = Extractor::new(filter, payload_schema).unindexed_schema;
+        // Conditions which can't be served by any index are not a reason to create one
+        unindexed_schema.retain(|_, field_schemas| !field_schemas.is_empty());
+        unindexed_schema
+    }
 }
 
 impl Issue for UnindexedField {
@@ -216,7 +227,7 @@ fn infer_schema_from_field_condition(field_condition: &FieldCondition) -> Vec<Pa
     }
     if let Some(range_interface) = range {
         match range_interface {
-            RangeInterface::DateTime(_) => {
+            RangeInterface::DateTime(_) | RangeInterface::RelativeDateTime(_) => {
                 inferred.push(PayloadFieldSchema::FieldType(PayloadSchemaType::Datetime));
             }
             RangeInterface::Float(_) => {
@@ -239,19 +250,16 @@ fn infer_schema_from_field_condition(field_condition: &FieldCondition) -> Vec<Pa
 struct Extractor<'a> {
     payload_schema: &'a HashMap<PayloadKeyType, PayloadFieldSchema>,
     unindexed_schema: HashMap<PayloadKeyType, Vec<PayloadFieldSchema>>,
-    collection_name: String,
 }
 
 impl<'a> Extractor<'a> {
     fn new(
         filter: &Filter,
         payload_schema: &'a HashMap<PayloadKeyType, PayloadFieldSchema>,
-        collection_name: String,
     ) -> Self {
         let mut extractor = Self {
             payload_schema,
             unindexed_schema: HashMap::new(),
-            collection_name,
         };
 
         extractor.update_from_filter(None, filter);
@@ -259,13 +267,13 @@ impl<'a> Extractor<'a> {
         extractor
     }
 
-    fn into_issues(self) -> Vec<UnindexedField> {
+    fn into_issues(self, collection_name: String) -> Vec<UnindexedField> {
         self.unindexed_schema
             .into_iter()
             .filter_map(|(key, field_schemas)| {
                 let field_schemas = HashSet::from_iter(field_schemas);
 
-                UnindexedField::try_new(key, field_schemas, self.collection_name.clone()).ok()
+                UnindexedField::try_new(key, field_schemas, collection_name.clone()).ok()
             })
             .collect()
     }
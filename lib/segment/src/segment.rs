@@ -7,6 +7,7 @@ use std::thread::{self, JoinHandle};
 
 use atomic_refcell::AtomicRefCell;
 use bitvec::prelude::BitVec;
+use chrono::{DateTime, Utc};
 use common::types::{PointOffsetType, ScoredPointOffset, TelemetryDetail};
 use io::file_operations::{atomic_save_json, read_json};
 use io::storage_version::{StorageVersion, VERSION_FILE};
@@ -14,6 +15,7 @@ use itertools::Either;
 use memory::mmap_ops;
 use parking_lot::{Mutex, RwLock};
 use rocksdb::DB;
+use smallvec::smallvec;
 use tar::Builder;
 use uuid::Uuid;
 
@@ -24,22 +26,24 @@ use crate::common::operation_error::{
 use crate::common::validate_snapshot_archive::open_snapshot_archive_with_validation;
 use crate::common::{check_named_vectors, check_query_vectors, check_stopped, check_vector_name};
 use crate::data_types::named_vectors::NamedVectors;
-use crate::data_types::order_by::{Direction, OrderBy, OrderValue};
+use crate::data_types::order_by::{Direction, OrderBy, OrderValue, OrderValues};
 use crate::data_types::query_context::{QueryContext, SegmentQueryContext};
 use crate::data_types::vectors::{QueryVector, Vector};
 use crate::entry::entry_point::SegmentEntry;
-use crate::id_tracker::IdTrackerSS;
+use crate::id_tracker::{IdTrackerSS, TrashedPoint};
+use crate::index::field_index::geo_index::GeoMapIndex;
 use crate::index::field_index::numeric_index::StreamRange;
-use crate::index::field_index::CardinalityEstimation;
+use crate::index::field_index::{CardinalityEstimation, NumericFieldIndex};
 use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::index::{PayloadIndex, VectorIndex, VectorIndexEnum};
 use crate::json_path::JsonPath;
 use crate::spaces::tools::{peek_top_largest_iterable, peek_top_smallest_iterable};
 use crate::telemetry::SegmentTelemetry;
 use crate::types::{
-    Filter, Payload, PayloadFieldSchema, PayloadIndexInfo, PayloadKeyType, PayloadKeyTypeRef,
-    PayloadSchemaType, PointIdType, ScoredPoint, SearchParams, SegmentConfig, SegmentInfo,
-    SegmentState, SegmentType, SeqNumberType, VectorDataInfo, WithPayload, WithVector,
+    Filter, GeoPoint, Payload, PayloadFieldSchema, PayloadIndexInfo, PayloadKeyType,
+    PayloadKeyTypeRef, PayloadSchemaType, PointIdType, ScoredPoint, SearchParams, SegmentConfig,
+    SegmentInfo, SegmentState, SegmentType, SeqNumberType, VectorDataConfig, VectorDataInfo,
+    WithPayload, WithVector,
 };
 use crate::utils;
 use crate::utils::fs::find_symlink;
@@ -108,6 +112,7 @@ impl VectorData {
 
         let storage_task = match &*self.vector_storage.borrow() {
             VectorStorageEnum::DenseMemmap(storage) => storage.prefault_mmap_pages(),
+            VectorStorageEnum::MultiDenseMemmap(storage) => storage.prefault_mmap_pages(),
             _ => None,
         };
 
@@ -380,6 +385,29 @@ impl Segment {
         Ok(applied)
     }
 
+    /// Forget the point in trash of this segment, and drop its payload
+    ///
+    /// Returns `true` if the point was in trash.
+    fn purge_trashed_point(&mut self, point_id: PointIdType) -> OperationResult<bool> {
+        let internal_id = self.id_tracker.borrow_mut().purge_trashed(point_id)?;
+        if let Some(internal_id) = internal_id {
+            self.payload_index.borrow_mut().drop(internal_id)?;
+        }
+        Ok(internal_id.is_some())
+    }
+
+    fn lookup_trashed_internal_id(
+        &self,
+        point_id: PointIdType,
+    ) -> OperationResult<PointOffsetType> {
+        match self.id_tracker.borrow().trashed_point(point_id) {
+            Some(trashed_point) => Ok(trashed_point.internal_id),
+            None => Err(OperationError::PointIdError {
+                missed_point_id: point_id,
+            }),
+        }
+    }
+
     fn bump_segment_version(&mut self, op_num: SeqNumberType) {
         self.version = Some(max(op_num, self.version.unwrap_or(0)));
     }
@@ -425,6 +453,14 @@ impl Segment {
         })
     }
 
+    /// Whether the named vector is a prefix view over the storage of another vector
+    fn is_prefix_view(&self, vector_name: &str) -> bool {
+        self.segment_config
+            .vector_data
+            .get(vector_name)
+            .map_or(false, |config| config.prefix_of.is_some())
+    }
+
     /// Retrieve vector by internal ID
     ///
     /// Returns None if the vector does not exists or deleted
@@ -458,7 +494,20 @@ impl Segment {
                     ),
                 })
             } else {
-                Ok(Some(vector_storage.get_vector(point_offset).to_owned()))
+                let vector = vector_storage.get_vector(point_offset).to_owned();
+                let prefix_dim = self
+                    .segment_config
+                    .vector_data
+                    .get(vector_name)
+                    .and_then(VectorDataConfig::prefix_dim);
+                match (vector, prefix_dim) {
+                    // Prefix views only expose the leading dimensions of the source vector
+                    (Vector::Dense(mut dense), Some(dim)) => {
+                        dense.truncate(dim);
+                        Ok(Some(Vector::Dense(dense)))
+                    }
+                    (vector, _) => Ok(Some(vector)),
+                }
             }
         } else {
             Ok(None)
@@ -471,6 +520,9 @@ impl Segment {
     ) -> OperationResult<NamedVectors> {
         let mut vectors = NamedVectors::default();
         for (vector_name, vector_data) in &self.vector_data {
+            if self.is_prefix_view(vector_name) {
+                continue;
+            }
             let is_vector_deleted = vector_data
                 .vector_storage
                 .borrow()
@@ -650,6 +702,7 @@ impl Segment {
                     vector,
                     shard_key: None,
                     order_value: None,
+                    then_order_values: Vec::new(),
                 })
             })
             .collect()
@@ -870,6 +923,135 @@ impl Segment {
         Ok(reads)
     }
 
+    /// Read points ordered by several keys or by geo distance, see [`OrderBy::then_by`] and
+    /// [`OrderBy::geo_distance_from`].
+    ///
+    /// Unlike the single-key reads, each point is returned once: with its first value of each key
+    /// in the direction of that key. Points without a value for the primary key are skipped.
+    pub fn filtered_read_by_keys_ordered(
+        &self,
+        order_by: &OrderBy,
+        limit: Option<usize>,
+        filter: Option<&Filter>,
+    ) -> OperationResult<Vec<(OrderValues, PointIdType)>> {
+        let payload_index = self.payload_index.borrow();
+        let id_tracker = self.id_tracker.borrow();
+
+        let key_indexes = order_by
+            .keys()
+            .map(|(key, direction, geo_distance_from)| {
+                let indexes = payload_index.field_indexes.get(key);
+                match geo_distance_from {
+                    Some(origin) => indexes
+                        .and_then(|indexes| indexes.iter().find_map(|index| index.as_geo()))
+                        .map(|index| OrderKeyIndex::GeoDistance(index, origin))
+                        .ok_or_else(|| OperationError::MissingGeoIndexForOrderBy {
+                            key: key.to_string(),
+                        }),
+                    None => indexes
+                        .and_then(|indexes| indexes.iter().find_map(|index| index.as_numeric()))
+                        .map(|index| OrderKeyIndex::Numeric(index, direction))
+                        .ok_or_else(|| OperationError::MissingRangeIndexForOrderBy {
+                            key: key.to_string(),
+                        }),
+                }
+            })
+            .collect::<OperationResult<Vec<_>>>()?;
+
+        let point_values = |internal_id: PointOffsetType, primary_value: OrderValue| {
+            let external_id = id_tracker.external_id(internal_id)?;
+            let secondary_values =
+                order_by
+                    .then_by
+                    .iter()
+                    .zip(&key_indexes[1..])
+                    .map(|(key, index)| {
+                        index
+                            .value(internal_id)
+                            .unwrap_or_else(|| OrderBy::missing_value(key.direction()))
+                    });
+            let values = std::iter::once(primary_value)
+                .chain(secondary_values)
+                .collect::<OrderValues>();
+            Some((values, external_id))
+        };
+
+        let cmp = |(a_values, a_id): &(OrderValues, PointIdType),
+                   (b_values, b_id): &(OrderValues, PointIdType)| {
+            order_by.cmp_points((a_values, a_id), (b_values, b_id))
+        };
+
+        let use_stream = match (&key_indexes[0], filter) {
+            (OrderKeyIndex::GeoDistance(..), _) => false,
+            (OrderKeyIndex::Numeric(..), None) => true,
+            (OrderKeyIndex::Numeric(..), Some(filter)) => !self.should_pre_filter(filter, limit),
+        };
+
+        let mut page = match &key_indexes[0] {
+            OrderKeyIndex::Numeric(numeric_index, direction) if use_stream => {
+                // Stream the primary key in order, and stop once all points tied with the last
+                // value within the limit are read, so the secondary keys can break the ties
+                let range_iter = numeric_index.stream_range(&order_by.as_range());
+                let directed_range_iter = match direction {
+                    Direction::Asc => Either::Left(range_iter),
+                    Direction::Desc => Either::Right(range_iter.rev()),
+                };
+                let filter_context = filter.map(|filter| payload_index.filter_context(filter));
+
+                let mut seen = HashSet::new();
+                let mut page: Vec<(OrderValues, PointIdType)> = Vec::new();
+                for (value, internal_id) in directed_range_iter {
+                    if limit.is_some_and(|limit| page.len() >= limit)
+                        && page.last().map(|(values, _)| values[0]) != Some(value)
+                    {
+                        break;
+                    }
+                    if filter_context
+                        .as_ref()
+                        .is_some_and(|context| !context.check(internal_id))
+                    {
+                        continue;
+                    }
+                    // The first value of a point in the stream is its best one in this direction
+                    if !seen.insert(internal_id) {
+                        continue;
+                    }
+                    page.extend(point_values(internal_id, value));
+                }
+                page
+            }
+            primary_index => {
+                let candidates = match filter {
+                    None => Either::Left(id_tracker.iter_ids()),
+                    Some(filter) => Either::Right(payload_index.query_points(filter).into_iter()),
+                };
+                let mut page: Vec<_> = candidates
+                    .filter_map(|internal_id| {
+                        let value = primary_index.value(internal_id)?;
+                        if !order_by.is_from_start(&value) {
+                            return None;
+                        }
+                        point_values(internal_id, value)
+                    })
+                    .collect();
+                if let Some(limit) = limit {
+                    if page.len() > limit {
+                        page.select_nth_unstable_by(limit, cmp);
+                        page.truncate(limit);
+                    }
+                }
+                page
+            }
+        };
+
+        page.sort_unstable_by(cmp);
+        if let Some(limit) = limit {
+            page.truncate(limit);
+        }
+
+        Ok(page)
+    }
+
     /// Check consistency of the segment's data and repair it if possible.
     pub fn check_consistency_and_repair(&mut self) -> OperationResult<()> {
         let mut internal_ids_to_delete = HashSet::new();
@@ -1149,6 +1331,8 @@ impl SegmentEntry for Segment {
                 segment.replace_all_vectors(existing_internal_id, vectors)?;
                 Ok((true, Some(existing_internal_id)))
             } else {
+                // The new point replaces the one in trash of this segment
+                segment.purge_trashed_point(point_id)?;
                 let new_index = segment.insert_new_vectors(point_id, vectors)?;
                 Ok((false, Some(new_index)))
             }
@@ -1162,8 +1346,17 @@ impl SegmentEntry for Segment {
     ) -> OperationResult<bool> {
         let internal_id = self.id_tracker.borrow().internal_id(point_id);
         match internal_id {
-            // Point does already not exist anymore
-            None => Ok(false),
+            // Point does already not exist anymore, but it may still be in trash
+            None => {
+                let trashed_point = self.id_tracker.borrow().trashed_point(point_id);
+                match trashed_point {
+                    Some(trashed_point) if trashed_point.version <= op_num => self
+                        .handle_point_version_and_failure(op_num, None, |segment| {
+                            Ok((segment.purge_trashed_point(point_id)?, None))
+                        }),
+                    _ => Ok(false),
+                }
+            }
             Some(internal_id) => {
                 self.handle_point_version_and_failure(op_num, Some(internal_id), |segment| {
                     // Mark point as deleted, drop mapping
@@ -1217,6 +1410,13 @@ impl SegmentEntry for Segment {
         vector_name: &str,
     ) -> OperationResult<bool> {
         check_vector_name(vector_name, &self.segment_config)?;
+        if self.is_prefix_view(vector_name) {
+            return Err(OperationError::ValidationError {
+                description: format!(
+                    "Cannot delete vector {vector_name}, it is a prefix view of another vector"
+                ),
+            });
+        }
         let internal_id = self.id_tracker.borrow().internal_id(point_id);
         match internal_id {
             None => Err(OperationError::PointIdError {
@@ -1318,6 +1518,92 @@ impl SegmentEntry for Segment {
         })
     }
 
+    fn trash_point(
+        &mut self,
+        op_num: SeqNumberType,
+        point_id: PointIdType,
+        trashed_at: DateTime<Utc>,
+    ) -> OperationResult<bool> {
+        let internal_id = self.id_tracker.borrow().internal_id(point_id);
+        match internal_id {
+            // Point does already not exist anymore
+            None => Ok(false),
+            Some(internal_id) => {
+                self.handle_point_version_and_failure(op_num, Some(internal_id), |segment| {
+                    // Hide point like a deleted one, but keep its vectors and payload,
+                    // so it can be restored
+                    segment
+                        .payload_index
+                        .borrow_mut()
+                        .remove_from_field_indexes(internal_id)?;
+                    segment
+                        .id_tracker
+                        .borrow_mut()
+                        .trash(point_id, op_num, trashed_at)?;
+
+                    Ok((true, None))
+                })
+            }
+        }
+    }
+
+    fn restore_point(
+        &mut self,
+        op_num: SeqNumberType,
+        point_id: PointIdType,
+    ) -> OperationResult<bool> {
+        debug_assert!(self.is_appendable());
+        let trashed_point = self.id_tracker.borrow().trashed_point(point_id);
+        match trashed_point {
+            // Point is not in trash, or it was moved to trash by a later operation
+            None => Ok(false),
+            Some(trashed_point) if trashed_point.version > op_num => Ok(false),
+            Some(_) => self.handle_point_version_and_failure(op_num, None, |segment| {
+                let internal_id = segment.id_tracker.borrow_mut().restore(point_id)?;
+                if let Some(internal_id) = internal_id {
+                    segment
+                        .payload_index
+                        .borrow_mut()
+                        .add_to_field_indexes(internal_id)?;
+                }
+                Ok((internal_id.is_some(), internal_id))
+            }),
+        }
+    }
+
+    fn trashed_point(&self, point_id: PointIdType) -> Option<TrashedPoint> {
+        self.id_tracker.borrow().trashed_point(point_id)
+    }
+
+    fn trashed_points(&self, filter: Option<&Filter>) -> Vec<PointIdType> {
+        let id_tracker = self.id_tracker.borrow();
+        let payload_index = self.payload_index.borrow();
+        id_tracker
+            .iter_trashed()
+            .filter(|(point_id, trashed_point)| {
+                filter.map_or(true, |filter| {
+                    payload_index
+                        .check_trashed_point(filter, *point_id, trashed_point.internal_id)
+                        .unwrap_or_else(|err| {
+                            log::error!("Failed to check trashed point {point_id}: {err}");
+                            false
+                        })
+                })
+            })
+            .map(|(point_id, _)| point_id)
+            .collect()
+    }
+
+    fn trashed_vectors(&self, point_id: PointIdType) -> OperationResult<NamedVectors> {
+        let internal_id = self.lookup_trashed_internal_id(point_id)?;
+        self.all_vectors_by_offset(internal_id)
+    }
+
+    fn trashed_payload(&self, point_id: PointIdType) -> OperationResult<Payload> {
+        let internal_id = self.lookup_trashed_internal_id(point_id)?;
+        self.payload_by_offset(internal_id)
+    }
+
     fn vector(&self, vector_name: &str, point_id: PointIdType) -> OperationResult<Option<Vector>> {
         check_vector_name(vector_name, &self.segment_config)?;
         let internal_id = self.lookup_internal_id(point_id)?;
@@ -1328,6 +1614,9 @@ impl SegmentEntry for Segment {
     fn all_vectors(&self, point_id: PointIdType) -> OperationResult<NamedVectors> {
         let mut result = NamedVectors::default();
         for vector_name in self.vector_data.keys() {
+            if self.is_prefix_view(vector_name) {
+                continue;
+            }
             if let Some(vec) = self.vector(vector_name, point_id)? {
                 result.insert(vector_name.clone(), vec);
             }
@@ -1371,8 +1660,12 @@ impl SegmentEntry for Segment {
         limit: Option<usize>,
         filter: Option<&'a Filter>,
         order_by: &'a OrderBy,
-    ) -> OperationResult<Vec<(OrderValue, PointIdType)>> {
-        match filter {
+    ) -> OperationResult<Vec<(OrderValues, PointIdType)>> {
+        if !order_by.is_single_numeric_key() {
+            return self.filtered_read_by_keys_ordered(order_by, limit, filter);
+        }
+
+        let reads = match filter {
             None => self.filtered_read_by_value_stream(order_by, limit, None),
             Some(filter) => {
                 if self.should_pre_filter(filter, limit) {
@@ -1381,7 +1674,12 @@ impl SegmentEntry for Segment {
                     self.filtered_read_by_value_stream(order_by, limit, Some(filter))
                 }
             }
-        }
+        }?;
+
+        Ok(reads
+            .into_iter()
+            .map(|(value, point_id)| (smallvec![value], point_id))
+            .collect())
     }
 
     fn read_range(&self, from: Option<PointIdType>, to: Option<PointIdType>) -> Vec<PointIdType> {
@@ -1532,6 +1830,7 @@ impl SegmentEntry for Segment {
             .collect();
         let state = self.get_state();
         let current_path = self.current_path.clone();
+        let id_tracker_trash_flusher = self.id_tracker.borrow().trash_flusher();
         let id_tracker_mapping_flusher = self.id_tracker.borrow().mapping_flusher();
         let payload_index_flusher = self.payload_index.borrow().flusher();
         let id_tracker_versions_flusher = self.id_tracker.borrow().versions_flusher();
@@ -1585,7 +1884,12 @@ impl SegmentEntry for Segment {
         //  400
 
         let flush_op = move || {
-            // Flush mapping first to prevent having orphan internal ids.
+            // Flush trash before mapping, so a point moved to trash is never lost.
+            // Outdated trash entries of points with persisted mapping are discarded on load.
+            id_tracker_trash_flusher().map_err(|err| {
+                OperationError::service_error(format!("Failed to flush id_tracker trash: {err}"))
+            })?;
+            // Then flush mapping to prevent having orphan internal ids.
             id_tracker_mapping_flusher().map_err(|err| {
                 OperationError::service_error(format!("Failed to flush id_tracker mapping: {err}"))
             })?;
@@ -1770,7 +2074,7 @@ impl SegmentEntry for Segment {
 
         let files = Path::new(SNAPSHOT_PATH).join(SNAPSHOT_FILES_PATH);
 
-        for vector_data in self.vector_data.values() {
+        for (vector_name, vector_data) in &self.vector_data {
             for file in vector_data.vector_index.borrow().files() {
                 utils::tar::append_file_relative_to_base(
                     &mut builder,
@@ -1780,7 +2084,14 @@ impl SegmentEntry for Segment {
                 )?;
             }
 
-            for file in vector_data.vector_storage.borrow().files() {
+            // Storage of prefix views is archived with their source vector
+            let storage_files = if self.is_prefix_view(vector_name) {
+                vec![]
+            } else {
+                vector_data.vector_storage.borrow().files()
+            };
+
+            for file in storage_files {
                 utils::tar::append_file_relative_to_base(
                     &mut builder,
                     &self.current_path,
@@ -1868,6 +2179,29 @@ impl SegmentEntry for Segment {
     }
 }
 
+/// Index used to read the values of one key of a compound `order_by`
+enum OrderKeyIndex<'a> {
+    Numeric(NumericFieldIndex<'a>, Direction),
+    GeoDistance(&'a GeoMapIndex, &'a GeoPoint),
+}
+
+impl<'a> OrderKeyIndex<'a> {
+    /// First value of the point in the key's direction, or its distance to the closest geo value
+    fn value(&self, internal_id: PointOffsetType) -> Option<OrderValue> {
+        match self {
+            OrderKeyIndex::Numeric(index, Direction::Asc) => {
+                index.get_ordering_values(internal_id).min()
+            }
+            OrderKeyIndex::Numeric(index, Direction::Desc) => {
+                index.get_ordering_values(internal_id).max()
+            }
+            OrderKeyIndex::GeoDistance(index, origin) => {
+                OrderValue::closest_geo_distance(origin, index.get_values(internal_id)?)
+            }
+        }
+    }
+}
+
 impl Drop for Segment {
     fn drop(&mut self) {
         if let Err(flushing_err) = self.lock_flushing() {
@@ -1936,8 +2270,10 @@ mod tests {
                     storage_type: VectorStorageType::Memory,
                     index: Indexes::Plain {},
                     quantization_config: None,
+                    additional_quantization_configs: vec![],
                     multivector_config: None,
                     datatype: None,
+                    prefix_of: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
@@ -2010,8 +2346,10 @@ mod tests {
                     storage_type: VectorStorageType::Memory,
                     index: Indexes::Plain {},
                     quantization_config: None,
+                    additional_quantization_configs: vec![],
                     multivector_config: None,
                     datatype: None,
+                    prefix_of: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
@@ -2102,8 +2440,10 @@ mod tests {
                     storage_type: VectorStorageType::Memory,
                     index: Indexes::Plain {},
                     quantization_config: None,
+                    additional_quantization_configs: vec![],
                     multivector_config: None,
                     datatype: None,
+                    prefix_of: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
@@ -2198,8 +2538,10 @@ mod tests {
                     storage_type: VectorStorageType::Memory,
                     index: Indexes::Plain {},
                     quantization_config: None,
+                    additional_quantization_configs: vec![],
                     multivector_config: None,
                     datatype: None,
+                    prefix_of: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
@@ -2232,8 +2574,10 @@ mod tests {
                     storage_type: VectorStorageType::Memory,
                     index: Indexes::Plain {},
                     quantization_config: None,
+                    additional_quantization_configs: vec![],
                     multivector_config: None,
                     datatype: None,
+                    prefix_of: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
@@ -2327,8 +2671,10 @@ mod tests {
                     storage_type: VectorStorageType::Memory,
                     index: Indexes::Plain {},
                     quantization_config: None,
+                    additional_quantization_configs: vec![],
                     multivector_config: None,
                     datatype: None,
+                    prefix_of: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
@@ -2382,8 +2728,10 @@ mod tests {
                         storage_type: VectorStorageType::Memory,
                         index: Indexes::Plain {},
                         quantization_config: None,
+                        additional_quantization_configs: vec![],
                         multivector_config: None,
                         datatype: None,
+                        prefix_of: None,
                     },
                 ),
                 (
@@ -2394,8 +2742,10 @@ mod tests {
                         storage_type: VectorStorageType::Memory,
                         index: Indexes::Plain {},
                         quantization_config: None,
+                        additional_quantization_configs: vec![],
                         multivector_config: None,
                         datatype: None,
+                        prefix_of: None,
                     },
                 ),
             ]),
@@ -2499,8 +2849,10 @@ mod tests {
                         storage_type: VectorStorageType::Memory,
                         index: Indexes::Plain {},
                         quantization_config: None,
+                        additional_quantization_configs: vec![],
                         multivector_config: None,
                         datatype: None,
+                        prefix_of: None,
                     },
                 ),
                 (
@@ -2511,8 +2863,10 @@ mod tests {
                         storage_type: VectorStorageType::Memory,
                         index: Indexes::Plain {},
                         quantization_config: None,
+                        additional_quantization_configs: vec![],
                         multivector_config: None,
                         datatype: None,
+                        prefix_of: None,
                     },
                 ),
             ]),
@@ -2673,8 +3027,10 @@ mod tests {
                     storage_type: VectorStorageType::Memory,
                     index: Indexes::Plain {},
                     quantization_config: None,
+                    additional_quantization_configs: vec![],
                     multivector_config: None,
                     datatype: None,
+                    prefix_of: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
@@ -2717,4 +3073,88 @@ mod tests {
             .unwrap();
         assert!(applied);
     }
+
+    #[test]
+    fn test_trash_and_restore_point() {
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let dim = 2;
+        let config = SegmentConfig {
+            vector_data: HashMap::from([(
+                DEFAULT_VECTOR_NAME.to_owned(),
+                VectorDataConfig {
+                    size: dim,
+                    distance: Distance::Dot,
+                    storage_type: VectorStorageType::Memory,
+                    index: Indexes::Plain {},
+                    quantization_config: None,
+                    additional_quantization_configs: vec![],
+                    multivector_config: None,
+                    datatype: None,
+                    prefix_of: None,
+                },
+            )]),
+            sparse_vector_data: Default::default(),
+            payload_storage_type: Default::default(),
+        };
+
+        let mut segment = build_segment(dir.path(), &config, true).unwrap();
+        let color: JsonPath = "color".parse().unwrap();
+        segment
+            .create_field_index(0, &color, Some(&PayloadSchemaType::Keyword.into()))
+            .unwrap();
+
+        let payload: Payload = serde_json::from_str(r#"{"color": "red"}"#).unwrap();
+        for id in [1u64, 2] {
+            segment
+                .upsert_point(1, id.into(), only_default_vector(&[1.0, id as f32]))
+                .unwrap();
+            segment.set_full_payload(1, id.into(), &payload).unwrap();
+        }
+
+        let filter: Filter =
+            serde_json::from_str(r#"{"must": [{"key": "color", "match": {"value": "red"}}]}"#)
+                .unwrap();
+
+        let trashed_at = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        assert!(segment.trash_point(2, 1.into(), trashed_at).unwrap());
+
+        // Trashed point is hidden, but its data is kept
+        assert!(!segment.has_point(1.into()));
+        assert_eq!(segment.available_point_count(), 1);
+        assert_eq!(
+            segment.read_filtered(None, None, Some(&filter)),
+            vec![2.into()]
+        );
+        assert_eq!(segment.trashed_payload(1.into()).unwrap(), payload);
+        assert_eq!(
+            segment.trashed_vectors(1.into()).unwrap(),
+            only_default_vector(&[1.0, 1.0]),
+        );
+        assert_eq!(segment.trashed_points(Some(&filter)), vec![1.into()]);
+        assert_eq!(
+            segment
+                .trashed_point(1.into())
+                .map(|point| point.trashed_at),
+            Some(trashed_at),
+        );
+
+        // Operations older than moving to trash don't restore the point
+        assert!(!segment.restore_point(1, 1.into()).unwrap());
+
+        assert!(segment.restore_point(3, 1.into()).unwrap());
+        assert!(segment.has_point(1.into()));
+        assert_eq!(segment.point_version(1.into()), Some(3));
+        assert_eq!(segment.payload(1.into()).unwrap(), payload);
+        assert_eq!(
+            segment.read_filtered(None, None, Some(&filter)),
+            vec![1.into(), 2.into()],
+        );
+        assert!(segment.trashed_points(None).is_empty());
+
+        // Deleting a trashed point purges it from trash
+        assert!(segment.trash_point(4, 2.into(), trashed_at).unwrap());
+        assert!(segment.delete_point(5, 2.into()).unwrap());
+        assert!(segment.trashed_point(2.into()).is_none());
+        assert!(!segment.restore_point(6, 2.into()).unwrap());
+    }
 }
@@ -5,6 +5,7 @@ use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 
 use atomic_refcell::AtomicRefCell;
+use chrono::{DateTime, Utc};
 use common::cpu::CpuPermit;
 use io::storage_version::StorageVersion;
 
@@ -17,7 +18,7 @@ use super::{
 use crate::common::error_logging::LogError;
 use crate::common::operation_error::{check_process_stopped, OperationError, OperationResult};
 use crate::entry::entry_point::SegmentEntry;
-use crate::id_tracker::{IdTracker, IdTrackerEnum};
+use crate::id_tracker::{IdTracker, IdTrackerEnum, TrashedPoint};
 use crate::index::sparse_index::sparse_vector_index::SparseVectorIndexOpenArgs;
 use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::index::PayloadIndex;
@@ -26,7 +27,7 @@ use crate::payload_storage::PayloadStorage;
 use crate::segment::{Segment, SegmentVersion};
 use crate::segment_constructor::load_segment;
 use crate::types::{
-    PayloadFieldSchema, PayloadKeyType, SegmentConfig, SegmentState, SeqNumberType,
+    PayloadFieldSchema, PayloadKeyType, PointIdType, SegmentConfig, SegmentState, SeqNumberType,
 };
 use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
 use crate::vector_storage::{VectorStorage, VectorStorageEnum};
@@ -44,6 +45,8 @@ pub struct SegmentBuilder {
     // Path to the temporary segment directory
     temp_path: PathBuf,
     indexed_fields: HashMap<PayloadKeyType, PayloadFieldSchema>,
+    // Points moved to trash after this time are kept in the new segment, others are purged
+    trashed_after: Option<DateTime<Utc>>,
 }
 
 impl SegmentBuilder {
@@ -67,6 +70,11 @@ impl SegmentBuilder {
         let mut vector_storages = HashMap::new();
 
         for (vector_name, vector_config) in &segment_config.vector_data {
+            // Prefix views have no storage of their own
+            if vector_config.prefix_of.is_some() {
+                continue;
+            }
+
             let vector_storage_path = get_vector_storage_path(&temp_path, vector_name);
             let vector_storage = open_vector_storage(
                 &database,
@@ -79,13 +87,15 @@ impl SegmentBuilder {
             vector_storages.insert(vector_name.to_owned(), vector_storage);
         }
 
-        #[allow(clippy::for_kv_map)]
-        for (vector_name, _sparse_vector_config) in &segment_config.sparse_vector_data {
-            // `_sparse_vector_config` should be used, once we are able to initialize storage with
-            // different datatypes
-
-            let vector_storage =
-                create_sparse_vector_storage(database.clone(), vector_name, &stopped)?;
+        for (vector_name, sparse_vector_config) in &segment_config.sparse_vector_data {
+            let vector_storage_path = get_vector_storage_path(&temp_path, vector_name);
+            let vector_storage = create_sparse_vector_storage(
+                database.clone(),
+                &vector_storage_path,
+                vector_name,
+                sparse_vector_config.storage_type,
+                &stopped,
+            )?;
             vector_storages.insert(vector_name.to_owned(), vector_storage);
         }
 
@@ -101,6 +111,7 @@ impl SegmentBuilder {
             destination_path,
             temp_path,
             indexed_fields: Default::default(),
+            trashed_after: None,
         })
     }
 
@@ -112,7 +123,15 @@ impl SegmentBuilder {
         self.indexed_fields.insert(field, schema);
     }
 
+    /// Keep points, which were moved to trash after the given time, in the new segment
+    ///
+    /// By default, all points in trash are purged.
+    pub fn retain_trash(&mut self, trashed_after: DateTime<Utc>) {
+        self.trashed_after = Some(trashed_after);
+    }
+
     /// Update current segment builder with all (not deleted) vectors and payload form `other` segment
+    /// and with its retained points in trash.
     /// Perform index building at the end of update
     ///
     /// # Arguments
@@ -130,12 +149,36 @@ impl SegmentBuilder {
         let other_vector_storages: HashMap<_, _> = other
             .vector_data
             .iter()
+            .filter(|(vector_name, _)| {
+                other
+                    .segment_config
+                    .vector_data
+                    .get(*vector_name)
+                    .map_or(true, |config| config.prefix_of.is_none())
+            })
             .map(|(vector_name, vector_data)| {
                 (vector_name.to_owned(), vector_data.vector_storage.borrow())
             })
             .collect();
         let other_payload_index = other.payload_index.borrow();
 
+        let retained_trash: Vec<(PointIdType, TrashedPoint)> = match self.trashed_after {
+            Some(trashed_after) => other_id_tracker
+                .iter_trashed()
+                .filter(|(_, trashed_point)| trashed_point.trashed_at > trashed_after)
+                .collect(),
+            None => Vec::new(),
+        };
+        // Points in trash are copied after all available points
+        let other_points = || {
+            other_id_tracker
+                .iter_ids()
+                .map(|internal_id| (internal_id, None))
+                .chain(retained_trash.iter().map(|&(point_id, trashed_point)| {
+                    (trashed_point.internal_id, Some((point_id, trashed_point)))
+                }))
+        };
+
         let id_tracker = &mut self.id_tracker;
 
         if self.vector_storages.len() != other_vector_storages.len() {
@@ -154,7 +197,7 @@ impl SegmentBuilder {
             })?;
             let internal_range = vector_storage.update_from(
                 other_vector_storage,
-                &mut other_id_tracker.iter_ids(),
+                &mut other_points().map(|(internal_id, _)| internal_id),
                 stopped,
             )?;
             match new_internal_range.clone() {
@@ -170,70 +213,86 @@ impl SegmentBuilder {
         }
 
         if let Some(new_internal_range) = new_internal_range {
-            let internal_id_iter = new_internal_range.zip(other_id_tracker.iter_ids());
+            let internal_id_iter = new_internal_range.zip(other_points());
 
-            for (new_internal_id, old_internal_id) in internal_id_iter {
+            for (new_internal_id, (old_internal_id, trashed)) in internal_id_iter {
                 check_process_stopped(stopped)?;
 
-                let external_id =
-                    if let Some(external_id) = other_id_tracker.external_id(old_internal_id) {
-                        external_id
-                    } else {
-                        log::warn!(
-                            "Cannot find external id for internal id {old_internal_id}, skipping"
-                        );
-                        continue;
-                    };
-
-                let other_version = other_id_tracker
-                    .internal_version(old_internal_id)
-                    .unwrap_or_else(|| {
-                        log::debug!(
-                            "Internal version not found for internal id {old_internal_id}, using 0"
-                        );
-                        0
-                    });
-
-                match id_tracker.internal_id(external_id) {
+                let (external_id, other_version) = match trashed {
+                    Some((external_id, trashed_point)) => (external_id, trashed_point.version),
                     None => {
-                        // New point, just insert
-                        id_tracker.set_link(external_id, new_internal_id)?;
-                        id_tracker.set_internal_version(new_internal_id, other_version)?;
-                        let other_payload = other_payload_index.payload(old_internal_id)?;
-                        // Propagate payload to new segment
-                        if !other_payload.is_empty() {
-                            self.payload_storage
-                                .assign(new_internal_id, &other_payload)?;
-                        }
-                    }
-                    Some(existing_internal_id) => {
-                        // Point exists in both: newly constructed and old segments, so we need to merge them
-                        // Based on version
-                        let existing_version =
-                            id_tracker.internal_version(existing_internal_id).unwrap();
-                        let remove_id = if existing_version < other_version {
-                            // Other version is the newest, remove the existing one and replace
-                            id_tracker.drop(external_id)?;
-                            id_tracker.set_link(external_id, new_internal_id)?;
-                            id_tracker.set_internal_version(new_internal_id, other_version)?;
-                            self.payload_storage.drop(existing_internal_id)?;
-                            let other_payload = other_payload_index.payload(old_internal_id)?;
-                            // Propagate payload to new segment
-                            if !other_payload.is_empty() {
-                                self.payload_storage
-                                    .assign(new_internal_id, &other_payload)?;
-                            }
-                            existing_internal_id
+                        let external_id = if let Some(external_id) =
+                            other_id_tracker.external_id(old_internal_id)
+                        {
+                            external_id
                         } else {
-                            // Old version is still good, do not move anything else
-                            // Mark newly added vector as removed
-                            new_internal_id
+                            log::warn!(
+                                "Cannot find external id for internal id {old_internal_id}, skipping"
+                            );
+                            continue;
                         };
+
+                        let other_version = other_id_tracker
+                            .internal_version(old_internal_id)
+                            .unwrap_or_else(|| {
+                                log::debug!(
+                                    "Internal version not found for internal id {old_internal_id}, using 0"
+                                );
+                                0
+                            });
+
+                        (external_id, other_version)
+                    }
+                };
+
+                // Point may exist in both: newly constructed and old segments, either available
+                // or in trash, so we need to merge them based on version
+                let existing = match id_tracker.internal_id(external_id) {
+                    Some(existing_internal_id) => Some((
+                        existing_internal_id,
+                        id_tracker.internal_version(existing_internal_id).unwrap(),
+                    )),
+                    None => id_tracker
+                        .trashed_point(external_id)
+                        .map(|trashed_point| (trashed_point.internal_id, trashed_point.version)),
+                };
+
+                if let Some((_, existing_version)) = existing {
+                    if existing_version >= other_version {
+                        // Old version is still good, do not move anything else
+                        // Mark newly added vector as removed
                         for vector_storage in self.vector_storages.values_mut() {
-                            vector_storage.delete_vector(remove_id)?;
+                            vector_storage.delete_vector(new_internal_id)?;
                         }
+                        continue;
                     }
                 }
+
+                if let Some((existing_internal_id, _)) = existing {
+                    // Other version is the newest, remove the existing one and replace
+                    id_tracker.drop(external_id)?;
+                    id_tracker.purge_trashed(external_id)?;
+                    self.payload_storage.drop(existing_internal_id)?;
+                    for vector_storage in self.vector_storages.values_mut() {
+                        vector_storage.delete_vector(existing_internal_id)?;
+                    }
+                }
+
+                id_tracker.set_link(external_id, new_internal_id)?;
+                id_tracker.set_internal_version(new_internal_id, other_version)?;
+                let other_payload = other_payload_index.payload(old_internal_id)?;
+                // Propagate payload to new segment
+                if !other_payload.is_empty() {
+                    self.payload_storage
+                        .assign(new_internal_id, &other_payload)?;
+                }
+                if let Some((_, trashed_point)) = trashed {
+                    id_tracker.trash(
+                        external_id,
+                        trashed_point.version,
+                        trashed_point.trashed_at,
+                    )?;
+                }
             }
         }
 
@@ -241,6 +300,7 @@ impl SegmentBuilder {
             self.indexed_fields.insert(field, payload_schema);
         }
 
+        id_tracker.trash_flusher()()?;
         id_tracker.mapping_flusher()()?;
         id_tracker.versions_flusher()()?;
 
@@ -258,6 +318,7 @@ impl SegmentBuilder {
                 destination_path,
                 temp_path,
                 indexed_fields,
+                trashed_after: _,
             } = self;
 
             let appendable_flag = segment_config.is_appendable();
@@ -265,6 +326,7 @@ impl SegmentBuilder {
             payload_storage.flusher()()?;
             let payload_storage_arc = Arc::new(AtomicRefCell::new(payload_storage));
 
+            id_tracker.trash_flusher()()?;
             id_tracker.mapping_flusher()()?;
             id_tracker.versions_flusher()()?;
             let id_tracker_arc = Arc::new(AtomicRefCell::new(id_tracker));
@@ -297,7 +359,13 @@ impl SegmentBuilder {
                 stopped,
             )?;
 
+            let mut vector_storage_arcs = HashMap::new();
+
             for (vector_name, vector_config) in &segment_config.vector_data {
+                if vector_config.prefix_of.is_some() {
+                    continue;
+                }
+
                 let vector_index_path = get_vector_index_path(&temp_path, vector_name);
 
                 let Some(vector_storage) = vector_storages.remove(vector_name) else {
@@ -317,12 +385,40 @@ impl SegmentBuilder {
                     vector_config,
                     &vector_index_path,
                     id_tracker_arc.clone(),
-                    vector_storage_arc,
+                    vector_storage_arc.clone(),
                     payload_index_arc.clone(),
                     quantized_vectors_arc,
                     Some(permit.clone()),
                     stopped,
                 )?;
+
+                vector_storage_arcs.insert(vector_name.to_owned(), vector_storage_arc);
+            }
+
+            // Prefix views are indexed over the storage of their source vector
+            for (vector_name, vector_config) in &segment_config.vector_data {
+                let Some(source_name) = &vector_config.prefix_of else {
+                    continue;
+                };
+
+                let vector_index_path = get_vector_index_path(&temp_path, vector_name);
+
+                let Some(vector_storage_arc) = vector_storage_arcs.get(source_name) else {
+                    return Err(OperationError::service_error(format!(
+                        "Source vector storage {source_name} for prefix view {vector_name} not found on segment build"
+                    )));
+                };
+
+                create_vector_index(
+                    vector_config,
+                    &vector_index_path,
+                    id_tracker_arc.clone(),
+                    vector_storage_arc.clone(),
+                    payload_index_arc.clone(),
+                    Arc::new(AtomicRefCell::new(None)),
+                    Some(permit.clone()),
+                    stopped,
+                )?;
             }
 
             for (vector_name, sparse_vector_config) in &segment_config.sparse_vector_data {
@@ -417,7 +513,7 @@ impl SegmentBuilder {
 
                 let vector_storage_path = get_vector_storage_path(segment_path, vector_name);
 
-                let quantized_vectors = QuantizedVectors::create(
+                let mut quantized_vectors = QuantizedVectors::create(
                     vector_storage,
                     quantization,
                     &vector_storage_path,
@@ -425,6 +521,13 @@ impl SegmentBuilder {
                     stopped,
                 )?;
 
+                quantized_vectors.create_additional(
+                    vector_storage,
+                    config.additional_quantization_configs(vector_name),
+                    max_threads,
+                    stopped,
+                )?;
+
                 quantized_vectors_map.insert(vector_name.to_owned(), quantized_vectors);
             }
         }
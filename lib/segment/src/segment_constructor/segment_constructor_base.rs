@@ -33,26 +33,39 @@ use crate::payload_storage::simple_payload_storage::SimplePayloadStorage;
 use crate::segment::{Segment, SegmentVersion, VectorData, SEGMENT_STATE_FILE};
 use crate::types::{
     Distance, Indexes, PayloadStorageType, SegmentConfig, SegmentState, SegmentType, SeqNumberType,
-    VectorDataConfig, VectorStorageDatatype, VectorStorageType,
+    SparseVectorStorageType, VectorDataConfig, VectorStorageDatatype, VectorStorageType,
 };
 use crate::vector_storage::dense::appendable_mmap_dense_vector_storage::{
-    open_appendable_memmap_vector_storage, open_appendable_memmap_vector_storage_byte,
-    open_appendable_memmap_vector_storage_half,
+    open_appendable_memmap_vector_storage, open_appendable_memmap_vector_storage_bf16,
+    open_appendable_memmap_vector_storage_byte, open_appendable_memmap_vector_storage_half,
+    open_appendable_memmap_vector_storage_int8,
 };
 use crate::vector_storage::dense::memmap_dense_vector_storage::{
-    open_memmap_vector_storage, open_memmap_vector_storage_byte, open_memmap_vector_storage_half,
+    open_memmap_vector_storage, open_memmap_vector_storage_bf16, open_memmap_vector_storage_byte,
+    open_memmap_vector_storage_half, open_memmap_vector_storage_int8,
 };
 use crate::vector_storage::dense::simple_dense_vector_storage::{
-    open_simple_dense_byte_vector_storage, open_simple_dense_half_vector_storage,
+    open_simple_dense_bf16_vector_storage, open_simple_dense_byte_vector_storage,
+    open_simple_dense_half_vector_storage, open_simple_dense_int8_vector_storage,
     open_simple_dense_vector_storage,
 };
+use crate::vector_storage::mmap_sparse_vector_storage::open_mmap_sparse_vector_storage;
 use crate::vector_storage::multi_dense::appendable_mmap_multi_dense_vector_storage::{
-    open_appendable_memmap_multi_vector_storage, open_appendable_memmap_multi_vector_storage_byte,
+    appendable_memmap_multi_vector_storage_exists, open_appendable_memmap_multi_vector_storage,
+    open_appendable_memmap_multi_vector_storage_bf16,
+    open_appendable_memmap_multi_vector_storage_byte,
     open_appendable_memmap_multi_vector_storage_half,
+    open_appendable_memmap_multi_vector_storage_int8,
+};
+use crate::vector_storage::multi_dense::memmap_multi_dense_vector_storage::{
+    open_memmap_multi_vector_storage, open_memmap_multi_vector_storage_bf16,
+    open_memmap_multi_vector_storage_byte, open_memmap_multi_vector_storage_half,
+    open_memmap_multi_vector_storage_int8,
 };
 use crate::vector_storage::multi_dense::simple_multi_dense_vector_storage::{
-    open_simple_multi_dense_vector_storage, open_simple_multi_dense_vector_storage_byte,
-    open_simple_multi_dense_vector_storage_half,
+    open_simple_multi_dense_vector_storage, open_simple_multi_dense_vector_storage_bf16,
+    open_simple_multi_dense_vector_storage_byte, open_simple_multi_dense_vector_storage_half,
+    open_simple_multi_dense_vector_storage_int8,
 };
 use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
 use crate::vector_storage::simple_sparse_vector_storage::open_simple_sparse_vector_storage;
@@ -93,6 +106,7 @@ pub(crate) fn open_vector_storage(
     vector_name: &str,
 ) -> OperationResult<VectorStorageEnum> {
     let storage_element_type = vector_config.datatype.unwrap_or_default();
+    let dim = vector_config.storage_dim();
 
     match vector_config.storage_type {
         // In memory
@@ -104,23 +118,41 @@ pub(crate) fn open_vector_storage(
                     VectorStorageDatatype::Float32 => open_simple_multi_dense_vector_storage(
                         database.clone(),
                         &db_column_name,
-                        vector_config.size,
+                        dim,
                         vector_config.distance,
                         *multi_vec_config,
                         stopped,
                     ),
-                    VectorStorageDatatype::Uint8 => open_simple_multi_dense_vector_storage_byte(
+                    VectorStorageDatatype::Uint8 | VectorStorageDatatype::Bit => {
+                        open_simple_multi_dense_vector_storage_byte(
+                            database.clone(),
+                            &db_column_name,
+                            dim,
+                            vector_config.distance,
+                            *multi_vec_config,
+                            stopped,
+                        )
+                    }
+                    VectorStorageDatatype::Float16 => open_simple_multi_dense_vector_storage_half(
                         database.clone(),
                         &db_column_name,
-                        vector_config.size,
+                        dim,
                         vector_config.distance,
                         *multi_vec_config,
                         stopped,
                     ),
-                    VectorStorageDatatype::Float16 => open_simple_multi_dense_vector_storage_half(
+                    VectorStorageDatatype::BFloat16 => open_simple_multi_dense_vector_storage_bf16(
+                        database.clone(),
+                        &db_column_name,
+                        dim,
+                        vector_config.distance,
+                        *multi_vec_config,
+                        stopped,
+                    ),
+                    VectorStorageDatatype::Int8 => open_simple_multi_dense_vector_storage_int8(
                         database.clone(),
                         &db_column_name,
-                        vector_config.size,
+                        dim,
                         vector_config.distance,
                         *multi_vec_config,
                         stopped,
@@ -131,21 +163,37 @@ pub(crate) fn open_vector_storage(
                     VectorStorageDatatype::Float32 => open_simple_dense_vector_storage(
                         database.clone(),
                         &db_column_name,
-                        vector_config.size,
+                        dim,
                         vector_config.distance,
                         stopped,
                     ),
-                    VectorStorageDatatype::Uint8 => open_simple_dense_byte_vector_storage(
+                    VectorStorageDatatype::Uint8 | VectorStorageDatatype::Bit => {
+                        open_simple_dense_byte_vector_storage(
+                            database.clone(),
+                            &db_column_name,
+                            dim,
+                            vector_config.distance,
+                            stopped,
+                        )
+                    }
+                    VectorStorageDatatype::Float16 => open_simple_dense_half_vector_storage(
                         database.clone(),
                         &db_column_name,
-                        vector_config.size,
+                        dim,
                         vector_config.distance,
                         stopped,
                     ),
-                    VectorStorageDatatype::Float16 => open_simple_dense_half_vector_storage(
+                    VectorStorageDatatype::BFloat16 => open_simple_dense_bf16_vector_storage(
+                        database.clone(),
+                        &db_column_name,
+                        dim,
+                        vector_config.distance,
+                        stopped,
+                    ),
+                    VectorStorageDatatype::Int8 => open_simple_dense_int8_vector_storage(
                         database.clone(),
                         &db_column_name,
-                        vector_config.size,
+                        dim,
                         vector_config.distance,
                         stopped,
                     ),
@@ -155,46 +203,111 @@ pub(crate) fn open_vector_storage(
         // Mmap on disk, not appendable
         VectorStorageType::Mmap => {
             if let Some(multi_vec_config) = &vector_config.multivector_config {
-                // there are no mmap multi vector storages, appendable only
-                match storage_element_type {
-                    VectorStorageDatatype::Float32 => open_appendable_memmap_multi_vector_storage(
-                        vector_storage_path,
-                        vector_config.size,
-                        vector_config.distance,
-                        *multi_vec_config,
-                    ),
-                    VectorStorageDatatype::Uint8 => {
-                        open_appendable_memmap_multi_vector_storage_byte(
+                // Segments created by older versions use appendable multivector storage
+                if appendable_memmap_multi_vector_storage_exists(vector_storage_path) {
+                    match storage_element_type {
+                        VectorStorageDatatype::Float32 => {
+                            open_appendable_memmap_multi_vector_storage(
+                                vector_storage_path,
+                                dim,
+                                vector_config.distance,
+                                *multi_vec_config,
+                            )
+                        }
+                        VectorStorageDatatype::Uint8 | VectorStorageDatatype::Bit => {
+                            open_appendable_memmap_multi_vector_storage_byte(
+                                vector_storage_path,
+                                dim,
+                                vector_config.distance,
+                                *multi_vec_config,
+                            )
+                        }
+                        VectorStorageDatatype::Float16 => {
+                            open_appendable_memmap_multi_vector_storage_half(
+                                vector_storage_path,
+                                dim,
+                                vector_config.distance,
+                                *multi_vec_config,
+                            )
+                        }
+                        VectorStorageDatatype::BFloat16 => {
+                            open_appendable_memmap_multi_vector_storage_bf16(
+                                vector_storage_path,
+                                dim,
+                                vector_config.distance,
+                                *multi_vec_config,
+                            )
+                        }
+                        VectorStorageDatatype::Int8 => {
+                            open_appendable_memmap_multi_vector_storage_int8(
+                                vector_storage_path,
+                                dim,
+                                vector_config.distance,
+                                *multi_vec_config,
+                            )
+                        }
+                    }
+                } else {
+                    match storage_element_type {
+                        VectorStorageDatatype::Float32 => open_memmap_multi_vector_storage(
                             vector_storage_path,
-                            vector_config.size,
+                            dim,
                             vector_config.distance,
                             *multi_vec_config,
-                        )
-                    }
-                    VectorStorageDatatype::Float16 => {
-                        open_appendable_memmap_multi_vector_storage_half(
+                        ),
+                        VectorStorageDatatype::Uint8 | VectorStorageDatatype::Bit => {
+                            open_memmap_multi_vector_storage_byte(
+                                vector_storage_path,
+                                dim,
+                                vector_config.distance,
+                                *multi_vec_config,
+                            )
+                        }
+                        VectorStorageDatatype::Float16 => open_memmap_multi_vector_storage_half(
                             vector_storage_path,
-                            vector_config.size,
+                            dim,
                             vector_config.distance,
                             *multi_vec_config,
-                        )
+                        ),
+                        VectorStorageDatatype::BFloat16 => open_memmap_multi_vector_storage_bf16(
+                            vector_storage_path,
+                            dim,
+                            vector_config.distance,
+                            *multi_vec_config,
+                        ),
+                        VectorStorageDatatype::Int8 => open_memmap_multi_vector_storage_int8(
+                            vector_storage_path,
+                            dim,
+                            vector_config.distance,
+                            *multi_vec_config,
+                        ),
                     }
                 }
             } else {
                 match storage_element_type {
-                    VectorStorageDatatype::Float32 => open_memmap_vector_storage(
+                    VectorStorageDatatype::Float32 => {
+                        open_memmap_vector_storage(vector_storage_path, dim, vector_config.distance)
+                    }
+                    VectorStorageDatatype::Uint8 | VectorStorageDatatype::Bit => {
+                        open_memmap_vector_storage_byte(
+                            vector_storage_path,
+                            dim,
+                            vector_config.distance,
+                        )
+                    }
+                    VectorStorageDatatype::Float16 => open_memmap_vector_storage_half(
                         vector_storage_path,
-                        vector_config.size,
+                        dim,
                         vector_config.distance,
                     ),
-                    VectorStorageDatatype::Uint8 => open_memmap_vector_storage_byte(
+                    VectorStorageDatatype::BFloat16 => open_memmap_vector_storage_bf16(
                         vector_storage_path,
-                        vector_config.size,
+                        dim,
                         vector_config.distance,
                     ),
-                    VectorStorageDatatype::Float16 => open_memmap_vector_storage_half(
+                    VectorStorageDatatype::Int8 => open_memmap_vector_storage_int8(
                         vector_storage_path,
-                        vector_config.size,
+                        dim,
                         vector_config.distance,
                     ),
                 }
@@ -206,14 +319,14 @@ pub(crate) fn open_vector_storage(
                 match storage_element_type {
                     VectorStorageDatatype::Float32 => open_appendable_memmap_multi_vector_storage(
                         vector_storage_path,
-                        vector_config.size,
+                        dim,
                         vector_config.distance,
                         *multi_vec_config,
                     ),
-                    VectorStorageDatatype::Uint8 => {
+                    VectorStorageDatatype::Uint8 | VectorStorageDatatype::Bit => {
                         open_appendable_memmap_multi_vector_storage_byte(
                             vector_storage_path,
-                            vector_config.size,
+                            dim,
                             vector_config.distance,
                             *multi_vec_config,
                         )
@@ -221,7 +334,23 @@ pub(crate) fn open_vector_storage(
                     VectorStorageDatatype::Float16 => {
                         open_appendable_memmap_multi_vector_storage_half(
                             vector_storage_path,
-                            vector_config.size,
+                            dim,
+                            vector_config.distance,
+                            *multi_vec_config,
+                        )
+                    }
+                    VectorStorageDatatype::BFloat16 => {
+                        open_appendable_memmap_multi_vector_storage_bf16(
+                            vector_storage_path,
+                            dim,
+                            vector_config.distance,
+                            *multi_vec_config,
+                        )
+                    }
+                    VectorStorageDatatype::Int8 => {
+                        open_appendable_memmap_multi_vector_storage_int8(
+                            vector_storage_path,
+                            dim,
                             vector_config.distance,
                             *multi_vec_config,
                         )
@@ -231,17 +360,29 @@ pub(crate) fn open_vector_storage(
                 match storage_element_type {
                     VectorStorageDatatype::Float32 => open_appendable_memmap_vector_storage(
                         vector_storage_path,
-                        vector_config.size,
+                        dim,
                         vector_config.distance,
                     ),
-                    VectorStorageDatatype::Uint8 => open_appendable_memmap_vector_storage_byte(
+                    VectorStorageDatatype::Uint8 | VectorStorageDatatype::Bit => {
+                        open_appendable_memmap_vector_storage_byte(
+                            vector_storage_path,
+                            dim,
+                            vector_config.distance,
+                        )
+                    }
+                    VectorStorageDatatype::Float16 => open_appendable_memmap_vector_storage_half(
                         vector_storage_path,
-                        vector_config.size,
+                        dim,
                         vector_config.distance,
                     ),
-                    VectorStorageDatatype::Float16 => open_appendable_memmap_vector_storage_half(
+                    VectorStorageDatatype::BFloat16 => open_appendable_memmap_vector_storage_bf16(
                         vector_storage_path,
-                        vector_config.size,
+                        dim,
+                        vector_config.distance,
+                    ),
+                    VectorStorageDatatype::Int8 => open_appendable_memmap_vector_storage_int8(
+                        vector_storage_path,
+                        dim,
                         vector_config.distance,
                     ),
                 }
@@ -310,6 +451,7 @@ pub(crate) fn create_vector_index(
             id_tracker.clone(),
             vector_storage.clone(),
             payload_index.clone(),
+            vector_config.prefix_dim(),
         )),
         Indexes::Hnsw(vector_hnsw_config) => {
             let args = HnswIndexOpenArgs {
@@ -319,6 +461,7 @@ pub(crate) fn create_vector_index(
                 quantized_vectors: quantized_vectors.clone(),
                 payload_index: payload_index.clone(),
                 hnsw_config: vector_hnsw_config.clone(),
+                prefix_dim: vector_config.prefix_dim(),
                 permit,
                 stopped,
             };
@@ -348,7 +491,14 @@ pub(crate) fn create_sparse_vector_index(
         args.config.datatype.unwrap_or_default(),
         sparse_vector_index::USE_COMPRESSED,
     ) {
-        (_, a @ (VectorStorageDatatype::Float16 | VectorStorageDatatype::Uint8), false) => {
+        (
+            _,
+            a @ (VectorStorageDatatype::Bit
+            | VectorStorageDatatype::BFloat16
+            | VectorStorageDatatype::Int8),
+            _,
+        )
+        | (_, a @ (VectorStorageDatatype::Float16 | VectorStorageDatatype::Uint8), false) => {
             Err(OperationError::ValidationError {
                 description: format!("{:?} datatype is not supported", a),
             })?
@@ -392,11 +542,66 @@ pub(crate) fn create_sparse_vector_index(
 
 pub(crate) fn create_sparse_vector_storage(
     database: Arc<RwLock<DB>>,
+    path: &Path,
     vector_name: &str,
+    storage_type: SparseVectorStorageType,
     stopped: &AtomicBool,
 ) -> OperationResult<VectorStorageEnum> {
-    let db_column_name = get_vector_name_with_prefix(DB_VECTOR_CF, vector_name);
-    open_simple_sparse_vector_storage(database, &db_column_name, stopped)
+    match storage_type {
+        SparseVectorStorageType::OnDisk => {
+            let db_column_name = get_vector_name_with_prefix(DB_VECTOR_CF, vector_name);
+            open_simple_sparse_vector_storage(database, &db_column_name, stopped)
+        }
+        SparseVectorStorageType::Mmap => open_mmap_sparse_vector_storage(path),
+    }
+}
+
+/// Check that every prefix view refers to a dense vector it can be a prefix of
+fn check_prefix_views(config: &SegmentConfig) -> OperationResult<()> {
+    for (vector_name, vector_config) in &config.vector_data {
+        let Some(source_name) = &vector_config.prefix_of else {
+            continue;
+        };
+
+        let invalid = |reason: String| OperationError::ValidationError {
+            description: format!("Invalid prefix view {vector_name}: {reason}"),
+        };
+
+        let Some(source_config) = config.vector_data.get(source_name) else {
+            return Err(invalid(format!(
+                "source vector {source_name} does not exist"
+            )));
+        };
+        if source_config.prefix_of.is_some() {
+            return Err(invalid(format!(
+                "source vector {source_name} is a prefix view itself"
+            )));
+        }
+        if vector_config.multivector_config.is_some() || source_config.multivector_config.is_some()
+        {
+            return Err(invalid("multivectors are not supported".to_string()));
+        }
+        if vector_config.size > source_config.size {
+            return Err(invalid(format!(
+                "size {} exceeds size {} of source vector {source_name}",
+                vector_config.size, source_config.size,
+            )));
+        }
+        if vector_config.distance != source_config.distance {
+            return Err(invalid(format!(
+                "distance must match source vector {source_name}"
+            )));
+        }
+        if vector_config.datatype != source_config.datatype {
+            return Err(invalid(format!(
+                "datatype must match source vector {source_name}"
+            )));
+        }
+        if source_config.datatype == Some(VectorStorageDatatype::Bit) {
+            return Err(invalid("bit vectors are not supported".to_string()));
+        }
+    }
+    Ok(())
 }
 
 fn create_segment(
@@ -420,8 +625,15 @@ fn create_segment(
         appendable_flag,
     )?);
 
+    check_prefix_views(config)?;
+
     let mut vector_data = HashMap::new();
     for (vector_name, vector_config) in &config.vector_data {
+        // Prefix views are created below, once their source storage is opened
+        if vector_config.prefix_of.is_some() {
+            continue;
+        }
+
         let vector_storage_path = get_vector_storage_path(segment_path, vector_name);
         let vector_index_path = get_vector_index_path(segment_path, vector_name);
 
@@ -480,13 +692,56 @@ fn create_segment(
         );
     }
 
+    for (vector_name, vector_config) in &config.vector_data {
+        let Some(source_name) = &vector_config.prefix_of else {
+            continue;
+        };
+
+        let vector_index_path = get_vector_index_path(segment_path, vector_name);
+
+        // Prefix views share the storage of their source vector
+        let vector_storage = vector_data
+            .get(source_name)
+            .map(|source| source.vector_storage.clone())
+            .ok_or_else(|| {
+                OperationError::service_error(format!(
+                    "Source vector {source_name} for prefix view {vector_name} is not loaded"
+                ))
+            })?;
+        let quantized_vectors = sp(None);
+
+        let vector_index: Arc<AtomicRefCell<VectorIndexEnum>> = sp(create_vector_index(
+            vector_config,
+            &vector_index_path,
+            id_tracker.clone(),
+            vector_storage.clone(),
+            payload_index.clone(),
+            quantized_vectors.clone(),
+            None,
+            stopped,
+        )?);
+
+        check_process_stopped(stopped)?;
+
+        vector_data.insert(
+            vector_name.to_owned(),
+            VectorData {
+                vector_storage,
+                vector_index,
+                quantized_vectors,
+            },
+        );
+    }
+
     for (vector_name, sparse_vector_config) in &config.sparse_vector_data {
         let vector_storage_path = get_vector_storage_path(segment_path, vector_name);
         let vector_index_path = get_vector_index_path(segment_path, vector_name);
 
         let vector_storage = sp(create_sparse_vector_storage(
             database.clone(),
+            &vector_storage_path,
             vector_name,
+            sparse_vector_config.storage_type,
             stopped,
         )?);
 
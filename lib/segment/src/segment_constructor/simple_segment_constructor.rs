@@ -29,8 +29,10 @@ pub fn build_simple_segment(
                     storage_type: VectorStorageType::Memory,
                     index: Indexes::Plain {},
                     quantization_config: None,
+                    additional_quantization_configs: vec![],
                     multivector_config: None,
                     datatype: None,
+                    prefix_of: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
@@ -55,8 +57,10 @@ pub fn build_multivec_segment(
             storage_type: VectorStorageType::Memory,
             index: Indexes::Plain {},
             quantization_config: None,
+            additional_quantization_configs: vec![],
             multivector_config: None,
             datatype: None,
+            prefix_of: None,
         },
     );
     vectors_config.insert(
@@ -67,8 +71,10 @@ pub fn build_multivec_segment(
             storage_type: VectorStorageType::Memory,
             index: Indexes::Plain {},
             quantization_config: None,
+            additional_quantization_configs: vec![],
             multivector_config: None,
             datatype: None,
+            prefix_of: None,
         },
     );
 
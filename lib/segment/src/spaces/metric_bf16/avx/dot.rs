@@ -0,0 +1,68 @@
+use std::arch::x86_64::*;
+
+use common::types::ScoreType;
+
+use super::load_bf16_ps;
+use crate::data_types::vectors::VectorElementTypeBFloat16;
+use crate::spaces::simple_avx::hsum256_ps_avx;
+
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+#[target_feature(enable = "fma")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn avx_dot_similarity_bf16(
+    v1: &[VectorElementTypeBFloat16],
+    v2: &[VectorElementTypeBFloat16],
+) -> ScoreType {
+    debug_assert!(v1.len() == v2.len());
+
+    let n = v1.len();
+    let m = n - (n % 16);
+    let mut ptr1: *const __m128i = v1.as_ptr() as *const __m128i;
+    let mut ptr2: *const __m128i = v2.as_ptr() as *const __m128i;
+    let mut sum256_1: __m256 = _mm256_setzero_ps();
+    let mut sum256_2: __m256 = _mm256_setzero_ps();
+
+    let mut i: usize = 0;
+    while i < m {
+        sum256_1 = _mm256_fmadd_ps(load_bf16_ps(ptr1), load_bf16_ps(ptr2), sum256_1);
+        sum256_2 = _mm256_fmadd_ps(
+            load_bf16_ps(ptr1.wrapping_add(1)),
+            load_bf16_ps(ptr2.wrapping_add(1)),
+            sum256_2,
+        );
+
+        ptr1 = ptr1.wrapping_add(2);
+        ptr2 = ptr2.wrapping_add(2);
+        i += 16;
+    }
+
+    let mut result = hsum256_ps_avx(sum256_1) + hsum256_ps_avx(sum256_2);
+    for (a, b) in v1[m..].iter().zip(&v2[m..]) {
+        result += a.to_f32() * b.to_f32();
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_spaces_avx() {
+        use super::*;
+        use crate::spaces::metric_bf16::simple_dot::*;
+        use crate::spaces::metric_bf16::tests::test_vectors;
+
+        if is_x86_feature_detected!("avx")
+            && is_x86_feature_detected!("avx2")
+            && is_x86_feature_detected!("fma")
+        {
+            let (v1, v2) = test_vectors();
+
+            let dot_simd = unsafe { avx_dot_similarity_bf16(&v1, &v2) };
+            let dot = dot_similarity_bf16(&v1, &v2);
+            assert!((dot_simd - dot).abs() / dot.abs() < 0.0005);
+        } else {
+            println!("avx test skipped");
+        }
+    }
+}
@@ -0,0 +1,17 @@
+pub mod dot;
+pub mod euclid;
+pub mod manhattan;
+
+use std::arch::x86_64::*;
+
+/// Load 8 bfloat16 values and widen them into single-precision floats
+///
+/// bfloat16 is the upper half of an f32, so widening is just a 16 bit shift.
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+unsafe fn load_bf16_ps(ptr: *const __m128i) -> __m256 {
+    _mm256_castsi256_ps(_mm256_slli_epi32(
+        _mm256_cvtepu16_epi32(_mm_loadu_si128(ptr)),
+        16,
+    ))
+}
@@ -0,0 +1,28 @@
+pub mod simple_cosine;
+pub mod simple_dot;
+pub mod simple_euclid;
+pub mod simple_manhattan;
+
+#[cfg(target_arch = "x86_64")]
+pub mod avx;
+
+#[cfg(target_arch = "aarch64")]
+pub mod neon;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub mod sse;
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use half::bf16;
+
+    pub fn test_vectors() -> (Vec<bf16>, Vec<bf16>) {
+        let v1 = (0..301)
+            .map(|i| bf16::from_f32(((i * 17) % 83) as f32 / 10.0 - 4.0))
+            .collect();
+        let v2 = (0..301)
+            .map(|i| bf16::from_f32(((i * 31) % 79) as f32 / 10.0 - 3.5))
+            .collect();
+        (v1, v2)
+    }
+}
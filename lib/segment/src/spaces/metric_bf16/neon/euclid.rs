@@ -0,0 +1,64 @@
+use std::arch::aarch64::*;
+
+use common::types::ScoreType;
+
+use super::load_bf16_f32;
+use crate::data_types::vectors::VectorElementTypeBFloat16;
+
+#[target_feature(enable = "neon")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn neon_euclid_similarity_bf16(
+    v1: &[VectorElementTypeBFloat16],
+    v2: &[VectorElementTypeBFloat16],
+) -> ScoreType {
+    debug_assert!(v1.len() == v2.len());
+
+    let n = v1.len();
+    let m = n - (n % 8);
+    let mut ptr1: *const u16 = v1.as_ptr() as *const u16;
+    let mut ptr2: *const u16 = v2.as_ptr() as *const u16;
+    let mut sum1 = vdupq_n_f32(0.);
+    let mut sum2 = vdupq_n_f32(0.);
+
+    let mut i: usize = 0;
+    while i < m {
+        let (low1, high1) = load_bf16_f32(ptr1);
+        let (low2, high2) = load_bf16_f32(ptr2);
+        let sub1 = vsubq_f32(low1, low2);
+        let sub2 = vsubq_f32(high1, high2);
+        sum1 = vfmaq_f32(sum1, sub1, sub1);
+        sum2 = vfmaq_f32(sum2, sub2, sub2);
+
+        ptr1 = ptr1.add(8);
+        ptr2 = ptr2.add(8);
+        i += 8;
+    }
+
+    let mut result = vaddvq_f32(vaddq_f32(sum1, sum2));
+    for (a, b) in v1[m..].iter().zip(&v2[m..]) {
+        result += (a.to_f32() - b.to_f32()).powi(2);
+    }
+    -result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::arch::is_aarch64_feature_detected;
+
+    use super::*;
+    use crate::spaces::metric_bf16::simple_euclid::euclid_similarity_bf16;
+    use crate::spaces::metric_bf16::tests::test_vectors;
+
+    #[test]
+    fn test_spaces_neon() {
+        if is_aarch64_feature_detected!("neon") {
+            let (v1, v2) = test_vectors();
+
+            let euclid_simd = unsafe { neon_euclid_similarity_bf16(&v1, &v2) };
+            let euclid = euclid_similarity_bf16(&v1, &v2);
+            assert!((euclid_simd - euclid).abs() / euclid.abs() < 0.0005);
+        } else {
+            println!("neon test skipped");
+        }
+    }
+}
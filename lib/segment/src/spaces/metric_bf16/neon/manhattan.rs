@@ -0,0 +1,62 @@
+use std::arch::aarch64::*;
+
+use common::types::ScoreType;
+
+use super::load_bf16_f32;
+use crate::data_types::vectors::VectorElementTypeBFloat16;
+
+#[target_feature(enable = "neon")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn neon_manhattan_similarity_bf16(
+    v1: &[VectorElementTypeBFloat16],
+    v2: &[VectorElementTypeBFloat16],
+) -> ScoreType {
+    debug_assert!(v1.len() == v2.len());
+
+    let n = v1.len();
+    let m = n - (n % 8);
+    let mut ptr1: *const u16 = v1.as_ptr() as *const u16;
+    let mut ptr2: *const u16 = v2.as_ptr() as *const u16;
+    let mut sum1 = vdupq_n_f32(0.);
+    let mut sum2 = vdupq_n_f32(0.);
+
+    let mut i: usize = 0;
+    while i < m {
+        let (low1, high1) = load_bf16_f32(ptr1);
+        let (low2, high2) = load_bf16_f32(ptr2);
+        sum1 = vaddq_f32(sum1, vabdq_f32(low1, low2));
+        sum2 = vaddq_f32(sum2, vabdq_f32(high1, high2));
+
+        ptr1 = ptr1.add(8);
+        ptr2 = ptr2.add(8);
+        i += 8;
+    }
+
+    let mut result = vaddvq_f32(vaddq_f32(sum1, sum2));
+    for (a, b) in v1[m..].iter().zip(&v2[m..]) {
+        result += (a.to_f32() - b.to_f32()).abs();
+    }
+    -result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::arch::is_aarch64_feature_detected;
+
+    use super::*;
+    use crate::spaces::metric_bf16::simple_manhattan::manhattan_similarity_bf16;
+    use crate::spaces::metric_bf16::tests::test_vectors;
+
+    #[test]
+    fn test_spaces_neon() {
+        if is_aarch64_feature_detected!("neon") {
+            let (v1, v2) = test_vectors();
+
+            let manhattan_simd = unsafe { neon_manhattan_similarity_bf16(&v1, &v2) };
+            let manhattan = manhattan_similarity_bf16(&v1, &v2);
+            assert!((manhattan_simd - manhattan).abs() / manhattan.abs() < 0.0005);
+        } else {
+            println!("neon test skipped");
+        }
+    }
+}
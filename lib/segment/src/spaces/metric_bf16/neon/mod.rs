@@ -0,0 +1,17 @@
+pub mod dot;
+pub mod euclid;
+pub mod manhattan;
+
+use std::arch::aarch64::*;
+
+/// Load 8 bfloat16 values and widen them into two vectors of single-precision floats
+///
+/// bfloat16 is the upper half of an f32, so widening is just a 16 bit shift.
+#[target_feature(enable = "neon")]
+unsafe fn load_bf16_f32(ptr: *const u16) -> (float32x4_t, float32x4_t) {
+    let values = vld1q_u16(ptr);
+    (
+        vreinterpretq_f32_u32(vshll_n_u16::<16>(vget_low_u16(values))),
+        vreinterpretq_f32_u32(vshll_high_n_u16::<16>(values)),
+    )
+}
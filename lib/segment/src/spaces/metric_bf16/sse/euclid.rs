@@ -0,0 +1,67 @@
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use common::types::ScoreType;
+
+use super::load_bf16_ps;
+use crate::data_types::vectors::VectorElementTypeBFloat16;
+use crate::spaces::simple_sse::hsum128_ps_sse;
+
+#[target_feature(enable = "sse")]
+#[target_feature(enable = "sse2")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn sse_euclid_similarity_bf16(
+    v1: &[VectorElementTypeBFloat16],
+    v2: &[VectorElementTypeBFloat16],
+) -> ScoreType {
+    debug_assert!(v1.len() == v2.len());
+
+    let n = v1.len();
+    let m = n - (n % 8);
+    let mut ptr1: *const __m128i = v1.as_ptr() as *const __m128i;
+    let mut ptr2: *const __m128i = v2.as_ptr() as *const __m128i;
+    let mut sum128_1: __m128 = _mm_setzero_ps();
+    let mut sum128_2: __m128 = _mm_setzero_ps();
+
+    let mut i: usize = 0;
+    while i < m {
+        let (low1, high1) = load_bf16_ps(ptr1);
+        let (low2, high2) = load_bf16_ps(ptr2);
+        let sub128_1 = _mm_sub_ps(low1, low2);
+        let sub128_2 = _mm_sub_ps(high1, high2);
+        sum128_1 = _mm_add_ps(_mm_mul_ps(sub128_1, sub128_1), sum128_1);
+        sum128_2 = _mm_add_ps(_mm_mul_ps(sub128_2, sub128_2), sum128_2);
+
+        ptr1 = ptr1.wrapping_add(1);
+        ptr2 = ptr2.wrapping_add(1);
+        i += 8;
+    }
+
+    let mut result = hsum128_ps_sse(sum128_1) + hsum128_ps_sse(sum128_2);
+    for (a, b) in v1[m..].iter().zip(&v2[m..]) {
+        result += (a.to_f32() - b.to_f32()).powi(2);
+    }
+    -result
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_spaces_sse() {
+        use super::*;
+        use crate::spaces::metric_bf16::simple_euclid::*;
+        use crate::spaces::metric_bf16::tests::test_vectors;
+
+        if is_x86_feature_detected!("sse") && is_x86_feature_detected!("sse2") {
+            let (v1, v2) = test_vectors();
+
+            let euclid_simd = unsafe { sse_euclid_similarity_bf16(&v1, &v2) };
+            let euclid = euclid_similarity_bf16(&v1, &v2);
+            assert!((euclid_simd - euclid).abs() / euclid.abs() < 0.0005);
+        } else {
+            println!("sse test skipped");
+        }
+    }
+}
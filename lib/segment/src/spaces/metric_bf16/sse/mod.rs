@@ -0,0 +1,22 @@
+pub mod dot;
+pub mod euclid;
+pub mod manhattan;
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Load 8 bfloat16 values and widen them into two vectors of single-precision floats
+///
+/// bfloat16 is the upper half of an f32, so widening is interleaving with zeros.
+#[target_feature(enable = "sse")]
+#[target_feature(enable = "sse2")]
+unsafe fn load_bf16_ps(ptr: *const __m128i) -> (__m128, __m128) {
+    let values = _mm_loadu_si128(ptr);
+    let zero = _mm_setzero_si128();
+    (
+        _mm_castsi128_ps(_mm_unpacklo_epi16(zero, values)),
+        _mm_castsi128_ps(_mm_unpackhi_epi16(zero, values)),
+    )
+}
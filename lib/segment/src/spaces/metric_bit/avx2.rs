@@ -0,0 +1,97 @@
+use std::arch::x86_64::*;
+
+use super::{and_or_popcount_simple, xor_popcount_simple};
+
+/// Count bits of each byte with a lookup table of nibble popcounts
+#[target_feature(enable = "avx2")]
+unsafe fn popcount_epi8_avx(x: __m256i) -> __m256i {
+    let lookup = _mm256_setr_epi8(
+        0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4, //
+        0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4,
+    );
+    let mask_u4 = _mm256_set1_epi8(0x0F);
+    let low = _mm256_shuffle_epi8(lookup, _mm256_and_si256(x, mask_u4));
+    let high = _mm256_shuffle_epi8(lookup, _mm256_and_si256(_mm256_srli_epi16(x, 4), mask_u4));
+    _mm256_add_epi8(low, high)
+}
+
+/// Add bit counts of 32 bytes to 4x64 bit accumulator
+#[target_feature(enable = "avx2")]
+unsafe fn popcount_acc_avx(acc: __m256i, x: __m256i) -> __m256i {
+    _mm256_add_epi64(
+        acc,
+        _mm256_sad_epu8(popcount_epi8_avx(x), _mm256_setzero_si256()),
+    )
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn hsum256_epi64_avx(x: __m256i) -> u32 {
+    let mut lanes = [0u64; 4];
+    _mm256_storeu_si256(lanes.as_mut_ptr() as *mut __m256i, x);
+    lanes.iter().sum::<u64>() as u32
+}
+
+#[target_feature(enable = "avx2")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn avx_xor_popcount(v1: &[u8], v2: &[u8]) -> u32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("avx2"));
+
+    let mut acc = _mm256_setzero_si256();
+    let len = v1.len();
+    let simd_len = len - len % 32;
+    for i in (0..simd_len).step_by(32) {
+        let p1 = _mm256_loadu_si256(v1.as_ptr().add(i) as *const __m256i);
+        let p2 = _mm256_loadu_si256(v2.as_ptr().add(i) as *const __m256i);
+        acc = popcount_acc_avx(acc, _mm256_xor_si256(p1, p2));
+    }
+
+    hsum256_epi64_avx(acc) + xor_popcount_simple(&v1[simd_len..], &v2[simd_len..])
+}
+
+#[target_feature(enable = "avx2")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn avx_and_or_popcount(v1: &[u8], v2: &[u8]) -> (u32, u32) {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("avx2"));
+
+    let mut and_acc = _mm256_setzero_si256();
+    let mut or_acc = _mm256_setzero_si256();
+    let len = v1.len();
+    let simd_len = len - len % 32;
+    for i in (0..simd_len).step_by(32) {
+        let p1 = _mm256_loadu_si256(v1.as_ptr().add(i) as *const __m256i);
+        let p2 = _mm256_loadu_si256(v2.as_ptr().add(i) as *const __m256i);
+        and_acc = popcount_acc_avx(and_acc, _mm256_and_si256(p1, p2));
+        or_acc = popcount_acc_avx(or_acc, _mm256_or_si256(p1, p2));
+    }
+
+    let (and_count, or_count) = and_or_popcount_simple(&v1[simd_len..], &v2[simd_len..]);
+    (
+        hsum256_epi64_avx(and_acc) + and_count,
+        hsum256_epi64_avx(or_acc) + or_count,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::metric_bit::tests::test_vectors;
+
+    #[test]
+    fn test_spaces_avx() {
+        if is_x86_feature_detected!("avx2") {
+            let (v1, v2) = test_vectors();
+
+            unsafe {
+                assert_eq!(avx_xor_popcount(&v1, &v2), xor_popcount_simple(&v1, &v2));
+                assert_eq!(
+                    avx_and_or_popcount(&v1, &v2),
+                    and_or_popcount_simple(&v1, &v2),
+                );
+            }
+        } else {
+            println!("avx2 test skipped");
+        }
+    }
+}
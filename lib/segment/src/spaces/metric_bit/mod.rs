@@ -0,0 +1,375 @@
+//! Distances between bit vectors, packed 8 dimensions per byte.
+//!
+//! Vector elements of any datatype are interpreted as packed bytes.
+//! Both distances are negated in similarity, so that greater score means closer vectors.
+
+#[cfg(target_arch = "x86_64")]
+pub mod avx2;
+
+#[cfg(target_arch = "aarch64")]
+pub mod neon;
+
+use common::types::ScoreType;
+
+use crate::data_types::vectors::{
+    DenseVector, VectorElementType, VectorElementTypeBFloat16, VectorElementTypeByte,
+    VectorElementTypeHalf, VectorElementTypeInt8,
+};
+use crate::spaces::metric::{Metric, MetricPostProcessing};
+#[cfg(target_arch = "x86_64")]
+use crate::spaces::simple::MIN_DIM_SIZE_AVX;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::spaces::simple::MIN_DIM_SIZE_SIMD;
+use crate::spaces::simple::{HammingMetric, JaccardMetric};
+use crate::types::Distance;
+
+/// Number of bits set in `v1 XOR v2`
+pub fn xor_popcount(v1: &[u8], v2: &[u8]) -> u32 {
+    debug_assert!(v1.len() == v2.len());
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") && v1.len() >= MIN_DIM_SIZE_AVX {
+            return unsafe { avx2::avx_xor_popcount(v1, v2) };
+        }
+    }
+
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") && v1.len() >= MIN_DIM_SIZE_SIMD {
+            return unsafe { neon::neon_xor_popcount(v1, v2) };
+        }
+    }
+
+    xor_popcount_simple(v1, v2)
+}
+
+/// Number of bits set in `v1 AND v2` and in `v1 OR v2`
+pub fn and_or_popcount(v1: &[u8], v2: &[u8]) -> (u32, u32) {
+    debug_assert!(v1.len() == v2.len());
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") && v1.len() >= MIN_DIM_SIZE_AVX {
+            return unsafe { avx2::avx_and_or_popcount(v1, v2) };
+        }
+    }
+
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") && v1.len() >= MIN_DIM_SIZE_SIMD {
+            return unsafe { neon::neon_and_or_popcount(v1, v2) };
+        }
+    }
+
+    and_or_popcount_simple(v1, v2)
+}
+
+/// Split bytes into 64 bit words and the remaining bytes
+fn words(v: &[u8]) -> (impl Iterator<Item = u64> + '_, &[u8]) {
+    let chunks = v.chunks_exact(8);
+    let remainder = chunks.remainder();
+    let words = chunks.map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()));
+    (words, remainder)
+}
+
+pub fn xor_popcount_simple(v1: &[u8], v2: &[u8]) -> u32 {
+    let (words1, remainder1) = words(v1);
+    let (words2, remainder2) = words(v2);
+    let words_count: u32 = words1.zip(words2).map(|(a, b)| (a ^ b).count_ones()).sum();
+    let remainder_count: u32 = remainder1
+        .iter()
+        .zip(remainder2)
+        .map(|(a, b)| (a ^ b).count_ones())
+        .sum();
+    words_count + remainder_count
+}
+
+pub fn and_or_popcount_simple(v1: &[u8], v2: &[u8]) -> (u32, u32) {
+    let (words1, remainder1) = words(v1);
+    let (words2, remainder2) = words(v2);
+    let mut and_count = 0;
+    let mut or_count = 0;
+    for (a, b) in words1.zip(words2) {
+        and_count += (a & b).count_ones();
+        or_count += (a | b).count_ones();
+    }
+    for (a, b) in remainder1.iter().zip(remainder2) {
+        and_count += (a & b).count_ones();
+        or_count += (a | b).count_ones();
+    }
+    (and_count, or_count)
+}
+
+fn hamming_similarity(xor_count: u32) -> ScoreType {
+    -(xor_count as ScoreType)
+}
+
+fn jaccard_similarity(and_count: u32, or_count: u32) -> ScoreType {
+    if or_count == 0 {
+        // both vectors are empty sets
+        return 0.0;
+    }
+    and_count as ScoreType / or_count as ScoreType - 1.0
+}
+
+/// Interpret float elements as packed bytes
+fn float_bytes<'a, T: Copy + Into<f32>>(v: &'a [T]) -> impl Iterator<Item = u8> + 'a {
+    v.iter().map(|&x| x.into() as u8)
+}
+
+impl Metric<VectorElementTypeByte> for HammingMetric {
+    fn distance() -> Distance {
+        Distance::Hamming
+    }
+
+    fn similarity(v1: &[VectorElementTypeByte], v2: &[VectorElementTypeByte]) -> ScoreType {
+        hamming_similarity(xor_popcount(v1, v2))
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+impl Metric<VectorElementType> for HammingMetric {
+    fn distance() -> Distance {
+        Distance::Hamming
+    }
+
+    fn similarity(v1: &[VectorElementType], v2: &[VectorElementType]) -> ScoreType {
+        let xor_count = float_bytes(v1)
+            .zip(float_bytes(v2))
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum();
+        hamming_similarity(xor_count)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+impl Metric<VectorElementTypeHalf> for HammingMetric {
+    fn distance() -> Distance {
+        Distance::Hamming
+    }
+
+    fn similarity(v1: &[VectorElementTypeHalf], v2: &[VectorElementTypeHalf]) -> ScoreType {
+        let xor_count = float_bytes(v1)
+            .zip(float_bytes(v2))
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum();
+        hamming_similarity(xor_count)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+impl Metric<VectorElementTypeBFloat16> for HammingMetric {
+    fn distance() -> Distance {
+        Distance::Hamming
+    }
+
+    fn similarity(v1: &[VectorElementTypeBFloat16], v2: &[VectorElementTypeBFloat16]) -> ScoreType {
+        let xor_count = float_bytes(v1)
+            .zip(float_bytes(v2))
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum();
+        hamming_similarity(xor_count)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+impl Metric<VectorElementTypeInt8> for HammingMetric {
+    fn distance() -> Distance {
+        Distance::Hamming
+    }
+
+    fn similarity(v1: &[VectorElementTypeInt8], v2: &[VectorElementTypeInt8]) -> ScoreType {
+        let xor_count = float_bytes(v1)
+            .zip(float_bytes(v2))
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum();
+        hamming_similarity(xor_count)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+impl MetricPostProcessing for HammingMetric {
+    fn postprocess(score: ScoreType) -> ScoreType {
+        score.abs()
+    }
+}
+
+impl Metric<VectorElementTypeByte> for JaccardMetric {
+    fn distance() -> Distance {
+        Distance::Jaccard
+    }
+
+    fn similarity(v1: &[VectorElementTypeByte], v2: &[VectorElementTypeByte]) -> ScoreType {
+        let (and_count, or_count) = and_or_popcount(v1, v2);
+        jaccard_similarity(and_count, or_count)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+impl Metric<VectorElementType> for JaccardMetric {
+    fn distance() -> Distance {
+        Distance::Jaccard
+    }
+
+    fn similarity(v1: &[VectorElementType], v2: &[VectorElementType]) -> ScoreType {
+        let (and_count, or_count) =
+            float_bytes(v1)
+                .zip(float_bytes(v2))
+                .fold((0, 0), |(and_count, or_count), (a, b)| {
+                    (
+                        and_count + (a & b).count_ones(),
+                        or_count + (a | b).count_ones(),
+                    )
+                });
+        jaccard_similarity(and_count, or_count)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+impl Metric<VectorElementTypeHalf> for JaccardMetric {
+    fn distance() -> Distance {
+        Distance::Jaccard
+    }
+
+    fn similarity(v1: &[VectorElementTypeHalf], v2: &[VectorElementTypeHalf]) -> ScoreType {
+        let (and_count, or_count) =
+            float_bytes(v1)
+                .zip(float_bytes(v2))
+                .fold((0, 0), |(and_count, or_count), (a, b)| {
+                    (
+                        and_count + (a & b).count_ones(),
+                        or_count + (a | b).count_ones(),
+                    )
+                });
+        jaccard_similarity(and_count, or_count)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+impl Metric<VectorElementTypeBFloat16> for JaccardMetric {
+    fn distance() -> Distance {
+        Distance::Jaccard
+    }
+
+    fn similarity(v1: &[VectorElementTypeBFloat16], v2: &[VectorElementTypeBFloat16]) -> ScoreType {
+        let (and_count, or_count) =
+            float_bytes(v1)
+                .zip(float_bytes(v2))
+                .fold((0, 0), |(and_count, or_count), (a, b)| {
+                    (
+                        and_count + (a & b).count_ones(),
+                        or_count + (a | b).count_ones(),
+                    )
+                });
+        jaccard_similarity(and_count, or_count)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+impl Metric<VectorElementTypeInt8> for JaccardMetric {
+    fn distance() -> Distance {
+        Distance::Jaccard
+    }
+
+    fn similarity(v1: &[VectorElementTypeInt8], v2: &[VectorElementTypeInt8]) -> ScoreType {
+        let (and_count, or_count) =
+            float_bytes(v1)
+                .zip(float_bytes(v2))
+                .fold((0, 0), |(and_count, or_count), (a, b)| {
+                    (
+                        and_count + (a & b).count_ones(),
+                        or_count + (a | b).count_ones(),
+                    )
+                });
+        jaccard_similarity(and_count, or_count)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+impl MetricPostProcessing for JaccardMetric {
+    fn postprocess(score: ScoreType) -> ScoreType {
+        score.abs()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Packed bits of varying length, covering all byte values
+    pub fn test_vectors() -> (Vec<u8>, Vec<u8>) {
+        let v1: Vec<u8> = (0..301u32).map(|i| (i * 37 % 256) as u8).collect();
+        let v2: Vec<u8> = (0..301u32).map(|i| (255 - i * 11 % 256) as u8).collect();
+        (v1, v2)
+    }
+
+    #[test]
+    fn test_simple_popcount() {
+        let v1 = [0b1100_1010, 0b0000_0001];
+        let v2 = [0b1010_1010, 0b0000_0011];
+        assert_eq!(xor_popcount_simple(&v1, &v2), 3);
+        assert_eq!(and_or_popcount_simple(&v1, &v2), (4, 7));
+    }
+
+    #[test]
+    fn test_bit_metrics() {
+        let v1: Vec<u8> = vec![0b1111_0000, 0b0000_1111];
+        let v2: Vec<u8> = vec![0b1100_0000, 0b0000_1100];
+
+        let hamming = <HammingMetric as Metric<VectorElementTypeByte>>::similarity(&v1, &v2);
+        assert_eq!(HammingMetric::postprocess(hamming), 4.0);
+
+        let jaccard = <JaccardMetric as Metric<VectorElementTypeByte>>::similarity(&v1, &v2);
+        assert_eq!(JaccardMetric::postprocess(jaccard), 0.5);
+
+        // float elements are interpreted as packed bytes
+        let v1_float: Vec<f32> = v1.iter().map(|&x| x as f32).collect();
+        let v2_float: Vec<f32> = v2.iter().map(|&x| x as f32).collect();
+        assert_eq!(
+            <HammingMetric as Metric<VectorElementType>>::similarity(&v1_float, &v2_float),
+            hamming,
+        );
+        assert_eq!(
+            <JaccardMetric as Metric<VectorElementType>>::similarity(&v1_float, &v2_float),
+            jaccard,
+        );
+
+        // empty sets are equal
+        let empty = [0u8; 2];
+        assert_eq!(
+            <JaccardMetric as Metric<VectorElementTypeByte>>::similarity(&empty, &empty),
+            0.0,
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use std::arch::aarch64::*;
+
+use super::{and_or_popcount_simple, xor_popcount_simple};
+
+/// Add bit counts of 16 bytes to 4x32 bit accumulator
+#[target_feature(enable = "neon")]
+unsafe fn popcount_acc_neon(acc: uint32x4_t, x: uint8x16_t) -> uint32x4_t {
+    vpadalq_u16(acc, vpaddlq_u8(vcntq_u8(x)))
+}
+
+#[target_feature(enable = "neon")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn neon_xor_popcount(v1: &[u8], v2: &[u8]) -> u32 {
+    debug_assert!(v1.len() == v2.len());
+
+    let mut acc = vdupq_n_u32(0);
+    let len = v1.len();
+    let simd_len = len - len % 16;
+    for i in (0..simd_len).step_by(16) {
+        let p1 = vld1q_u8(v1.as_ptr().add(i));
+        let p2 = vld1q_u8(v2.as_ptr().add(i));
+        acc = popcount_acc_neon(acc, veorq_u8(p1, p2));
+    }
+
+    vaddvq_u32(acc) + xor_popcount_simple(&v1[simd_len..], &v2[simd_len..])
+}
+
+#[target_feature(enable = "neon")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn neon_and_or_popcount(v1: &[u8], v2: &[u8]) -> (u32, u32) {
+    debug_assert!(v1.len() == v2.len());
+
+    let mut and_acc = vdupq_n_u32(0);
+    let mut or_acc = vdupq_n_u32(0);
+    let len = v1.len();
+    let simd_len = len - len % 16;
+    for i in (0..simd_len).step_by(16) {
+        let p1 = vld1q_u8(v1.as_ptr().add(i));
+        let p2 = vld1q_u8(v2.as_ptr().add(i));
+        and_acc = popcount_acc_neon(and_acc, vandq_u8(p1, p2));
+        or_acc = popcount_acc_neon(or_acc, vorrq_u8(p1, p2));
+    }
+
+    let (and_count, or_count) = and_or_popcount_simple(&v1[simd_len..], &v2[simd_len..]);
+    (
+        vaddvq_u32(and_acc) + and_count,
+        vaddvq_u32(or_acc) + or_count,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::arch::is_aarch64_feature_detected;
+
+    use super::*;
+    use crate::spaces::metric_bit::tests::test_vectors;
+
+    #[test]
+    fn test_spaces_neon() {
+        if is_aarch64_feature_detected!("neon") {
+            let (v1, v2) = test_vectors();
+
+            unsafe {
+                assert_eq!(neon_xor_popcount(&v1, &v2), xor_popcount_simple(&v1, &v2));
+                assert_eq!(
+                    neon_and_or_popcount(&v1, &v2),
+                    and_or_popcount_simple(&v1, &v2),
+                );
+            }
+        } else {
+            println!("neon test skipped");
+        }
+    }
+}
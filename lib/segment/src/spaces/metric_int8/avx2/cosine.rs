@@ -0,0 +1,88 @@
+use std::arch::x86_64::*;
+
+use crate::spaces::simple_avx::hsum256_ps_avx;
+
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+#[target_feature(enable = "fma")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn avx_cosine_similarity_int8(v1: &[i8], v2: &[i8]) -> f32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("avx"));
+    debug_assert!(is_x86_feature_detected!("avx2"));
+    debug_assert!(is_x86_feature_detected!("fma"));
+
+    let mut ptr1: *const i8 = v1.as_ptr();
+    let mut ptr2: *const i8 = v2.as_ptr();
+
+    // sum accumulator for 8x32 bit integers
+    let mut dot_acc = _mm256_setzero_si256();
+    let mut norm1_acc = _mm256_setzero_si256();
+    let mut norm2_acc = _mm256_setzero_si256();
+    let len = v1.len();
+    for _ in 0..len / 16 {
+        // load 16 bytes and sign-extend them into 16x16 bit integers
+        let p1 = _mm256_cvtepi8_epi16(_mm_loadu_si128(ptr1 as *const __m128i));
+        let p2 = _mm256_cvtepi8_epi16(_mm_loadu_si128(ptr2 as *const __m128i));
+        ptr1 = ptr1.add(16);
+        ptr2 = ptr2.add(16);
+
+        // calculate 16bit multiplication with adding pairs into 32 bit accumulators
+        dot_acc = _mm256_add_epi32(dot_acc, _mm256_madd_epi16(p1, p2));
+        norm1_acc = _mm256_add_epi32(norm1_acc, _mm256_madd_epi16(p1, p1));
+        norm2_acc = _mm256_add_epi32(norm2_acc, _mm256_madd_epi16(p2, p2));
+    }
+
+    // convert 8x32 bit integers into 8x32 bit floats and calculate horizontal sum
+    let mut dot_product = hsum256_ps_avx(_mm256_cvtepi32_ps(dot_acc));
+    let mut norm1 = hsum256_ps_avx(_mm256_cvtepi32_ps(norm1_acc));
+    let mut norm2 = hsum256_ps_avx(_mm256_cvtepi32_ps(norm2_acc));
+
+    let remainder = len % 16;
+    if remainder != 0 {
+        let mut remainder_dot = 0;
+        let mut remainder_norm1 = 0;
+        let mut remainder_norm2 = 0;
+        for _ in 0..remainder {
+            let v1 = *ptr1 as i32;
+            let v2 = *ptr2 as i32;
+            ptr1 = ptr1.add(1);
+            ptr2 = ptr2.add(1);
+            remainder_dot += v1 * v2;
+            remainder_norm1 += v1 * v1;
+            remainder_norm2 += v2 * v2;
+        }
+        dot_product += remainder_dot as f32;
+        norm1 += remainder_norm1 as f32;
+        norm2 += remainder_norm2 as f32;
+    }
+
+    if norm1 == 0.0 || norm2 == 0.0 {
+        return 0.0;
+    }
+
+    dot_product / (norm1 * norm2).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::metric_int8::simple_cosine::cosine_similarity_int8;
+    use crate::spaces::metric_int8::tests::test_vectors;
+
+    #[test]
+    fn test_spaces_avx() {
+        if is_x86_feature_detected!("avx")
+            && is_x86_feature_detected!("avx2")
+            && is_x86_feature_detected!("fma")
+        {
+            let (v1, v2) = test_vectors();
+
+            let cosine_simd = unsafe { avx_cosine_similarity_int8(&v1, &v2) };
+            let cosine = cosine_similarity_int8(&v1, &v2);
+            assert!((cosine_simd - cosine).abs() < 1e-6);
+        } else {
+            println!("avx2 test skipped");
+        }
+    }
+}
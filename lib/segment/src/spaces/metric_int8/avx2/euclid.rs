@@ -0,0 +1,74 @@
+use std::arch::x86_64::*;
+
+use crate::spaces::simple_avx::hsum256_ps_avx;
+
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+#[target_feature(enable = "fma")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn avx_euclid_similarity_int8(v1: &[i8], v2: &[i8]) -> f32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("avx"));
+    debug_assert!(is_x86_feature_detected!("avx2"));
+    debug_assert!(is_x86_feature_detected!("fma"));
+
+    let mut ptr1: *const i8 = v1.as_ptr();
+    let mut ptr2: *const i8 = v2.as_ptr();
+
+    // sum accumulator for 8x32 bit integers
+    let mut sum_acc = _mm256_setzero_si256();
+    let len = v1.len();
+    for _ in 0..len / 16 {
+        // load 16 bytes and sign-extend them into 16x16 bit integers
+        let p1 = _mm256_cvtepi8_epi16(_mm_loadu_si128(ptr1 as *const __m128i));
+        let p2 = _mm256_cvtepi8_epi16(_mm_loadu_si128(ptr2 as *const __m128i));
+        ptr1 = ptr1.add(16);
+        ptr2 = ptr2.add(16);
+
+        // calculate squared 16bit differences with adding pairs into 32 bit accumulator
+        let diff = _mm256_sub_epi16(p1, p2);
+        sum_acc = _mm256_add_epi32(sum_acc, _mm256_madd_epi16(diff, diff));
+    }
+
+    // convert 8x32 bit integers into 8x32 bit floats and calculate horizontal sum
+    let mut score = hsum256_ps_avx(_mm256_cvtepi32_ps(sum_acc));
+
+    let remainder = len % 16;
+    if remainder != 0 {
+        let mut remainder_sum = 0;
+        for _ in 0..remainder {
+            let v1 = *ptr1;
+            let v2 = *ptr2;
+            ptr1 = ptr1.add(1);
+            ptr2 = ptr2.add(1);
+            let diff = v1 as i32 - v2 as i32;
+            remainder_sum += diff * diff;
+        }
+        score += remainder_sum as f32;
+    }
+
+    -score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::metric_int8::simple_euclid::euclid_similarity_int8;
+    use crate::spaces::metric_int8::tests::test_vectors;
+
+    #[test]
+    fn test_spaces_avx() {
+        if is_x86_feature_detected!("avx")
+            && is_x86_feature_detected!("avx2")
+            && is_x86_feature_detected!("fma")
+        {
+            let (v1, v2) = test_vectors();
+
+            let euclid_simd = unsafe { avx_euclid_similarity_int8(&v1, &v2) };
+            let euclid = euclid_similarity_int8(&v1, &v2);
+            assert_eq!(euclid_simd, euclid);
+        } else {
+            println!("avx2 test skipped");
+        }
+    }
+}
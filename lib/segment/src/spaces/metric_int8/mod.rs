@@ -0,0 +1,26 @@
+pub mod simple_cosine;
+pub mod simple_dot;
+pub mod simple_euclid;
+pub mod simple_manhattan;
+
+#[cfg(target_arch = "x86_64")]
+pub mod avx2;
+
+#[cfg(target_arch = "aarch64")]
+pub mod neon;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub mod sse2;
+
+#[cfg(test)]
+pub(crate) mod tests {
+    pub fn test_vectors() -> (Vec<i8>, Vec<i8>) {
+        let v1 = (0..301)
+            .map(|i: i32| ((i * 37) % 256 - 128) as i8)
+            .collect();
+        let v2 = (0..301)
+            .map(|i: i32| (127 - (i * 53) % 256) as i8)
+            .collect();
+        (v1, v2)
+    }
+}
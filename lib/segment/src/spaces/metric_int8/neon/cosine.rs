@@ -0,0 +1,72 @@
+use std::arch::aarch64::*;
+
+#[target_feature(enable = "neon")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn neon_cosine_similarity_int8(v1: &[i8], v2: &[i8]) -> f32 {
+    debug_assert!(v1.len() == v2.len());
+    let mut ptr1: *const i8 = v1.as_ptr();
+    let mut ptr2: *const i8 = v2.as_ptr();
+
+    let mut dot_acc = vdupq_n_s32(0);
+    let mut norm1_acc = vdupq_n_s32(0);
+    let mut norm2_acc = vdupq_n_s32(0);
+    let len = v1.len();
+    for _ in 0..len / 16 {
+        let p1 = vld1q_s8(ptr1);
+        let p2 = vld1q_s8(ptr2);
+        ptr1 = ptr1.add(16);
+        ptr2 = ptr2.add(16);
+
+        let p1_low = vget_low_s8(p1);
+        let p2_low = vget_low_s8(p2);
+
+        dot_acc = vpadalq_s16(dot_acc, vmull_s8(p1_low, p2_low));
+        dot_acc = vpadalq_s16(dot_acc, vmull_high_s8(p1, p2));
+        norm1_acc = vpadalq_s16(norm1_acc, vmull_s8(p1_low, p1_low));
+        norm1_acc = vpadalq_s16(norm1_acc, vmull_high_s8(p1, p1));
+        norm2_acc = vpadalq_s16(norm2_acc, vmull_s8(p2_low, p2_low));
+        norm2_acc = vpadalq_s16(norm2_acc, vmull_high_s8(p2, p2));
+    }
+    let mut dot_product = vaddvq_s32(dot_acc);
+    let mut norm1 = vaddvq_s32(norm1_acc);
+    let mut norm2 = vaddvq_s32(norm2_acc);
+
+    let remainder = len % 16;
+    for _ in 0..remainder {
+        let v1 = *ptr1 as i32;
+        let v2 = *ptr2 as i32;
+        ptr1 = ptr1.add(1);
+        ptr2 = ptr2.add(1);
+        dot_product += v1 * v2;
+        norm1 += v1 * v1;
+        norm2 += v2 * v2;
+    }
+
+    if norm1 == 0 || norm2 == 0 {
+        return 0.0;
+    }
+
+    dot_product as f32 / ((norm1 as f32 * norm2 as f32).sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::arch::is_aarch64_feature_detected;
+
+    use super::*;
+    use crate::spaces::metric_int8::simple_cosine::cosine_similarity_int8;
+    use crate::spaces::metric_int8::tests::test_vectors;
+
+    #[test]
+    fn test_spaces_neon() {
+        if is_aarch64_feature_detected!("neon") {
+            let (v1, v2) = test_vectors();
+
+            let cosine_simd = unsafe { neon_cosine_similarity_int8(&v1, &v2) };
+            let cosine = cosine_similarity_int8(&v1, &v2);
+            assert!((cosine_simd - cosine).abs() < 1e-6);
+        } else {
+            println!("neon test skipped");
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::arch::aarch64::*;
+
+#[target_feature(enable = "neon")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn neon_manhattan_similarity_int8(v1: &[i8], v2: &[i8]) -> f32 {
+    debug_assert!(v1.len() == v2.len());
+    let mut ptr1: *const i8 = v1.as_ptr();
+    let mut ptr2: *const i8 = v2.as_ptr();
+
+    let mut sum1 = vdupq_n_s32(0);
+    let mut sum2 = vdupq_n_s32(0);
+    let len = v1.len();
+    for _ in 0..len / 16 {
+        let p1 = vld1q_s8(ptr1);
+        let p2 = vld1q_s8(ptr2);
+        ptr1 = ptr1.add(16);
+        ptr2 = ptr2.add(16);
+
+        // absolute differences widened to 16 bits, then pairwise added into 32 bits
+        let abs_diff_low = vabdl_s8(vget_low_s8(p1), vget_low_s8(p2));
+        let abs_diff_high = vabdl_high_s8(p1, p2);
+        sum1 = vpadalq_s16(sum1, abs_diff_low);
+        sum2 = vpadalq_s16(sum2, abs_diff_high);
+    }
+    let mut score = vaddvq_s32(vaddq_s32(sum1, sum2)) as f32;
+
+    let remainder = len % 16;
+    if remainder != 0 {
+        let mut remainder_score = 0;
+        for _ in 0..remainder {
+            let v1 = *ptr1;
+            let v2 = *ptr2;
+            ptr1 = ptr1.add(1);
+            ptr2 = ptr2.add(1);
+            remainder_score += (v1 as i32 - v2 as i32).abs();
+        }
+        score += remainder_score as f32;
+    }
+
+    -score
+}
+
+#[cfg(test)]
+mod tests {
+    use std::arch::is_aarch64_feature_detected;
+
+    use super::*;
+    use crate::spaces::metric_int8::simple_manhattan::manhattan_similarity_int8;
+    use crate::spaces::metric_int8::tests::test_vectors;
+
+    #[test]
+    fn test_spaces_neon() {
+        if is_aarch64_feature_detected!("neon") {
+            let (v1, v2) = test_vectors();
+
+            let manhattan_simd = unsafe { neon_manhattan_similarity_int8(&v1, &v2) };
+            let manhattan = manhattan_similarity_int8(&v1, &v2);
+            assert_eq!(manhattan_simd, manhattan);
+        } else {
+            println!("neon test skipped");
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use std::arch::x86_64::*;
+
+use super::sign_extend_epi8;
+use crate::spaces::simple_sse::hsum128_ps_sse;
+
+#[target_feature(enable = "sse")]
+#[target_feature(enable = "sse2")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn sse_dot_similarity_int8(v1: &[i8], v2: &[i8]) -> f32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("sse"));
+    debug_assert!(is_x86_feature_detected!("sse2"));
+
+    let mut ptr1: *const i8 = v1.as_ptr();
+    let mut ptr2: *const i8 = v2.as_ptr();
+
+    // sum accumulator for 4x32 bit integers
+    let mut dot_acc = _mm_setzero_si128();
+    let len = v1.len();
+    for _ in 0..len / 16 {
+        // load 16 bytes and sign-extend them into 2x8x16 bit integers
+        let (p1_low, p1_high) = sign_extend_epi8(_mm_loadu_si128(ptr1 as *const __m128i));
+        let (p2_low, p2_high) = sign_extend_epi8(_mm_loadu_si128(ptr2 as *const __m128i));
+        ptr1 = ptr1.add(16);
+        ptr2 = ptr2.add(16);
+
+        // calculate 16bit multiplication with adding pairs into 32 bit accumulator
+        dot_acc = _mm_add_epi32(dot_acc, _mm_madd_epi16(p1_low, p2_low));
+        dot_acc = _mm_add_epi32(dot_acc, _mm_madd_epi16(p1_high, p2_high));
+    }
+
+    // convert 4x32 bit integers into 4x32 bit floats and calculate horizontal sum
+    let mut score = hsum128_ps_sse(_mm_cvtepi32_ps(dot_acc));
+
+    let remainder = len % 16;
+    if remainder != 0 {
+        let mut remainder_dot = 0;
+        for _ in 0..remainder {
+            let v1 = *ptr1;
+            let v2 = *ptr2;
+            ptr1 = ptr1.add(1);
+            ptr2 = ptr2.add(1);
+            remainder_dot += (v1 as i32) * (v2 as i32);
+        }
+        score += remainder_dot as f32;
+    }
+
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::metric_int8::simple_dot::dot_similarity_int8;
+    use crate::spaces::metric_int8::tests::test_vectors;
+
+    #[test]
+    fn test_spaces_sse() {
+        if is_x86_feature_detected!("sse") && is_x86_feature_detected!("sse2") {
+            let (v1, v2) = test_vectors();
+
+            let dot_simd = unsafe { sse_dot_similarity_int8(&v1, &v2) };
+            let dot = dot_similarity_int8(&v1, &v2);
+            assert_eq!(dot_simd, dot);
+        } else {
+            println!("sse2 test skipped");
+        }
+    }
+}
@@ -0,0 +1,17 @@
+pub mod cosine;
+pub mod dot;
+pub mod euclid;
+pub mod manhattan;
+
+use std::arch::x86_64::*;
+
+/// Sign-extend 16x8 bit integers into two vectors of 8x16 bit integers
+#[target_feature(enable = "sse2")]
+unsafe fn sign_extend_epi8(values: __m128i) -> (__m128i, __m128i) {
+    // duplicate each byte into both halves of a 16 bit lane,
+    // then arithmetic shift right keeps the sign of the upper copy
+    (
+        _mm_srai_epi16(_mm_unpacklo_epi8(values, values), 8),
+        _mm_srai_epi16(_mm_unpackhi_epi8(values, values), 8),
+    )
+}
@@ -0,0 +1,113 @@
+use std::arch::x86_64::*;
+
+use super::{dot_u4_simple, euclid_u4_simple, manhattan_u4_simple};
+
+#[target_feature(enable = "avx2")]
+unsafe fn hsum256_epi32_avx(x: __m256i) -> u32 {
+    let mut lanes = [0i32; 8];
+    _mm256_storeu_si256(lanes.as_mut_ptr() as *mut __m256i, x);
+    lanes.iter().sum::<i32>() as u32
+}
+
+/// Split 32 bytes of packed codes into lower and higher nibbles
+#[target_feature(enable = "avx2")]
+unsafe fn unpack_u4_avx(ptr: *const u8) -> (__m256i, __m256i) {
+    let mask_u4 = _mm256_set1_epi8(0x0F);
+    let packed = _mm256_loadu_si256(ptr as *const __m256i);
+    let low = _mm256_and_si256(packed, mask_u4);
+    let high = _mm256_and_si256(_mm256_srli_epi16(packed, 4), mask_u4);
+    (low, high)
+}
+
+#[target_feature(enable = "avx2")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn avx_dot_u4(v1: &[u8], v2: &[u8]) -> u32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("avx2"));
+
+    let ones_epi16 = _mm256_set1_epi16(1);
+    let mut acc = _mm256_setzero_si256();
+    let len = v1.len();
+    let simd_len = len - len % 32;
+    for i in (0..simd_len).step_by(32) {
+        let (p1_low, p1_high) = unpack_u4_avx(v1.as_ptr().add(i));
+        let (p2_low, p2_high) = unpack_u4_avx(v2.as_ptr().add(i));
+
+        // codes are at most 15, so the sum of products of 4 codes fits into 16 bits
+        let dot_low = _mm256_maddubs_epi16(p1_low, p2_low);
+        let dot_high = _mm256_maddubs_epi16(p1_high, p2_high);
+        let dot = _mm256_madd_epi16(_mm256_add_epi16(dot_low, dot_high), ones_epi16);
+        acc = _mm256_add_epi32(acc, dot);
+    }
+
+    hsum256_epi32_avx(acc) + dot_u4_simple(&v1[simd_len..], &v2[simd_len..])
+}
+
+#[target_feature(enable = "avx2")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn avx_euclid_u4(v1: &[u8], v2: &[u8]) -> u32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("avx2"));
+
+    let ones_epi16 = _mm256_set1_epi16(1);
+    let mut acc = _mm256_setzero_si256();
+    let len = v1.len();
+    let simd_len = len - len % 32;
+    for i in (0..simd_len).step_by(32) {
+        let (p1_low, p1_high) = unpack_u4_avx(v1.as_ptr().add(i));
+        let (p2_low, p2_high) = unpack_u4_avx(v2.as_ptr().add(i));
+
+        let diff_low = _mm256_abs_epi8(_mm256_sub_epi8(p1_low, p2_low));
+        let diff_high = _mm256_abs_epi8(_mm256_sub_epi8(p1_high, p2_high));
+        let squares_low = _mm256_maddubs_epi16(diff_low, diff_low);
+        let squares_high = _mm256_maddubs_epi16(diff_high, diff_high);
+        let squares = _mm256_madd_epi16(_mm256_add_epi16(squares_low, squares_high), ones_epi16);
+        acc = _mm256_add_epi32(acc, squares);
+    }
+
+    hsum256_epi32_avx(acc) + euclid_u4_simple(&v1[simd_len..], &v2[simd_len..])
+}
+
+#[target_feature(enable = "avx2")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn avx_manhattan_u4(v1: &[u8], v2: &[u8]) -> u32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("avx2"));
+
+    let mut acc = _mm256_setzero_si256();
+    let len = v1.len();
+    let simd_len = len - len % 32;
+    for i in (0..simd_len).step_by(32) {
+        let (p1_low, p1_high) = unpack_u4_avx(v1.as_ptr().add(i));
+        let (p2_low, p2_high) = unpack_u4_avx(v2.as_ptr().add(i));
+
+        // sum of absolute differences into 4x64 bit integers
+        acc = _mm256_add_epi64(acc, _mm256_sad_epu8(p1_low, p2_low));
+        acc = _mm256_add_epi64(acc, _mm256_sad_epu8(p1_high, p2_high));
+    }
+
+    let mut lanes = [0u64; 4];
+    _mm256_storeu_si256(lanes.as_mut_ptr() as *mut __m256i, acc);
+    lanes.iter().sum::<u64>() as u32 + manhattan_u4_simple(&v1[simd_len..], &v2[simd_len..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::metric_u4::tests::test_vectors;
+
+    #[test]
+    fn test_spaces_avx() {
+        if is_x86_feature_detected!("avx2") {
+            let (v1, v2) = test_vectors();
+
+            unsafe {
+                assert_eq!(avx_dot_u4(&v1, &v2), dot_u4_simple(&v1, &v2));
+                assert_eq!(avx_euclid_u4(&v1, &v2), euclid_u4_simple(&v1, &v2));
+                assert_eq!(avx_manhattan_u4(&v1, &v2), manhattan_u4_simple(&v1, &v2));
+            }
+        } else {
+            println!("avx2 test skipped");
+        }
+    }
+}
@@ -0,0 +1,161 @@
+//! Distance kernels for 4-bit codes, packed two per byte.
+//!
+//! The lower nibble of each byte holds one code, the higher nibble holds the next one.
+//! Kernels return raw integer sums over codes, scaling is up to the caller.
+
+#[cfg(target_arch = "x86_64")]
+pub mod avx2;
+
+#[cfg(target_arch = "aarch64")]
+pub mod neon;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub mod sse2;
+
+#[cfg(target_arch = "x86_64")]
+use crate::spaces::simple::MIN_DIM_SIZE_AVX;
+use crate::spaces::simple::MIN_DIM_SIZE_SIMD;
+
+/// Sum of products of codes
+pub fn dot_u4(v1: &[u8], v2: &[u8]) -> u32 {
+    debug_assert!(v1.len() == v2.len());
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") && v1.len() >= MIN_DIM_SIZE_AVX {
+            return unsafe { avx2::avx_dot_u4(v1, v2) };
+        }
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if is_x86_feature_detected!("sse2") && v1.len() >= MIN_DIM_SIZE_SIMD {
+            return unsafe { sse2::sse_dot_u4(v1, v2) };
+        }
+    }
+
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") && v1.len() >= MIN_DIM_SIZE_SIMD {
+            return unsafe { neon::neon_dot_u4(v1, v2) };
+        }
+    }
+
+    dot_u4_simple(v1, v2)
+}
+
+/// Sum of squared differences of codes
+pub fn euclid_u4(v1: &[u8], v2: &[u8]) -> u32 {
+    debug_assert!(v1.len() == v2.len());
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") && v1.len() >= MIN_DIM_SIZE_AVX {
+            return unsafe { avx2::avx_euclid_u4(v1, v2) };
+        }
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if is_x86_feature_detected!("sse2") && v1.len() >= MIN_DIM_SIZE_SIMD {
+            return unsafe { sse2::sse_euclid_u4(v1, v2) };
+        }
+    }
+
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") && v1.len() >= MIN_DIM_SIZE_SIMD {
+            return unsafe { neon::neon_euclid_u4(v1, v2) };
+        }
+    }
+
+    euclid_u4_simple(v1, v2)
+}
+
+/// Sum of absolute differences of codes
+pub fn manhattan_u4(v1: &[u8], v2: &[u8]) -> u32 {
+    debug_assert!(v1.len() == v2.len());
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") && v1.len() >= MIN_DIM_SIZE_AVX {
+            return unsafe { avx2::avx_manhattan_u4(v1, v2) };
+        }
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if is_x86_feature_detected!("sse2") && v1.len() >= MIN_DIM_SIZE_SIMD {
+            return unsafe { sse2::sse_manhattan_u4(v1, v2) };
+        }
+    }
+
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") && v1.len() >= MIN_DIM_SIZE_SIMD {
+            return unsafe { neon::neon_manhattan_u4(v1, v2) };
+        }
+    }
+
+    manhattan_u4_simple(v1, v2)
+}
+
+#[inline]
+fn nibbles(byte: u8) -> (u32, u32) {
+    ((byte & 0x0F) as u32, (byte >> 4) as u32)
+}
+
+pub fn dot_u4_simple(v1: &[u8], v2: &[u8]) -> u32 {
+    v1.iter()
+        .zip(v2)
+        .map(|(&a, &b)| {
+            let (a_low, a_high) = nibbles(a);
+            let (b_low, b_high) = nibbles(b);
+            a_low * b_low + a_high * b_high
+        })
+        .sum()
+}
+
+pub fn euclid_u4_simple(v1: &[u8], v2: &[u8]) -> u32 {
+    v1.iter()
+        .zip(v2)
+        .map(|(&a, &b)| {
+            let (a_low, a_high) = nibbles(a);
+            let (b_low, b_high) = nibbles(b);
+            a_low.abs_diff(b_low).pow(2) + a_high.abs_diff(b_high).pow(2)
+        })
+        .sum()
+}
+
+pub fn manhattan_u4_simple(v1: &[u8], v2: &[u8]) -> u32 {
+    v1.iter()
+        .zip(v2)
+        .map(|(&a, &b)| {
+            let (a_low, a_high) = nibbles(a);
+            let (b_low, b_high) = nibbles(b);
+            a_low.abs_diff(b_low) + a_high.abs_diff(b_high)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Packed codes of varying length, covering all nibble values
+    pub fn test_vectors() -> (Vec<u8>, Vec<u8>) {
+        let v1: Vec<u8> = (0..101u32).map(|i| (i * 37 % 256) as u8).collect();
+        let v2: Vec<u8> = (0..101u32).map(|i| (255 - i * 11 % 256) as u8).collect();
+        (v1, v2)
+    }
+
+    #[test]
+    fn test_simple_u4() {
+        // codes [1, 2] and [3, 15]
+        let v1 = [0x21];
+        let v2 = [0xF3];
+        assert_eq!(dot_u4_simple(&v1, &v2), 3 + 2 * 15);
+        assert_eq!(euclid_u4_simple(&v1, &v2), 2 * 2 + 13 * 13);
+        assert_eq!(manhattan_u4_simple(&v1, &v2), 2 + 13);
+    }
+}
@@ -0,0 +1,101 @@
+use std::arch::aarch64::*;
+
+use super::{dot_u4_simple, euclid_u4_simple, manhattan_u4_simple};
+
+/// Split 16 bytes of packed codes into lower and higher nibbles
+#[target_feature(enable = "neon")]
+unsafe fn unpack_u4_neon(ptr: *const u8) -> (uint8x16_t, uint8x16_t) {
+    let packed = vld1q_u8(ptr);
+    let low = vandq_u8(packed, vdupq_n_u8(0x0F));
+    let high = vshrq_n_u8(packed, 4);
+    (low, high)
+}
+
+/// Add products of 16x8 bit integers to 4x32 bit accumulator
+#[target_feature(enable = "neon")]
+unsafe fn mul_acc_u8_neon(acc: uint32x4_t, a: uint8x16_t, b: uint8x16_t) -> uint32x4_t {
+    let acc = vpadalq_u16(acc, vmull_u8(vget_low_u8(a), vget_low_u8(b)));
+    vpadalq_u16(acc, vmull_u8(vget_high_u8(a), vget_high_u8(b)))
+}
+
+#[target_feature(enable = "neon")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn neon_dot_u4(v1: &[u8], v2: &[u8]) -> u32 {
+    debug_assert!(v1.len() == v2.len());
+
+    let mut acc = vdupq_n_u32(0);
+    let len = v1.len();
+    let simd_len = len - len % 16;
+    for i in (0..simd_len).step_by(16) {
+        let (p1_low, p1_high) = unpack_u4_neon(v1.as_ptr().add(i));
+        let (p2_low, p2_high) = unpack_u4_neon(v2.as_ptr().add(i));
+
+        acc = mul_acc_u8_neon(acc, p1_low, p2_low);
+        acc = mul_acc_u8_neon(acc, p1_high, p2_high);
+    }
+
+    vaddvq_u32(acc) + dot_u4_simple(&v1[simd_len..], &v2[simd_len..])
+}
+
+#[target_feature(enable = "neon")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn neon_euclid_u4(v1: &[u8], v2: &[u8]) -> u32 {
+    debug_assert!(v1.len() == v2.len());
+
+    let mut acc = vdupq_n_u32(0);
+    let len = v1.len();
+    let simd_len = len - len % 16;
+    for i in (0..simd_len).step_by(16) {
+        let (p1_low, p1_high) = unpack_u4_neon(v1.as_ptr().add(i));
+        let (p2_low, p2_high) = unpack_u4_neon(v2.as_ptr().add(i));
+
+        let diff_low = vabdq_u8(p1_low, p2_low);
+        let diff_high = vabdq_u8(p1_high, p2_high);
+        acc = mul_acc_u8_neon(acc, diff_low, diff_low);
+        acc = mul_acc_u8_neon(acc, diff_high, diff_high);
+    }
+
+    vaddvq_u32(acc) + euclid_u4_simple(&v1[simd_len..], &v2[simd_len..])
+}
+
+#[target_feature(enable = "neon")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn neon_manhattan_u4(v1: &[u8], v2: &[u8]) -> u32 {
+    debug_assert!(v1.len() == v2.len());
+
+    let mut acc = vdupq_n_u32(0);
+    let len = v1.len();
+    let simd_len = len - len % 16;
+    for i in (0..simd_len).step_by(16) {
+        let (p1_low, p1_high) = unpack_u4_neon(v1.as_ptr().add(i));
+        let (p2_low, p2_high) = unpack_u4_neon(v2.as_ptr().add(i));
+
+        let diff = vaddq_u8(vabdq_u8(p1_low, p2_low), vabdq_u8(p1_high, p2_high));
+        acc = vpadalq_u16(acc, vpaddlq_u8(diff));
+    }
+
+    vaddvq_u32(acc) + manhattan_u4_simple(&v1[simd_len..], &v2[simd_len..])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::arch::is_aarch64_feature_detected;
+
+    use super::*;
+    use crate::spaces::metric_u4::tests::test_vectors;
+
+    #[test]
+    fn test_spaces_neon() {
+        if is_aarch64_feature_detected!("neon") {
+            let (v1, v2) = test_vectors();
+
+            unsafe {
+                assert_eq!(neon_dot_u4(&v1, &v2), dot_u4_simple(&v1, &v2));
+                assert_eq!(neon_euclid_u4(&v1, &v2), euclid_u4_simple(&v1, &v2));
+                assert_eq!(neon_manhattan_u4(&v1, &v2), manhattan_u4_simple(&v1, &v2));
+            }
+        } else {
+            println!("neon test skipped");
+        }
+    }
+}
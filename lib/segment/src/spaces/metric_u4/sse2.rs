@@ -0,0 +1,124 @@
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use super::{dot_u4_simple, euclid_u4_simple, manhattan_u4_simple};
+
+#[target_feature(enable = "sse2")]
+unsafe fn hsum128_epi32_sse(x: __m128i) -> u32 {
+    let mut lanes = [0i32; 4];
+    _mm_storeu_si128(lanes.as_mut_ptr() as *mut __m128i, x);
+    lanes.iter().sum::<i32>() as u32
+}
+
+/// Split 16 bytes of packed codes into lower and higher nibbles
+#[target_feature(enable = "sse2")]
+unsafe fn unpack_u4_sse(ptr: *const u8) -> (__m128i, __m128i) {
+    let mask_u4 = _mm_set1_epi8(0x0F);
+    let packed = _mm_loadu_si128(ptr as *const __m128i);
+    let low = _mm_and_si128(packed, mask_u4);
+    let high = _mm_and_si128(_mm_srli_epi16(packed, 4), mask_u4);
+    (low, high)
+}
+
+/// Widen 16x8 bit codes into two vectors of 8x16 bit integers
+#[target_feature(enable = "sse2")]
+unsafe fn widen_u8_sse(x: __m128i) -> (__m128i, __m128i) {
+    let zero = _mm_setzero_si128();
+    (_mm_unpacklo_epi8(x, zero), _mm_unpackhi_epi8(x, zero))
+}
+
+#[target_feature(enable = "sse2")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn sse_dot_u4(v1: &[u8], v2: &[u8]) -> u32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("sse2"));
+
+    let mut acc = _mm_setzero_si128();
+    let len = v1.len();
+    let simd_len = len - len % 16;
+    for i in (0..simd_len).step_by(16) {
+        let (p1_low, p1_high) = unpack_u4_sse(v1.as_ptr().add(i));
+        let (p2_low, p2_high) = unpack_u4_sse(v2.as_ptr().add(i));
+
+        for (p1, p2) in [(p1_low, p2_low), (p1_high, p2_high)] {
+            let (p1_first, p1_second) = widen_u8_sse(p1);
+            let (p2_first, p2_second) = widen_u8_sse(p2);
+            acc = _mm_add_epi32(acc, _mm_madd_epi16(p1_first, p2_first));
+            acc = _mm_add_epi32(acc, _mm_madd_epi16(p1_second, p2_second));
+        }
+    }
+
+    hsum128_epi32_sse(acc) + dot_u4_simple(&v1[simd_len..], &v2[simd_len..])
+}
+
+#[target_feature(enable = "sse2")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn sse_euclid_u4(v1: &[u8], v2: &[u8]) -> u32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("sse2"));
+
+    let mut acc = _mm_setzero_si128();
+    let len = v1.len();
+    let simd_len = len - len % 16;
+    for i in (0..simd_len).step_by(16) {
+        let (p1_low, p1_high) = unpack_u4_sse(v1.as_ptr().add(i));
+        let (p2_low, p2_high) = unpack_u4_sse(v2.as_ptr().add(i));
+
+        for (p1, p2) in [(p1_low, p2_low), (p1_high, p2_high)] {
+            let (p1_first, p1_second) = widen_u8_sse(p1);
+            let (p2_first, p2_second) = widen_u8_sse(p2);
+            let diff_first = _mm_sub_epi16(p1_first, p2_first);
+            let diff_second = _mm_sub_epi16(p1_second, p2_second);
+            acc = _mm_add_epi32(acc, _mm_madd_epi16(diff_first, diff_first));
+            acc = _mm_add_epi32(acc, _mm_madd_epi16(diff_second, diff_second));
+        }
+    }
+
+    hsum128_epi32_sse(acc) + euclid_u4_simple(&v1[simd_len..], &v2[simd_len..])
+}
+
+#[target_feature(enable = "sse2")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn sse_manhattan_u4(v1: &[u8], v2: &[u8]) -> u32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("sse2"));
+
+    let mut acc = _mm_setzero_si128();
+    let len = v1.len();
+    let simd_len = len - len % 16;
+    for i in (0..simd_len).step_by(16) {
+        let (p1_low, p1_high) = unpack_u4_sse(v1.as_ptr().add(i));
+        let (p2_low, p2_high) = unpack_u4_sse(v2.as_ptr().add(i));
+
+        // sum of absolute differences into 2x64 bit integers
+        acc = _mm_add_epi64(acc, _mm_sad_epu8(p1_low, p2_low));
+        acc = _mm_add_epi64(acc, _mm_sad_epu8(p1_high, p2_high));
+    }
+
+    let mut lanes = [0u64; 2];
+    _mm_storeu_si128(lanes.as_mut_ptr() as *mut __m128i, acc);
+    lanes.iter().sum::<u64>() as u32 + manhattan_u4_simple(&v1[simd_len..], &v2[simd_len..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::metric_u4::tests::test_vectors;
+
+    #[test]
+    fn test_spaces_sse() {
+        if is_x86_feature_detected!("sse2") {
+            let (v1, v2) = test_vectors();
+
+            unsafe {
+                assert_eq!(sse_dot_u4(&v1, &v2), dot_u4_simple(&v1, &v2));
+                assert_eq!(sse_euclid_u4(&v1, &v2), euclid_u4_simple(&v1, &v2));
+                assert_eq!(sse_manhattan_u4(&v1, &v2), manhattan_u4_simple(&v1, &v2));
+            }
+        } else {
+            println!("sse2 test skipped");
+        }
+    }
+}
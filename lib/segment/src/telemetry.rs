@@ -137,8 +137,10 @@ impl Anonymize for VectorDataConfig {
             storage_type: self.storage_type,
             index: self.index.clone(),
             quantization_config: self.quantization_config.clone(),
+            additional_quantization_configs: self.additional_quantization_configs.clone(),
             multivector_config: self.multivector_config,
             datatype: self.datatype,
+            prefix_of: self.prefix_of.anonymize(),
         }
     }
 }
@@ -147,6 +149,7 @@ impl Anonymize for SparseVectorDataConfig {
     fn anonymize(&self) -> Self {
         SparseVectorDataConfig {
             index: self.index.anonymize(),
+            storage_type: self.storage_type,
         }
     }
 }
@@ -27,13 +27,16 @@ use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::utils::{self, MaybeOneOrMany, MultiValue};
 use crate::data_types::integer_index::IntegerIndexParams;
 use crate::data_types::order_by::OrderValue;
+use crate::data_types::relative_datetime::RelativeDatetimeRange;
 use crate::data_types::text_index::TextIndexParams;
 use crate::data_types::vectors::VectorStructInternal;
 use crate::index::field_index::CardinalityEstimation;
 use crate::index::sparse_index::sparse_index_config::SparseIndexConfig;
 use crate::json_path::{JsonPath, JsonPathInterface};
 use crate::spaces::metric::MetricPostProcessing;
-use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+};
 use crate::vector_storage::simple_sparse_vector_storage::SPARSE_VECTOR_DISTANCE;
 
 pub type PayloadKeyType = JsonPath;
@@ -164,6 +167,10 @@ pub enum Distance {
     Dot,
     // <https://simple.wikipedia.org/wiki/Manhattan_distance>
     Manhattan,
+    // <https://en.wikipedia.org/wiki/Hamming_distance>
+    Hamming,
+    // <https://en.wikipedia.org/wiki/Jaccard_index>
+    Jaccard,
 }
 
 impl Distance {
@@ -173,16 +180,25 @@ impl Distance {
             Distance::Euclid => EuclidMetric::postprocess(score),
             Distance::Dot => DotProductMetric::postprocess(score),
             Distance::Manhattan => ManhattanMetric::postprocess(score),
+            Distance::Hamming => HammingMetric::postprocess(score),
+            Distance::Jaccard => JaccardMetric::postprocess(score),
         }
     }
 
     pub fn distance_order(&self) -> Order {
         match self {
             Distance::Cosine | Distance::Dot => Order::LargeBetter,
-            Distance::Euclid | Distance::Manhattan => Order::SmallBetter,
+            Distance::Euclid | Distance::Manhattan | Distance::Hamming | Distance::Jaccard => {
+                Order::SmallBetter
+            }
         }
     }
 
+    /// Whether the distance compares bit vectors, only used with the `Bit` datatype
+    pub fn is_bit(&self) -> bool {
+        matches!(self, Distance::Hamming | Distance::Jaccard)
+    }
+
     /// Checks if score satisfies threshold condition
     pub fn check_threshold(&self, score: ScoreType, threshold: ScoreType) -> bool {
         match self.distance_order() {
@@ -215,6 +231,15 @@ pub struct ScoredPoint {
     pub shard_key: Option<ShardKey>,
     /// Order-by value
     pub order_value: Option<OrderValue>,
+    /// Values of the secondary order-by keys, used to break ties of `order_value`
+    pub then_order_values: Vec<OrderValue>,
+}
+
+impl ScoredPoint {
+    /// Values of all order-by keys of this point, starting with the primary one
+    pub fn order_values(&self) -> impl Iterator<Item = &OrderValue> {
+        self.order_value.iter().chain(&self.then_order_values)
+    }
 }
 
 impl Eq for ScoredPoint {}
@@ -338,6 +363,11 @@ pub struct QuantizationSearchParams {
     #[serde(default = "default_quantization_oversampling_value")]
     #[validate(range(min = 1.0))]
     pub oversampling: Option<f64>,
+
+    /// Kind of quantization to use for the search, if multiple quantizations are configured for the vector.
+    /// If not set, the primary quantization config is used. Search fails if the vector has no quantization of this kind.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quantization: Option<QuantizationKind>,
 }
 
 pub const fn default_quantization_ignore_value() -> bool {
@@ -512,6 +542,7 @@ pub enum CompressionRatio {
 pub enum ScalarType {
     #[default]
     Int8,
+    Int4,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq)]
@@ -519,6 +550,7 @@ pub enum ScalarType {
 pub struct ScalarQuantizationConfig {
     /// Type of quantization to use
     /// If `int8` - 8 bit quantization will be used
+    /// If `int4` - 4 bit quantization will be used
     pub r#type: ScalarType,
     /// Quantile for quantization. Expected value range in [0.5, 1.0]. If not set - use the whole range of values
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -581,11 +613,61 @@ impl std::hash::Hash for ScalarQuantizationConfig {
 
 impl Eq for ScalarQuantizationConfig {}
 
+/// Encoding of vector dimensions in binary quantization
+#[derive(Default, Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum BinaryQuantizationEncoding {
+    /// One bit per dimension: whether the value is positive
+    #[default]
+    OneBit,
+    /// Two bits per dimension: whether the value is low, medium or high
+    TwoBits,
+    /// Three bits per pair of dimensions: whether each value is not low, and whether any of them is high
+    OneAndHalfBits,
+}
+
+/// Encoding of the query, which is scored against binary quantized vectors
+#[derive(Default, Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum BinaryQuantizationQueryEncoding {
+    /// Query is encoded the same way as the stored vectors
+    #[default]
+    Binary,
+    /// Query is quantized to 4 bits per value and scored asymmetrically
+    #[serde(rename = "scalar4bits")]
+    Scalar4Bits,
+    /// Query is quantized to 8 bits per value and scored asymmetrically
+    #[serde(rename = "scalar8bits")]
+    Scalar8Bits,
+}
+
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 pub struct BinaryQuantizationConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub always_ram: Option<bool>,
+    /// Number of bits used to encode each dimension.
+    /// More bits improve precision at the cost of memory. Default is `one_bit`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<BinaryQuantizationEncoding>,
+    /// Encoding of the query vector.
+    /// Scalar encodings keep more precision of the query without using more memory for the stored vectors,
+    /// at the cost of slower scoring. Default is `binary`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query_encoding: Option<BinaryQuantizationQueryEncoding>,
+}
+
+impl BinaryQuantizationConfig {
+    /// Detect configuration mismatch against `other` that requires rebuilding
+    ///
+    /// Returns true only if both conditions are met:
+    /// - this configuration does not match `other`
+    /// - to effectively change the configuration, a quantization rebuild is required
+    pub fn mismatch_requires_rebuild(&self, other: &Self) -> bool {
+        self.always_ram != other.always_ram
+            || self.encoding.unwrap_or_default() != other.encoding.unwrap_or_default()
+            || self.query_encoding.unwrap_or_default() != other.query_encoding.unwrap_or_default()
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq, Hash)]
@@ -602,14 +684,36 @@ pub enum QuantizationConfig {
     Binary(BinaryQuantization),
 }
 
+/// Kind of quantization, used to select one of multiple quantizations of a vector
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum QuantizationKind {
+    Scalar,
+    Product,
+    Binary,
+}
+
 impl QuantizationConfig {
+    pub fn kind(&self) -> QuantizationKind {
+        match self {
+            QuantizationConfig::Scalar(_) => QuantizationKind::Scalar,
+            QuantizationConfig::Product(_) => QuantizationKind::Product,
+            QuantizationConfig::Binary(_) => QuantizationKind::Binary,
+        }
+    }
+
     /// Detect configuration mismatch against `other` that requires rebuilding
     ///
     /// Returns true only if both conditions are met:
     /// - this configuration does not match `other`
     /// - to effectively change the configuration, a quantization rebuild is required
     pub fn mismatch_requires_rebuild(&self, other: &Self) -> bool {
-        self != other
+        match (self, other) {
+            (QuantizationConfig::Binary(this), QuantizationConfig::Binary(other)) => {
+                this.binary.mismatch_requires_rebuild(&other.binary)
+            }
+            _ => self != other,
+        }
     }
 }
 
@@ -720,6 +824,16 @@ impl SegmentConfig {
             .and_then(|v| v.quantization_config.as_ref())
     }
 
+    /// Helper to get vector specific additional quantization configs.
+    ///
+    /// These are built next to the primary quantization config and can be selected at search time.
+    pub fn additional_quantization_configs(&self, vector_name: &str) -> &[QuantizationConfig] {
+        self.vector_data
+            .get(vector_name)
+            .map(|v| v.additional_quantization_configs.as_slice())
+            .unwrap_or_default()
+    }
+
     pub fn distance(&self, vector_name: &str) -> Option<Distance> {
         let distance = self
             .vector_data
@@ -811,6 +925,13 @@ pub enum VectorStorageDatatype {
     Float16,
     // Unsigned 8-bit integer
     Uint8,
+    // Packed bits, 8 dimensions per byte
+    Bit,
+    // Brain floating point, truncated single-precision
+    #[serde(rename = "bfloat16")]
+    BFloat16,
+    // Signed 8-bit integer
+    Int8,
 }
 
 #[derive(Debug, Default, Deserialize, Serialize, JsonSchema, Eq, PartialEq, Copy, Clone, Hash)]
@@ -818,6 +939,9 @@ pub enum VectorStorageDatatype {
 pub struct MultiVectorConfig {
     /// How to compare multivector points
     pub comparator: MultiVectorComparator,
+    /// Which vectors are used as nodes of the HNSW graph. Default: point
+    #[serde(default)]
+    pub index_mode: MultiVectorIndexMode,
 }
 
 #[derive(Debug, Default, Deserialize, Serialize, JsonSchema, Eq, PartialEq, Copy, Clone, Hash)]
@@ -827,6 +951,19 @@ pub enum MultiVectorComparator {
     MaxSim,
 }
 
+/// Which vectors are used as nodes of the HNSW graph of a multivector
+#[derive(Debug, Default, Deserialize, Serialize, JsonSchema, Eq, PartialEq, Copy, Clone, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum MultiVectorIndexMode {
+    /// Each point is a single node of the HNSW graph, compared with the multivector comparator
+    #[default]
+    Point,
+    /// Each sub-vector is a separate node of the HNSW graph.
+    /// Sub-vector hits are mapped back to their points, which are then rescored with the comparator.
+    /// Recommend, discovery and context queries are answered with an exact search.
+    Token,
+}
+
 impl VectorStorageType {
     /// Whether this storage type is a mmap on disk
     pub fn is_on_disk(&self) -> bool {
@@ -851,15 +988,46 @@ pub struct VectorDataConfig {
     pub index: Indexes,
     /// Vector specific quantization config that overrides collection config
     pub quantization_config: Option<QuantizationConfig>,
+    /// Vector specific quantization configs, which are built in addition to the primary one
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub additional_quantization_configs: Vec<QuantizationConfig>,
     /// Vector specific configuration to enable multiple vectors per point
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub multivector_config: Option<MultiVectorConfig>,
     /// Vector specific configuration to set specific storage element type
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub datatype: Option<VectorStorageDatatype>,
+    /// Name of the dense vector this vector is a prefix view of
+    ///
+    /// A prefix view has no storage of its own. It reads the first `size` dimensions of the
+    /// source vector and only maintains its own index over them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefix_of: Option<String>,
 }
 
 impl VectorDataConfig {
+    /// Number of leading dimensions to read from the source storage, if this is a prefix view
+    pub fn prefix_dim(&self) -> Option<usize> {
+        self.prefix_of.as_ref().map(|_| self.size)
+    }
+
+    /// Number of elements in a stored vector
+    ///
+    /// Bit vectors are packed, so each element holds 8 dimensions.
+    pub fn storage_dim(&self) -> usize {
+        match self.datatype {
+            Some(VectorStorageDatatype::Bit) => self.size.div_ceil(u8::BITS as usize),
+            Some(
+                VectorStorageDatatype::Float32
+                | VectorStorageDatatype::Float16
+                | VectorStorageDatatype::Uint8
+                | VectorStorageDatatype::BFloat16
+                | VectorStorageDatatype::Int8,
+            )
+            | None => self.size,
+        }
+    }
+
     /// Whether this vector data can be appended to
     ///
     /// This requires an index and storage type that both support appending.
@@ -883,6 +1051,25 @@ impl VectorDataConfig {
 pub struct SparseVectorDataConfig {
     /// Sparse inverted index config
     pub index: SparseIndexConfig,
+    /// Type of storage this sparse vector uses
+    #[serde(default = "default_sparse_vector_storage_type_when_not_in_config")]
+    pub storage_type: SparseVectorStorageType,
+}
+
+/// Segments created before mmap sparse storage existed keep their sparse vectors in RocksDB
+const fn default_sparse_vector_storage_type_when_not_in_config() -> SparseVectorStorageType {
+    SparseVectorStorageType::OnDisk
+}
+
+/// Storage types for sparse vectors
+#[derive(Default, Debug, Deserialize, Serialize, JsonSchema, Eq, PartialEq, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum SparseVectorStorageType {
+    /// Storage in RocksDB
+    OnDisk,
+    /// Storage in chunked mmap files, appendable
+    #[default]
+    Mmap,
 }
 
 impl SparseVectorDataConfig {
@@ -1476,6 +1663,17 @@ impl From<Vec<IntPayloadType>> for MatchExcept {
 pub enum RangeInterface {
     Float(Range<FloatPayloadType>),
     DateTime(Range<DateTimePayloadType>),
+    /// Resolved into [`RangeInterface::DateTime`] once per request, before it reaches the segments
+    RelativeDateTime(RelativeDatetimeRange),
+}
+
+impl RangeInterface {
+    /// Replace bounds relative to the time of the request with absolute datetimes
+    pub fn resolve_relative(&mut self, now: chrono::DateTime<chrono::Utc>) {
+        if let RangeInterface::RelativeDateTime(range) = self {
+            *self = RangeInterface::DateTime(range.resolve(now));
+        }
+    }
 }
 
 /// Range filter request
@@ -2326,6 +2524,41 @@ impl Filter {
             (Some(this), Some(other)) => Some(this.merge_owned(other)),
         }
     }
+
+    /// Resolve relative datetime ranges, so that all segments and replicas see the same bounds
+    pub fn resolve_relative_datetimes(&mut self, now: chrono::DateTime<chrono::Utc>) {
+        let Filter {
+            should,
+            min_should,
+            must,
+            must_not,
+        } = self;
+
+        let conditions = [should, must, must_not]
+            .into_iter()
+            .flatten()
+            .flatten()
+            .chain(
+                min_should
+                    .iter_mut()
+                    .flat_map(|min_should| &mut min_should.conditions),
+            );
+
+        for condition in conditions {
+            match condition {
+                Condition::Field(FieldCondition {
+                    range: Some(range), ..
+                }) => range.resolve_relative(now),
+                Condition::Nested(nested) => nested.nested.filter.resolve_relative_datetimes(now),
+                Condition::Filter(filter) => filter.resolve_relative_datetimes(now),
+                Condition::Field(_)
+                | Condition::IsEmpty(_)
+                | Condition::IsNull(_)
+                | Condition::HasId(_)
+                | Condition::Resharding(_) => {}
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -15,6 +15,8 @@ struct BufferMeta {
     pub index: usize,
     /// Id of the point that is currently being processed
     pub point_id: PointOffsetType,
+    /// Number of bytes being read for the point
+    pub length: usize,
 }
 
 struct Buffer {
@@ -75,6 +77,24 @@ impl<T: PrimitiveVectorElement> UringReader<T> {
     pub fn read_stream(
         &mut self,
         points: impl IntoIterator<Item = PointOffsetType>,
+        callback: impl FnMut(usize, PointOffsetType, &[T]),
+    ) -> OperationResult<()> {
+        let header_size = self.header_size;
+        let raw_size = self.raw_size;
+        let ranges = points.into_iter().map(move |point| {
+            let offset = header_size + raw_size * point as usize;
+            (point, offset, raw_size)
+        });
+        self.read_ranges(ranges, callback)
+    }
+
+    /// Takes in iterator of point offsets along with the byte offset and length of their data in
+    /// the file, reads it, and yields a callback with the read data.
+    ///
+    /// Allows reading data of variable size, such as multivectors.
+    pub fn read_ranges(
+        &mut self,
+        ranges: impl IntoIterator<Item = (PointOffsetType, usize, usize)>,
         mut callback: impl FnMut(usize, PointOffsetType, &[T]),
     ) -> OperationResult<()> {
         // Take `UringReader::io_uring`, so that if we return an error or panic during `read_stream`,
@@ -89,8 +109,8 @@ impl<T: PrimitiveVectorElement> UringReader<T> {
         let buffers_count = self.buffers.buffers.len();
         let mut unused_buffer_ids = (0..buffers_count).collect::<Vec<_>>();
 
-        for item in points.into_iter().enumerate() {
-            let (idx, point): (usize, PointOffsetType) = item;
+        for item in ranges.into_iter().enumerate() {
+            let (idx, (point, offset, length)): (usize, (PointOffsetType, usize, usize)) = item;
 
             if unused_buffer_ids.is_empty() {
                 submit_and_read(
@@ -98,7 +118,6 @@ impl<T: PrimitiveVectorElement> UringReader<T> {
                     &mut self.buffers,
                     &mut unused_buffer_ids,
                     &mut callback,
-                    self.raw_size,
                 )?;
             }
             // Assume there is at least one buffer available at this point
@@ -107,17 +126,20 @@ impl<T: PrimitiveVectorElement> UringReader<T> {
             self.buffers.buffers[buffer_id].meta = Some(BufferMeta {
                 index: idx,
                 point_id: point,
+                length,
             });
 
             let buffer = &mut self.buffers.buffers[buffer_id].buffer;
-            let offset = self.header_size + self.raw_size * point as usize;
+            if buffer.len() < length {
+                buffer.resize(length, 0);
+            }
 
             let user_data = buffer_id;
 
             let read_e = opcode::Read::new(
                 types::Fd(self.file.as_raw_fd()),
                 buffer.as_mut_ptr(),
-                buffer.len() as _,
+                length as _,
             )
             .offset(offset as _)
             .build()
@@ -139,7 +161,6 @@ impl<T: PrimitiveVectorElement> UringReader<T> {
                 &mut self.buffers,
                 &mut unused_buffer_ids,
                 &mut callback,
-                self.raw_size,
             )?;
 
             operations_to_wait_for = self.buffers.buffers.len() - unused_buffer_ids.len();
@@ -156,7 +177,6 @@ fn submit_and_read<T: PrimitiveVectorElement>(
     buffers: &mut BufferStore,
     unused_buffer_ids: &mut Vec<usize>,
     mut callback: impl FnMut(usize, PointOffsetType, &[T]),
-    raw_size: usize,
 ) -> OperationResult<()> {
     let buffers_count = buffers.buffers.len();
     let used_buffers_count = buffers_count - unused_buffer_ids.len();
@@ -167,21 +187,21 @@ fn submit_and_read<T: PrimitiveVectorElement>(
     let cqe = io_uring.completion();
     for entry in cqe {
         let result = entry.result();
+        let buffer_id = entry.user_data() as usize;
+        let meta = buffers.buffers[buffer_id].meta.take().unwrap();
         if result < 0 {
             return Err(OperationError::service_error(format!(
                 "io_uring operation failed with {} error",
                 result
             )));
-        } else if (result as usize) != raw_size {
+        } else if (result as usize) != meta.length {
             return Err(OperationError::service_error(format!(
                 "io_uring operation returned {} bytes instead of {}",
-                result, raw_size
+                result, meta.length
             )));
         }
 
-        let buffer_id = entry.user_data() as usize;
-        let meta = buffers.buffers[buffer_id].meta.take().unwrap();
-        let buffer = &buffers.buffers[buffer_id].buffer;
+        let buffer = &buffers.buffers[buffer_id].buffer[..meta.length];
         let vector = transmute_from_u8_to_slice(buffer);
         callback(meta.index, meta.point_id, vector);
         unused_buffer_ids.push(buffer_id);
@@ -1,3 +1,4 @@
+use std::marker::PhantomData;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use bitvec::prelude::BitSlice;
@@ -6,14 +7,22 @@ use common::types::{PointOffsetType, ScoreType, ScoredPointOffset};
 
 use super::query::{ContextQuery, DiscoveryQuery, RecoQuery, TransformInto};
 use super::query_scorer::custom_query_scorer::CustomQueryScorer;
+use super::query_scorer::multi_custom_query_scorer::MultiCustomQueryScorer;
 use crate::common::operation_error::{OperationError, OperationResult};
-use crate::data_types::vectors::{DenseVector, QueryVector, Vector, VectorElementType};
+use crate::data_types::vectors::{
+    DenseVector, MultiDenseVectorInternal, QueryVector, Vector, VectorElementType,
+};
 use crate::spaces::metric::Metric;
-use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+};
 use crate::types::Distance;
 use crate::vector_storage::dense::memmap_dense_vector_storage::MemmapDenseVectorStorage;
 use crate::vector_storage::dense::mmap_dense_vectors::MmapDenseVectors;
+use crate::vector_storage::multi_dense::memmap_multi_dense_vector_storage::MemmapMultiDenseVectorStorage;
+use crate::vector_storage::multi_dense::mmap_multi_dense_vectors::MmapMultiDenseVectors;
 use crate::vector_storage::query_scorer::metric_query_scorer::MetricQueryScorer;
+use crate::vector_storage::query_scorer::multi_metric_query_scorer::MultiMetricQueryScorer;
 use crate::vector_storage::query_scorer::QueryScorer;
 use crate::vector_storage::{RawScorer, VectorStorage as _, DEFAULT_STOPPED};
 
@@ -28,25 +37,170 @@ pub fn new<'a>(
         .build()
 }
 
-pub struct AsyncRawScorerImpl<'a, TQueryScorer: QueryScorer<[VectorElementType]>> {
+/// Storage which is able to read stored vectors through asynchronous IO
+pub trait AsyncVectorsReader<TVector: ?Sized> {
+    fn read_vectors_async(
+        &self,
+        points: impl Iterator<Item = PointOffsetType>,
+        callback: impl FnMut(usize, PointOffsetType, &TVector),
+    ) -> OperationResult<()>;
+}
+
+impl AsyncVectorsReader<[VectorElementType]> for MmapDenseVectors<VectorElementType> {
+    fn read_vectors_async(
+        &self,
+        points: impl Iterator<Item = PointOffsetType>,
+        callback: impl FnMut(usize, PointOffsetType, &[VectorElementType]),
+    ) -> OperationResult<()> {
+        MmapDenseVectors::read_vectors_async(self, points, callback)
+    }
+}
+
+impl AsyncVectorsReader<MultiDenseVectorInternal> for MmapMultiDenseVectors<VectorElementType> {
+    fn read_vectors_async(
+        &self,
+        points: impl Iterator<Item = PointOffsetType>,
+        mut callback: impl FnMut(usize, PointOffsetType, &MultiDenseVectorInternal),
+    ) -> OperationResult<()> {
+        // Read buffers are reused between points, so the multivector must be copied out
+        self.read_multi_vectors_async(points, |idx, point, multi_vector| {
+            let multi_vector = MultiDenseVectorInternal::new(
+                multi_vector.flattened_vectors.to_vec(),
+                multi_vector.dim,
+            );
+            callback(idx, point, &multi_vector)
+        })
+    }
+}
+
+pub fn new_multi<'a>(
+    query: QueryVector,
+    storage: &'a MemmapMultiDenseVectorStorage<VectorElementType>,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    match storage.distance() {
+        Distance::Cosine => {
+            new_multi_with_metric::<CosineMetric>(query, storage, point_deleted, is_stopped)
+        }
+        Distance::Euclid => {
+            new_multi_with_metric::<EuclidMetric>(query, storage, point_deleted, is_stopped)
+        }
+        Distance::Dot => {
+            new_multi_with_metric::<DotProductMetric>(query, storage, point_deleted, is_stopped)
+        }
+        Distance::Manhattan => {
+            new_multi_with_metric::<ManhattanMetric>(query, storage, point_deleted, is_stopped)
+        }
+        Distance::Hamming => {
+            new_multi_with_metric::<HammingMetric>(query, storage, point_deleted, is_stopped)
+        }
+        Distance::Jaccard => {
+            new_multi_with_metric::<JaccardMetric>(query, storage, point_deleted, is_stopped)
+        }
+    }
+}
+
+fn new_multi_with_metric<'a, TMetric: Metric<VectorElementType> + 'a>(
+    query: QueryVector,
+    storage: &'a MemmapMultiDenseVectorStorage<VectorElementType>,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    let points_count = storage.total_vector_count() as _;
+    let vec_deleted = storage.deleted_vector_bitslice();
+    let mmap_vectors = storage.get_mmap_vectors();
+    match query {
+        QueryVector::Nearest(vector) => {
+            let query_scorer = MultiMetricQueryScorer::<VectorElementType, TMetric, _>::new(
+                vector.try_into()?,
+                storage,
+            );
+            Ok(Box::new(AsyncRawScorerImpl::new(
+                points_count,
+                query_scorer,
+                mmap_vectors,
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )))
+        }
+        QueryVector::Recommend(reco_query) => {
+            let reco_query: RecoQuery<MultiDenseVectorInternal> = reco_query.transform_into()?;
+            let query_scorer = MultiCustomQueryScorer::<VectorElementType, TMetric, _, _, _>::new(
+                reco_query, storage,
+            );
+            Ok(Box::new(AsyncRawScorerImpl::new(
+                points_count,
+                query_scorer,
+                mmap_vectors,
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )))
+        }
+        QueryVector::Discovery(discovery_query) => {
+            let discovery_query: DiscoveryQuery<MultiDenseVectorInternal> =
+                discovery_query.transform_into()?;
+            let query_scorer = MultiCustomQueryScorer::<VectorElementType, TMetric, _, _, _>::new(
+                discovery_query,
+                storage,
+            );
+            Ok(Box::new(AsyncRawScorerImpl::new(
+                points_count,
+                query_scorer,
+                mmap_vectors,
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )))
+        }
+        QueryVector::Context(context_query) => {
+            let context_query: ContextQuery<MultiDenseVectorInternal> =
+                context_query.transform_into()?;
+            let query_scorer = MultiCustomQueryScorer::<VectorElementType, TMetric, _, _, _>::new(
+                context_query,
+                storage,
+            );
+            Ok(Box::new(AsyncRawScorerImpl::new(
+                points_count,
+                query_scorer,
+                mmap_vectors,
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )))
+        }
+    }
+}
+
+pub struct AsyncRawScorerImpl<'a, TVector, TStorage, TQueryScorer>
+where
+    TVector: ?Sized,
+    TStorage: AsyncVectorsReader<TVector>,
+    TQueryScorer: QueryScorer<TVector>,
+{
     points_count: PointOffsetType,
     query_scorer: TQueryScorer,
-    storage: &'a MmapDenseVectors<VectorElementType>,
+    storage: &'a TStorage,
     point_deleted: &'a BitSlice,
     vec_deleted: &'a BitSlice,
     /// This flag indicates that the search process is stopped externally,
     /// the search result is no longer needed and the search process should be stopped as soon as possible.
     pub is_stopped: &'a AtomicBool,
+    vector: PhantomData<*const TVector>,
 }
 
-impl<'a, TQueryScorer> AsyncRawScorerImpl<'a, TQueryScorer>
+impl<'a, TVector, TStorage, TQueryScorer> AsyncRawScorerImpl<'a, TVector, TStorage, TQueryScorer>
 where
-    TQueryScorer: QueryScorer<[VectorElementType]>,
+    TVector: ?Sized,
+    TStorage: AsyncVectorsReader<TVector>,
+    TQueryScorer: QueryScorer<TVector>,
 {
     fn new(
         points_count: PointOffsetType,
         query_scorer: TQueryScorer,
-        storage: &'a MmapDenseVectors<VectorElementType>,
+        storage: &'a TStorage,
         point_deleted: &'a BitSlice,
         vec_deleted: &'a BitSlice,
         is_stopped: &'a AtomicBool,
@@ -58,13 +212,17 @@ where
             point_deleted,
             vec_deleted,
             is_stopped,
+            vector: PhantomData,
         }
     }
 }
 
-impl<'a, TQueryScorer> RawScorer for AsyncRawScorerImpl<'a, TQueryScorer>
+impl<'a, TVector, TStorage, TQueryScorer> RawScorer
+    for AsyncRawScorerImpl<'a, TVector, TStorage, TQueryScorer>
 where
-    TQueryScorer: QueryScorer<[VectorElementType]>,
+    TVector: ?Sized,
+    TStorage: AsyncVectorsReader<TVector>,
+    TQueryScorer: QueryScorer<TVector>,
 {
     fn score_points(&self, points: &[PointOffsetType], scores: &mut [ScoredPointOffset]) -> usize {
         if self.is_stopped.load(Ordering::Relaxed) {
@@ -243,6 +401,8 @@ impl<'a> AsyncRawScorerBuilder<'a> {
             Distance::Euclid => self._build_with_metric::<EuclidMetric>(),
             Distance::Dot => self._build_with_metric::<DotProductMetric>(),
             Distance::Manhattan => self._build_with_metric::<ManhattanMetric>(),
+            Distance::Hamming => self._build_with_metric::<HammingMetric>(),
+            Distance::Jaccard => self._build_with_metric::<JaccardMetric>(),
         }
     }
 
@@ -69,6 +69,26 @@ pub fn open_memmap_vector_storage_half(
     Ok(VectorStorageEnum::DenseMemmapHalf(storage))
 }
 
+pub fn open_memmap_vector_storage_bf16(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+) -> OperationResult<VectorStorageEnum> {
+    let storage =
+        open_memmap_vector_storage_with_async_io_impl(path, dim, distance, get_async_scorer())?;
+    Ok(VectorStorageEnum::DenseMemmapBFloat16(storage))
+}
+
+pub fn open_memmap_vector_storage_int8(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+) -> OperationResult<VectorStorageEnum> {
+    let storage =
+        open_memmap_vector_storage_with_async_io_impl(path, dim, distance, get_async_scorer())?;
+    Ok(VectorStorageEnum::DenseMemmapInt8(storage))
+}
+
 pub fn open_memmap_vector_storage_with_async_io(
     path: &Path,
     dim: usize,
@@ -257,7 +277,7 @@ impl<T: PrimitiveVectorElement> VectorStorage for MemmapDenseVectorStorage<T> {
 }
 
 /// Open a file shortly for appending
-fn open_append<P: AsRef<Path>>(path: P) -> io::Result<File> {
+pub(crate) fn open_append<P: AsRef<Path>>(path: P) -> io::Result<File> {
     OpenOptions::new()
         .read(false)
         .write(false)
@@ -20,9 +20,9 @@ use crate::vector_storage::async_io::UringReader;
 #[cfg(not(target_os = "linux"))]
 use crate::vector_storage::async_io_mock::UringReader;
 
-const HEADER_SIZE: usize = 4;
-const VECTORS_HEADER: &[u8; HEADER_SIZE] = b"data";
-const DELETED_HEADER: &[u8; HEADER_SIZE] = b"drop";
+pub(crate) const HEADER_SIZE: usize = 4;
+pub(crate) const VECTORS_HEADER: &[u8; HEADER_SIZE] = b"data";
+pub(crate) const DELETED_HEADER: &[u8; HEADER_SIZE] = b"drop";
 
 /// Mem-mapped file for dense vectors
 pub struct MmapDenseVectors<T: PrimitiveVectorElement> {
@@ -208,7 +208,11 @@ impl<T: PrimitiveVectorElement> MmapDenseVectors<T> {
 /// * `path`: path of the file.
 /// * `header`: header to set when the file is newly created.
 /// * `size`: set the file size in bytes, filled with zeroes.
-fn ensure_mmap_file_size(path: &Path, header: &[u8], size: Option<u64>) -> OperationResult<()> {
+pub(crate) fn ensure_mmap_file_size(
+    path: &Path,
+    header: &[u8],
+    size: Option<u64>,
+) -> OperationResult<()> {
     // If it exists, only set the length
     if path.exists() {
         if let Some(size) = size {
@@ -231,7 +235,7 @@ fn ensure_mmap_file_size(path: &Path, header: &[u8], size: Option<u64>) -> Opera
 
 /// Get start position of flags `BitSlice` in deleted mmap.
 #[inline]
-const fn deleted_mmap_data_start() -> usize {
+pub(crate) const fn deleted_mmap_data_start() -> usize {
     let align = mem::align_of::<usize>();
     HEADER_SIZE.div_ceil(align) * align
 }
@@ -239,7 +243,7 @@ const fn deleted_mmap_data_start() -> usize {
 /// Calculate size for deleted mmap to hold the given number of vectors.
 ///
 /// The mmap will hold a file header and an aligned `BitSlice`.
-fn deleted_mmap_size(num: usize) -> usize {
+pub(crate) fn deleted_mmap_size(num: usize) -> usize {
     let unit_size = mem::size_of::<usize>();
     let num_bytes = num.div_ceil(8);
     let num_usizes = num_bytes.div_ceil(unit_size);
@@ -139,6 +139,42 @@ pub fn open_simple_dense_half_vector_storage(
     Ok(VectorStorageEnum::DenseSimpleHalf(storage))
 }
 
+pub fn open_simple_dense_bf16_vector_storage(
+    database: Arc<RwLock<DB>>,
+    database_column_name: &str,
+    dim: usize,
+    distance: Distance,
+    stopped: &AtomicBool,
+) -> OperationResult<VectorStorageEnum> {
+    let storage = open_simple_dense_vector_storage_impl(
+        database,
+        database_column_name,
+        dim,
+        distance,
+        stopped,
+    )?;
+
+    Ok(VectorStorageEnum::DenseSimpleBFloat16(storage))
+}
+
+pub fn open_simple_dense_int8_vector_storage(
+    database: Arc<RwLock<DB>>,
+    database_column_name: &str,
+    dim: usize,
+    distance: Distance,
+    stopped: &AtomicBool,
+) -> OperationResult<VectorStorageEnum> {
+    let storage = open_simple_dense_vector_storage_impl(
+        database,
+        database_column_name,
+        dim,
+        distance,
+        stopped,
+    )?;
+
+    Ok(VectorStorageEnum::DenseSimpleInt8(storage))
+}
+
 impl<T: PrimitiveVectorElement> SimpleDenseVectorStorage<T> {
     /// Set deleted flag for given key. Returns previous deleted state.
     #[inline]
@@ -0,0 +1,272 @@
+use std::fs::create_dir_all;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+
+use bitvec::prelude::BitSlice;
+use common::types::PointOffsetType;
+use sparse::common::sparse_vector::SparseVector;
+use sparse::common::types::{DimId, DimWeight};
+
+use super::SparseVectorStorage;
+use crate::common::operation_error::{check_process_stopped, OperationError, OperationResult};
+use crate::common::Flusher;
+use crate::data_types::named_vectors::CowVector;
+use crate::data_types::vectors::VectorRef;
+use crate::types::{Distance, VectorStorageDatatype};
+use crate::vector_storage::chunked_mmap_vectors::ChunkedMmapVectors;
+use crate::vector_storage::dense::dynamic_mmap_flags::DynamicMmapFlags;
+use crate::vector_storage::simple_sparse_vector_storage::SPARSE_VECTOR_DISTANCE;
+use crate::vector_storage::{VectorStorage, VectorStorageEnum};
+
+const INDICES_DIR_PATH: &str = "indices";
+const VALUES_DIR_PATH: &str = "values";
+const OFFSETS_DIR_PATH: &str = "offsets";
+const DELETED_DIR_PATH: &str = "deleted";
+
+/// Location of a single sparse vector in the indices and values chunks
+///
+/// Elements of all sparse vectors together may outnumber [`PointOffsetType`], so offsets are 64-bit.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct SparseVectorMmapOffset {
+    offset: u64,
+    count: PointOffsetType,
+    capacity: PointOffsetType,
+}
+
+/// Appendable sparse vector storage, based on chunked mmap files
+///
+/// Indices and values of a sparse vector are stored at the same position in two separate chunked
+/// files. Elements of a single sparse vector never cross a chunk boundary.
+pub struct MmapSparseVectorStorage {
+    indices: ChunkedMmapVectors<DimId>,
+    values: ChunkedMmapVectors<DimWeight>,
+    offsets: ChunkedMmapVectors<SparseVectorMmapOffset>,
+    deleted: DynamicMmapFlags,
+    deleted_count: usize,
+}
+
+pub fn open_mmap_sparse_vector_storage(path: &Path) -> OperationResult<VectorStorageEnum> {
+    let storage = open_mmap_sparse_vector_storage_impl(path)?;
+    Ok(VectorStorageEnum::SparseMmap(storage))
+}
+
+fn open_mmap_sparse_vector_storage_impl(path: &Path) -> OperationResult<MmapSparseVectorStorage> {
+    create_dir_all(path)?;
+
+    let indices = ChunkedMmapVectors::open(&path.join(INDICES_DIR_PATH), 1)?;
+    let values = ChunkedMmapVectors::open(&path.join(VALUES_DIR_PATH), 1)?;
+    let offsets = ChunkedMmapVectors::open(&path.join(OFFSETS_DIR_PATH), 1)?;
+
+    let deleted = DynamicMmapFlags::open(&path.join(DELETED_DIR_PATH))?;
+    let deleted_count = deleted.count_flags();
+
+    Ok(MmapSparseVectorStorage {
+        indices,
+        values,
+        offsets,
+        deleted,
+        deleted_count,
+    })
+}
+
+impl MmapSparseVectorStorage {
+    /// Set deleted flag for given key. Returns previous deleted state.
+    #[inline]
+    fn set_deleted(&mut self, key: PointOffsetType, deleted: bool) -> OperationResult<bool> {
+        if self.offsets.len() <= key as usize {
+            return Ok(false);
+        }
+
+        if self.deleted.len() <= key as usize {
+            self.deleted.set_len(key as usize + 1)?;
+        }
+        let previous = self.deleted.set(key, deleted);
+        if !previous && deleted {
+            self.deleted_count += 1;
+        } else if previous && !deleted {
+            self.deleted_count -= 1;
+        }
+        Ok(previous)
+    }
+
+    fn get_offset(&self, key: PointOffsetType) -> Option<SparseVectorMmapOffset> {
+        if self.offsets.len() <= key as usize {
+            return None;
+        }
+        self.offsets
+            .get(key as usize)
+            .map(|offset| *offset.first().expect("mmap_offset must not be empty"))
+    }
+}
+
+impl SparseVectorStorage for MmapSparseVectorStorage {
+    fn get_sparse(&self, key: PointOffsetType) -> OperationResult<SparseVector> {
+        let offset = self.get_offset(key).ok_or_else(|| {
+            OperationError::service_error(format!("Sparse vector {key} not found"))
+        })?;
+
+        if offset.count == 0 {
+            return Ok(SparseVector::default());
+        }
+
+        let indices = self.indices.get_many(offset.offset, offset.count as usize);
+        let values = self.values.get_many(offset.offset, offset.count as usize);
+        match (indices, values) {
+            (Some(indices), Some(values)) => Ok(SparseVector {
+                indices: indices.to_vec(),
+                values: values.to_vec(),
+            }),
+            _ => Err(OperationError::service_error(format!(
+                "Sparse vector {key} is out of storage bounds"
+            ))),
+        }
+    }
+}
+
+impl VectorStorage for MmapSparseVectorStorage {
+    fn distance(&self) -> Distance {
+        SPARSE_VECTOR_DISTANCE
+    }
+
+    fn datatype(&self) -> VectorStorageDatatype {
+        VectorStorageDatatype::Float32
+    }
+
+    fn is_on_disk(&self) -> bool {
+        true
+    }
+
+    fn total_vector_count(&self) -> usize {
+        self.offsets.len()
+    }
+
+    fn available_size_in_bytes(&self) -> usize {
+        if self.total_vector_count() > 0 {
+            let total_size = self.indices.len()
+                * (std::mem::size_of::<DimId>() + std::mem::size_of::<DimWeight>());
+            (total_size as u128 * self.available_vector_count() as u128
+                / self.total_vector_count() as u128) as usize
+        } else {
+            0
+        }
+    }
+
+    fn get_vector(&self, key: PointOffsetType) -> CowVector {
+        let vector = self.get_vector_opt(key);
+        debug_assert!(vector.is_some());
+        vector.unwrap_or_else(CowVector::default_sparse)
+    }
+
+    /// Get vector by key, if it exists.
+    ///
+    /// ignore any error
+    fn get_vector_opt(&self, key: PointOffsetType) -> Option<CowVector> {
+        self.get_sparse(key).ok().map(CowVector::from)
+    }
+
+    fn insert_vector(&mut self, key: PointOffsetType, vector: VectorRef) -> OperationResult<()> {
+        let vector: &SparseVector = vector.try_into()?;
+        debug_assert!(vector.is_sorted());
+        let vector_size_in_bytes = std::mem::size_of_val(vector.indices.as_slice());
+        let chunk_size = self.indices.get_chunk_size_in_bytes();
+        if vector_size_in_bytes >= chunk_size {
+            return Err(OperationError::service_error(format!("Cannot insert sparse vector of size {vector_size_in_bytes} to the mmap vector storage. It's too large, maximum size is {chunk_size}.")));
+        }
+
+        let count = vector.indices.len();
+        let mut offset = self.get_offset(key).unwrap_or_default();
+
+        if count > offset.capacity as usize {
+            // append vector to the end
+            let mut new_key = self.indices.len();
+            let chunk_left_keys = self.indices.get_remaining_chunk_keys(new_key);
+            if count > chunk_left_keys {
+                new_key += chunk_left_keys;
+            }
+
+            offset = SparseVectorMmapOffset {
+                offset: new_key as u64,
+                count: count as PointOffsetType,
+                capacity: count as PointOffsetType,
+            };
+        } else {
+            // use existing place to insert vector
+            offset.count = count as PointOffsetType;
+        }
+
+        if count > 0 {
+            self.indices
+                .insert_many(offset.offset, &vector.indices, count)?;
+            self.values
+                .insert_many(offset.offset, &vector.values, count)?;
+        }
+        self.offsets.insert(key as usize, &[offset])?;
+        self.set_deleted(key, false)?;
+
+        Ok(())
+    }
+
+    fn update_from(
+        &mut self,
+        other: &VectorStorageEnum,
+        other_ids: &mut impl Iterator<Item = PointOffsetType>,
+        stopped: &AtomicBool,
+    ) -> OperationResult<Range<PointOffsetType>> {
+        let start_index = self.offsets.len() as PointOffsetType;
+        for point_id in other_ids {
+            check_process_stopped(stopped)?;
+            // Do not perform preprocessing - vectors should be already processed
+            let other_deleted = other.is_deleted_vector(point_id);
+            let other_vector = other.get_vector(point_id);
+            let other_vector: VectorRef = other_vector.as_vec_ref();
+            let new_id = self.offsets.len() as PointOffsetType;
+            self.insert_vector(new_id, other_vector)?;
+            self.set_deleted(new_id, other_deleted)?;
+        }
+        let end_index = self.offsets.len() as PointOffsetType;
+        Ok(start_index..end_index)
+    }
+
+    fn flusher(&self) -> Flusher {
+        Box::new({
+            let indices_flusher = self.indices.flusher();
+            let values_flusher = self.values.flusher();
+            let offsets_flusher = self.offsets.flusher();
+            let deleted_flusher = self.deleted.flusher();
+            move || {
+                indices_flusher()?;
+                values_flusher()?;
+                offsets_flusher()?;
+                deleted_flusher()?;
+                Ok(())
+            }
+        })
+    }
+
+    fn files(&self) -> Vec<PathBuf> {
+        let mut files = self.indices.files();
+        files.extend(self.values.files());
+        files.extend(self.offsets.files());
+        files.extend(self.deleted.files());
+        files
+    }
+
+    fn delete_vector(&mut self, key: PointOffsetType) -> OperationResult<bool> {
+        let is_deleted = !self.set_deleted(key, true)?;
+        Ok(is_deleted)
+    }
+
+    fn is_deleted_vector(&self, key: PointOffsetType) -> bool {
+        self.deleted.get(key)
+    }
+
+    fn deleted_vector_count(&self) -> usize {
+        self.deleted_count
+    }
+
+    fn deleted_vector_bitslice(&self) -> &BitSlice {
+        self.deleted.get_bitslice()
+    }
+}
@@ -16,10 +16,12 @@ mod async_io_mock;
 mod bitvec;
 pub mod common;
 pub mod dense;
+pub mod mmap_sparse_vector_storage;
 pub mod multi_dense;
 pub mod query;
 mod query_scorer;
 pub mod simple_sparse_vector_storage;
+pub mod token_raw_scorer;
 
 pub use raw_scorer::*;
 pub use vector_storage_base::*;
@@ -38,6 +38,11 @@ pub struct AppendableMmapMultiDenseVectorStorage<T: PrimitiveVectorElement> {
     deleted_count: usize,
 }
 
+/// Check whether the given directory holds an appendable multivector storage
+pub fn appendable_memmap_multi_vector_storage_exists(path: &Path) -> bool {
+    path.join(VECTORS_DIR_PATH).is_dir()
+}
+
 pub fn open_appendable_memmap_multi_vector_storage(
     path: &Path,
     dim: usize,
@@ -84,6 +89,34 @@ pub fn open_appendable_memmap_multi_vector_storage_half(
     )))
 }
 
+pub fn open_appendable_memmap_multi_vector_storage_bf16(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+    multi_vector_config: MultiVectorConfig,
+) -> OperationResult<VectorStorageEnum> {
+    let storage =
+        open_appendable_memmap_multi_vector_storage_impl(path, dim, distance, multi_vector_config)?;
+
+    Ok(VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(
+        Box::new(storage),
+    ))
+}
+
+pub fn open_appendable_memmap_multi_vector_storage_int8(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+    multi_vector_config: MultiVectorConfig,
+) -> OperationResult<VectorStorageEnum> {
+    let storage =
+        open_appendable_memmap_multi_vector_storage_impl(path, dim, distance, multi_vector_config)?;
+
+    Ok(VectorStorageEnum::MultiDenseAppendableMemmapInt8(Box::new(
+        storage,
+    )))
+}
+
 pub fn open_appendable_memmap_multi_vector_storage_impl<T: PrimitiveVectorElement>(
     path: &Path,
     dim: usize,
@@ -0,0 +1,378 @@
+use std::fs::create_dir_all;
+use std::io::Write;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+
+use bitvec::prelude::BitSlice;
+use common::types::PointOffsetType;
+use memory::mmap_ops;
+
+use crate::common::operation_error::{check_process_stopped, OperationError, OperationResult};
+use crate::common::Flusher;
+use crate::data_types::named_vectors::{CowMultiVector, CowVector};
+use crate::data_types::primitive::PrimitiveVectorElement;
+use crate::data_types::vectors::{
+    TypedMultiDenseVector, TypedMultiDenseVectorRef, VectorElementType, VectorRef,
+};
+use crate::types::{Distance, MultiVectorConfig, VectorStorageDatatype};
+use crate::vector_storage::common::get_async_scorer;
+use crate::vector_storage::dense::memmap_dense_vector_storage::open_append;
+use crate::vector_storage::multi_dense::mmap_multi_dense_vectors::{
+    MmapMultiDenseVectors, MultiVectorMmapOffset,
+};
+use crate::vector_storage::{MultiVectorStorage, VectorStorage, VectorStorageEnum};
+
+const VECTORS_PATH: &str = "matrix.dat";
+const OFFSETS_PATH: &str = "offsets.dat";
+const DELETED_PATH: &str = "deleted.dat";
+
+/// Stores all multi-dense vectors in mem-mapped files
+///
+/// It is not possible to insert new vectors into mem-mapped storage,
+/// but possible to mark some vectors as removed
+///
+/// Mem-mapped storage can only be constructed from another storage
+pub struct MemmapMultiDenseVectorStorage<T: PrimitiveVectorElement> {
+    vectors_path: PathBuf,
+    offsets_path: PathBuf,
+    deleted_path: PathBuf,
+    mmap_store: Option<MmapMultiDenseVectors<T>>,
+    distance: Distance,
+    multi_vector_config: MultiVectorConfig,
+}
+
+pub fn open_memmap_multi_vector_storage(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+    multi_vector_config: MultiVectorConfig,
+) -> OperationResult<VectorStorageEnum> {
+    let storage = open_memmap_multi_vector_storage_with_async_io_impl::<VectorElementType>(
+        path,
+        dim,
+        distance,
+        multi_vector_config,
+        get_async_scorer(),
+    )?;
+    Ok(VectorStorageEnum::MultiDenseMemmap(storage))
+}
+
+pub fn open_memmap_multi_vector_storage_byte(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+    multi_vector_config: MultiVectorConfig,
+) -> OperationResult<VectorStorageEnum> {
+    let storage = open_memmap_multi_vector_storage_with_async_io_impl(
+        path,
+        dim,
+        distance,
+        multi_vector_config,
+        get_async_scorer(),
+    )?;
+    Ok(VectorStorageEnum::MultiDenseMemmapByte(storage))
+}
+
+pub fn open_memmap_multi_vector_storage_half(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+    multi_vector_config: MultiVectorConfig,
+) -> OperationResult<VectorStorageEnum> {
+    let storage = open_memmap_multi_vector_storage_with_async_io_impl(
+        path,
+        dim,
+        distance,
+        multi_vector_config,
+        get_async_scorer(),
+    )?;
+    Ok(VectorStorageEnum::MultiDenseMemmapHalf(storage))
+}
+
+pub fn open_memmap_multi_vector_storage_bf16(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+    multi_vector_config: MultiVectorConfig,
+) -> OperationResult<VectorStorageEnum> {
+    let storage = open_memmap_multi_vector_storage_with_async_io_impl(
+        path,
+        dim,
+        distance,
+        multi_vector_config,
+        get_async_scorer(),
+    )?;
+    Ok(VectorStorageEnum::MultiDenseMemmapBFloat16(storage))
+}
+
+pub fn open_memmap_multi_vector_storage_int8(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+    multi_vector_config: MultiVectorConfig,
+) -> OperationResult<VectorStorageEnum> {
+    let storage = open_memmap_multi_vector_storage_with_async_io_impl(
+        path,
+        dim,
+        distance,
+        multi_vector_config,
+        get_async_scorer(),
+    )?;
+    Ok(VectorStorageEnum::MultiDenseMemmapInt8(storage))
+}
+
+pub fn open_memmap_multi_vector_storage_with_async_io(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+    multi_vector_config: MultiVectorConfig,
+    with_async_io: bool,
+) -> OperationResult<VectorStorageEnum> {
+    let storage = open_memmap_multi_vector_storage_with_async_io_impl::<VectorElementType>(
+        path,
+        dim,
+        distance,
+        multi_vector_config,
+        with_async_io,
+    )?;
+    Ok(VectorStorageEnum::MultiDenseMemmap(storage))
+}
+
+fn open_memmap_multi_vector_storage_with_async_io_impl<T: PrimitiveVectorElement>(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+    multi_vector_config: MultiVectorConfig,
+    with_async_io: bool,
+) -> OperationResult<Box<MemmapMultiDenseVectorStorage<T>>> {
+    create_dir_all(path)?;
+
+    let vectors_path = path.join(VECTORS_PATH);
+    let offsets_path = path.join(OFFSETS_PATH);
+    let deleted_path = path.join(DELETED_PATH);
+    let mmap_store = MmapMultiDenseVectors::open(
+        &vectors_path,
+        &offsets_path,
+        &deleted_path,
+        dim,
+        with_async_io,
+    )?;
+
+    Ok(Box::new(MemmapMultiDenseVectorStorage {
+        vectors_path,
+        offsets_path,
+        deleted_path,
+        mmap_store: Some(mmap_store),
+        distance,
+        multi_vector_config,
+    }))
+}
+
+impl<T: PrimitiveVectorElement> MemmapMultiDenseVectorStorage<T> {
+    pub fn prefault_mmap_pages(&self) -> Option<mmap_ops::PrefaultMmapPages> {
+        Some(
+            self.mmap_store
+                .as_ref()?
+                .prefault_mmap_pages(&self.vectors_path),
+        )
+    }
+
+    pub fn get_mmap_vectors(&self) -> &MmapMultiDenseVectors<T> {
+        self.mmap_store.as_ref().unwrap()
+    }
+
+    pub fn has_async_reader(&self) -> bool {
+        self.mmap_store
+            .as_ref()
+            .map(|x| x.has_async_reader())
+            .unwrap_or(false)
+    }
+}
+
+impl<T: PrimitiveVectorElement> MultiVectorStorage<T> for MemmapMultiDenseVectorStorage<T> {
+    fn vector_dim(&self) -> usize {
+        self.mmap_store.as_ref().unwrap().dim
+    }
+
+    /// Panics if key is not found
+    fn get_multi(&self, key: PointOffsetType) -> TypedMultiDenseVectorRef<T> {
+        self.mmap_store.as_ref().unwrap().get_multi(key)
+    }
+
+    /// Returns None if key is not found
+    fn get_multi_opt(&self, key: PointOffsetType) -> Option<TypedMultiDenseVectorRef<T>> {
+        self.mmap_store.as_ref().unwrap().get_multi_opt(key)
+    }
+
+    fn iterate_inner_vectors(&self) -> impl Iterator<Item = &[T]> + Clone + Send {
+        self.mmap_store.as_ref().unwrap().iterate_inner_vectors()
+    }
+
+    fn multi_vector_config(&self) -> &MultiVectorConfig {
+        &self.multi_vector_config
+    }
+}
+
+impl<T: PrimitiveVectorElement> VectorStorage for MemmapMultiDenseVectorStorage<T> {
+    fn distance(&self) -> Distance {
+        self.distance
+    }
+
+    fn datatype(&self) -> VectorStorageDatatype {
+        T::datatype()
+    }
+
+    fn is_on_disk(&self) -> bool {
+        true
+    }
+
+    fn total_vector_count(&self) -> usize {
+        self.mmap_store.as_ref().unwrap().num_vectors
+    }
+
+    fn available_size_in_bytes(&self) -> usize {
+        if self.total_vector_count() > 0 {
+            let mmap_store = self.mmap_store.as_ref().unwrap();
+            let total_size = mmap_store.inner_vectors_count() * mmap_store.raw_size();
+            (total_size as u128 * self.available_vector_count() as u128
+                / self.total_vector_count() as u128) as usize
+        } else {
+            0
+        }
+    }
+
+    fn get_vector(&self, key: PointOffsetType) -> CowVector {
+        self.get_vector_opt(key).expect("vector not found")
+    }
+
+    fn get_vector_opt(&self, key: PointOffsetType) -> Option<CowVector> {
+        // TODO(colbert) borrow instead of clone
+        self.get_multi_opt(key).map(|multivector| {
+            let multivector = TypedMultiDenseVector {
+                flattened_vectors: multivector.flattened_vectors.to_vec(),
+                dim: multivector.dim,
+            };
+            CowVector::MultiDense(T::into_float_multivector(CowMultiVector::Owned(
+                multivector,
+            )))
+        })
+    }
+
+    fn insert_vector(&mut self, _key: PointOffsetType, _vector: VectorRef) -> OperationResult<()> {
+        panic!("Can't directly update vector in mmap storage")
+    }
+
+    fn update_from(
+        &mut self,
+        other: &VectorStorageEnum,
+        other_ids: &mut impl Iterator<Item = PointOffsetType>,
+        stopped: &AtomicBool,
+    ) -> OperationResult<Range<PointOffsetType>> {
+        let dim = self.vector_dim();
+        let (start_index, mut inner_vectors_count) = {
+            let mmap_store = self.mmap_store.as_ref().unwrap();
+            (
+                mmap_store.num_vectors as PointOffsetType,
+                mmap_store.inner_vectors_count(),
+            )
+        };
+        let mut end_index = start_index;
+
+        let with_async_io = self
+            .mmap_store
+            .take()
+            .map(|x| x.has_async_reader())
+            .unwrap_or(get_async_scorer());
+
+        // Extend vectors and offsets files, write other vectors into them
+        let mut vectors_file = open_append(&self.vectors_path)?;
+        let mut offsets_file = open_append(&self.offsets_path)?;
+        let mut deleted_ids = vec![];
+        for id in other_ids {
+            check_process_stopped(stopped)?;
+            let other_vector = other.get_vector(id);
+            let other_vector: TypedMultiDenseVectorRef<VectorElementType> =
+                other_vector.as_vec_ref().try_into()?;
+            let multi_vector = T::from_float_multivector(CowMultiVector::Borrowed(other_vector));
+            let multi_vector = multi_vector.as_vec_ref();
+            if multi_vector.dim != dim {
+                return Err(OperationError::WrongVectorDimension {
+                    expected_dim: dim,
+                    received_dim: multi_vector.dim,
+                });
+            }
+
+            vectors_file.write_all(mmap_ops::transmute_to_u8_slice(
+                multi_vector.flattened_vectors,
+            ))?;
+            let offset = MultiVectorMmapOffset {
+                offset: inner_vectors_count as u64,
+                count: multi_vector.vectors_count() as u64,
+            };
+            offsets_file.write_all(mmap_ops::transmute_to_u8(&offset))?;
+            inner_vectors_count += multi_vector.vectors_count();
+
+            // Remember deleted IDs so we can propagate deletions later
+            if other.is_deleted_vector(id) {
+                deleted_ids.push(end_index);
+            }
+            end_index += 1;
+        }
+        vectors_file.flush()?;
+        offsets_file.flush()?;
+        drop(vectors_file);
+        drop(offsets_file);
+
+        // Load store with updated files
+        self.mmap_store.replace(MmapMultiDenseVectors::open(
+            &self.vectors_path,
+            &self.offsets_path,
+            &self.deleted_path,
+            dim,
+            with_async_io,
+        )?);
+
+        // Flush deleted flags into store
+        // We must do that in the updated store, because the file backing delete storage must be
+        // resized to the new number of vectors, which is done automatically when opening the store.
+        let store = self.mmap_store.as_mut().unwrap();
+        for id in deleted_ids {
+            check_process_stopped(stopped)?;
+            store.delete(id);
+        }
+
+        Ok(start_index..end_index)
+    }
+
+    fn flusher(&self) -> Flusher {
+        match &self.mmap_store {
+            Some(mmap_store) => mmap_store.flusher(),
+            None => Box::new(|| Ok(())),
+        }
+    }
+
+    fn files(&self) -> Vec<PathBuf> {
+        vec![
+            self.vectors_path.clone(),
+            self.offsets_path.clone(),
+            self.deleted_path.clone(),
+        ]
+    }
+
+    fn delete_vector(&mut self, key: PointOffsetType) -> OperationResult<bool> {
+        Ok(self.mmap_store.as_mut().unwrap().delete(key))
+    }
+
+    fn is_deleted_vector(&self, key: PointOffsetType) -> bool {
+        self.mmap_store.as_ref().unwrap().is_deleted_vector(key)
+    }
+
+    fn deleted_vector_count(&self) -> usize {
+        self.mmap_store.as_ref().unwrap().deleted_count
+    }
+
+    fn deleted_vector_bitslice(&self) -> &BitSlice {
+        self.mmap_store.as_ref().unwrap().deleted_vector_bitslice()
+    }
+}
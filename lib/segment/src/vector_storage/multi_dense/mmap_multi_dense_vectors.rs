@@ -0,0 +1,271 @@
+use std::fs::File;
+use std::mem::size_of;
+use std::path::Path;
+use std::sync::Arc;
+
+use bitvec::prelude::BitSlice;
+use common::types::PointOffsetType;
+use memmap2::Mmap;
+use memory::mmap_ops;
+use parking_lot::Mutex;
+
+use crate::common::error_logging::LogError;
+use crate::common::mmap_type::MmapBitSlice;
+use crate::common::operation_error::OperationResult;
+use crate::common::Flusher;
+use crate::data_types::primitive::PrimitiveVectorElement;
+use crate::data_types::vectors::TypedMultiDenseVectorRef;
+#[cfg(target_os = "linux")]
+use crate::vector_storage::async_io::UringReader;
+#[cfg(not(target_os = "linux"))]
+use crate::vector_storage::async_io_mock::UringReader;
+use crate::vector_storage::dense::mmap_dense_vectors::{
+    deleted_mmap_data_start, deleted_mmap_size, ensure_mmap_file_size, DELETED_HEADER, HEADER_SIZE,
+    VECTORS_HEADER,
+};
+
+const OFFSETS_HEADER: &[u8; HEADER_SIZE] = b"offs";
+
+/// Location of a single multivector in the flattened vectors file
+///
+/// Inner vectors of all points together may outnumber [`PointOffsetType`], so offsets are 64-bit.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MultiVectorMmapOffset {
+    /// Index of the first inner vector
+    pub offset: u64,
+    /// Number of inner vectors
+    pub count: u64,
+}
+
+/// Mem-mapped files for multi-dense vectors
+///
+/// Inner vectors of all points are stored back to back in a single file. A separate offsets file
+/// tells where the inner vectors of each point start, and how many of them there are.
+pub struct MmapMultiDenseVectors<T: PrimitiveVectorElement> {
+    pub dim: usize,
+    pub num_vectors: usize,
+    /// Memory mapped file for flattened inner vectors
+    vectors_mmap: Arc<Mmap>,
+    /// Memory mapped file for the offset of each multivector
+    ///
+    /// Has an exact size to fit a header and `num_vectors` of offsets.
+    offsets_mmap: Mmap,
+    /// Context for io_uring-base async IO
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    uring_reader: Mutex<Option<UringReader<T>>>,
+    /// Memory mapped deletion flags
+    deleted: MmapBitSlice,
+    /// Current number of deleted vectors.
+    pub deleted_count: usize,
+}
+
+impl<T: PrimitiveVectorElement> MmapMultiDenseVectors<T> {
+    pub fn open(
+        vectors_path: &Path,
+        offsets_path: &Path,
+        deleted_path: &Path,
+        dim: usize,
+        with_async_io: bool,
+    ) -> OperationResult<Self> {
+        // Allocate/open vectors mmap
+        ensure_mmap_file_size(vectors_path, VECTORS_HEADER, None)
+            .describe("Create mmap data file")?;
+        let vectors_mmap =
+            mmap_ops::open_read_mmap(vectors_path).describe("Open mmap for reading")?;
+
+        // Allocate/open offsets mmap
+        ensure_mmap_file_size(offsets_path, OFFSETS_HEADER, None)
+            .describe("Create mmap offsets file")?;
+        let offsets_mmap =
+            mmap_ops::open_read_mmap(offsets_path).describe("Open mmap offsets for reading")?;
+        let num_vectors = (offsets_mmap.len() - HEADER_SIZE) / size_of::<MultiVectorMmapOffset>();
+
+        // Allocate/open deleted mmap
+        let deleted_mmap_size = deleted_mmap_size(num_vectors);
+        ensure_mmap_file_size(deleted_path, DELETED_HEADER, Some(deleted_mmap_size as u64))
+            .describe("Create mmap deleted file")?;
+        let deleted_mmap =
+            mmap_ops::open_write_mmap(deleted_path).describe("Open mmap deleted for writing")?;
+
+        // Advise kernel that we'll need this page soon so the kernel can prepare
+        #[cfg(unix)]
+        if let Err(err) = deleted_mmap.advise(memmap2::Advice::WillNeed) {
+            log::error!("Failed to advise MADV_WILLNEED for deleted flags: {}", err,);
+        }
+
+        // Transform into mmap BitSlice
+        let deleted = MmapBitSlice::try_from(deleted_mmap, deleted_mmap_data_start())?;
+        let deleted_count = deleted.count_ones();
+
+        let uring_reader = if with_async_io {
+            // Keep file handle open for async IO
+            let vectors_file = File::open(vectors_path)?;
+            let raw_size = dim * size_of::<T>();
+            Some(UringReader::new(vectors_file, raw_size, HEADER_SIZE)?)
+        } else {
+            None
+        };
+
+        Ok(MmapMultiDenseVectors {
+            dim,
+            num_vectors,
+            vectors_mmap: vectors_mmap.into(),
+            offsets_mmap,
+            uring_reader: Mutex::new(uring_reader),
+            deleted,
+            deleted_count,
+        })
+    }
+
+    pub fn has_async_reader(&self) -> bool {
+        self.uring_reader.lock().is_some()
+    }
+
+    pub fn flusher(&self) -> Flusher {
+        self.deleted.flusher()
+    }
+
+    /// Size of a single inner vector in bytes
+    pub fn raw_size(&self) -> usize {
+        self.dim * size_of::<T>()
+    }
+
+    /// Total number of inner vectors of all multivectors
+    pub fn inner_vectors_count(&self) -> usize {
+        (self.vectors_mmap.len() - HEADER_SIZE) / self.raw_size()
+    }
+
+    pub fn get_offset(&self, key: PointOffsetType) -> Option<MultiVectorMmapOffset> {
+        if key >= (self.num_vectors as PointOffsetType) {
+            return None;
+        }
+        let offset_size = size_of::<MultiVectorMmapOffset>();
+        let start = HEADER_SIZE + (key as usize) * offset_size;
+        let bytes = &self.offsets_mmap[start..start + offset_size];
+        // Offsets follow the 4 byte header, so they are not aligned to their 8 byte fields.
+        // SAFETY: `bytes` has the size of the offset, which is plain data valid for any bytes
+        let offset =
+            unsafe { std::ptr::read_unaligned(bytes.as_ptr().cast::<MultiVectorMmapOffset>()) };
+        Some(offset)
+    }
+
+    /// Byte range of the inner vectors of the given multivector in the vectors file
+    fn data_range(&self, offset: MultiVectorMmapOffset) -> (usize, usize) {
+        let start = HEADER_SIZE + offset.offset as usize * self.raw_size();
+        let length = offset.count as usize * self.raw_size();
+        (start, length)
+    }
+
+    /// Returns reference to multivector data by key
+    pub fn get_multi(&self, key: PointOffsetType) -> TypedMultiDenseVectorRef<T> {
+        self.get_multi_opt(key).expect("vector not found")
+    }
+
+    /// Returns an optional reference to multivector data by key
+    pub fn get_multi_opt(&self, key: PointOffsetType) -> Option<TypedMultiDenseVectorRef<T>> {
+        self.get_offset(key).map(|offset| {
+            let (start, length) = self.data_range(offset);
+            TypedMultiDenseVectorRef {
+                flattened_vectors: mmap_ops::transmute_from_u8_to_slice(
+                    &self.vectors_mmap[start..start + length],
+                ),
+                dim: self.dim,
+            }
+        })
+    }
+
+    /// Iterate over all inner vectors, including the ones of deleted multivectors
+    pub fn iterate_inner_vectors(&self) -> impl Iterator<Item = &[T]> + Clone + Send {
+        let flattened_vectors: &[T] =
+            mmap_ops::transmute_from_u8_to_slice(&self.vectors_mmap[HEADER_SIZE..]);
+        flattened_vectors.chunks_exact(self.dim)
+    }
+
+    pub fn delete(&mut self, key: PointOffsetType) -> bool {
+        if self.num_vectors <= key as usize {
+            return false;
+        }
+
+        let is_deleted = !self.deleted.replace(key as usize, true);
+        if is_deleted {
+            self.deleted_count += 1;
+        }
+        is_deleted
+    }
+
+    pub fn is_deleted_vector(&self, key: PointOffsetType) -> bool {
+        self.deleted.get(key as usize).map(|b| *b).unwrap_or(false)
+    }
+
+    /// Get [`BitSlice`] representation for deleted vectors with deletion flags
+    ///
+    /// The size of this slice is not guaranteed. It may be smaller/larger than the number of
+    /// vectors in this segment.
+    pub fn deleted_vector_bitslice(&self) -> &BitSlice {
+        &self.deleted
+    }
+
+    pub fn prefault_mmap_pages(&self, path: &Path) -> mmap_ops::PrefaultMmapPages {
+        mmap_ops::PrefaultMmapPages::new(self.vectors_mmap.clone(), Some(path))
+    }
+
+    #[cfg(target_os = "linux")]
+    fn process_points_uring(
+        &self,
+        points: impl Iterator<Item = PointOffsetType>,
+        mut callback: impl FnMut(usize, PointOffsetType, TypedMultiDenseVectorRef<T>),
+    ) -> OperationResult<()> {
+        let dim = self.dim;
+        let ranges = points.filter_map(|point| {
+            let (start, length) = self.data_range(self.get_offset(point)?);
+            Some((point, start, length))
+        });
+        self.uring_reader
+            .lock()
+            .as_mut()
+            .expect("io_uring reader should be initialized")
+            .read_ranges(ranges, |idx, point, flattened_vectors| {
+                callback(
+                    idx,
+                    point,
+                    TypedMultiDenseVectorRef {
+                        flattened_vectors,
+                        dim,
+                    },
+                )
+            })
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn process_points_simple(
+        &self,
+        points: impl Iterator<Item = PointOffsetType>,
+        mut callback: impl FnMut(usize, PointOffsetType, TypedMultiDenseVectorRef<T>),
+    ) -> OperationResult<()> {
+        for (idx, point) in points.enumerate() {
+            let multi_vector = self.get_multi(point);
+            callback(idx, point, multi_vector);
+        }
+        Ok(())
+    }
+
+    /// Reads multivectors for the given ids and calls the callback for each multivector.
+    /// Tries to utilize asynchronous IO if possible.
+    /// In particular, uses io_uring on Linux and simple synchronous IO otherwise.
+    pub fn read_multi_vectors_async(
+        &self,
+        points: impl Iterator<Item = PointOffsetType>,
+        callback: impl FnMut(usize, PointOffsetType, TypedMultiDenseVectorRef<T>),
+    ) -> OperationResult<()> {
+        #[cfg(target_os = "linux")]
+        {
+            self.process_points_uring(points, callback)
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            self.process_points_simple(points, callback)
+        }
+    }
+}
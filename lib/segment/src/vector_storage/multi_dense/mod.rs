@@ -1,2 +1,5 @@
 pub mod appendable_mmap_multi_dense_vector_storage;
+pub mod memmap_multi_dense_vector_storage;
+pub mod mmap_multi_dense_vectors;
 pub mod simple_multi_dense_vector_storage;
+pub mod token_mapping;
@@ -0,0 +1,105 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+
+use common::types::PointOffsetType;
+use io::file_operations::{atomic_save_bin, read_bin};
+use serde::{Deserialize, Serialize};
+
+use crate::common::operation_error::{check_process_stopped, OperationResult};
+use crate::data_types::vectors::{DenseVector, TypedMultiDenseVectorRef, VectorElementType};
+use crate::vector_storage::{VectorStorage, VectorStorageEnum};
+
+const TOKEN_MAPPING_FILE: &str = "tokens.bin";
+
+/// Position of a single sub-vector (token) in a multivector storage
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultiVectorToken {
+    /// Point, which the token belongs to
+    pub point_id: PointOffsetType,
+    /// Position of the token among the sub-vectors of the point
+    pub inner_id: u32,
+}
+
+/// Flat enumeration of the sub-vectors of a multivector storage
+///
+/// Allows to address each sub-vector with a single id, e.g. as a node of a token level HNSW graph.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct TokenMapping {
+    tokens: Vec<MultiVectorToken>,
+}
+
+impl TokenMapping {
+    /// Enumerate all sub-vectors of the given points
+    pub fn build(
+        point_ids: impl Iterator<Item = PointOffsetType>,
+        vector_storage: &VectorStorageEnum,
+        stopped: &AtomicBool,
+    ) -> OperationResult<Self> {
+        let mut tokens = Vec::new();
+        for point_id in point_ids {
+            check_process_stopped(stopped)?;
+            let vector = vector_storage.get_vector(point_id);
+            let multi_vector: TypedMultiDenseVectorRef<VectorElementType> =
+                vector.as_vec_ref().try_into()?;
+            tokens.extend(
+                (0..multi_vector.vectors_count() as u32)
+                    .map(|inner_id| MultiVectorToken { point_id, inner_id }),
+            );
+        }
+        Ok(Self { tokens })
+    }
+
+    pub fn get_path(path: &Path) -> PathBuf {
+        path.join(TOKEN_MAPPING_FILE)
+    }
+
+    pub fn load(path: &Path) -> OperationResult<Self> {
+        Ok(read_bin(path)?)
+    }
+
+    pub fn save(&self, path: &Path) -> OperationResult<()> {
+        Ok(atomic_save_bin(path, self)?)
+    }
+
+    pub fn tokens(&self) -> &[MultiVectorToken] {
+        &self.tokens
+    }
+
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    /// Number of distinct points, which have at least one token
+    pub fn point_count(&self) -> usize {
+        // Tokens of the same point are always adjacent
+        self.tokens
+            .iter()
+            .enumerate()
+            .filter(|(idx, token)| *idx == 0 || self.tokens[idx - 1].point_id != token.point_id)
+            .count()
+    }
+
+    /// Point, which the given token belongs to
+    #[inline]
+    pub fn point_id(&self, token_id: PointOffsetType) -> PointOffsetType {
+        self.tokens[token_id as usize].point_id
+    }
+
+    /// Read the sub-vector of the given token as a float vector
+    pub fn token_vector(
+        &self,
+        token_id: PointOffsetType,
+        vector_storage: &VectorStorageEnum,
+    ) -> OperationResult<DenseVector> {
+        let token = self.tokens[token_id as usize];
+        let vector = vector_storage.get_vector(token.point_id);
+        let multi_vector: TypedMultiDenseVectorRef<VectorElementType> =
+            vector.as_vec_ref().try_into()?;
+        let start = token.inner_id as usize * multi_vector.dim;
+        Ok(multi_vector.flattened_vectors[start..start + multi_vector.dim].to_vec())
+    }
+}
@@ -0,0 +1,217 @@
+//! Asymmetric scoring of binary quantized vectors.
+//!
+//! Instead of reducing the query to bits, the query is scalar quantized with several bits per value.
+//! Scalar codes are split into bit planes, each plane is encoded as a regular binary query.
+//! Binary score is linear in the dot product of `±1` vectors, so scores of the planes can be
+//! combined into a score of the scalar query against the signs of the stored vector.
+
+use std::marker::PhantomData;
+use std::path::Path;
+
+use common::types::{PointOffsetType, ScoreType};
+use quantization::EncodedVectors;
+
+use crate::data_types::vectors::VectorElementType;
+use crate::types::BinaryQuantizationQueryEncoding;
+
+pub enum EncodedBinaryQuery<TEncodedQuery> {
+    Binary(TEncodedQuery),
+    Scalar(EncodedScalarQuery<TEncodedQuery>),
+}
+
+pub struct EncodedScalarQuery<TEncodedQuery> {
+    /// Bit planes of the scalar codes, starting from the least significant bit
+    bit_planes: Vec<TEncodedQuery>,
+    /// Query of positive values only, which scores to the sum of signs of the stored vector
+    ones: TEncodedQuery,
+    offset: f32,
+    step: f32,
+}
+
+pub struct AsymmetricBinaryVectors<TEncodedQuery, TEncodedVectors>
+where
+    TEncodedVectors: EncodedVectors<TEncodedQuery>,
+{
+    storage: TEncodedVectors,
+    query_encoding: BinaryQuantizationQueryEncoding,
+    encoded_query: PhantomData<TEncodedQuery>,
+}
+
+impl<TEncodedQuery, TEncodedVectors> AsymmetricBinaryVectors<TEncodedQuery, TEncodedVectors>
+where
+    TEncodedVectors: EncodedVectors<TEncodedQuery>,
+{
+    pub fn new(storage: TEncodedVectors, query_encoding: BinaryQuantizationQueryEncoding) -> Self {
+        Self {
+            storage,
+            query_encoding,
+            encoded_query: PhantomData,
+        }
+    }
+
+    fn encode_scalar_query(
+        &self,
+        query: &[VectorElementType],
+        bits: u32,
+    ) -> EncodedScalarQuery<TEncodedQuery> {
+        let levels = ((1u32 << bits) - 1) as f32;
+        let offset = query.iter().copied().fold(f32::INFINITY, f32::min);
+        let max = query.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        let step = if max > offset {
+            (max - offset) / levels
+        } else {
+            0.0
+        };
+
+        let codes: Vec<u32> = query
+            .iter()
+            .map(|&value| {
+                if step > 0.0 {
+                    ((value - offset) / step).round().clamp(0.0, levels) as u32
+                } else {
+                    0
+                }
+            })
+            .collect();
+
+        let bit_planes = (0..bits)
+            .map(|bit| {
+                let plane: Vec<VectorElementType> = codes
+                    .iter()
+                    .map(|&code| if (code >> bit) & 1 == 1 { 1.0 } else { -1.0 })
+                    .collect();
+                self.storage.encode_query(&plane)
+            })
+            .collect();
+
+        let ones = self.storage.encode_query(&vec![1.0; query.len()]);
+
+        EncodedScalarQuery {
+            bit_planes,
+            ones,
+            offset: if offset.is_finite() { offset } else { 0.0 },
+            step,
+        }
+    }
+
+    fn score_scalar_query(
+        &self,
+        query: &EncodedScalarQuery<TEncodedQuery>,
+        i: PointOffsetType,
+    ) -> ScoreType {
+        let signs_sum = self.storage.score_point(&query.ones, i);
+        // Each plane scores to `sum(sign(bit) * sign(x))`, so `(score + signs_sum) / 2` is `sum(bit * sign(x))`
+        let codes_score: ScoreType = query
+            .bit_planes
+            .iter()
+            .enumerate()
+            .map(|(bit, plane)| {
+                (1u32 << bit) as ScoreType * (self.storage.score_point(plane, i) + signs_sum)
+            })
+            .sum();
+        query.offset * signs_sum + query.step * codes_score / 2.0
+    }
+}
+
+impl<TEncodedQuery, TEncodedVectors> EncodedVectors<EncodedBinaryQuery<TEncodedQuery>>
+    for AsymmetricBinaryVectors<TEncodedQuery, TEncodedVectors>
+where
+    TEncodedVectors: EncodedVectors<TEncodedQuery>,
+{
+    fn save(&self, data_path: &Path, meta_path: &Path) -> std::io::Result<()> {
+        self.storage.save(data_path, meta_path)
+    }
+
+    /// Query encoding is not stored with the vectors, so this storage can't be loaded by itself.
+    /// Load the binary storage instead, and wrap it with [`AsymmetricBinaryVectors::new`].
+    fn load(
+        _data_path: &Path,
+        _meta_path: &Path,
+        _vector_parameters: &quantization::VectorParameters,
+    ) -> std::io::Result<Self> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "Asymmetric binary storage must be loaded as binary storage and wrapped with the query encoding",
+        ))
+    }
+
+    fn encode_query(&self, query: &[VectorElementType]) -> EncodedBinaryQuery<TEncodedQuery> {
+        match self.query_encoding {
+            BinaryQuantizationQueryEncoding::Binary => {
+                EncodedBinaryQuery::Binary(self.storage.encode_query(query))
+            }
+            BinaryQuantizationQueryEncoding::Scalar4Bits => {
+                EncodedBinaryQuery::Scalar(self.encode_scalar_query(query, 4))
+            }
+            BinaryQuantizationQueryEncoding::Scalar8Bits => {
+                EncodedBinaryQuery::Scalar(self.encode_scalar_query(query, 8))
+            }
+        }
+    }
+
+    fn score_point(&self, query: &EncodedBinaryQuery<TEncodedQuery>, i: PointOffsetType) -> f32 {
+        match query {
+            EncodedBinaryQuery::Binary(query) => self.storage.score_point(query, i),
+            EncodedBinaryQuery::Scalar(query) => self.score_scalar_query(query, i),
+        }
+    }
+
+    fn score_internal(&self, i: PointOffsetType, j: PointOffsetType) -> f32 {
+        self.storage.score_internal(i, j)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quantization::encoded_vectors_binary::EncodedVectorsBin;
+    use quantization::{DistanceType, VectorParameters};
+
+    use super::*;
+    use crate::vector_storage::chunked_vectors::ChunkedVectors;
+
+    #[test]
+    fn test_scalar_query_scores_signs() {
+        let dim = 32;
+        let vectors: Vec<Vec<f32>> = vec![
+            (0..dim)
+                .map(|i| if i % 2 == 0 { 1.0 } else { -1.0 })
+                .collect(),
+            (0..dim)
+                .map(|i| if i < dim / 3 { 1.0 } else { -1.0 })
+                .collect(),
+            vec![1.0; dim],
+        ];
+        let vector_parameters = VectorParameters {
+            dim,
+            count: vectors.len(),
+            distance_type: DistanceType::Dot,
+            invert: false,
+        };
+        let quantized_vector_size =
+            EncodedVectorsBin::<u128, ChunkedVectors<u8>>::get_quantized_vector_size_from_params(
+                &vector_parameters,
+            );
+        let encoded = EncodedVectorsBin::<u128, _>::encode(
+            vectors.iter(),
+            ChunkedVectors::<u8>::new(quantized_vector_size),
+            &vector_parameters,
+            || false,
+        )
+        .unwrap();
+        let storage =
+            AsymmetricBinaryVectors::new(encoded, BinaryQuantizationQueryEncoding::Scalar4Bits);
+
+        // Values are exactly representable with 4 bits
+        let query: Vec<f32> = (0..dim).map(|i| (i % 16) as f32 * 0.5 - 2.0).collect();
+        let encoded_query = storage.encode_query(&query);
+
+        for (i, vector) in vectors.iter().enumerate() {
+            let expected: f32 = query.iter().zip(vector).map(|(q, v)| q * v).sum();
+            let score = storage.score_point(&encoded_query, i as PointOffsetType);
+            assert!(
+                (score - expected).abs() < 1e-3,
+                "score {score} != expected {expected}",
+            );
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! Multi-bit encodings for binary quantization.
+//!
+//! Binary quantization stores a single bit per value: whether the value is positive.
+//! Multi-bit encodings are built on top of it by expanding each vector into more values
+//! before quantization, so that several bits are stored per original dimension.
+//! Each dimension is split into three zones: low, medium and high. The zones are encoded
+//! in a way, that the hamming distance between codes grows with the distance between zones.
+
+use std::borrow::Cow;
+
+use crate::data_types::primitive::PrimitiveVectorElement;
+use crate::types::{BinaryQuantization, BinaryQuantizationEncoding, Distance, QuantizationConfig};
+
+/// Bound of the medium zone, relative to the root mean square of the vector values.
+///
+/// Splits normally distributed values into three zones of roughly equal size.
+const ZONE_BOUND: f32 = 0.43;
+
+impl BinaryQuantizationEncoding {
+    /// Number of values, which vector of the given dimension is expanded to.
+    pub fn encoded_dim(self, dim: usize) -> usize {
+        match self {
+            BinaryQuantizationEncoding::OneBit => dim,
+            BinaryQuantizationEncoding::TwoBits => dim * 2,
+            BinaryQuantizationEncoding::OneAndHalfBits => dim + dim.div_ceil(2),
+        }
+    }
+
+    /// Expand vector, so that the sign of each resulting value is one bit of the encoding.
+    pub fn encode<'a>(self, vector: Cow<'a, [f32]>) -> Cow<'a, [f32]> {
+        match self {
+            BinaryQuantizationEncoding::OneBit => vector,
+            // Low: 00, medium: 10, high: 11
+            BinaryQuantizationEncoding::TwoBits => {
+                let bound = zone_bound(&vector);
+                vector
+                    .iter()
+                    .flat_map(|&value| [value + bound, value - bound])
+                    .collect()
+            }
+            // Per pair: whether each value is not low, and whether any of them is high
+            BinaryQuantizationEncoding::OneAndHalfBits => {
+                let bound = zone_bound(&vector);
+                vector
+                    .chunks(2)
+                    .flat_map(|pair| {
+                        let max = pair.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+                        pair.iter()
+                            .map(move |&value| value + bound)
+                            .chain([max - bound])
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+fn zone_bound(vector: &[f32]) -> f32 {
+    if vector.is_empty() {
+        return 0.0;
+    }
+    let mean_square = vector.iter().map(|value| value * value).sum::<f32>() / vector.len() as f32;
+    mean_square.sqrt() * ZONE_BOUND
+}
+
+/// Number of values of the vector, as it is passed to the quantization.
+pub fn quantized_dim(quantization_config: &QuantizationConfig, dim: usize) -> usize {
+    match quantization_config {
+        QuantizationConfig::Binary(BinaryQuantization { binary }) => {
+            binary.encoding.unwrap_or_default().encoded_dim(dim)
+        }
+        QuantizationConfig::Scalar(_) | QuantizationConfig::Product(_) => dim,
+    }
+}
+
+/// Convert a single vector into the input of quantization.
+///
+/// Must be applied to each inner vector of a multivector separately.
+pub fn prequantize<'a, TElement: PrimitiveVectorElement>(
+    quantization_config: &QuantizationConfig,
+    distance: Distance,
+    vector: &'a [TElement],
+) -> Cow<'a, [f32]> {
+    let vector = TElement::quantization_preprocess(quantization_config, distance, vector);
+    match quantization_config {
+        QuantizationConfig::Binary(BinaryQuantization { binary }) => {
+            binary.encoding.unwrap_or_default().encode(vector)
+        }
+        QuantizationConfig::Scalar(_) | QuantizationConfig::Product(_) => vector,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bits(encoding: BinaryQuantizationEncoding, vector: &[f32]) -> Vec<bool> {
+        encoding
+            .encode(Cow::Borrowed(vector))
+            .iter()
+            .map(|&value| value > 0.0)
+            .collect()
+    }
+
+    #[test]
+    fn test_two_bits_encoding() {
+        let vector = [-1.0, 0.0, 1.0];
+        let encoded = bits(BinaryQuantizationEncoding::TwoBits, &vector);
+        assert_eq!(
+            encoded.len(),
+            BinaryQuantizationEncoding::TwoBits.encoded_dim(3)
+        );
+        assert_eq!(encoded, [false, false, true, false, true, true]);
+    }
+
+    #[test]
+    fn test_one_and_half_bits_encoding() {
+        let vector = [-1.0, 0.0, 1.0, -1.0, 0.0];
+        let encoded = bits(BinaryQuantizationEncoding::OneAndHalfBits, &vector);
+        assert_eq!(
+            encoded.len(),
+            BinaryQuantizationEncoding::OneAndHalfBits.encoded_dim(5),
+        );
+        assert_eq!(
+            encoded,
+            [false, true, false, true, false, true, true, false],
+        );
+    }
+}
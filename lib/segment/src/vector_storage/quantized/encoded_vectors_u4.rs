@@ -0,0 +1,267 @@
+//! Scalar quantization with 4 bits per value.
+//!
+//! Values are mapped onto 16 levels between the bounds of the value distribution,
+//! two codes are packed into each byte.
+//! Each encoded vector is prefixed with a correction term, which makes dot product
+//! of codes equal to dot product of the dequantized vectors.
+
+use std::path::Path;
+
+use common::types::PointOffsetType;
+use quantization::{
+    DistanceType, EncodedStorage, EncodedStorageBuilder, EncodedVectors, EncodingError,
+    VectorParameters,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::data_types::vectors::VectorElementType;
+use crate::spaces::metric_u4::{dot_u4, euclid_u4, manhattan_u4};
+
+/// Highest 4-bit code
+const MAX_CODE: f32 = 15.0;
+
+/// Max number of values to sample for finding quantization bounds
+const SAMPLE_SIZE: usize = 100_000;
+
+const CORRECTION_SIZE: usize = std::mem::size_of::<f32>();
+
+pub struct EncodedVectorsU4<TStorage: EncodedStorage> {
+    encoded_vectors: TStorage,
+    metadata: Metadata,
+    vector_parameters: VectorParameters,
+}
+
+pub struct EncodedQueryU4 {
+    codes: Vec<u8>,
+    correction: f32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Metadata {
+    offset: f32,
+    step: f32,
+}
+
+impl<TStorage: EncodedStorage> EncodedVectorsU4<TStorage> {
+    pub fn encode<'a>(
+        vectors: impl Iterator<Item = impl AsRef<[VectorElementType]> + 'a> + Clone,
+        mut storage_builder: impl EncodedStorageBuilder<TStorage>,
+        vector_parameters: &VectorParameters,
+        quantile: Option<f32>,
+        stop_condition: impl Fn() -> bool,
+    ) -> Result<Self, EncodingError> {
+        let (min, max) = find_bounds(vectors.clone(), vector_parameters, quantile);
+        let step = (max - min) / MAX_CODE;
+        let metadata = Metadata {
+            offset: min,
+            step: if step > 0.0 { step } else { 1.0 },
+        };
+
+        let mut encoded = Vec::with_capacity(Self::get_quantized_vector_size(vector_parameters));
+        for vector in vectors {
+            if stop_condition() {
+                return Err(EncodingError::Stopped);
+            }
+            let (codes, correction) = metadata.encode(vector.as_ref(), vector_parameters);
+            encoded.clear();
+            encoded.extend_from_slice(&correction.to_le_bytes());
+            encoded.extend_from_slice(&codes);
+            storage_builder.push_vector_data(&encoded);
+        }
+
+        Ok(Self {
+            encoded_vectors: storage_builder.build(),
+            metadata,
+            vector_parameters: vector_parameters.clone(),
+        })
+    }
+
+    pub fn get_quantized_vector_size(vector_parameters: &VectorParameters) -> usize {
+        CORRECTION_SIZE + vector_parameters.dim.div_ceil(2)
+    }
+
+    fn get_encoded(&self, i: PointOffsetType) -> (&[u8], f32) {
+        let data = self.encoded_vectors.get_vector_data(
+            i as usize,
+            Self::get_quantized_vector_size(&self.vector_parameters),
+        );
+        let (correction, codes) = data.split_at(CORRECTION_SIZE);
+        let correction = f32::from_le_bytes(correction.try_into().unwrap());
+        (codes, correction)
+    }
+
+    fn score(&self, codes_a: &[u8], correction_a: f32, codes_b: &[u8], correction_b: f32) -> f32 {
+        let step = self.metadata.step;
+        let score = match self.vector_parameters.distance_type {
+            DistanceType::Dot => {
+                correction_a + correction_b + step * step * dot_u4(codes_a, codes_b) as f32
+            }
+            DistanceType::L2 => step * step * euclid_u4(codes_a, codes_b) as f32,
+            DistanceType::L1 => step * manhattan_u4(codes_a, codes_b) as f32,
+        };
+        if self.vector_parameters.invert {
+            -score
+        } else {
+            score
+        }
+    }
+}
+
+impl Metadata {
+    /// Packed codes and the correction term of a vector
+    fn encode(
+        &self,
+        vector: &[VectorElementType],
+        vector_parameters: &VectorParameters,
+    ) -> (Vec<u8>, f32) {
+        let codes = vector.iter().map(|&value| {
+            ((value - self.offset) / self.step)
+                .round()
+                .clamp(0.0, MAX_CODE) as u8
+        });
+
+        let mut packed = vec![0u8; vector_parameters.dim.div_ceil(2)];
+        let mut codes_sum = 0u32;
+        for (i, code) in codes.enumerate() {
+            packed[i / 2] |= code << (4 * (i % 2));
+            codes_sum += code as u32;
+        }
+
+        // dot(x, y) = offset^2 * dim + offset * step * (sum(x) + sum(y)) + step^2 * dot(codes)
+        let correction = match vector_parameters.distance_type {
+            DistanceType::Dot => {
+                self.offset * self.offset * vector_parameters.dim as f32 / 2.0
+                    + self.offset * self.step * codes_sum as f32
+            }
+            DistanceType::L1 | DistanceType::L2 => 0.0,
+        };
+
+        (packed, correction)
+    }
+}
+
+/// Bounds of the values, which cover the given quantile of the distribution
+fn find_bounds<'a>(
+    vectors: impl Iterator<Item = impl AsRef<[VectorElementType]> + 'a>,
+    vector_parameters: &VectorParameters,
+    quantile: Option<f32>,
+) -> (f32, f32) {
+    let total_values = vector_parameters.count * vector_parameters.dim;
+    let vectors_step = (total_values / SAMPLE_SIZE).max(1);
+    let mut values: Vec<f32> = vectors
+        .step_by(vectors_step)
+        .flat_map(|vector| vector.as_ref().to_vec())
+        .filter(|value| value.is_finite())
+        .collect();
+
+    if values.is_empty() {
+        return (0.0, 0.0);
+    }
+
+    values.sort_unstable_by(f32::total_cmp);
+    let cut = match quantile {
+        Some(quantile) => ((1.0 - quantile) / 2.0 * values.len() as f32) as usize,
+        None => 0,
+    };
+    let cut = cut.min((values.len() - 1) / 2);
+    (values[cut], values[values.len() - 1 - cut])
+}
+
+impl<TStorage: EncodedStorage> EncodedVectors<EncodedQueryU4> for EncodedVectorsU4<TStorage> {
+    fn save(&self, data_path: &Path, meta_path: &Path) -> std::io::Result<()> {
+        self.encoded_vectors.save_to_file(data_path)?;
+        let metadata_bytes = serde_json::to_vec(&self.metadata)?;
+        std::fs::write(meta_path, metadata_bytes)
+    }
+
+    fn load(
+        data_path: &Path,
+        meta_path: &Path,
+        vector_parameters: &VectorParameters,
+    ) -> std::io::Result<Self> {
+        let metadata: Metadata = serde_json::from_slice(&std::fs::read(meta_path)?)?;
+        let encoded_vectors = TStorage::from_file(
+            data_path,
+            Self::get_quantized_vector_size(vector_parameters),
+            vector_parameters.count,
+        )?;
+        Ok(Self {
+            encoded_vectors,
+            metadata,
+            vector_parameters: vector_parameters.clone(),
+        })
+    }
+
+    fn encode_query(&self, query: &[VectorElementType]) -> EncodedQueryU4 {
+        let (codes, correction) = self.metadata.encode(query, &self.vector_parameters);
+        EncodedQueryU4 { codes, correction }
+    }
+
+    fn score_point(&self, query: &EncodedQueryU4, i: PointOffsetType) -> f32 {
+        let (codes, correction) = self.get_encoded(i);
+        self.score(&query.codes, query.correction, codes, correction)
+    }
+
+    fn score_internal(&self, i: PointOffsetType, j: PointOffsetType) -> f32 {
+        let (codes_i, correction_i) = self.get_encoded(i);
+        let (codes_j, correction_j) = self.get_encoded(j);
+        self.score(codes_i, correction_i, codes_j, correction_j)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+    use crate::vector_storage::chunked_vectors::ChunkedVectors;
+
+    #[test]
+    fn test_u4_scores_match_dequantized() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let dim = 37;
+        let vectors: Vec<Vec<f32>> = (0..20)
+            .map(|_| (0..dim).map(|_| rng.gen_range(-1.0..1.0)).collect())
+            .collect();
+
+        for distance_type in [DistanceType::Dot, DistanceType::L2, DistanceType::L1] {
+            let vector_parameters = VectorParameters {
+                dim,
+                count: vectors.len(),
+                distance_type,
+                invert: false,
+            };
+            let quantized_vector_size =
+                EncodedVectorsU4::<ChunkedVectors<u8>>::get_quantized_vector_size(
+                    &vector_parameters,
+                );
+            let encoded = EncodedVectorsU4::encode(
+                vectors.iter(),
+                ChunkedVectors::<u8>::new(quantized_vector_size),
+                &vector_parameters,
+                None,
+                || false,
+            )
+            .unwrap();
+
+            let query: Vec<f32> = (0..dim).map(|_| rng.gen_range(-1.0..1.0)).collect();
+            let encoded_query = encoded.encode_query(&query);
+
+            for (i, vector) in vectors.iter().enumerate() {
+                let pairs = query.iter().zip(vector);
+                let expected: f32 = match vector_parameters.distance_type {
+                    DistanceType::Dot => pairs.map(|(a, b)| a * b).sum(),
+                    DistanceType::L2 => pairs.map(|(a, b)| (a - b).powi(2)).sum(),
+                    DistanceType::L1 => pairs.map(|(a, b)| (a - b).abs()).sum(),
+                };
+                let score = encoded.score_point(&encoded_query, i as PointOffsetType);
+                // each value is off by at most half of the quantization step
+                assert!(
+                    (score - expected).abs() < 0.1 * dim as f32,
+                    "score {score} != expected {expected}",
+                );
+            }
+        }
+    }
+}
@@ -1,3 +1,6 @@
+mod asymmetric_binary;
+mod binary_encoding;
+mod encoded_vectors_u4;
 mod quantized_custom_query_scorer;
 mod quantized_mmap_storage;
 pub mod quantized_multivector_storage;
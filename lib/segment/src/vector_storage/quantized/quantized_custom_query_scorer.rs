@@ -4,6 +4,7 @@ use std::marker::PhantomData;
 use common::types::{PointOffsetType, ScoreType};
 use itertools::Itertools;
 
+use super::binary_encoding::prequantize;
 use crate::data_types::named_vectors::CowMultiVector;
 use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{
@@ -60,11 +61,8 @@ where
         let query: TQuery = original_query
             .clone()
             .transform(|original_vector| {
-                let original_vector_prequantized = TElement::quantization_preprocess(
-                    quantization_config,
-                    TMetric::distance(),
-                    &original_vector,
-                );
+                let original_vector_prequantized =
+                    prequantize(quantization_config, TMetric::distance(), &original_vector);
                 Ok(quantized_storage.encode_query(&original_vector_prequantized))
             })
             .unwrap();
@@ -108,11 +106,13 @@ where
         let query: TQuery = original_query
             .clone()
             .transform(|original_vector| {
-                let original_vector_prequantized = TElement::quantization_preprocess(
-                    quantization_config,
-                    TMetric::distance(),
-                    &original_vector.flattened_vectors,
-                );
+                let original_vector_prequantized = original_vector
+                    .multi_vectors()
+                    .flat_map(|inner_vector| {
+                        prequantize(quantization_config, TMetric::distance(), inner_vector)
+                            .into_owned()
+                    })
+                    .collect_vec();
                 Ok(quantized_storage.encode_query(&original_vector_prequantized))
             })
             .unwrap();
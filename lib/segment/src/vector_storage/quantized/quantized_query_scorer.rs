@@ -4,6 +4,7 @@ use std::marker::PhantomData;
 use common::types::{PointOffsetType, ScoreType};
 use itertools::Itertools;
 
+use super::binary_encoding::prequantize;
 use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{DenseVector, MultiDenseVectorInternal};
 use crate::spaces::metric::Metric;
@@ -36,7 +37,7 @@ where
     ) -> Self {
         let raw_preprocessed_query = TMetric::preprocess(raw_query);
         let original_query = TElement::slice_from_float_cow(Cow::Owned(raw_preprocessed_query));
-        let original_query_prequantized = TElement::quantization_preprocess(
+        let original_query_prequantized = prequantize(
             quantization_config,
             TMetric::distance(),
             original_query.as_ref(),
@@ -63,7 +64,7 @@ where
                 let inner_preprocessed = TMetric::preprocess(inner_vector.to_vec());
                 let inner_converted =
                     TElement::slice_from_float_cow(Cow::Owned(inner_preprocessed));
-                let inner_prequantized = TElement::quantization_preprocess(
+                let inner_prequantized = prequantize(
                     quantization_config,
                     TMetric::distance(),
                     inner_converted.as_ref(),
@@ -9,11 +9,13 @@ use super::quantized_vectors::QuantizedVectorStorage;
 use crate::common::operation_error::OperationResult;
 use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{
-    DenseVector, MultiDenseVectorInternal, QueryVector, VectorElementType, VectorElementTypeByte,
-    VectorElementTypeHalf,
+    DenseVector, MultiDenseVectorInternal, QueryVector, VectorElementType,
+    VectorElementTypeBFloat16, VectorElementTypeByte, VectorElementTypeHalf, VectorElementTypeInt8,
 };
 use crate::spaces::metric::Metric;
-use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+};
 use crate::types::{Distance, QuantizationConfig, VectorStorageDatatype};
 use crate::vector_storage::query::{ContextQuery, DiscoveryQuery, RecoQuery, TransformInto};
 use crate::vector_storage::{raw_scorer_from_query_scorer, RawScorer};
@@ -62,8 +64,10 @@ impl<'a> QuantizedScorerBuilder<'a> {
                 Distance::Manhattan => {
                     self.build_with_metric::<VectorElementType, ManhattanMetric>()
                 }
+                Distance::Hamming => self.build_with_metric::<VectorElementType, HammingMetric>(),
+                Distance::Jaccard => self.build_with_metric::<VectorElementType, JaccardMetric>(),
             },
-            VectorStorageDatatype::Uint8 => match self.distance {
+            VectorStorageDatatype::Uint8 | VectorStorageDatatype::Bit => match self.distance {
                 Distance::Cosine => self.build_with_metric::<VectorElementTypeByte, CosineMetric>(),
                 Distance::Euclid => self.build_with_metric::<VectorElementTypeByte, EuclidMetric>(),
                 Distance::Dot => {
@@ -72,6 +76,12 @@ impl<'a> QuantizedScorerBuilder<'a> {
                 Distance::Manhattan => {
                     self.build_with_metric::<VectorElementTypeByte, ManhattanMetric>()
                 }
+                Distance::Hamming => {
+                    self.build_with_metric::<VectorElementTypeByte, HammingMetric>()
+                }
+                Distance::Jaccard => {
+                    self.build_with_metric::<VectorElementTypeByte, JaccardMetric>()
+                }
             },
             VectorStorageDatatype::Float16 => match self.distance {
                 Distance::Cosine => self.build_with_metric::<VectorElementTypeHalf, CosineMetric>(),
@@ -82,6 +92,48 @@ impl<'a> QuantizedScorerBuilder<'a> {
                 Distance::Manhattan => {
                     self.build_with_metric::<VectorElementTypeHalf, ManhattanMetric>()
                 }
+                Distance::Hamming => {
+                    self.build_with_metric::<VectorElementTypeHalf, HammingMetric>()
+                }
+                Distance::Jaccard => {
+                    self.build_with_metric::<VectorElementTypeHalf, JaccardMetric>()
+                }
+            },
+            VectorStorageDatatype::BFloat16 => match self.distance {
+                Distance::Cosine => {
+                    self.build_with_metric::<VectorElementTypeBFloat16, CosineMetric>()
+                }
+                Distance::Euclid => {
+                    self.build_with_metric::<VectorElementTypeBFloat16, EuclidMetric>()
+                }
+                Distance::Dot => {
+                    self.build_with_metric::<VectorElementTypeBFloat16, DotProductMetric>()
+                }
+                Distance::Manhattan => {
+                    self.build_with_metric::<VectorElementTypeBFloat16, ManhattanMetric>()
+                }
+                Distance::Hamming => {
+                    self.build_with_metric::<VectorElementTypeBFloat16, HammingMetric>()
+                }
+                Distance::Jaccard => {
+                    self.build_with_metric::<VectorElementTypeBFloat16, JaccardMetric>()
+                }
+            },
+            VectorStorageDatatype::Int8 => match self.distance {
+                Distance::Cosine => self.build_with_metric::<VectorElementTypeInt8, CosineMetric>(),
+                Distance::Euclid => self.build_with_metric::<VectorElementTypeInt8, EuclidMetric>(),
+                Distance::Dot => {
+                    self.build_with_metric::<VectorElementTypeInt8, DotProductMetric>()
+                }
+                Distance::Manhattan => {
+                    self.build_with_metric::<VectorElementTypeInt8, ManhattanMetric>()
+                }
+                Distance::Hamming => {
+                    self.build_with_metric::<VectorElementTypeInt8, HammingMetric>()
+                }
+                Distance::Jaccard => {
+                    self.build_with_metric::<VectorElementTypeInt8, JaccardMetric>()
+                }
             },
         }
     }
@@ -98,6 +150,12 @@ impl<'a> QuantizedScorerBuilder<'a> {
             QuantizedVectorStorage::ScalarMmap(storage) => {
                 self.new_quantized_scorer::<TElement, TMetric, _>(storage)
             }
+            QuantizedVectorStorage::ScalarU4Ram(storage) => {
+                self.new_quantized_scorer::<TElement, TMetric, _>(storage)
+            }
+            QuantizedVectorStorage::ScalarU4Mmap(storage) => {
+                self.new_quantized_scorer::<TElement, TMetric, _>(storage)
+            }
             QuantizedVectorStorage::PQRam(storage) => {
                 self.new_quantized_scorer::<TElement, TMetric, _>(storage)
             }
@@ -116,6 +174,12 @@ impl<'a> QuantizedScorerBuilder<'a> {
             QuantizedVectorStorage::ScalarMmapMulti(storage) => {
                 self.new_multi_quantized_scorer::<TElement, TMetric, _>(storage)
             }
+            QuantizedVectorStorage::ScalarU4RamMulti(storage) => {
+                self.new_multi_quantized_scorer::<TElement, TMetric, _>(storage)
+            }
+            QuantizedVectorStorage::ScalarU4MmapMulti(storage) => {
+                self.new_multi_quantized_scorer::<TElement, TMetric, _>(storage)
+            }
             QuantizedVectorStorage::PQRamMulti(storage) => {
                 self.new_multi_quantized_scorer::<TElement, TMetric, _>(storage)
             }
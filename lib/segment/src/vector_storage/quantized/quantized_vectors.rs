@@ -10,6 +10,9 @@ use quantization::{
 };
 use serde::{Deserialize, Serialize};
 
+use super::asymmetric_binary::{AsymmetricBinaryVectors, EncodedBinaryQuery};
+use super::binary_encoding::{prequantize, quantized_dim};
+use super::encoded_vectors_u4::{EncodedQueryU4, EncodedVectorsU4};
 use super::quantized_multivector_storage::{
     create_offsets_file_from_iter, MultivectorOffset, MultivectorOffsetsStorage,
     MultivectorOffsetsStorageMmap, QuantizedMultivectorStorage,
@@ -21,8 +24,8 @@ use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{QueryVector, VectorElementType};
 use crate::types::{
     BinaryQuantization, BinaryQuantizationConfig, CompressionRatio, Distance, MultiVectorConfig,
-    ProductQuantization, ProductQuantizationConfig, QuantizationConfig, ScalarQuantization,
-    ScalarQuantizationConfig, VectorStorageDatatype,
+    ProductQuantization, ProductQuantizationConfig, QuantizationConfig, QuantizationKind,
+    ScalarQuantization, ScalarQuantizationConfig, ScalarType, VectorStorageDatatype,
 };
 use crate::vector_storage::chunked_vectors::ChunkedVectors;
 use crate::vector_storage::quantized::quantized_mmap_storage::{
@@ -36,6 +39,7 @@ pub const QUANTIZED_CONFIG_PATH: &str = "quantized.config.json";
 pub const QUANTIZED_DATA_PATH: &str = "quantized.data";
 pub const QUANTIZED_META_PATH: &str = "quantized.meta.json";
 pub const QUANTIZED_OFFSETS_PATH: &str = "quantized.offsets.data";
+pub const QUANTIZED_ADDITIONAL_PATH: &str = "quantized.additional";
 
 #[derive(Deserialize, Serialize, Clone)]
 pub struct QuantizedVectorsConfig {
@@ -55,6 +59,18 @@ type ScalarMmapMulti = QuantizedMultivectorStorage<
     MultivectorOffsetsStorageMmap,
 >;
 
+type ScalarU4RamMulti = QuantizedMultivectorStorage<
+    EncodedQueryU4,
+    EncodedVectorsU4<ChunkedVectors<u8>>,
+    Vec<MultivectorOffset>,
+>;
+
+type ScalarU4MmapMulti = QuantizedMultivectorStorage<
+    EncodedQueryU4,
+    EncodedVectorsU4<QuantizedMmapStorage>,
+    MultivectorOffsetsStorageMmap,
+>;
+
 type PQRamMulti = QuantizedMultivectorStorage<
     EncodedQueryPQ,
     EncodedVectorsPQ<ChunkedVectors<u8>>,
@@ -67,27 +83,37 @@ type PQMmapMulti = QuantizedMultivectorStorage<
     MultivectorOffsetsStorageMmap,
 >;
 
+type BinaryRam =
+    AsymmetricBinaryVectors<EncodedBinVector<u128>, EncodedVectorsBin<u128, ChunkedVectors<u8>>>;
+
+type BinaryMmap =
+    AsymmetricBinaryVectors<EncodedBinVector<u128>, EncodedVectorsBin<u128, QuantizedMmapStorage>>;
+
 type BinaryRamMulti = QuantizedMultivectorStorage<
-    EncodedBinVector<u8>,
-    EncodedVectorsBin<u8, ChunkedVectors<u8>>,
+    EncodedBinaryQuery<EncodedBinVector<u8>>,
+    AsymmetricBinaryVectors<EncodedBinVector<u8>, EncodedVectorsBin<u8, ChunkedVectors<u8>>>,
     Vec<MultivectorOffset>,
 >;
 
 type BinaryMmapMulti = QuantizedMultivectorStorage<
-    EncodedBinVector<u8>,
-    EncodedVectorsBin<u8, QuantizedMmapStorage>,
+    EncodedBinaryQuery<EncodedBinVector<u8>>,
+    AsymmetricBinaryVectors<EncodedBinVector<u8>, EncodedVectorsBin<u8, QuantizedMmapStorage>>,
     MultivectorOffsetsStorageMmap,
 >;
 
 pub enum QuantizedVectorStorage {
     ScalarRam(EncodedVectorsU8<ChunkedVectors<u8>>),
     ScalarMmap(EncodedVectorsU8<QuantizedMmapStorage>),
+    ScalarU4Ram(EncodedVectorsU4<ChunkedVectors<u8>>),
+    ScalarU4Mmap(EncodedVectorsU4<QuantizedMmapStorage>),
     PQRam(EncodedVectorsPQ<ChunkedVectors<u8>>),
     PQMmap(EncodedVectorsPQ<QuantizedMmapStorage>),
-    BinaryRam(EncodedVectorsBin<u128, ChunkedVectors<u8>>),
-    BinaryMmap(EncodedVectorsBin<u128, QuantizedMmapStorage>),
+    BinaryRam(BinaryRam),
+    BinaryMmap(BinaryMmap),
     ScalarRamMulti(ScalarRamMulti),
     ScalarMmapMulti(ScalarMmapMulti),
+    ScalarU4RamMulti(ScalarU4RamMulti),
+    ScalarU4MmapMulti(ScalarU4MmapMulti),
     PQRamMulti(PQRamMulti),
     PQMmapMulti(PQMmapMulti),
     BinaryRamMulti(BinaryRamMulti),
@@ -100,26 +126,68 @@ pub struct QuantizedVectors {
     path: PathBuf,
     distance: Distance,
     datatype: VectorStorageDatatype,
+    /// Quantizations built in addition to this one, stored in subdirectories
+    additional: Vec<QuantizedVectors>,
 }
 
 impl QuantizedVectors {
+    /// Select quantization of the given kind, or this quantization if no kind is given
+    ///
+    /// Quantizations of a vector are of different kinds, so the kind identifies one of them.
+    /// Fails if there is no quantization of the given kind.
+    pub fn select(&self, kind: Option<QuantizationKind>) -> OperationResult<&Self> {
+        let Some(kind) = kind else {
+            return Ok(self);
+        };
+
+        std::iter::once(self)
+            .chain(&self.additional)
+            .find(|quantized| quantized.config.quantization_config.kind() == kind)
+            .ok_or_else(|| OperationError::ValidationError {
+                description: format!("No {kind:?} quantization is configured for the vector"),
+            })
+    }
+
+    pub fn config(&self) -> &QuantizedVectorsConfig {
+        &self.config
+    }
+
+    /// Whether results are rescored with original vectors, unless the search params say otherwise.
     pub fn default_rescoring(&self) -> bool {
-        matches!(
-            self.storage_impl,
-            QuantizedVectorStorage::BinaryRam(_) | QuantizedVectorStorage::BinaryMmap(_)
-        )
+        match self.storage_impl {
+            QuantizedVectorStorage::ScalarRam(_) => false,
+            QuantizedVectorStorage::ScalarMmap(_) => false,
+            QuantizedVectorStorage::ScalarU4Ram(_) => true,
+            QuantizedVectorStorage::ScalarU4Mmap(_) => true,
+            QuantizedVectorStorage::PQRam(_) => false,
+            QuantizedVectorStorage::PQMmap(_) => false,
+            QuantizedVectorStorage::BinaryRam(_) => true,
+            QuantizedVectorStorage::BinaryMmap(_) => true,
+            QuantizedVectorStorage::ScalarRamMulti(_) => false,
+            QuantizedVectorStorage::ScalarMmapMulti(_) => false,
+            QuantizedVectorStorage::ScalarU4RamMulti(_) => true,
+            QuantizedVectorStorage::ScalarU4MmapMulti(_) => true,
+            QuantizedVectorStorage::PQRamMulti(_) => false,
+            QuantizedVectorStorage::PQMmapMulti(_) => false,
+            QuantizedVectorStorage::BinaryRamMulti(_) => false,
+            QuantizedVectorStorage::BinaryMmapMulti(_) => false,
+        }
     }
 
     pub fn is_multivector(&self) -> bool {
         match self.storage_impl {
             QuantizedVectorStorage::ScalarRam(_) => false,
             QuantizedVectorStorage::ScalarMmap(_) => false,
+            QuantizedVectorStorage::ScalarU4Ram(_) => false,
+            QuantizedVectorStorage::ScalarU4Mmap(_) => false,
             QuantizedVectorStorage::PQRam(_) => false,
             QuantizedVectorStorage::PQMmap(_) => false,
             QuantizedVectorStorage::BinaryRam(_) => false,
             QuantizedVectorStorage::BinaryMmap(_) => false,
             QuantizedVectorStorage::ScalarRamMulti(_) => true,
             QuantizedVectorStorage::ScalarMmapMulti(_) => true,
+            QuantizedVectorStorage::ScalarU4RamMulti(_) => true,
+            QuantizedVectorStorage::ScalarU4MmapMulti(_) => true,
             QuantizedVectorStorage::PQRamMulti(_) => true,
             QuantizedVectorStorage::PQMmapMulti(_) => true,
             QuantizedVectorStorage::BinaryRamMulti(_) => true,
@@ -154,6 +222,10 @@ impl QuantizedVectors {
         match &self.storage_impl {
             QuantizedVectorStorage::ScalarRam(storage) => storage.save(&data_path, &meta_path)?,
             QuantizedVectorStorage::ScalarMmap(storage) => storage.save(&data_path, &meta_path)?,
+            QuantizedVectorStorage::ScalarU4Ram(storage) => storage.save(&data_path, &meta_path)?,
+            QuantizedVectorStorage::ScalarU4Mmap(storage) => {
+                storage.save(&data_path, &meta_path)?
+            }
             QuantizedVectorStorage::PQRam(storage) => storage.save(&data_path, &meta_path)?,
             QuantizedVectorStorage::PQMmap(storage) => storage.save(&data_path, &meta_path)?,
             QuantizedVectorStorage::BinaryRam(storage) => storage.save(&data_path, &meta_path)?,
@@ -164,6 +236,12 @@ impl QuantizedVectors {
             QuantizedVectorStorage::ScalarMmapMulti(storage) => {
                 storage.save_multi(&data_path, &meta_path, &offsets_path)?
             }
+            QuantizedVectorStorage::ScalarU4RamMulti(storage) => {
+                storage.save_multi(&data_path, &meta_path, &offsets_path)?
+            }
+            QuantizedVectorStorage::ScalarU4MmapMulti(storage) => {
+                storage.save_multi(&data_path, &meta_path, &offsets_path)?
+            }
             QuantizedVectorStorage::PQRamMulti(storage) => {
                 storage.save_multi(&data_path, &meta_path, &offsets_path)?
             }
@@ -192,9 +270,39 @@ impl QuantizedVectors {
         if self.is_multivector() {
             files.push(self.path.join(QUANTIZED_OFFSETS_PATH));
         }
+        for additional in &self.additional {
+            files.extend(additional.files());
+        }
         files
     }
 
+    fn additional_path(path: &Path, index: usize) -> PathBuf {
+        path.join(format!("{QUANTIZED_ADDITIONAL_PATH}.{index}"))
+    }
+
+    /// Build quantizations in addition to this one, which can be selected at search time
+    pub fn create_additional(
+        &mut self,
+        vector_storage: &VectorStorageEnum,
+        quantization_configs: &[QuantizationConfig],
+        max_threads: usize,
+        stopped: &AtomicBool,
+    ) -> OperationResult<()> {
+        for (index, quantization_config) in quantization_configs.iter().enumerate() {
+            let path = Self::additional_path(&self.path, index);
+            std::fs::create_dir_all(&path)?;
+            let additional = Self::create(
+                vector_storage,
+                quantization_config,
+                &path,
+                max_threads,
+                stopped,
+            )?;
+            self.additional.push(additional);
+        }
+        Ok(())
+    }
+
     pub fn create(
         vector_storage: &VectorStorageEnum,
         quantization_config: &QuantizationConfig,
@@ -212,6 +320,12 @@ impl QuantizedVectors {
             VectorStorageEnum::DenseSimpleHalf(v) => {
                 Self::create_impl(v, quantization_config, path, max_threads, stopped)
             }
+            VectorStorageEnum::DenseSimpleBFloat16(v) => {
+                Self::create_impl(v, quantization_config, path, max_threads, stopped)
+            }
+            VectorStorageEnum::DenseSimpleInt8(v) => {
+                Self::create_impl(v, quantization_config, path, max_threads, stopped)
+            }
             VectorStorageEnum::DenseMemmap(v) => {
                 Self::create_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
             }
@@ -221,6 +335,12 @@ impl QuantizedVectors {
             VectorStorageEnum::DenseMemmapHalf(v) => {
                 Self::create_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
             }
+            VectorStorageEnum::DenseMemmapBFloat16(v) => {
+                Self::create_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
+            }
+            VectorStorageEnum::DenseMemmapInt8(v) => {
+                Self::create_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
+            }
             VectorStorageEnum::DenseAppendableMemmap(v) => {
                 Self::create_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
             }
@@ -230,7 +350,14 @@ impl QuantizedVectors {
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => {
                 Self::create_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
             }
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => {
+                Self::create_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
+            }
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => {
+                Self::create_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
+            }
             VectorStorageEnum::SparseSimple(_) => Err(OperationError::WrongSparse),
+            VectorStorageEnum::SparseMmap(_) => Err(OperationError::WrongSparse),
             VectorStorageEnum::MultiDenseSimple(v) => {
                 Self::create_multi_impl(v, quantization_config, path, max_threads, stopped)
             }
@@ -240,6 +367,12 @@ impl QuantizedVectors {
             VectorStorageEnum::MultiDenseSimpleHalf(v) => {
                 Self::create_multi_impl(v, quantization_config, path, max_threads, stopped)
             }
+            VectorStorageEnum::MultiDenseSimpleBFloat16(v) => {
+                Self::create_multi_impl(v, quantization_config, path, max_threads, stopped)
+            }
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => {
+                Self::create_multi_impl(v, quantization_config, path, max_threads, stopped)
+            }
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => {
                 Self::create_multi_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
             }
@@ -249,6 +382,27 @@ impl QuantizedVectors {
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => {
                 Self::create_multi_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
             }
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => {
+                Self::create_multi_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
+            }
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => {
+                Self::create_multi_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
+            }
+            VectorStorageEnum::MultiDenseMemmap(v) => {
+                Self::create_multi_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
+            }
+            VectorStorageEnum::MultiDenseMemmapByte(v) => {
+                Self::create_multi_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
+            }
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => {
+                Self::create_multi_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
+            }
+            VectorStorageEnum::MultiDenseMemmapBFloat16(v) => {
+                Self::create_multi_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
+            }
+            VectorStorageEnum::MultiDenseMemmapInt8(v) => {
+                Self::create_multi_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
+            }
         }
     }
 
@@ -266,28 +420,37 @@ impl QuantizedVectors {
         let count = vector_storage.total_vector_count();
         let distance = vector_storage.distance();
         let datatype = vector_storage.datatype();
-        let vectors = (0..count as PointOffsetType).map(|i| {
-            PrimitiveVectorElement::quantization_preprocess(
-                quantization_config,
-                distance,
-                vector_storage.get_dense(i),
-            )
-        });
+        let vectors = (0..count as PointOffsetType)
+            .map(|i| prequantize(quantization_config, distance, vector_storage.get_dense(i)));
         let on_disk_vector_storage = vector_storage.is_on_disk();
 
-        let vector_parameters = Self::construct_vector_parameters(distance, dim, count);
+        let vector_parameters = Self::construct_vector_parameters(
+            distance,
+            quantized_dim(quantization_config, dim),
+            count,
+        )?;
 
         let quantized_storage = match quantization_config {
             QuantizationConfig::Scalar(ScalarQuantization {
                 scalar: scalar_config,
-            }) => Self::create_scalar(
-                vectors,
-                &vector_parameters,
-                scalar_config,
-                path,
-                on_disk_vector_storage,
-                stopped,
-            )?,
+            }) => match scalar_config.r#type {
+                ScalarType::Int8 => Self::create_scalar(
+                    vectors,
+                    &vector_parameters,
+                    scalar_config,
+                    path,
+                    on_disk_vector_storage,
+                    stopped,
+                )?,
+                ScalarType::Int4 => Self::create_scalar_u4(
+                    vectors,
+                    &vector_parameters,
+                    scalar_config,
+                    path,
+                    on_disk_vector_storage,
+                    stopped,
+                )?,
+            },
             QuantizationConfig::Product(ProductQuantization { product: pq_config }) => {
                 Self::create_pq(
                     vectors,
@@ -322,6 +485,7 @@ impl QuantizedVectors {
             path: path.to_path_buf(),
             distance,
             datatype,
+            additional: Vec::new(),
         };
 
         quantized_vectors.save_to(path)?;
@@ -343,14 +507,17 @@ impl QuantizedVectors {
         let distance = vector_storage.distance();
         let datatype = vector_storage.datatype();
         let multi_vector_config = *vector_storage.multi_vector_config();
-        let vectors = vector_storage.iterate_inner_vectors().map(|v| {
-            PrimitiveVectorElement::quantization_preprocess(quantization_config, distance, v)
-        });
+        let vectors = vector_storage
+            .iterate_inner_vectors()
+            .map(|v| prequantize(quantization_config, distance, v));
         let inner_vectors_count = vectors.clone().count();
         let on_disk_vector_storage = vector_storage.is_on_disk();
 
-        let vector_parameters =
-            Self::construct_vector_parameters(distance, dim, inner_vectors_count);
+        let vector_parameters = Self::construct_vector_parameters(
+            distance,
+            quantized_dim(quantization_config, dim),
+            inner_vectors_count,
+        )?;
 
         let offsets = (0..vector_storage.total_vector_count() as PointOffsetType)
             .map(|idx| vector_storage.get_multi(idx).vectors_count() as PointOffsetType)
@@ -366,16 +533,28 @@ impl QuantizedVectors {
         let quantized_storage = match quantization_config {
             QuantizationConfig::Scalar(ScalarQuantization {
                 scalar: scalar_config,
-            }) => Self::create_scalar_multi(
-                vectors,
-                offsets,
-                &vector_parameters,
-                scalar_config,
-                multi_vector_config,
-                path,
-                on_disk_vector_storage,
-                stopped,
-            )?,
+            }) => match scalar_config.r#type {
+                ScalarType::Int8 => Self::create_scalar_multi(
+                    vectors,
+                    offsets,
+                    &vector_parameters,
+                    scalar_config,
+                    multi_vector_config,
+                    path,
+                    on_disk_vector_storage,
+                    stopped,
+                )?,
+                ScalarType::Int4 => Self::create_scalar_u4_multi(
+                    vectors,
+                    offsets,
+                    &vector_parameters,
+                    scalar_config,
+                    multi_vector_config,
+                    path,
+                    on_disk_vector_storage,
+                    stopped,
+                )?,
+            },
             QuantizationConfig::Product(ProductQuantization { product: pq_config }) => {
                 Self::create_pq_multi(
                     vectors,
@@ -414,6 +593,7 @@ impl QuantizedVectors {
             path: path.to_path_buf(),
             distance,
             datatype,
+            additional: Vec::new(),
         };
 
         quantized_vectors.save_to(path)?;
@@ -439,6 +619,31 @@ impl QuantizedVectors {
         {
             let offsets_path = path.join(QUANTIZED_OFFSETS_PATH);
             match &config.quantization_config {
+                QuantizationConfig::Scalar(ScalarQuantization { scalar })
+                    if scalar.r#type == ScalarType::Int4 =>
+                {
+                    if Self::is_ram(scalar.always_ram, on_disk_vector_storage) {
+                        QuantizedVectorStorage::ScalarU4RamMulti(
+                            QuantizedMultivectorStorage::load_multi(
+                                &data_path,
+                                &meta_path,
+                                &offsets_path,
+                                &config.vector_parameters,
+                                multivector_config,
+                            )?,
+                        )
+                    } else {
+                        QuantizedVectorStorage::ScalarU4MmapMulti(
+                            QuantizedMultivectorStorage::load_multi(
+                                &data_path,
+                                &meta_path,
+                                &offsets_path,
+                                &config.vector_parameters,
+                                multivector_config,
+                            )?,
+                        )
+                    }
+                }
                 QuantizationConfig::Scalar(ScalarQuantization { scalar }) => {
                     if Self::is_ram(scalar.always_ram, on_disk_vector_storage) {
                         QuantizedVectorStorage::ScalarRamMulti(
@@ -484,31 +689,45 @@ impl QuantizedVectors {
                     }
                 }
                 QuantizationConfig::Binary(BinaryQuantization { binary }) => {
+                    let quantized_storage =
+                        EncodedVectorsBin::load(&data_path, &meta_path, &config.vector_parameters)?;
+                    let query_encoding = binary.query_encoding.unwrap_or_default();
                     if Self::is_ram(binary.always_ram, on_disk_vector_storage) {
-                        QuantizedVectorStorage::BinaryRamMulti(
-                            QuantizedMultivectorStorage::load_multi(
-                                &data_path,
-                                &meta_path,
-                                &offsets_path,
-                                &config.vector_parameters,
-                                multivector_config,
-                            )?,
-                        )
+                        QuantizedVectorStorage::BinaryRamMulti(QuantizedMultivectorStorage::new(
+                            config.vector_parameters.dim,
+                            AsymmetricBinaryVectors::new(quantized_storage, query_encoding),
+                            MultivectorOffsetsStorage::load(&offsets_path)?,
+                            *multivector_config,
+                        ))
                     } else {
-                        QuantizedVectorStorage::BinaryMmapMulti(
-                            QuantizedMultivectorStorage::load_multi(
-                                &data_path,
-                                &meta_path,
-                                &offsets_path,
-                                &config.vector_parameters,
-                                multivector_config,
-                            )?,
-                        )
+                        QuantizedVectorStorage::BinaryMmapMulti(QuantizedMultivectorStorage::new(
+                            config.vector_parameters.dim,
+                            AsymmetricBinaryVectors::new(quantized_storage, query_encoding),
+                            MultivectorOffsetsStorage::load(&offsets_path)?,
+                            *multivector_config,
+                        ))
                     }
                 }
             }
         } else {
             match &config.quantization_config {
+                QuantizationConfig::Scalar(ScalarQuantization { scalar })
+                    if scalar.r#type == ScalarType::Int4 =>
+                {
+                    if Self::is_ram(scalar.always_ram, on_disk_vector_storage) {
+                        QuantizedVectorStorage::ScalarU4Ram(EncodedVectorsU4::load(
+                            &data_path,
+                            &meta_path,
+                            &config.vector_parameters,
+                        )?)
+                    } else {
+                        QuantizedVectorStorage::ScalarU4Mmap(EncodedVectorsU4::load(
+                            &data_path,
+                            &meta_path,
+                            &config.vector_parameters,
+                        )?)
+                    }
+                }
                 QuantizationConfig::Scalar(ScalarQuantization { scalar }) => {
                     if Self::is_ram(scalar.always_ram, on_disk_vector_storage) {
                         QuantizedVectorStorage::ScalarRam(EncodedVectorsU8::load(
@@ -540,29 +759,46 @@ impl QuantizedVectors {
                     }
                 }
                 QuantizationConfig::Binary(BinaryQuantization { binary }) => {
+                    let query_encoding = binary.query_encoding.unwrap_or_default();
                     if Self::is_ram(binary.always_ram, on_disk_vector_storage) {
-                        QuantizedVectorStorage::BinaryRam(EncodedVectorsBin::load(
-                            &data_path,
-                            &meta_path,
-                            &config.vector_parameters,
-                        )?)
+                        QuantizedVectorStorage::BinaryRam(AsymmetricBinaryVectors::new(
+                            EncodedVectorsBin::load(
+                                &data_path,
+                                &meta_path,
+                                &config.vector_parameters,
+                            )?,
+                            query_encoding,
+                        ))
                     } else {
-                        QuantizedVectorStorage::BinaryMmap(EncodedVectorsBin::load(
-                            &data_path,
-                            &meta_path,
-                            &config.vector_parameters,
-                        )?)
+                        QuantizedVectorStorage::BinaryMmap(AsymmetricBinaryVectors::new(
+                            EncodedVectorsBin::load(
+                                &data_path,
+                                &meta_path,
+                                &config.vector_parameters,
+                            )?,
+                            query_encoding,
+                        ))
                     }
                 }
             }
         };
 
+        let mut additional = Vec::new();
+        loop {
+            let additional_path = Self::additional_path(path, additional.len());
+            if !Self::config_exists(&additional_path) {
+                break;
+            }
+            additional.push(Self::load(vector_storage, &additional_path)?);
+        }
+
         Ok(QuantizedVectors {
             storage_impl: quantized_store,
             config,
             path: path.to_path_buf(),
             distance,
             datatype,
+            additional,
         })
     }
 
@@ -665,6 +901,107 @@ impl QuantizedVectors {
         }
     }
 
+    fn create_scalar_u4<'a>(
+        vectors: impl Iterator<Item = impl AsRef<[VectorElementType]> + 'a> + Clone,
+        vector_parameters: &quantization::VectorParameters,
+        scalar_config: &ScalarQuantizationConfig,
+        path: &Path,
+        on_disk_vector_storage: bool,
+        stopped: &AtomicBool,
+    ) -> OperationResult<QuantizedVectorStorage> {
+        let quantized_vector_size =
+            EncodedVectorsU4::<QuantizedMmapStorage>::get_quantized_vector_size(vector_parameters);
+        let in_ram = Self::is_ram(scalar_config.always_ram, on_disk_vector_storage);
+        if in_ram {
+            let mut storage_builder = ChunkedVectors::<u8>::new(quantized_vector_size);
+            storage_builder.try_set_capacity_exact(vector_parameters.count)?;
+            Ok(QuantizedVectorStorage::ScalarU4Ram(
+                EncodedVectorsU4::encode(
+                    vectors,
+                    storage_builder,
+                    vector_parameters,
+                    scalar_config.quantile,
+                    || stopped.load(Ordering::Relaxed),
+                )?,
+            ))
+        } else {
+            let mmap_data_path = path.join(QUANTIZED_DATA_PATH);
+            let storage_builder = QuantizedMmapStorageBuilder::new(
+                mmap_data_path.as_path(),
+                vector_parameters.count,
+                quantized_vector_size,
+            )?;
+            Ok(QuantizedVectorStorage::ScalarU4Mmap(
+                EncodedVectorsU4::encode(
+                    vectors,
+                    storage_builder,
+                    vector_parameters,
+                    scalar_config.quantile,
+                    || stopped.load(Ordering::Relaxed),
+                )?,
+            ))
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn create_scalar_u4_multi<'a>(
+        vectors: impl Iterator<Item = impl AsRef<[VectorElementType]> + 'a> + Clone,
+        offsets: impl Iterator<Item = MultivectorOffset>,
+        vector_parameters: &quantization::VectorParameters,
+        scalar_config: &ScalarQuantizationConfig,
+        multi_vector_config: MultiVectorConfig,
+        path: &Path,
+        on_disk_vector_storage: bool,
+        stopped: &AtomicBool,
+    ) -> OperationResult<QuantizedVectorStorage> {
+        let quantized_vector_size =
+            EncodedVectorsU4::<QuantizedMmapStorage>::get_quantized_vector_size(vector_parameters);
+        let in_ram = Self::is_ram(scalar_config.always_ram, on_disk_vector_storage);
+        if in_ram {
+            let mut storage_builder = ChunkedVectors::<u8>::new(quantized_vector_size);
+            storage_builder.try_set_capacity_exact(vector_parameters.count)?;
+            let quantized_storage = EncodedVectorsU4::encode(
+                vectors,
+                storage_builder,
+                vector_parameters,
+                scalar_config.quantile,
+                || stopped.load(Ordering::Relaxed),
+            )?;
+            Ok(QuantizedVectorStorage::ScalarU4RamMulti(
+                QuantizedMultivectorStorage::new(
+                    vector_parameters.dim,
+                    quantized_storage,
+                    offsets.collect(),
+                    multi_vector_config,
+                ),
+            ))
+        } else {
+            let mmap_data_path = path.join(QUANTIZED_DATA_PATH);
+            let storage_builder = QuantizedMmapStorageBuilder::new(
+                mmap_data_path.as_path(),
+                vector_parameters.count,
+                quantized_vector_size,
+            )?;
+            let quantized_storage = EncodedVectorsU4::encode(
+                vectors,
+                storage_builder,
+                vector_parameters,
+                scalar_config.quantile,
+                || stopped.load(Ordering::Relaxed),
+            )?;
+            let offsets_path = path.join(QUANTIZED_OFFSETS_PATH);
+            create_offsets_file_from_iter(&offsets_path, vector_parameters.count, offsets)?;
+            Ok(QuantizedVectorStorage::ScalarU4MmapMulti(
+                QuantizedMultivectorStorage::new(
+                    vector_parameters.dim,
+                    quantized_storage,
+                    MultivectorOffsetsStorage::load(&offsets_path)?,
+                    multi_vector_config,
+                ),
+            ))
+        }
+    }
+
     fn create_pq<'a>(
         vectors: impl Iterator<Item = impl AsRef<[VectorElementType]> + 'a> + Clone + Send,
         vector_parameters: &quantization::VectorParameters,
@@ -789,13 +1126,17 @@ impl QuantizedVectors {
                 vector_parameters,
             );
         let in_ram = Self::is_ram(binary_config.always_ram, on_disk_vector_storage);
+        let query_encoding = binary_config.query_encoding.unwrap_or_default();
         if in_ram {
             let mut storage_builder = ChunkedVectors::<u8>::new(quantized_vector_size);
             storage_builder.try_set_capacity_exact(vector_parameters.count)?;
             Ok(QuantizedVectorStorage::BinaryRam(
-                EncodedVectorsBin::encode(vectors, storage_builder, vector_parameters, || {
-                    stopped.load(Ordering::Relaxed)
-                })?,
+                AsymmetricBinaryVectors::new(
+                    EncodedVectorsBin::encode(vectors, storage_builder, vector_parameters, || {
+                        stopped.load(Ordering::Relaxed)
+                    })?,
+                    query_encoding,
+                ),
             ))
         } else {
             let mmap_data_path = path.join(QUANTIZED_DATA_PATH);
@@ -805,9 +1146,12 @@ impl QuantizedVectors {
                 quantized_vector_size,
             )?;
             Ok(QuantizedVectorStorage::BinaryMmap(
-                EncodedVectorsBin::encode(vectors, storage_builder, vector_parameters, || {
-                    stopped.load(Ordering::Relaxed)
-                })?,
+                AsymmetricBinaryVectors::new(
+                    EncodedVectorsBin::encode(vectors, storage_builder, vector_parameters, || {
+                        stopped.load(Ordering::Relaxed)
+                    })?,
+                    query_encoding,
+                ),
             ))
         }
     }
@@ -828,13 +1172,16 @@ impl QuantizedVectors {
                 vector_parameters,
             );
         let in_ram = Self::is_ram(binary_config.always_ram, on_disk_vector_storage);
+        let query_encoding = binary_config.query_encoding.unwrap_or_default();
         if in_ram {
             let mut storage_builder = ChunkedVectors::<u8>::new(quantized_vector_size);
             storage_builder.try_set_capacity_exact(vector_parameters.count)?;
-            let quantized_storage =
+            let quantized_storage = AsymmetricBinaryVectors::new(
                 EncodedVectorsBin::encode(vectors, storage_builder, vector_parameters, || {
                     stopped.load(Ordering::Relaxed)
-                })?;
+                })?,
+                query_encoding,
+            );
             Ok(QuantizedVectorStorage::BinaryRamMulti(
                 QuantizedMultivectorStorage::new(
                     vector_parameters.dim,
@@ -850,10 +1197,12 @@ impl QuantizedVectors {
                 vector_parameters.count,
                 quantized_vector_size,
             )?;
-            let quantized_storage =
+            let quantized_storage = AsymmetricBinaryVectors::new(
                 EncodedVectorsBin::encode(vectors, storage_builder, vector_parameters, || {
                     stopped.load(Ordering::Relaxed)
-                })?;
+                })?,
+                query_encoding,
+            );
             let offsets_path = path.join(QUANTIZED_OFFSETS_PATH);
             create_offsets_file_from_iter(&offsets_path, vector_parameters.count, offsets)?;
             Ok(QuantizedVectorStorage::BinaryMmapMulti(
@@ -875,18 +1224,24 @@ impl QuantizedVectors {
         distance: Distance,
         dim: usize,
         count: usize,
-    ) -> quantization::VectorParameters {
-        quantization::VectorParameters {
+    ) -> OperationResult<quantization::VectorParameters> {
+        let distance_type = match distance {
+            Distance::Cosine => quantization::DistanceType::Dot,
+            Distance::Euclid => quantization::DistanceType::L2,
+            Distance::Dot => quantization::DistanceType::Dot,
+            Distance::Manhattan => quantization::DistanceType::L1,
+            Distance::Hamming | Distance::Jaccard => {
+                return Err(OperationError::ValidationError {
+                    description: format!("Quantization is not supported for {distance:?} distance"),
+                });
+            }
+        };
+        Ok(quantization::VectorParameters {
             dim,
             count,
-            distance_type: match distance {
-                Distance::Cosine => quantization::DistanceType::Dot,
-                Distance::Euclid => quantization::DistanceType::L2,
-                Distance::Dot => quantization::DistanceType::Dot,
-                Distance::Manhattan => quantization::DistanceType::L1,
-            },
+            distance_type,
             invert: distance == Distance::Euclid || distance == Distance::Manhattan,
-        }
+        })
     }
 
     fn get_bucket_size(compression: CompressionRatio) -> usize {
@@ -9,7 +9,9 @@ pub mod custom_query_scorer;
 pub mod metric_query_scorer;
 pub mod multi_custom_query_scorer;
 pub mod multi_metric_query_scorer;
+pub mod prefix_query_scorer;
 pub mod sparse_custom_query_scorer;
+pub mod token_query_scorer;
 
 pub trait QueryScorer<TVector: ?Sized> {
     fn score_stored(&self, idx: PointOffsetType) -> ScoreType;
@@ -0,0 +1,101 @@
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+use common::types::{PointOffsetType, ScoreType};
+
+use crate::data_types::primitive::PrimitiveVectorElement;
+use crate::data_types::vectors::VectorElementType;
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::CosineMetric;
+use crate::types::{Distance, VectorStorageDatatype};
+use crate::vector_storage::query_scorer::QueryScorer;
+use crate::vector_storage::{DenseVectorStorage, VectorStorage};
+
+/// Query scorer, which only considers the first `dim` dimensions of stored dense vectors
+///
+/// Wraps another dense query scorer, which must be created with queries of `dim` dimensions.
+///
+/// Cosine similarity of float vectors is a dot product of vectors normalized on insertion.
+/// Prefixes of normalized vectors are not normalized, so they are normalized again before scoring.
+pub struct PrefixQueryScorer<
+    'a,
+    TElement: PrimitiveVectorElement,
+    TMetric: Metric<TElement>,
+    TVectorStorage: DenseVectorStorage<TElement>,
+    TQueryScorer: QueryScorer<[TElement]>,
+> {
+    query_scorer: TQueryScorer,
+    vector_storage: &'a TVectorStorage,
+    dim: usize,
+    normalize: bool,
+    metric: PhantomData<TMetric>,
+    _element: PhantomData<TElement>,
+}
+
+impl<
+        'a,
+        TElement: PrimitiveVectorElement,
+        TMetric: Metric<TElement>,
+        TVectorStorage: DenseVectorStorage<TElement>,
+        TQueryScorer: QueryScorer<[TElement]>,
+    > PrefixQueryScorer<'a, TElement, TMetric, TVectorStorage, TQueryScorer>
+{
+    pub fn new(query_scorer: TQueryScorer, vector_storage: &'a TVectorStorage, dim: usize) -> Self {
+        // Cosine similarity of integer vectors is computed without normalization
+        let normalize = vector_storage.distance() == Distance::Cosine
+            && matches!(
+                TElement::datatype(),
+                VectorStorageDatatype::Float32
+                    | VectorStorageDatatype::Float16
+                    | VectorStorageDatatype::BFloat16
+            );
+
+        Self {
+            query_scorer,
+            vector_storage,
+            dim,
+            normalize,
+            metric: PhantomData,
+            _element: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn prefix<'b>(&self, vector: &'b [TElement]) -> Cow<'b, [TElement]> {
+        let prefix = &vector[..self.dim.min(vector.len())];
+        if !self.normalize {
+            return Cow::Borrowed(prefix);
+        }
+
+        let prefix = TElement::slice_to_float_cow(Cow::Borrowed(prefix)).into_owned();
+        let normalized = <CosineMetric as Metric<VectorElementType>>::preprocess(prefix);
+        TElement::slice_from_float_cow(Cow::Owned(normalized))
+    }
+}
+
+impl<
+        'a,
+        TElement: PrimitiveVectorElement,
+        TMetric: Metric<TElement>,
+        TVectorStorage: DenseVectorStorage<TElement>,
+        TQueryScorer: QueryScorer<[TElement]>,
+    > QueryScorer<[TElement]>
+    for PrefixQueryScorer<'a, TElement, TMetric, TVectorStorage, TQueryScorer>
+{
+    #[inline]
+    fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
+        let stored = self.vector_storage.get_dense(idx);
+        self.query_scorer.score(&self.prefix(stored))
+    }
+
+    #[inline]
+    fn score(&self, v2: &[TElement]) -> ScoreType {
+        self.query_scorer.score(&self.prefix(v2))
+    }
+
+    fn score_internal(&self, point_a: PointOffsetType, point_b: PointOffsetType) -> ScoreType {
+        let v1 = self.vector_storage.get_dense(point_a);
+        let v2 = self.vector_storage.get_dense(point_b);
+        TMetric::similarity(&self.prefix(v1), &self.prefix(v2))
+    }
+}
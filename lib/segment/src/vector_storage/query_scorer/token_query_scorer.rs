@@ -0,0 +1,81 @@
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+use common::types::{PointOffsetType, ScoreType};
+
+use crate::data_types::primitive::PrimitiveVectorElement;
+use crate::data_types::vectors::{DenseVector, TypedDenseVector};
+use crate::spaces::metric::Metric;
+use crate::vector_storage::multi_dense::token_mapping::MultiVectorToken;
+use crate::vector_storage::query_scorer::QueryScorer;
+use crate::vector_storage::MultiVectorStorage;
+
+/// Query scorer, which compares a single dense query with individual sub-vectors of a multivector
+/// storage
+///
+/// Stored vectors are addressed by token ids, see [`MultiVectorToken`].
+pub struct TokenQueryScorer<
+    'a,
+    TElement: PrimitiveVectorElement,
+    TMetric: Metric<TElement>,
+    TVectorStorage: MultiVectorStorage<TElement>,
+> {
+    vector_storage: &'a TVectorStorage,
+    tokens: &'a [MultiVectorToken],
+    query: TypedDenseVector<TElement>,
+    metric: PhantomData<TMetric>,
+}
+
+impl<
+        'a,
+        TElement: PrimitiveVectorElement,
+        TMetric: Metric<TElement>,
+        TVectorStorage: MultiVectorStorage<TElement>,
+    > TokenQueryScorer<'a, TElement, TMetric, TVectorStorage>
+{
+    pub fn new(
+        query: DenseVector,
+        vector_storage: &'a TVectorStorage,
+        tokens: &'a [MultiVectorToken],
+    ) -> Self {
+        let preprocessed_vector = TMetric::preprocess(query);
+        Self {
+            query: TypedDenseVector::from(TElement::slice_from_float_cow(Cow::from(
+                preprocessed_vector,
+            ))),
+            vector_storage,
+            tokens,
+            metric: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn token_vector(&self, token_id: PointOffsetType) -> &'a [TElement] {
+        let token = self.tokens[token_id as usize];
+        let multi_vector = self.vector_storage.get_multi(token.point_id);
+        let start = token.inner_id as usize * multi_vector.dim;
+        &multi_vector.flattened_vectors[start..start + multi_vector.dim]
+    }
+}
+
+impl<
+        'a,
+        TElement: PrimitiveVectorElement,
+        TMetric: Metric<TElement>,
+        TVectorStorage: MultiVectorStorage<TElement>,
+    > QueryScorer<[TElement]> for TokenQueryScorer<'a, TElement, TMetric, TVectorStorage>
+{
+    #[inline]
+    fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
+        TMetric::similarity(&self.query, self.token_vector(idx))
+    }
+
+    #[inline]
+    fn score(&self, v2: &[TElement]) -> ScoreType {
+        TMetric::similarity(&self.query, v2)
+    }
+
+    fn score_internal(&self, point_a: PointOffsetType, point_b: PointOffsetType) -> ScoreType {
+        TMetric::similarity(self.token_vector(point_a), self.token_vector(point_b))
+    }
+}
@@ -7,15 +7,19 @@ use sparse::common::sparse_vector::SparseVector;
 use super::query::{ContextQuery, DiscoveryQuery, RecoQuery, TransformInto};
 use super::query_scorer::custom_query_scorer::CustomQueryScorer;
 use super::query_scorer::multi_custom_query_scorer::MultiCustomQueryScorer;
+use super::query_scorer::prefix_query_scorer::PrefixQueryScorer;
 use super::query_scorer::sparse_custom_query_scorer::SparseCustomQueryScorer;
 use super::{DenseVectorStorage, MultiVectorStorage, SparseVectorStorage, VectorStorageEnum};
 use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{
-    DenseVector, MultiDenseVectorInternal, QueryVector, VectorElementType, VectorElementTypeByte,
-    VectorElementTypeHalf,
+    DenseVector, MultiDenseVectorInternal, QueryVector, VectorElementType,
+    VectorElementTypeBFloat16, VectorElementTypeByte, VectorElementTypeHalf, VectorElementTypeInt8,
 };
 use crate::spaces::metric::Metric;
-use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+};
 use crate::spaces::tools::peek_top_largest_iterable;
 use crate::types::Distance;
 use crate::vector_storage::query_scorer::metric_query_scorer::MetricQueryScorer;
@@ -117,6 +121,12 @@ pub fn new_stoppable_raw_scorer<'a>(
         VectorStorageEnum::DenseSimpleHalf(vs) => {
             raw_scorer_half_impl(query, vs, point_deleted, is_stopped)
         }
+        VectorStorageEnum::DenseSimpleBFloat16(vs) => {
+            raw_scorer_bf16_impl(query, vs, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::DenseSimpleInt8(vs) => {
+            raw_scorer_int8_impl(query, vs, point_deleted, is_stopped)
+        }
 
         VectorStorageEnum::DenseMemmap(vs) => {
             if vs.has_async_reader() {
@@ -144,6 +154,12 @@ pub fn new_stoppable_raw_scorer<'a>(
         VectorStorageEnum::DenseMemmapHalf(vs) => {
             raw_scorer_half_impl(query, vs.as_ref(), point_deleted, is_stopped)
         }
+        VectorStorageEnum::DenseMemmapBFloat16(vs) => {
+            raw_scorer_bf16_impl(query, vs.as_ref(), point_deleted, is_stopped)
+        }
+        VectorStorageEnum::DenseMemmapInt8(vs) => {
+            raw_scorer_int8_impl(query, vs.as_ref(), point_deleted, is_stopped)
+        }
 
         VectorStorageEnum::DenseAppendableMemmap(vs) => {
             raw_scorer_impl(query, vs.as_ref(), point_deleted, is_stopped)
@@ -154,9 +170,18 @@ pub fn new_stoppable_raw_scorer<'a>(
         VectorStorageEnum::DenseAppendableMemmapHalf(vs) => {
             raw_scorer_half_impl(query, vs.as_ref(), point_deleted, is_stopped)
         }
+        VectorStorageEnum::DenseAppendableMemmapBFloat16(vs) => {
+            raw_scorer_bf16_impl(query, vs.as_ref(), point_deleted, is_stopped)
+        }
+        VectorStorageEnum::DenseAppendableMemmapInt8(vs) => {
+            raw_scorer_int8_impl(query, vs.as_ref(), point_deleted, is_stopped)
+        }
         VectorStorageEnum::SparseSimple(vs) => {
             raw_sparse_scorer_impl(query, vs, point_deleted, is_stopped)
         }
+        VectorStorageEnum::SparseMmap(vs) => {
+            raw_sparse_scorer_impl(query, vs, point_deleted, is_stopped)
+        }
         VectorStorageEnum::MultiDenseSimple(vs) => {
             raw_multi_scorer_impl(query, vs, point_deleted, is_stopped)
         }
@@ -166,6 +191,12 @@ pub fn new_stoppable_raw_scorer<'a>(
         VectorStorageEnum::MultiDenseSimpleHalf(vs) => {
             raw_multi_scorer_half_impl(query, vs, point_deleted, is_stopped)
         }
+        VectorStorageEnum::MultiDenseSimpleBFloat16(vs) => {
+            raw_multi_scorer_bf16_impl(query, vs, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::MultiDenseSimpleInt8(vs) => {
+            raw_multi_scorer_int8_impl(query, vs, point_deleted, is_stopped)
+        }
         VectorStorageEnum::MultiDenseAppendableMemmap(vs) => {
             raw_multi_scorer_impl(query, vs.as_ref(), point_deleted, is_stopped)
         }
@@ -175,6 +206,46 @@ pub fn new_stoppable_raw_scorer<'a>(
         VectorStorageEnum::MultiDenseAppendableMemmapHalf(vs) => {
             raw_multi_scorer_half_impl(query, vs.as_ref(), point_deleted, is_stopped)
         }
+        VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(vs) => {
+            raw_multi_scorer_bf16_impl(query, vs.as_ref(), point_deleted, is_stopped)
+        }
+        VectorStorageEnum::MultiDenseAppendableMemmapInt8(vs) => {
+            raw_multi_scorer_int8_impl(query, vs.as_ref(), point_deleted, is_stopped)
+        }
+        VectorStorageEnum::MultiDenseMemmap(vs) => {
+            if vs.has_async_reader() {
+                #[cfg(target_os = "linux")]
+                {
+                    let scorer_result = super::async_raw_scorer::new_multi(
+                        query.clone(),
+                        vs,
+                        point_deleted,
+                        is_stopped,
+                    );
+                    match scorer_result {
+                        Ok(raw_scorer) => return Ok(raw_scorer),
+                        Err(err) => log::error!("failed to initialize async raw scorer: {err}"),
+                    };
+                }
+
+                #[cfg(not(target_os = "linux"))]
+                log::warn!("async raw scorer is only supported on Linux");
+            }
+
+            raw_multi_scorer_impl(query, vs.as_ref(), point_deleted, is_stopped)
+        }
+        VectorStorageEnum::MultiDenseMemmapByte(vs) => {
+            raw_multi_scorer_byte_impl(query, vs.as_ref(), point_deleted, is_stopped)
+        }
+        VectorStorageEnum::MultiDenseMemmapHalf(vs) => {
+            raw_multi_scorer_half_impl(query, vs.as_ref(), point_deleted, is_stopped)
+        }
+        VectorStorageEnum::MultiDenseMemmapBFloat16(vs) => {
+            raw_multi_scorer_bf16_impl(query, vs.as_ref(), point_deleted, is_stopped)
+        }
+        VectorStorageEnum::MultiDenseMemmapInt8(vs) => {
+            raw_multi_scorer_int8_impl(query, vs.as_ref(), point_deleted, is_stopped)
+        }
     }
 }
 
@@ -229,6 +300,249 @@ pub fn new_raw_scorer<'a>(
     new_stoppable_raw_scorer(vector, vector_storage, point_deleted, &DEFAULT_STOPPED)
 }
 
+/// Create a raw scorer, which only considers the first `prefix_dim` dimensions of dense vectors
+///
+/// Falls back to [`new_stoppable_raw_scorer`] if no prefix is given.
+pub fn new_stoppable_raw_scorer_with_prefix<'a>(
+    query: QueryVector,
+    vector_storage: &'a VectorStorageEnum,
+    prefix_dim: Option<usize>,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    let Some(dim) = prefix_dim else {
+        return new_stoppable_raw_scorer(query, vector_storage, point_deleted, is_stopped);
+    };
+
+    match vector_storage {
+        VectorStorageEnum::DenseSimple(vs) => {
+            raw_prefix_scorer_impl(query, vs, dim, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::DenseSimpleByte(vs) => {
+            raw_prefix_scorer_impl(query, vs, dim, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::DenseSimpleHalf(vs) => {
+            raw_prefix_scorer_impl(query, vs, dim, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::DenseSimpleBFloat16(vs) => {
+            raw_prefix_scorer_impl(query, vs, dim, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::DenseSimpleInt8(vs) => {
+            raw_prefix_scorer_impl(query, vs, dim, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::DenseMemmap(vs) => {
+            raw_prefix_scorer_impl(query, vs.as_ref(), dim, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::DenseMemmapByte(vs) => {
+            raw_prefix_scorer_impl(query, vs.as_ref(), dim, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::DenseMemmapHalf(vs) => {
+            raw_prefix_scorer_impl(query, vs.as_ref(), dim, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::DenseMemmapBFloat16(vs) => {
+            raw_prefix_scorer_impl(query, vs.as_ref(), dim, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::DenseMemmapInt8(vs) => {
+            raw_prefix_scorer_impl(query, vs.as_ref(), dim, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::DenseAppendableMemmap(vs) => {
+            raw_prefix_scorer_impl(query, vs.as_ref(), dim, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::DenseAppendableMemmapByte(vs) => {
+            raw_prefix_scorer_impl(query, vs.as_ref(), dim, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::DenseAppendableMemmapHalf(vs) => {
+            raw_prefix_scorer_impl(query, vs.as_ref(), dim, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::DenseAppendableMemmapBFloat16(vs) => {
+            raw_prefix_scorer_impl(query, vs.as_ref(), dim, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::DenseAppendableMemmapInt8(vs) => {
+            raw_prefix_scorer_impl(query, vs.as_ref(), dim, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::SparseSimple(_)
+        | VectorStorageEnum::SparseMmap(_)
+        | VectorStorageEnum::MultiDenseSimple(_)
+        | VectorStorageEnum::MultiDenseSimpleByte(_)
+        | VectorStorageEnum::MultiDenseSimpleHalf(_)
+        | VectorStorageEnum::MultiDenseSimpleBFloat16(_)
+        | VectorStorageEnum::MultiDenseSimpleInt8(_)
+        | VectorStorageEnum::MultiDenseAppendableMemmap(_)
+        | VectorStorageEnum::MultiDenseAppendableMemmapByte(_)
+        | VectorStorageEnum::MultiDenseAppendableMemmapHalf(_)
+        | VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(_)
+        | VectorStorageEnum::MultiDenseAppendableMemmapInt8(_)
+        | VectorStorageEnum::MultiDenseMemmap(_)
+        | VectorStorageEnum::MultiDenseMemmapByte(_)
+        | VectorStorageEnum::MultiDenseMemmapHalf(_)
+        | VectorStorageEnum::MultiDenseMemmapBFloat16(_)
+        | VectorStorageEnum::MultiDenseMemmapInt8(_) => Err(OperationError::service_error(
+            "Prefix scoring is only supported for dense vectors",
+        )),
+    }
+}
+
+pub fn new_raw_scorer_with_prefix<'a>(
+    vector: QueryVector,
+    vector_storage: &'a VectorStorageEnum,
+    prefix_dim: Option<usize>,
+    point_deleted: &'a BitSlice,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    new_stoppable_raw_scorer_with_prefix(
+        vector,
+        vector_storage,
+        prefix_dim,
+        point_deleted,
+        &DEFAULT_STOPPED,
+    )
+}
+
+fn raw_prefix_scorer_impl<'a, TElement, TVectorStorage>(
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    dim: usize,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>>
+where
+    TElement: PrimitiveVectorElement,
+    TVectorStorage: DenseVectorStorage<TElement>,
+    CosineMetric: Metric<TElement>,
+    EuclidMetric: Metric<TElement>,
+    DotProductMetric: Metric<TElement>,
+    ManhattanMetric: Metric<TElement>,
+    HammingMetric: Metric<TElement>,
+    JaccardMetric: Metric<TElement>,
+{
+    match vector_storage.distance() {
+        Distance::Cosine => new_prefix_scorer_with_metric::<_, CosineMetric, _>(
+            query,
+            vector_storage,
+            dim,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Euclid => new_prefix_scorer_with_metric::<_, EuclidMetric, _>(
+            query,
+            vector_storage,
+            dim,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Dot => new_prefix_scorer_with_metric::<_, DotProductMetric, _>(
+            query,
+            vector_storage,
+            dim,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Manhattan => new_prefix_scorer_with_metric::<_, ManhattanMetric, _>(
+            query,
+            vector_storage,
+            dim,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Hamming => new_prefix_scorer_with_metric::<_, HammingMetric, _>(
+            query,
+            vector_storage,
+            dim,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Jaccard => new_prefix_scorer_with_metric::<_, JaccardMetric, _>(
+            query,
+            vector_storage,
+            dim,
+            point_deleted,
+            is_stopped,
+        ),
+    }
+}
+
+fn new_prefix_scorer_with_metric<
+    'a,
+    TElement: PrimitiveVectorElement,
+    TMetric: Metric<TElement> + 'a,
+    TVectorStorage: DenseVectorStorage<TElement>,
+>(
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    dim: usize,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    let vec_deleted = vector_storage.deleted_vector_bitslice();
+    // Queries may come with full dimensionality, e.g. stored vectors during index building
+    let truncate = |mut vector: DenseVector| -> OperationResult<DenseVector> {
+        vector.truncate(dim);
+        Ok(vector)
+    };
+    match query {
+        QueryVector::Nearest(vector) => raw_scorer_from_query_scorer(
+            PrefixQueryScorer::<_, TMetric, _, _>::new(
+                MetricQueryScorer::<TElement, TMetric, _>::new(
+                    truncate(vector.try_into()?)?,
+                    vector_storage,
+                ),
+                vector_storage,
+                dim,
+            ),
+            point_deleted,
+            vec_deleted,
+            is_stopped,
+        ),
+        QueryVector::Recommend(reco_query) => {
+            let reco_query: RecoQuery<DenseVector> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                PrefixQueryScorer::<_, TMetric, _, _>::new(
+                    CustomQueryScorer::<TElement, TMetric, _, _, _>::new(
+                        reco_query.transform(truncate)?,
+                        vector_storage,
+                    ),
+                    vector_storage,
+                    dim,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Discovery(discovery_query) => {
+            let discovery_query: DiscoveryQuery<DenseVector> = discovery_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                PrefixQueryScorer::<_, TMetric, _, _>::new(
+                    CustomQueryScorer::<TElement, TMetric, _, _, _>::new(
+                        discovery_query.transform(truncate)?,
+                        vector_storage,
+                    ),
+                    vector_storage,
+                    dim,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Context(context_query) => {
+            let context_query: ContextQuery<DenseVector> = context_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                PrefixQueryScorer::<_, TMetric, _, _>::new(
+                    CustomQueryScorer::<TElement, TMetric, _, _, _>::new(
+                        context_query.transform(truncate)?,
+                        vector_storage,
+                    ),
+                    vector_storage,
+                    dim,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+    }
+}
+
 pub fn raw_scorer_impl<'a, TVectorStorage: DenseVectorStorage<VectorElementType>>(
     query: QueryVector,
     vector_storage: &'a TVectorStorage,
@@ -260,6 +574,18 @@ pub fn raw_scorer_impl<'a, TVectorStorage: DenseVectorStorage<VectorElementType>
             point_deleted,
             is_stopped,
         ),
+        Distance::Hamming => new_scorer_with_metric::<HammingMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Jaccard => new_scorer_with_metric::<JaccardMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
     }
 }
 
@@ -354,6 +680,18 @@ pub fn raw_scorer_byte_impl<'a, TVectorStorage: DenseVectorStorage<VectorElement
             point_deleted,
             is_stopped,
         ),
+        Distance::Hamming => new_scorer_byte_with_metric::<HammingMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Jaccard => new_scorer_byte_with_metric::<JaccardMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
     }
 }
 
@@ -448,6 +786,18 @@ pub fn raw_scorer_half_impl<'a, TVectorStorage: DenseVectorStorage<VectorElement
             point_deleted,
             is_stopped,
         ),
+        Distance::Hamming => new_scorer_half_with_metric::<HammingMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Jaccard => new_scorer_half_with_metric::<JaccardMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
     }
 }
 
@@ -511,51 +861,44 @@ fn new_scorer_half_with_metric<
     }
 }
 
-pub fn raw_scorer_from_query_scorer<'a, TVector, TQueryScorer>(
-    query_scorer: TQueryScorer,
-    point_deleted: &'a BitSlice,
-    vec_deleted: &'a BitSlice,
-    is_stopped: &'a AtomicBool,
-) -> OperationResult<Box<dyn RawScorer + 'a>>
-where
-    TVector: ?Sized + 'a,
-    TQueryScorer: QueryScorer<TVector> + 'a,
-{
-    Ok(Box::new(RawScorerImpl::<TVector, TQueryScorer> {
-        query_scorer,
-        point_deleted,
-        vec_deleted,
-        is_stopped,
-        vector: std::marker::PhantomData,
-    }))
-}
-
-pub fn raw_multi_scorer_impl<'a, TVectorStorage: MultiVectorStorage<VectorElementType>>(
+pub fn raw_scorer_bf16_impl<'a, TVectorStorage: DenseVectorStorage<VectorElementTypeBFloat16>>(
     query: QueryVector,
     vector_storage: &'a TVectorStorage,
     point_deleted: &'a BitSlice,
     is_stopped: &'a AtomicBool,
 ) -> OperationResult<Box<dyn RawScorer + 'a>> {
     match vector_storage.distance() {
-        Distance::Cosine => new_multi_scorer_with_metric::<CosineMetric, _>(
+        Distance::Cosine => new_scorer_bf16_with_metric::<CosineMetric, _>(
             query,
             vector_storage,
             point_deleted,
             is_stopped,
         ),
-        Distance::Euclid => new_multi_scorer_with_metric::<EuclidMetric, _>(
+        Distance::Euclid => new_scorer_bf16_with_metric::<EuclidMetric, _>(
             query,
             vector_storage,
             point_deleted,
             is_stopped,
         ),
-        Distance::Dot => new_multi_scorer_with_metric::<DotProductMetric, _>(
+        Distance::Dot => new_scorer_bf16_with_metric::<DotProductMetric, _>(
             query,
             vector_storage,
             point_deleted,
             is_stopped,
         ),
-        Distance::Manhattan => new_multi_scorer_with_metric::<ManhattanMetric, _>(
+        Distance::Manhattan => new_scorer_bf16_with_metric::<ManhattanMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Hamming => new_scorer_bf16_with_metric::<HammingMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Jaccard => new_scorer_bf16_with_metric::<JaccardMetric, _>(
             query,
             vector_storage,
             point_deleted,
@@ -564,10 +907,10 @@ pub fn raw_multi_scorer_impl<'a, TVectorStorage: MultiVectorStorage<VectorElemen
     }
 }
 
-fn new_multi_scorer_with_metric<
+fn new_scorer_bf16_with_metric<
     'a,
-    TMetric: Metric<VectorElementType> + 'a,
-    TVectorStorage: MultiVectorStorage<VectorElementType>,
+    TMetric: Metric<VectorElementTypeBFloat16> + 'a,
+    TVectorStorage: DenseVectorStorage<VectorElementTypeBFloat16>,
 >(
     query: QueryVector,
     vector_storage: &'a TVectorStorage,
@@ -577,7 +920,7 @@ fn new_multi_scorer_with_metric<
     let vec_deleted = vector_storage.deleted_vector_bitslice();
     match query {
         QueryVector::Nearest(vector) => raw_scorer_from_query_scorer(
-            MultiMetricQueryScorer::<VectorElementType, TMetric, _>::new(
+            MetricQueryScorer::<VectorElementTypeBFloat16, TMetric, _>::new(
                 vector.try_into()?,
                 vector_storage,
             ),
@@ -586,9 +929,9 @@ fn new_multi_scorer_with_metric<
             is_stopped,
         ),
         QueryVector::Recommend(reco_query) => {
-            let reco_query: RecoQuery<MultiDenseVectorInternal> = reco_query.transform_into()?;
+            let reco_query: RecoQuery<DenseVector> = reco_query.transform_into()?;
             raw_scorer_from_query_scorer(
-                MultiCustomQueryScorer::<VectorElementType, TMetric, _, _, _>::new(
+                CustomQueryScorer::<VectorElementTypeBFloat16, TMetric, _, _, _>::new(
                     reco_query,
                     vector_storage,
                 ),
@@ -598,10 +941,9 @@ fn new_multi_scorer_with_metric<
             )
         }
         QueryVector::Discovery(discovery_query) => {
-            let discovery_query: DiscoveryQuery<MultiDenseVectorInternal> =
-                discovery_query.transform_into()?;
+            let discovery_query: DiscoveryQuery<DenseVector> = discovery_query.transform_into()?;
             raw_scorer_from_query_scorer(
-                MultiCustomQueryScorer::<VectorElementType, TMetric, _, _, _>::new(
+                CustomQueryScorer::<VectorElementTypeBFloat16, TMetric, _, _, _>::new(
                     discovery_query,
                     vector_storage,
                 ),
@@ -611,10 +953,9 @@ fn new_multi_scorer_with_metric<
             )
         }
         QueryVector::Context(context_query) => {
-            let context_query: ContextQuery<MultiDenseVectorInternal> =
-                context_query.transform_into()?;
+            let context_query: ContextQuery<DenseVector> = context_query.transform_into()?;
             raw_scorer_from_query_scorer(
-                MultiCustomQueryScorer::<VectorElementType, TMetric, _, _, _>::new(
+                CustomQueryScorer::<VectorElementTypeBFloat16, TMetric, _, _, _>::new(
                     context_query,
                     vector_storage,
                 ),
@@ -626,41 +967,286 @@ fn new_multi_scorer_with_metric<
     }
 }
 
-pub fn raw_multi_scorer_byte_impl<'a, TVectorStorage: MultiVectorStorage<VectorElementTypeByte>>(
+pub fn raw_scorer_int8_impl<'a, TVectorStorage: DenseVectorStorage<VectorElementTypeInt8>>(
     query: QueryVector,
     vector_storage: &'a TVectorStorage,
     point_deleted: &'a BitSlice,
     is_stopped: &'a AtomicBool,
 ) -> OperationResult<Box<dyn RawScorer + 'a>> {
     match vector_storage.distance() {
-        Distance::Cosine => new_multi_scorer_byte_with_metric::<CosineMetric, _>(
+        Distance::Cosine => new_scorer_int8_with_metric::<CosineMetric, _>(
             query,
             vector_storage,
             point_deleted,
             is_stopped,
         ),
-        Distance::Euclid => new_multi_scorer_byte_with_metric::<EuclidMetric, _>(
+        Distance::Euclid => new_scorer_int8_with_metric::<EuclidMetric, _>(
             query,
             vector_storage,
             point_deleted,
             is_stopped,
         ),
-        Distance::Dot => new_multi_scorer_byte_with_metric::<DotProductMetric, _>(
+        Distance::Dot => new_scorer_int8_with_metric::<DotProductMetric, _>(
             query,
             vector_storage,
             point_deleted,
             is_stopped,
         ),
-        Distance::Manhattan => new_multi_scorer_byte_with_metric::<ManhattanMetric, _>(
+        Distance::Manhattan => new_scorer_int8_with_metric::<ManhattanMetric, _>(
             query,
             vector_storage,
             point_deleted,
             is_stopped,
         ),
-    }
-}
-
-fn new_multi_scorer_byte_with_metric<
+        Distance::Hamming => new_scorer_int8_with_metric::<HammingMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Jaccard => new_scorer_int8_with_metric::<JaccardMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+    }
+}
+
+fn new_scorer_int8_with_metric<
+    'a,
+    TMetric: Metric<VectorElementTypeInt8> + 'a,
+    TVectorStorage: DenseVectorStorage<VectorElementTypeInt8>,
+>(
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    let vec_deleted = vector_storage.deleted_vector_bitslice();
+    match query {
+        QueryVector::Nearest(vector) => raw_scorer_from_query_scorer(
+            MetricQueryScorer::<VectorElementTypeInt8, TMetric, _>::new(
+                vector.try_into()?,
+                vector_storage,
+            ),
+            point_deleted,
+            vec_deleted,
+            is_stopped,
+        ),
+        QueryVector::Recommend(reco_query) => {
+            let reco_query: RecoQuery<DenseVector> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                CustomQueryScorer::<VectorElementTypeInt8, TMetric, _, _, _>::new(
+                    reco_query,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Discovery(discovery_query) => {
+            let discovery_query: DiscoveryQuery<DenseVector> = discovery_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                CustomQueryScorer::<VectorElementTypeInt8, TMetric, _, _, _>::new(
+                    discovery_query,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Context(context_query) => {
+            let context_query: ContextQuery<DenseVector> = context_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                CustomQueryScorer::<VectorElementTypeInt8, TMetric, _, _, _>::new(
+                    context_query,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+    }
+}
+
+pub fn raw_scorer_from_query_scorer<'a, TVector, TQueryScorer>(
+    query_scorer: TQueryScorer,
+    point_deleted: &'a BitSlice,
+    vec_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>>
+where
+    TVector: ?Sized + 'a,
+    TQueryScorer: QueryScorer<TVector> + 'a,
+{
+    Ok(Box::new(RawScorerImpl::<TVector, TQueryScorer> {
+        query_scorer,
+        point_deleted,
+        vec_deleted,
+        is_stopped,
+        vector: std::marker::PhantomData,
+    }))
+}
+
+pub fn raw_multi_scorer_impl<'a, TVectorStorage: MultiVectorStorage<VectorElementType>>(
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    match vector_storage.distance() {
+        Distance::Cosine => new_multi_scorer_with_metric::<CosineMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Euclid => new_multi_scorer_with_metric::<EuclidMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Dot => new_multi_scorer_with_metric::<DotProductMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Manhattan => new_multi_scorer_with_metric::<ManhattanMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Hamming => new_multi_scorer_with_metric::<HammingMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Jaccard => new_multi_scorer_with_metric::<JaccardMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+    }
+}
+
+fn new_multi_scorer_with_metric<
+    'a,
+    TMetric: Metric<VectorElementType> + 'a,
+    TVectorStorage: MultiVectorStorage<VectorElementType>,
+>(
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    let vec_deleted = vector_storage.deleted_vector_bitslice();
+    match query {
+        QueryVector::Nearest(vector) => raw_scorer_from_query_scorer(
+            MultiMetricQueryScorer::<VectorElementType, TMetric, _>::new(
+                vector.try_into()?,
+                vector_storage,
+            ),
+            point_deleted,
+            vec_deleted,
+            is_stopped,
+        ),
+        QueryVector::Recommend(reco_query) => {
+            let reco_query: RecoQuery<MultiDenseVectorInternal> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MultiCustomQueryScorer::<VectorElementType, TMetric, _, _, _>::new(
+                    reco_query,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Discovery(discovery_query) => {
+            let discovery_query: DiscoveryQuery<MultiDenseVectorInternal> =
+                discovery_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MultiCustomQueryScorer::<VectorElementType, TMetric, _, _, _>::new(
+                    discovery_query,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Context(context_query) => {
+            let context_query: ContextQuery<MultiDenseVectorInternal> =
+                context_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MultiCustomQueryScorer::<VectorElementType, TMetric, _, _, _>::new(
+                    context_query,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+    }
+}
+
+pub fn raw_multi_scorer_byte_impl<'a, TVectorStorage: MultiVectorStorage<VectorElementTypeByte>>(
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    match vector_storage.distance() {
+        Distance::Cosine => new_multi_scorer_byte_with_metric::<CosineMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Euclid => new_multi_scorer_byte_with_metric::<EuclidMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Dot => new_multi_scorer_byte_with_metric::<DotProductMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Manhattan => new_multi_scorer_byte_with_metric::<ManhattanMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Hamming => new_multi_scorer_byte_with_metric::<HammingMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Jaccard => new_multi_scorer_byte_with_metric::<JaccardMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+    }
+}
+
+fn new_multi_scorer_byte_with_metric<
     'a,
     TMetric: Metric<VectorElementTypeByte> + 'a,
     TVectorStorage: MultiVectorStorage<VectorElementTypeByte>,
@@ -753,6 +1339,18 @@ pub fn raw_multi_scorer_half_impl<'a, TVectorStorage: MultiVectorStorage<VectorE
             point_deleted,
             is_stopped,
         ),
+        Distance::Hamming => new_multi_scorer_half_with_metric::<HammingMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Jaccard => new_multi_scorer_half_with_metric::<JaccardMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
     }
 }
 
@@ -818,6 +1416,225 @@ fn new_multi_scorer_half_with_metric<
     }
 }
 
+pub fn raw_multi_scorer_bf16_impl<
+    'a,
+    TVectorStorage: MultiVectorStorage<VectorElementTypeBFloat16>,
+>(
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    match vector_storage.distance() {
+        Distance::Cosine => new_multi_scorer_bf16_with_metric::<CosineMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Euclid => new_multi_scorer_bf16_with_metric::<EuclidMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Dot => new_multi_scorer_bf16_with_metric::<DotProductMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Manhattan => new_multi_scorer_bf16_with_metric::<ManhattanMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Hamming => new_multi_scorer_bf16_with_metric::<HammingMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Jaccard => new_multi_scorer_bf16_with_metric::<JaccardMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+    }
+}
+
+fn new_multi_scorer_bf16_with_metric<
+    'a,
+    TMetric: Metric<VectorElementTypeBFloat16> + 'a,
+    TVectorStorage: MultiVectorStorage<VectorElementTypeBFloat16>,
+>(
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    let vec_deleted = vector_storage.deleted_vector_bitslice();
+    match query {
+        QueryVector::Nearest(vector) => raw_scorer_from_query_scorer(
+            MultiMetricQueryScorer::<VectorElementTypeBFloat16, TMetric, _>::new(
+                vector.try_into()?,
+                vector_storage,
+            ),
+            point_deleted,
+            vec_deleted,
+            is_stopped,
+        ),
+        QueryVector::Recommend(reco_query) => {
+            let reco_query: RecoQuery<MultiDenseVectorInternal> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MultiCustomQueryScorer::<VectorElementTypeBFloat16, TMetric, _, _, _>::new(
+                    reco_query,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Discovery(discovery_query) => {
+            let discovery_query: DiscoveryQuery<MultiDenseVectorInternal> =
+                discovery_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MultiCustomQueryScorer::<VectorElementTypeBFloat16, TMetric, _, _, _>::new(
+                    discovery_query,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Context(context_query) => {
+            let context_query: ContextQuery<MultiDenseVectorInternal> =
+                context_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MultiCustomQueryScorer::<VectorElementTypeBFloat16, TMetric, _, _, _>::new(
+                    context_query,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+    }
+}
+
+pub fn raw_multi_scorer_int8_impl<'a, TVectorStorage: MultiVectorStorage<VectorElementTypeInt8>>(
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    match vector_storage.distance() {
+        Distance::Cosine => new_multi_scorer_int8_with_metric::<CosineMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Euclid => new_multi_scorer_int8_with_metric::<EuclidMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Dot => new_multi_scorer_int8_with_metric::<DotProductMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Manhattan => new_multi_scorer_int8_with_metric::<ManhattanMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Hamming => new_multi_scorer_int8_with_metric::<HammingMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Jaccard => new_multi_scorer_int8_with_metric::<JaccardMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+        ),
+    }
+}
+
+fn new_multi_scorer_int8_with_metric<
+    'a,
+    TMetric: Metric<VectorElementTypeInt8> + 'a,
+    TVectorStorage: MultiVectorStorage<VectorElementTypeInt8>,
+>(
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    let vec_deleted = vector_storage.deleted_vector_bitslice();
+    match query {
+        QueryVector::Nearest(vector) => raw_scorer_from_query_scorer(
+            MultiMetricQueryScorer::<VectorElementTypeInt8, TMetric, _>::new(
+                vector.try_into()?,
+                vector_storage,
+            ),
+            point_deleted,
+            vec_deleted,
+            is_stopped,
+        ),
+        QueryVector::Recommend(reco_query) => {
+            let reco_query: RecoQuery<MultiDenseVectorInternal> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MultiCustomQueryScorer::<VectorElementTypeInt8, TMetric, _, _, _>::new(
+                    reco_query,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Discovery(discovery_query) => {
+            let discovery_query: DiscoveryQuery<MultiDenseVectorInternal> =
+                discovery_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MultiCustomQueryScorer::<VectorElementTypeInt8, TMetric, _, _, _>::new(
+                    discovery_query,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Context(context_query) => {
+            let context_query: ContextQuery<MultiDenseVectorInternal> =
+                context_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MultiCustomQueryScorer::<VectorElementTypeInt8, TMetric, _, _, _>::new(
+                    context_query,
+                    vector_storage,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+    }
+}
+
 impl<'a, TVector, TQueryScorer> RawScorer for RawScorerImpl<'a, TVector, TQueryScorer>
 where
     TVector: ?Sized,
@@ -3,16 +3,18 @@ use std::sync::atomic::AtomicBool;
 use bitvec::slice::BitSlice;
 use itertools::Itertools;
 use rand::seq::IteratorRandom as _;
-use rand::SeedableRng as _;
+use rand::{Rng as _, SeedableRng as _};
 
 use super::utils::{delete_random_vectors, insert_distributed_vectors, sampler, score, Result};
 use crate::common::rocksdb_wrapper;
-use crate::data_types::vectors::QueryVector;
+use crate::data_types::vectors::{MultiDenseVectorInternal, QueryVector};
 use crate::fixtures::payload_context_fixture::FixtureIdTracker;
 use crate::id_tracker::IdTracker;
-use crate::types::Distance;
+use crate::types::{Distance, MultiVectorConfig};
 use crate::vector_storage::dense::memmap_dense_vector_storage::open_memmap_vector_storage_with_async_io;
 use crate::vector_storage::dense::simple_dense_vector_storage::open_simple_dense_vector_storage;
+use crate::vector_storage::multi_dense::memmap_multi_dense_vector_storage::open_memmap_multi_vector_storage_with_async_io;
+use crate::vector_storage::multi_dense::simple_multi_dense_vector_storage::open_simple_multi_dense_vector_storage;
 use crate::vector_storage::vector_storage_base::VectorStorage;
 use crate::vector_storage::{async_raw_scorer, new_raw_scorer, VectorStorageEnum};
 
@@ -36,6 +38,16 @@ fn async_raw_scorer_dot() -> Result<()> {
     test_async_raw_scorer_defaults(Distance::Dot)
 }
 
+#[test]
+fn async_raw_scorer_multi_cosine() -> Result<()> {
+    test_async_raw_scorer_multi(6942, 64, Distance::Cosine, 512, 64, 128)
+}
+
+#[test]
+fn async_raw_scorer_multi_dot() -> Result<()> {
+    test_async_raw_scorer_multi(6942, 64, Distance::Dot, 512, 64, 128)
+}
+
 fn test_async_raw_scorer_defaults(distance: Distance) -> Result<()> {
     test_async_raw_scorer(6942, 128, distance, 1024, 128, 256)
 }
@@ -122,3 +134,82 @@ fn test_random_score(
 
     Ok(())
 }
+
+fn test_async_raw_scorer_multi(
+    seed: u64,
+    dim: usize,
+    distance: Distance,
+    points: usize,
+    delete: usize,
+    score: usize,
+) -> Result<()> {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+
+    let dir = tempfile::Builder::new()
+        .prefix("immutable-storage")
+        .tempdir()?;
+
+    let mut storage = open_memmap_multi_vector_storage_with_async_io(
+        dir.path(),
+        dim,
+        distance,
+        MultiVectorConfig::default(),
+        true,
+    )?;
+
+    let mut id_tracker = FixtureIdTracker::new(points);
+
+    let mutable_dir = tempfile::Builder::new()
+        .prefix("mutable-storage")
+        .tempdir()?;
+
+    let db = rocksdb_wrapper::open_db(mutable_dir.path(), &[rocksdb_wrapper::DB_VECTOR_CF])?;
+
+    let mut mutable_storage = open_simple_multi_dense_vector_storage(
+        db,
+        rocksdb_wrapper::DB_VECTOR_CF,
+        dim,
+        distance,
+        MultiVectorConfig::default(),
+        &AtomicBool::new(false),
+    )?;
+
+    for offset in 0..points as u32 {
+        // Vary the number of inner vectors to exercise variable sized reads
+        let inner_count = rng.gen_range(1..8);
+        let vector = sampler(&mut rng).take(dim * inner_count).collect_vec();
+        let multi_vector = MultiDenseVectorInternal::new(vector, dim);
+        mutable_storage.insert_vector(offset, (&multi_vector).into())?;
+    }
+    delete_random_vectors(&mut rng, &mut mutable_storage, &mut id_tracker, delete)?;
+
+    storage.update_from(&mutable_storage, &mut (0..points as _), &Default::default())?;
+
+    let VectorStorageEnum::MultiDenseMemmap(memmap_storage) = &storage else {
+        unreachable!();
+    };
+    assert!(memmap_storage.has_async_reader());
+
+    for _ in 0..score {
+        let inner_count = rng.gen_range(1..4);
+        let query = sampler(&mut rng).take(dim * inner_count).collect_vec();
+        let query = QueryVector::Nearest(MultiDenseVectorInternal::new(query, dim).into());
+        let deleted_points = id_tracker.deleted_point_bitslice();
+
+        let raw_scorer = new_raw_scorer(query.clone(), &mutable_storage, deleted_points)?;
+
+        let is_stopped = AtomicBool::new(false);
+        let async_raw_scorer =
+            async_raw_scorer::new_multi(query, memmap_storage, deleted_points, &is_stopped)?;
+
+        let points = rng.gen_range(1..storage.total_vector_count());
+        let points = (0..storage.total_vector_count() as _).choose_multiple(&mut rng, points);
+
+        let res = score(&*raw_scorer, &points);
+        let async_res = score(&*async_raw_scorer, &points);
+
+        assert_eq!(res, async_res);
+    }
+
+    Ok(())
+}
@@ -17,6 +17,7 @@ use crate::id_tracker::IdTrackerSS;
 use crate::types::{Distance, MultiVectorConfig};
 use crate::vector_storage::chunked_vectors::CHUNK_SIZE;
 use crate::vector_storage::multi_dense::appendable_mmap_multi_dense_vector_storage::open_appendable_memmap_multi_vector_storage;
+use crate::vector_storage::multi_dense::memmap_multi_dense_vector_storage::open_memmap_multi_vector_storage;
 use crate::vector_storage::multi_dense::simple_multi_dense_vector_storage::open_simple_multi_dense_vector_storage;
 use crate::vector_storage::{new_raw_scorer, MultiVectorStorage, VectorStorage, VectorStorageEnum};
 
@@ -24,6 +25,7 @@ use crate::vector_storage::{new_raw_scorer, MultiVectorStorage, VectorStorage, V
 enum MultiDenseStorageType {
     SimpleRamFloat,
     AppendableMmapFloat,
+    MemmapFloat,
 }
 
 fn multi_points_fixtures(vec_count: usize, vec_dim: usize) -> Vec<MultiDenseVectorInternal> {
@@ -73,13 +75,20 @@ fn do_test_delete_points(vector_dim: usize, vec_count: usize, storage: &mut Vect
             VectorStorageEnum::DenseSimple(_) => unreachable!(),
             VectorStorageEnum::DenseSimpleByte(_) => unreachable!(),
             VectorStorageEnum::DenseSimpleHalf(_) => unreachable!(),
+            VectorStorageEnum::DenseSimpleBFloat16(_) => unreachable!(),
+            VectorStorageEnum::DenseSimpleInt8(_) => unreachable!(),
             VectorStorageEnum::DenseMemmap(_) => unreachable!(),
             VectorStorageEnum::DenseMemmapByte(_) => unreachable!(),
             VectorStorageEnum::DenseMemmapHalf(_) => unreachable!(),
+            VectorStorageEnum::DenseMemmapBFloat16(_) => unreachable!(),
+            VectorStorageEnum::DenseMemmapInt8(_) => unreachable!(),
             VectorStorageEnum::DenseAppendableMemmap(_) => unreachable!(),
             VectorStorageEnum::DenseAppendableMemmapByte(_) => unreachable!(),
             VectorStorageEnum::DenseAppendableMemmapHalf(_) => unreachable!(),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(_) => unreachable!(),
+            VectorStorageEnum::DenseAppendableMemmapInt8(_) => unreachable!(),
             VectorStorageEnum::SparseSimple(_) => unreachable!(),
+            VectorStorageEnum::SparseMmap(_) => unreachable!(),
             VectorStorageEnum::MultiDenseSimple(v) => {
                 for (orig, vec) in orig_iter.zip(v.iterate_inner_vectors()) {
                     assert_eq!(orig, vec);
@@ -87,6 +96,8 @@ fn do_test_delete_points(vector_dim: usize, vec_count: usize, storage: &mut Vect
             }
             VectorStorageEnum::MultiDenseSimpleByte(_) => unreachable!(),
             VectorStorageEnum::MultiDenseSimpleHalf(_) => unreachable!(),
+            VectorStorageEnum::MultiDenseSimpleBFloat16(_) => unreachable!(),
+            VectorStorageEnum::MultiDenseSimpleInt8(_) => unreachable!(),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => {
                 for (orig, vec) in orig_iter.zip(v.iterate_inner_vectors()) {
                     assert_eq!(orig, vec);
@@ -94,6 +105,17 @@ fn do_test_delete_points(vector_dim: usize, vec_count: usize, storage: &mut Vect
             }
             VectorStorageEnum::MultiDenseAppendableMemmapByte(_) => unreachable!(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(_) => unreachable!(),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(_) => unreachable!(),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(_) => unreachable!(),
+            VectorStorageEnum::MultiDenseMemmap(v) => {
+                for (orig, vec) in orig_iter.zip(v.iterate_inner_vectors()) {
+                    assert_eq!(orig, vec);
+                }
+            }
+            VectorStorageEnum::MultiDenseMemmapByte(_) => unreachable!(),
+            VectorStorageEnum::MultiDenseMemmapHalf(_) => unreachable!(),
+            VectorStorageEnum::MultiDenseMemmapBFloat16(_) => unreachable!(),
+            VectorStorageEnum::MultiDenseMemmapInt8(_) => unreachable!(),
         };
     }
 
@@ -253,6 +275,13 @@ fn create_vector_storage(
             MultiVectorConfig::default(),
         )
         .unwrap(),
+        MultiDenseStorageType::MemmapFloat => open_memmap_multi_vector_storage(
+            path,
+            vec_dim,
+            Distance::Dot,
+            MultiVectorConfig::default(),
+        )
+        .unwrap(),
     }
 }
 
@@ -290,7 +319,8 @@ fn test_delete_points_in_multi_dense_vector_storage(
 fn test_update_from_delete_points_multi_dense_vector_storage(
     #[values(
         MultiDenseStorageType::SimpleRamFloat,
-        MultiDenseStorageType::AppendableMmapFloat
+        MultiDenseStorageType::AppendableMmapFloat,
+        MultiDenseStorageType::MemmapFloat
     )]
     storage_type: MultiDenseStorageType,
 ) {
@@ -316,6 +346,47 @@ fn test_update_from_delete_points_multi_dense_vector_storage(
     }
 }
 
+#[test]
+fn test_reopen_memmap_multi_dense_vector_storage() {
+    let vec_dim = 16;
+    let vec_count = 5;
+    let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+    let points = multi_points_fixtures(vec_count, vec_dim);
+
+    {
+        let db_dir = Builder::new().prefix("db_dir").tempdir().unwrap();
+        let mut source_storage = create_vector_storage(
+            MultiDenseStorageType::SimpleRamFloat,
+            vec_dim,
+            db_dir.path(),
+        );
+        for (i, vec) in points.iter().enumerate() {
+            source_storage
+                .insert_vector(i as PointOffsetType, vec.into())
+                .unwrap();
+        }
+
+        let mut storage =
+            create_vector_storage(MultiDenseStorageType::MemmapFloat, vec_dim, dir.path());
+        storage
+            .update_from(
+                &source_storage,
+                &mut Box::new(0..points.len() as u32),
+                &Default::default(),
+            )
+            .unwrap();
+        storage.flusher()().unwrap();
+    }
+
+    // Offsets of all points are read from the reopened storage
+    let storage = create_vector_storage(MultiDenseStorageType::MemmapFloat, vec_dim, dir.path());
+    assert_eq!(storage.total_vector_count(), points.len());
+    for (i, vec) in points.iter().enumerate() {
+        let stored = storage.get_vector(i as PointOffsetType);
+        assert_eq!(stored.as_vec_ref(), VectorRef::from(vec));
+    }
+}
+
 #[rstest]
 fn test_large_multi_dense_vector_storage(
     #[values(
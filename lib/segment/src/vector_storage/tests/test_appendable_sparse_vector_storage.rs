@@ -10,6 +10,7 @@ use crate::common::rocksdb_wrapper::{open_db, DB_VECTOR_CF};
 use crate::data_types::vectors::QueryVector;
 use crate::fixtures::payload_context_fixture::FixtureIdTracker;
 use crate::id_tracker::IdTrackerSS;
+use crate::vector_storage::mmap_sparse_vector_storage::open_mmap_sparse_vector_storage;
 use crate::vector_storage::query::RecoQuery;
 use crate::vector_storage::simple_sparse_vector_storage::open_simple_sparse_vector_storage;
 use crate::vector_storage::{new_raw_scorer, VectorStorage, VectorStorageEnum};
@@ -215,3 +216,31 @@ fn test_update_from_delete_points_simple_sparse_vector_storage() {
     let _storage =
         open_simple_sparse_vector_storage(db, DB_VECTOR_CF, &AtomicBool::new(false)).unwrap();
 }
+
+#[test]
+fn test_delete_points_in_mmap_sparse_vector_storage() {
+    let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+
+    {
+        let mut storage = open_mmap_sparse_vector_storage(dir.path()).unwrap();
+        do_test_delete_points(&mut storage);
+        storage.flusher()().unwrap();
+    }
+    let storage = open_mmap_sparse_vector_storage(dir.path()).unwrap();
+    assert_eq!(storage.total_vector_count(), 5);
+    assert_eq!(storage.deleted_vector_count(), 5);
+}
+
+#[test]
+fn test_update_from_delete_points_mmap_sparse_vector_storage() {
+    let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+    {
+        let mut storage = open_mmap_sparse_vector_storage(dir.path()).unwrap();
+        do_test_update_from_delete_points(&mut storage);
+        storage.flusher()().unwrap();
+    }
+
+    let storage = open_mmap_sparse_vector_storage(dir.path()).unwrap();
+    assert_eq!(storage.total_vector_count(), 5);
+    assert_eq!(storage.deleted_vector_count(), 5);
+}
@@ -0,0 +1,278 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use bitvec::prelude::BitSlice;
+use common::types::{PointOffsetType, ScoreType, ScoredPointOffset};
+
+use super::query_scorer::token_query_scorer::TokenQueryScorer;
+use super::query_scorer::QueryScorer;
+use super::raw_scorer::check_deleted_condition;
+use super::{MultiVectorStorage, RawScorer, VectorStorageEnum};
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::primitive::PrimitiveVectorElement;
+use crate::data_types::vectors::DenseVector;
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+};
+use crate::spaces::tools::peek_top_largest_iterable;
+use crate::types::Distance;
+use crate::vector_storage::multi_dense::token_mapping::MultiVectorToken;
+
+/// Raw scorer over individual sub-vectors (tokens) of a multivector storage
+///
+/// Scored ids are token ids, deletion is checked for the point each token belongs to.
+pub struct TokenRawScorer<'a, TElement: PrimitiveVectorElement, TQueryScorer>
+where
+    TQueryScorer: QueryScorer<[TElement]>,
+{
+    query_scorer: TQueryScorer,
+    tokens: &'a [MultiVectorToken],
+    /// [`BitSlice`] defining flags for deleted points
+    point_deleted: &'a BitSlice,
+    /// [`BitSlice`] defining flags for deleted vectors in this segment
+    vec_deleted: &'a BitSlice,
+    /// This flag indicates that the search process is stopped externally
+    is_stopped: &'a AtomicBool,
+    element: std::marker::PhantomData<TElement>,
+}
+
+/// Create a raw scorer, which compares a single dense `query` with each token of a multivector
+/// storage
+pub fn new_token_raw_scorer<'a>(
+    query: DenseVector,
+    vector_storage: &'a VectorStorageEnum,
+    tokens: &'a [MultiVectorToken],
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    match vector_storage {
+        VectorStorageEnum::MultiDenseSimple(vs) => {
+            raw_token_scorer_impl(query, vs, tokens, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::MultiDenseSimpleByte(vs) => {
+            raw_token_scorer_impl(query, vs, tokens, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::MultiDenseSimpleHalf(vs) => {
+            raw_token_scorer_impl(query, vs, tokens, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::MultiDenseSimpleBFloat16(vs) => {
+            raw_token_scorer_impl(query, vs, tokens, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::MultiDenseSimpleInt8(vs) => {
+            raw_token_scorer_impl(query, vs, tokens, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::MultiDenseAppendableMemmap(vs) => {
+            raw_token_scorer_impl(query, vs.as_ref(), tokens, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::MultiDenseAppendableMemmapByte(vs) => {
+            raw_token_scorer_impl(query, vs.as_ref(), tokens, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::MultiDenseAppendableMemmapHalf(vs) => {
+            raw_token_scorer_impl(query, vs.as_ref(), tokens, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(vs) => {
+            raw_token_scorer_impl(query, vs.as_ref(), tokens, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::MultiDenseAppendableMemmapInt8(vs) => {
+            raw_token_scorer_impl(query, vs.as_ref(), tokens, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::MultiDenseMemmap(vs) => {
+            raw_token_scorer_impl(query, vs.as_ref(), tokens, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::MultiDenseMemmapByte(vs) => {
+            raw_token_scorer_impl(query, vs.as_ref(), tokens, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::MultiDenseMemmapHalf(vs) => {
+            raw_token_scorer_impl(query, vs.as_ref(), tokens, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::MultiDenseMemmapBFloat16(vs) => {
+            raw_token_scorer_impl(query, vs.as_ref(), tokens, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::MultiDenseMemmapInt8(vs) => {
+            raw_token_scorer_impl(query, vs.as_ref(), tokens, point_deleted, is_stopped)
+        }
+        VectorStorageEnum::DenseSimple(_)
+        | VectorStorageEnum::DenseSimpleByte(_)
+        | VectorStorageEnum::DenseSimpleHalf(_)
+        | VectorStorageEnum::DenseSimpleBFloat16(_)
+        | VectorStorageEnum::DenseSimpleInt8(_)
+        | VectorStorageEnum::DenseMemmap(_)
+        | VectorStorageEnum::DenseMemmapByte(_)
+        | VectorStorageEnum::DenseMemmapHalf(_)
+        | VectorStorageEnum::DenseMemmapBFloat16(_)
+        | VectorStorageEnum::DenseMemmapInt8(_)
+        | VectorStorageEnum::DenseAppendableMemmap(_)
+        | VectorStorageEnum::DenseAppendableMemmapByte(_)
+        | VectorStorageEnum::DenseAppendableMemmapHalf(_)
+        | VectorStorageEnum::DenseAppendableMemmapBFloat16(_)
+        | VectorStorageEnum::DenseAppendableMemmapInt8(_)
+        | VectorStorageEnum::SparseSimple(_)
+        | VectorStorageEnum::SparseMmap(_) => Err(OperationError::service_error(
+            "Token scoring is only supported for multi-dense vectors",
+        )),
+    }
+}
+
+fn raw_token_scorer_impl<'a, TElement, TVectorStorage>(
+    query: DenseVector,
+    vector_storage: &'a TVectorStorage,
+    tokens: &'a [MultiVectorToken],
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>>
+where
+    TElement: PrimitiveVectorElement,
+    TVectorStorage: MultiVectorStorage<TElement>,
+    CosineMetric: Metric<TElement>,
+    EuclidMetric: Metric<TElement>,
+    DotProductMetric: Metric<TElement>,
+    ManhattanMetric: Metric<TElement>,
+    HammingMetric: Metric<TElement>,
+    JaccardMetric: Metric<TElement>,
+{
+    match vector_storage.distance() {
+        Distance::Cosine => new_token_scorer_with_metric::<_, CosineMetric, _>(
+            query,
+            vector_storage,
+            tokens,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Euclid => new_token_scorer_with_metric::<_, EuclidMetric, _>(
+            query,
+            vector_storage,
+            tokens,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Dot => new_token_scorer_with_metric::<_, DotProductMetric, _>(
+            query,
+            vector_storage,
+            tokens,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Manhattan => new_token_scorer_with_metric::<_, ManhattanMetric, _>(
+            query,
+            vector_storage,
+            tokens,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Hamming => new_token_scorer_with_metric::<_, HammingMetric, _>(
+            query,
+            vector_storage,
+            tokens,
+            point_deleted,
+            is_stopped,
+        ),
+        Distance::Jaccard => new_token_scorer_with_metric::<_, JaccardMetric, _>(
+            query,
+            vector_storage,
+            tokens,
+            point_deleted,
+            is_stopped,
+        ),
+    }
+}
+
+fn new_token_scorer_with_metric<
+    'a,
+    TElement: PrimitiveVectorElement,
+    TMetric: Metric<TElement> + 'a,
+    TVectorStorage: MultiVectorStorage<TElement>,
+>(
+    query: DenseVector,
+    vector_storage: &'a TVectorStorage,
+    tokens: &'a [MultiVectorToken],
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    Ok(Box::new(TokenRawScorer {
+        query_scorer: TokenQueryScorer::<TElement, TMetric, _>::new(query, vector_storage, tokens),
+        tokens,
+        point_deleted,
+        vec_deleted: vector_storage.deleted_vector_bitslice(),
+        is_stopped,
+        element: std::marker::PhantomData,
+    }))
+}
+
+impl<'a, TElement, TQueryScorer> RawScorer for TokenRawScorer<'a, TElement, TQueryScorer>
+where
+    TElement: PrimitiveVectorElement,
+    TQueryScorer: QueryScorer<[TElement]>,
+{
+    fn score_points(&self, points: &[PointOffsetType], scores: &mut [ScoredPointOffset]) -> usize {
+        if self.is_stopped.load(Ordering::Relaxed) {
+            return 0;
+        }
+        let mut size: usize = 0;
+        for token_id in points.iter().copied() {
+            if !self.check_vector(token_id) {
+                continue;
+            }
+            scores[size] = ScoredPointOffset {
+                idx: token_id,
+                score: self.query_scorer.score_stored(token_id),
+            };
+
+            size += 1;
+            if size == scores.len() {
+                return size;
+            }
+        }
+        size
+    }
+
+    fn score_points_unfiltered(
+        &self,
+        points: &mut dyn Iterator<Item = PointOffsetType>,
+    ) -> Vec<ScoredPointOffset> {
+        if self.is_stopped.load(Ordering::Relaxed) {
+            return vec![];
+        }
+        points
+            .map(|token_id| ScoredPointOffset {
+                idx: token_id,
+                score: self.query_scorer.score_stored(token_id),
+            })
+            .collect()
+    }
+
+    fn check_vector(&self, token_id: PointOffsetType) -> bool {
+        self.tokens
+            .get(token_id as usize)
+            .map(|token| {
+                check_deleted_condition(token.point_id, self.vec_deleted, self.point_deleted)
+            })
+            .unwrap_or(false)
+    }
+
+    fn score_point(&self, token_id: PointOffsetType) -> ScoreType {
+        self.query_scorer.score_stored(token_id)
+    }
+
+    fn score_internal(&self, token_a: PointOffsetType, token_b: PointOffsetType) -> ScoreType {
+        self.query_scorer.score_internal(token_a, token_b)
+    }
+
+    fn peek_top_iter(
+        &self,
+        points: &mut dyn Iterator<Item = PointOffsetType>,
+        top: usize,
+    ) -> Vec<ScoredPointOffset> {
+        let scores = points
+            .take_while(|_| !self.is_stopped.load(Ordering::Relaxed))
+            .filter(|token_id| self.check_vector(*token_id))
+            .map(|token_id| ScoredPointOffset {
+                idx: token_id,
+                score: self.query_scorer.score_stored(token_id),
+            });
+        peek_top_largest_iterable(scores, top)
+    }
+
+    fn peek_top_all(&self, top: usize) -> Vec<ScoredPointOffset> {
+        self.peek_top_iter(&mut (0..self.tokens.len() as PointOffsetType), top)
+    }
+}
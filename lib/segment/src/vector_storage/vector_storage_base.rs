@@ -9,6 +9,7 @@ use sparse::common::sparse_vector::SparseVector;
 use super::dense::memmap_dense_vector_storage::MemmapDenseVectorStorage;
 use super::dense::simple_dense_vector_storage::SimpleDenseVectorStorage;
 use super::multi_dense::appendable_mmap_multi_dense_vector_storage::AppendableMmapMultiDenseVectorStorage;
+use super::multi_dense::memmap_multi_dense_vector_storage::MemmapMultiDenseVectorStorage;
 use super::multi_dense::simple_multi_dense_vector_storage::SimpleMultiDenseVectorStorage;
 use crate::common::operation_error::OperationResult;
 use crate::common::Flusher;
@@ -16,10 +17,12 @@ use crate::data_types::named_vectors::CowVector;
 use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{
     MultiDenseVectorInternal, TypedMultiDenseVectorRef, Vector, VectorElementType,
-    VectorElementTypeByte, VectorElementTypeHalf, VectorRef,
+    VectorElementTypeBFloat16, VectorElementTypeByte, VectorElementTypeHalf, VectorElementTypeInt8,
+    VectorRef,
 };
 use crate::types::{Distance, MultiVectorConfig, VectorStorageDatatype};
 use crate::vector_storage::dense::appendable_mmap_dense_vector_storage::AppendableMmapDenseVectorStorage;
+use crate::vector_storage::mmap_sparse_vector_storage::MmapSparseVectorStorage;
 use crate::vector_storage::simple_sparse_vector_storage::SimpleSparseVectorStorage;
 
 /// Trait for vector storage
@@ -122,16 +125,25 @@ pub enum VectorStorageEnum {
     DenseSimple(SimpleDenseVectorStorage<VectorElementType>),
     DenseSimpleByte(SimpleDenseVectorStorage<VectorElementTypeByte>),
     DenseSimpleHalf(SimpleDenseVectorStorage<VectorElementTypeHalf>),
+    DenseSimpleBFloat16(SimpleDenseVectorStorage<VectorElementTypeBFloat16>),
+    DenseSimpleInt8(SimpleDenseVectorStorage<VectorElementTypeInt8>),
     DenseMemmap(Box<MemmapDenseVectorStorage<VectorElementType>>),
     DenseMemmapByte(Box<MemmapDenseVectorStorage<VectorElementTypeByte>>),
     DenseMemmapHalf(Box<MemmapDenseVectorStorage<VectorElementTypeHalf>>),
+    DenseMemmapBFloat16(Box<MemmapDenseVectorStorage<VectorElementTypeBFloat16>>),
+    DenseMemmapInt8(Box<MemmapDenseVectorStorage<VectorElementTypeInt8>>),
     DenseAppendableMemmap(Box<AppendableMmapDenseVectorStorage<VectorElementType>>),
     DenseAppendableMemmapByte(Box<AppendableMmapDenseVectorStorage<VectorElementTypeByte>>),
     DenseAppendableMemmapHalf(Box<AppendableMmapDenseVectorStorage<VectorElementTypeHalf>>),
+    DenseAppendableMemmapBFloat16(Box<AppendableMmapDenseVectorStorage<VectorElementTypeBFloat16>>),
+    DenseAppendableMemmapInt8(Box<AppendableMmapDenseVectorStorage<VectorElementTypeInt8>>),
     SparseSimple(SimpleSparseVectorStorage),
+    SparseMmap(MmapSparseVectorStorage),
     MultiDenseSimple(SimpleMultiDenseVectorStorage<VectorElementType>),
     MultiDenseSimpleByte(SimpleMultiDenseVectorStorage<VectorElementTypeByte>),
     MultiDenseSimpleHalf(SimpleMultiDenseVectorStorage<VectorElementTypeHalf>),
+    MultiDenseSimpleBFloat16(SimpleMultiDenseVectorStorage<VectorElementTypeBFloat16>),
+    MultiDenseSimpleInt8(SimpleMultiDenseVectorStorage<VectorElementTypeInt8>),
     MultiDenseAppendableMemmap(Box<AppendableMmapMultiDenseVectorStorage<VectorElementType>>),
     MultiDenseAppendableMemmapByte(
         Box<AppendableMmapMultiDenseVectorStorage<VectorElementTypeByte>>,
@@ -139,6 +151,17 @@ pub enum VectorStorageEnum {
     MultiDenseAppendableMemmapHalf(
         Box<AppendableMmapMultiDenseVectorStorage<VectorElementTypeHalf>>,
     ),
+    MultiDenseAppendableMemmapBFloat16(
+        Box<AppendableMmapMultiDenseVectorStorage<VectorElementTypeBFloat16>>,
+    ),
+    MultiDenseAppendableMemmapInt8(
+        Box<AppendableMmapMultiDenseVectorStorage<VectorElementTypeInt8>>,
+    ),
+    MultiDenseMemmap(Box<MemmapMultiDenseVectorStorage<VectorElementType>>),
+    MultiDenseMemmapByte(Box<MemmapMultiDenseVectorStorage<VectorElementTypeByte>>),
+    MultiDenseMemmapHalf(Box<MemmapMultiDenseVectorStorage<VectorElementTypeHalf>>),
+    MultiDenseMemmapBFloat16(Box<MemmapMultiDenseVectorStorage<VectorElementTypeBFloat16>>),
+    MultiDenseMemmapInt8(Box<MemmapMultiDenseVectorStorage<VectorElementTypeInt8>>),
 }
 
 impl VectorStorageEnum {
@@ -147,19 +170,37 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseSimple(_) => None,
             VectorStorageEnum::DenseSimpleByte(_) => None,
             VectorStorageEnum::DenseSimpleHalf(_) => None,
+            VectorStorageEnum::DenseSimpleBFloat16(_) => None,
+            VectorStorageEnum::DenseSimpleInt8(_) => None,
             VectorStorageEnum::DenseMemmap(_) => None,
             VectorStorageEnum::DenseMemmapByte(_) => None,
             VectorStorageEnum::DenseMemmapHalf(_) => None,
+            VectorStorageEnum::DenseMemmapBFloat16(_) => None,
+            VectorStorageEnum::DenseMemmapInt8(_) => None,
             VectorStorageEnum::DenseAppendableMemmap(_) => None,
             VectorStorageEnum::DenseAppendableMemmapByte(_) => None,
             VectorStorageEnum::DenseAppendableMemmapHalf(_) => None,
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(_) => None,
+            VectorStorageEnum::DenseAppendableMemmapInt8(_) => None,
             VectorStorageEnum::SparseSimple(_) => None,
+            VectorStorageEnum::SparseMmap(_) => None,
             VectorStorageEnum::MultiDenseSimple(s) => Some(s.multi_vector_config()),
             VectorStorageEnum::MultiDenseSimpleByte(s) => Some(s.multi_vector_config()),
             VectorStorageEnum::MultiDenseSimpleHalf(s) => Some(s.multi_vector_config()),
+            VectorStorageEnum::MultiDenseSimpleBFloat16(s) => Some(s.multi_vector_config()),
+            VectorStorageEnum::MultiDenseSimpleInt8(s) => Some(s.multi_vector_config()),
             VectorStorageEnum::MultiDenseAppendableMemmap(s) => Some(s.multi_vector_config()),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(s) => Some(s.multi_vector_config()),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(s) => Some(s.multi_vector_config()),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(s) => {
+                Some(s.multi_vector_config())
+            }
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(s) => Some(s.multi_vector_config()),
+            VectorStorageEnum::MultiDenseMemmap(s) => Some(s.multi_vector_config()),
+            VectorStorageEnum::MultiDenseMemmapByte(s) => Some(s.multi_vector_config()),
+            VectorStorageEnum::MultiDenseMemmapHalf(s) => Some(s.multi_vector_config()),
+            VectorStorageEnum::MultiDenseMemmapBFloat16(s) => Some(s.multi_vector_config()),
+            VectorStorageEnum::MultiDenseMemmapInt8(s) => Some(s.multi_vector_config()),
         }
     }
 
@@ -168,9 +209,13 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseSimple(v) => Vector::from(vec![1.0; v.vector_dim()]),
             VectorStorageEnum::DenseSimpleByte(v) => Vector::from(vec![1.0; v.vector_dim()]),
             VectorStorageEnum::DenseSimpleHalf(v) => Vector::from(vec![1.0; v.vector_dim()]),
+            VectorStorageEnum::DenseSimpleBFloat16(v) => Vector::from(vec![1.0; v.vector_dim()]),
+            VectorStorageEnum::DenseSimpleInt8(v) => Vector::from(vec![1.0; v.vector_dim()]),
             VectorStorageEnum::DenseMemmap(v) => Vector::from(vec![1.0; v.vector_dim()]),
             VectorStorageEnum::DenseMemmapByte(v) => Vector::from(vec![1.0; v.vector_dim()]),
             VectorStorageEnum::DenseMemmapHalf(v) => Vector::from(vec![1.0; v.vector_dim()]),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => Vector::from(vec![1.0; v.vector_dim()]),
+            VectorStorageEnum::DenseMemmapInt8(v) => Vector::from(vec![1.0; v.vector_dim()]),
             VectorStorageEnum::DenseAppendableMemmap(v) => Vector::from(vec![1.0; v.vector_dim()]),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => {
                 Vector::from(vec![1.0; v.vector_dim()])
@@ -178,7 +223,14 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => {
                 Vector::from(vec![1.0; v.vector_dim()])
             }
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => {
+                Vector::from(vec![1.0; v.vector_dim()])
+            }
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => {
+                Vector::from(vec![1.0; v.vector_dim()])
+            }
             VectorStorageEnum::SparseSimple(_) => Vector::from(SparseVector::default()),
+            VectorStorageEnum::SparseMmap(_) => Vector::from(SparseVector::default()),
             VectorStorageEnum::MultiDenseSimple(v) => {
                 Vector::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
             }
@@ -188,6 +240,12 @@ impl VectorStorageEnum {
             VectorStorageEnum::MultiDenseSimpleHalf(v) => {
                 Vector::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
             }
+            VectorStorageEnum::MultiDenseSimpleBFloat16(v) => {
+                Vector::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
+            }
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => {
+                Vector::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
+            }
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => {
                 Vector::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
             }
@@ -197,6 +255,27 @@ impl VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => {
                 Vector::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
             }
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => {
+                Vector::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
+            }
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => {
+                Vector::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
+            }
+            VectorStorageEnum::MultiDenseMemmap(v) => {
+                Vector::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
+            }
+            VectorStorageEnum::MultiDenseMemmapByte(v) => {
+                Vector::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
+            }
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => {
+                Vector::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
+            }
+            VectorStorageEnum::MultiDenseMemmapBFloat16(v) => {
+                Vector::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
+            }
+            VectorStorageEnum::MultiDenseMemmapInt8(v) => {
+                Vector::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
+            }
         }
     }
 }
@@ -207,19 +286,35 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseSimple(v) => v.distance(),
             VectorStorageEnum::DenseSimpleByte(v) => v.distance(),
             VectorStorageEnum::DenseSimpleHalf(v) => v.distance(),
+            VectorStorageEnum::DenseSimpleBFloat16(v) => v.distance(),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.distance(),
             VectorStorageEnum::DenseMemmap(v) => v.distance(),
             VectorStorageEnum::DenseMemmapByte(v) => v.distance(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.distance(),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.distance(),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.distance(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.distance(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.distance(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.distance(),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.distance(),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.distance(),
             VectorStorageEnum::SparseSimple(v) => v.distance(),
+            VectorStorageEnum::SparseMmap(v) => v.distance(),
             VectorStorageEnum::MultiDenseSimple(v) => v.distance(),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.distance(),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.distance(),
+            VectorStorageEnum::MultiDenseSimpleBFloat16(v) => v.distance(),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.distance(),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.distance(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.distance(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.distance(),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.distance(),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.distance(),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.distance(),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.distance(),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.distance(),
+            VectorStorageEnum::MultiDenseMemmapBFloat16(v) => v.distance(),
+            VectorStorageEnum::MultiDenseMemmapInt8(v) => v.distance(),
         }
     }
 
@@ -228,19 +323,35 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseSimple(v) => v.datatype(),
             VectorStorageEnum::DenseSimpleByte(v) => v.datatype(),
             VectorStorageEnum::DenseSimpleHalf(v) => v.datatype(),
+            VectorStorageEnum::DenseSimpleBFloat16(v) => v.datatype(),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.datatype(),
             VectorStorageEnum::DenseMemmap(v) => v.datatype(),
             VectorStorageEnum::DenseMemmapByte(v) => v.datatype(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.datatype(),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.datatype(),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.datatype(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.datatype(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.datatype(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.datatype(),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.datatype(),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.datatype(),
             VectorStorageEnum::SparseSimple(v) => v.datatype(),
+            VectorStorageEnum::SparseMmap(v) => v.datatype(),
             VectorStorageEnum::MultiDenseSimple(v) => v.datatype(),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.datatype(),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.datatype(),
+            VectorStorageEnum::MultiDenseSimpleBFloat16(v) => v.datatype(),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.datatype(),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.datatype(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.datatype(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.datatype(),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.datatype(),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.datatype(),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.datatype(),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.datatype(),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.datatype(),
+            VectorStorageEnum::MultiDenseMemmapBFloat16(v) => v.datatype(),
+            VectorStorageEnum::MultiDenseMemmapInt8(v) => v.datatype(),
         }
     }
 
@@ -249,19 +360,35 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseSimple(v) => v.is_on_disk(),
             VectorStorageEnum::DenseSimpleByte(v) => v.is_on_disk(),
             VectorStorageEnum::DenseSimpleHalf(v) => v.is_on_disk(),
+            VectorStorageEnum::DenseSimpleBFloat16(v) => v.is_on_disk(),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.is_on_disk(),
             VectorStorageEnum::DenseMemmap(v) => v.is_on_disk(),
             VectorStorageEnum::DenseMemmapByte(v) => v.is_on_disk(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.is_on_disk(),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.is_on_disk(),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.is_on_disk(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.is_on_disk(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.is_on_disk(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.is_on_disk(),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.is_on_disk(),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.is_on_disk(),
             VectorStorageEnum::SparseSimple(v) => v.is_on_disk(),
+            VectorStorageEnum::SparseMmap(v) => v.is_on_disk(),
             VectorStorageEnum::MultiDenseSimple(v) => v.is_on_disk(),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.is_on_disk(),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.is_on_disk(),
+            VectorStorageEnum::MultiDenseSimpleBFloat16(v) => v.is_on_disk(),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.is_on_disk(),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.is_on_disk(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.is_on_disk(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.is_on_disk(),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.is_on_disk(),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.is_on_disk(),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.is_on_disk(),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.is_on_disk(),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.is_on_disk(),
+            VectorStorageEnum::MultiDenseMemmapBFloat16(v) => v.is_on_disk(),
+            VectorStorageEnum::MultiDenseMemmapInt8(v) => v.is_on_disk(),
         }
     }
 
@@ -270,19 +397,35 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseSimple(v) => v.total_vector_count(),
             VectorStorageEnum::DenseSimpleByte(v) => v.total_vector_count(),
             VectorStorageEnum::DenseSimpleHalf(v) => v.total_vector_count(),
+            VectorStorageEnum::DenseSimpleBFloat16(v) => v.total_vector_count(),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.total_vector_count(),
             VectorStorageEnum::DenseMemmap(v) => v.total_vector_count(),
             VectorStorageEnum::DenseMemmapByte(v) => v.total_vector_count(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.total_vector_count(),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.total_vector_count(),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.total_vector_count(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.total_vector_count(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.total_vector_count(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.total_vector_count(),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.total_vector_count(),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.total_vector_count(),
             VectorStorageEnum::SparseSimple(v) => v.total_vector_count(),
+            VectorStorageEnum::SparseMmap(v) => v.total_vector_count(),
             VectorStorageEnum::MultiDenseSimple(v) => v.total_vector_count(),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.total_vector_count(),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.total_vector_count(),
+            VectorStorageEnum::MultiDenseSimpleBFloat16(v) => v.total_vector_count(),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.total_vector_count(),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.total_vector_count(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.total_vector_count(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.total_vector_count(),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.total_vector_count(),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.total_vector_count(),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.total_vector_count(),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.total_vector_count(),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.total_vector_count(),
+            VectorStorageEnum::MultiDenseMemmapBFloat16(v) => v.total_vector_count(),
+            VectorStorageEnum::MultiDenseMemmapInt8(v) => v.total_vector_count(),
         }
     }
 
@@ -291,19 +434,35 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseSimple(v) => v.available_size_in_bytes(),
             VectorStorageEnum::DenseSimpleByte(v) => v.available_size_in_bytes(),
             VectorStorageEnum::DenseSimpleHalf(v) => v.available_size_in_bytes(),
+            VectorStorageEnum::DenseSimpleBFloat16(v) => v.available_size_in_bytes(),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.available_size_in_bytes(),
             VectorStorageEnum::DenseMemmap(v) => v.available_size_in_bytes(),
             VectorStorageEnum::DenseMemmapByte(v) => v.available_size_in_bytes(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.available_size_in_bytes(),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.available_size_in_bytes(),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.available_size_in_bytes(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.available_size_in_bytes(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.available_size_in_bytes(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.available_size_in_bytes(),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.available_size_in_bytes(),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.available_size_in_bytes(),
             VectorStorageEnum::SparseSimple(v) => v.available_size_in_bytes(),
+            VectorStorageEnum::SparseMmap(v) => v.available_size_in_bytes(),
             VectorStorageEnum::MultiDenseSimple(v) => v.available_size_in_bytes(),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.available_size_in_bytes(),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.available_size_in_bytes(),
+            VectorStorageEnum::MultiDenseSimpleBFloat16(v) => v.available_size_in_bytes(),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.available_size_in_bytes(),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.available_size_in_bytes(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.available_size_in_bytes(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.available_size_in_bytes(),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.available_size_in_bytes(),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.available_size_in_bytes(),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.available_size_in_bytes(),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.available_size_in_bytes(),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.available_size_in_bytes(),
+            VectorStorageEnum::MultiDenseMemmapBFloat16(v) => v.available_size_in_bytes(),
+            VectorStorageEnum::MultiDenseMemmapInt8(v) => v.available_size_in_bytes(),
         }
     }
 
@@ -312,19 +471,35 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseSimple(v) => v.get_vector(key),
             VectorStorageEnum::DenseSimpleByte(v) => v.get_vector(key),
             VectorStorageEnum::DenseSimpleHalf(v) => v.get_vector(key),
+            VectorStorageEnum::DenseSimpleBFloat16(v) => v.get_vector(key),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.get_vector(key),
             VectorStorageEnum::DenseMemmap(v) => v.get_vector(key),
             VectorStorageEnum::DenseMemmapByte(v) => v.get_vector(key),
             VectorStorageEnum::DenseMemmapHalf(v) => v.get_vector(key),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.get_vector(key),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.get_vector(key),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.get_vector(key),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.get_vector(key),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.get_vector(key),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.get_vector(key),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.get_vector(key),
             VectorStorageEnum::SparseSimple(v) => v.get_vector(key),
+            VectorStorageEnum::SparseMmap(v) => v.get_vector(key),
             VectorStorageEnum::MultiDenseSimple(v) => v.get_vector(key),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.get_vector(key),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.get_vector(key),
+            VectorStorageEnum::MultiDenseSimpleBFloat16(v) => v.get_vector(key),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.get_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.get_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.get_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.get_vector(key),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.get_vector(key),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.get_vector(key),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.get_vector(key),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.get_vector(key),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.get_vector(key),
+            VectorStorageEnum::MultiDenseMemmapBFloat16(v) => v.get_vector(key),
+            VectorStorageEnum::MultiDenseMemmapInt8(v) => v.get_vector(key),
         }
     }
 
@@ -333,19 +508,35 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseSimple(v) => v.get_vector_opt(key),
             VectorStorageEnum::DenseSimpleByte(v) => v.get_vector_opt(key),
             VectorStorageEnum::DenseSimpleHalf(v) => v.get_vector_opt(key),
+            VectorStorageEnum::DenseSimpleBFloat16(v) => v.get_vector_opt(key),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.get_vector_opt(key),
             VectorStorageEnum::DenseMemmap(v) => v.get_vector_opt(key),
             VectorStorageEnum::DenseMemmapByte(v) => v.get_vector_opt(key),
             VectorStorageEnum::DenseMemmapHalf(v) => v.get_vector_opt(key),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.get_vector_opt(key),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.get_vector_opt(key),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.get_vector_opt(key),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.get_vector_opt(key),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.get_vector_opt(key),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.get_vector_opt(key),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.get_vector_opt(key),
             VectorStorageEnum::SparseSimple(v) => v.get_vector_opt(key),
+            VectorStorageEnum::SparseMmap(v) => v.get_vector_opt(key),
             VectorStorageEnum::MultiDenseSimple(v) => v.get_vector_opt(key),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.get_vector_opt(key),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.get_vector_opt(key),
+            VectorStorageEnum::MultiDenseSimpleBFloat16(v) => v.get_vector_opt(key),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.get_vector_opt(key),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.get_vector_opt(key),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.get_vector_opt(key),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.get_vector_opt(key),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.get_vector_opt(key),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.get_vector_opt(key),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.get_vector_opt(key),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.get_vector_opt(key),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.get_vector_opt(key),
+            VectorStorageEnum::MultiDenseMemmapBFloat16(v) => v.get_vector_opt(key),
+            VectorStorageEnum::MultiDenseMemmapInt8(v) => v.get_vector_opt(key),
         }
     }
 
@@ -354,19 +545,37 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseSimple(v) => v.insert_vector(key, vector),
             VectorStorageEnum::DenseSimpleByte(v) => v.insert_vector(key, vector),
             VectorStorageEnum::DenseSimpleHalf(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::DenseSimpleBFloat16(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.insert_vector(key, vector),
             VectorStorageEnum::DenseMemmap(v) => v.insert_vector(key, vector),
             VectorStorageEnum::DenseMemmapByte(v) => v.insert_vector(key, vector),
             VectorStorageEnum::DenseMemmapHalf(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.insert_vector(key, vector),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.insert_vector(key, vector),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.insert_vector(key, vector),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.insert_vector(key, vector),
             VectorStorageEnum::SparseSimple(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::SparseMmap(v) => v.insert_vector(key, vector),
             VectorStorageEnum::MultiDenseSimple(v) => v.insert_vector(key, vector),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.insert_vector(key, vector),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::MultiDenseSimpleBFloat16(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.insert_vector(key, vector),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.insert_vector(key, vector),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.insert_vector(key, vector),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => {
+                v.insert_vector(key, vector)
+            }
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::MultiDenseMemmapBFloat16(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::MultiDenseMemmapInt8(v) => v.insert_vector(key, vector),
         }
     }
 
@@ -380,9 +589,13 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseSimple(v) => v.update_from(other, other_ids, stopped),
             VectorStorageEnum::DenseSimpleByte(v) => v.update_from(other, other_ids, stopped),
             VectorStorageEnum::DenseSimpleHalf(v) => v.update_from(other, other_ids, stopped),
+            VectorStorageEnum::DenseSimpleBFloat16(v) => v.update_from(other, other_ids, stopped),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.update_from(other, other_ids, stopped),
             VectorStorageEnum::DenseMemmap(v) => v.update_from(other, other_ids, stopped),
             VectorStorageEnum::DenseMemmapByte(v) => v.update_from(other, other_ids, stopped),
             VectorStorageEnum::DenseMemmapHalf(v) => v.update_from(other, other_ids, stopped),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.update_from(other, other_ids, stopped),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.update_from(other, other_ids, stopped),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.update_from(other, other_ids, stopped),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => {
                 v.update_from(other, other_ids, stopped)
@@ -390,10 +603,21 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => {
                 v.update_from(other, other_ids, stopped)
             }
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => {
+                v.update_from(other, other_ids, stopped)
+            }
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => {
+                v.update_from(other, other_ids, stopped)
+            }
             VectorStorageEnum::SparseSimple(v) => v.update_from(other, other_ids, stopped),
+            VectorStorageEnum::SparseMmap(v) => v.update_from(other, other_ids, stopped),
             VectorStorageEnum::MultiDenseSimple(v) => v.update_from(other, other_ids, stopped),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.update_from(other, other_ids, stopped),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.update_from(other, other_ids, stopped),
+            VectorStorageEnum::MultiDenseSimpleBFloat16(v) => {
+                v.update_from(other, other_ids, stopped)
+            }
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.update_from(other, other_ids, stopped),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => {
                 v.update_from(other, other_ids, stopped)
             }
@@ -403,6 +627,19 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => {
                 v.update_from(other, other_ids, stopped)
             }
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => {
+                v.update_from(other, other_ids, stopped)
+            }
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => {
+                v.update_from(other, other_ids, stopped)
+            }
+            VectorStorageEnum::MultiDenseMemmap(v) => v.update_from(other, other_ids, stopped),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.update_from(other, other_ids, stopped),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.update_from(other, other_ids, stopped),
+            VectorStorageEnum::MultiDenseMemmapBFloat16(v) => {
+                v.update_from(other, other_ids, stopped)
+            }
+            VectorStorageEnum::MultiDenseMemmapInt8(v) => v.update_from(other, other_ids, stopped),
         }
     }
 
@@ -411,19 +648,35 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseSimple(v) => v.flusher(),
             VectorStorageEnum::DenseSimpleByte(v) => v.flusher(),
             VectorStorageEnum::DenseSimpleHalf(v) => v.flusher(),
+            VectorStorageEnum::DenseSimpleBFloat16(v) => v.flusher(),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.flusher(),
             VectorStorageEnum::DenseMemmap(v) => v.flusher(),
             VectorStorageEnum::DenseMemmapByte(v) => v.flusher(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.flusher(),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.flusher(),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.flusher(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.flusher(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.flusher(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.flusher(),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.flusher(),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.flusher(),
             VectorStorageEnum::SparseSimple(v) => v.flusher(),
+            VectorStorageEnum::SparseMmap(v) => v.flusher(),
             VectorStorageEnum::MultiDenseSimple(v) => v.flusher(),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.flusher(),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.flusher(),
+            VectorStorageEnum::MultiDenseSimpleBFloat16(v) => v.flusher(),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.flusher(),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.flusher(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.flusher(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.flusher(),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.flusher(),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.flusher(),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.flusher(),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.flusher(),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.flusher(),
+            VectorStorageEnum::MultiDenseMemmapBFloat16(v) => v.flusher(),
+            VectorStorageEnum::MultiDenseMemmapInt8(v) => v.flusher(),
         }
     }
 
@@ -432,19 +685,35 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseSimple(v) => v.files(),
             VectorStorageEnum::DenseSimpleByte(v) => v.files(),
             VectorStorageEnum::DenseSimpleHalf(v) => v.files(),
+            VectorStorageEnum::DenseSimpleBFloat16(v) => v.files(),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.files(),
             VectorStorageEnum::DenseMemmap(v) => v.files(),
             VectorStorageEnum::DenseMemmapByte(v) => v.files(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.files(),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.files(),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.files(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.files(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.files(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.files(),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.files(),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.files(),
             VectorStorageEnum::SparseSimple(v) => v.files(),
+            VectorStorageEnum::SparseMmap(v) => v.files(),
             VectorStorageEnum::MultiDenseSimple(v) => v.files(),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.files(),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.files(),
+            VectorStorageEnum::MultiDenseSimpleBFloat16(v) => v.files(),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.files(),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.files(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.files(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.files(),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.files(),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.files(),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.files(),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.files(),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.files(),
+            VectorStorageEnum::MultiDenseMemmapBFloat16(v) => v.files(),
+            VectorStorageEnum::MultiDenseMemmapInt8(v) => v.files(),
         }
     }
 
@@ -453,19 +722,35 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseSimple(v) => v.delete_vector(key),
             VectorStorageEnum::DenseSimpleByte(v) => v.delete_vector(key),
             VectorStorageEnum::DenseSimpleHalf(v) => v.delete_vector(key),
+            VectorStorageEnum::DenseSimpleBFloat16(v) => v.delete_vector(key),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.delete_vector(key),
             VectorStorageEnum::DenseMemmap(v) => v.delete_vector(key),
             VectorStorageEnum::DenseMemmapByte(v) => v.delete_vector(key),
             VectorStorageEnum::DenseMemmapHalf(v) => v.delete_vector(key),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.delete_vector(key),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.delete_vector(key),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.delete_vector(key),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.delete_vector(key),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.delete_vector(key),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.delete_vector(key),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.delete_vector(key),
             VectorStorageEnum::SparseSimple(v) => v.delete_vector(key),
+            VectorStorageEnum::SparseMmap(v) => v.delete_vector(key),
             VectorStorageEnum::MultiDenseSimple(v) => v.delete_vector(key),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.delete_vector(key),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.delete_vector(key),
+            VectorStorageEnum::MultiDenseSimpleBFloat16(v) => v.delete_vector(key),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.delete_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.delete_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.delete_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.delete_vector(key),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.delete_vector(key),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.delete_vector(key),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.delete_vector(key),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.delete_vector(key),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.delete_vector(key),
+            VectorStorageEnum::MultiDenseMemmapBFloat16(v) => v.delete_vector(key),
+            VectorStorageEnum::MultiDenseMemmapInt8(v) => v.delete_vector(key),
         }
     }
 
@@ -474,19 +759,35 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseSimple(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseSimpleByte(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseSimpleHalf(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::DenseSimpleBFloat16(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseMemmap(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseMemmapByte(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseMemmapHalf(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.is_deleted_vector(key),
             VectorStorageEnum::SparseSimple(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::SparseMmap(v) => v.is_deleted_vector(key),
             VectorStorageEnum::MultiDenseSimple(v) => v.is_deleted_vector(key),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.is_deleted_vector(key),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::MultiDenseSimpleBFloat16(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.is_deleted_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.is_deleted_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.is_deleted_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::MultiDenseMemmapBFloat16(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::MultiDenseMemmapInt8(v) => v.is_deleted_vector(key),
         }
     }
 
@@ -495,19 +796,35 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseSimple(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseSimpleByte(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseSimpleHalf(v) => v.deleted_vector_count(),
+            VectorStorageEnum::DenseSimpleBFloat16(v) => v.deleted_vector_count(),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseMemmap(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseMemmapByte(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.deleted_vector_count(),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.deleted_vector_count(),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.deleted_vector_count(),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.deleted_vector_count(),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.deleted_vector_count(),
             VectorStorageEnum::SparseSimple(v) => v.deleted_vector_count(),
+            VectorStorageEnum::SparseMmap(v) => v.deleted_vector_count(),
             VectorStorageEnum::MultiDenseSimple(v) => v.deleted_vector_count(),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.deleted_vector_count(),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.deleted_vector_count(),
+            VectorStorageEnum::MultiDenseSimpleBFloat16(v) => v.deleted_vector_count(),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.deleted_vector_count(),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.deleted_vector_count(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.deleted_vector_count(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.deleted_vector_count(),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.deleted_vector_count(),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.deleted_vector_count(),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.deleted_vector_count(),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.deleted_vector_count(),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.deleted_vector_count(),
+            VectorStorageEnum::MultiDenseMemmapBFloat16(v) => v.deleted_vector_count(),
+            VectorStorageEnum::MultiDenseMemmapInt8(v) => v.deleted_vector_count(),
         }
     }
 
@@ -516,19 +833,35 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseSimple(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseSimpleByte(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseSimpleHalf(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::DenseSimpleBFloat16(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseMemmap(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseMemmapByte(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::SparseSimple(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::SparseMmap(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::MultiDenseSimple(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::MultiDenseSimpleBFloat16(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::MultiDenseMemmap(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::MultiDenseMemmapByte(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::MultiDenseMemmapHalf(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::MultiDenseMemmapBFloat16(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::MultiDenseMemmapInt8(v) => v.deleted_vector_bitslice(),
         }
     }
 }
@@ -42,8 +42,10 @@ fn test_batch_and_single_request_equivalency() {
                 storage_type: VectorStorageType::Memory,
                 index: Indexes::Plain {},
                 quantization_config: None,
+                additional_quantization_configs: vec![],
                 multivector_config: None,
                 datatype: None,
+                prefix_of: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -156,6 +158,7 @@ fn test_batch_and_single_request_equivalency() {
         quantized_vectors: quantized_vectors.clone(),
         payload_index: payload_index_ptr,
         hnsw_config,
+        prefix_dim: None,
         permit: Some(permit),
         stopped: &stopped,
     })
@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use rstest::rstest;
+use segment::common::operation_error::OperationError;
+use segment::data_types::vectors::{only_default_vector, DEFAULT_VECTOR_NAME};
+use segment::entry::entry_point::SegmentEntry;
+use segment::segment_constructor::build_segment;
+use segment::types::{
+    Distance, Indexes, SegmentConfig, VectorDataConfig, VectorStorageDatatype, VectorStorageType,
+    WithPayload,
+};
+use tempfile::Builder;
+
+#[rstest]
+#[case::hamming(Distance::Hamming, [0.0, -8.0, -12.0], [8.0, 12.0])]
+#[case::jaccard(Distance::Jaccard, [0.0, -0.25, -1.0], [0.25, 1.0])]
+fn test_bit_vector_search(
+    #[case] distance: Distance,
+    #[case] expected_scores: [f32; 3],
+    #[case] expected_distances: [f32; 2],
+) {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: 16,
+                distance,
+                storage_type: VectorStorageType::Memory,
+                index: Indexes::Plain {},
+                quantization_config: None,
+                additional_quantization_configs: vec![],
+                multivector_config: None,
+                datatype: Some(VectorStorageDatatype::Bit),
+                prefix_of: None,
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+    let mut segment = build_segment(dir.path(), &config, true).unwrap();
+
+    // 16 bits are packed into 2 bytes
+    let vectors: [[f32; 2]; 3] = [
+        [0b1111_0000 as f32, 0.0],
+        [0b0000_1111 as f32, 0.0],
+        [0b1111_1111 as f32, 0b1111_1111 as f32],
+    ];
+    for (idx, vector) in vectors.iter().enumerate() {
+        segment
+            .upsert_point(idx as u64, (idx as u64).into(), only_default_vector(vector))
+            .unwrap();
+    }
+
+    let unpacked_vector = [1.0; 16];
+    let result = segment.upsert_point(10, 10.into(), only_default_vector(&unpacked_vector));
+    assert!(matches!(
+        result,
+        Err(OperationError::WrongVectorDimension {
+            expected_dim: 2,
+            received_dim: 16,
+        }),
+    ));
+
+    let query_vector = [0b1111_0000 as f32, 0.0].into();
+    let res = segment
+        .search(
+            DEFAULT_VECTOR_NAME,
+            &query_vector,
+            &WithPayload::default(),
+            &false.into(),
+            None,
+            10,
+            None,
+        )
+        .unwrap();
+
+    assert_eq!(res.len(), 3);
+    assert_eq!(res[0].id, 0.into());
+    let scores = res.iter().map(|point| point.score).collect::<Vec<_>>();
+    for (score, expected) in scores.iter().zip(expected_scores) {
+        assert!((score - expected).abs() < 1e-6, "{scores:?}");
+    }
+
+    // Postprocessed scores are the actual distances
+    let distances = res[1..]
+        .iter()
+        .map(|point| distance.postprocess_score(point.score))
+        .collect::<Vec<_>>();
+    for (distance, expected) in distances.iter().zip(expected_distances) {
+        assert!((distance - expected).abs() < 1e-6, "{distances:?}");
+    }
+}
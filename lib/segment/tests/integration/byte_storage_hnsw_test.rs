@@ -84,6 +84,7 @@ fn compare_search_result(result_a: &[Vec<ScoredPointOffset>], result_b: &[Vec<Sc
 #[rstest]
 #[case::nearest(QueryVariant::Nearest, VectorStorageDatatype::Uint8, 32, 10)]
 #[case::nearest(QueryVariant::Nearest, VectorStorageDatatype::Float16, 32, 10)]
+#[case::nearest(QueryVariant::Nearest, VectorStorageDatatype::BFloat16, 32, 10)]
 #[case::discovery(QueryVariant::Discovery, VectorStorageDatatype::Uint8, 128, 20)]
 #[case::recommend(
     QueryVariant::RecommendBestScore,
@@ -128,8 +129,10 @@ fn test_byte_storage_hnsw(
                 storage_type: VectorStorageType::Memory,
                 index: Indexes::Plain {},
                 quantization_config: None,
+                additional_quantization_configs: vec![],
                 multivector_config: None,
                 datatype: None,
+                prefix_of: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -144,8 +147,10 @@ fn test_byte_storage_hnsw(
                 storage_type: VectorStorageType::Memory,
                 index: Indexes::Plain {},
                 quantization_config: None,
+                additional_quantization_configs: vec![],
                 multivector_config: None,
                 datatype: Some(storage_data_type),
+                prefix_of: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -165,6 +170,7 @@ fn test_byte_storage_hnsw(
         assert!(
             matches!(raw_storage, &VectorStorageEnum::DenseSimpleByte(_))
                 | matches!(raw_storage, &VectorStorageEnum::DenseSimpleHalf(_))
+                | matches!(raw_storage, &VectorStorageEnum::DenseSimpleBFloat16(_))
         );
     }
 
@@ -222,6 +228,7 @@ fn test_byte_storage_hnsw(
             .clone(),
         payload_index: segment_byte.payload_index.clone(),
         hnsw_config,
+        prefix_dim: None,
         permit: Some(permit),
         stopped: &stopped,
     })
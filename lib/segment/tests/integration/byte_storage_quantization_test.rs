@@ -52,12 +52,17 @@ where
 {
     match data_type {
         VectorStorageDatatype::Float32 => unreachable!(),
-        VectorStorageDatatype::Float16 => {
+        VectorStorageDatatype::Float16 | VectorStorageDatatype::BFloat16 => {
             let mut vector = segment::fixtures::payload_fixtures::random_vector(rnd_gen, dim);
             vector.iter_mut().for_each(|x| *x -= 0.5);
             vector
         }
-        VectorStorageDatatype::Uint8 => random_dense_byte_vector(rnd_gen, dim),
+        VectorStorageDatatype::Uint8 | VectorStorageDatatype::Bit => {
+            random_dense_byte_vector(rnd_gen, dim)
+        }
+        VectorStorageDatatype::Int8 => (0..dim)
+            .map(|_| rnd_gen.gen_range(i8::MIN..=i8::MAX) as f32)
+            .collect(),
     }
 }
 
@@ -201,6 +206,24 @@ fn sames_count(a: &[Vec<ScoredPointOffset>], b: &[Vec<ScoredPointOffset>]) -> us
     32, // ef
     80., // min_acc out of 100
 )]
+#[case::nearest_scalar_dot(
+    QueryVariant::Nearest,
+    VectorStorageDatatype::BFloat16,
+    QuantizationVariant::Scalar,
+    Distance::Dot,
+    32, // dim
+    32, // ef
+    80., // min_acc out of 100
+)]
+#[case::nearest_scalar_dot(
+    QueryVariant::Nearest,
+    VectorStorageDatatype::Int8,
+    QuantizationVariant::Scalar,
+    Distance::Dot,
+    32, // dim
+    32, // ef
+    80., // min_acc out of 100
+)]
 #[case::nearest_scalar_cosine(
     QueryVariant::Nearest,
     VectorStorageDatatype::Uint8,
@@ -251,8 +274,10 @@ fn test_byte_storage_binary_quantization_hnsw(
                 storage_type: VectorStorageType::Memory,
                 index: Indexes::Plain {},
                 quantization_config: None,
+                additional_quantization_configs: vec![],
                 multivector_config: None,
                 datatype: Some(storage_data_type),
+                prefix_of: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -271,6 +296,8 @@ fn test_byte_storage_binary_quantization_hnsw(
         assert!(
             matches!(raw_storage, &VectorStorageEnum::DenseSimpleByte(_))
                 | matches!(raw_storage, &VectorStorageEnum::DenseSimpleHalf(_))
+                | matches!(raw_storage, &VectorStorageEnum::DenseSimpleBFloat16(_))
+                | matches!(raw_storage, &VectorStorageEnum::DenseSimpleInt8(_))
         );
     }
 
@@ -307,7 +334,12 @@ fn test_byte_storage_binary_quantization_hnsw(
             always_ram: None,
         }
         .into(),
-        QuantizationVariant::Binary => BinaryQuantizationConfig { always_ram: None }.into(),
+        QuantizationVariant::Binary => BinaryQuantizationConfig {
+            always_ram: None,
+            encoding: None,
+            query_encoding: None,
+        }
+        .into(),
     };
 
     segment_byte
@@ -348,6 +380,7 @@ fn test_byte_storage_binary_quantization_hnsw(
             .clone(),
         payload_index: segment_byte.payload_index.clone(),
         hnsw_config,
+        prefix_dim: None,
         permit: Some(permit),
         stopped: &stopped,
     })
@@ -50,8 +50,10 @@ fn exact_search_test() {
                 storage_type: VectorStorageType::Memory,
                 index: Indexes::Plain {},
                 quantization_config: None,
+                additional_quantization_configs: vec![],
                 multivector_config: None,
                 datatype: None,
+                prefix_of: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -139,6 +141,7 @@ fn exact_search_test() {
             .clone(),
         payload_index: payload_index_ptr.clone(),
         hnsw_config,
+        prefix_dim: None,
         permit: Some(permit),
         stopped: &stopped,
     })
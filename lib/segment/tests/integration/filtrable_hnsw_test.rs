@@ -112,8 +112,10 @@ fn _test_filterable_hnsw(
                 storage_type: VectorStorageType::Memory,
                 index: Indexes::Plain {},
                 quantization_config: None,
+                additional_quantization_configs: vec![],
                 multivector_config: None,
                 datatype: None,
+                prefix_of: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -199,6 +201,7 @@ fn _test_filterable_hnsw(
         quantized_vectors: quantized_vectors.clone(),
         payload_index: payload_index_ptr.clone(),
         hnsw_config,
+        prefix_dim: None,
         permit: Some(permit),
         stopped: &stopped,
     })
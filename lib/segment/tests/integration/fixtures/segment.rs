@@ -9,7 +9,8 @@ use segment::segment::Segment;
 use segment::segment_constructor::build_segment;
 use segment::segment_constructor::simple_segment_constructor::build_simple_segment;
 use segment::types::{
-    Distance, Indexes, SegmentConfig, SparseVectorDataConfig, VectorDataConfig, VectorStorageType,
+    Distance, Indexes, SegmentConfig, SparseVectorDataConfig, SparseVectorStorageType,
+    VectorDataConfig, VectorStorageType,
 };
 use serde_json::json;
 use sparse::common::sparse_vector::SparseVector;
@@ -134,8 +135,10 @@ pub fn build_segment_3(path: &Path) -> Segment {
                         storage_type: VectorStorageType::Memory,
                         index: Indexes::Plain {},
                         quantization_config: None,
+                        additional_quantization_configs: vec![],
                         multivector_config: None,
                         datatype: None,
+                        prefix_of: None,
                     },
                 ),
                 (
@@ -146,8 +149,10 @@ pub fn build_segment_3(path: &Path) -> Segment {
                         storage_type: VectorStorageType::Memory,
                         index: Indexes::Plain {},
                         quantization_config: None,
+                        additional_quantization_configs: vec![],
                         multivector_config: None,
                         datatype: None,
+                        prefix_of: None,
                     },
                 ),
                 (
@@ -158,8 +163,10 @@ pub fn build_segment_3(path: &Path) -> Segment {
                         storage_type: VectorStorageType::Memory,
                         index: Indexes::Plain {},
                         quantization_config: None,
+                        additional_quantization_configs: vec![],
                         multivector_config: None,
                         datatype: None,
+                        prefix_of: None,
                     },
                 ),
             ]),
@@ -255,6 +262,7 @@ pub fn build_segment_sparse_1(path: &Path) -> Segment {
                 "sparse".to_owned(),
                 SparseVectorDataConfig {
                     index: SparseIndexConfig::new(None, SparseIndexType::MutableRam, None),
+                    storage_type: SparseVectorStorageType::default(),
                 },
             )]),
             payload_storage_type: Default::default(),
@@ -340,6 +348,7 @@ pub fn build_segment_sparse_2(path: &Path) -> Segment {
                 "sparse".to_owned(),
                 SparseVectorDataConfig {
                     index: SparseIndexConfig::new(None, SparseIndexType::MutableRam, None),
+                    storage_type: SparseVectorStorageType::default(),
                 },
             )]),
             payload_storage_type: Default::default(),
@@ -75,8 +75,10 @@ fn hnsw_discover_precision() {
                 storage_type: VectorStorageType::Memory,
                 index: Indexes::Plain {},
                 quantization_config: None,
+                additional_quantization_configs: vec![],
                 multivector_config: None,
                 datatype: None,
+                prefix_of: None,
             },
         )]),
         payload_storage_type: Default::default(),
@@ -117,6 +119,7 @@ fn hnsw_discover_precision() {
         quantized_vectors: quantized_vectors.clone(),
         payload_index: payload_index_ptr.clone(),
         hnsw_config,
+        prefix_dim: None,
         permit: Some(permit),
         stopped: &stopped,
     })
@@ -187,8 +190,10 @@ fn filtered_hnsw_discover_precision() {
                 storage_type: VectorStorageType::Memory,
                 index: Indexes::Plain {},
                 quantization_config: None,
+                additional_quantization_configs: vec![],
                 multivector_config: None,
                 datatype: None,
+                prefix_of: None,
             },
         )]),
         payload_storage_type: Default::default(),
@@ -240,6 +245,7 @@ fn filtered_hnsw_discover_precision() {
         quantized_vectors: quantized_vectors.clone(),
         payload_index: payload_index_ptr.clone(),
         hnsw_config,
+        prefix_dim: None,
         permit: Some(permit),
         stopped: &stopped,
     })
@@ -17,13 +17,14 @@ use segment::index::hnsw_index::num_rayon_threads;
 use segment::index::{VectorIndex, VectorIndexEnum};
 use segment::json_path::path;
 use segment::segment::Segment;
-use segment::segment_constructor::build_segment;
 use segment::segment_constructor::segment_builder::SegmentBuilder;
+use segment::segment_constructor::{build_segment, load_segment};
 use segment::types::PayloadSchemaType::Keyword;
 use segment::types::{
-    CompressionRatio, Condition, Distance, FieldCondition, Filter, HnswConfig, Indexes, Payload,
-    ProductQuantizationConfig, QuantizationConfig, QuantizationSearchParams,
-    ScalarQuantizationConfig, SearchParams, SegmentConfig, VectorDataConfig, VectorStorageType,
+    BinaryQuantizationConfig, CompressionRatio, Condition, Distance, FieldCondition, Filter,
+    HnswConfig, Indexes, Payload, ProductQuantizationConfig, QuantizationConfig, QuantizationKind,
+    QuantizationSearchParams, ScalarQuantizationConfig, ScalarType, SearchParams, SegmentConfig,
+    VectorDataConfig, VectorStorageType,
 };
 use segment::vector_storage::quantized::quantized_vectors::QuantizedVectors;
 use serde_json::json;
@@ -71,8 +72,10 @@ fn hnsw_quantized_search_test(
                 storage_type: VectorStorageType::Memory,
                 index: Indexes::Plain {},
                 quantization_config: None,
+                additional_quantization_configs: vec![],
                 multivector_config: None,
                 datatype: None,
+                prefix_of: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -140,6 +143,7 @@ fn hnsw_quantized_search_test(
             .clone(),
         payload_index: segment.payload_index.clone(),
         hnsw_config,
+        prefix_dim: None,
         permit: Some(permit),
         stopped: &stopped,
     })
@@ -356,6 +360,34 @@ fn hnsw_quantized_search_manhattan_test() {
     );
 }
 
+#[test]
+fn hnsw_quantized_search_int4_cosine_test() {
+    hnsw_quantized_search_test(
+        Distance::Cosine,
+        5003,
+        ScalarQuantizationConfig {
+            r#type: ScalarType::Int4,
+            quantile: None,
+            always_ram: None,
+        }
+        .into(),
+    );
+}
+
+#[test]
+fn hnsw_quantized_search_int4_euclid_test() {
+    hnsw_quantized_search_test(
+        Distance::Euclid,
+        5003,
+        ScalarQuantizationConfig {
+            r#type: ScalarType::Int4,
+            quantile: Some(0.99),
+            always_ram: None,
+        }
+        .into(),
+    );
+}
+
 #[test]
 fn hnsw_product_quantization_cosine_test() {
     hnsw_quantized_search_test(
@@ -446,3 +478,117 @@ fn test_build_hnsw_using_quantization() {
         _ => panic!("unexpected vector index type"),
     }
 }
+
+#[test]
+fn test_build_hnsw_using_multiple_quantizations() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+
+    let stopped = AtomicBool::new(false);
+
+    let segment1 = build_segment_1(dir.path());
+    let mut config = segment1.segment_config.clone();
+    let vector_data_config = config.vector_data.get_mut(DEFAULT_VECTOR_NAME).unwrap();
+    vector_data_config.quantization_config = Some(
+        ScalarQuantizationConfig {
+            r#type: Default::default(),
+            quantile: None,
+            always_ram: None,
+        }
+        .into(),
+    );
+    vector_data_config.additional_quantization_configs = vec![BinaryQuantizationConfig {
+        always_ram: None,
+        encoding: None,
+        query_encoding: None,
+    }
+    .into()];
+    vector_data_config.index = Indexes::Hnsw(HnswConfig {
+        m: 16,
+        ef_construct: 64,
+        full_scan_threshold: 16,
+        max_indexing_threads: 2,
+        on_disk: Some(false),
+        payload_m: None,
+    });
+
+    let permit_cpu_count = num_rayon_threads(0);
+    let permit = CpuPermit::dummy(permit_cpu_count as u32);
+
+    let mut builder = SegmentBuilder::new(dir.path(), temp_dir.path(), &config).unwrap();
+
+    builder.update_from(&segment1, &stopped).unwrap();
+
+    let built_segment: Segment = builder.build(permit, &stopped).unwrap();
+    let segment_path = built_segment.current_path.clone();
+    drop(built_segment);
+
+    // additional quantization must survive reload
+    let loaded_segment = load_segment(&segment_path, &stopped).unwrap().unwrap();
+    let vector_data = &loaded_segment.vector_data[DEFAULT_VECTOR_NAME];
+    let quantized_vectors = vector_data.quantized_vectors.borrow();
+    let quantized_vectors = quantized_vectors.as_ref().unwrap();
+
+    let selected_kind = |kind| {
+        quantized_vectors
+            .select(kind)
+            .unwrap()
+            .config()
+            .quantization_config
+            .kind()
+    };
+    assert_eq!(selected_kind(None), QuantizationKind::Scalar);
+    assert_eq!(
+        selected_kind(Some(QuantizationKind::Scalar)),
+        QuantizationKind::Scalar
+    );
+    assert_eq!(
+        selected_kind(Some(QuantizationKind::Binary)),
+        QuantizationKind::Binary
+    );
+    // quantization, which is not configured, can't be selected
+    assert!(quantized_vectors
+        .select(Some(QuantizationKind::Product))
+        .is_err());
+
+    // search with each quantization, rescored results must match exact search
+    let query: QueryVector = vec![1.0, 1.0, 1.0, 1.0].into();
+    let top = 3;
+    let exact_result = vector_data
+        .vector_index
+        .borrow()
+        .search(
+            &[&query],
+            None,
+            top,
+            Some(&SearchParams {
+                exact: true,
+                ..Default::default()
+            }),
+            &Default::default(),
+        )
+        .unwrap();
+    for kind in [QuantizationKind::Scalar, QuantizationKind::Binary] {
+        let result = vector_data
+            .vector_index
+            .borrow()
+            .search(
+                &[&query],
+                None,
+                top,
+                Some(&SearchParams {
+                    quantization: Some(QuantizationSearchParams {
+                        rescore: Some(true),
+                        oversampling: Some(2.0),
+                        quantization: Some(kind),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                &Default::default(),
+            )
+            .unwrap();
+        assert_eq!(result[0].len(), top);
+        assert_eq!(result[0][0].idx, exact_result[0][0].idx);
+    }
+}
@@ -1,6 +1,7 @@
 #![cfg(test)]
 
 pub mod batch_search_test;
+mod bit_vector_test;
 mod byte_storage_hnsw_test;
 pub mod byte_storage_quantization_test;
 pub mod disbalanced_vectors_test;
@@ -14,9 +15,12 @@ pub mod hnsw_quantized_search_test;
 mod multivector_filtrable_hnsw_test;
 mod multivector_hnsw_test;
 mod multivector_quantization_test;
+mod multivector_token_hnsw_test;
 pub mod nested_filtering_test;
 pub mod payload_index_test;
+mod prefix_view_test;
 pub mod scroll_filtering_test;
+mod search_explain_test;
 pub mod segment_builder_test;
 pub mod segment_tests;
 mod sparse_discover_test;
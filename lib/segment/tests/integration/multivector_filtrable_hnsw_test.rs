@@ -127,8 +127,10 @@ fn test_multi_filterable_hnsw(
                 storage_type: VectorStorageType::Memory,
                 index: Indexes::Plain {}, // uses plain index for comparison
                 quantization_config: None,
+                additional_quantization_configs: vec![],
                 multivector_config: Some(MultiVectorConfig::default()), // uses multivec config
                 datatype: None,
+                prefix_of: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -190,6 +192,7 @@ fn test_multi_filterable_hnsw(
         quantized_vectors: quantized_vectors.clone(),
         payload_index: payload_index_ptr.clone(),
         hnsw_config,
+        prefix_dim: None,
         permit: Some(permit),
         stopped: &stopped,
     })
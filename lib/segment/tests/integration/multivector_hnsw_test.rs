@@ -20,7 +20,9 @@ use segment::index::VectorIndex;
 use segment::json_path::path;
 use segment::segment_constructor::build_segment;
 use segment::spaces::metric::Metric;
-use segment::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use segment::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+};
 use segment::types::{
     Condition, Distance, FieldCondition, Filter, HnswConfig, Indexes, MultiVectorConfig, Payload,
     PayloadSchemaType, SegmentConfig, SeqNumberType, VectorDataConfig, VectorStorageType,
@@ -50,8 +52,10 @@ fn test_single_multi_and_dense_hnsw_equivalency() {
                 storage_type: VectorStorageType::Memory,
                 index: Indexes::Plain {},
                 quantization_config: None,
+                additional_quantization_configs: vec![],
                 multivector_config: None,
                 datatype: None,
+                prefix_of: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -94,6 +98,12 @@ fn test_single_multi_and_dense_hnsw_equivalency() {
             Distance::Manhattan => {
                 <ManhattanMetric as Metric<VectorElementType>>::preprocess(vector.clone())
             }
+            Distance::Hamming => {
+                <HammingMetric as Metric<VectorElementType>>::preprocess(vector.clone())
+            }
+            Distance::Jaccard => {
+                <JaccardMetric as Metric<VectorElementType>>::preprocess(vector.clone())
+            }
         };
         let vector_multi = MultiDenseVectorInternal::new(preprocessed_vector, vector.len());
 
@@ -145,6 +155,7 @@ fn test_single_multi_and_dense_hnsw_equivalency() {
         quantized_vectors: quantized_vectors.clone(),
         payload_index: segment.payload_index.clone(),
         hnsw_config: hnsw_config.clone(),
+        prefix_dim: None,
         permit: Some(permit.clone()),
         stopped: &stopped,
     })
@@ -159,6 +170,7 @@ fn test_single_multi_and_dense_hnsw_equivalency() {
         quantized_vectors: quantized_vectors.clone(),
         payload_index: segment.payload_index.clone(),
         hnsw_config,
+        prefix_dim: None,
         permit: Some(permit),
         stopped: &stopped,
     })
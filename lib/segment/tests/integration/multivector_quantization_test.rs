@@ -21,8 +21,9 @@ use segment::index::{PayloadIndex, VectorIndex};
 use segment::json_path::path;
 use segment::segment_constructor::build_segment;
 use segment::types::{
-    BinaryQuantizationConfig, CompressionRatio, Condition, Distance, FieldCondition, Filter,
-    HnswConfig, Indexes, MultiVectorConfig, Payload, PayloadSchemaType, ProductQuantizationConfig,
+    BinaryQuantizationConfig, BinaryQuantizationEncoding, BinaryQuantizationQueryEncoding,
+    CompressionRatio, Condition, Distance, FieldCondition, Filter, HnswConfig, Indexes,
+    MultiVectorConfig, Payload, PayloadSchemaType, ProductQuantizationConfig,
     QuantizationSearchParams, Range, ScalarQuantizationConfig, SearchParams, SegmentConfig,
     SeqNumberType, VectorDataConfig, VectorStorageType,
 };
@@ -44,6 +45,8 @@ enum QuantizationVariant {
     Scalar,
     PQ,
     Binary,
+    BinaryTwoBits,
+    BinaryScalarQuery,
 }
 
 fn random_vector<R: Rng + ?Sized>(rnd: &mut R, dim: usize) -> MultiDenseVectorInternal {
@@ -154,6 +157,24 @@ fn sames_count(a: &[Vec<ScoredPointOffset>], b: &[Vec<ScoredPointOffset>]) -> us
     false,
     15., // min_acc out of 100
 )]
+#[case::nearest_binary_two_bits_dot(
+    QueryVariant::Nearest,
+    QuantizationVariant::BinaryTwoBits,
+    Distance::Dot,
+    128, // dim
+    32, // ef
+    false,
+    25., // min_acc out of 100
+)]
+#[case::nearest_binary_scalar_query_dot(
+    QueryVariant::Nearest,
+    QuantizationVariant::BinaryScalarQuery,
+    Distance::Dot,
+    128, // dim
+    32, // ef
+    false,
+    25., // min_acc out of 100
+)]
 #[case::nearest_scalar_dot(
     QueryVariant::Nearest,
     QuantizationVariant::Scalar,
@@ -227,8 +248,10 @@ fn test_multivector_quantization_hnsw(
                 storage_type,
                 index: Indexes::Plain {},
                 quantization_config: None,
+                additional_quantization_configs: vec![],
                 multivector_config: Some(MultiVectorConfig::default()), // uses multivec config
                 datatype: None,
+                prefix_of: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -274,6 +297,20 @@ fn test_multivector_quantization_hnsw(
         .into(),
         QuantizationVariant::Binary => BinaryQuantizationConfig {
             always_ram: Some(false),
+            encoding: None,
+            query_encoding: None,
+        }
+        .into(),
+        QuantizationVariant::BinaryTwoBits => BinaryQuantizationConfig {
+            always_ram: Some(false),
+            encoding: Some(BinaryQuantizationEncoding::TwoBits),
+            query_encoding: None,
+        }
+        .into(),
+        QuantizationVariant::BinaryScalarQuery => BinaryQuantizationConfig {
+            always_ram: Some(false),
+            encoding: None,
+            query_encoding: Some(BinaryQuantizationQueryEncoding::Scalar8Bits),
         }
         .into(),
     };
@@ -319,6 +356,7 @@ fn test_multivector_quantization_hnsw(
             .clone(),
         payload_index: segment.payload_index.clone(),
         hnsw_config,
+        prefix_dim: None,
         permit: Some(permit),
         stopped: &stopped,
     })
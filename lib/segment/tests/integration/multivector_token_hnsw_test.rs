@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use common::cpu::CpuPermit;
+use common::types::TelemetryDetail;
+use itertools::Itertools;
+use rand::prelude::StdRng;
+use rand::{Rng, SeedableRng};
+use rstest::rstest;
+use segment::data_types::vectors::{only_default_multi_vector, QueryVector, DEFAULT_VECTOR_NAME};
+use segment::entry::entry_point::SegmentEntry;
+use segment::fixtures::payload_fixtures::{random_int_payload, random_multi_vector};
+use segment::index::hnsw_index::graph_links::GraphLinksRam;
+use segment::index::hnsw_index::hnsw::{HNSWIndex, HnswIndexOpenArgs};
+use segment::index::hnsw_index::num_rayon_threads;
+use segment::index::{PayloadIndex, VectorIndex};
+use segment::json_path::path;
+use segment::segment_constructor::build_segment;
+use segment::types::{
+    Condition, Distance, FieldCondition, Filter, HnswConfig, Indexes, MultiVectorConfig,
+    MultiVectorIndexMode, Payload, PayloadSchemaType, Range, SearchParams, SegmentConfig,
+    SeqNumberType, VectorDataConfig, VectorStorageType,
+};
+use segment::vector_storage::query::{ContextPair, DiscoveryQuery};
+use serde_json::json;
+use tempfile::Builder;
+
+fn random_multi_vec_discovery_query<R: Rng + ?Sized>(
+    rnd: &mut R,
+    dim: usize,
+    num_vector_per_points: usize,
+) -> QueryVector {
+    let target = random_multi_vector(rnd, dim, num_vector_per_points).into();
+    let pairs = (0..2)
+        .map(|_| {
+            let positive = random_multi_vector(rnd, dim, num_vector_per_points).into();
+            let negative = random_multi_vector(rnd, dim, num_vector_per_points).into();
+            ContextPair { positive, negative }
+        })
+        .collect_vec();
+
+    DiscoveryQuery::new(target, pairs).into()
+}
+
+/// Compare search over the token graph with the exact search of a plain index
+#[rstest]
+#[case::nearest(false, false, None, 10)]
+#[case::nearest_filtered(true, false, None, 10)]
+#[case::nearest_filtered_payload_links(true, false, Some(8), 10)]
+#[case::discovery_exact_fallback(false, true, None, 0)]
+fn test_multivector_token_hnsw(
+    #[case] filtered: bool,
+    #[case] discovery: bool,
+    #[case] payload_m: Option<usize>,
+    #[case] max_failures: usize, // out of 100
+) {
+    let stopped = AtomicBool::new(false);
+
+    let vector_dim = 8;
+    let m = 8;
+    let num_points: u64 = 2_000;
+    let max_num_vector_per_points = 4;
+    let ef_construct = 32;
+    let ef = 64;
+    let distance = Distance::Cosine;
+    let full_scan_threshold = 8; // KB
+    let num_payload_values = 2;
+
+    let mut rnd = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hnsw_dir = Builder::new().prefix("hnsw_dir").tempdir().unwrap();
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: vector_dim,
+                distance,
+                storage_type: VectorStorageType::Memory,
+                index: Indexes::Plain {}, // uses plain index for comparison
+                quantization_config: None,
+                additional_quantization_configs: vec![],
+                multivector_config: Some(MultiVectorConfig {
+                    index_mode: MultiVectorIndexMode::Token,
+                    ..Default::default()
+                }),
+                datatype: None,
+                prefix_of: None,
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+
+    let int_key = "int";
+
+    let mut segment = build_segment(dir.path(), &config, true).unwrap();
+    for n in 0..num_points {
+        let idx = n.into();
+        let num_vector_for_point = rnd.gen_range(1..=max_num_vector_per_points);
+        let multi_vec = random_multi_vector(&mut rnd, vector_dim, num_vector_for_point);
+
+        let int_payload = random_int_payload(&mut rnd, num_payload_values..=num_payload_values);
+        let payload: Payload = json!({int_key:int_payload,}).into();
+
+        let named_vectors = only_default_multi_vector(&multi_vec);
+        segment
+            .upsert_point(n as SeqNumberType, idx, named_vectors)
+            .unwrap();
+        segment
+            .set_full_payload(n as SeqNumberType, idx, &payload)
+            .unwrap();
+    }
+
+    let payload_index_ptr = segment.payload_index.clone();
+    payload_index_ptr
+        .borrow_mut()
+        .set_indexed(&path(int_key), PayloadSchemaType::Integer.into())
+        .unwrap();
+
+    let hnsw_config = HnswConfig {
+        m,
+        ef_construct,
+        full_scan_threshold,
+        max_indexing_threads: 2,
+        on_disk: Some(false),
+        payload_m,
+    };
+
+    let permit_cpu_count = num_rayon_threads(hnsw_config.max_indexing_threads);
+    let permit = Arc::new(CpuPermit::dummy(permit_cpu_count as u32));
+
+    let vector_storage = &segment.vector_data[DEFAULT_VECTOR_NAME].vector_storage;
+    let quantized_vectors = &segment.vector_data[DEFAULT_VECTOR_NAME].quantized_vectors;
+    let hnsw_index = HNSWIndex::<GraphLinksRam>::open(HnswIndexOpenArgs {
+        path: hnsw_dir.path(),
+        id_tracker: segment.id_tracker.clone(),
+        vector_storage: vector_storage.clone(),
+        quantized_vectors: quantized_vectors.clone(),
+        payload_index: payload_index_ptr.clone(),
+        hnsw_config,
+        prefix_dim: None,
+        permit: Some(permit),
+        stopped: &stopped,
+    })
+    .unwrap();
+
+    // Token mapping is persisted next to the graph
+    assert!(hnsw_index
+        .files()
+        .iter()
+        .any(|file| file.ends_with("tokens.bin")));
+    assert_eq!(hnsw_index.indexed_vector_count(), num_points as usize);
+
+    let top = 5;
+    let mut hits = 0;
+    let attempts = 100;
+    for i in 0..attempts {
+        let num_vector_for_query = rnd.gen_range(1..=max_num_vector_per_points);
+        let query = if discovery {
+            random_multi_vec_discovery_query(&mut rnd, vector_dim, num_vector_for_query)
+        } else {
+            random_multi_vector(&mut rnd, vector_dim, num_vector_for_query).into()
+        };
+
+        let left_range = rnd.gen_range(0..400);
+        let filter = Filter::new_must(Condition::Field(FieldCondition::new_range(
+            path(int_key),
+            Range {
+                lt: None,
+                gt: None,
+                gte: Some(left_range as f64),
+                lte: Some((left_range + 100) as f64),
+            },
+        )));
+        let filter_query = filtered.then_some(&filter);
+
+        let index_result = hnsw_index
+            .search(
+                &[&query],
+                filter_query,
+                top,
+                Some(&SearchParams {
+                    hnsw_ef: Some(ef),
+                    ..Default::default()
+                }),
+                &Default::default(),
+            )
+            .unwrap();
+
+        if !filtered {
+            // check that search was performed using HNSW index
+            assert_eq!(
+                hnsw_index
+                    .get_telemetry_data(TelemetryDetail::default())
+                    .unfiltered_hnsw
+                    .count,
+                i + 1
+            );
+        }
+
+        // segment uses a plain index by configuration
+        let plain_result = segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_index
+            .borrow()
+            .search(&[&query], filter_query, top, None, &Default::default())
+            .unwrap();
+
+        if plain_result == index_result {
+            hits += 1;
+        } else {
+            eprintln!("Attempt {}/{}", i, attempts);
+            eprintln!("plain_result = {:#?}", plain_result);
+            eprintln!("index_result = {:#?}", index_result);
+        }
+    }
+    assert!(
+        attempts - hits <= max_failures,
+        "hits: {hits}/{attempts} (expected less than {max_failures} failures)"
+    );
+    eprintln!("hits = {hits:#?} out of {attempts}");
+
+    // Tokens of the same points must not crowd out other points, even with a small `ef`
+    let query: QueryVector =
+        random_multi_vector(&mut rnd, vector_dim, max_num_vector_per_points).into();
+    let top = 50;
+    let index_result = hnsw_index
+        .search(
+            &[&query],
+            None,
+            top,
+            Some(&SearchParams {
+                hnsw_ef: Some(1),
+                ..Default::default()
+            }),
+            &Default::default(),
+        )
+        .unwrap();
+    assert_eq!(index_result[0].len(), top);
+}
@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+
+use common::cpu::CpuPermit;
+use segment::common::operation_error::OperationError;
+use segment::data_types::named_vectors::NamedVectors;
+use segment::data_types::vectors::{QueryVector, Vector};
+use segment::entry::entry_point::SegmentEntry;
+use segment::index::hnsw_index::num_rayon_threads;
+use segment::segment::Segment;
+use segment::segment_constructor::build_segment;
+use segment::segment_constructor::segment_builder::SegmentBuilder;
+use segment::types::{
+    Distance, HnswConfig, Indexes, SegmentConfig, VectorDataConfig, VectorStorageType, WithPayload,
+};
+use tempfile::Builder;
+
+const FULL_VECTOR_NAME: &str = "full";
+const PREFIX_VECTOR_NAME: &str = "prefix";
+const FULL_DIM: usize = 8;
+const PREFIX_DIM: usize = 2;
+const NUM_POINTS: u64 = 20;
+
+fn vector_config(size: usize, prefix_of: Option<&str>) -> VectorDataConfig {
+    VectorDataConfig {
+        size,
+        distance: Distance::Dot,
+        storage_type: VectorStorageType::Memory,
+        index: Indexes::Plain {},
+        quantization_config: None,
+        additional_quantization_configs: vec![],
+        multivector_config: None,
+        datatype: None,
+        prefix_of: prefix_of.map(ToString::to_string),
+    }
+}
+
+fn point_vector(idx: u64) -> Vec<f32> {
+    let mut vector = vec![idx as f32, 1.0];
+    vector.resize(FULL_DIM, (NUM_POINTS - idx) as f32);
+    vector
+}
+
+fn check_prefix_search(segment: &Segment) {
+    let query: QueryVector = vec![1.0, 1.0].into();
+    let res = segment
+        .search(
+            PREFIX_VECTOR_NAME,
+            &query,
+            &WithPayload::default(),
+            &false.into(),
+            None,
+            5,
+            None,
+        )
+        .unwrap();
+
+    assert_eq!(res.len(), 5);
+    for (rank, point) in res.iter().enumerate() {
+        let expected_idx = NUM_POINTS - 1 - rank as u64;
+        assert_eq!(point.id, expected_idx.into());
+        // Only the leading dimensions contribute to the score
+        assert!((point.score - (expected_idx as f32 + 1.0)).abs() < 1e-6);
+    }
+
+    // The full vector ranks points differently, as trailing dimensions dominate
+    let query: QueryVector = vec![1.0; FULL_DIM].into();
+    let res = segment
+        .search(
+            FULL_VECTOR_NAME,
+            &query,
+            &WithPayload::default(),
+            &false.into(),
+            None,
+            1,
+            None,
+        )
+        .unwrap();
+    assert_eq!(res[0].id, 0.into());
+}
+
+#[test]
+fn test_prefix_view_search() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+    let stopped = AtomicBool::new(false);
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([
+            (FULL_VECTOR_NAME.to_owned(), vector_config(FULL_DIM, None)),
+            (
+                PREFIX_VECTOR_NAME.to_owned(),
+                vector_config(PREFIX_DIM, Some(FULL_VECTOR_NAME)),
+            ),
+        ]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+    let mut segment = build_segment(dir.path(), &config, true).unwrap();
+
+    for idx in 0..NUM_POINTS {
+        let vectors = NamedVectors::from_pairs([(FULL_VECTOR_NAME.to_owned(), point_vector(idx))]);
+        segment.upsert_point(idx, idx.into(), vectors).unwrap();
+    }
+
+    // Prefix view exposes the leading dimensions of the source vector
+    let prefix_vector = segment.vector(PREFIX_VECTOR_NAME, 3.into()).unwrap();
+    assert_eq!(prefix_vector, Some(Vector::Dense(vec![3.0, 1.0])));
+
+    // But is not returned as a separate vector of the point
+    let all_vectors = segment.all_vectors(3.into()).unwrap();
+    assert!(all_vectors.get(PREFIX_VECTOR_NAME).is_none());
+    assert!(all_vectors.get(FULL_VECTOR_NAME).is_some());
+
+    // Prefix views can't be written directly
+    let vectors = NamedVectors::from_pairs([(PREFIX_VECTOR_NAME.to_owned(), vec![1.0, 1.0])]);
+    let result = segment.upsert_point(NUM_POINTS, NUM_POINTS.into(), vectors);
+    assert!(matches!(
+        result,
+        Err(OperationError::ValidationError { .. })
+    ));
+
+    check_prefix_search(&segment);
+
+    // Build an indexed segment, where the prefix view gets its own HNSW graph
+    let mut indexed_config = config.clone();
+    for vector_config in indexed_config.vector_data.values_mut() {
+        vector_config.index = Indexes::Hnsw(HnswConfig {
+            m: 8,
+            ef_construct: 64,
+            full_scan_threshold: 0,
+            max_indexing_threads: 2,
+            on_disk: Some(false),
+            payload_m: None,
+        });
+    }
+
+    let permit_cpu_count = num_rayon_threads(0);
+    let permit = CpuPermit::dummy(permit_cpu_count as u32);
+
+    let mut builder = SegmentBuilder::new(dir.path(), temp_dir.path(), &indexed_config).unwrap();
+    builder.update_from(&segment, &stopped).unwrap();
+    let built_segment: Segment = builder.build(permit, &stopped).unwrap();
+
+    check_prefix_search(&built_segment);
+}
+
+#[test]
+fn test_prefix_view_invalid_source() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([
+            (FULL_VECTOR_NAME.to_owned(), vector_config(FULL_DIM, None)),
+            (
+                PREFIX_VECTOR_NAME.to_owned(),
+                vector_config(FULL_DIM + 1, Some(FULL_VECTOR_NAME)),
+            ),
+        ]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+    let result = build_segment(dir.path(), &config, true);
+    assert!(matches!(
+        result,
+        Err(OperationError::ValidationError { .. })
+    ));
+}
+
+#[test]
+fn test_prefix_view_cosine_search() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+
+    let mut config = SegmentConfig {
+        vector_data: HashMap::from([
+            (FULL_VECTOR_NAME.to_owned(), vector_config(FULL_DIM, None)),
+            (
+                PREFIX_VECTOR_NAME.to_owned(),
+                vector_config(PREFIX_DIM, Some(FULL_VECTOR_NAME)),
+            ),
+        ]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+    for vector_config in config.vector_data.values_mut() {
+        vector_config.distance = Distance::Cosine;
+    }
+    let mut segment = build_segment(dir.path(), &config, true).unwrap();
+
+    for idx in 0..NUM_POINTS {
+        let vectors = NamedVectors::from_pairs([(FULL_VECTOR_NAME.to_owned(), point_vector(idx))]);
+        segment.upsert_point(idx, idx.into(), vectors).unwrap();
+    }
+
+    let query: QueryVector = vec![1.0, 0.0].into();
+    let res = segment
+        .search(
+            PREFIX_VECTOR_NAME,
+            &query,
+            &WithPayload::default(),
+            &false.into(),
+            None,
+            5,
+            None,
+        )
+        .unwrap();
+
+    assert_eq!(res.len(), 5);
+    for (rank, point) in res.iter().enumerate() {
+        let expected_idx = NUM_POINTS - 1 - rank as u64;
+        assert_eq!(point.id, expected_idx.into());
+        // Score is the cosine similarity of the prefix, not of the normalized full vector
+        let x = expected_idx as f32;
+        let expected_score = x / (x * x + 1.0).sqrt();
+        assert!((point.score - expected_score).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use atomic_refcell::AtomicRefCell;
+use common::cpu::CpuPermit;
+use parking_lot::Mutex;
+use rand::prelude::StdRng;
+use rand::SeedableRng;
+use segment::data_types::query_context::SegmentQueryContext;
+use segment::data_types::search_explain::{SearchStrategy, VectorSearchExplanation};
+use segment::data_types::vectors::{only_default_vector, QueryVector, DEFAULT_VECTOR_NAME};
+use segment::entry::entry_point::SegmentEntry;
+use segment::fixtures::payload_fixtures::random_vector;
+use segment::index::hnsw_index::graph_links::GraphLinksRam;
+use segment::index::hnsw_index::hnsw::{HNSWIndex, HnswIndexOpenArgs};
+use segment::index::hnsw_index::num_rayon_threads;
+use segment::index::VectorIndex;
+use segment::json_path::path;
+use segment::segment_constructor::build_segment;
+use segment::types::{
+    Condition, Distance, FieldCondition, Filter, HnswConfig, Indexes, Payload, PayloadSchemaType,
+    QuantizationSearchParams, Range, ScalarQuantizationConfig, SearchParams, SegmentConfig,
+    SeqNumberType, VectorDataConfig, VectorStorageType,
+};
+use segment::vector_storage::quantized::quantized_vectors::QuantizedVectors;
+use serde_json::json;
+use tempfile::Builder;
+
+const INT_KEY: &str = "int";
+
+/// Search the index and return the explanation of the search
+fn explain_search(
+    index: &impl VectorIndex,
+    query: &QueryVector,
+    filter: Option<&Filter>,
+    params: Option<&SearchParams>,
+) -> VectorSearchExplanation {
+    let explanation = Mutex::new(VectorSearchExplanation::default());
+
+    {
+        let segment_query_context = SegmentQueryContext::default().with_explanation(&explanation);
+        let vector_query_context = segment_query_context.get_vector_context(DEFAULT_VECTOR_NAME);
+
+        let result = index
+            .search(&[query], filter, 10, params, &vector_query_context)
+            .unwrap();
+        assert_eq!(result.len(), 1);
+        assert!(!result[0].is_empty());
+    }
+
+    explanation.into_inner()
+}
+
+/// Filter matching points with the given value of the integer field
+fn int_filter(value: i64) -> Filter {
+    Filter::new_must(Condition::Field(FieldCondition::new_match(
+        path(INT_KEY),
+        value.into(),
+    )))
+}
+
+#[test]
+fn test_search_explain() {
+    let stopped = AtomicBool::new(false);
+
+    let dim = 16;
+    let num_vectors: u64 = 2_000;
+    let num_int_values = 100;
+    let full_scan_threshold = 16; // KB, 256 vectors of this dimension
+
+    let mut rnd = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let quantized_dir = Builder::new().prefix("quantized_dir").tempdir().unwrap();
+    let hnsw_dir = Builder::new().prefix("hnsw_dir").tempdir().unwrap();
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: dim,
+                distance: Distance::Dot,
+                storage_type: VectorStorageType::Memory,
+                index: Indexes::Plain {},
+                quantization_config: None,
+                additional_quantization_configs: vec![],
+                multivector_config: None,
+                datatype: None,
+                prefix_of: None,
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+
+    let mut segment = build_segment(dir.path(), &config, true).unwrap();
+
+    let mut op_num: SeqNumberType = 0;
+    segment
+        .create_field_index(
+            op_num,
+            &path(INT_KEY),
+            Some(&PayloadSchemaType::Integer.into()),
+        )
+        .unwrap();
+
+    for n in 0..num_vectors {
+        op_num += 1;
+        let idx = n.into();
+        let vector = random_vector(&mut rnd, dim);
+        let payload: Payload = json!({ INT_KEY: n % num_int_values }).into();
+
+        segment
+            .upsert_point(op_num, idx, only_default_vector(&vector))
+            .unwrap();
+        segment.set_full_payload(op_num, idx, &payload).unwrap();
+    }
+
+    let query = random_vector(&mut rnd, dim).into();
+    let small_filter = int_filter(1);
+    let large_filter = Filter::new_must(Condition::Field(FieldCondition::new_range(
+        path(INT_KEY),
+        Range {
+            gte: Some(0.0),
+            ..Default::default()
+        },
+    )));
+
+    // Plain index scores all points, or the points selected by the payload index
+    {
+        let plain_index = segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_index
+            .borrow();
+
+        let explanation = explain_search(&*plain_index, &query, None, None);
+        assert_eq!(explanation.strategy, SearchStrategy::FullScan);
+        assert!(explanation.cardinality.is_none());
+        assert!(!explanation.quantization);
+        assert!(!explanation.rescoring);
+
+        let explanation = explain_search(&*plain_index, &query, Some(&small_filter), None);
+        assert_eq!(explanation.strategy, SearchStrategy::PayloadIndex);
+        assert!(!explanation.quantization);
+    }
+
+    segment.vector_data.values_mut().for_each(|vector_data| {
+        let quantized_vectors = QuantizedVectors::create(
+            &vector_data.vector_storage.borrow(),
+            &ScalarQuantizationConfig {
+                r#type: Default::default(),
+                quantile: None,
+                always_ram: None,
+            }
+            .into(),
+            quantized_dir.path(),
+            4,
+            &stopped,
+        )
+        .unwrap();
+        vector_data.quantized_vectors = Arc::new(AtomicRefCell::new(Some(quantized_vectors)));
+    });
+
+    let hnsw_config = HnswConfig {
+        m: 16,
+        ef_construct: 64,
+        full_scan_threshold,
+        max_indexing_threads: 2,
+        on_disk: Some(false),
+        payload_m: None,
+    };
+
+    let permit_cpu_count = num_rayon_threads(hnsw_config.max_indexing_threads);
+    let permit = Arc::new(CpuPermit::dummy(permit_cpu_count as u32));
+    let hnsw_index = HNSWIndex::<GraphLinksRam>::open(HnswIndexOpenArgs {
+        path: hnsw_dir.path(),
+        id_tracker: segment.id_tracker.clone(),
+        vector_storage: segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_storage
+            .clone(),
+        quantized_vectors: segment.vector_data[DEFAULT_VECTOR_NAME]
+            .quantized_vectors
+            .clone(),
+        payload_index: segment.payload_index.clone(),
+        hnsw_config,
+        prefix_dim: None,
+        permit: Some(permit),
+        stopped: &stopped,
+    })
+    .unwrap();
+
+    let rescore_params = SearchParams {
+        quantization: Some(QuantizationSearchParams {
+            rescore: Some(true),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let ignore_quantization_params = SearchParams {
+        quantization: Some(QuantizationSearchParams {
+            ignore: true,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let exact_params = SearchParams {
+        exact: true,
+        ..Default::default()
+    };
+
+    // Graph search without filter, scalar quantization is not rescored by default
+    let explanation = explain_search(&hnsw_index, &query, None, None);
+    assert_eq!(explanation.strategy, SearchStrategy::Hnsw);
+    assert!(explanation.cardinality.is_none());
+    assert!(explanation.quantization);
+    assert!(!explanation.rescoring);
+
+    let explanation = explain_search(&hnsw_index, &query, None, Some(&rescore_params));
+    assert_eq!(explanation.strategy, SearchStrategy::Hnsw);
+    assert!(explanation.quantization);
+    assert!(explanation.rescoring);
+
+    let explanation = explain_search(&hnsw_index, &query, None, Some(&ignore_quantization_params));
+    assert_eq!(explanation.strategy, SearchStrategy::Hnsw);
+    assert!(!explanation.quantization);
+    assert!(!explanation.rescoring);
+
+    // Exact search scores all points
+    let explanation = explain_search(&hnsw_index, &query, None, Some(&exact_params));
+    assert_eq!(explanation.strategy, SearchStrategy::FullScan);
+
+    // Few matching points are selected with the payload index
+    let explanation = explain_search(
+        &hnsw_index,
+        &query,
+        Some(&small_filter),
+        Some(&rescore_params),
+    );
+    assert_eq!(explanation.strategy, SearchStrategy::PayloadIndex);
+    let cardinality = explanation.cardinality.unwrap();
+    assert!(cardinality.max < num_vectors as usize);
+    assert!(!cardinality.primary_clauses.is_empty());
+    assert!(explanation.quantization);
+    assert!(explanation.rescoring);
+
+    // Exact search with filter doesn't estimate cardinality, and never uses quantization
+    let explanation = explain_search(
+        &hnsw_index,
+        &query,
+        Some(&small_filter),
+        Some(&exact_params),
+    );
+    assert_eq!(explanation.strategy, SearchStrategy::PayloadIndex);
+    assert!(explanation.cardinality.is_none());
+    assert!(!explanation.quantization);
+    assert!(!explanation.rescoring);
+
+    // Many matching points are searched through the graph, checking the filter
+    let explanation = explain_search(&hnsw_index, &query, Some(&large_filter), None);
+    assert_eq!(explanation.strategy, SearchStrategy::HnswFiltered);
+    let cardinality = explanation.cardinality.unwrap();
+    assert!(!cardinality.primary_clauses.is_empty());
+    assert!(explanation.quantization);
+}
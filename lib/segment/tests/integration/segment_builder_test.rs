@@ -7,12 +7,21 @@ use common::cpu::CpuPermit;
 use itertools::Itertools;
 use segment::common::operation_error::OperationError;
 use segment::data_types::named_vectors::NamedVectors;
-use segment::data_types::vectors::{only_default_vector, VectorRef, DEFAULT_VECTOR_NAME};
+use segment::data_types::vectors::{
+    only_default_vector, QueryVector, VectorRef, DEFAULT_VECTOR_NAME,
+};
 use segment::entry::entry_point::SegmentEntry;
 use segment::index::hnsw_index::num_rayon_threads;
+use segment::index::sparse_index::sparse_index_config::{SparseIndexConfig, SparseIndexType};
 use segment::segment::Segment;
+use segment::segment_constructor::build_segment;
 use segment::segment_constructor::segment_builder::SegmentBuilder;
-use segment::types::{Indexes, SegmentConfig, VectorDataConfig, VectorStorageType};
+use segment::types::{
+    Indexes, SegmentConfig, SparseVectorDataConfig, SparseVectorStorageType, VectorDataConfig,
+    VectorStorageType, WithPayload,
+};
+use segment::vector_storage::VectorStorageEnum;
+use serde_json::json;
 use sparse::common::sparse_vector::SparseVector;
 use tempfile::Builder;
 
@@ -143,6 +152,104 @@ fn test_building_new_sparse_segment() {
     assert_eq!(merged_segment.point_version(3.into()), Some(100));
 }
 
+#[test]
+fn test_building_mmap_sparse_segment_from_legacy_config() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+
+    let stopped = AtomicBool::new(false);
+
+    // Config of a segment created before sparse storage type was configurable
+    let legacy_sparse_config: SparseVectorDataConfig = serde_json::from_value(json!({
+        "index": SparseIndexConfig::new(None, SparseIndexType::MutableRam, None),
+    }))
+    .unwrap();
+    assert_eq!(
+        legacy_sparse_config.storage_type,
+        SparseVectorStorageType::OnDisk,
+    );
+
+    let legacy_config = SegmentConfig {
+        vector_data: Default::default(),
+        sparse_vector_data: HashMap::from([("sparse".to_owned(), legacy_sparse_config)]),
+        payload_storage_type: Default::default(),
+    };
+    let mut segment = build_segment(dir.path(), &legacy_config, true).unwrap();
+
+    for idx in 1..=10u64 {
+        let vector = SparseVector::new(
+            vec![0, 1, 2, idx as u32 + 2],
+            vec![idx as f32, 1.0, 0.5, 2.0],
+        )
+        .unwrap();
+        segment
+            .upsert_point(
+                idx,
+                idx.into(),
+                NamedVectors::from_ref("sparse", VectorRef::Sparse(&vector)),
+            )
+            .unwrap();
+    }
+    segment.delete_point(11, 2.into()).unwrap();
+    segment.delete_vector(12, 4.into(), "sparse").unwrap();
+
+    let mut mmap_config = legacy_config.clone();
+    for sparse_config in mmap_config.sparse_vector_data.values_mut() {
+        sparse_config.storage_type = SparseVectorStorageType::Mmap;
+    }
+
+    let mut builder = SegmentBuilder::new(dir.path(), temp_dir.path(), &mmap_config).unwrap();
+    builder.update_from(&segment, &stopped).unwrap();
+
+    let permit_cpu_count = num_rayon_threads(0);
+    let permit = CpuPermit::dummy(permit_cpu_count as u32);
+    let built_segment: Segment = builder.build(permit, &stopped).unwrap();
+
+    assert!(matches!(
+        &*built_segment.vector_data["sparse"].vector_storage.borrow(),
+        VectorStorageEnum::SparseMmap(_),
+    ));
+
+    // Deleted points and vectors stay deleted
+    assert!(!built_segment.has_point(2.into()));
+    assert_eq!(built_segment.vector("sparse", 4.into()).unwrap(), None);
+    assert_eq!(
+        built_segment.available_point_count(),
+        segment.available_point_count(),
+    );
+
+    for point_id in segment.iter_points() {
+        assert_eq!(
+            built_segment.vector("sparse", point_id).unwrap(),
+            segment.vector("sparse", point_id).unwrap(),
+        );
+    }
+
+    let query: QueryVector = SparseVector::new(vec![0, 1, 5], vec![1.0, 1.0, 1.0])
+        .unwrap()
+        .into();
+    let search = |segment: &Segment| {
+        segment
+            .search(
+                "sparse",
+                &query,
+                &WithPayload::default(),
+                &false.into(),
+                None,
+                10,
+                None,
+            )
+            .unwrap()
+    };
+    let expected = search(&segment);
+    let result = search(&built_segment);
+    assert_eq!(result.len(), expected.len());
+    for (found, expected) in result.iter().zip(&expected) {
+        assert_eq!(found.id, expected.id);
+        assert!((found.score - expected.score).abs() < 1e-6);
+    }
+}
+
 fn estimate_build_time(segment: &Segment, stop_delay_millis: Option<u64>) -> (u64, bool) {
     let stopped = Arc::new(AtomicBool::new(false));
 
@@ -158,8 +265,10 @@ fn estimate_build_time(segment: &Segment, stop_delay_millis: Option<u64>) -> (u6
                 storage_type: VectorStorageType::Memory,
                 index: Indexes::Hnsw(Default::default()),
                 quantization_config: None,
+                additional_quantization_configs: vec![],
                 multivector_config: None,
                 datatype: None,
+                prefix_of: None,
             },
         )]),
         sparse_vector_data: Default::default(),
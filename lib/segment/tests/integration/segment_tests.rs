@@ -5,14 +5,20 @@ use std::sync::atomic::AtomicBool;
 use itertools::Itertools;
 use segment::common::operation_error::OperationError;
 use segment::data_types::named_vectors::NamedVectors;
+use segment::data_types::order_by::{Direction, OrderBy, OrderByKey};
 use segment::data_types::vectors::{
     only_default_vector, VectorRef, VectorStructInternal, DEFAULT_VECTOR_NAME,
 };
 use segment::entry::entry_point::SegmentEntry;
 use segment::fixtures::index_fixtures::random_vector;
+use segment::json_path::path;
 use segment::segment_constructor::load_segment;
 use segment::segment_constructor::simple_segment_constructor::build_simple_segment;
-use segment::types::{Condition, Distance, Filter, SearchParams, WithPayload};
+use segment::types::{
+    Condition, Distance, Filter, GeoPoint, Payload, PayloadSchemaType, PointIdType, SearchParams,
+    SeqNumberType, WithPayload,
+};
+use serde_json::json;
 use tempfile::Builder;
 
 use crate::fixtures::segment::{build_segment_1, build_segment_3};
@@ -328,3 +334,93 @@ fn test_update_named_vector() {
     // check that nearests are the same
     assert_eq!(nearest_upsert.id, nearest_update.id);
 }
+
+#[test]
+fn test_order_by_geo_distance_then_by() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let mut segment = build_simple_segment(dir.path(), 4, Distance::Dot).unwrap();
+
+    segment
+        .create_field_index(0, &path("location"), Some(&PayloadSchemaType::Geo.into()))
+        .unwrap();
+    segment
+        .create_field_index(1, &path("rating"), Some(&PayloadSchemaType::Integer.into()))
+        .unwrap();
+
+    // (id, lon, lat, rating)
+    let stores = [
+        (1, 0.0, 0.0, 3),
+        (2, 0.0, 0.0, 5),
+        (3, 1.0, 1.0, 4),
+        (4, 0.5, 0.5, 1),
+        (5, 0.5, 0.5, 2),
+    ];
+
+    for (n, (id, lon, lat, rating)) in stores.into_iter().enumerate() {
+        let op_num = n as SeqNumberType + 2;
+        segment
+            .upsert_point(op_num, id.into(), only_default_vector(&[1.0; 4]))
+            .unwrap();
+        let payload: Payload = json!({
+            "location": { "lon": lon, "lat": lat },
+            "rating": rating,
+        })
+        .into();
+        segment
+            .set_full_payload(op_num, id.into(), &payload)
+            .unwrap();
+    }
+
+    let order_by = OrderBy {
+        key: path("location"),
+        direction: None,
+        start_from: None,
+        geo_distance_from: Some(GeoPoint { lon: 0.0, lat: 0.0 }),
+        then_by: vec![OrderByKey {
+            key: path("rating"),
+            direction: Some(Direction::Desc),
+            geo_distance_from: None,
+        }],
+    };
+
+    let read_ids = |limit: Option<usize>, filter: Option<&Filter>| {
+        segment
+            .read_ordered_filtered(limit, filter, &order_by)
+            .unwrap()
+            .into_iter()
+            .map(|(_, id)| id)
+            .collect_vec()
+    };
+
+    // Closest stores first, then by rating desc
+    assert_eq!(
+        read_ids(None, None),
+        vec![2.into(), 1.into(), 5.into(), 4.into(), 3.into()],
+    );
+    assert_eq!(read_ids(Some(3), None), vec![2.into(), 1.into(), 5.into()]);
+
+    let excluded_ids: HashSet<PointIdType> = HashSet::from([2.into()]);
+    let filter = Filter::new_must_not(Condition::HasId(excluded_ids.into()));
+    assert_eq!(read_ids(Some(2), Some(&filter)), vec![1.into(), 5.into()],);
+
+    // Rating asc, then closest stores first, with ties cut by the limit
+    let order_by = OrderBy {
+        key: path("rating"),
+        direction: Some(Direction::Asc),
+        start_from: None,
+        geo_distance_from: None,
+        then_by: vec![OrderByKey {
+            key: path("location"),
+            direction: None,
+            geo_distance_from: Some(GeoPoint { lon: 1.0, lat: 1.0 }),
+        }],
+    };
+    let values = segment
+        .read_ordered_filtered(Some(2), None, &order_by)
+        .unwrap();
+    assert_eq!(
+        values.iter().map(|(_, id)| *id).collect_vec(),
+        vec![4.into(), 5.into()],
+    );
+    assert_eq!(values[0].0.len(), 2);
+}
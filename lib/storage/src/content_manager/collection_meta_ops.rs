@@ -1,6 +1,8 @@
 use std::collections::BTreeMap;
 
-use collection::config::{CollectionConfig, ShardingMethod};
+use collection::config::{
+    CollectionConfig, PayloadValidationConfig, ShardingMethod, StrictModeConfig,
+};
 use collection::operations::config_diff::{
     CollectionParamsDiff, HnswConfigDiff, OptimizersConfigDiff, QuantizationConfigDiff,
     WalConfigDiff,
@@ -14,9 +16,10 @@ use collection::shards::shard::{PeerId, ShardId, ShardsPlacement};
 use collection::shards::transfer::{ShardTransfer, ShardTransferKey, ShardTransferRestart};
 use collection::shards::{replica_set, CollectionId};
 use schemars::JsonSchema;
+use segment::json_path::JsonPath;
 use segment::types::{PayloadFieldSchema, PayloadKeyType, QuantizationConfig, ShardKey};
 use serde::{Deserialize, Serialize};
-use validator::Validate;
+use validator::{Validate, ValidationError};
 
 use crate::content_manager::shard_distribution::ShardDistributionProposal;
 
@@ -103,6 +106,7 @@ pub struct InitFrom {
 
 /// Operation for creating new collection and (optionally) specify index params
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, PartialEq, Eq, Hash, Clone)]
+#[validate(schema(function = "validate_create_collection"))]
 #[serde(rename_all = "snake_case")]
 pub struct CreateCollection {
     /// Vector data config.
@@ -165,6 +169,46 @@ pub struct CreateCollection {
     /// Sparse vector data config.
     #[validate]
     pub sparse_vectors: Option<BTreeMap<String, SparseVectorParams>>,
+    /// Strict mode guardrails against expensive requests. If none - strict mode is disabled.
+    #[serde(default)]
+    #[validate]
+    pub strict_mode_config: Option<StrictModeConfig>,
+    /// Rules for payload values of upserted points. If none - payload is not validated.
+    #[serde(default)]
+    pub payload_validation_config: Option<PayloadValidationConfig>,
+    /// Payload field with the expiry time of each point, as an RFC 3339 datetime.
+    /// Expired points are hidden from all reads and removed in background.
+    /// If none - points never expire.
+    #[serde(default)]
+    pub expiry_field: Option<JsonPath>,
+    /// Number of seconds deleted points are kept in trash, hidden from reads, so they can be restored.
+    /// After that, they are purged by the optimizer.
+    /// If none - deleted points are removed right away.
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub trash_retention_sec: Option<u64>,
+}
+
+/// Collection quantization applies to every vector without a quantization config of its own,
+/// so it can't be used with bit distances, which don't support quantization
+fn validate_create_collection(operation: &CreateCollection) -> Result<(), ValidationError> {
+    if operation.quantization_config.is_none() {
+        return Ok(());
+    }
+
+    let has_bit_distance = operation
+        .vectors
+        .params_iter()
+        .any(|(_, params)| params.distance.is_bit());
+    if has_bit_distance {
+        let mut error = ValidationError::new("quantization_config");
+        error.message = Some(
+            "collection quantization can't be used with `hamming` and `jaccard` distances".into(),
+        );
+        return Err(error);
+    }
+
+    Ok(())
 }
 
 /// Operation for creating new collection and (optionally) specify index params
@@ -222,6 +266,13 @@ pub struct UpdateCollection {
     /// Map of sparse vector data parameters to update for each sparse vector.
     #[validate]
     pub sparse_vectors: Option<SparseVectorsConfig>,
+    /// Strict mode parameters to update. If none - it is left unchanged.
+    #[serde(default)]
+    #[validate]
+    pub strict_mode_config: Option<StrictModeConfig>,
+    /// Payload validation rules, replacing the existing ones. If none - they are left unchanged.
+    #[serde(default)]
+    pub payload_validation_config: Option<PayloadValidationConfig>,
 }
 
 /// Operation for updating parameters of the existing collection
@@ -244,6 +295,8 @@ impl UpdateCollectionOperation {
                 optimizers_config: None,
                 quantization_config: None,
                 sparse_vectors: None,
+                strict_mode_config: None,
+                payload_validation_config: None,
             },
             shard_replica_changes: None,
         }
@@ -405,6 +458,10 @@ impl From<CollectionConfig> for CreateCollection {
             init_from: None,
             quantization_config: value.quantization_config,
             sparse_vectors: value.params.sparse_vectors,
+            strict_mode_config: value.strict_mode_config,
+            payload_validation_config: value.payload_validation_config,
+            expiry_field: value.params.expiry_field,
+            trash_retention_sec: value.params.trash_retention_sec,
         }
     }
 }
@@ -1,6 +1,9 @@
+use api::grpc::conversions::json_path_from_proto;
 use collection::operations::conversions::sharding_method_from_proto;
 use collection::operations::types::SparseVectorsConfig;
+use collection::operations::validation;
 use tonic::Status;
+use validator::Validate as _;
 
 use crate::content_manager::collection_meta_ops::{
     AliasOperations, ChangeAliasesOperation, CollectionMetaOperations, CreateAlias,
@@ -30,37 +33,55 @@ impl TryFrom<api::grpc::qdrant::CreateCollection> for CollectionMetaOperations {
     type Error = Status;
 
     fn try_from(value: api::grpc::qdrant::CreateCollection) -> Result<Self, Self::Error> {
+        let create_collection = CreateCollection {
+            vectors: match value.vectors_config.and_then(|config| config.config) {
+                Some(vector_config) => vector_config.try_into()?,
+                // TODO(sparse): sparse or dense vectors config is required
+                None => Default::default(),
+            },
+            sparse_vectors: value
+                .sparse_vectors_config
+                .map(|v| SparseVectorsConfig::try_from(v).map(|SparseVectorsConfig(x)| x))
+                .transpose()?,
+            hnsw_config: value.hnsw_config.map(|v| v.into()),
+            wal_config: value.wal_config.map(|v| v.into()),
+            optimizers_config: value.optimizers_config.map(|v| v.into()),
+            shard_number: value.shard_number,
+            on_disk_payload: value.on_disk_payload,
+            replication_factor: value.replication_factor,
+            write_consistency_factor: value.write_consistency_factor,
+            init_from: value
+                .init_from_collection
+                .map(|v| InitFrom { collection: v }),
+            quantization_config: value
+                .quantization_config
+                .map(TryInto::try_into)
+                .transpose()?,
+            sharding_method: value
+                .sharding_method
+                .map(sharding_method_from_proto)
+                .transpose()?,
+            strict_mode_config: value.strict_mode_config.map(Into::into),
+            payload_validation_config: value
+                .payload_validation_config
+                .map(TryInto::try_into)
+                .transpose()?,
+            expiry_field: value
+                .expiry_field
+                .as_deref()
+                .map(json_path_from_proto)
+                .transpose()?,
+            trash_retention_sec: value.trash_retention_sec,
+        };
+
+        // Fields of the gRPC request are validated one by one, check them against each other
+        create_collection.validate().map_err(|ref err| {
+            Status::invalid_argument(validation::label_errors("Validation error in body", err))
+        })?;
+
         Ok(Self::CreateCollection(CreateCollectionOperation::new(
             value.collection_name,
-            CreateCollection {
-                vectors: match value.vectors_config.and_then(|config| config.config) {
-                    Some(vector_config) => vector_config.try_into()?,
-                    // TODO(sparse): sparse or dense vectors config is required
-                    None => Default::default(),
-                },
-                sparse_vectors: value
-                    .sparse_vectors_config
-                    .map(|v| SparseVectorsConfig::try_from(v).map(|SparseVectorsConfig(x)| x))
-                    .transpose()?,
-                hnsw_config: value.hnsw_config.map(|v| v.into()),
-                wal_config: value.wal_config.map(|v| v.into()),
-                optimizers_config: value.optimizers_config.map(|v| v.into()),
-                shard_number: value.shard_number,
-                on_disk_payload: value.on_disk_payload,
-                replication_factor: value.replication_factor,
-                write_consistency_factor: value.write_consistency_factor,
-                init_from: value
-                    .init_from_collection
-                    .map(|v| InitFrom { collection: v }),
-                quantization_config: value
-                    .quantization_config
-                    .map(TryInto::try_into)
-                    .transpose()?,
-                sharding_method: value
-                    .sharding_method
-                    .map(sharding_method_from_proto)
-                    .transpose()?,
-            },
+            create_collection,
         )))
     }
 }
@@ -88,6 +109,11 @@ impl TryFrom<api::grpc::qdrant::UpdateCollection> for CollectionMetaOperations {
                     .sparse_vectors_config
                     .map(TryInto::try_into)
                     .transpose()?,
+                strict_mode_config: value.strict_mode_config.map(Into::into),
+                payload_validation_config: value
+                    .payload_validation_config
+                    .map(TryInto::try_into)
+                    .transpose()?,
             },
         )))
     }
@@ -130,6 +130,8 @@ pub mod consensus_ops {
                     hnsw_config: None,
                     quantization_config: None,
                     sparse_vectors: None,
+                    strict_mode_config: None,
+                    payload_validation_config: None,
                 },
             );
             operation
@@ -44,6 +44,10 @@ impl TableOfContent {
             init_from,
             quantization_config,
             sparse_vectors,
+            strict_mode_config,
+            expiry_field,
+            payload_validation_config,
+            trash_retention_sec,
         } = operation;
 
         self.collections
@@ -150,6 +154,8 @@ impl TableOfContent {
                 },
             )?,
             read_fan_out_factor: None,
+            expiry_field,
+            trash_retention_sec,
         };
         let wal_config = match wal_config_diff {
             None => self.storage_config.wal.clone(),
@@ -186,6 +192,8 @@ impl TableOfContent {
             optimizer_config: optimizers_config,
             hnsw_config,
             quantization_config,
+            strict_mode_config,
+            payload_validation_config,
         };
         let collection = Collection::new(
             collection_name.to_string(),
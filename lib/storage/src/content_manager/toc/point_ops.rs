@@ -9,14 +9,17 @@ use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::types::*;
 use collection::operations::universal_query::collection_query::CollectionQueryRequest;
 use collection::operations::{CollectionUpdateOperations, OperationWithClockTag};
+use collection::shards::shard::ShardId;
+use collection::wal_delta::WalUpdatesReader;
 use collection::{discovery, recommendations};
 use futures::stream::FuturesUnordered;
 use futures::TryStreamExt as _;
+use segment::data_types::search_explain::SearchExplanation;
 use segment::types::{ScoredPoint, ShardKey};
 
 use super::TableOfContent;
 use crate::content_manager::errors::StorageError;
-use crate::rbac::Access;
+use crate::rbac::{Access, AccessRequirements};
 
 impl TableOfContent {
     /// Recommend points using positive and negative example from the request
@@ -129,6 +132,36 @@ impl TableOfContent {
             .map_err(|err| err.into())
     }
 
+    /// Search for the closest points, explaining how each shard and segment executed the search
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_name` - in what collection do we search
+    /// * `request` - [`CoreSearchRequest`]
+    /// * `shard_selection` - which local shard to use
+    /// * `timeout` - how long to wait for the response
+    ///
+    /// # Result
+    ///
+    /// Points with search score, and the explanation of the search
+    pub async fn explain_search(
+        &self,
+        collection_name: &str,
+        mut request: CoreSearchRequest,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: ShardSelectorInternal,
+        access: Access,
+        timeout: Option<Duration>,
+    ) -> Result<(Vec<ScoredPoint>, SearchExplanation), StorageError> {
+        let collection_pass = access.check_point_op(collection_name, &mut request)?;
+
+        let collection = self.get_collection(&collection_pass).await?;
+        collection
+            .explain_search(request, read_consistency, shard_selection, timeout)
+            .await
+            .map_err(|err| err.into())
+    }
+
     /// Count points in the collection.
     ///
     /// # Arguments
@@ -318,6 +351,25 @@ impl TableOfContent {
             .map_err(|err| err.into())
     }
 
+    /// Query the collection, explaining how each shard and segment executed the searches
+    pub async fn explain_query(
+        &self,
+        collection_name: &str,
+        mut request: CollectionQueryRequest,
+        shard_selection: ShardSelectorInternal,
+        read_consistency: Option<ReadConsistency>,
+        access: Access,
+        timeout: Option<Duration>,
+    ) -> Result<(Vec<ScoredPoint>, SearchExplanation), StorageError> {
+        let collection_pass = access.check_point_op(collection_name, &mut request)?;
+
+        let collection = self.get_collection(&collection_pass).await?;
+        collection
+            .explain_query(request, shard_selection, read_consistency, timeout)
+            .await
+            .map_err(|err| err.into())
+    }
+
     /// # Cancel safety
     ///
     /// This method is cancel safe.
@@ -465,4 +517,108 @@ impl TableOfContent {
 
         Ok(res)
     }
+
+    /// Update points selected by filter, after the safety checks of the request.
+    ///
+    /// Points affected by the operation are counted first. In dry-run mode they are only
+    /// reported, and with `max_affected_points` the operation is rejected if there are more.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_with_checks(
+        &self,
+        collection_name: &str,
+        operation: OperationWithClockTag,
+        wait: bool,
+        ordering: WriteOrdering,
+        shard_selector: ShardSelectorInternal,
+        access: Access,
+        checks: FilterUpdateChecks,
+    ) -> Result<UpdateResult, StorageError> {
+        if checks.is_empty() {
+            return self
+                .update(
+                    collection_name,
+                    operation,
+                    wait,
+                    ordering,
+                    shard_selector,
+                    access,
+                )
+                .await;
+        }
+
+        // Count points with the same restrictions of the filter, as the operation will have
+        let mut checked_operation = operation.operation.clone();
+        let collection_pass = access.check_point_op(collection_name, &mut checked_operation)?;
+
+        if checked_operation.filter_mut().is_none() {
+            return Err(StorageError::bad_request(
+                "Dry run and affected points limit are only supported for operations selecting points by filter",
+            ));
+        }
+
+        let collection = self.get_collection(&collection_pass).await?;
+        let count_shard_selector = match &shard_selector {
+            ShardSelectorInternal::Empty => ShardSelectorInternal::All,
+            shard_selector => shard_selector.clone(),
+        };
+        let affected = collection
+            .affected_points(checked_operation, &count_shard_selector)
+            .await?;
+
+        if checks.dry_run {
+            return Ok(UpdateResult {
+                operation_id: None,
+                status: UpdateStatus::DryRun,
+                clock_tag: None,
+                skipped: None,
+                dry_run: Some(affected),
+            });
+        }
+
+        if let Some(max_affected_points) = checks.max_affected_points {
+            if affected.affected_points > max_affected_points {
+                return Err(StorageError::bad_request(format!(
+                    "Operation would affect {} points, which exceeds the limit of {max_affected_points}",
+                    affected.affected_points,
+                )));
+            }
+        }
+
+        self.update(
+            collection_name,
+            operation,
+            wait,
+            ordering,
+            shard_selector,
+            access,
+        )
+        .await
+    }
+
+    /// Readers of update operations in the WAL of every shard of the collection
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_name` - which collection to use
+    /// * `requested_shards` - shards the caller has positions for, all must exist in the collection
+    ///
+    /// # Result
+    ///
+    /// Reader for each shard, fails if some shard of the collection is not local to this peer
+    pub async fn wal_updates(
+        &self,
+        collection_name: &str,
+        requested_shards: impl IntoIterator<Item = ShardId>,
+        access: &Access,
+    ) -> Result<Vec<(ShardId, WalUpdatesReader)>, StorageError> {
+        // Updates contain all points and payloads, so access to the whole collection is required
+        let collection_pass =
+            access.check_collection_access(collection_name, AccessRequirements::new().whole())?;
+
+        let collection = self.get_collection(&collection_pass).await?;
+        collection
+            .wal_updates(requested_shards)
+            .await
+            .map_err(|err| err.into())
+    }
 }
@@ -4,6 +4,7 @@ use std::time::Duration;
 
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::universal_query::shard_query::{ShardQueryRequest, ShardQueryResponse};
+use segment::data_types::search_explain::SegmentSearchExplanation;
 
 use super::TableOfContent;
 use crate::content_manager::errors::StorageError;
@@ -15,11 +16,12 @@ impl TableOfContent {
         requests: Vec<ShardQueryRequest>,
         shard_selection: ShardSelectorInternal,
         timeout: Option<Duration>,
-    ) -> Result<Vec<ShardQueryResponse>, StorageError> {
+        explain: bool,
+    ) -> Result<(Vec<ShardQueryResponse>, Vec<SegmentSearchExplanation>), StorageError> {
         let collection = self.get_collection_unchecked(collection_name).await?;
 
         let res = collection
-            .query_batch_internal(requests, &shard_selection, timeout)
+            .query_batch_internal(requests, &shard_selection, timeout, explain)
             .await?;
 
         Ok(res)
@@ -5,8 +5,11 @@ use std::mem::take;
 use api::rest::LookupLocation;
 use collection::grouping::group_by::{GroupRequest, SourceRequest};
 use collection::lookup::WithLookup;
-use collection::operations::payload_ops::{DeletePayloadOp, PayloadOps, SetPayloadOp};
+use collection::operations::payload_ops::{
+    DeletePayloadOp, MutatePayloadOp, PayloadOps, SetPayloadOp,
+};
 use collection::operations::point_ops::{PointIdsList, PointOperations};
+use collection::operations::trash::{RestorePointsOp, TrashPointsOp};
 use collection::operations::types::{
     ContextExamplePair, CoreSearchRequest, CountRequestInternal, DiscoverRequestInternal,
     PointRequestInternal, RecommendExample, RecommendRequestInternal, ScrollRequestInternal,
@@ -379,17 +382,33 @@ impl CheckableCollectionOperation for CollectionUpdateOperations {
                 manage: true,
                 whole: true,
             },
+            CollectionUpdateOperations::AtomicBatch(batch) => batch
+                .operations
+                .iter()
+                .map(|operation| operation.access_requirements())
+                .fold(
+                    AccessRequirements {
+                        write: true,
+                        manage: false,
+                        whole: false,
+                    },
+                    |acc, requirements| AccessRequirements {
+                        write: acc.write || requirements.write,
+                        manage: acc.manage || requirements.manage,
+                        whole: acc.whole || requirements.whole,
+                    },
+                ),
         }
     }
 
     fn check_access(
         &mut self,
         view: CollectionAccessView<'_>,
-        _access: &CollectionAccessList,
+        access: &CollectionAccessList,
     ) -> Result<(), StorageError> {
         match self {
             CollectionUpdateOperations::PointOperation(op) => match op {
-                PointOperations::UpsertPoints(_) => {
+                PointOperations::UpsertPoints(_) | PointOperations::UpsertPointsConditional(_) => {
                     view.check_whole_access()?;
                 }
                 PointOperations::DeletePoints { ids } => {
@@ -407,6 +426,24 @@ impl CheckableCollectionOperation for CollectionUpdateOperations {
                 PointOperations::SyncPoints(_) => {
                     view.check_whole_access()?;
                 }
+                PointOperations::TrashPoints(TrashPointsOp {
+                    points,
+                    filter,
+                    trashed_at: _,
+                })
+                | PointOperations::RestorePoints(RestorePointsOp {
+                    points,
+                    filter,
+                    trashed_after: _,
+                }) => {
+                    if let Some(payload) = &view.payload {
+                        let filter = filter.get_or_insert_with(Default::default);
+                        if let Some(points) = take(points) {
+                            *filter = take(filter).merge_owned(make_filter_from_ids(points));
+                        }
+                        *filter = take(filter).merge_owned(payload.to_filter());
+                    }
+                }
             },
 
             CollectionUpdateOperations::VectorOperation(op) => match op {
@@ -453,6 +490,19 @@ impl CheckableCollectionOperation for CollectionUpdateOperations {
                         // Reject as not implemented
                         return incompatible_with_payload_constraint(view.collection);
                     }
+                    PayloadOps::MutatePayload(MutatePayloadOp {
+                        mutations: _, // TODO: validate
+                        points,
+                        filter,
+                    }) => {
+                        let filter = filter.get_or_insert_with(Default::default);
+                        if let Some(points) = take(points) {
+                            *filter = take(filter).merge_owned(make_filter_from_ids(points));
+                        }
+
+                        // Reject as not implemented
+                        return incompatible_with_payload_constraint(view.collection);
+                    }
                     PayloadOps::DeletePayload(DeletePayloadOp {
                         keys: _, // TODO: validate
                         points,
@@ -502,6 +552,12 @@ impl CheckableCollectionOperation for CollectionUpdateOperations {
             }
 
             CollectionUpdateOperations::FieldIndexOperation(_) => (),
+
+            CollectionUpdateOperations::AtomicBatch(batch) => {
+                for operation in batch.operations.iter_mut() {
+                    operation.check_access(view, access)?;
+                }
+            }
         }
         Ok(())
     }
@@ -616,10 +672,11 @@ mod tests_ops {
         BatchVectorStruct, LookupLocation, OrderByInterface, RecommendStrategy,
         SearchRequestInternal, VectorStruct,
     };
-    use collection::operations::payload_ops::PayloadOpsDiscriminants;
+    use collection::operations::payload_ops::{PayloadMutation, PayloadOpsDiscriminants};
     use collection::operations::point_ops::{
-        Batch, PointInsertOperationsInternal, PointInsertOperationsInternalDiscriminants,
-        PointOperationsDiscriminants, PointStruct, PointSyncOperation,
+        Batch, ConditionalInsertOperationInternal, PointInsertOperationsInternal,
+        PointInsertOperationsInternalDiscriminants, PointOperationsDiscriminants, PointStruct,
+        PointSyncOperation, UpdateMode,
     };
     use collection::operations::query_enum::QueryEnum;
     use collection::operations::types::UsingVector;
@@ -627,7 +684,7 @@ mod tests_ops {
         PointVectors, UpdateVectorsOp, VectorOperationsDiscriminants,
     };
     use collection::operations::{
-        CollectionUpdateOperationsDiscriminants, CreateIndex, FieldIndexOperations,
+        AtomicBatchOp, CollectionUpdateOperationsDiscriminants, CreateIndex, FieldIndexOperations,
         FieldIndexOperationsDiscriminants,
     };
     use segment::data_types::vectors::NamedVectorStruct;
@@ -1085,6 +1142,9 @@ mod tests_ops {
             CollectionUpdateOperationsDiscriminants::FieldIndexOperation => {
                 check_collection_update_operations_field_index()
             }
+            CollectionUpdateOperationsDiscriminants::AtomicBatch => {
+                check_collection_update_operations_atomic_batch()
+            }
         });
     }
 
@@ -1117,6 +1177,22 @@ mod tests_ops {
                 }
             }
 
+            PointOperationsDiscriminants::UpsertPointsConditional => {
+                let op = CollectionUpdateOperations::PointOperation(
+                    PointOperations::UpsertPointsConditional(ConditionalInsertOperationInternal {
+                        points_op: PointInsertOperationsInternal::PointsList(vec![PointStruct {
+                            id: ExtendedPointId::NumId(12345),
+                            vector: VectorStruct::Single(vec![0.0, 1.0, 2.0]),
+                            payload: None,
+                        }]),
+                        condition: Some(make_filter_from_ids(vec![ExtendedPointId::NumId(12345)])),
+                        update_mode: UpdateMode::InsertOnly,
+                        idempotency_key: None,
+                    }),
+                );
+                assert_requires_whole_write_access(&op);
+            }
+
             PointOperationsDiscriminants::DeletePoints => {
                 let op =
                     CollectionUpdateOperations::PointOperation(PointOperations::DeletePoints {
@@ -1144,9 +1220,74 @@ mod tests_ops {
                 ));
                 assert_requires_whole_write_access(&op);
             }
+
+            PointOperationsDiscriminants::TrashPoints => {
+                let trash_points = |points, filter| {
+                    CollectionUpdateOperations::PointOperation(PointOperations::TrashPoints(
+                        TrashPointsOp {
+                            points,
+                            filter,
+                            trashed_at: chrono::DateTime::UNIX_EPOCH,
+                        },
+                    ))
+                };
+                check_collection_update_operations_trash(trash_points);
+            }
+
+            PointOperationsDiscriminants::RestorePoints => {
+                let restore_points = |points, filter| {
+                    CollectionUpdateOperations::PointOperation(PointOperations::RestorePoints(
+                        RestorePointsOp {
+                            points,
+                            filter,
+                            trashed_after: None,
+                        },
+                    ))
+                };
+                check_collection_update_operations_trash(restore_points);
+            }
         });
     }
 
+    /// Tests for [`CollectionUpdateOperations::PointOperation`] with
+    /// [`PointOperations::TrashPoints`] and [`PointOperations::RestorePoints`].
+    fn check_collection_update_operations_trash(
+        make_op: impl Fn(Option<Vec<PointIdType>>, Option<Filter>) -> CollectionUpdateOperations,
+    ) {
+        let op = make_op(Some(vec![ExtendedPointId::NumId(12345)]), None);
+
+        assert_allowed(&op, &Access::Global(GlobalAccessMode::Manage));
+        assert_forbidden(&op, &Access::Global(GlobalAccessMode::Read));
+
+        assert_allowed(
+            &op,
+            &AccessCollectionBuilder::new().add("col", true, true).into(),
+        );
+        assert_forbidden(
+            &op,
+            &AccessCollectionBuilder::new()
+                .add("col", false, true)
+                .into(),
+        );
+
+        assert_allowed_rewrite(
+            &op,
+            &AccessCollectionBuilder::new()
+                .add("col", true, false)
+                .into(),
+            |op| {
+                *op = make_op(
+                    None,
+                    Some(
+                        Filter::default()
+                            .merge_owned(make_filter_from_ids(vec![ExtendedPointId::NumId(12345)]))
+                            .merge_owned(PayloadConstraint::new_test("col").to_filter()),
+                    ),
+                );
+            },
+        );
+    }
+
     /// Tests for [`CollectionUpdateOperations::PointOperation`] with
     /// [`PointOperations::DeletePoints`] and [`PointOperations::DeletePointsByFilter`].
     fn check_collection_update_operations_delete_points(op: &CollectionUpdateOperations) {
@@ -1190,6 +1331,7 @@ mod tests_ops {
                             id: ExtendedPointId::NumId(12345),
                             vector: VectorStruct::Single(vec![0.0, 1.0, 2.0]),
                         }],
+                        update_filter: None,
                     }),
                 );
                 assert_requires_whole_write_access(&op);
@@ -1261,6 +1403,16 @@ mod tests_ops {
                     filter: None,
                     key: None,
                 }),
+                PayloadOpsDiscriminants::MutatePayload => {
+                    PayloadOps::MutatePayload(MutatePayloadOp {
+                        mutations: vec![PayloadMutation::Increment {
+                            key: "path".parse().unwrap(),
+                            by: 1.into(),
+                        }],
+                        points: Some(vec![ExtendedPointId::NumId(12345)]),
+                        filter: None,
+                    })
+                }
                 PayloadOpsDiscriminants::DeletePayload => {
                     PayloadOps::DeletePayload(DeletePayloadOp {
                         keys: vec!["path".parse().unwrap()],
@@ -1321,4 +1473,24 @@ mod tests_ops {
             );
         }
     }
+
+    /// Tests for [`CollectionUpdateOperations::AtomicBatch`].
+    fn check_collection_update_operations_atomic_batch() {
+        let op = CollectionUpdateOperations::AtomicBatch(AtomicBatchOp {
+            operations: vec![
+                CollectionUpdateOperations::PointOperation(PointOperations::DeletePoints {
+                    ids: vec![ExtendedPointId::NumId(12345)],
+                }),
+                CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+                    PointInsertOperationsInternal::PointsList(vec![PointStruct {
+                        id: ExtendedPointId::NumId(12345),
+                        vector: VectorStruct::Single(vec![0.0, 1.0, 2.0]),
+                        payload: None,
+                    }]),
+                )),
+            ],
+            idempotency_key: None,
+        });
+        assert_requires_whole_write_access(&op);
+    }
 }
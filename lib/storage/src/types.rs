@@ -91,6 +91,9 @@ pub struct StorageConfig {
     pub recovery_mode: Option<String>,
     #[serde(default)]
     pub update_concurrency: Option<NonZeroUsize>,
+    /// How long shards remember idempotency keys of upserts, in seconds.
+    #[serde(default)]
+    pub idempotency_window_sec: Option<u64>,
     /// Default method used for transferring shards.
     #[serde(default)]
     pub shard_transfer_method: Option<ShardTransferMethod>,
@@ -116,6 +119,7 @@ impl StorageConfig {
             self.performance.outgoing_shard_transfers_limit,
             self.snapshots_path.clone(),
             self.snapshots_config.clone(),
+            self.idempotency_window_sec.map(Duration::from_secs),
         )
     }
 }
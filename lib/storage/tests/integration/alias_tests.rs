@@ -66,6 +66,7 @@ fn test_alias_operation() {
         async_scorer: false,
         update_concurrency: Some(NonZeroUsize::new(2).unwrap()),
         // update_concurrency: None,
+        idempotency_window_sec: None,
         shard_transfer_method: None,
         collection: None,
     };
@@ -112,6 +113,10 @@ fn test_alias_operation() {
                         init_from: None,
                         quantization_config: None,
                         sharding_method: None,
+                        strict_mode_config: None,
+                        payload_validation_config: None,
+                        expiry_field: None,
+                        trash_retention_sec: None,
                     },
                 )),
                 FULL_ACCESS.clone(),
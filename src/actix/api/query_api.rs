@@ -1,3 +1,4 @@
+use actix_web::rt::time::Instant;
 use actix_web::{post, web, Responder};
 use actix_web_validator::{Json, Path, Query};
 use api::rest::{QueryRequest, QueryRequestBatch, QueryResponse};
@@ -20,17 +21,43 @@ async fn query_points(
     params: Query<ReadParams>,
     ActixAccess(access): ActixAccess,
 ) -> impl Responder {
-    helpers::time(async move {
-        let QueryRequest {
-            internal: query_request,
-            shard_key,
-        } = request.into_inner();
+    let QueryRequest {
+        internal: query_request,
+        shard_key,
+        explain,
+    } = request.into_inner();
+
+    let shard_selection = match shard_key {
+        None => ShardSelectorInternal::All,
+        Some(shard_keys) => shard_keys.into(),
+    };
 
-        let shard_selection = match shard_key {
-            None => ShardSelectorInternal::All,
-            Some(shard_keys) => shard_keys.into(),
-        };
+    if explain.unwrap_or_default() {
+        let timing = Instant::now();
 
+        let response = dispatcher
+            .toc(&access)
+            .explain_query(
+                &collection.name,
+                query_request.into(),
+                shard_selection,
+                params.consistency,
+                access,
+                params.timeout(),
+            )
+            .await
+            .map(|(points, explanation)| {
+                let points = points
+                    .into_iter()
+                    .map(api::rest::ScoredPoint::from)
+                    .collect_vec();
+                (QueryResponse { points }, explanation)
+            });
+
+        return helpers::process_response_with_explanation(response, timing);
+    }
+
+    helpers::time(async move {
         let points = dispatcher
             .toc(&access)
             .query_batch(
@@ -71,6 +98,7 @@ async fn query_points_batch(
                 let QueryRequest {
                     internal,
                     shard_key,
+                    explain: _,
                 } = request;
 
                 let request = CollectionQueryRequest::from(internal);
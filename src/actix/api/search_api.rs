@@ -11,9 +11,9 @@ use storage::dispatcher::Dispatcher;
 use super::read_params::ReadParams;
 use super::CollectionPath;
 use crate::actix::auth::ActixAccess;
-use crate::actix::helpers::process_response;
+use crate::actix::helpers::{process_response, process_response_with_explanation};
 use crate::common::points::{
-    do_core_search_points, do_search_batch_points, do_search_point_groups,
+    do_core_search_points, do_explain_search_points, do_search_batch_points, do_search_point_groups,
 };
 
 #[post("/collections/{name}/points/search")]
@@ -29,6 +29,7 @@ async fn search_points(
     let SearchRequest {
         search_request,
         shard_key,
+        explain,
     } = request.into_inner();
 
     let shard_selection = match shard_key {
@@ -36,6 +37,28 @@ async fn search_points(
         Some(shard_keys) => shard_keys.into(),
     };
 
+    if explain.unwrap_or_default() {
+        let response = do_explain_search_points(
+            dispatcher.toc(&access),
+            &collection.name,
+            search_request.into(),
+            params.consistency,
+            shard_selection,
+            access,
+            params.timeout(),
+        )
+        .await
+        .map(|(scored_points, explanation)| {
+            let scored_points = scored_points
+                .into_iter()
+                .map(api::rest::ScoredPoint::from)
+                .collect_vec();
+            (scored_points, explanation)
+        });
+
+        return process_response_with_explanation(response, timing);
+    }
+
     let response = do_core_search_points(
         dispatcher.toc(&access),
         &collection.name,
@@ -74,6 +97,7 @@ async fn batch_search_points(
             let SearchRequest {
                 search_request,
                 shard_key,
+                explain: _,
             } = req;
             let shard_selection = match shard_key {
                 None => ShardSelectorInternal::All,
@@ -58,6 +58,18 @@ fn telemetry(
     })
 }
 
+#[get("/telemetry/slow_queries")]
+fn slow_queries(
+    telemetry_collector: web::Data<Mutex<TelemetryCollector>>,
+    ActixAccess(access): ActixAccess,
+) -> impl Future<Output = HttpResponse> {
+    helpers::time(async move {
+        access.check_global_access(AccessRequirements::new())?;
+        let slow_query_log = telemetry_collector.lock().await.slow_query_log.clone();
+        Ok(slow_query_log.records())
+    })
+}
+
 #[derive(Deserialize, Serialize, JsonSchema)]
 pub struct MetricsParam {
     pub anonymize: Option<bool>,
@@ -198,6 +210,7 @@ async fn update_logger_config(
 // Configure services
 pub fn config_service_api(cfg: &mut web::ServiceConfig) {
     cfg.service(telemetry)
+        .service(slow_queries)
         .service(metrics)
         .service(put_locks)
         .service(get_locks)
@@ -1,12 +1,14 @@
 use actix_web::rt::time::Instant;
 use actix_web::{delete, post, put, web, Responder};
 use actix_web_validator::{Json, Path, Query};
-use collection::operations::payload_ops::{DeletePayload, SetPayload};
+use collection::operations::payload_ops::{DeletePayload, MutatePayload, SetPayload};
 use collection::operations::point_ops::{PointInsertOperations, PointsSelector, WriteOrdering};
+use collection::operations::types::FilterUpdateChecks;
 use collection::operations::vector_ops::{DeleteVectors, UpdateVectors};
 use schemars::JsonSchema;
 use segment::json_path::{JsonPath, JsonPathInterface};
 use serde::{Deserialize, Serialize};
+use storage::content_manager::errors::StorageError;
 use storage::dispatcher::Dispatcher;
 use validator::Validate;
 
@@ -14,9 +16,10 @@ use super::CollectionPath;
 use crate::actix::auth::ActixAccess;
 use crate::actix::helpers::process_response;
 use crate::common::points::{
-    do_batch_update_points, do_clear_payload, do_create_index, do_delete_index, do_delete_payload,
-    do_delete_points, do_delete_vectors, do_overwrite_payload, do_set_payload, do_update_vectors,
-    do_upsert_points, CreateFieldIndex, UpdateOperations,
+    do_atomic_batch_update_points, do_batch_update_points, do_clear_payload, do_create_index,
+    do_delete_index, do_delete_payload, do_delete_points, do_delete_vectors, do_mutate_payload,
+    do_overwrite_payload, do_restore_points, do_set_payload, do_update_vectors, do_upsert_points,
+    CreateFieldIndex, UpdateOperations,
 };
 
 #[derive(Deserialize, Validate)]
@@ -32,6 +35,25 @@ pub struct UpdateParam {
     pub ordering: Option<WriteOrdering>,
 }
 
+/// Parameters of updates, which may select points by filter
+#[derive(Deserialize, Serialize, JsonSchema, Validate)]
+pub struct FilterUpdateParam {
+    pub wait: Option<bool>,
+    pub ordering: Option<WriteOrdering>,
+    pub dry_run: Option<bool>,
+    #[validate(range(min = 1))]
+    pub max_affected_points: Option<usize>,
+}
+
+impl FilterUpdateParam {
+    fn checks(&self) -> FilterUpdateChecks {
+        FilterUpdateChecks {
+            dry_run: self.dry_run.unwrap_or(false),
+            max_affected_points: self.max_affected_points,
+        }
+    }
+}
+
 #[put("/collections/{name}/points")]
 async fn upsert_points(
     dispatcher: web::Data<Dispatcher>,
@@ -64,7 +86,7 @@ async fn delete_points(
     dispatcher: web::Data<Dispatcher>,
     collection: Path<CollectionPath>,
     operation: Json<PointsSelector>,
-    params: Query<UpdateParam>,
+    params: Query<FilterUpdateParam>,
     ActixAccess(access): ActixAccess,
 ) -> impl Responder {
     let timing = Instant::now();
@@ -81,6 +103,35 @@ async fn delete_points(
         wait,
         ordering,
         access,
+        params.checks(),
+    )
+    .await;
+    process_response(response, timing)
+}
+
+#[post("/collections/{name}/points/restore")]
+async fn restore_points(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    operation: Json<PointsSelector>,
+    params: Query<UpdateParam>,
+    ActixAccess(access): ActixAccess,
+) -> impl Responder {
+    let timing = Instant::now();
+    let operation = operation.into_inner();
+    let wait = params.wait.unwrap_or(false);
+    let ordering = params.ordering.unwrap_or_default();
+
+    let response = do_restore_points(
+        dispatcher.toc(&access).clone(),
+        collection.into_inner().name,
+        operation,
+        None,
+        None,
+        None,
+        wait,
+        ordering,
+        access,
     )
     .await;
     process_response(response, timing)
@@ -145,7 +196,7 @@ async fn set_payload(
     dispatcher: web::Data<Dispatcher>,
     collection: Path<CollectionPath>,
     operation: Json<SetPayload>,
-    params: Query<UpdateParam>,
+    params: Query<FilterUpdateParam>,
     ActixAccess(access): ActixAccess,
 ) -> impl Responder {
     let timing = Instant::now();
@@ -162,6 +213,7 @@ async fn set_payload(
         wait,
         ordering,
         access,
+        params.checks(),
     )
     .await;
     process_response(response, timing)
@@ -172,7 +224,7 @@ async fn overwrite_payload(
     dispatcher: web::Data<Dispatcher>,
     collection: Path<CollectionPath>,
     operation: Json<SetPayload>,
-    params: Query<UpdateParam>,
+    params: Query<FilterUpdateParam>,
     ActixAccess(access): ActixAccess,
 ) -> impl Responder {
     let timing = Instant::now();
@@ -189,6 +241,34 @@ async fn overwrite_payload(
         wait,
         ordering,
         access,
+        params.checks(),
+    )
+    .await;
+    process_response(response, timing)
+}
+
+#[post("/collections/{name}/points/payload/mutate")]
+async fn mutate_payload(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    operation: Json<MutatePayload>,
+    params: Query<UpdateParam>,
+    ActixAccess(access): ActixAccess,
+) -> impl Responder {
+    let timing = Instant::now();
+    let operation = operation.into_inner();
+    let wait = params.wait.unwrap_or(false);
+    let ordering = params.ordering.unwrap_or_default();
+
+    let response = do_mutate_payload(
+        dispatcher.toc(&access).clone(),
+        collection.into_inner().name,
+        operation,
+        None,
+        None,
+        wait,
+        ordering,
+        access,
     )
     .await;
     process_response(response, timing)
@@ -199,7 +279,7 @@ async fn delete_payload(
     dispatcher: web::Data<Dispatcher>,
     collection: Path<CollectionPath>,
     operation: Json<DeletePayload>,
-    params: Query<UpdateParam>,
+    params: Query<FilterUpdateParam>,
     ActixAccess(access): ActixAccess,
 ) -> impl Responder {
     let timing = Instant::now();
@@ -216,6 +296,7 @@ async fn delete_payload(
         wait,
         ordering,
         access,
+        params.checks(),
     )
     .await;
     process_response(response, timing)
@@ -226,7 +307,7 @@ async fn clear_payload(
     dispatcher: web::Data<Dispatcher>,
     collection: Path<CollectionPath>,
     operation: Json<PointsSelector>,
-    params: Query<UpdateParam>,
+    params: Query<FilterUpdateParam>,
     ActixAccess(access): ActixAccess,
 ) -> impl Responder {
     let timing = Instant::now();
@@ -243,6 +324,7 @@ async fn clear_payload(
         wait,
         ordering,
         access,
+        params.checks(),
     )
     .await;
     process_response(response, timing)
@@ -261,17 +343,40 @@ async fn update_batch(
     let wait = params.wait.unwrap_or(false);
     let ordering = params.ordering.unwrap_or_default();
 
-    let response = do_batch_update_points(
-        dispatcher.toc(&access).clone(),
-        collection.into_inner().name,
-        operations.operations,
-        None,
-        None,
-        wait,
-        ordering,
-        access,
-    )
-    .await;
+    let response = if operations.atomic.unwrap_or(false) {
+        let operations_count = operations.operations.len();
+        do_atomic_batch_update_points(
+            dispatcher.toc(&access).clone(),
+            collection.into_inner().name,
+            operations.operations,
+            operations.idempotency_key,
+            None,
+            None,
+            None,
+            wait,
+            ordering,
+            access,
+        )
+        .await
+        // The batch is applied as a whole, so every operation shares the same result
+        .map(|result| vec![result; operations_count])
+    } else if operations.idempotency_key.is_some() {
+        Err(StorageError::bad_request(
+            "Idempotency key is only supported for atomic batches",
+        ))
+    } else {
+        do_batch_update_points(
+            dispatcher.toc(&access).clone(),
+            collection.into_inner().name,
+            operations.operations,
+            None,
+            None,
+            wait,
+            ordering,
+            access,
+        )
+        .await
+    };
     process_response(response, timing)
 }
 #[put("/collections/{name}/index")]
@@ -331,10 +436,12 @@ async fn delete_field_index(
 pub fn config_update_api(cfg: &mut web::ServiceConfig) {
     cfg.service(upsert_points)
         .service(delete_points)
+        .service(restore_points)
         .service(update_vectors)
         .service(delete_vectors)
         .service(set_payload)
         .service(overwrite_payload)
+        .service(mutate_payload)
         .service(delete_payload)
         .service(clear_payload)
         .service(create_field_index)
@@ -5,6 +5,7 @@ use actix_web::rt::time::Instant;
 use actix_web::{http, HttpResponse, ResponseError};
 use api::grpc::models::{ApiResponse, ApiStatus};
 use collection::operations::types::CollectionError;
+use segment::data_types::search_explain::SearchExplanation;
 use serde::Serialize;
 use storage::content_manager::errors::StorageError;
 use tokio::task::JoinHandle;
@@ -14,6 +15,7 @@ pub fn accepted_response(timing: Instant) -> HttpResponse {
         result: None,
         status: ApiStatus::Accepted,
         time: timing.elapsed().as_secs_f64(),
+        explanation: None,
     })
 }
 
@@ -26,6 +28,26 @@ where
             result: Some(res),
             status: ApiStatus::Ok,
             time: timing.elapsed().as_secs_f64(),
+            explanation: None,
+        }),
+        Err(err) => process_response_error(err, timing),
+    }
+}
+
+/// Same as [`process_response`], but also puts the explanation of the search into the response
+pub fn process_response_with_explanation<D>(
+    response: Result<(D, SearchExplanation), StorageError>,
+    timing: Instant,
+) -> HttpResponse
+where
+    D: Serialize,
+{
+    match response {
+        Ok((res, explanation)) => HttpResponse::Ok().json(ApiResponse {
+            result: Some(res),
+            status: ApiStatus::Ok,
+            time: timing.elapsed().as_secs_f64(),
+            explanation: Some(explanation),
         }),
         Err(err) => process_response_error(err, timing),
     }
@@ -49,6 +71,7 @@ pub fn process_response_error(err: StorageError, timing: Instant) -> HttpRespons
         result: None,
         status: ApiStatus::Error(error.to_string()),
         time: timing.elapsed().as_secs_f64(),
+        explanation: None,
     })
 }
 
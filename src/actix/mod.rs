@@ -267,6 +267,7 @@ fn validation_error_handler(
         result: None,
         status: ApiStatus::Error(msg),
         time: 0.0,
+        explanation: None,
     });
     error::InternalError::from_response(err, response).into()
 }
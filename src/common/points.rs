@@ -2,30 +2,36 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use api::rest::{SearchGroupsRequestInternal, ShardKeySelector};
+use chrono::{DateTime, Utc};
 use collection::common::batching::batch_requests;
 use collection::operations::consistency_params::ReadConsistency;
 use collection::operations::payload_ops::{
-    DeletePayload, DeletePayloadOp, PayloadOps, SetPayload, SetPayloadOp,
+    DeletePayload, DeletePayloadOp, MutatePayload, MutatePayloadOp, PayloadOps, SetPayload,
+    SetPayloadOp,
 };
 use collection::operations::point_ops::{
     FilterSelector, PointIdsList, PointInsertOperations, PointOperations, PointsSelector,
     WriteOrdering,
 };
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
+use collection::operations::trash::{RestorePointsOp, TrashPointsOp};
 use collection::operations::types::{
     CoreSearchRequest, CoreSearchRequestBatch, CountRequestInternal, CountResult,
-    DiscoverRequestBatch, DiscoverRequestInternal, GroupsResult, PointRequestInternal,
-    RecommendGroupsRequestInternal, Record, ScrollRequestInternal, ScrollResult, UpdateResult,
+    DiscoverRequestBatch, DiscoverRequestInternal, FilterUpdateChecks, GroupsResult,
+    PointRequestInternal, RecommendGroupsRequestInternal, Record, ScrollRequestInternal,
+    ScrollResult, UpdateResult,
 };
 use collection::operations::universal_query::collection_query::CollectionQueryRequest;
 use collection::operations::vector_ops::{
     DeleteVectors, UpdateVectors, UpdateVectorsOp, VectorOperations,
 };
 use collection::operations::{
-    ClockTag, CollectionUpdateOperations, CreateIndex, FieldIndexOperations, OperationWithClockTag,
+    AtomicBatchOp, ClockTag, CollectionUpdateOperations, CreateIndex, FieldIndexOperations,
+    OperationWithClockTag,
 };
 use collection::shards::shard::ShardId;
 use schemars::JsonSchema;
+use segment::data_types::search_explain::SearchExplanation;
 use segment::json_path::JsonPath;
 use segment::types::{PayloadFieldSchema, PayloadKeyType, ScoredPoint};
 use serde::{Deserialize, Serialize};
@@ -48,49 +54,49 @@ pub struct CreateFieldIndex {
 #[derive(Deserialize, Serialize, JsonSchema, Validate)]
 pub struct UpsertOperation {
     #[validate]
-    upsert: PointInsertOperations,
+    pub upsert: PointInsertOperations,
 }
 
 #[derive(Deserialize, Serialize, JsonSchema, Validate)]
 pub struct DeleteOperation {
     #[validate]
-    delete: PointsSelector,
+    pub delete: PointsSelector,
 }
 
 #[derive(Deserialize, Serialize, JsonSchema, Validate)]
 pub struct SetPayloadOperation {
     #[validate]
-    set_payload: SetPayload,
+    pub set_payload: SetPayload,
 }
 
 #[derive(Deserialize, Serialize, JsonSchema, Validate)]
 pub struct OverwritePayloadOperation {
     #[validate]
-    overwrite_payload: SetPayload,
+    pub overwrite_payload: SetPayload,
 }
 
 #[derive(Deserialize, Serialize, JsonSchema, Validate)]
 pub struct DeletePayloadOperation {
     #[validate]
-    delete_payload: DeletePayload,
+    pub delete_payload: DeletePayload,
 }
 
 #[derive(Deserialize, Serialize, JsonSchema, Validate)]
 pub struct ClearPayloadOperation {
     #[validate]
-    clear_payload: PointsSelector,
+    pub clear_payload: PointsSelector,
 }
 
 #[derive(Deserialize, Serialize, JsonSchema, Validate)]
 pub struct UpdateVectorsOperation {
     #[validate]
-    update_vectors: UpdateVectors,
+    pub update_vectors: UpdateVectors,
 }
 
 #[derive(Deserialize, Serialize, JsonSchema, Validate)]
 pub struct DeleteVectorsOperation {
     #[validate]
-    delete_vectors: DeleteVectors,
+    pub delete_vectors: DeleteVectors,
 }
 
 #[derive(Deserialize, Serialize, JsonSchema)]
@@ -110,6 +116,16 @@ pub enum UpdateOperation {
 #[derive(Deserialize, Serialize, JsonSchema, Validate)]
 pub struct UpdateOperations {
     pub operations: Vec<UpdateOperation>,
+    /// If true, operations are applied to each shard as a single unit.
+    /// If any operation fails, all changes of the batch in that shard are rolled back.
+    /// All operations must target the same shard key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub atomic: Option<bool>,
+    /// Unique key of the request, repeated requests with the same key are applied only once.
+    /// Only supported for atomic batches.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1, max = 255))]
+    pub idempotency_key: Option<String>,
 }
 
 impl Validate for UpdateOperation {
@@ -127,6 +143,144 @@ impl Validate for UpdateOperation {
     }
 }
 
+impl UpdateOperation {
+    /// Split into shard key and the collection operations to apply
+    ///
+    /// Deleting vectors by both points and filter results in two operations.
+    fn decompose(self) -> (Option<ShardKeySelector>, Vec<CollectionUpdateOperations>) {
+        match self {
+            UpdateOperation::Upsert(operation) => {
+                let (shard_key, operation) = operation.upsert.decompose();
+                (
+                    shard_key,
+                    vec![CollectionUpdateOperations::PointOperation(operation)],
+                )
+            }
+            UpdateOperation::Delete(operation) => {
+                let (operation, shard_key) = match operation.delete {
+                    PointsSelector::PointIdsSelector(PointIdsList { points, shard_key }) => {
+                        (PointOperations::DeletePoints { ids: points }, shard_key)
+                    }
+                    PointsSelector::FilterSelector(FilterSelector { filter, shard_key }) => {
+                        (PointOperations::DeletePointsByFilter(filter), shard_key)
+                    }
+                };
+                (
+                    shard_key,
+                    vec![CollectionUpdateOperations::PointOperation(operation)],
+                )
+            }
+            UpdateOperation::SetPayload(operation) => {
+                let SetPayload {
+                    points,
+                    payload,
+                    filter,
+                    shard_key,
+                    key,
+                } = operation.set_payload;
+                let operation = PayloadOps::SetPayload(SetPayloadOp {
+                    payload,
+                    points,
+                    filter,
+                    key,
+                });
+                (
+                    shard_key,
+                    vec![CollectionUpdateOperations::PayloadOperation(operation)],
+                )
+            }
+            UpdateOperation::OverwritePayload(operation) => {
+                let SetPayload {
+                    points,
+                    payload,
+                    filter,
+                    shard_key,
+                    ..
+                } = operation.overwrite_payload;
+                let operation = PayloadOps::OverwritePayload(SetPayloadOp {
+                    payload,
+                    points,
+                    filter,
+                    // overwrite operation doesn't support payload selector
+                    key: None,
+                });
+                (
+                    shard_key,
+                    vec![CollectionUpdateOperations::PayloadOperation(operation)],
+                )
+            }
+            UpdateOperation::DeletePayload(operation) => {
+                let DeletePayload {
+                    keys,
+                    points,
+                    filter,
+                    shard_key,
+                } = operation.delete_payload;
+                let operation = PayloadOps::DeletePayload(DeletePayloadOp {
+                    keys,
+                    points,
+                    filter,
+                });
+                (
+                    shard_key,
+                    vec![CollectionUpdateOperations::PayloadOperation(operation)],
+                )
+            }
+            UpdateOperation::ClearPayload(operation) => {
+                let (operation, shard_key) = match operation.clear_payload {
+                    PointsSelector::PointIdsSelector(PointIdsList { points, shard_key }) => {
+                        (PayloadOps::ClearPayload { points }, shard_key)
+                    }
+                    PointsSelector::FilterSelector(FilterSelector { filter, shard_key }) => {
+                        (PayloadOps::ClearPayloadByFilter(filter), shard_key)
+                    }
+                };
+                (
+                    shard_key,
+                    vec![CollectionUpdateOperations::PayloadOperation(operation)],
+                )
+            }
+            UpdateOperation::UpdateVectors(operation) => {
+                let UpdateVectors {
+                    points,
+                    shard_key,
+                    update_filter,
+                } = operation.update_vectors;
+                let operation = VectorOperations::UpdateVectors(UpdateVectorsOp {
+                    points,
+                    update_filter,
+                });
+                (
+                    shard_key,
+                    vec![CollectionUpdateOperations::VectorOperation(operation)],
+                )
+            }
+            UpdateOperation::DeleteVectors(operation) => {
+                let DeleteVectors {
+                    vector,
+                    filter,
+                    points,
+                    shard_key,
+                } = operation.delete_vectors;
+                let vector_names: Vec<_> = vector.into_iter().collect();
+
+                let mut operations = Vec::new();
+                if let Some(filter) = filter {
+                    operations.push(CollectionUpdateOperations::VectorOperation(
+                        VectorOperations::DeleteVectorsByFilter(filter, vector_names.clone()),
+                    ));
+                }
+                if let Some(points) = points {
+                    operations.push(CollectionUpdateOperations::VectorOperation(
+                        VectorOperations::DeleteVectors(points.into(), vector_names),
+                    ));
+                }
+                (shard_key, operations)
+            }
+        }
+    }
+}
+
 /// Converts a pair of parameters into a shard selector
 /// suitable for update operations.
 ///
@@ -169,9 +323,8 @@ pub async fn do_upsert_points(
     ordering: WriteOrdering,
     access: Access,
 ) -> Result<UpdateResult, StorageError> {
-    let (shard_key, operation) = operation.decompose();
-    let collection_operation =
-        CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(operation));
+    let (shard_key, point_operation) = operation.decompose();
+    let collection_operation = CollectionUpdateOperations::PointOperation(point_operation);
 
     let shard_selector = get_shard_selector_for_update(shard_selection, shard_key);
 
@@ -196,6 +349,7 @@ pub async fn do_delete_points(
     wait: bool,
     ordering: WriteOrdering,
     access: Access,
+    checks: FilterUpdateChecks,
 ) -> Result<UpdateResult, StorageError> {
     let (point_operation, shard_key) = match points {
         PointsSelector::PointIdsSelector(PointIdsList { points, shard_key }) => {
@@ -208,6 +362,88 @@ pub async fn do_delete_points(
     let collection_operation = CollectionUpdateOperations::PointOperation(point_operation);
     let shard_selector = get_shard_selector_for_update(shard_selection, shard_key);
 
+    toc.update_with_checks(
+        &collection_name,
+        OperationWithClockTag::new(collection_operation, clock_tag),
+        wait,
+        ordering,
+        shard_selector,
+        access,
+        checks,
+    )
+    .await
+}
+
+/// Move selected points to trash at the given time, instead of deleting them.
+///
+/// Used by peers to replicate deletions in collections with trash enabled.
+#[allow(clippy::too_many_arguments)]
+pub async fn do_trash_points(
+    toc: Arc<TableOfContent>,
+    collection_name: String,
+    points: PointsSelector,
+    trashed_at: DateTime<Utc>,
+    clock_tag: Option<ClockTag>,
+    shard_selection: Option<ShardId>,
+    wait: bool,
+    ordering: WriteOrdering,
+    access: Access,
+) -> Result<UpdateResult, StorageError> {
+    let (points, filter, shard_key) = match points {
+        PointsSelector::PointIdsSelector(PointIdsList { points, shard_key }) => {
+            (Some(points), None, shard_key)
+        }
+        PointsSelector::FilterSelector(FilterSelector { filter, shard_key }) => {
+            (None, Some(filter), shard_key)
+        }
+    };
+    let collection_operation =
+        CollectionUpdateOperations::PointOperation(PointOperations::TrashPoints(TrashPointsOp {
+            points,
+            filter,
+            trashed_at,
+        }));
+    let shard_selector = get_shard_selector_for_update(shard_selection, shard_key);
+
+    toc.update(
+        &collection_name,
+        OperationWithClockTag::new(collection_operation, clock_tag),
+        wait,
+        ordering,
+        shard_selector,
+        access,
+    )
+    .await
+}
+
+/// Move selected points out of trash.
+///
+/// Points, which are not in trash, are not affected.
+/// If `trashed_after` is not set, it is derived from the trash retention of the collection.
+#[allow(clippy::too_many_arguments)]
+pub async fn do_restore_points(
+    toc: Arc<TableOfContent>,
+    collection_name: String,
+    points: PointsSelector,
+    trashed_after: Option<DateTime<Utc>>,
+    clock_tag: Option<ClockTag>,
+    shard_selection: Option<ShardId>,
+    wait: bool,
+    ordering: WriteOrdering,
+    access: Access,
+) -> Result<UpdateResult, StorageError> {
+    let shard_key = match &points {
+        PointsSelector::PointIdsSelector(PointIdsList { shard_key, .. })
+        | PointsSelector::FilterSelector(FilterSelector { shard_key, .. }) => shard_key.clone(),
+    };
+    let collection_operation = CollectionUpdateOperations::PointOperation(
+        PointOperations::RestorePoints(RestorePointsOp {
+            trashed_after,
+            ..RestorePointsOp::from(points)
+        }),
+    );
+    let shard_selector = get_shard_selector_for_update(shard_selection, shard_key);
+
     toc.update(
         &collection_name,
         OperationWithClockTag::new(collection_operation, clock_tag),
@@ -230,10 +466,17 @@ pub async fn do_update_vectors(
     ordering: WriteOrdering,
     access: Access,
 ) -> Result<UpdateResult, StorageError> {
-    let UpdateVectors { points, shard_key } = operation;
+    let UpdateVectors {
+        points,
+        shard_key,
+        update_filter,
+    } = operation;
 
     let collection_operation = CollectionUpdateOperations::VectorOperation(
-        VectorOperations::UpdateVectors(UpdateVectorsOp { points }),
+        VectorOperations::UpdateVectors(UpdateVectorsOp {
+            points,
+            update_filter,
+        }),
     );
 
     let shard_selector = get_shard_selector_for_update(shard_selection, shard_key);
@@ -322,6 +565,7 @@ pub async fn do_set_payload(
     wait: bool,
     ordering: WriteOrdering,
     access: Access,
+    checks: FilterUpdateChecks,
 ) -> Result<UpdateResult, StorageError> {
     let SetPayload {
         points,
@@ -341,13 +585,14 @@ pub async fn do_set_payload(
 
     let shard_selector = get_shard_selector_for_update(shard_selection, shard_key);
 
-    toc.update(
+    toc.update_with_checks(
         &collection_name,
         OperationWithClockTag::new(collection_operation, clock_tag),
         wait,
         ordering,
         shard_selector,
         access,
+        checks,
     )
     .await
 }
@@ -362,6 +607,7 @@ pub async fn do_overwrite_payload(
     wait: bool,
     ordering: WriteOrdering,
     access: Access,
+    checks: FilterUpdateChecks,
 ) -> Result<UpdateResult, StorageError> {
     let SetPayload {
         points,
@@ -382,6 +628,45 @@ pub async fn do_overwrite_payload(
 
     let shard_selector = get_shard_selector_for_update(shard_selection, shard_key);
 
+    toc.update_with_checks(
+        &collection_name,
+        OperationWithClockTag::new(collection_operation, clock_tag),
+        wait,
+        ordering,
+        shard_selector,
+        access,
+        checks,
+    )
+    .await
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn do_mutate_payload(
+    toc: Arc<TableOfContent>,
+    collection_name: String,
+    operation: MutatePayload,
+    clock_tag: Option<ClockTag>,
+    shard_selection: Option<ShardId>,
+    wait: bool,
+    ordering: WriteOrdering,
+    access: Access,
+) -> Result<UpdateResult, StorageError> {
+    let MutatePayload {
+        mutations,
+        points,
+        filter,
+        shard_key,
+    } = operation;
+
+    let collection_operation =
+        CollectionUpdateOperations::PayloadOperation(PayloadOps::MutatePayload(MutatePayloadOp {
+            mutations,
+            points,
+            filter,
+        }));
+
+    let shard_selector = get_shard_selector_for_update(shard_selection, shard_key);
+
     toc.update(
         &collection_name,
         OperationWithClockTag::new(collection_operation, clock_tag),
@@ -403,6 +688,7 @@ pub async fn do_delete_payload(
     wait: bool,
     ordering: WriteOrdering,
     access: Access,
+    checks: FilterUpdateChecks,
 ) -> Result<UpdateResult, StorageError> {
     let DeletePayload {
         keys,
@@ -420,13 +706,14 @@ pub async fn do_delete_payload(
 
     let shard_selector = get_shard_selector_for_update(shard_selection, shard_key);
 
-    toc.update(
+    toc.update_with_checks(
         &collection_name,
         OperationWithClockTag::new(collection_operation, clock_tag),
         wait,
         ordering,
         shard_selector,
         access,
+        checks,
     )
     .await
 }
@@ -441,6 +728,7 @@ pub async fn do_clear_payload(
     wait: bool,
     ordering: WriteOrdering,
     access: Access,
+    checks: FilterUpdateChecks,
 ) -> Result<UpdateResult, StorageError> {
     let (point_operation, shard_key) = match points {
         PointsSelector::PointIdsSelector(PointIdsList { points, shard_key }) => {
@@ -455,13 +743,14 @@ pub async fn do_clear_payload(
 
     let shard_selector = get_shard_selector_for_update(shard_selection, shard_key);
 
-    toc.update(
+    toc.update_with_checks(
         &collection_name,
         OperationWithClockTag::new(collection_operation, clock_tag),
         wait,
         ordering,
         shard_selector,
         access,
+        checks,
     )
     .await
 }
@@ -503,6 +792,7 @@ pub async fn do_batch_update_points(
                     wait,
                     ordering,
                     access.clone(),
+                    FilterUpdateChecks::default(),
                 )
                 .await
             }
@@ -516,6 +806,7 @@ pub async fn do_batch_update_points(
                     wait,
                     ordering,
                     access.clone(),
+                    FilterUpdateChecks::default(),
                 )
                 .await
             }
@@ -529,6 +820,7 @@ pub async fn do_batch_update_points(
                     wait,
                     ordering,
                     access.clone(),
+                    FilterUpdateChecks::default(),
                 )
                 .await
             }
@@ -542,6 +834,7 @@ pub async fn do_batch_update_points(
                     wait,
                     ordering,
                     access.clone(),
+                    FilterUpdateChecks::default(),
                 )
                 .await
             }
@@ -555,6 +848,7 @@ pub async fn do_batch_update_points(
                     wait,
                     ordering,
                     access.clone(),
+                    FilterUpdateChecks::default(),
                 )
                 .await
             }
@@ -590,6 +884,62 @@ pub async fn do_batch_update_points(
     Ok(results)
 }
 
+/// Apply all operations as a single atomic batch in each affected shard
+///
+/// All operations must target the same shard key.
+#[allow(clippy::too_many_arguments)]
+pub async fn do_atomic_batch_update_points(
+    toc: Arc<TableOfContent>,
+    collection_name: String,
+    operations: Vec<UpdateOperation>,
+    idempotency_key: Option<String>,
+    trashed_at: Option<DateTime<Utc>>,
+    clock_tag: Option<ClockTag>,
+    shard_selection: Option<ShardId>,
+    wait: bool,
+    ordering: WriteOrdering,
+    access: Access,
+) -> Result<UpdateResult, StorageError> {
+    let mut batch_shard_key = None;
+    let mut batch_operations = Vec::with_capacity(operations.len());
+
+    for (idx, operation) in operations.into_iter().enumerate() {
+        let (shard_key, operations) = operation.decompose();
+        if operations.is_empty() {
+            return Err(StorageError::bad_request("No filter or points provided"));
+        }
+        if idx > 0 && shard_key != batch_shard_key {
+            return Err(StorageError::bad_request(
+                "All operations of an atomic batch must use the same shard key",
+            ));
+        }
+        batch_shard_key = shard_key;
+        batch_operations.extend(operations);
+    }
+
+    let mut collection_operation = CollectionUpdateOperations::AtomicBatch(AtomicBatchOp {
+        operations: batch_operations,
+        idempotency_key,
+    });
+
+    // Deletions, replicated by a peer, move points to trash at the time set by the peer
+    if let Some(trashed_at) = trashed_at {
+        collection_operation = collection_operation.into_trash(trashed_at);
+    }
+
+    let shard_selector = get_shard_selector_for_update(shard_selection, batch_shard_key);
+
+    toc.update(
+        &collection_name,
+        OperationWithClockTag::new(collection_operation, clock_tag),
+        wait,
+        ordering,
+        shard_selector,
+        access,
+    )
+    .await
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn do_create_index_internal(
     toc: Arc<TableOfContent>,
@@ -775,6 +1125,26 @@ pub async fn do_core_search_points(
         .ok_or_else(|| StorageError::service_error("Empty search result"))
 }
 
+pub async fn do_explain_search_points(
+    toc: &TableOfContent,
+    collection_name: &str,
+    request: CoreSearchRequest,
+    read_consistency: Option<ReadConsistency>,
+    shard_selection: ShardSelectorInternal,
+    access: Access,
+    timeout: Option<Duration>,
+) -> Result<(Vec<ScoredPoint>, SearchExplanation), StorageError> {
+    toc.explain_search(
+        collection_name,
+        request,
+        read_consistency,
+        shard_selection,
+        access,
+        timeout,
+    )
+    .await
+}
+
 pub async fn do_search_batch_points(
     toc: &TableOfContent,
     collection_name: &str,
@@ -1000,6 +1370,26 @@ pub async fn do_query_points(
         .ok_or_else(|| StorageError::service_error("Empty query result"))
 }
 
+pub async fn do_explain_query_points(
+    toc: &TableOfContent,
+    collection_name: &str,
+    request: CollectionQueryRequest,
+    read_consistency: Option<ReadConsistency>,
+    shard_selection: ShardSelectorInternal,
+    access: Access,
+    timeout: Option<Duration>,
+) -> Result<(Vec<ScoredPoint>, SearchExplanation), StorageError> {
+    toc.explain_query(
+        collection_name,
+        request,
+        shard_selection,
+        read_consistency,
+        access,
+        timeout,
+    )
+    .await
+}
+
 pub async fn do_query_batch_points(
     toc: &TableOfContent,
     collection_name: &str,
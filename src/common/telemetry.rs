@@ -15,6 +15,7 @@ use crate::common::telemetry_ops::collections_telemetry::CollectionsTelemetry;
 use crate::common::telemetry_ops::requests_telemetry::{
     ActixTelemetryCollector, RequestsTelemetry, TonicTelemetryCollector,
 };
+use crate::common::telemetry_ops::slow_queries_telemetry::SlowQueryLog;
 use crate::settings::Settings;
 
 pub struct TelemetryCollector {
@@ -24,6 +25,7 @@ pub struct TelemetryCollector {
     pub app_telemetry_collector: AppBuildTelemetryCollector,
     pub actix_telemetry_collector: Arc<Mutex<ActixTelemetryCollector>>,
     pub tonic_telemetry_collector: Arc<Mutex<TonicTelemetryCollector>>,
+    pub slow_query_log: SlowQueryLog,
 }
 
 // Whole telemetry data
@@ -58,6 +60,8 @@ impl TelemetryCollector {
     }
 
     pub fn new(settings: Settings, dispatcher: Arc<Dispatcher>, id: Uuid) -> Self {
+        let slow_query_log = SlowQueryLog::new(&settings.logger.slow_query);
+
         Self {
             process_id: id,
             settings,
@@ -69,6 +73,7 @@ impl TelemetryCollector {
             tonic_telemetry_collector: Arc::new(Mutex::new(TonicTelemetryCollector {
                 workers: Vec::new(),
             })),
+            slow_query_log,
         }
     }
 
@@ -2,3 +2,4 @@ pub mod app_telemetry;
 pub mod cluster_telemetry;
 pub mod collections_telemetry;
 pub mod requests_telemetry;
+pub mod slow_queries_telemetry;
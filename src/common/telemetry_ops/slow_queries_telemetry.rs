@@ -0,0 +1,245 @@
+use std::collections::VecDeque;
+use std::sync::{mpsc, Arc};
+use std::thread;
+
+use chrono::{DateTime, Utc};
+use collection::events::{SlowRequestEvent, SlowRequestType};
+use issues::broker::Subscriber;
+use parking_lot::Mutex;
+use schemars::JsonSchema;
+use segment::types::Filter;
+use serde::Serialize;
+use serde_json::Value;
+use sha2::{Digest as _, Sha256};
+
+use crate::tracing::slow_query::{self, RotatingLogFile};
+
+#[derive(Serialize, Clone, Copy, Debug, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SlowQueryType {
+    Search,
+    Query,
+    Scroll,
+}
+
+impl From<SlowRequestType> for SlowQueryType {
+    fn from(request_type: SlowRequestType) -> Self {
+        match request_type {
+            SlowRequestType::Search => Self::Search,
+            SlowRequestType::Query => Self::Query,
+            SlowRequestType::Scroll => Self::Scroll,
+        }
+    }
+}
+
+/// Single request, that took longer than the slow query threshold
+#[derive(Serialize, Clone, Debug, JsonSchema)]
+pub struct SlowQueryRecord {
+    pub timestamp: DateTime<Utc>,
+    pub collection: String,
+    pub request_type: SlowQueryType,
+    /// Hash of the filter structure, with all values stripped.
+    /// Requests with the same filter shape, but different values, share the fingerprint.
+    pub filter_fingerprint: Option<String>,
+    pub limit: usize,
+    pub hnsw_ef: Option<usize>,
+    /// Number of segments read on this peer while executing the request
+    pub segments_touched: usize,
+    /// Number of requests in the batch, this request was part of
+    pub batch_size: usize,
+    /// Time taken by the whole batch, in seconds
+    pub duration: f64,
+    /// Whether the request completed successfully, or returned an error, timed out or was cancelled
+    pub success: bool,
+}
+
+/// Max number of records waiting to be written into the log-file, newer records are dropped
+const LOG_FILE_QUEUE_SIZE: usize = 1024;
+
+/// Keeps the most recent slow queries in memory and optionally appends them to a log-file
+#[derive(Clone)]
+pub struct SlowQueryLog {
+    keep_last: usize,
+    records: Arc<Mutex<VecDeque<SlowQueryRecord>>>,
+    /// Records are written into the log-file by a background thread, not to block request handling
+    log_file_sender: Option<mpsc::SyncSender<String>>,
+}
+
+impl SlowQueryLog {
+    pub fn new(config: &slow_query::Config) -> Self {
+        let log_file = match config.new_log_file() {
+            Ok(log_file) => log_file,
+            Err(err) => {
+                log::error!("Failed to open slow query log-file: {err}");
+                None
+            }
+        };
+
+        let log_file_sender = log_file.and_then(|log_file| match Self::spawn_writer(log_file) {
+            Ok(sender) => Some(sender),
+            Err(err) => {
+                log::error!("Failed to start slow query log writer: {err}");
+                None
+            }
+        });
+
+        Self {
+            keep_last: config.keep_last(),
+            records: Arc::new(Mutex::new(VecDeque::new())),
+            log_file_sender,
+        }
+    }
+
+    fn spawn_writer(mut log_file: RotatingLogFile) -> std::io::Result<mpsc::SyncSender<String>> {
+        let (sender, receiver) = mpsc::sync_channel::<String>(LOG_FILE_QUEUE_SIZE);
+
+        thread::Builder::new()
+            .name("slow-query-log".to_string())
+            .spawn(move || {
+                for line in receiver {
+                    if let Err(err) = log_file.write_line(&line) {
+                        log::warn!("Failed to write slow query log: {err}");
+                    }
+                }
+            })?;
+
+        Ok(sender)
+    }
+
+    /// Most recent slow queries, oldest first
+    pub fn records(&self) -> Vec<SlowQueryRecord> {
+        self.records.lock().iter().cloned().collect()
+    }
+
+    fn add(&self, record: SlowQueryRecord) {
+        if let Some(log_file_sender) = &self.log_file_sender {
+            match serde_json::to_string(&record) {
+                Ok(line) => {
+                    if let Err(mpsc::TrySendError::Full(_)) = log_file_sender.try_send(line) {
+                        log::warn!("Slow query log-file writer is falling behind, record dropped");
+                    }
+                }
+                Err(err) => log::warn!("Failed to serialize slow query record: {err}"),
+            }
+        }
+
+        if self.keep_last == 0 {
+            return;
+        }
+
+        let mut records = self.records.lock();
+        if records.len() >= self.keep_last {
+            records.pop_front();
+        }
+        records.push_back(record);
+    }
+}
+
+impl Subscriber<SlowRequestEvent> for SlowQueryLog {
+    fn notify(&self, event: Arc<SlowRequestEvent>) {
+        let timestamp = Utc::now();
+        let batch_size = event.requests.len();
+
+        for request in &event.requests {
+            self.add(SlowQueryRecord {
+                timestamp,
+                collection: event.collection_id.clone(),
+                request_type: event.request_type.into(),
+                filter_fingerprint: filter_fingerprint(&request.filters),
+                limit: request.limit,
+                hnsw_ef: request.hnsw_ef,
+                segments_touched: event.segments_touched,
+                batch_size,
+                duration: event.duration.as_secs_f64(),
+                success: event.success,
+            });
+        }
+    }
+}
+
+/// Fingerprint of the filters structure: field keys and condition kinds are kept, values are not
+fn filter_fingerprint(filters: &[Filter]) -> Option<String> {
+    if filters.is_empty() {
+        return None;
+    }
+
+    let shape = filters
+        .iter()
+        .map(|filter| serde_json::to_value(filter).map(strip_values))
+        .collect::<Result<Vec<_>, _>>()
+        .ok()?;
+
+    // Stable across restarts and versions, so fingerprints can be compared between log-files
+    let hash = Sha256::digest(Value::Array(shape).to_string());
+
+    Some(format!("{:x}", hash)[..16].to_string())
+}
+
+/// Replace all values, except for field keys, with a placeholder.
+/// Consecutive array elements with the same shape are collapsed into one.
+fn strip_values(value: Value) -> Value {
+    match value {
+        Value::Null => Value::Null,
+        Value::Bool(_) | Value::Number(_) | Value::String(_) => Value::String("?".to_string()),
+        Value::Array(values) => {
+            let mut stripped: Vec<Value> = values.into_iter().map(strip_values).collect();
+            stripped.dedup();
+            Value::Array(stripped)
+        }
+        Value::Object(object) => Value::Object(
+            object
+                .into_iter()
+                .map(|(key, value)| {
+                    let value = match value {
+                        Value::String(_) if key == "key" => value,
+                        value => strip_values(value),
+                    };
+                    (key, value)
+                })
+                .collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use segment::json_path::path;
+    use segment::types::{Condition, FieldCondition, Match, Range};
+
+    use super::*;
+
+    fn filter(key: &str, value: i64) -> Filter {
+        Filter::new_must(Condition::Field(FieldCondition::new_match(
+            path(key),
+            Match::from(value),
+        )))
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_values() {
+        assert_eq!(
+            filter_fingerprint(&[filter("a", 1)]),
+            filter_fingerprint(&[filter("a", 2)]),
+        );
+
+        assert_ne!(
+            filter_fingerprint(&[filter("a", 1)]),
+            filter_fingerprint(&[filter("b", 1)]),
+        );
+
+        let range = Filter::new_must(Condition::Field(FieldCondition::new_range(
+            path("a"),
+            Range {
+                gte: Some(1.0),
+                ..Default::default()
+            },
+        )));
+
+        assert_ne!(
+            filter_fingerprint(&[filter("a", 1)]),
+            filter_fingerprint(&[range]),
+        );
+
+        assert_eq!(filter_fingerprint(&[]), None);
+    }
+}
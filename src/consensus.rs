@@ -1345,6 +1345,10 @@ mod tests {
                             init_from: None,
                             quantization_config: None,
                             sharding_method: None,
+                            strict_mode_config: None,
+                            payload_validation_config: None,
+                            expiry_field: None,
+                            trash_retention_sec: None,
                         },
                     )),
                     Access::full("For test"),
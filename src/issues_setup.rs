@@ -1,12 +1,16 @@
 use std::time::Duration;
 
-use collection::events::{CollectionDeletedEvent, IndexCreatedEvent, SlowQueryEvent};
+use collection::events::{
+    CollectionDeletedEvent, IndexCreatedEvent, SlowQueryEvent, SlowRequestEvent,
+    SLOW_REQUEST_LOG_THRESHOLD,
+};
 use segment::problems::unindexed_field;
 use storage::issues_subscribers::UnindexedFieldSubscriber;
 
+use crate::common::telemetry_ops::slow_queries_telemetry::SlowQueryLog;
 use crate::settings::Settings;
 
-pub fn setup_subscribers(settings: &Settings) {
+pub fn setup_subscribers(settings: &Settings, slow_query_log: SlowQueryLog) {
     settings
         .service
         .slow_query_secs
@@ -17,4 +21,13 @@ pub fn setup_subscribers(settings: &Settings) {
     issues::broker::add_subscriber::<SlowQueryEvent>(Box::new(unindexed_subscriber));
     issues::broker::add_subscriber::<IndexCreatedEvent>(Box::new(unindexed_subscriber));
     issues::broker::add_subscriber::<CollectionDeletedEvent>(Box::new(unindexed_subscriber));
+
+    let slow_query_config = &settings.logger.slow_query;
+
+    if slow_query_config.is_enabled() {
+        let _ =
+            SLOW_REQUEST_LOG_THRESHOLD.set(Duration::from_millis(slow_query_config.threshold_ms()));
+
+        issues::broker::add_subscriber::<SlowRequestEvent>(Box::new(slow_query_log));
+    }
 }
@@ -381,6 +381,7 @@ fn main() -> anyhow::Result<()> {
     };
 
     let tonic_telemetry_collector = telemetry_collector.tonic_telemetry_collector.clone();
+    let slow_query_log = telemetry_collector.slow_query_log.clone();
 
     //
     // Telemetry reporting
@@ -398,7 +399,7 @@ fn main() -> anyhow::Result<()> {
     }
 
     // Setup subscribers to listen for issue-able events
-    issues_setup::setup_subscribers(&settings);
+    issues_setup::setup_subscribers(&settings, slow_query_log);
 
     // Helper to better log start errors
     let log_err_if_any = |server_name, result| match result {
@@ -63,6 +63,10 @@ pub async fn handle_existing_collections(
                 optimizers_config: Some(collection_state.config.optimizer_config.into()),
                 init_from: None,
                 quantization_config: collection_state.config.quantization_config,
+                strict_mode_config: collection_state.config.strict_mode_config,
+                payload_validation_config: collection_state.config.payload_validation_config,
+                expiry_field: collection_state.config.params.expiry_field,
+                trash_retention_sec: collection_state.config.params.trash_retention_sec,
             },
         );
 
@@ -2,7 +2,7 @@ use api::grpc::models::{CollectionsResponse, VersionInfo};
 use api::rest::{QueryRequest, QueryRequestBatch, QueryResponse, Record, ScoredPoint};
 use collection::operations::cluster_ops::ClusterOperations;
 use collection::operations::consistency_params::ReadConsistency;
-use collection::operations::payload_ops::{DeletePayload, SetPayload};
+use collection::operations::payload_ops::{DeletePayload, MutatePayload, SetPayload};
 use collection::operations::point_ops::{PointInsertOperations, PointsSelector, WriteOrdering};
 use collection::operations::snapshot_ops::{
     ShardSnapshotRecover, SnapshotDescription, SnapshotRecover,
@@ -26,6 +26,7 @@ use storage::types::ClusterStatus;
 use crate::common::helpers::LocksOption;
 use crate::common::points::{CreateFieldIndex, UpdateOperations};
 use crate::common::telemetry::TelemetryData;
+use crate::common::telemetry_ops::slow_queries_telemetry::SlowQueryRecord;
 
 mod actix;
 mod common;
@@ -84,6 +85,8 @@ struct AllDefinitions {
     be: QueryRequest,
     bf: QueryRequestBatch,
     bg: QueryResponse,
+    bh: SlowQueryRecord,
+    bi: MutatePayload,
 }
 
 fn save_schema<T: JsonSchema>() {
@@ -1,3 +1,4 @@
+use std::pin::Pin;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -6,26 +7,28 @@ use api::grpc::qdrant::{
     ClearPayloadPoints, CountPoints, CountResponse, CreateFieldIndexCollection,
     DeleteFieldIndexCollection, DeletePayloadPoints, DeletePointVectors, DeletePoints,
     DiscoverBatchPoints, DiscoverBatchResponse, DiscoverPoints, DiscoverResponse, GetPoints,
-    GetResponse, PointsOperationResponse, QueryBatchPoints, QueryBatchResponse, QueryPoints,
-    QueryResponse, RecommendBatchPoints, RecommendBatchResponse, RecommendGroupsResponse,
-    RecommendPointGroups, RecommendPoints, RecommendResponse, ScrollPoints, ScrollResponse,
-    SearchBatchPoints, SearchBatchResponse, SearchGroupsResponse, SearchPointGroups, SearchPoints,
-    SearchResponse, SetPayloadPoints, UpdateBatchPoints, UpdateBatchResponse, UpdatePointVectors,
-    UpsertPoints,
+    GetResponse, MutatePayloadPoints, PointsOperationResponse, QueryBatchPoints,
+    QueryBatchResponse, QueryPoints, QueryResponse, RecommendBatchPoints, RecommendBatchResponse,
+    RecommendGroupsResponse, RecommendPointGroups, RecommendPoints, RecommendResponse,
+    RestorePoints, ScrollPoints, ScrollResponse, SearchBatchPoints, SearchBatchResponse,
+    SearchGroupsResponse, SearchPointGroups, SearchPoints, SearchResponse, SetPayloadPoints,
+    SubscribeUpdatesRequest, SubscribeUpdatesResponse, UpdateBatchPoints, UpdateBatchResponse,
+    UpdatePointVectors, UpsertPoints,
 };
 use collection::operations::types::CoreSearchRequest;
+use futures::Stream;
 use storage::dispatcher::Dispatcher;
 use tonic::{Request, Response, Status};
 
 use super::points_common::{
-    delete_vectors, discover, discover_batch, query, query_batch, recommend_groups, search_groups,
-    update_batch, update_vectors,
+    delete_vectors, discover, discover_batch, query, query_batch, recommend_groups, restore,
+    search_groups, subscribe_updates, update_batch, update_vectors,
 };
 use super::validate;
 use crate::tonic::api::points_common::{
     clear_payload, convert_shard_selector_for_read, core_search_batch, count, create_field_index,
-    delete, delete_field_index, delete_payload, get, overwrite_payload, recommend, recommend_batch,
-    scroll, search, set_payload, upsert,
+    delete, delete_field_index, delete_payload, get, mutate_payload, overwrite_payload, recommend,
+    recommend_batch, scroll, search, set_payload, upsert,
 };
 use crate::tonic::auth::extract_access;
 
@@ -79,6 +82,26 @@ impl Points for PointsService {
         .map(|resp| resp.map(Into::into))
     }
 
+    async fn restore(
+        &self,
+        mut request: Request<RestorePoints>,
+    ) -> Result<Response<PointsOperationResponse>, Status> {
+        validate(request.get_ref())?;
+
+        let access = extract_access(&mut request);
+
+        restore(
+            self.dispatcher.toc(&access).clone(),
+            request.into_inner(),
+            None,
+            None,
+            None,
+            access,
+        )
+        .await
+        .map(|resp| resp.map(Into::into))
+    }
+
     async fn get(&self, mut request: Request<GetPoints>) -> Result<Response<GetResponse>, Status> {
         validate(request.get_ref())?;
 
@@ -169,6 +192,25 @@ impl Points for PointsService {
         .map(|resp| resp.map(Into::into))
     }
 
+    async fn mutate_payload(
+        &self,
+        mut request: Request<MutatePayloadPoints>,
+    ) -> Result<Response<PointsOperationResponse>, Status> {
+        validate(request.get_ref())?;
+
+        let access = extract_access(&mut request);
+
+        mutate_payload(
+            self.dispatcher.toc(&access).clone(),
+            request.into_inner(),
+            None,
+            None,
+            access,
+        )
+        .await
+        .map(|resp| resp.map(Into::into))
+    }
+
     async fn delete_payload(
         &self,
         mut request: Request<DeletePayloadPoints>,
@@ -485,4 +527,25 @@ impl Points for PointsService {
         )
         .await
     }
+
+    type SubscribeUpdatesStream =
+        Pin<Box<dyn Stream<Item = Result<SubscribeUpdatesResponse, Status>> + Send>>;
+
+    async fn subscribe_updates(
+        &self,
+        mut request: Request<SubscribeUpdatesRequest>,
+    ) -> Result<Response<Self::SubscribeUpdatesStream>, Status> {
+        validate(request.get_ref())?;
+
+        let access = extract_access(&mut request);
+
+        let updates = subscribe_updates(
+            self.dispatcher.toc(&access).clone(),
+            request.into_inner(),
+            access,
+        )
+        .await?;
+
+        Ok(Response::new(Box::pin(updates)))
+    }
 }
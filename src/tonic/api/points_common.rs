@@ -1,3 +1,4 @@
+use std::collections::VecDeque;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
@@ -8,33 +9,38 @@ use api::grpc::qdrant::{
     points_update_operation, BatchResult, ClearPayloadPoints, CoreSearchPoints, CountPoints,
     CountResponse, CreateFieldIndexCollection, DeleteFieldIndexCollection, DeletePayloadPoints,
     DeletePointVectors, DeletePoints, DiscoverBatchResponse, DiscoverPoints, DiscoverResponse,
-    FieldType, GetPoints, GetResponse, PayloadIndexParams, PointsOperationResponseInternal,
-    PointsSelector, QueryBatchResponse, QueryPoints, QueryResponse,
-    ReadConsistency as ReadConsistencyGrpc, RecommendBatchResponse, RecommendGroupsResponse,
-    RecommendPointGroups, RecommendPoints, RecommendResponse, ScrollPoints, ScrollResponse,
-    SearchBatchResponse, SearchGroupsResponse, SearchPointGroups, SearchPoints, SearchResponse,
-    SetPayloadPoints, SyncPoints, UpdateBatchPoints, UpdateBatchResponse, UpdatePointVectors,
-    UpsertPoints,
+    FieldType, GetPoints, GetResponse, MutatePayloadPoints, PayloadIndexParams,
+    PointsOperationResponseInternal, PointsSelector, QueryBatchResponse, QueryPoints,
+    QueryResponse, ReadConsistency as ReadConsistencyGrpc, RecommendBatchResponse,
+    RecommendGroupsResponse, RecommendPointGroups, RecommendPoints, RecommendResponse,
+    RestorePoints, ScrollPoints, ScrollResponse, SearchBatchResponse, SearchGroupsResponse,
+    SearchPointGroups, SearchPoints, SearchResponse, SetPayloadPoints, SubscribeUpdatesRequest,
+    SubscribeUpdatesResponse, SyncPoints, UpdateBatchPoints, UpdateBatchResponse, UpdateClock,
+    UpdatePointVectors, UpsertPoints,
 };
 use api::rest::{OrderByInterface, ShardKeySelector};
+use chrono::{DateTime, Utc};
 use collection::operations::consistency_params::ReadConsistency;
 use collection::operations::conversions::{
     try_discover_request_from_grpc, try_points_selector_from_grpc, write_ordering_from_proto,
 };
-use collection::operations::payload_ops::DeletePayload;
+use collection::operations::payload_ops::{DeletePayload, MutatePayload, PayloadMutation};
 use collection::operations::point_ops::{
-    self, PointInsertOperations, PointOperations, PointSyncOperation, PointsList,
+    self, PointInsertOperations, PointOperations, PointSyncOperation, PointsList, UpdateMode,
 };
 use collection::operations::query_enum::QueryEnum;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::types::{
-    default_exact_count, CoreSearchRequest, CoreSearchRequestBatch, PointRequestInternal,
-    RecommendExample, Record, ScrollRequestInternal,
+    default_exact_count, CoreSearchRequest, CoreSearchRequestBatch, FilterUpdateChecks,
+    PointRequestInternal, RecommendExample, Record, ScrollRequestInternal,
 };
 use collection::operations::universal_query::collection_query::CollectionQueryRequest;
 use collection::operations::vector_ops::{DeleteVectors, PointVectors, UpdateVectors};
 use collection::operations::{ClockTag, CollectionUpdateOperations, OperationWithClockTag};
+use collection::shards::conversions::update_operation_to_grpc;
 use collection::shards::shard::ShardId;
+use collection::wal_delta::WalUpdatesReader;
+use futures::Stream;
 use itertools::Itertools;
 use segment::data_types::order_by::OrderBy;
 use segment::data_types::vectors::VectorStructInternal;
@@ -48,11 +54,15 @@ use storage::rbac::Access;
 use tonic::{Response, Status};
 
 use crate::common::points::{
-    do_clear_payload, do_core_search_points, do_count_points, do_create_index,
-    do_create_index_internal, do_delete_index, do_delete_index_internal, do_delete_payload,
-    do_delete_points, do_delete_vectors, do_get_points, do_overwrite_payload,
-    do_query_batch_points, do_query_points, do_scroll_points, do_search_batch_points,
-    do_set_payload, do_update_vectors, do_upsert_points, CreateFieldIndex,
+    do_atomic_batch_update_points, do_clear_payload, do_core_search_points, do_count_points,
+    do_create_index, do_create_index_internal, do_delete_index, do_delete_index_internal,
+    do_delete_payload, do_delete_points, do_delete_vectors, do_explain_query_points,
+    do_explain_search_points, do_get_points, do_mutate_payload, do_overwrite_payload,
+    do_query_batch_points, do_query_points, do_restore_points, do_scroll_points,
+    do_search_batch_points, do_set_payload, do_trash_points, do_update_vectors, do_upsert_points,
+    ClearPayloadOperation, CreateFieldIndex, DeleteOperation, DeletePayloadOperation,
+    DeleteVectorsOperation, OverwritePayloadOperation, SetPayloadOperation, UpdateOperation,
+    UpdateVectorsOperation, UpsertOperation,
 };
 
 fn extract_points_selector(
@@ -111,6 +121,9 @@ pub async fn upsert(
         points,
         ordering,
         shard_key_selector,
+        update_filter,
+        update_mode,
+        idempotency_key,
     } = upsert_points;
     let points = points
         .into_iter()
@@ -119,6 +132,9 @@ pub async fn upsert(
     let operation = PointInsertOperations::PointsList(PointsList {
         points,
         shard_key: shard_key_selector.map(ShardKeySelector::from),
+        update_filter: update_filter.map(TryInto::try_into).transpose()?,
+        update_mode: update_mode.map(UpdateMode::try_from).transpose()?,
+        idempotency_key,
     });
     let timing = Instant::now();
     let result = do_upsert_points(
@@ -205,6 +221,8 @@ pub async fn delete(
         points,
         ordering,
         shard_key_selector,
+        dry_run,
+        max_affected_points,
     } = delete_points;
 
     let points_selector = match points {
@@ -222,6 +240,7 @@ pub async fn delete(
         wait.unwrap_or(false),
         write_ordering_from_proto(ordering)?,
         access,
+        filter_update_checks(dry_run, max_affected_points),
     )
     .await
     .map_err(error_to_status)?;
@@ -230,21 +249,102 @@ pub async fn delete(
     Ok(Response::new(response))
 }
 
-pub async fn update_vectors(
+/// Move points to trash at the given time, replicated by a peer instead of deleting them
+pub async fn trash(
     toc: Arc<TableOfContent>,
-    update_point_vectors: UpdatePointVectors,
+    delete_points: DeletePoints,
+    trashed_at: DateTime<Utc>,
     clock_tag: Option<ClockTag>,
     shard_selection: Option<ShardId>,
     access: Access,
 ) -> Result<Response<PointsOperationResponseInternal>, Status> {
-    let UpdatePointVectors {
+    let DeletePoints {
         collection_name,
         wait,
         points,
         ordering,
         shard_key_selector,
-    } = update_point_vectors;
+        dry_run: _,
+        max_affected_points: _,
+    } = delete_points;
+
+    let points_selector = match points {
+        None => return Err(Status::invalid_argument("PointSelector is missing")),
+        Some(p) => try_points_selector_from_grpc(p, shard_key_selector)?,
+    };
+
+    let timing = Instant::now();
+    let result = do_trash_points(
+        toc,
+        collection_name,
+        points_selector,
+        trashed_at,
+        clock_tag,
+        shard_selection,
+        wait.unwrap_or(false),
+        write_ordering_from_proto(ordering)?,
+        access,
+    )
+    .await
+    .map_err(error_to_status)?;
+
+    let response = points_operation_response_internal(timing, result);
+    Ok(Response::new(response))
+}
+
+pub async fn restore(
+    toc: Arc<TableOfContent>,
+    restore_points: RestorePoints,
+    trashed_after: Option<DateTime<Utc>>,
+    clock_tag: Option<ClockTag>,
+    shard_selection: Option<ShardId>,
+    access: Access,
+) -> Result<Response<PointsOperationResponseInternal>, Status> {
+    let RestorePoints {
+        collection_name,
+        wait,
+        points,
+        ordering,
+        shard_key_selector,
+    } = restore_points;
+
+    let points_selector = match points {
+        None => return Err(Status::invalid_argument("PointSelector is missing")),
+        Some(p) => try_points_selector_from_grpc(p, shard_key_selector)?,
+    };
+
+    let timing = Instant::now();
+    let result = do_restore_points(
+        toc,
+        collection_name,
+        points_selector,
+        trashed_after,
+        clock_tag,
+        shard_selection,
+        wait.unwrap_or(false),
+        write_ordering_from_proto(ordering)?,
+        access,
+    )
+    .await
+    .map_err(error_to_status)?;
 
+    let response = points_operation_response_internal(timing, result);
+    Ok(Response::new(response))
+}
+
+fn filter_update_checks(
+    dry_run: Option<bool>,
+    max_affected_points: Option<u64>,
+) -> FilterUpdateChecks {
+    FilterUpdateChecks {
+        dry_run: dry_run.unwrap_or(false),
+        max_affected_points: max_affected_points.map(|limit| limit as usize),
+    }
+}
+
+fn convert_point_vectors(
+    points: Vec<api::grpc::qdrant::PointVectors>,
+) -> Result<Vec<PointVectors>, Status> {
     // Build list of operation points
     let mut op_points = Vec::with_capacity(points.len());
     for point in points {
@@ -261,10 +361,29 @@ pub async fn update_vectors(
             vector: api::rest::VectorStruct::from(vector),
         });
     }
+    Ok(op_points)
+}
+
+pub async fn update_vectors(
+    toc: Arc<TableOfContent>,
+    update_point_vectors: UpdatePointVectors,
+    clock_tag: Option<ClockTag>,
+    shard_selection: Option<ShardId>,
+    access: Access,
+) -> Result<Response<PointsOperationResponseInternal>, Status> {
+    let UpdatePointVectors {
+        collection_name,
+        wait,
+        points,
+        ordering,
+        shard_key_selector,
+        update_filter,
+    } = update_point_vectors;
 
     let operation = UpdateVectors {
-        points: op_points,
+        points: convert_point_vectors(points)?,
         shard_key: shard_key_selector.map(ShardKeySelector::from),
+        update_filter: update_filter.map(TryInto::try_into).transpose()?,
     };
 
     let timing = Instant::now();
@@ -347,6 +466,8 @@ pub async fn set_payload(
         ordering,
         shard_key_selector,
         key,
+        dry_run,
+        max_affected_points,
     } = set_payload_points;
     let key = key.map(|k| json_path_from_proto(&k)).transpose()?;
 
@@ -369,6 +490,52 @@ pub async fn set_payload(
         wait.unwrap_or(false),
         write_ordering_from_proto(ordering)?,
         access,
+        filter_update_checks(dry_run, max_affected_points),
+    )
+    .await
+    .map_err(error_to_status)?;
+
+    let response = points_operation_response_internal(timing, result);
+    Ok(Response::new(response))
+}
+
+pub async fn mutate_payload(
+    toc: Arc<TableOfContent>,
+    mutate_payload_points: MutatePayloadPoints,
+    clock_tag: Option<ClockTag>,
+    shard_selection: Option<ShardId>,
+    access: Access,
+) -> Result<Response<PointsOperationResponseInternal>, Status> {
+    let MutatePayloadPoints {
+        collection_name,
+        wait,
+        mutations,
+        points_selector,
+        ordering,
+        shard_key_selector,
+    } = mutate_payload_points;
+
+    let (points, filter) = extract_points_selector(points_selector)?;
+    let operation = MutatePayload {
+        mutations: mutations
+            .into_iter()
+            .map(PayloadMutation::try_from)
+            .collect::<Result<_, _>>()?,
+        points,
+        filter,
+        shard_key: shard_key_selector.map(ShardKeySelector::from),
+    };
+
+    let timing = Instant::now();
+    let result = do_mutate_payload(
+        toc,
+        collection_name,
+        operation,
+        clock_tag,
+        shard_selection,
+        wait.unwrap_or(false),
+        write_ordering_from_proto(ordering)?,
+        access,
     )
     .await
     .map_err(error_to_status)?;
@@ -391,6 +558,8 @@ pub async fn overwrite_payload(
         points_selector,
         ordering,
         shard_key_selector,
+        dry_run,
+        max_affected_points,
         ..
     } = set_payload_points;
 
@@ -414,6 +583,7 @@ pub async fn overwrite_payload(
         wait.unwrap_or(false),
         write_ordering_from_proto(ordering)?,
         access,
+        filter_update_checks(dry_run, max_affected_points),
     )
     .await
     .map_err(error_to_status)?;
@@ -436,6 +606,8 @@ pub async fn delete_payload(
         points_selector,
         ordering,
         shard_key_selector,
+        dry_run,
+        max_affected_points,
     } = delete_payload_points;
     let keys = keys.iter().map(|k| json_path_from_proto(k)).try_collect()?;
 
@@ -457,6 +629,7 @@ pub async fn delete_payload(
         wait.unwrap_or(false),
         write_ordering_from_proto(ordering)?,
         access,
+        filter_update_checks(dry_run, max_affected_points),
     )
     .await
     .map_err(error_to_status)?;
@@ -478,6 +651,8 @@ pub async fn clear_payload(
         points,
         ordering,
         shard_key_selector,
+        dry_run,
+        max_affected_points,
     } = clear_payload_points;
 
     let points_selector = match points {
@@ -495,6 +670,7 @@ pub async fn clear_payload(
         wait.unwrap_or(false),
         write_ordering_from_proto(ordering)?,
         access,
+        filter_update_checks(dry_run, max_affected_points),
     )
     .await
     .map_err(error_to_status)?;
@@ -515,8 +691,41 @@ pub async fn update_batch(
         wait,
         operations,
         ordering,
+        atomic,
+        idempotency_key,
     } = update_batch_points;
 
+    if atomic.unwrap_or(false) {
+        let operations_count = operations.len();
+        let response = update_batch_atomic(
+            toc,
+            collection_name,
+            wait,
+            operations,
+            ordering,
+            idempotency_key,
+            None,
+            clock_tag,
+            shard_selection,
+            access,
+        )
+        .await?
+        .into_inner();
+
+        // The batch is applied as a whole, so every operation shares the same result
+        let result = response.result.unwrap();
+        return Ok(Response::new(UpdateBatchResponse {
+            result: vec![result.into(); operations_count],
+            time: response.time,
+        }));
+    }
+
+    if idempotency_key.is_some() {
+        return Err(Status::invalid_argument(
+            "Idempotency key is only supported for atomic batches",
+        ));
+    }
+
     let timing = Instant::now();
     let mut results = Vec::with_capacity(operations.len());
     for op in operations {
@@ -529,6 +738,8 @@ pub async fn update_batch(
             points_update_operation::Operation::Upsert(PointStructList {
                 points,
                 shard_key_selector,
+                update_filter,
+                update_mode,
             }) => {
                 upsert(
                     toc.clone(),
@@ -538,6 +749,9 @@ pub async fn update_batch(
                         wait,
                         ordering,
                         shard_key_selector,
+                        update_filter,
+                        update_mode,
+                        idempotency_key: None,
                     },
                     clock_tag,
                     shard_selection,
@@ -554,6 +768,8 @@ pub async fn update_batch(
                         points: Some(points),
                         ordering,
                         shard_key_selector: None,
+                        dry_run: None,
+                        max_affected_points: None,
                     },
                     clock_tag,
                     shard_selection,
@@ -579,6 +795,8 @@ pub async fn update_batch(
                         ordering,
                         shard_key_selector,
                         key,
+                        dry_run: None,
+                        max_affected_points: None,
                     },
                     clock_tag,
                     shard_selection,
@@ -605,6 +823,8 @@ pub async fn update_batch(
                         shard_key_selector,
                         // overwrite operation don't support it
                         key: None,
+                        dry_run: None,
+                        max_affected_points: None,
                     },
                     clock_tag,
                     shard_selection,
@@ -628,6 +848,8 @@ pub async fn update_batch(
                         points_selector,
                         ordering,
                         shard_key_selector,
+                        dry_run: None,
+                        max_affected_points: None,
                     },
                     clock_tag,
                     shard_selection,
@@ -647,6 +869,8 @@ pub async fn update_batch(
                         points,
                         ordering,
                         shard_key_selector,
+                        dry_run: None,
+                        max_affected_points: None,
                     },
                     clock_tag,
                     shard_selection,
@@ -658,6 +882,7 @@ pub async fn update_batch(
                 points_update_operation::UpdateVectors {
                     points,
                     shard_key_selector,
+                    update_filter,
                 },
             ) => {
                 update_vectors(
@@ -668,6 +893,7 @@ pub async fn update_batch(
                         points,
                         ordering,
                         shard_key_selector,
+                        update_filter,
                     },
                     clock_tag,
                     shard_selection,
@@ -707,6 +933,8 @@ pub async fn update_batch(
                         points: Some(selector),
                         ordering,
                         shard_key_selector: None,
+                        dry_run: None,
+                        max_affected_points: None,
                     },
                     clock_tag,
                     shard_selection,
@@ -726,6 +954,8 @@ pub async fn update_batch(
                         points,
                         ordering,
                         shard_key_selector,
+                        dry_run: None,
+                        max_affected_points: None,
                     },
                     clock_tag,
                     shard_selection,
@@ -745,6 +975,177 @@ pub async fn update_batch(
     }))
 }
 
+fn update_operation_from_grpc(operation: Operation) -> Result<UpdateOperation, Status> {
+    let operation = match operation {
+        Operation::Upsert(PointStructList {
+            points,
+            shard_key_selector,
+            update_filter,
+            update_mode,
+        }) => UpdateOperation::Upsert(UpsertOperation {
+            upsert: PointInsertOperations::PointsList(PointsList {
+                points: points
+                    .into_iter()
+                    .map(|point| point.try_into())
+                    .collect::<Result<_, _>>()?,
+                shard_key: shard_key_selector.map(ShardKeySelector::from),
+                update_filter: update_filter.map(TryInto::try_into).transpose()?,
+                update_mode: update_mode.map(UpdateMode::try_from).transpose()?,
+                idempotency_key: None,
+            }),
+        }),
+        Operation::DeleteDeprecated(points) => UpdateOperation::Delete(DeleteOperation {
+            delete: try_points_selector_from_grpc(points, None)?,
+        }),
+        Operation::SetPayload(points_update_operation::SetPayload {
+            payload,
+            points_selector,
+            shard_key_selector,
+            key,
+        }) => {
+            let (points, filter) = extract_points_selector(points_selector)?;
+            UpdateOperation::SetPayload(SetPayloadOperation {
+                set_payload: collection::operations::payload_ops::SetPayload {
+                    payload: proto_to_payloads(payload)?,
+                    points,
+                    filter,
+                    shard_key: shard_key_selector.map(ShardKeySelector::from),
+                    key: key.map(|k| json_path_from_proto(&k)).transpose()?,
+                },
+            })
+        }
+        Operation::OverwritePayload(points_update_operation::OverwritePayload {
+            payload,
+            points_selector,
+            shard_key_selector,
+            ..
+        }) => {
+            let (points, filter) = extract_points_selector(points_selector)?;
+            UpdateOperation::OverwritePayload(OverwritePayloadOperation {
+                overwrite_payload: collection::operations::payload_ops::SetPayload {
+                    payload: proto_to_payloads(payload)?,
+                    points,
+                    filter,
+                    shard_key: shard_key_selector.map(ShardKeySelector::from),
+                    // overwrite operation don't support indicate path of property
+                    key: None,
+                },
+            })
+        }
+        Operation::DeletePayload(points_update_operation::DeletePayload {
+            keys,
+            points_selector,
+            shard_key_selector,
+        }) => {
+            let (points, filter) = extract_points_selector(points_selector)?;
+            UpdateOperation::DeletePayload(DeletePayloadOperation {
+                delete_payload: DeletePayload {
+                    keys: keys.iter().map(|k| json_path_from_proto(k)).try_collect()?,
+                    points,
+                    filter,
+                    shard_key: shard_key_selector.map(ShardKeySelector::from),
+                },
+            })
+        }
+        Operation::ClearPayload(ClearPayload {
+            points,
+            shard_key_selector,
+        }) => UpdateOperation::ClearPayload(ClearPayloadOperation {
+            clear_payload: match points {
+                None => return Err(Status::invalid_argument("PointSelector is missing")),
+                Some(p) => try_points_selector_from_grpc(p, shard_key_selector)?,
+            },
+        }),
+        Operation::ClearPayloadDeprecated(selector) => {
+            UpdateOperation::ClearPayload(ClearPayloadOperation {
+                clear_payload: try_points_selector_from_grpc(selector, None)?,
+            })
+        }
+        Operation::UpdateVectors(points_update_operation::UpdateVectors {
+            points,
+            shard_key_selector,
+            update_filter,
+        }) => UpdateOperation::UpdateVectors(UpdateVectorsOperation {
+            update_vectors: UpdateVectors {
+                points: convert_point_vectors(points)?,
+                shard_key: shard_key_selector.map(ShardKeySelector::from),
+                update_filter: update_filter.map(TryInto::try_into).transpose()?,
+            },
+        }),
+        Operation::DeleteVectors(points_update_operation::DeleteVectors {
+            points_selector,
+            vectors,
+            shard_key_selector,
+        }) => {
+            let (points, filter) = extract_points_selector(points_selector)?;
+            let vector_names = match vectors {
+                Some(vectors) => vectors.names,
+                None => return Err(Status::invalid_argument("vectors is expected")),
+            };
+            UpdateOperation::DeleteVectors(DeleteVectorsOperation {
+                delete_vectors: DeleteVectors {
+                    points,
+                    filter,
+                    vector: vector_names.into_iter().collect(),
+                    shard_key: shard_key_selector.map(ShardKeySelector::from),
+                },
+            })
+        }
+        Operation::DeletePoints(points_update_operation::DeletePoints {
+            points,
+            shard_key_selector,
+        }) => UpdateOperation::Delete(DeleteOperation {
+            delete: match points {
+                None => return Err(Status::invalid_argument("PointSelector is missing")),
+                Some(p) => try_points_selector_from_grpc(p, shard_key_selector)?,
+            },
+        }),
+    };
+    Ok(operation)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn update_batch_atomic(
+    toc: Arc<TableOfContent>,
+    collection_name: String,
+    wait: Option<bool>,
+    operations: Vec<api::grpc::qdrant::PointsUpdateOperation>,
+    ordering: Option<api::grpc::qdrant::WriteOrdering>,
+    idempotency_key: Option<String>,
+    trashed_at: Option<DateTime<Utc>>,
+    clock_tag: Option<ClockTag>,
+    shard_selection: Option<ShardId>,
+    access: Access,
+) -> Result<Response<PointsOperationResponseInternal>, Status> {
+    let operations = operations
+        .into_iter()
+        .map(|op| {
+            op.operation
+                .ok_or(Status::invalid_argument("Operation is missing"))
+                .and_then(update_operation_from_grpc)
+        })
+        .collect::<Result<_, _>>()?;
+
+    let timing = Instant::now();
+    let result = do_atomic_batch_update_points(
+        toc,
+        collection_name,
+        operations,
+        idempotency_key,
+        trashed_at,
+        clock_tag,
+        shard_selection,
+        wait.unwrap_or(false),
+        write_ordering_from_proto(ordering)?,
+        access,
+    )
+    .await
+    .map_err(error_to_status)?;
+
+    let response = points_operation_response_internal(timing, result);
+    Ok(Response::new(response))
+}
+
 fn convert_field_type(
     field_type: Option<i32>,
     field_index_params: Option<PayloadIndexParams>,
@@ -965,6 +1366,7 @@ pub async fn search(
         timeout,
         shard_key_selector,
         sparse_indices,
+        explain,
     } = search_points;
 
     let vector_struct =
@@ -990,17 +1392,33 @@ pub async fn search(
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
 
     let timing = Instant::now();
-    let scored_points = do_core_search_points(
-        toc,
-        &collection_name,
-        search_request,
-        read_consistency,
-        shard_selector,
-        access,
-        timeout.map(Duration::from_secs),
-    )
-    .await
-    .map_err(error_to_status)?;
+    let (scored_points, explanation) = if explain.unwrap_or_default() {
+        let (scored_points, explanation) = do_explain_search_points(
+            toc,
+            &collection_name,
+            search_request,
+            read_consistency,
+            shard_selector,
+            access,
+            timeout.map(Duration::from_secs),
+        )
+        .await
+        .map_err(error_to_status)?;
+        (scored_points, Some(explanation))
+    } else {
+        let scored_points = do_core_search_points(
+            toc,
+            &collection_name,
+            search_request,
+            read_consistency,
+            shard_selector,
+            access,
+            timeout.map(Duration::from_secs),
+        )
+        .await
+        .map_err(error_to_status)?;
+        (scored_points, None)
+    };
 
     let response = SearchResponse {
         result: scored_points
@@ -1008,6 +1426,7 @@ pub async fn search(
             .map(|point| point.into())
             .collect(),
         time: timing.elapsed().as_secs_f64(),
+        explanation: explanation.map(From::from),
     };
 
     Ok(Response::new(response))
@@ -1575,19 +1994,36 @@ pub async fn query(
         .transpose()?;
     let timeout = query_points.timeout.map(Duration::from_secs);
     let collection_name = query_points.collection_name.clone();
+    let explain = query_points.explain.unwrap_or_default();
     let request = CollectionQueryRequest::try_from(query_points)?;
     let timing = Instant::now();
-    let scored_points = do_query_points(
-        toc,
-        &collection_name,
-        request,
-        read_consistency,
-        shard_selector,
-        access,
-        timeout,
-    )
-    .await
-    .map_err(error_to_status)?;
+    let (scored_points, explanation) = if explain {
+        let (scored_points, explanation) = do_explain_query_points(
+            toc,
+            &collection_name,
+            request,
+            read_consistency,
+            shard_selector,
+            access,
+            timeout,
+        )
+        .await
+        .map_err(error_to_status)?;
+        (scored_points, Some(explanation))
+    } else {
+        let scored_points = do_query_points(
+            toc,
+            &collection_name,
+            request,
+            read_consistency,
+            shard_selector,
+            access,
+            timeout,
+        )
+        .await
+        .map_err(error_to_status)?;
+        (scored_points, None)
+    };
 
     let response = QueryResponse {
         result: scored_points
@@ -1595,6 +2031,7 @@ pub async fn query(
             .map(|point| point.into())
             .collect(),
         time: timing.elapsed().as_secs_f64(),
+        explanation: explanation.map(From::from),
     };
 
     Ok(Response::new(response))
@@ -1640,3 +2077,157 @@ pub async fn query_batch(
 
     Ok(Response::new(response))
 }
+
+/// Maximum number of WAL records read at once from each shard, when streaming collection updates
+const SUBSCRIBE_UPDATES_BATCH_SIZE: usize = 128;
+
+/// Position of the updates stream in the WAL of a single shard
+struct ShardUpdatesState {
+    shard_id: ShardId,
+    reader: WalUpdatesReader,
+    /// Operation number to read from the WAL next
+    next_op_num: u64,
+}
+
+/// State of the collection updates stream
+struct SubscribeUpdatesState {
+    collection_name: String,
+    shards: Vec<ShardUpdatesState>,
+    /// Operations already read from the WAL, but not yet sent
+    buffer: VecDeque<(ShardId, u64, OperationWithClockTag)>,
+}
+
+impl SubscribeUpdatesState {
+    async fn next_update(mut self) -> Result<Option<(SubscribeUpdatesResponse, Self)>, Status> {
+        loop {
+            if let Some((shard_id, op_num, operation)) = self.buffer.pop_front() {
+                let OperationWithClockTag {
+                    operation,
+                    clock_tag,
+                } = operation;
+
+                let update = update_operation_to_grpc(self.collection_name.clone(), operation)
+                    .map_err(|err| {
+                        Status::internal(format!(
+                            "Failed to convert operation {op_num} of shard {shard_id}: {err}"
+                        ))
+                    })?;
+
+                let response = SubscribeUpdatesResponse {
+                    shard_id,
+                    op_num,
+                    clock: clock_tag.map(|clock_tag| UpdateClock {
+                        peer_id: clock_tag.peer_id,
+                        clock_id: clock_tag.clock_id,
+                        clock_tick: clock_tag.clock_tick,
+                    }),
+                    update,
+                };
+
+                return Ok(Some((response, self)));
+            }
+
+            if !self.read_updates().await? {
+                self.wait_updates().await?;
+            }
+        }
+    }
+
+    /// Read next operations of all shards into the buffer, returns `false` if there are none
+    async fn read_updates(&mut self) -> Result<bool, Status> {
+        for shard in &mut self.shards {
+            let updates = shard
+                .reader
+                .read(shard.next_op_num, SUBSCRIBE_UPDATES_BATCH_SIZE)
+                .await
+                .map_err(|err| error_to_status(err.into()))?;
+
+            if let Some((op_num, _)) = updates.last() {
+                shard.next_op_num = op_num + 1;
+            }
+
+            let shard_id = shard.shard_id;
+            self.buffer.extend(
+                updates
+                    .into_iter()
+                    .map(|(op_num, operation)| (shard_id, op_num, operation)),
+            );
+        }
+
+        Ok(!self.buffer.is_empty())
+    }
+
+    /// Wait until a new operation is written into the WAL of any shard
+    async fn wait_updates(&mut self) -> Result<(), Status> {
+        let (is_open, index, pending) = futures::future::select_all(
+            self.shards
+                .iter_mut()
+                .map(|shard| Box::pin(shard.reader.wait())),
+        )
+        .await;
+        drop(pending);
+
+        if !is_open {
+            return Err(Status::aborted(format!(
+                "Shard {} was unloaded, subscribe again to continue from the last received operation",
+                self.shards[index].shard_id,
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Stream update operations of all shards of a collection, starting at `start_from` of each shard WAL
+///
+/// Operations of each shard are streamed in order, operations of different shards are interleaved.
+/// The stream never ends by itself, it waits for new updates once all existing are sent.
+/// It fails if the next position to send is already truncated from the WAL.
+/// Shards created after subscribing, e.g. for a new shard key, are not streamed.
+pub async fn subscribe_updates(
+    toc: Arc<TableOfContent>,
+    subscribe_updates: SubscribeUpdatesRequest,
+    access: Access,
+) -> Result<impl Stream<Item = Result<SubscribeUpdatesResponse, Status>> + Send + 'static, Status> {
+    let SubscribeUpdatesRequest {
+        collection_name,
+        start_from,
+    } = subscribe_updates;
+
+    let readers = toc
+        .wal_updates(&collection_name, start_from.keys().copied(), &access)
+        .await
+        .map_err(error_to_status)?;
+
+    if readers.is_empty() {
+        return Err(Status::failed_precondition(format!(
+            "Collection {collection_name} has no shards to stream updates of",
+        )));
+    }
+
+    let shards = readers
+        .into_iter()
+        .map(|(shard_id, reader)| ShardUpdatesState {
+            shard_id,
+            next_op_num: start_from
+                .get(&shard_id)
+                .copied()
+                .unwrap_or_else(|| reader.first_index()),
+            reader,
+        })
+        .collect();
+
+    let mut state = SubscribeUpdatesState {
+        collection_name,
+        shards,
+        buffer: VecDeque::new(),
+    };
+
+    // Read first updates eagerly, to reject the request right away if a position is truncated
+    state.read_updates().await?;
+
+    Ok(futures::stream::try_unfold(
+        state,
+        SubscribeUpdatesState::next_update,
+    ))
+}
@@ -1,15 +1,17 @@
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+use api::grpc::conversions::try_date_time_from_proto;
 use api::grpc::qdrant::points_internal_server::PointsInternal;
 use api::grpc::qdrant::{
     ClearPayloadPointsInternal, CoreSearchBatchPointsInternal, CountPointsInternal, CountResponse,
     CreateFieldIndexCollectionInternal, DeleteFieldIndexCollectionInternal,
     DeletePayloadPointsInternal, DeletePointsInternal, DeleteVectorsInternal, GetPointsInternal,
-    GetResponse, IntermediateResult, PointsOperationResponseInternal, QueryBatchPointsInternal,
-    QueryBatchResponseInternal, QueryResultInternal, QueryShardPoints, RecommendPointsInternal,
-    RecommendResponse, ScrollPointsInternal, ScrollResponse, SearchBatchResponse,
-    SetPayloadPointsInternal, SyncPointsInternal, UpdateVectorsInternal, UpsertPointsInternal,
+    GetResponse, IntermediateResult, MutatePayloadPointsInternal, PointsOperationResponseInternal,
+    QueryBatchPointsInternal, QueryBatchResponseInternal, QueryResultInternal, QueryShardPoints,
+    RecommendPointsInternal, RecommendResponse, RestorePointsInternal, ScrollPointsInternal,
+    ScrollResponse, SearchBatchResponse, SetPayloadPointsInternal, SyncPointsInternal,
+    UpdateBatchInternal, UpdateBatchPoints, UpdateVectorsInternal, UpsertPointsInternal,
 };
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::universal_query::shard_query::ShardQueryRequest;
@@ -24,8 +26,8 @@ use super::points_common::core_search_list;
 use super::validate_and_log;
 use crate::tonic::api::points_common::{
     clear_payload, count, create_field_index_internal, delete, delete_field_index_internal,
-    delete_payload, delete_vectors, get, overwrite_payload, recommend, scroll, set_payload, sync,
-    update_vectors, upsert,
+    delete_payload, delete_vectors, get, mutate_payload, overwrite_payload, recommend, restore,
+    scroll, set_payload, sync, trash, update_batch_atomic, update_vectors, upsert,
 };
 
 const FULL_ACCESS: Access = Access::full("Internal API");
@@ -47,6 +49,7 @@ pub async fn query_batch_internal(
     query_points: Vec<QueryShardPoints>,
     shard_selection: Option<ShardId>,
     timeout: Option<Duration>,
+    explain: bool,
 ) -> Result<Response<QueryBatchResponseInternal>, Status> {
     let batch_requests: Vec<_> = query_points
         .into_iter()
@@ -65,8 +68,14 @@ pub async fn query_batch_internal(
         Some(shard_id) => ShardSelectorInternal::ShardId(shard_id),
     };
 
-    let batch_response = toc
-        .query_batch_internal(&collection_name, batch_requests, shard_selection, timeout)
+    let (batch_response, explanations) = toc
+        .query_batch_internal(
+            &collection_name,
+            batch_requests,
+            shard_selection,
+            timeout,
+            explain,
+        )
         .await
         .map_err(error_to_status)?;
 
@@ -83,6 +92,7 @@ pub async fn query_batch_internal(
             })
             .collect(),
         time: timing.elapsed().as_secs_f64(),
+        explanations: explanations.into_iter().map(From::from).collect(),
     };
 
     Ok(Response::new(response))
@@ -125,11 +135,25 @@ impl PointsInternal for PointsInternalService {
             delete_points,
             shard_id,
             clock_tag,
+            trashed_at,
         } = request.into_inner();
 
         let delete_points =
             delete_points.ok_or_else(|| Status::invalid_argument("DeletePoints is missing"))?;
 
+        // Deletions in collections with trash enabled are replicated as moving to trash
+        if let Some(trashed_at) = trashed_at {
+            return trash(
+                self.toc.clone(),
+                delete_points,
+                try_date_time_from_proto(trashed_at)?.0,
+                clock_tag.map(Into::into),
+                shard_id,
+                FULL_ACCESS.clone(),
+            )
+            .await;
+        }
+
         delete(
             self.toc.clone(),
             delete_points,
@@ -140,6 +164,38 @@ impl PointsInternal for PointsInternalService {
         .await
     }
 
+    async fn restore(
+        &self,
+        request: Request<RestorePointsInternal>,
+    ) -> Result<Response<PointsOperationResponseInternal>, Status> {
+        validate_and_log(request.get_ref());
+
+        let RestorePointsInternal {
+            restore_points,
+            shard_id,
+            clock_tag,
+            trashed_after,
+        } = request.into_inner();
+
+        let restore_points =
+            restore_points.ok_or_else(|| Status::invalid_argument("RestorePoints is missing"))?;
+
+        let trashed_after = trashed_after
+            .map(try_date_time_from_proto)
+            .transpose()?
+            .map(|trashed_after| trashed_after.0);
+
+        restore(
+            self.toc.clone(),
+            restore_points,
+            trashed_after,
+            clock_tag.map(Into::into),
+            shard_id,
+            FULL_ACCESS.clone(),
+        )
+        .await
+    }
+
     async fn update_vectors(
         &self,
         request: Request<UpdateVectorsInternal>,
@@ -215,6 +271,31 @@ impl PointsInternal for PointsInternalService {
         .await
     }
 
+    async fn mutate_payload(
+        &self,
+        request: Request<MutatePayloadPointsInternal>,
+    ) -> Result<Response<PointsOperationResponseInternal>, Status> {
+        validate_and_log(request.get_ref());
+
+        let MutatePayloadPointsInternal {
+            mutate_payload_points,
+            shard_id,
+            clock_tag,
+        } = request.into_inner();
+
+        let mutate_payload_points = mutate_payload_points
+            .ok_or_else(|| Status::invalid_argument("MutatePayloadPoints is missing"))?;
+
+        mutate_payload(
+            self.toc.clone(),
+            mutate_payload_points,
+            clock_tag.map(Into::into),
+            shard_id,
+            FULL_ACCESS.clone(),
+        )
+        .await
+    }
+
     async fn overwrite_payload(
         &self,
         request: Request<SetPayloadPointsInternal>,
@@ -338,6 +419,50 @@ impl PointsInternal for PointsInternalService {
         .await
     }
 
+    async fn update_batch(
+        &self,
+        request: Request<UpdateBatchInternal>,
+    ) -> Result<Response<PointsOperationResponseInternal>, Status> {
+        validate_and_log(request.get_ref());
+
+        let UpdateBatchInternal {
+            update_batch_points,
+            shard_id,
+            clock_tag,
+            trashed_at,
+        } = request.into_inner();
+
+        let UpdateBatchPoints {
+            collection_name,
+            wait,
+            operations,
+            ordering,
+            atomic: _,
+            idempotency_key,
+        } = update_batch_points
+            .ok_or_else(|| Status::invalid_argument("UpdateBatchPoints is missing"))?;
+
+        let trashed_at = trashed_at
+            .map(try_date_time_from_proto)
+            .transpose()?
+            .map(|trashed_at| trashed_at.0);
+
+        // Only atomic batches are forwarded between peers
+        update_batch_atomic(
+            self.toc.clone(),
+            collection_name,
+            wait,
+            operations,
+            ordering,
+            idempotency_key,
+            trashed_at,
+            clock_tag.map(Into::into),
+            shard_id,
+            FULL_ACCESS.clone(),
+        )
+        .await
+    }
+
     async fn core_search_batch(
         &self,
         request: Request<CoreSearchBatchPointsInternal>,
@@ -492,6 +617,7 @@ impl PointsInternal for PointsInternalService {
             shard_id,
             query_points,
             timeout,
+            explain,
         } = request.into_inner();
 
         let timeout = timeout.map(Duration::from_secs);
@@ -502,6 +628,7 @@ impl PointsInternal for PointsInternalService {
             query_points,
             shard_id,
             timeout,
+            explain.unwrap_or_default(),
         )
         .await
     }
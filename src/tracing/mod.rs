@@ -4,6 +4,7 @@ pub mod config;
 pub mod default;
 pub mod handle;
 pub mod on_disk;
+pub mod slow_query;
 
 #[cfg(test)]
 mod test;
@@ -0,0 +1,141 @@
+use std::fs;
+use std::io::{self, Write as _};
+use std::path::{Path, PathBuf};
+
+use anyhow::Context as _;
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_THRESHOLD_MS: u64 = 1000;
+const DEFAULT_MAX_FILE_SIZE_MB: u64 = 64;
+const DEFAULT_MAX_FILES: usize = 4;
+const DEFAULT_KEEP_LAST: usize = 128;
+
+/// Slow query log configuration
+///
+/// Only applied on startup, changing it through the logger config API has no effect.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Config {
+    pub enabled: Option<bool>,
+    /// Search, query and scroll requests taking longer than this are logged
+    pub threshold_ms: Option<u64>,
+    /// If not set, slow queries are only kept in memory
+    pub log_file: Option<String>,
+    /// Log-file is rotated, when it grows beyond this size
+    pub max_file_size_mb: Option<u64>,
+    /// Number of rotated log-files to keep, in addition to the current one
+    pub max_files: Option<usize>,
+    /// Number of the most recent slow queries kept in memory and served through the API
+    pub keep_last: Option<usize>,
+}
+
+impl Config {
+    pub fn merge(&mut self, other: Self) {
+        self.enabled = other.enabled.or(self.enabled.take());
+        self.threshold_ms = other.threshold_ms.or(self.threshold_ms.take());
+        self.log_file = other.log_file.or(self.log_file.take());
+        self.max_file_size_mb = other.max_file_size_mb.or(self.max_file_size_mb.take());
+        self.max_files = other.max_files.or(self.max_files.take());
+        self.keep_last = other.keep_last.or(self.keep_last.take());
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.unwrap_or_default()
+    }
+
+    pub fn threshold_ms(&self) -> u64 {
+        self.threshold_ms.unwrap_or(DEFAULT_THRESHOLD_MS)
+    }
+
+    pub fn keep_last(&self) -> usize {
+        self.keep_last.unwrap_or(DEFAULT_KEEP_LAST)
+    }
+
+    pub fn new_log_file(&self) -> anyhow::Result<Option<RotatingLogFile>> {
+        if !self.is_enabled() {
+            return Ok(None);
+        }
+
+        let Some(log_file) = &self.log_file else {
+            return Ok(None);
+        };
+
+        let max_file_size = self.max_file_size_mb.unwrap_or(DEFAULT_MAX_FILE_SIZE_MB) * 1024 * 1024;
+        let max_files = self.max_files.unwrap_or(DEFAULT_MAX_FILES);
+
+        RotatingLogFile::open(log_file.into(), max_file_size, max_files).map(Some)
+    }
+}
+
+/// Append-only log-file, that is rotated into `<path>.1`, `<path>.2`, ... once it grows too big
+pub struct RotatingLogFile {
+    path: PathBuf,
+    file: io::BufWriter<fs::File>,
+    size: u64,
+    max_file_size: u64,
+    max_files: usize,
+}
+
+impl RotatingLogFile {
+    pub fn open(path: PathBuf, max_file_size: u64, max_files: usize) -> anyhow::Result<Self> {
+        let file = Self::open_file(&path)?;
+        let size = file.metadata()?.len();
+
+        Ok(Self {
+            path,
+            file: io::BufWriter::new(file),
+            size,
+            max_file_size,
+            max_files,
+        })
+    }
+
+    /// Append a single line to the log-file, rotating it first if it's full
+    pub fn write_line(&mut self, line: &str) -> anyhow::Result<()> {
+        if self.size > 0 && self.size + line.len() as u64 >= self.max_file_size {
+            self.rotate()?;
+        }
+
+        writeln!(self.file, "{line}")?;
+        self.file.flush()?;
+        self.size += line.len() as u64 + 1;
+
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> anyhow::Result<()> {
+        self.file.flush()?;
+
+        if self.max_files == 0 {
+            fs::remove_file(&self.path)?;
+        } else {
+            for index in (1..self.max_files).rev() {
+                let from = self.rotated_path(index);
+                if from.exists() {
+                    fs::rename(&from, self.rotated_path(index + 1))?;
+                }
+            }
+
+            fs::rename(&self.path, self.rotated_path(1))?;
+        }
+
+        self.file = io::BufWriter::new(Self::open_file(&self.path)?);
+        self.size = 0;
+
+        Ok(())
+    }
+
+    fn rotated_path(&self, index: usize) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(format!(".{index}"));
+        path.into()
+    }
+
+    fn open_file(path: &Path) -> anyhow::Result<fs::File> {
+        fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("failed to open {} slow query log-file", path.display()))
+    }
+}
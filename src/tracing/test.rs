@@ -16,6 +16,15 @@ fn deseriailze_logger_config() {
             "log_file": "/logs/qdrant",
             "log_level": "tracing",
             "span_events": ["new", "close"],
+        },
+
+        "slow_query": {
+            "enabled": true,
+            "threshold_ms": 500,
+            "log_file": "/logs/slow_queries",
+            "max_file_size_mb": 16,
+            "max_files": 2,
+            "keep_last": 64,
         }
     });
 
@@ -40,6 +49,15 @@ fn deseriailze_logger_config() {
                 config::SpanEvent::Close,
             ])),
         },
+
+        slow_query: slow_query::Config {
+            enabled: Some(true),
+            threshold_ms: Some(500),
+            log_file: Some("/logs/slow_queries".into()),
+            max_file_size_mb: Some(16),
+            max_files: Some(2),
+            keep_last: Some(64),
+        },
     };
 
     assert_eq!(config, expected);
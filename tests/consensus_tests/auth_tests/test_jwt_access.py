@@ -406,6 +406,14 @@ ACTION_ACCESS = {
         "POST /collections/{collection_name}/points/delete",
         "qdrant.Points/Delete",
     ),
+    "restore_points": EndpointAccess(
+        False,
+        True,
+        True,
+        "POST /collections/{collection_name}/points/restore",
+        "qdrant.Points/Restore",
+        coll_rw_payload=False,
+    ),
     "update_vectors": EndpointAccess(
         False,
         True,
@@ -530,6 +538,9 @@ ACTION_ACCESS = {
     "query_batch_points": EndpointAccess(
         True, True, True, "POST /collections/{collection_name}/points/query/batch", "qdrant.Points/QueryBatch"
     ),
+    "subscribe_updates": EndpointAccess(
+        True, True, True, None, "qdrant.Points/SubscribeUpdates", coll_rw_payload=False
+    ),
     ### Service ###
     "root": EndpointAccess(True, True, True, "GET /", "qdrant.Qdrant/HealthCheck"),
     "readyz": EndpointAccess(True, True, True, "GET /readyz", "grpc.health.v1.Health/Check"),
@@ -660,6 +671,9 @@ def check_grpc_access(
 
     try:
         _res = client.request(service=service, method=method, request=request)
+        # Server streaming responses only fail once consumed
+        if not isinstance(_res, dict):
+            _res = list(_res)
     except grpc.RpcError as e:
         if should_succeed:
             if e.code() not in [grpc.StatusCode.INVALID_ARGUMENT, grpc.StatusCode.NOT_FOUND]:
@@ -722,68 +736,69 @@ def check_access(
     assert isinstance(action_access, EndpointAccess)
 
     ## Check Rest
-    method, path = action_access.rest_endpoint.split(" ")
-
-    allowed_for = action_access.access
-
-    check_rest_access(
-        method, path, rest_request, allowed_for.read, TOKEN_R, path_params, rest_req_kwargs
-    )
-    check_rest_access(
-        method, path, rest_request, allowed_for.coll_r, TOKEN_COLL_R, path_params, rest_req_kwargs
-    )
-    check_rest_access(
-        method,
-        path,
-        rest_request,
-        allowed_for.coll_rw,
-        TOKEN_COLL_RW,
-        path_params,
-        rest_req_kwargs,
-    )
-    check_rest_access(
-        method,
-        path,
-        rest_request,
-        allowed_for.coll_r_payload,
-        TOKEN_COLL_R_PAYLOAD,
-        path_params,
-        rest_req_kwargs,
-    )
-    check_rest_access(
-        method,
-        path,
-        rest_request,
-        allowed_for.coll_rw_payload,
-        TOKEN_COLL_RW_PAYLOAD,
-        path_params,
-        rest_req_kwargs,
-    )
-    check_rest_access(
-        method, path, rest_request, allowed_for.manage, TOKEN_M, path_params, rest_req_kwargs
-    )
-
-    # Check that API key is the same as manage token
-    check_rest_access(
-        method,
-        path,
-        rest_request,
-        allowed_for.manage,
-        SECRET,
-        path_params,
-        rest_req_kwargs,
-    )
-
-    # Check that read-only API key is the same as read-only token
-    check_rest_access(
-        method,
-        path,
-        rest_request,
-        allowed_for.read,
-        READ_ONLY_API_KEY,
-        path_params,
-        rest_req_kwargs,
-    )
+    if action_access.rest_endpoint is not None:
+        method, path = action_access.rest_endpoint.split(" ")
+
+        allowed_for = action_access.access
+
+        check_rest_access(
+            method, path, rest_request, allowed_for.read, TOKEN_R, path_params, rest_req_kwargs
+        )
+        check_rest_access(
+            method, path, rest_request, allowed_for.coll_r, TOKEN_COLL_R, path_params, rest_req_kwargs
+        )
+        check_rest_access(
+            method,
+            path,
+            rest_request,
+            allowed_for.coll_rw,
+            TOKEN_COLL_RW,
+            path_params,
+            rest_req_kwargs,
+        )
+        check_rest_access(
+            method,
+            path,
+            rest_request,
+            allowed_for.coll_r_payload,
+            TOKEN_COLL_R_PAYLOAD,
+            path_params,
+            rest_req_kwargs,
+        )
+        check_rest_access(
+            method,
+            path,
+            rest_request,
+            allowed_for.coll_rw_payload,
+            TOKEN_COLL_RW_PAYLOAD,
+            path_params,
+            rest_req_kwargs,
+        )
+        check_rest_access(
+            method, path, rest_request, allowed_for.manage, TOKEN_M, path_params, rest_req_kwargs
+        )
+
+        # Check that API key is the same as manage token
+        check_rest_access(
+            method,
+            path,
+            rest_request,
+            allowed_for.manage,
+            SECRET,
+            path_params,
+            rest_req_kwargs,
+        )
+
+        # Check that read-only API key is the same as read-only token
+        check_rest_access(
+            method,
+            path,
+            rest_request,
+            allowed_for.read,
+            READ_ONLY_API_KEY,
+            path_params,
+            rest_req_kwargs,
+        )
 
     ## Check GRPC
     grpc_endpoint = action_access.grpc_endpoint
@@ -1507,6 +1522,19 @@ def test_delete_points():
     )
 
 
+def test_restore_points():
+    check_access(
+        "restore_points",
+        rest_request={"points": [3], "shard_key": SHARD_KEY},
+        path_params={"collection_name": COLL_NAME},
+        grpc_request={
+            "collection_name": COLL_NAME,
+            "points": {"points": {"ids": [{"num": 3}]}},
+            **SHARD_KEY_SELECTOR,
+        },
+    )
+
+
 def test_update_vectors():
     check_access(
         "update_vectors",
@@ -1752,6 +1780,14 @@ def test_query_batch_points():
     )
     
 
+def test_subscribe_updates():
+    # Non-existent shard makes the stream fail right after the access check
+    check_access(
+        "subscribe_updates",
+        grpc_request={"collection_name": COLL_NAME, "start_from": {1000: 0}},
+    )
+
+
 def test_root():
     check_access("root")
 